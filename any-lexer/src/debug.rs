@@ -0,0 +1,181 @@
+//! Utilities for debugging lexers, by rendering token streams as text.
+//!
+//! # Example
+//!
+//! ```rust
+//! use any_lexer::debug;
+//! use any_lexer::RustLexer;
+//!
+//! let code = "fn main() {}";
+//! println!("{}", debug::dump(RustLexer::new(code), code));
+//! ```
+
+use std::fmt::{self, Write};
+use std::ops::Range;
+
+use crate::TokenSpan;
+
+/// The maximum amount of characters of token text
+/// rendered, before the text is truncated.
+const MAX_TEXT_LEN: usize = 40;
+
+/// Renders `tokens` as an aligned table of kind, range, and escaped text,
+/// one token per line.
+///
+/// Text not covered by any token, i.e. gaps between tokens and trailing
+/// text, is rendered as `<gap>` rows, which makes it easy to spot text
+/// a lexer skipped.
+///
+/// Long token text is truncated.
+///
+/// See also [`dump_compact()`].
+///
+/// # Example
+///
+/// ```rust
+/// use any_lexer::{debug, JsonLexer};
+///
+/// let json = r#"{"a": 1}"#;
+/// let table = debug::dump(JsonLexer::new(json), json);
+///
+/// assert_eq!(
+///     table,
+///     r#"Delim   0..1  "{"
+/// String  1..4  "\"a\""
+/// Punct   4..5  ":"
+/// Space   5..6  " "
+/// Number  6..7  "1"
+/// Delim   7..8  "}"
+/// "#
+/// );
+/// ```
+pub fn dump<'text, Tok, I>(tokens: I, text: &str) -> String
+where
+    I: IntoIterator<Item = (Tok, TokenSpan<'text>)>,
+    Tok: fmt::Debug,
+{
+    let rows = rows(tokens, text);
+
+    let kind_width = rows.iter().map(|row| row.0.len()).max().unwrap_or(0);
+    let range_width = rows.iter().map(|row| row.1.len()).max().unwrap_or(0);
+
+    let mut table = String::new();
+    for (kind, range, text) in rows {
+        // Writing to a `String` never fails
+        _ = writeln!(table, "{kind:kind_width$}  {range:range_width$}  {text}");
+    }
+    table
+}
+
+/// Renders `tokens` on a single line, e.g. `Ident("a") Space(" ")`.
+///
+/// Text not covered by any token is rendered as `<gap>("...")`.
+///
+/// See also [`dump()`].
+///
+/// # Example
+///
+/// ```rust
+/// use any_lexer::{debug, JsonLexer};
+///
+/// let json = "[1, 2]";
+/// let line = debug::dump_compact(JsonLexer::new(json), json);
+///
+/// assert_eq!(
+///     line,
+///     r#"Delim("[") Number("1") Punct(",") Space(" ") Number("2") Delim("]")"#
+/// );
+/// ```
+pub fn dump_compact<'text, Tok, I>(tokens: I, text: &str) -> String
+where
+    I: IntoIterator<Item = (Tok, TokenSpan<'text>)>,
+    Tok: fmt::Debug,
+{
+    let mut line = String::new();
+    for (kind, _range, text) in rows(tokens, text) {
+        if !line.is_empty() {
+            line.push(' ');
+        }
+        // Writing to a `String` never fails
+        _ = write!(line, "{kind}({text})");
+    }
+    line
+}
+
+/// Returns the kind, range, and escaped text of each token and gap.
+fn rows<'text, Tok, I>(tokens: I, text: &str) -> Vec<(String, String, String)>
+where
+    I: IntoIterator<Item = (Tok, TokenSpan<'text>)>,
+    Tok: fmt::Debug,
+{
+    let gap = |range: Range<usize>| {
+        let s = text.get(range.clone()).unwrap_or_default();
+        ("<gap>".to_owned(), format!("{range:?}"), escape(s))
+    };
+
+    let mut rows = Vec::new();
+    let mut pos = 0;
+    for (tok, span) in tokens {
+        if span.start() > pos {
+            rows.push(gap(pos..span.start()));
+        }
+        pos = pos.max(span.end());
+
+        rows.push((
+            format!("{tok:?}"),
+            format!("{:?}", span.range()),
+            escape(span.as_str()),
+        ));
+    }
+    if text.len() > pos {
+        rows.push(gap(pos..text.len()));
+    }
+    rows
+}
+
+/// Returns `s` quoted and escaped, and truncated if it is long.
+fn escape(s: &str) -> String {
+    match truncate(s) {
+        Some(truncated) => format!("{truncated:?}…"),
+        None => format!("{s:?}"),
+    }
+}
+
+/// Returns `s` truncated to [`MAX_TEXT_LEN`] characters,
+/// or `None` if it is not longer than that.
+pub(crate) fn truncate(s: &str) -> Option<&str> {
+    s.char_indices()
+        .nth(MAX_TEXT_LEN)
+        .map(|(end, _c)| &s[..end])
+}
+
+#[cfg(all(test, feature = "lang-rust"))]
+mod tests {
+    use super::*;
+    use crate::RustLexer;
+
+    #[test]
+    fn test_dump_gaps() {
+        let text = "a b c";
+        let tokens = RustLexer::new(text).filter(|(_tok, span)| span.as_str() != "b");
+
+        let expected = "\
+Ident  0..1  \"a\"
+Space  1..2  \" \"
+<gap>  2..3  \"b\"
+Space  3..4  \" \"
+Ident  4..5  \"c\"
+";
+        assert_eq!(dump(tokens.clone(), text), expected);
+
+        let expected = r#"Ident("a") Space(" ") <gap>("b") Space(" ") Ident("c")"#;
+        assert_eq!(dump_compact(tokens, text), expected);
+    }
+
+    #[test]
+    fn test_dump_truncate() {
+        let text = format!("\"{}\"", "x".repeat(100));
+        let dump = dump_compact(RustLexer::new(&text), &text);
+        assert_eq!(dump, format!("String({:?}…)", &text[..MAX_TEXT_LEN]));
+    }
+}
@@ -0,0 +1,179 @@
+use text_scanner::ext::ApacheScannerExt;
+use text_scanner::Scanner;
+
+use crate::{
+    impl_lexer_from_scanner, impl_token_kind_sets, impl_token_names, ScanToken, ScannerExt,
+    TokenSpan,
+};
+
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+pub enum ApacheToken {
+    Space,
+    LineComment,
+    /// Directive name at the start of a line, e.g. `ServerName`.
+    Directive,
+    /// Start of a section tag, e.g. `<VirtualHost` and `</VirtualHost`.
+    SectionOpen,
+    /// End of a section tag, i.e. `>`.
+    SectionClose,
+    /// Unquoted argument, e.g. `/var/www/html` and `*:80`.
+    Word,
+    /// Unquoted argument which is an integer, e.g. `80`.
+    Number,
+    /// Variable, e.g. `${SRVROOT}`, `%{HTTP_HOST}`, and `$1`.
+    Var,
+    /// String, e.g. `"/var/www/html"`.
+    String,
+    /// Line continuation, i.e. `\` at the end of a line.
+    Continuation,
+    /// Given valid Apache configuration, then this variant should never be
+    /// encountered. If is is encountered, then check if an issue has already
+    /// been submitted, otherwise please [submit an issue].
+    ///
+    /// [submit an issue]: https://github.com/vallentin/colorblast/issues
+    Unknown,
+}
+
+impl_token_names!(ApacheToken {
+    Space => "space",
+    LineComment => "line_comment",
+    Directive => "directive",
+    SectionOpen => "section_open",
+    SectionClose => "section_close",
+    Word => "word",
+    Number => "number",
+    Var => "var",
+    String => "string",
+    Continuation => "continuation",
+    Unknown => "unknown",
+});
+
+impl_token_kind_sets!(ApacheToken {
+    /// Whitespace and comments.
+    TRIVIA => [Space, LineComment],
+    /// String and number arguments.
+    LITERALS => [String, Number],
+});
+
+impl ScanToken for ApacheToken {
+    fn scan_token<'text>(scanner: &mut Scanner<'text>) -> Option<(Self, TokenSpan<'text>)> {
+        let (r, _s) = scanner.skip_whitespace();
+        if !r.is_empty() {
+            return Some((Self::Space, scanner.span(r)));
+        }
+
+        if scanner.is_line_start() && !scanner.follows_continuation() {
+            if let Ok((r, _s)) = scanner.scan_apache_line_comment() {
+                return Some((Self::LineComment, scanner.span(r)));
+            } else if let Ok((r, _s)) = scanner.scan_apache_section_open() {
+                return Some((Self::SectionOpen, scanner.span(r)));
+            } else if let Ok((r, _s)) = scanner.scan_apache_identifier() {
+                return Some((Self::Directive, scanner.span(r)));
+            }
+        }
+
+        if scanner.remaining_text().starts_with('>') && is_in_section_tag(scanner) {
+            let (r, _c) = scanner.next().ok()?;
+            return Some((Self::SectionClose, scanner.span(r)));
+        }
+
+        if let Ok((r, outcome)) = scanner.scan_apache_string_outcome() {
+            return Some((Self::String, scanner.span_outcome(r, &outcome)));
+        } else if let Ok((r, _s)) = scanner.scan_apache_variable() {
+            return Some((Self::Var, scanner.span(r)));
+        } else if let Ok((r, _s)) = scanner.scan_apache_continuation() {
+            return Some((Self::Continuation, scanner.span(r)));
+        }
+
+        if let Ok((r, s)) = scanner.scan_apache_word() {
+            let tok = if s.chars().all(|c| c.is_ascii_digit()) {
+                Self::Number
+            } else {
+                Self::Word
+            };
+            return Some((tok, scanner.span(r)));
+        }
+
+        // `>` outside of a section tag, e.g. in `Require expr %{X} > 1`
+        if let Ok((r, _c)) = scanner.accept_char('>') {
+            return Some((Self::Word, scanner.span(r)));
+        }
+
+        let (r, _c) = scanner.next().ok()?;
+        Some((Self::Unknown, scanner.span(r)))
+    }
+}
+
+/// Returns `true` if the cursor is within a section tag, i.e. the line
+/// starts with `<` and the tag has not been closed by a `>` yet.
+fn is_in_section_tag(scanner: &Scanner<'_>) -> bool {
+    let before = &scanner.text()[..scanner.cursor_pos()];
+    let line = before.rsplit(['\n', '\r']).next().unwrap_or_default();
+    let line = line.trim_start();
+    line.starts_with('<') && !line.contains('>')
+}
+
+/// Apache HTTP Server configuration lexer producing [`ApacheToken`]s.
+///
+/// **Note:** Cloning `ApacheLexer` is essentially a copy, as it just contains
+/// a `&str` and a `usize` for its `cursor`. However, `Copy` is not
+/// implemented, to avoid accidentally copying immutable `ApacheLexer`s.
+#[derive(Clone, Debug)]
+pub struct ApacheLexer<'text> {
+    scanner: Scanner<'text>,
+}
+
+impl<'text> ApacheLexer<'text> {
+    #[inline]
+    pub fn new(text: &'text str) -> Self {
+        Self {
+            scanner: Scanner::new(text),
+        }
+    }
+}
+
+impl_lexer_from_scanner!('text, ApacheLexer<'text>, ApacheToken, scanner);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_apache_lexer_spans() {
+        // This intentionally uses Rust code as input, as it is
+        // only testing that ApacheLexer returns all characters
+        let input = include_str!("../../../text-scanner/src/ext/rust.rs");
+        let mut output = String::new();
+
+        let lexer = ApacheLexer::new(input);
+        for (_tok, span) in lexer {
+            output.push_str(span.as_str());
+        }
+
+        assert_eq!(input, output);
+    }
+
+    #[test]
+    fn test_apache_lexer_tokens() {
+        let input = "# c\n<VirtualHost *:80>\n  DocumentRoot \"${ROOT}/htdocs\" # x\n  Listen 80 \\\n    ssl\n</VirtualHost>\n";
+        let tokens = ApacheLexer::new(input)
+            .filter(|(tok, _span)| *tok != ApacheToken::Space)
+            .map(|(tok, span)| (tok, span.as_str()))
+            .collect::<Vec<_>>();
+
+        use ApacheToken::*;
+        #[rustfmt::skip]
+        let expected = [
+            (LineComment, "# c"),
+            (SectionOpen, "<VirtualHost"), (Word, "*:80"), (SectionClose, ">"),
+            // `#` only starts a comment at the start of a line
+            (Directive, "DocumentRoot"), (String, "\"${ROOT}/htdocs\""), (Word, "#"), (Word, "x"),
+            (Directive, "Listen"), (Number, "80"), (Continuation, "\\"),
+            // Continued lines do not start with a directive
+            (Word, "ssl"),
+            (SectionOpen, "</VirtualHost"), (SectionClose, ">"),
+        ];
+
+        assert_eq!(tokens, expected);
+    }
+}
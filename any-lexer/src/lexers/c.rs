@@ -1,6 +1,9 @@
 use text_scanner::{ext::CScannerExt, Scanner};
 
-use crate::{impl_lexer_from_scanner, ScanToken, ScannerExt, TokenSpan};
+use crate::{
+    impl_lexer_from_scanner, impl_token_kind_sets, impl_token_names, ScanToken, ScannerExt,
+    TokenSpan,
+};
 
 // Reference: https://learn.microsoft.com/en-us/cpp/c-language/c-keywords?view=msvc-170#standard-c-keywords
 #[rustfmt::skip]
@@ -43,6 +46,28 @@ pub enum CToken {
     Unknown,
 }
 
+impl_token_names!(CToken {
+    Space => "space",
+    LineComment => "line_comment",
+    BlockComment => "block_comment",
+    Ident => "ident",
+    Keyword => "keyword",
+    Char => "char",
+    String => "string",
+    Int => "int",
+    Float => "float",
+    Delim => "delim",
+    Punct => "punct",
+    Unknown => "unknown",
+});
+
+impl_token_kind_sets!(CToken {
+    /// Whitespace and comments.
+    TRIVIA => [Space, LineComment, BlockComment],
+    /// Character, string, and number literals.
+    LITERALS => [Char, String, Int, Float],
+});
+
 impl ScanToken for CToken {
     fn scan_token<'text>(scanner: &mut Scanner<'text>) -> Option<(Self, TokenSpan<'text>)> {
         let (r, _s) = scanner.skip_whitespace();
@@ -52,8 +77,8 @@ impl ScanToken for CToken {
 
         if let Ok((r, _s)) = scanner.scan_c_line_comment() {
             return Some((Self::LineComment, scanner.span(r)));
-        } else if let Ok((r, _s)) = scanner.scan_c_block_comment() {
-            return Some((Self::BlockComment, scanner.span(r)));
+        } else if let Ok((r, outcome)) = scanner.scan_c_block_comment_outcome() {
+            return Some((Self::BlockComment, scanner.span_outcome(r, &outcome)));
         }
 
         if let Ok((r, ident)) = scanner.scan_c_identifier() {
@@ -67,8 +92,8 @@ impl ScanToken for CToken {
 
         if let Ok((r, _s)) = scanner.scan_c_char() {
             return Some((Self::Char, scanner.span(r)));
-        } else if let Ok((r, _s)) = scanner.scan_c_string() {
-            return Some((Self::String, scanner.span(r)));
+        } else if let Ok((r, outcome)) = scanner.scan_c_string_outcome() {
+            return Some((Self::String, scanner.span_outcome(r, &outcome)));
         }
 
         if let Ok((r, _s)) = scanner.scan_c_float() {
@@ -0,0 +1,181 @@
+use text_scanner::ext::CMakeScannerExt;
+use text_scanner::Scanner;
+
+use crate::{
+    impl_lexer_from_scanner, impl_token_kind_sets, impl_token_names, ScanToken, ScannerExt,
+    TokenSpan,
+};
+
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+pub enum CMakeToken {
+    Space,
+    LineComment,
+    /// Bracket comment, e.g. `#[[ comment ]]`.
+    BracketComment,
+    /// Command names and unquoted arguments which are identifiers,
+    /// e.g. `add_executable` and `PRIVATE`.
+    Ident,
+    /// Variable reference, e.g. `${NAME}` and `$ENV{PATH}`.
+    VarRef,
+    /// Quoted argument, e.g. `"Hello ${NAME}"`.
+    QuotedArgument,
+    /// Bracket argument, e.g. `[[text]]` and `[=[text]=]`.
+    BracketArgument,
+    /// Unquoted arguments which are numbers, e.g. `3` and `3.10`.
+    Number,
+    /// Unquoted arguments which are not identifiers or numbers,
+    /// e.g. `src/main.c` and `-Wall`.
+    UnquotedArgument,
+    Delim,
+    /// Given valid CMake code, then this variant should never be encountered.
+    /// If is is encountered, then check if an issue has already been submitted,
+    /// otherwise please [submit an issue].
+    ///
+    /// [submit an issue]: https://github.com/vallentin/colorblast/issues
+    Unknown,
+}
+
+impl_token_names!(CMakeToken {
+    Space => "space",
+    LineComment => "line_comment",
+    BracketComment => "bracket_comment",
+    Ident => "ident",
+    VarRef => "var_ref",
+    QuotedArgument => "quoted_argument",
+    BracketArgument => "bracket_argument",
+    Number => "number",
+    UnquotedArgument => "unquoted_argument",
+    Delim => "delim",
+    Unknown => "unknown",
+});
+
+impl_token_kind_sets!(CMakeToken {
+    /// Whitespace and comments.
+    TRIVIA => [Space, LineComment, BracketComment],
+    /// Quoted, bracket, and number arguments.
+    LITERALS => [QuotedArgument, BracketArgument, Number],
+});
+
+impl ScanToken for CMakeToken {
+    fn scan_token<'text>(scanner: &mut Scanner<'text>) -> Option<(Self, TokenSpan<'text>)> {
+        let (r, _s) = scanner.skip_whitespace();
+        if !r.is_empty() {
+            return Some((Self::Space, scanner.span(r)));
+        }
+
+        if let Ok((r, outcome)) = scanner.scan_cmake_bracket_comment_outcome() {
+            return Some((Self::BracketComment, scanner.span_outcome(r, &outcome)));
+        } else if let Ok((r, _s)) = scanner.scan_cmake_line_comment() {
+            return Some((Self::LineComment, scanner.span(r)));
+        }
+
+        if let Ok((r, outcome)) = scanner.scan_cmake_quoted_argument_outcome() {
+            return Some((Self::QuotedArgument, scanner.span_outcome(r, &outcome)));
+        } else if let Ok((r, outcome)) = scanner.scan_cmake_bracket_argument_outcome() {
+            return Some((Self::BracketArgument, scanner.span_outcome(r, &outcome)));
+        }
+
+        if let Ok((r, _s)) = scanner.scan_cmake_variable_reference() {
+            return Some((Self::VarRef, scanner.span(r)));
+        }
+
+        if let Ok((r, s)) = scanner.scan_cmake_unquoted_element() {
+            let tok = if is_cmake_identifier(s) {
+                Self::Ident
+            } else if is_cmake_number(s) {
+                Self::Number
+            } else {
+                Self::UnquotedArgument
+            };
+            return Some((tok, scanner.span(r)));
+        }
+
+        if let Ok((r, _c)) = scanner.accept_char_any(&['(', ')']) {
+            return Some((Self::Delim, scanner.span(r)));
+        }
+
+        let (r, _c) = scanner.next().ok()?;
+        Some((Self::Unknown, scanner.span(r)))
+    }
+}
+
+fn is_cmake_identifier(s: &str) -> bool {
+    let mut scanner = Scanner::new(s);
+    scanner.scan_cmake_identifier().is_ok() && !scanner.has_remaining_text()
+}
+
+/// Returns `true` if `s` is a number, e.g. `3` and `3.10`, which are
+/// commonly used in e.g. `cmake_minimum_required(VERSION 3.10)`.
+fn is_cmake_number(s: &str) -> bool {
+    s.starts_with(|c: char| c.is_ascii_digit())
+        && s.bytes().all(|b| b.is_ascii_digit() || (b == b'.'))
+}
+
+/// CMake lexer producing [`CMakeToken`]s.
+///
+/// **Note:** Cloning `CMakeLexer` is essentially a copy, as it just contains
+/// a `&str` and a `usize` for its `cursor`. However, `Copy` is not
+/// implemented, to avoid accidentally copying immutable `CMakeLexer`s.
+#[derive(Clone, Debug)]
+pub struct CMakeLexer<'text> {
+    scanner: Scanner<'text>,
+}
+
+impl<'text> CMakeLexer<'text> {
+    #[inline]
+    pub fn new(text: &'text str) -> Self {
+        Self {
+            scanner: Scanner::new(text),
+        }
+    }
+}
+
+impl_lexer_from_scanner!('text, CMakeLexer<'text>, CMakeToken, scanner);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cmake_lexer_spans() {
+        // This intentionally uses Rust code as input, as it is
+        // only testing that CMakeLexer returns all characters
+        let input = include_str!("../../../text-scanner/src/ext/rust.rs");
+        let mut output = String::new();
+
+        let lexer = CMakeLexer::new(input);
+        for (_tok, span) in lexer {
+            output.push_str(span.as_str());
+        }
+
+        assert_eq!(input, output);
+    }
+
+    #[test]
+    fn test_cmake_lexer_tokens() {
+        use CMakeToken as Tok;
+
+        let code = r#"#[[ a ]] if(${X}_y VERSION 3.10 "s" [=[b]=] -Wall) # c"#;
+        let tokens = CMakeLexer::new(code)
+            .filter(|(tok, _span)| *tok != Tok::Space)
+            .map(|(tok, span)| (tok, span.as_str()))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            tokens,
+            [
+                (Tok::BracketComment, "#[[ a ]]"),
+                (Tok::Ident, "if"),
+                (Tok::Delim, "("),
+                (Tok::VarRef, "${X}"),
+                (Tok::Ident, "_y"),
+                (Tok::Ident, "VERSION"),
+                (Tok::Number, "3.10"),
+                (Tok::QuotedArgument, "\"s\""),
+                (Tok::BracketArgument, "[=[b]=]"),
+                (Tok::UnquotedArgument, "-Wall"),
+                (Tok::Delim, ")"),
+                (Tok::LineComment, "# c"),
+            ]
+        );
+    }
+}
@@ -1,6 +1,11 @@
+use std::ops::Range;
+
 use text_scanner::{ext::CScannerExt, Scanner};
 
-use crate::{impl_lexer_from_scanner, ScanToken, ScannerExt, TokenSpan};
+use crate::{
+    impl_from_str_for_lexer, impl_iter_for_lexer, impl_token_kind_sets, impl_token_names,
+    ScanToken, ScannerExt, TokenSpan,
+};
 
 // Reference: https://en.cppreference.com/w/cpp/keyword
 #[rustfmt::skip]
@@ -33,6 +38,11 @@ pub enum CppToken {
     Float,
     Delim,
     Punct,
+    /// Code in a conditional region, which is always excluded by the
+    /// preprocessor, e.g. `#if 0 ... #endif`.
+    ///
+    /// Only produced if enabled by [`CppLexer::inactive_regions()`].
+    Inactive,
     /// Given valid C++ code, then this variant should never be encountered. If
     /// is is encountered, then check if an issue has already been submitted,
     /// otherwise please [submit an issue].
@@ -41,6 +51,29 @@ pub enum CppToken {
     Unknown,
 }
 
+impl_token_names!(CppToken {
+    Space => "space",
+    LineComment => "line_comment",
+    BlockComment => "block_comment",
+    Ident => "ident",
+    Keyword => "keyword",
+    Char => "char",
+    String => "string",
+    Int => "int",
+    Float => "float",
+    Delim => "delim",
+    Punct => "punct",
+    Inactive => "inactive",
+    Unknown => "unknown",
+});
+
+impl_token_kind_sets!(CppToken {
+    /// Whitespace, comments, and inactive regions.
+    TRIVIA => [Space, LineComment, BlockComment, Inactive],
+    /// Character, string, and number literals.
+    LITERALS => [Char, String, Int, Float],
+});
+
 impl ScanToken for CppToken {
     fn scan_token<'text>(scanner: &mut Scanner<'text>) -> Option<(Self, TokenSpan<'text>)> {
         let (r, _s) = scanner.skip_whitespace();
@@ -50,8 +83,8 @@ impl ScanToken for CppToken {
 
         if let Ok((r, _s)) = scanner.scan_c_line_comment() {
             return Some((Self::LineComment, scanner.span(r)));
-        } else if let Ok((r, _s)) = scanner.scan_c_block_comment() {
-            return Some((Self::BlockComment, scanner.span(r)));
+        } else if let Ok((r, outcome)) = scanner.scan_c_block_comment_outcome() {
+            return Some((Self::BlockComment, scanner.span_outcome(r, &outcome)));
         }
 
         if let Ok((r, ident)) = scanner.scan_c_identifier() {
@@ -65,8 +98,8 @@ impl ScanToken for CppToken {
 
         if let Ok((r, _s)) = scanner.scan_c_char() {
             return Some((Self::Char, scanner.span(r)));
-        } else if let Ok((r, _s)) = scanner.scan_c_string() {
-            return Some((Self::String, scanner.span(r)));
+        } else if let Ok((r, outcome)) = scanner.scan_c_string_outcome() {
+            return Some((Self::String, scanner.span_outcome(r, &outcome)));
         }
 
         if let Ok((r, _s)) = scanner.scan_c_float() {
@@ -149,11 +182,50 @@ impl ScanToken for CppToken {
 /// C++ lexer producing [`CppToken`]s.
 ///
 /// **Note:** Cloning `CppLexer` is essentially a copy, as it just contains
-/// a `&str` and a `usize` for its `cursor`. However, `Copy` is not
-/// implemented, to avoid accidentally copying immutable `CppLexer`s.
+/// a `&str` and a `usize` for its `cursor`, unless [`inactive_regions()`]
+/// is enabled, in which case the upcoming inactive regions are cloned.
+/// However, `Copy` is not implemented, to avoid accidentally copying
+/// immutable `CppLexer`s.
+///
+/// # Inactive Regions
+///
+/// If enabled with [`inactive_regions()`], then code in conditional
+/// regions, which are always excluded by the preprocessor, is produced
+/// as a single [`CppToken::Inactive`], similarly to how editors gray out
+/// such regions. This includes regions following `#if 0` or `#elif 0`,
+/// as well as the `#else` and `#elif` branches following `#if 1`.
+/// The directives themselves are produced as regular tokens.
+///
+/// ```rust
+/// use any_lexer::{CppLexer, CppToken};
+///
+/// let code = "#if 0\nint x;\n#else\nint y;\n#endif";
+/// let tokens = CppLexer::new(code)
+///     .inactive_regions(true)
+///     .filter(|(tok, _span)| *tok != CppToken::Space)
+///     .map(|(tok, span)| (tok, span.as_str()))
+///     .collect::<Vec<_>>();
+///
+/// use CppToken::*;
+/// assert_eq!(
+///     tokens,
+///     [
+///         (Punct, "#"), (Keyword, "if"), (Int, "0"),
+///         (Inactive, "int x;\n"),
+///         (Punct, "#"), (Keyword, "else"),
+///         (Keyword, "int"), (Ident, "y"), (Punct, ";"),
+///         (Punct, "#"), (Ident, "endif"),
+///     ]
+/// );
+/// ```
+///
+/// [`inactive_regions()`]: Self::inactive_regions
 #[derive(Clone, Debug)]
 pub struct CppLexer<'text> {
     scanner: Scanner<'text>,
+    inactive_regions: bool,
+    /// Upcoming inactive regions, sorted by their start.
+    inactive: Vec<Range<usize>>,
 }
 
 impl<'text> CppLexer<'text> {
@@ -161,11 +233,188 @@ impl<'text> CppLexer<'text> {
     pub fn new(text: &'text str) -> Self {
         Self {
             scanner: Scanner::new(text),
+            inactive_regions: false,
+            inactive: Vec::new(),
+        }
+    }
+
+    /// Sets whether code in conditional regions, which are always excluded
+    /// by the preprocessor, is produced as [`CppToken::Inactive`], which is
+    /// disabled by default.
+    ///
+    /// See [Inactive Regions](Self#inactive-regions) for more information.
+    #[inline]
+    pub fn inactive_regions(mut self, inactive_regions: bool) -> Self {
+        self.inactive_regions = inactive_regions;
+        self
+    }
+}
+
+impl<'text> crate::Lexer<'text> for CppLexer<'text> {
+    type Token = CppToken;
+
+    fn next_token(&mut self) -> Option<(Self::Token, TokenSpan<'text>)> {
+        if self.inactive_regions {
+            let pos = self.scanner.cursor_pos();
+            self.inactive.retain(|r| r.end > pos);
+
+            if let Some(r) = self.inactive.first() {
+                if r.start <= pos {
+                    let r = pos..r.end;
+                    self.inactive.remove(0);
+                    self.scanner.set_cursor_pos(r.end);
+                    return Some((CppToken::Inactive, self.scanner.span(r)));
+                }
+            }
+
+            if let Some(r) = find_inactive_region(self.scanner.text(), pos) {
+                let i = self.inactive.partition_point(|other| other.start < r.start);
+                self.inactive.insert(i, r);
+            }
+        }
+
+        let (tok, span) = CppToken::scan_token(&mut self.scanner)?;
+
+        // Truncate tokens overlapping the next inactive region, e.g.
+        // the whitespace following an `#if 0` directive
+        if let Some(r) = self.inactive.first() {
+            if (span.start() < r.start) && (r.start < span.end()) {
+                self.scanner.set_cursor_pos(r.start);
+                return Some((tok, self.scanner.span(span.start()..r.start)));
+            }
+        }
+
+        Some((tok, span))
+    }
+
+    #[inline]
+    fn peek_token(&self) -> Option<(Self::Token, TokenSpan<'text>)> {
+        self.clone().next_token()
+    }
+
+    #[inline]
+    fn reset(&mut self) -> usize {
+        self.inactive.clear();
+        self.set_cursor_pos(0)
+    }
+
+    #[inline]
+    fn scanner(&self) -> &Scanner<'text> {
+        &self.scanner
+    }
+
+    #[inline]
+    fn scanner_mut(&mut self) -> &mut Scanner<'text> {
+        &mut self.scanner
+    }
+}
+
+impl_iter_for_lexer!('text, CppLexer<'text>);
+impl_from_str_for_lexer!('text, CppLexer<'text>);
+
+/// Returns the inactive region of the conditional directive at `pos`,
+/// if the directive is at the start of a line, and its condition is
+/// lexically always false or always true, e.g. `#if 0` or `#if 1`.
+fn find_inactive_region(text: &str, pos: usize) -> Option<Range<usize>> {
+    // Checked first, as this is called for every token
+    if !text[pos..].starts_with('#') {
+        return None;
+    }
+
+    // Only look back across spaces and tabs, to avoid scanning
+    // the whole line before every `#`
+    let before = text[..pos].trim_end_matches([' ', '\t']);
+    if !before.is_empty() && !before.ends_with('\n') {
+        return None;
+    }
+
+    let (name, rest) = parse_directive(&text[pos..])?;
+    if !matches!(name, "if" | "elif") {
+        return None;
+    }
+    let cond = parse_const_condition(rest.lines().next().unwrap_or(""))?;
+
+    let mut body_start = text.len();
+    let mut branch = None;
+    let mut depth = 0usize;
+    let mut line_start = pos;
+    for line in text[pos..].split_inclusive('\n').skip(1) {
+        line_start += text[line_start..].find('\n').map_or(0, |i| i + 1);
+        if body_start == text.len() {
+            body_start = line_start;
+        }
+
+        let trimmed = line.trim_start_matches([' ', '\t']);
+        let Some((name, _rest)) = parse_directive(trimmed) else {
+            continue;
+        };
+        match name {
+            "if" | "ifdef" | "ifndef" => depth += 1,
+            "endif" if depth > 0 => depth -= 1,
+            "endif" => {
+                return match (cond, branch) {
+                    (false, _) => Some(body_start..line_start),
+                    (true, Some(branch_end)) => Some(branch_end..line_start),
+                    (true, None) => None,
+                }
+                .filter(|r| !r.is_empty());
+            }
+            "else" | "elif" | "elifdef" | "elifndef" if depth == 0 => {
+                if !cond {
+                    return Some(body_start..line_start).filter(|r| !r.is_empty());
+                } else if branch.is_none() {
+                    branch = Some(line_start + line.len());
+                }
+            }
+            _ => {}
         }
     }
+
+    // Unterminated conditional, so the region ends at the end of the text
+    match (cond, branch) {
+        (false, _) => Some(body_start..text.len()),
+        (true, Some(branch_end)) => Some(branch_end..text.len()),
+        (true, None) => None,
+    }
+    .filter(|r| !r.is_empty())
+}
+
+/// Returns the name of the directive at the start of `text`, e.g. `if`
+/// in `# if 0`, along with the remaining text.
+fn parse_directive(text: &str) -> Option<(&str, &str)> {
+    let text = text.strip_prefix('#')?.trim_start_matches([' ', '\t']);
+    let end = text
+        .find(|c: char| !c.is_ascii_alphabetic())
+        .unwrap_or(text.len());
+    Some(text.split_at(end))
 }
 
-impl_lexer_from_scanner!('text, CppLexer<'text>, CppToken, scanner);
+/// Returns the value of the condition in `line`, if it is lexically
+/// always false or always true, i.e. `0`, `1`, `false`, or `true`,
+/// optionally in parentheses and followed by a comment.
+fn parse_const_condition(line: &str) -> Option<bool> {
+    let line = line.trim();
+    let (line, close) = match line.strip_prefix('(') {
+        Some(line) => (line.trim_start(), ")"),
+        None => (line, ""),
+    };
+
+    let end = line
+        .find(|c: char| !c.is_ascii_alphanumeric())
+        .unwrap_or(line.len());
+    let cond = match &line[..end] {
+        "0" | "false" => false,
+        "1" | "true" => true,
+        _ => return None,
+    };
+
+    let rest = line[end..].trim_start().strip_prefix(close)?.trim_start();
+    if rest.is_empty() || rest.starts_with("//") || rest.starts_with("/*") {
+        Some(cond)
+    } else {
+        None
+    }
+}
 
 #[cfg(test)]
 mod tests {
@@ -185,4 +434,47 @@ mod tests {
 
         assert_eq!(input, output);
     }
+
+    #[test]
+    fn test_cpp_lexer_inactive_regions() {
+        #[rustfmt::skip]
+        let cases = [
+            ("#if 0\na\n#endif", &["a\n"][..]),
+            ("#if 0\n#endif", &[]),
+            ("# if (0) // Disabled\na\n#else\nb\n#endif", &["a\n"]),
+            ("#if false\na\n#elif X\nb\n#endif", &["a\n"]),
+            ("#if 1\na\n#else\nb\n#endif", &["b\n"]),
+            ("#if 1\na\n#elif X\nb\n#else\nc\n#endif", &["b\n#else\nc\n"]),
+            ("#if X\na\n#elif 0\nb\n#endif", &["b\n"]),
+            ("#if 0\n  #if X\n  #else\n  #endif\n#else\nb\n#endif", &["  #if X\n  #else\n  #endif\n"]),
+            ("#if 0\na\n#endif\n#if 0\nb\n#endif", &["a\n", "b\n"]),
+            ("#if 1\n#if 0\na\n#endif\n#else\nb\n#endif", &["a\n", "b\n"]),
+            ("#if 0\na", &["a"]),
+            ("#if 0\r\na\r\n#endif", &["a\r\n"]),
+            ("#if X\na\n#endif", &[]),
+            ("#if 0 && X\na\n#endif", &[]),
+            ("x #if 0\na\n#endif", &[]),
+        ];
+
+        for (code, expected) in cases {
+            let lexer = CppLexer::new(code).inactive_regions(true);
+
+            let mut output = String::new();
+            let mut inactive = Vec::new();
+            for (tok, span) in lexer {
+                output.push_str(span.as_str());
+                if tok == CppToken::Inactive {
+                    inactive.push(span.as_str());
+                }
+            }
+
+            assert_eq!(output, code);
+            assert_eq!(inactive, expected, "{code:?}");
+        }
+
+        let lexer = CppLexer::new("#if 0\na\n#endif");
+        assert!(lexer
+            .map(|(tok, _span)| tok)
+            .all(|tok| tok != CppToken::Inactive));
+    }
 }
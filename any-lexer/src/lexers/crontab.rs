@@ -0,0 +1,217 @@
+use text_scanner::ext::CrontabScannerExt;
+use text_scanner::Scanner;
+
+use crate::{
+    impl_lexer_from_scanner, impl_token_kind_sets, impl_token_names, ScanToken, ScannerExt,
+    TokenSpan,
+};
+
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+pub enum CrontabToken {
+    Space,
+    LineComment,
+    /// Time and date field of the schedule, e.g. `*/15` and `mon-fri`.
+    Field,
+    /// Schedule nickname, e.g. `@reboot` and `@daily`.
+    Nickname,
+    /// Command following the schedule, i.e. the remainder of the line.
+    Command,
+    /// Name of an environment setting, e.g. `MAILTO` in `MAILTO=root`.
+    EnvName,
+    /// Assignment, i.e. `=` following an environment setting name.
+    Equals,
+    /// Value of an environment setting, i.e. the remainder of the line.
+    EnvValue,
+    /// Given a valid crontab, then this variant should never be
+    /// encountered. If is is encountered, then check if an issue has already
+    /// been submitted, otherwise please [submit an issue].
+    ///
+    /// [submit an issue]: https://github.com/vallentin/colorblast/issues
+    Unknown,
+}
+
+impl_token_names!(CrontabToken {
+    Space => "space",
+    LineComment => "line_comment",
+    Field => "field",
+    Nickname => "nickname",
+    Command => "command",
+    EnvName => "env_name",
+    Equals => "equals",
+    EnvValue => "env_value",
+    Unknown => "unknown",
+});
+
+impl_token_kind_sets!(CrontabToken {
+    /// Whitespace and comments.
+    TRIVIA => [Space, LineComment],
+    /// Schedule fields and nicknames.
+    SCHEDULE => [Field, Nickname],
+});
+
+impl ScanToken for CrontabToken {
+    fn scan_token<'text>(scanner: &mut Scanner<'text>) -> Option<(Self, TokenSpan<'text>)> {
+        let (r, _s) = scanner.skip_whitespace();
+        if !r.is_empty() {
+            return Some((Self::Space, scanner.span(r)));
+        }
+
+        // The command and environment value branches always produce
+        // a token, even if empty, so the end of the text is checked here
+        if !scanner.has_remaining_text() {
+            return None;
+        }
+
+        let text = scanner.text();
+        let line_start = text[..scanner.cursor_pos()]
+            .rfind(['\n', '\r'])
+            .map_or(0, |i| i + 1);
+        let before = &text[line_start..scanner.cursor_pos()];
+
+        if before.trim().is_empty() {
+            if let Ok((r, _s)) = scanner.scan_crontab_line_comment() {
+                return Some((Self::LineComment, scanner.span(r)));
+            } else if let Ok((r, _s)) = scanner.scan_crontab_env_name() {
+                return Some((Self::EnvName, scanner.span(r)));
+            } else if let Ok((r, _s)) = scanner.scan_crontab_nickname() {
+                return Some((Self::Nickname, scanner.span(r)));
+            }
+        } else if is_env_setting(&text[line_start..]) {
+            // Environment setting, e.g. `MAILTO=root`
+            if before.contains('=') {
+                return Some((Self::EnvValue, scan_line(scanner)));
+            } else if let Ok((r, _c)) = scanner.accept_char('=') {
+                return Some((Self::Equals, scanner.span(r)));
+            }
+        } else {
+            // Either five fields or a single nickname precede the command
+            let count = if before.trim_start().starts_with('@') {
+                1
+            } else {
+                5
+            };
+            if before.split_whitespace().count() >= count {
+                return Some((Self::Command, scan_line(scanner)));
+            }
+        }
+
+        if let Ok((r, _s)) = scanner.scan_crontab_field() {
+            return Some((Self::Field, scanner.span(r)));
+        }
+
+        let (r, _s) = scanner.skip_until(char::is_whitespace);
+        if r.is_empty() {
+            return None;
+        }
+        Some((Self::Unknown, scanner.span(r)))
+    }
+}
+
+/// Returns `true` if `line` is an environment setting, e.g. `MAILTO=root`.
+fn is_env_setting(line: &str) -> bool {
+    let mut scanner = Scanner::new(line.trim_start());
+    scanner.scan_crontab_env_name().is_ok()
+}
+
+/// Scans the remainder of the line at the cursor.
+fn scan_line<'text>(scanner: &mut Scanner<'text>) -> TokenSpan<'text> {
+    let (r, _s) = scanner.skip_until_char_any(&['\n', '\r']);
+    scanner.span(r)
+}
+
+/// Crontab lexer producing [`CrontabToken`]s.
+///
+/// **Note:** Cloning `CrontabLexer` is essentially a copy, as it just contains
+/// a `&str` and a `usize` for its `cursor`. However, `Copy` is not
+/// implemented, to avoid accidentally copying immutable `CrontabLexer`s.
+#[derive(Clone, Debug)]
+pub struct CrontabLexer<'text> {
+    scanner: Scanner<'text>,
+}
+
+impl<'text> CrontabLexer<'text> {
+    #[inline]
+    pub fn new(text: &'text str) -> Self {
+        Self {
+            scanner: Scanner::new(text),
+        }
+    }
+}
+
+impl_lexer_from_scanner!('text, CrontabLexer<'text>, CrontabToken, scanner);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_crontab_lexer_spans() {
+        // This intentionally uses Rust code as input, as it is
+        // only testing that CrontabLexer returns all characters
+        let input = include_str!("../../../text-scanner/src/ext/rust.rs");
+        let mut output = String::new();
+
+        let lexer = CrontabLexer::new(input);
+        for (_tok, span) in lexer {
+            output.push_str(span.as_str());
+        }
+
+        assert_eq!(input, output);
+    }
+
+    #[test]
+    fn test_crontab_lexer_tokens() {
+        let input = "# c\nMAILTO = root@example.com\n*/15 9-17 * * mon-fri /usr/bin/backup --quiet # x\n@reboot sleep 60\n";
+        let tokens = CrontabLexer::new(input)
+            .filter(|(tok, _span)| *tok != CrontabToken::Space)
+            .map(|(tok, span)| (tok, span.as_str()))
+            .collect::<Vec<_>>();
+
+        use CrontabToken::*;
+        #[rustfmt::skip]
+        let expected = [
+            (LineComment, "# c"),
+            (EnvName, "MAILTO"), (Equals, "="), (EnvValue, "root@example.com"),
+            (Field, "*/15"), (Field, "9-17"), (Field, "*"), (Field, "*"), (Field, "mon-fri"),
+            // `#` does not start a comment within commands
+            (Command, "/usr/bin/backup --quiet # x"),
+            (Nickname, "@reboot"), (Command, "sleep 60"),
+        ];
+
+        assert_eq!(tokens, expected);
+    }
+
+    #[test]
+    fn test_crontab_lexer_no_trailing_newline() {
+        use CrontabToken::*;
+        let cases: [(&str, &[(CrontabToken, &str)]); 4] = [
+            (
+                "0 0 * * * cmd",
+                &[
+                    (Field, "0"),
+                    (Field, "0"),
+                    (Field, "*"),
+                    (Field, "*"),
+                    (Field, "*"),
+                    (Command, "cmd"),
+                ],
+            ),
+            (
+                "MAILTO=root",
+                &[(EnvName, "MAILTO"), (Equals, "="), (EnvValue, "root")],
+            ),
+            ("MAILTO=", &[(EnvName, "MAILTO"), (Equals, "=")]),
+            ("@reboot x", &[(Nickname, "@reboot"), (Command, "x")]),
+        ];
+
+        for (input, expected) in cases {
+            // Bounded, such that a regression fails instead of hanging
+            let tokens = CrontabLexer::new(input)
+                .take(expected.len() * 2 + 1)
+                .filter(|(tok, _span)| *tok != CrontabToken::Space)
+                .map(|(tok, span)| (tok, span.as_str()))
+                .collect::<Vec<_>>();
+            assert_eq!(tokens, expected, "{input:?}");
+        }
+    }
+}
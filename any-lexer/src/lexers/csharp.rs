@@ -0,0 +1,211 @@
+use text_scanner::ext::{CSharpScannerExt, CSharpStrExt};
+use text_scanner::Scanner;
+
+use crate::{
+    impl_lexer_from_scanner, impl_token_kind_sets, impl_token_names, ScanToken, ScannerExt,
+    TokenSpan,
+};
+
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+pub enum CSharpToken {
+    Space,
+    LineComment,
+    BlockComment,
+    Ident,
+    Keyword,
+    Char,
+    /// Regular, verbatim, raw, and interpolated string literals.
+    String,
+    Int,
+    Float,
+    Delim,
+    Punct,
+    /// Given valid C# code, then this variant should never be encountered.
+    /// If is is encountered, then check if an issue has already been submitted,
+    /// otherwise please [submit an issue].
+    ///
+    /// [submit an issue]: https://github.com/vallentin/colorblast/issues
+    Unknown,
+}
+
+impl_token_names!(CSharpToken {
+    Space => "space",
+    LineComment => "line_comment",
+    BlockComment => "block_comment",
+    Ident => "ident",
+    Keyword => "keyword",
+    Char => "char",
+    String => "string",
+    Int => "int",
+    Float => "float",
+    Delim => "delim",
+    Punct => "punct",
+    Unknown => "unknown",
+});
+
+impl_token_kind_sets!(CSharpToken {
+    /// Whitespace and comments.
+    TRIVIA => [Space, LineComment, BlockComment],
+    /// Character, string, and number literals.
+    LITERALS => [Char, String, Int, Float],
+});
+
+impl ScanToken for CSharpToken {
+    fn scan_token<'text>(scanner: &mut Scanner<'text>) -> Option<(Self, TokenSpan<'text>)> {
+        let (r, _s) = scanner.skip_whitespace();
+        if !r.is_empty() {
+            return Some((Self::Space, scanner.span(r)));
+        }
+
+        if let Ok((r, _s)) = scanner.scan_csharp_line_comment() {
+            return Some((Self::LineComment, scanner.span(r)));
+        } else if let Ok((r, _s)) = scanner.scan_csharp_block_comment() {
+            return Some((Self::BlockComment, scanner.span(r)));
+        }
+
+        if let Ok((r, _s)) = scanner
+            .scan_csharp_interpolated_string_literal()
+            .or_else(|_| scanner.scan_csharp_raw_string_literal())
+            .or_else(|_| scanner.scan_csharp_verbatim_string_literal())
+            .or_else(|_| scanner.scan_csharp_string_literal())
+        {
+            return Some((Self::String, scanner.span(r)));
+        } else if let Ok((r, _s)) = scanner.scan_csharp_char_literal() {
+            return Some((Self::Char, scanner.span(r)));
+        }
+
+        if let Ok((r, ident)) = scanner.scan_csharp_identifier() {
+            // Contextual keywords, e.g. `var` and `await`, are only keywords
+            // in certain contexts, so they are lexed as identifiers
+            let tok = if ident.is_csharp_keyword() {
+                Self::Keyword
+            } else {
+                Self::Ident
+            };
+            return Some((tok, scanner.span(r)));
+        }
+
+        if let Ok((r, _s)) = scanner.scan_csharp_real_literal() {
+            return Some((Self::Float, scanner.span(r)));
+        } else if let Ok((r, _s)) = scanner.scan_csharp_int_literal() {
+            return Some((Self::Int, scanner.span(r)));
+        }
+
+        if let Ok((r, _c)) = scanner.accept_char_any(&['{', '}', '[', ']', '(', ')']) {
+            return Some((Self::Delim, scanner.span(r)));
+        }
+
+        // Reference: https://learn.microsoft.com/en-us/dotnet/csharp/language-reference/language-specification/lexical-structure#6410-operators-and-punctuators
+        let res = scanner.scan_with(|scanner| {
+            let (r, c) = scanner.next()?;
+            match c {
+                '=' => {
+                    _ = scanner.accept_char_any(&['=', '>']);
+                }
+                '+' => {
+                    _ = scanner.accept_char_any(&['+', '=']);
+                }
+                '-' => {
+                    _ = scanner.accept_char_any(&['-', '=', '>']);
+                }
+                '*' | '/' | '%' | '^' | '!' => {
+                    _ = scanner.accept_char('=');
+                }
+                '&' => {
+                    _ = scanner.accept_char_any(&['&', '=']);
+                }
+                '|' => {
+                    _ = scanner.accept_char_any(&['|', '=']);
+                }
+                '<' => {
+                    _ = scanner.accept_char('<');
+                    _ = scanner.accept_char('=');
+                }
+                '>' => {
+                    _ = scanner.accept_char('>');
+                    _ = scanner.accept_char('>');
+                    _ = scanner.accept_char('=');
+                }
+                '?' => {
+                    if scanner.accept_char('?').is_ok() {
+                        _ = scanner.accept_char('=');
+                    } else {
+                        _ = scanner.accept_char('.');
+                    }
+                }
+                ':' => {
+                    _ = scanner.accept_char(':');
+                }
+                '.' => {
+                    _ = scanner.accept_char('.');
+                }
+                ',' | ';' | '~' | '#' => {}
+                _ => return Err(scanner.ranged_text(r)),
+            }
+            Ok(())
+        });
+        if let Ok((r, _s)) = res {
+            return Some((Self::Punct, scanner.span(r)));
+        }
+
+        let (r, _c) = scanner.next().ok()?;
+        Some((Self::Unknown, scanner.span(r)))
+    }
+}
+
+/// C# lexer producing [`CSharpToken`]s.
+///
+/// **Note:** Cloning `CSharpLexer` is essentially a copy, as it just contains
+/// a `&str` and a `usize` for its `cursor`. However, `Copy` is not
+/// implemented, to avoid accidentally copying immutable `CSharpLexer`s.
+#[derive(Clone, Debug)]
+pub struct CSharpLexer<'text> {
+    scanner: Scanner<'text>,
+}
+
+impl<'text> CSharpLexer<'text> {
+    #[inline]
+    pub fn new(text: &'text str) -> Self {
+        Self {
+            scanner: Scanner::new(text),
+        }
+    }
+}
+
+impl_lexer_from_scanner!('text, CSharpLexer<'text>, CSharpToken, scanner);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_csharp_lexer_spans() {
+        // This intentionally uses Rust code as input, as it is
+        // only testing that CSharpLexer returns all characters
+        let input = include_str!("../../../text-scanner/src/ext/rust.rs");
+        let mut output = String::new();
+
+        let lexer = CSharpLexer::new(input);
+        for (_tok, span) in lexer {
+            output.push_str(span.as_str());
+        }
+
+        assert_eq!(input, output);
+    }
+
+    #[test]
+    fn test_csharp_strings() {
+        let code = r#"var json = $$"""{"name": "{{name}}"}"""; Log($"{x:N2} {(b ? "}" : "{")}");"#;
+        let strings = CSharpLexer::new(code)
+            .filter(|(tok, _span)| *tok == CSharpToken::String)
+            .map(|(_tok, span)| span.as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            strings,
+            [
+                r#"$$"""{"name": "{{name}}"}""""#,
+                r#"$"{x:N2} {(b ? "}" : "{")}""#,
+            ]
+        );
+    }
+}
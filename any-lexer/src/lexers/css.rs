@@ -1,9 +1,15 @@
 use text_scanner::{ext::CssScannerExt, Scanner};
 
-use crate::{impl_lexer_from_scanner, ScanToken, ScannerExt, TokenSpan};
+use crate::{
+    impl_lexer_from_scanner, impl_token_kind_sets, impl_token_names, ScanToken, ScannerExt,
+    TokenSpan,
+};
 
 const DELIMITERS: [char; 6] = ['{', '}', '[', ']', '(', ')'];
-const PUNCTUATIONS: [char; 12] = [',', '.', ';', ':', '-', '+', '*', '=', '#', '!', '@', '%'];
+#[rustfmt::skip]
+const PUNCTUATIONS: [char; 18] = [
+    ',', '.', ';', ':', '-', '+', '*', '=', '#', '!', '@', '%', '>', '<', '~', '/', '&', '|',
+];
 
 #[derive(PartialEq, Eq, Clone, Copy, Debug)]
 pub enum CssToken {
@@ -14,6 +20,14 @@ pub enum CssToken {
     Hash,
     String,
     Number,
+    /// Attribute selector, e.g. `[data-x="y"]`.
+    AttrSelector,
+    /// Pseudo-class in a selector, e.g. `:hover` and `:nth-child`.
+    PseudoClass,
+    /// Pseudo-element in a selector, e.g. `::before`.
+    PseudoElement,
+    /// Combinator in a selector, i.e. `>`, `+`, `~`, and `||`.
+    Combinator,
     /// Punctuation e.g. `:`, `,`.
     Punct,
     /// Delimiter e.g. `{`, `}`, `[`, and `]`.
@@ -26,6 +40,32 @@ pub enum CssToken {
     Unknown,
 }
 
+impl_token_names!(CssToken {
+    Space => "space",
+    BlockComment => "block_comment",
+    Ident => "ident",
+    AtKeyword => "at_keyword",
+    Hash => "hash",
+    String => "string",
+    Number => "number",
+    AttrSelector => "attr_selector",
+    PseudoClass => "pseudo_class",
+    PseudoElement => "pseudo_element",
+    Combinator => "combinator",
+    Punct => "punct",
+    Delim => "delim",
+    Unknown => "unknown",
+});
+
+impl_token_kind_sets!(CssToken {
+    /// Whitespace and comments.
+    TRIVIA => [Space, BlockComment],
+    /// String and number literals.
+    LITERALS => [String, Number],
+    /// Parts of selectors, which are not identifiers or hashes.
+    SELECTORS => [AttrSelector, PseudoClass, PseudoElement, Combinator],
+});
+
 impl ScanToken for CssToken {
     fn scan_token<'text>(scanner: &mut Scanner<'text>) -> Option<(Self, TokenSpan<'text>)> {
         let (r, _s) = scanner.skip_whitespace();
@@ -43,8 +83,28 @@ impl ScanToken for CssToken {
 
         if let Ok((r, _c)) = scanner.scan_css_number() {
             return Some((Self::Number, scanner.span(r)));
-        } else if let Ok((r, _c)) = scanner.scan_css_string() {
-            return Some((Self::String, scanner.span(r)));
+        } else if let Ok((r, outcome)) = scanner.scan_css_string_outcome() {
+            return Some((Self::String, scanner.span_outcome(r, &outcome)));
+        }
+
+        if let Ok((r, outcome)) = scanner.scan_css_block_comment_outcome() {
+            return Some((Self::BlockComment, scanner.span_outcome(r, &outcome)));
+        }
+
+        if matches!(scanner.peek(), Ok((_r, ':' | '[' | '>' | '+' | '~' | '|')))
+            && is_in_selector(scanner)
+        {
+            if let Ok((r, _s)) = scanner.scan_css_pseudo_element() {
+                return Some((Self::PseudoElement, scanner.span(r)));
+            } else if let Ok((r, _s)) = scanner.scan_css_pseudo_class() {
+                return Some((Self::PseudoClass, scanner.span(r)));
+            } else if let Ok((r, _s)) = scanner.scan_css_attribute_selector() {
+                return Some((Self::AttrSelector, scanner.span(r)));
+            } else if !is_an_plus_b(scanner) {
+                if let Ok((r, _s)) = scanner.scan_css_combinator() {
+                    return Some((Self::Combinator, scanner.span(r)));
+                }
+            }
         }
 
         if let Ok((r, _c)) = scanner.accept_char_any(&DELIMITERS) {
@@ -53,21 +113,84 @@ impl ScanToken for CssToken {
             return Some((Self::Punct, scanner.span(r)));
         }
 
-        if let Ok((r, _c)) = scanner.scan_css_block_comment() {
-            return Some((Self::BlockComment, scanner.span(r)));
-        }
-
         let (r, _c) = scanner.next().ok()?;
         Some((Self::Unknown, scanner.span(r)))
     }
 }
 
+/// Returns `true` if the cursor is in a selector, i.e. in the prelude of
+/// a style rule, e.g. `a:hover` in `a:hover { color: red }`, as opposed to
+/// in a declaration, e.g. `color:red`, or in the prelude of an at-rule,
+/// e.g. `@media (orientation:landscape)`.
+///
+/// The cursor is in a prelude, if a `{` follows before any `;` or `}`,
+/// where strings and comments are skipped.
+fn is_in_selector(scanner: &Scanner<'_>) -> bool {
+    let before = &scanner.text()[..scanner.cursor_pos()];
+    let start = before.rfind(['{', '}', ';']).map_or(0, |i| i + 1);
+    if before[start..].trim_start().starts_with('@') {
+        return false;
+    }
+
+    let mut scanner = scanner.clone();
+    loop {
+        scanner.skip_until_char_any(&['{', '}', ';', '"', '\'', '/']);
+        if scanner.scan_css_string().is_ok() || scanner.scan_css_block_comment().is_ok() {
+            continue;
+        }
+        match scanner.next() {
+            Ok((_r, '{')) => return true,
+            Ok((_r, '/')) => {}
+            _ => return false,
+        }
+    }
+}
+
+/// Returns `true` if the cursor is at the `+` of an `An+B` argument, e.g.
+/// `:nth-child(2n + 1)`, as opposed to a combinator, which is never
+/// followed by a digit.
+///
+/// Reference: <https://www.w3.org/TR/css-syntax-3/#anb-microsyntax>
+fn is_an_plus_b(scanner: &Scanner<'_>) -> bool {
+    let Some(after) = scanner.remaining_text().strip_prefix('+') else {
+        return false;
+    };
+    after.trim_start().starts_with(|c: char| c.is_ascii_digit())
+}
+
 /// CSS lexer producing [`CssToken`]s.
 ///
 /// **Note:** Cloning `CssLexer` is essentially a copy, as it just contains
 /// a `&str` and a `usize` for its `cursor`. However, `Copy` is not
 /// implemented, to avoid accidentally copying immutable `CssLexer`s.
 ///
+/// # Selectors
+///
+/// Attribute selectors, pseudo-classes, pseudo-elements, and combinators
+/// are only produced as [`CssToken::SELECTORS`] in selectors, i.e. if a `{`
+/// follows before any `;` or `}`. Otherwise they are produced as e.g.
+/// [`CssToken::Punct`], e.g. the `:` in `color: red`.
+///
+/// ```rust
+/// use any_lexer::{CssLexer, CssToken};
+///
+/// let css = "a:hover > [href]::after { color:red }";
+/// let tokens = CssLexer::new(css)
+///     .filter(|(tok, _span)| *tok != CssToken::Space)
+///     .map(|(tok, span)| (tok, span.as_str()))
+///     .collect::<Vec<_>>();
+///
+/// use CssToken::*;
+/// assert_eq!(
+///     tokens,
+///     [
+///         (Ident, "a"), (PseudoClass, ":hover"), (Combinator, ">"),
+///         (AttrSelector, "[href]"), (PseudoElement, "::after"),
+///         (Delim, "{"), (Ident, "color"), (Punct, ":"), (Ident, "red"), (Delim, "}"),
+///     ]
+/// );
+/// ```
+///
 /// See also [`ScssLexer`].
 ///
 /// [`ScssLexer`]: super::ScssLexer
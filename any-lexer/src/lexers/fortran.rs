@@ -0,0 +1,300 @@
+use text_scanner::ext::{FortranScannerExt, FortranStrExt};
+use text_scanner::Scanner;
+
+use crate::{
+    impl_from_str_for_lexer, impl_iter_for_lexer, impl_token_kind_sets, impl_token_names,
+    ScanToken, ScannerExt, TokenSpan,
+};
+
+/// Source form of Fortran code, affecting how comments and
+/// continuation lines are written.
+#[derive(PartialEq, Eq, Clone, Copy, Default, Debug)]
+pub enum FortranForm {
+    /// Free-form source, e.g. `.f90` files, where `!` starts a comment
+    /// and a trailing `&` continues the statement on the next line.
+    #[default]
+    Free,
+    /// Fixed-form source, e.g. `.f` and `.for` files, where `C`, `c`, `*`,
+    /// or `!` in column 1 makes the line a comment, and any character other
+    /// than a blank or `0` in column 6 makes the line a continuation line.
+    Fixed,
+}
+
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+pub enum FortranToken {
+    Space,
+    /// Line comment, e.g. `! comment`, or in fixed-form source, a comment
+    /// line, e.g. `C comment`.
+    LineComment,
+    /// Continuation mark, i.e. `&` in free-form source, or the character
+    /// in column 6 in fixed-form source.
+    Continuation,
+    Ident,
+    Keyword,
+    String,
+    /// Integer literal, e.g. `123` and `123_8`, or binary, octal, or
+    /// hexadecimal literal, e.g. `Z'FF'`.
+    Int,
+    Real,
+    /// Logical literal, i.e. `.true.` and `.false.`.
+    Logical,
+    Delim,
+    /// Operators, including dot operators, e.g. `.and.` and `.eq.`.
+    Punct,
+    /// Given valid Fortran code, then this variant should never be encountered.
+    /// If is is encountered, then check if an issue has already been submitted,
+    /// otherwise please [submit an issue].
+    ///
+    /// [submit an issue]: https://github.com/vallentin/colorblast/issues
+    Unknown,
+}
+
+impl_token_names!(FortranToken {
+    Space => "space",
+    LineComment => "line_comment",
+    Continuation => "continuation",
+    Ident => "ident",
+    Keyword => "keyword",
+    String => "string",
+    Int => "int",
+    Real => "real",
+    Logical => "logical",
+    Delim => "delim",
+    Punct => "punct",
+    Unknown => "unknown",
+});
+
+impl_token_kind_sets!(FortranToken {
+    /// Whitespace, comments, and continuation marks.
+    TRIVIA => [Space, LineComment, Continuation],
+    /// String, number, and logical literals.
+    LITERALS => [String, Int, Real, Logical],
+});
+
+impl ScanToken for FortranToken {
+    #[inline]
+    fn scan_token<'text>(scanner: &mut Scanner<'text>) -> Option<(Self, TokenSpan<'text>)> {
+        scan_token(scanner, FortranForm::Free)
+    }
+}
+
+fn scan_token<'text>(
+    scanner: &mut Scanner<'text>,
+    form: FortranForm,
+) -> Option<(FortranToken, TokenSpan<'text>)> {
+    use FortranToken as Tok;
+
+    let (r, _s) = scanner.skip_whitespace();
+    if !r.is_empty() {
+        return Some((Tok::Space, scanner.span(r)));
+    }
+
+    match form {
+        FortranForm::Free => {
+            if let Ok((r, _s)) = scanner.scan_fortran_free_continuation() {
+                return Some((Tok::Continuation, scanner.span(r)));
+            }
+        }
+        FortranForm::Fixed => {
+            if let Ok((r, _s)) = scanner.scan_fortran_fixed_comment_line() {
+                return Some((Tok::LineComment, scanner.span(r)));
+            } else if let Ok((r, _s)) = scanner.scan_fortran_fixed_continuation() {
+                return Some((Tok::Continuation, scanner.span(r)));
+            }
+        }
+    }
+
+    if let Ok((r, _s)) = scanner.scan_fortran_line_comment() {
+        return Some((Tok::LineComment, scanner.span(r)));
+    }
+
+    if let Ok((r, outcome)) = scanner.scan_fortran_string_outcome() {
+        return Some((Tok::String, scanner.span_outcome(r, &outcome)));
+    }
+
+    if let Ok((r, _s)) = scanner.scan_fortran_boz() {
+        return Some((Tok::Int, scanner.span(r)));
+    }
+
+    if let Ok((r, ident)) = scanner.scan_fortran_identifier() {
+        let tok = if ident.is_fortran_keyword() {
+            Tok::Keyword
+        } else {
+            Tok::Ident
+        };
+        return Some((tok, scanner.span(r)));
+    }
+
+    if let Ok((r, _s)) = scanner.scan_fortran_real() {
+        return Some((Tok::Real, scanner.span(r)));
+    } else if let Ok((r, _s)) = scanner.scan_fortran_int() {
+        return Some((Tok::Int, scanner.span(r)));
+    }
+
+    if let Ok((r, op)) = scanner.scan_fortran_dot_operator() {
+        let tok = if op.eq_ignore_ascii_case(".true.") || op.eq_ignore_ascii_case(".false.") {
+            Tok::Logical
+        } else {
+            Tok::Punct
+        };
+        return Some((tok, scanner.span(r)));
+    }
+
+    if let Ok((r, _s)) = scanner.scan_fortran_delimiter() {
+        return Some((Tok::Delim, scanner.span(r)));
+    } else if let Ok((r, _s)) = scanner.scan_fortran_operator() {
+        return Some((Tok::Punct, scanner.span(r)));
+    }
+
+    let (r, _c) = scanner.next().ok()?;
+    Some((Tok::Unknown, scanner.span(r)))
+}
+
+/// Fortran lexer producing [`FortranToken`]s.
+///
+/// **Note:** Cloning `FortranLexer` is essentially a copy, as it just contains
+/// a `&str`, a `usize` for its `cursor`, and its source `form`. However, `Copy`
+/// is not implemented, to avoid accidentally copying immutable `FortranLexer`s.
+#[derive(Clone, Debug)]
+pub struct FortranLexer<'text> {
+    scanner: Scanner<'text>,
+    form: FortranForm,
+}
+
+impl<'text> FortranLexer<'text> {
+    #[inline]
+    pub fn new(text: &'text str) -> Self {
+        Self {
+            scanner: Scanner::new(text),
+            form: FortranForm::Free,
+        }
+    }
+
+    /// Sets the [`FortranForm`], which defaults to [`FortranForm::Free`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use any_lexer::{FortranForm, FortranLexer, FortranToken};
+    ///
+    /// let code = "C comment\n      X = 1 +\n     &    2\n";
+    /// let tokens = FortranLexer::new(code)
+    ///     .form(FortranForm::Fixed)
+    ///     .filter(|(tok, _span)| *tok != FortranToken::Space)
+    ///     .map(|(tok, span)| (tok, span.as_str()))
+    ///     .collect::<Vec<_>>();
+    ///
+    /// use FortranToken::*;
+    /// assert_eq!(
+    ///     tokens,
+    ///     [
+    ///         (LineComment, "C comment"),
+    ///         (Ident, "X"), (Punct, "="), (Int, "1"), (Punct, "+"),
+    ///         (Continuation, "&"), (Int, "2"),
+    ///     ]
+    /// );
+    /// ```
+    #[inline]
+    pub fn form(mut self, form: FortranForm) -> Self {
+        self.form = form;
+        self
+    }
+}
+
+impl<'text> crate::Lexer<'text> for FortranLexer<'text> {
+    type Token = FortranToken;
+
+    #[inline]
+    fn next_token(&mut self) -> Option<(Self::Token, TokenSpan<'text>)> {
+        scan_token(&mut self.scanner, self.form)
+    }
+
+    #[inline]
+    fn peek_token(&self) -> Option<(Self::Token, TokenSpan<'text>)> {
+        self.scanner
+            .peeking(|scanner| scan_token(scanner, self.form))
+    }
+
+    #[inline]
+    fn scanner(&self) -> &Scanner<'text> {
+        &self.scanner
+    }
+
+    #[inline]
+    fn scanner_mut(&mut self) -> &mut Scanner<'text> {
+        &mut self.scanner
+    }
+}
+
+impl_iter_for_lexer!('text, FortranLexer<'text>);
+impl_from_str_for_lexer!('text, FortranLexer<'text>);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fortran_lexer_spans() {
+        // This intentionally uses Rust code as input, as it is
+        // only testing that FortranLexer returns all characters
+        let input = include_str!("../../../text-scanner/src/ext/rust.rs");
+
+        for form in [FortranForm::Free, FortranForm::Fixed] {
+            let mut output = String::new();
+
+            let lexer = FortranLexer::new(input).form(form);
+            for (_tok, span) in lexer {
+                output.push_str(span.as_str());
+            }
+
+            assert_eq!(input, output, "{form:?}");
+        }
+    }
+
+    #[test]
+    fn test_fortran_lexer_form() {
+        let input = "c = 1 &\n     & .and. x ! y\n";
+
+        use FortranToken::*;
+        #[rustfmt::skip]
+        let cases = [
+            (FortranForm::Free, &[
+                (Ident, "c"), (Punct, "="), (Int, "1"), (Continuation, "&"),
+                (Continuation, "&"), (Punct, ".and."), (Ident, "x"), (LineComment, "! y"),
+            ][..]),
+            (FortranForm::Fixed, &[
+                (LineComment, "c = 1 &"),
+                (Continuation, "&"), (Punct, ".and."), (Ident, "x"), (LineComment, "! y"),
+            ]),
+        ];
+
+        for (form, expected) in cases {
+            let tokens = FortranLexer::new(input)
+                .form(form)
+                .filter(|(tok, _span)| *tok != Space)
+                .map(|(tok, span)| (tok, span.as_str()))
+                .collect::<Vec<_>>();
+            assert_eq!(tokens, expected, "{form:?}");
+        }
+    }
+
+    #[test]
+    fn test_fortran_lexer_literals() {
+        let input = "x = 1.5d0 + 2_8 + Z'FF' + .5 .EQ. 1.e3 .or. .TRUE. // 'it''s'";
+
+        use FortranToken::*;
+        #[rustfmt::skip]
+        let expected = [
+            (Ident, "x"), (Punct, "="), (Real, "1.5d0"), (Punct, "+"), (Int, "2_8"),
+            (Punct, "+"), (Int, "Z'FF'"), (Punct, "+"), (Real, ".5"), (Punct, ".EQ."),
+            (Real, "1.e3"), (Punct, ".or."), (Logical, ".TRUE."), (Punct, "//"),
+            (String, "'it''s'"),
+        ];
+
+        let tokens = FortranLexer::new(input)
+            .filter(|(tok, _span)| *tok != Space)
+            .map(|(tok, span)| (tok, span.as_str()))
+            .collect::<Vec<_>>();
+        assert_eq!(tokens, expected);
+    }
+}
@@ -0,0 +1,320 @@
+use text_scanner::{ext::HtmlScannerExt, Scanner};
+
+use crate::{
+    impl_from_str_for_lexer, impl_iter_for_lexer, impl_token_kind_sets, impl_token_names,
+    ScanToken, ScannerExt, TokenSpan,
+};
+
+/// Elements whose contents are not parsed as HTML, and instead
+/// produced as a single [`HtmlToken::RawText`].
+const RAW_TEXT_ELEMENTS: &[&str] = &["script", "style", "textarea", "title"];
+
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+pub enum HtmlToken {
+    Space,
+    /// Comment, e.g. `<!-- ... -->`.
+    Comment,
+    /// Markup declaration, e.g. `<!DOCTYPE html>`.
+    Doctype,
+    /// CDATA section, e.g. `<![CDATA[ x > y ]]>`.
+    Cdata,
+    /// Tag name, e.g. `div` in `<div>` and `</div>`.
+    TagName,
+    AttrName,
+    /// Quoted or unquoted attribute value, i.e. including any quotes.
+    AttrValue,
+    /// Text content, which might contain whitespace.
+    Text,
+    /// Character reference in text content, e.g. `&amp;` and `&#x27;`.
+    Entity,
+    /// Contents of a raw text element, e.g. `<script>` and `<style>`.
+    RawText,
+    /// Punctuation e.g. `<`, `</`, `>`, `/>`, and `=`.
+    Punct,
+    /// Given valid HTML, then this variant should never be encountered. If
+    /// is is encountered, then check if an issue has already been submitted,
+    /// otherwise please [submit an issue].
+    ///
+    /// [submit an issue]: https://github.com/vallentin/colorblast/issues
+    Unknown,
+}
+
+impl_token_names!(HtmlToken {
+    Space => "space",
+    Comment => "comment",
+    Doctype => "doctype",
+    Cdata => "cdata",
+    TagName => "tag_name",
+    AttrName => "attr_name",
+    AttrValue => "attr_value",
+    Text => "text",
+    Entity => "entity",
+    RawText => "raw_text",
+    Punct => "punct",
+    Unknown => "unknown",
+});
+
+impl_token_kind_sets!(HtmlToken {
+    /// Whitespace and comments.
+    TRIVIA => [Space, Comment],
+});
+
+impl ScanToken for HtmlToken {
+    /// Scans a token outside of tags.
+    ///
+    /// **Note:** HTML tokenization depends on whether the scanner is inside
+    /// a tag, so use [`HtmlLexer`] to tokenize complete HTML documents.
+    fn scan_token<'text>(scanner: &mut Scanner<'text>) -> Option<(Self, TokenSpan<'text>)> {
+        let mut state = State::Text;
+        scan_token(scanner, &mut state)
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+enum State {
+    Text,
+    /// After `<` or `</`, i.e. expecting a tag name.
+    TagName {
+        end_tag: bool,
+    },
+    /// Inside a tag, where `raw_text` is the name of the element if it is
+    /// a raw text element.
+    Tag {
+        raw_text: Option<&'static str>,
+    },
+    /// After `=` inside a tag, i.e. expecting an attribute value.
+    AttrValue {
+        raw_text: Option<&'static str>,
+    },
+    /// After the start tag of a raw text element.
+    RawText(&'static str),
+}
+
+fn scan_token<'text>(
+    scanner: &mut Scanner<'text>,
+    state: &mut State,
+) -> Option<(HtmlToken, TokenSpan<'text>)> {
+    match *state {
+        State::Text => {
+            let (r, _s) = scanner.skip_whitespace();
+            if !r.is_empty() {
+                return Some((HtmlToken::Space, scanner.span(r)));
+            }
+
+            if let Ok((r, _s)) = scanner.scan_html_comment() {
+                return Some((HtmlToken::Comment, scanner.span(r)));
+            } else if let Ok((r, _s)) = scanner.scan_html_cdata() {
+                return Some((HtmlToken::Cdata, scanner.span(r)));
+            } else if let Ok((r, _s)) = scanner.scan_html_doctype() {
+                return Some((HtmlToken::Doctype, scanner.span(r)));
+            } else if let Ok((r, _s)) = scanner.scan_html_entity() {
+                return Some((HtmlToken::Entity, scanner.span(r)));
+            }
+
+            let is_tag_start = |s: &str| {
+                let s = s.strip_prefix('/').unwrap_or(s);
+                s.starts_with(|c: char| c.is_alphabetic())
+            };
+
+            if let Some(after) = scanner.remaining_text().strip_prefix('<') {
+                if is_tag_start(after) {
+                    let end_tag = after.starts_with('/');
+                    let (r, _s) = scanner.next_str(if end_tag { 2 } else { 1 }).ok()?;
+                    *state = State::TagName { end_tag };
+                    return Some((HtmlToken::Punct, scanner.span(r)));
+                }
+            }
+
+            // Text continues until the next tag, comment, declaration, or
+            // character reference, where a lone `<` or `&` is part of the text
+            let start = scanner.cursor_pos();
+            scanner.next().ok()?;
+            loop {
+                scanner.skip_until_char_any(&['<', '&']);
+                let rest = scanner.remaining_text();
+                let after = rest.get(1..).unwrap_or_default();
+                if rest.is_empty()
+                    || (rest.starts_with('<') && (is_tag_start(after) || after.starts_with('!')))
+                    || (rest.starts_with('&') && scanner.clone().scan_html_entity().is_ok())
+                {
+                    break;
+                }
+                _ = scanner.next();
+            }
+
+            let r = start..scanner.cursor_pos();
+            Some((HtmlToken::Text, scanner.span(r)))
+        }
+        State::TagName { end_tag } => {
+            let (r, s) = scanner.scan_html_tag_name().ok().unwrap_or_default();
+
+            let raw_text = RAW_TEXT_ELEMENTS
+                .iter()
+                .find(|name| !end_tag && name.eq_ignore_ascii_case(s))
+                .copied();
+            *state = State::Tag { raw_text };
+
+            if r.is_empty() {
+                return scan_token(scanner, state);
+            }
+            Some((HtmlToken::TagName, scanner.span(r)))
+        }
+        State::Tag { raw_text } => {
+            let (r, _s) = scanner.skip_whitespace();
+            if !r.is_empty() {
+                return Some((HtmlToken::Space, scanner.span(r)));
+            }
+
+            if let Ok((r, _s)) = scanner.accept_str("/>") {
+                *state = State::Text;
+                return Some((HtmlToken::Punct, scanner.span(r)));
+            } else if let Ok((r, _c)) = scanner.accept_char('>') {
+                *state = match raw_text {
+                    Some(name) => State::RawText(name),
+                    None => State::Text,
+                };
+                return Some((HtmlToken::Punct, scanner.span(r)));
+            } else if let Ok((r, _c)) = scanner.accept_char('=') {
+                *state = State::AttrValue { raw_text };
+                return Some((HtmlToken::Punct, scanner.span(r)));
+            }
+
+            if let Ok((r, _s)) = scanner.scan_html_attr_name() {
+                return Some((HtmlToken::AttrName, scanner.span(r)));
+            } else if let Ok((r, _s)) = scanner.scan_html_attr_value() {
+                return Some((HtmlToken::AttrValue, scanner.span(r)));
+            }
+
+            let (r, _c) = scanner.next().ok()?;
+            Some((HtmlToken::Unknown, scanner.span(r)))
+        }
+        State::AttrValue { raw_text } => {
+            let (r, _s) = scanner.skip_whitespace();
+            if !r.is_empty() {
+                return Some((HtmlToken::Space, scanner.span(r)));
+            }
+
+            *state = State::Tag { raw_text };
+
+            if let Ok((r, _s)) = scanner.scan_html_attr_value() {
+                return Some((HtmlToken::AttrValue, scanner.span(r)));
+            }
+            scan_token(scanner, state)
+        }
+        State::RawText(name) => {
+            *state = State::Text;
+
+            if let Ok((r, _s)) = scanner.scan_html_raw_text(name) {
+                return Some((HtmlToken::RawText, scanner.span(r)));
+            }
+            scan_token(scanner, state)
+        }
+    }
+}
+
+/// HTML lexer producing [`HtmlToken`]s.
+///
+/// The contents of raw text elements, e.g. `<script>` and `<style>`,
+/// are produced as a single [`HtmlToken::RawText`], such that they can be
+/// tokenized by another lexer.
+///
+/// **Note:** As opposed to most other lexers, `HtmlLexer` keeps track of
+/// whether it is inside a tag. So [`set_cursor_pos()`] should only be used
+/// to restore a previous position of the same state.
+///
+/// Cloning `HtmlLexer` is essentially a copy, as it just contains a `&str`,
+/// a `usize` for its `cursor`, and its state. However, `Copy` is not
+/// implemented, to avoid accidentally copying immutable `HtmlLexer`s.
+///
+/// [`set_cursor_pos()`]: crate::Lexer::set_cursor_pos
+#[derive(Clone, Debug)]
+pub struct HtmlLexer<'text> {
+    scanner: Scanner<'text>,
+    state: State,
+}
+
+impl<'text> HtmlLexer<'text> {
+    #[inline]
+    pub fn new(text: &'text str) -> Self {
+        Self {
+            scanner: Scanner::new(text),
+            state: State::Text,
+        }
+    }
+}
+
+impl<'text> crate::Lexer<'text> for HtmlLexer<'text> {
+    type Token = HtmlToken;
+
+    #[inline]
+    fn next_token(&mut self) -> Option<(Self::Token, TokenSpan<'text>)> {
+        scan_token(&mut self.scanner, &mut self.state)
+    }
+
+    #[inline]
+    fn peek_token(&self) -> Option<(Self::Token, TokenSpan<'text>)> {
+        self.clone().next_token()
+    }
+
+    #[inline]
+    fn reset(&mut self) -> usize {
+        self.state = State::Text;
+        self.set_cursor_pos(0)
+    }
+
+    #[inline]
+    fn scanner(&self) -> &Scanner<'text> {
+        &self.scanner
+    }
+
+    #[inline]
+    fn scanner_mut(&mut self) -> &mut Scanner<'text> {
+        &mut self.scanner
+    }
+}
+
+impl_iter_for_lexer!('text, HtmlLexer<'text>);
+impl_from_str_for_lexer!('text, HtmlLexer<'text>);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_html_lexer_spans() {
+        // This intentionally uses Rust code as input, as it is
+        // only testing that HtmlLexer returns all characters
+        let input = include_str!("../../../text-scanner/src/ext/rust.rs");
+        let mut output = String::new();
+
+        let lexer = HtmlLexer::new(input);
+        for (_tok, span) in lexer {
+            output.push_str(span.as_str());
+        }
+
+        assert_eq!(input, output);
+    }
+
+    #[test]
+    fn test_html_lexer_tokens() {
+        let input = r#"<!DOCTYPE html><p v-if="a > b">x < y &amp; z&</p><script>a</b></script><![CDATA[>]]>"#;
+        let tokens = HtmlLexer::new(input)
+            .map(|(tok, span)| (tok, span.as_str()))
+            .collect::<Vec<_>>();
+
+        use HtmlToken::*;
+        #[rustfmt::skip]
+        let expected = [
+            (Doctype, "<!DOCTYPE html>"),
+            (Punct, "<"), (TagName, "p"), (Space, " "), (AttrName, "v-if"), (Punct, "="), (AttrValue, "\"a > b\""), (Punct, ">"),
+            (Text, "x < y "), (Entity, "&amp;"), (Space, " "), (Text, "z&"),
+            (Punct, "</"), (TagName, "p"), (Punct, ">"),
+            (Punct, "<"), (TagName, "script"), (Punct, ">"),
+            (RawText, "a</b>"),
+            (Punct, "</"), (TagName, "script"), (Punct, ">"),
+            (Cdata, "<![CDATA[>]]>"),
+        ];
+
+        assert_eq!(tokens, expected);
+    }
+}
@@ -0,0 +1,174 @@
+use text_scanner::ext::{JavaScannerExt, JavaStrExt};
+use text_scanner::Scanner;
+
+use crate::{
+    impl_lexer_from_scanner, impl_token_kind_sets, impl_token_names, ScanToken, ScannerExt,
+    TokenSpan,
+};
+
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+pub enum JavaToken {
+    Space,
+    LineComment,
+    BlockComment,
+    Ident,
+    Keyword,
+    Null,
+    Boolean,
+    Char,
+    /// String literals and text blocks.
+    String,
+    Int,
+    Float,
+    Delim,
+    Punct,
+    /// Given valid Java code, then this variant should never be encountered.
+    /// If is is encountered, then check if an issue has already been submitted,
+    /// otherwise please [submit an issue].
+    ///
+    /// [submit an issue]: https://github.com/vallentin/colorblast/issues
+    Unknown,
+}
+
+impl_token_names!(JavaToken {
+    Space => "space",
+    LineComment => "line_comment",
+    BlockComment => "block_comment",
+    Ident => "ident",
+    Keyword => "keyword",
+    Null => "null",
+    Boolean => "boolean",
+    Char => "char",
+    String => "string",
+    Int => "int",
+    Float => "float",
+    Delim => "delim",
+    Punct => "punct",
+    Unknown => "unknown",
+});
+
+impl_token_kind_sets!(JavaToken {
+    /// Whitespace and comments.
+    TRIVIA => [Space, LineComment, BlockComment],
+    /// `null`, boolean, character, string, and number literals.
+    LITERALS => [Null, Boolean, Char, String, Int, Float],
+});
+
+impl ScanToken for JavaToken {
+    fn scan_token<'text>(scanner: &mut Scanner<'text>) -> Option<(Self, TokenSpan<'text>)> {
+        let (r, _s) = scanner.skip_whitespace();
+        if !r.is_empty() {
+            return Some((Self::Space, scanner.span(r)));
+        }
+
+        if let Ok((r, _s)) = scanner.scan_java_line_comment() {
+            return Some((Self::LineComment, scanner.span(r)));
+        } else if let Ok((r, _s)) = scanner.scan_java_block_comment() {
+            return Some((Self::BlockComment, scanner.span(r)));
+        }
+
+        if let Ok((r, ident)) = scanner.scan_java_identifier() {
+            // Contextual keywords, e.g. `var` and `record`, are only keywords
+            // in certain contexts, so they are lexed as identifiers
+            let tok = match ident {
+                _ if ident.is_java_null_literal() => Self::Null,
+                _ if ident.is_java_boolean_literal() => Self::Boolean,
+                _ if ident.is_java_reserved_keyword() => Self::Keyword,
+                _ => Self::Ident,
+            };
+            return Some((tok, scanner.span(r)));
+        }
+
+        if let Ok((r, _s)) = scanner.scan_java_char_literal() {
+            return Some((Self::Char, scanner.span(r)));
+        } else if let Ok((r, _s)) = scanner
+            .scan_java_text_block()
+            .or_else(|_| scanner.scan_java_string_literal())
+        {
+            return Some((Self::String, scanner.span(r)));
+        }
+
+        if let Ok((r, _s)) = scanner.scan_java_float_literal() {
+            return Some((Self::Float, scanner.span(r)));
+        } else if let Ok((r, _s)) = scanner
+            .scan_java_int_hex_literal()
+            .or_else(|_| scanner.scan_java_int_bin_literal())
+            .or_else(|_| scanner.scan_java_int_oct_literal())
+            .or_else(|_| scanner.scan_java_int_dec_literal())
+        {
+            return Some((Self::Int, scanner.span(r)));
+        }
+
+        if let Ok((r, _c)) = scanner.accept_char_any(&['{', '}', '[', ']', '(', ')']) {
+            return Some((Self::Delim, scanner.span(r)));
+        } else if let Ok((r, _s)) = scanner
+            .scan_java_separator()
+            .or_else(|_| scanner.scan_java_operator())
+        {
+            return Some((Self::Punct, scanner.span(r)));
+        }
+
+        let (r, _c) = scanner.next().ok()?;
+        Some((Self::Unknown, scanner.span(r)))
+    }
+}
+
+/// Java lexer producing [`JavaToken`]s.
+///
+/// **Note:** Cloning `JavaLexer` is essentially a copy, as it just contains
+/// a `&str` and a `usize` for its `cursor`. However, `Copy` is not
+/// implemented, to avoid accidentally copying immutable `JavaLexer`s.
+#[derive(Clone, Debug)]
+pub struct JavaLexer<'text> {
+    scanner: Scanner<'text>,
+}
+
+impl<'text> JavaLexer<'text> {
+    #[inline]
+    pub fn new(text: &'text str) -> Self {
+        Self {
+            scanner: Scanner::new(text),
+        }
+    }
+}
+
+impl_lexer_from_scanner!('text, JavaLexer<'text>, JavaToken, scanner);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_java_lexer_spans() {
+        // This intentionally uses Rust code as input, as it is
+        // only testing that JavaLexer returns all characters
+        let input = include_str!("../../../text-scanner/src/ext/rust.rs");
+        let mut output = String::new();
+
+        let lexer = JavaLexer::new(input);
+        for (_tok, span) in lexer {
+            output.push_str(span.as_str());
+        }
+
+        assert_eq!(input, output);
+    }
+
+    #[test]
+    fn test_java_text_block() {
+        let code = "String s = \"\"\"\n    Hello \"World\"\n    \"\"\";";
+        let tokens = JavaLexer::new(code)
+            .filter(|(tok, _span)| *tok != JavaToken::Space)
+            .map(|(tok, span)| (tok, span.as_str()))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            tokens,
+            [
+                (JavaToken::Ident, "String"),
+                (JavaToken::Ident, "s"),
+                (JavaToken::Punct, "="),
+                (JavaToken::String, "\"\"\"\n    Hello \"World\"\n    \"\"\""),
+                (JavaToken::Punct, ";"),
+            ]
+        );
+    }
+}
@@ -0,0 +1,543 @@
+use text_scanner::ext::{JavaScriptScannerExt, JavaScriptStrExt};
+use text_scanner::Scanner;
+
+use crate::{
+    impl_from_str_for_lexer, impl_iter_for_lexer, impl_token_kind_sets, impl_token_names,
+    ScanToken, ScannerExt, TokenSpan,
+};
+
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+pub enum JavaScriptToken {
+    Space,
+    LineComment,
+    BlockComment,
+    Ident,
+    /// Keyword, including contextual keywords, e.g. `async` and `of`,
+    /// and TypeScript keywords if enabled.
+    ///
+    /// **Note:** Keywords used as property names, e.g. `x.default`,
+    /// are produced as [`JavaScriptToken::Ident`].
+    Keyword,
+    /// Decorator name, e.g. `@Component` and `@foo.bar` in `@foo.bar(x)`.
+    Decorator,
+    Number,
+    /// Single-quoted or double-quoted string, including JSX attribute values.
+    String,
+    /// Template literal, e.g. `` `Hello ${name}` ``.
+    TemplateString,
+    /// Regular expression literal, e.g. `/[a-z]+/gi`.
+    Regex,
+    Delim,
+    Punct,
+    /// JSX element name, e.g. `div` in `<div>` and `</div>`,
+    /// and `Foo.Bar` in `<Foo.Bar />`.
+    JsxTagName,
+    /// JSX attribute name, e.g. `className` in `<div className="x">`.
+    JsxAttrName,
+    /// JSX text content, which might contain whitespace.
+    JsxText,
+    /// JSX punctuation e.g. `<`, `</`, `>`, and `/>`, including
+    /// fragments, i.e. `<>` and `</>`, as well as `=` in attributes.
+    JsxPunct,
+    /// Braces around embedded expressions in JSX, i.e. `{` and `}`.
+    JsxBrace,
+    /// Given valid JavaScript code, then this variant should never be
+    /// encountered. If is is encountered, then check if an issue has already
+    /// been submitted, otherwise please [submit an issue].
+    ///
+    /// [submit an issue]: https://github.com/vallentin/colorblast/issues
+    Unknown,
+}
+
+impl_token_names!(JavaScriptToken {
+    Space => "space",
+    LineComment => "line_comment",
+    BlockComment => "block_comment",
+    Ident => "ident",
+    Keyword => "keyword",
+    Decorator => "decorator",
+    Number => "number",
+    String => "string",
+    TemplateString => "template_string",
+    Regex => "regex",
+    Delim => "delim",
+    Punct => "punct",
+    JsxTagName => "jsx_tag_name",
+    JsxAttrName => "jsx_attr_name",
+    JsxText => "jsx_text",
+    JsxPunct => "jsx_punct",
+    JsxBrace => "jsx_brace",
+    Unknown => "unknown",
+});
+
+impl_token_kind_sets!(JavaScriptToken {
+    /// Whitespace and comments.
+    TRIVIA => [Space, LineComment, BlockComment],
+    /// Number, string, template string, and regex literals.
+    LITERALS => [Number, String, TemplateString, Regex],
+});
+
+impl ScanToken for JavaScriptToken {
+    /// Scans a JavaScript token, where an expression is expected.
+    ///
+    /// **Note:** Whether `/` and `<` start a regular expression or a JSX
+    /// element depends on the previous tokens, so use [`JavaScriptLexer`]
+    /// to tokenize complete JavaScript code.
+    fn scan_token<'text>(scanner: &mut Scanner<'text>) -> Option<(Self, TokenSpan<'text>)> {
+        let mut state = State::new(Options::DEFAULT);
+        state.scan_token(scanner)
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+struct Options {
+    jsx: bool,
+    typescript: bool,
+}
+
+impl Options {
+    const DEFAULT: Self = Self {
+        jsx: true,
+        typescript: false,
+    };
+}
+
+#[derive(Clone, Copy, Debug)]
+enum Mode {
+    /// JavaScript code, where `braces` is the number of unclosed `{`.
+    Js { braces: usize },
+    /// Inside a JSX tag, where `name` is whether the tag name is expected.
+    JsxTag { closing: bool, name: bool },
+    /// Children of a JSX element.
+    JsxChildren,
+}
+
+#[derive(Clone, Debug)]
+struct State {
+    options: Options,
+    /// The current mode.
+    mode: Mode,
+    /// Stack of enclosing modes, where the bottom is always [`Mode::Js`].
+    /// This is only non-empty inside JSX, such that JavaScript outside
+    /// of JSX is tokenized without allocating.
+    outer: Vec<Mode>,
+    /// Whether an expression is expected, i.e. whether `/` starts a regular
+    /// expression and `<` starts a JSX element.
+    expr_allowed: bool,
+    /// Whether the previous token was `.` or `?.`.
+    after_dot: bool,
+}
+
+impl State {
+    fn new(options: Options) -> Self {
+        Self {
+            options,
+            mode: Mode::Js { braces: 0 },
+            outer: Vec::new(),
+            expr_allowed: true,
+            after_dot: false,
+        }
+    }
+
+    #[inline]
+    fn mode(&self) -> Mode {
+        self.mode
+    }
+
+    #[inline]
+    fn set_mode(&mut self, mode: Mode) {
+        self.mode = mode;
+    }
+
+    #[inline]
+    fn push_mode(&mut self, mode: Mode) {
+        self.outer.push(std::mem::replace(&mut self.mode, mode));
+    }
+
+    /// Pops the current mode, unless it is the bottom [`Mode::Js`].
+    #[inline]
+    fn pop_mode(&mut self) {
+        if let Some(mode) = self.outer.pop() {
+            self.mode = mode;
+        }
+    }
+
+    fn scan_token<'text>(
+        &mut self,
+        scanner: &mut Scanner<'text>,
+    ) -> Option<(JavaScriptToken, TokenSpan<'text>)> {
+        match self.mode() {
+            Mode::Js { braces } => self.scan_js_token(scanner, braces),
+            Mode::JsxTag { closing, name } => self.scan_jsx_tag_token(scanner, closing, name),
+            Mode::JsxChildren => self.scan_jsx_children_token(scanner),
+        }
+    }
+
+    fn scan_js_token<'text>(
+        &mut self,
+        scanner: &mut Scanner<'text>,
+        braces: usize,
+    ) -> Option<(JavaScriptToken, TokenSpan<'text>)> {
+        use JavaScriptToken as Tok;
+
+        let (r, _s) = scanner.skip_whitespace();
+        if !r.is_empty() {
+            return Some((Tok::Space, scanner.span(r)));
+        }
+
+        if let Ok((r, _s)) = scanner.scan_javascript_line_comment() {
+            return Some((Tok::LineComment, scanner.span(r)));
+        } else if let Ok((r, outcome)) = scanner.scan_javascript_block_comment_outcome() {
+            return Some((Tok::BlockComment, scanner.span_outcome(r, &outcome)));
+        }
+
+        let after_dot = std::mem::take(&mut self.after_dot);
+
+        if let Ok((r, ident)) = scanner.scan_javascript_identifier() {
+            let is_keyword = !after_dot
+                && (ident.is_javascript_keyword()
+                    || ident.is_javascript_contextual_keyword()
+                    || (self.options.typescript && ident.is_typescript_keyword()));
+            self.expr_allowed = is_keyword && ident.is_javascript_keyword_before_expr();
+            let tok = if is_keyword { Tok::Keyword } else { Tok::Ident };
+            return Some((tok, scanner.span(r)));
+        }
+
+        if let Ok((r, _s)) = scanner.scan_javascript_decorator() {
+            self.expr_allowed = false;
+            return Some((Tok::Decorator, scanner.span(r)));
+        }
+
+        if let Ok((r, _s)) = scanner.scan_javascript_number() {
+            self.expr_allowed = false;
+            return Some((Tok::Number, scanner.span(r)));
+        } else if let Ok((r, outcome)) = scanner.scan_javascript_string_outcome() {
+            self.expr_allowed = false;
+            return Some((Tok::String, scanner.span_outcome(r, &outcome)));
+        } else if let Ok((r, outcome)) = scanner.scan_javascript_template_string_outcome() {
+            self.expr_allowed = false;
+            return Some((Tok::TemplateString, scanner.span_outcome(r, &outcome)));
+        }
+
+        if self.expr_allowed {
+            if let Ok((r, _s)) = scanner.scan_javascript_regex() {
+                self.expr_allowed = false;
+                return Some((Tok::Regex, scanner.span(r)));
+            }
+
+            if self.options.jsx && self.is_jsx_start(scanner.remaining_text()) {
+                let (r, _c) = scanner.accept_char('<').ok()?;
+                self.push_mode(Mode::JsxTag {
+                    closing: false,
+                    name: true,
+                });
+                return Some((Tok::JsxPunct, scanner.span(r)));
+            }
+        }
+
+        if let Ok((r, delim)) = scanner.scan_javascript_delimiter() {
+            let mut tok = Tok::Delim;
+            match delim {
+                "{" => self.set_mode(Mode::Js { braces: braces + 1 }),
+                "}" if braces > 0 => self.set_mode(Mode::Js { braces: braces - 1 }),
+                "}" if !self.outer.is_empty() => {
+                    // End of an embedded expression in JSX
+                    self.pop_mode();
+                    tok = Tok::JsxBrace;
+                }
+                _ => {}
+            }
+            self.expr_allowed = matches!(delim, "(" | "[" | "{");
+            return Some((tok, scanner.span(r)));
+        } else if let Ok((r, punct)) = scanner.scan_javascript_operator() {
+            self.after_dot = matches!(punct, "." | "?.");
+            self.expr_allowed = !matches!(punct, "++" | "--");
+            return Some((Tok::Punct, scanner.span(r)));
+        }
+
+        let (r, _c) = scanner.next().ok()?;
+        Some((Tok::Unknown, scanner.span(r)))
+    }
+
+    fn scan_jsx_tag_token<'text>(
+        &mut self,
+        scanner: &mut Scanner<'text>,
+        closing: bool,
+        name: bool,
+    ) -> Option<(JavaScriptToken, TokenSpan<'text>)> {
+        use JavaScriptToken as Tok;
+
+        let (r, _s) = scanner.skip_whitespace();
+        if !r.is_empty() {
+            return Some((Tok::Space, scanner.span(r)));
+        }
+
+        if let Ok((r, _s)) = scanner.scan_javascript_line_comment() {
+            return Some((Tok::LineComment, scanner.span(r)));
+        } else if let Ok((r, outcome)) = scanner.scan_javascript_block_comment_outcome() {
+            return Some((Tok::BlockComment, scanner.span_outcome(r, &outcome)));
+        }
+
+        if let Ok((r, _s)) = scanner.accept_str("/>") {
+            self.end_jsx_element();
+            return Some((Tok::JsxPunct, scanner.span(r)));
+        } else if let Ok((r, _c)) = scanner.accept_char('>') {
+            if closing {
+                self.end_jsx_element();
+            } else {
+                self.set_mode(Mode::JsxChildren);
+            }
+            return Some((Tok::JsxPunct, scanner.span(r)));
+        } else if let Ok((r, _c)) = scanner.accept_char('=') {
+            return Some((Tok::JsxPunct, scanner.span(r)));
+        } else if let Ok((r, _c)) = scanner.accept_char('{') {
+            self.push_mode(Mode::Js { braces: 0 });
+            self.expr_allowed = true;
+            return Some((Tok::JsxBrace, scanner.span(r)));
+        }
+
+        if let Ok((r, _s)) = scanner.scan_jsx_identifier() {
+            let tok = if name {
+                self.set_mode(Mode::JsxTag {
+                    closing,
+                    name: false,
+                });
+                Tok::JsxTagName
+            } else {
+                Tok::JsxAttrName
+            };
+            return Some((tok, scanner.span(r)));
+        } else if let Ok((r, _s)) = scanner.scan_jsx_string() {
+            return Some((Tok::String, scanner.span(r)));
+        }
+
+        let (r, _c) = scanner.next().ok()?;
+        Some((Tok::Unknown, scanner.span(r)))
+    }
+
+    fn scan_jsx_children_token<'text>(
+        &mut self,
+        scanner: &mut Scanner<'text>,
+    ) -> Option<(JavaScriptToken, TokenSpan<'text>)> {
+        use JavaScriptToken as Tok;
+
+        if let Ok((r, _s)) = scanner.accept_str("</") {
+            self.set_mode(Mode::JsxTag {
+                closing: true,
+                name: true,
+            });
+            return Some((Tok::JsxPunct, scanner.span(r)));
+        } else if let Ok((r, _c)) = scanner.accept_char('<') {
+            self.push_mode(Mode::JsxTag {
+                closing: false,
+                name: true,
+            });
+            return Some((Tok::JsxPunct, scanner.span(r)));
+        } else if let Ok((r, _c)) = scanner.accept_char('{') {
+            self.push_mode(Mode::Js { braces: 0 });
+            self.expr_allowed = true;
+            return Some((Tok::JsxBrace, scanner.span(r)));
+        }
+
+        let (r, _s) = scanner.scan_jsx_text().ok()?;
+        Some((Tok::JsxText, scanner.span(r)))
+    }
+
+    /// Returns `true` if `<` at the start of `s` starts a JSX element,
+    /// i.e. if it is followed by a tag name or `>` for fragments.
+    fn is_jsx_start(&self, s: &str) -> bool {
+        let Some(after) = s.strip_prefix('<') else {
+            return false;
+        };
+        after.starts_with(|c: char| c.is_alphabetic() || matches!(c, '_' | '$' | '>'))
+    }
+
+    /// Pops the JSX tag of an element that has ended, i.e. after its
+    /// closing tag or `/>`.
+    fn end_jsx_element(&mut self) {
+        self.pop_mode();
+        // A JSX element is a value, e.g. `<a /> / 2`
+        self.expr_allowed = false;
+    }
+}
+
+/// JavaScript lexer producing [`JavaScriptToken`]s.
+///
+/// By default [JSX] is tokenized, i.e. element names, attribute names and
+/// values, text, and braces around embedded expressions. Use
+/// [`typescript()`] to additionally tokenize TypeScript keywords, i.e.
+/// for lexing TypeScript, where [`jsx()`] should be disabled unless
+/// lexing TSX.
+///
+/// **Note:** As opposed to most other lexers, `JavaScriptLexer` keeps track
+/// of whether it is inside JSX and of the previous token. So
+/// [`set_cursor_pos()`] should only be used to restore a previous position
+/// of the same state.
+///
+/// Cloning `JavaScriptLexer` is cheap, as it contains a `&str`, a `usize`
+/// for its `cursor`, and its state. However, inside JSX cloning allocates,
+/// as the state includes the enclosing JSX elements and expressions.
+///
+/// # Example
+///
+/// ```rust
+/// use any_lexer::{JavaScriptLexer, JavaScriptToken};
+///
+/// let tokens = JavaScriptLexer::new("<a href={url}>Hi</a>")
+///     .map(|(tok, span)| (tok, span.as_str()))
+///     .collect::<Vec<_>>();
+///
+/// use JavaScriptToken::*;
+/// assert_eq!(
+///     tokens,
+///     [
+///         (JsxPunct, "<"), (JsxTagName, "a"), (Space, " "), (JsxAttrName, "href"),
+///         (JsxPunct, "="), (JsxBrace, "{"), (Ident, "url"), (JsxBrace, "}"), (JsxPunct, ">"),
+///         (JsxText, "Hi"),
+///         (JsxPunct, "</"), (JsxTagName, "a"), (JsxPunct, ">"),
+///     ]
+/// );
+/// ```
+///
+/// [JSX]: https://facebook.github.io/jsx/
+/// [`typescript()`]: Self::typescript
+/// [`jsx()`]: Self::jsx
+/// [`set_cursor_pos()`]: crate::Lexer::set_cursor_pos
+#[derive(Clone, Debug)]
+pub struct JavaScriptLexer<'text> {
+    scanner: Scanner<'text>,
+    state: State,
+}
+
+impl<'text> JavaScriptLexer<'text> {
+    #[inline]
+    pub fn new(text: &'text str) -> Self {
+        Self {
+            scanner: Scanner::new(text),
+            state: State::new(Options::DEFAULT),
+        }
+    }
+
+    /// Sets whether [JSX] is tokenized, which is enabled by default.
+    ///
+    /// **Note:** This is intended to be called before tokenizing,
+    /// and does not affect JSX that has already been entered.
+    ///
+    /// [JSX]: https://facebook.github.io/jsx/
+    #[inline]
+    pub fn jsx(mut self, jsx: bool) -> Self {
+        self.state.options.jsx = jsx;
+        self
+    }
+
+    /// Sets whether TypeScript keywords, e.g. `interface`, `type`,
+    /// `readonly`, and `satisfies`, are produced as
+    /// [`JavaScriptToken::Keyword`], which is disabled by default.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use any_lexer::{JavaScriptLexer, JavaScriptToken};
+    ///
+    /// let code = "@sealed class A { readonly b: `on${T}` }";
+    /// let tokens = JavaScriptLexer::new(code)
+    ///     .jsx(false)
+    ///     .typescript(true)
+    ///     .filter(|(tok, _span)| *tok != JavaScriptToken::Space)
+    ///     .map(|(tok, span)| (tok, span.as_str()))
+    ///     .collect::<Vec<_>>();
+    ///
+    /// use JavaScriptToken::*;
+    /// assert_eq!(
+    ///     tokens,
+    ///     [
+    ///         (Decorator, "@sealed"), (Keyword, "class"), (Ident, "A"), (Delim, "{"),
+    ///         (Keyword, "readonly"), (Ident, "b"), (Punct, ":"),
+    ///         (TemplateString, "`on${T}`"), (Delim, "}"),
+    ///     ]
+    /// );
+    /// ```
+    #[inline]
+    pub fn typescript(mut self, typescript: bool) -> Self {
+        self.state.options.typescript = typescript;
+        self
+    }
+}
+
+impl<'text> crate::Lexer<'text> for JavaScriptLexer<'text> {
+    type Token = JavaScriptToken;
+
+    #[inline]
+    fn next_token(&mut self) -> Option<(Self::Token, TokenSpan<'text>)> {
+        self.state.scan_token(&mut self.scanner)
+    }
+
+    #[inline]
+    fn peek_token(&self) -> Option<(Self::Token, TokenSpan<'text>)> {
+        self.clone().next_token()
+    }
+
+    #[inline]
+    fn reset(&mut self) -> usize {
+        self.state = State::new(self.state.options);
+        self.set_cursor_pos(0)
+    }
+
+    #[inline]
+    fn scanner(&self) -> &Scanner<'text> {
+        &self.scanner
+    }
+
+    #[inline]
+    fn scanner_mut(&mut self) -> &mut Scanner<'text> {
+        &mut self.scanner
+    }
+}
+
+impl_iter_for_lexer!('text, JavaScriptLexer<'text>);
+impl_from_str_for_lexer!('text, JavaScriptLexer<'text>);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_javascript_lexer_spans() {
+        // This intentionally uses Rust code as input, as it is
+        // only testing that JavaScriptLexer returns all characters
+        let input = include_str!("../../../text-scanner/src/ext/rust.rs");
+        let mut output = String::new();
+
+        let lexer = JavaScriptLexer::new(input);
+        for (_tok, span) in lexer {
+            output.push_str(span.as_str());
+        }
+
+        assert_eq!(input, output);
+    }
+
+    #[test]
+    fn test_javascript_lexer_jsx() {
+        let input = r#"x = a < b / 2 ? <>{/a/.test(s) && <b.c {...p} d-e='1'/>} x</> : y.default;"#;
+        let tokens = JavaScriptLexer::new(input)
+            .map(|(tok, span)| (tok, span.as_str()))
+            .filter(|(tok, _)| *tok != JavaScriptToken::Space)
+            .collect::<Vec<_>>();
+
+        use JavaScriptToken::*;
+        #[rustfmt::skip]
+        let expected = [
+            (Ident, "x"), (Punct, "="), (Ident, "a"), (Punct, "<"), (Ident, "b"), (Punct, "/"), (Number, "2"), (Punct, "?"),
+            (JsxPunct, "<"), (JsxPunct, ">"),
+            (JsxBrace, "{"), (Regex, "/a/"), (Punct, "."), (Ident, "test"), (Delim, "("), (Ident, "s"), (Delim, ")"), (Punct, "&&"),
+            (JsxPunct, "<"), (JsxTagName, "b.c"), (JsxBrace, "{"), (Punct, "..."), (Ident, "p"), (JsxBrace, "}"),
+            (JsxAttrName, "d-e"), (JsxPunct, "="), (String, "'1'"), (JsxPunct, "/>"),
+            (JsxBrace, "}"),
+            (JsxText, " x"),
+            (JsxPunct, "</"), (JsxPunct, ">"),
+            (Punct, ":"), (Ident, "y"), (Punct, "."), (Ident, "default"), (Punct, ";"),
+        ];
+
+        assert_eq!(tokens, expected);
+    }
+}
@@ -1,6 +1,9 @@
 use text_scanner::{ext::JsonScannerExt, Scanner};
 
-use crate::{impl_lexer_from_scanner, ScanToken, ScannerExt, TokenSpan};
+use crate::{
+    impl_from_str_for_lexer, impl_iter_for_lexer, impl_token_kind_sets, impl_token_names,
+    ScanToken, ScannerExt, TokenSpan,
+};
 
 #[derive(PartialEq, Eq, Clone, Copy, Debug)]
 pub enum JsonToken {
@@ -22,6 +25,25 @@ pub enum JsonToken {
     Unknown,
 }
 
+impl_token_names!(JsonToken {
+    Space => "space",
+    String => "string",
+    Number => "number",
+    Null => "null",
+    True => "true",
+    False => "false",
+    Punct => "punct",
+    Delim => "delim",
+    Unknown => "unknown",
+});
+
+impl_token_kind_sets!(JsonToken {
+    /// Whitespace.
+    TRIVIA => [Space],
+    /// String, number, `null`, `true`, and `false` literals.
+    LITERALS => [String, Number, Null, True, False],
+});
+
 impl ScanToken for JsonToken {
     fn scan_token<'text>(scanner: &mut Scanner<'text>) -> Option<(Self, TokenSpan<'text>)> {
         let (r, _s) = scanner.skip_whitespace();
@@ -33,8 +55,8 @@ impl ScanToken for JsonToken {
             return Some((Self::Delim, scanner.span(r)));
         } else if let Ok((r, _c)) = scanner.accept_char_any(&[':', ',']) {
             return Some((Self::Punct, scanner.span(r)));
-        } else if let Ok((r, _c)) = scanner.scan_json_string() {
-            return Some((Self::String, scanner.span(r)));
+        } else if let Ok((r, outcome)) = scanner.scan_json_string_outcome() {
+            return Some((Self::String, scanner.span_outcome(r, &outcome)));
         } else if let Ok((r, _c)) = scanner.scan_json_number() {
             return Some((Self::Number, scanner.span(r)));
         }
@@ -62,14 +84,60 @@ impl ScanToken for JsonToken {
     }
 }
 
+impl JsonToken {
+    /// Same as [`scan_token()`], except that no token spans multiple
+    /// lines, see [`JsonLexer::loose()`].
+    ///
+    /// [`scan_token()`]: ScanToken::scan_token
+    fn scan_loose_token<'text>(scanner: &mut Scanner<'text>) -> Option<(Self, TokenSpan<'text>)> {
+        // Whitespace ends after a line break, such that each line
+        // starts with a new token
+        let (r, _s) = scanner.skip_while(|c| c.is_whitespace() && (c != '\n'));
+        let r = match scanner.accept_char('\n') {
+            Ok((newline, _c)) => r.start..newline.end,
+            Err(_) => r,
+        };
+        if !r.is_empty() {
+            return Some((Self::Space, scanner.span(r)));
+        }
+
+        let start = scanner.cursor_pos();
+        let (tok, span) = Self::scan_token(scanner)?;
+        match tok {
+            // Strings cannot contain line breaks, so a string continuing
+            // onto the next line is unterminated at the end of the line
+            Self::String => {
+                if let Some(i) = span.as_str().find(['\r', '\n']) {
+                    let end = start + i;
+                    scanner.set_cursor_pos(end);
+                    let span = scanner.span(start..end).with_unterminated(true);
+                    return Some((tok, span));
+                }
+            }
+            // Consecutive invalid characters, e.g. a truncated `tru`,
+            // are a single token instead of a token per character
+            Self::Unknown => {
+                let (r, _s) = scanner.skip_while(|c| {
+                    !c.is_whitespace() && !matches!(c, '{' | '}' | '[' | ']' | ':' | ',' | '"')
+                });
+                return Some((tok, scanner.span(start..r.end)));
+            }
+            _ => {}
+        }
+        Some((tok, span))
+    }
+}
+
 /// JSON lexer producing [`JsonToken`]s.
 ///
 /// **Note:** Cloning `JsonLexer` is essentially a copy, as it just contains
-/// a `&str` and a `usize` for its `cursor`. However, `Copy` is not
-/// implemented, to avoid accidentally copying immutable `JsonLexer`s.
+/// a `&str`, a `usize` for its `cursor`, and whether it is `loose`.
+/// However, `Copy` is not implemented, to avoid accidentally copying
+/// immutable `JsonLexer`s.
 #[derive(Clone, Debug)]
 pub struct JsonLexer<'text> {
     scanner: Scanner<'text>,
+    loose: bool,
 }
 
 impl<'text> JsonLexer<'text> {
@@ -77,11 +145,80 @@ impl<'text> JsonLexer<'text> {
     pub fn new(text: &'text str) -> Self {
         Self {
             scanner: Scanner::new(text),
+            loose: false,
         }
     }
+
+    /// Sets whether each line is lexed independently, which is disabled
+    /// by default. This is intended for [newline-delimited JSON] and logs,
+    /// where lines can contain truncated or otherwise invalid JSON.
+    ///
+    /// When enabled, then no token spans multiple lines. So an unterminated
+    /// string ends at the end of its line, instead of consuming all the
+    /// following lines. Additionally, consecutive invalid characters are
+    /// produced as a single [`JsonToken::Unknown`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use any_lexer::{JsonLexer, JsonToken};
+    ///
+    /// let ndjson = "{\"msg\": \"trunc\n{\"ok\": tru\n";
+    /// let tokens = JsonLexer::new(ndjson)
+    ///     .loose(true)
+    ///     .map(|(tok, span)| (tok, span.as_str()))
+    ///     .collect::<Vec<_>>();
+    ///
+    /// use JsonToken::*;
+    /// assert_eq!(
+    ///     tokens,
+    ///     [
+    ///         (Delim, "{"), (String, "\"msg\""), (Punct, ":"), (Space, " "),
+    ///         (String, "\"trunc"), (Space, "\n"),
+    ///         (Delim, "{"), (String, "\"ok\""), (Punct, ":"), (Space, " "),
+    ///         (Unknown, "tru"), (Space, "\n"),
+    ///     ]
+    /// );
+    /// ```
+    ///
+    /// [newline-delimited JSON]: https://github.com/ndjson/ndjson-spec
+    #[inline]
+    pub fn loose(mut self, loose: bool) -> Self {
+        self.loose = loose;
+        self
+    }
 }
 
-impl_lexer_from_scanner!('text, JsonLexer<'text>, JsonToken, scanner);
+impl<'text> crate::Lexer<'text> for JsonLexer<'text> {
+    type Token = JsonToken;
+
+    #[inline]
+    fn next_token(&mut self) -> Option<(Self::Token, TokenSpan<'text>)> {
+        if self.loose {
+            JsonToken::scan_loose_token(&mut self.scanner)
+        } else {
+            JsonToken::scan_token(&mut self.scanner)
+        }
+    }
+
+    #[inline]
+    fn peek_token(&self) -> Option<(Self::Token, TokenSpan<'text>)> {
+        self.clone().next_token()
+    }
+
+    #[inline]
+    fn scanner(&self) -> &Scanner<'text> {
+        &self.scanner
+    }
+
+    #[inline]
+    fn scanner_mut(&mut self) -> &mut Scanner<'text> {
+        &mut self.scanner
+    }
+}
+
+impl_iter_for_lexer!('text, JsonLexer<'text>);
+impl_from_str_for_lexer!('text, JsonLexer<'text>);
 
 #[cfg(test)]
 mod tests {
@@ -101,4 +238,32 @@ mod tests {
 
         assert_eq!(input, output);
     }
+
+    #[test]
+    fn test_json_lexer_loose() {
+        let ndjson = "{\"a\": \"b\\\r\n{\"c\": [1, fals}\r\n\n\"d\"";
+        let tokens = JsonLexer::new(ndjson)
+            .loose(true)
+            .map(|(tok, span)| (tok, span.as_str(), span.is_unterminated()))
+            .collect::<Vec<_>>();
+
+        use JsonToken::*;
+        #[rustfmt::skip]
+        assert_eq!(
+            tokens,
+            [
+                (Delim, "{", false), (String, "\"a\"", false), (Punct, ":", false),
+                (Space, " ", false), (String, "\"b\\", true), (Space, "\r\n", false),
+                (Delim, "{", false), (String, "\"c\"", false), (Punct, ":", false),
+                (Space, " ", false), (Delim, "[", false), (Number, "1", false),
+                (Punct, ",", false), (Space, " ", false), (Unknown, "fals", false),
+                (Delim, "}", false), (Space, "\r\n", false), (Space, "\n", false),
+                (String, "\"d\"", false),
+            ]
+        );
+
+        // Without `loose()`, the string continues onto the next line
+        let (tok, span) = JsonLexer::new(ndjson).nth(4).unwrap();
+        assert_eq!((tok, span.as_str()), (String, "\"b\\\r\n{\""));
+    }
 }
@@ -1,6 +1,10 @@
 use text_scanner::{ext::JsonCScannerExt, Scanner};
 
-use crate::{impl_lexer_from_scanner, JsonToken, ScanToken, ScannerExt, TokenSpan};
+use crate::trivia::{attach_trivia, Attached};
+use crate::{
+    impl_lexer_from_scanner, impl_token_kind_sets, impl_token_names, JsonToken, ScanToken,
+    ScannerExt, TokenSpan,
+};
 
 #[derive(PartialEq, Eq, Clone, Copy, Debug)]
 pub enum JsonCToken {
@@ -24,12 +28,33 @@ pub enum JsonCToken {
     Unknown,
 }
 
+impl_token_names!(JsonCToken {
+    Space => "space",
+    LineComment => "line_comment",
+    BlockComment => "block_comment",
+    String => "string",
+    Number => "number",
+    Null => "null",
+    True => "true",
+    False => "false",
+    Punct => "punct",
+    Delim => "delim",
+    Unknown => "unknown",
+});
+
+impl_token_kind_sets!(JsonCToken {
+    /// Whitespace and comments.
+    TRIVIA => [Space, LineComment, BlockComment],
+    /// String, number, `null`, `true`, and `false` literals.
+    LITERALS => [String, Number, Null, True, False],
+});
+
 impl ScanToken for JsonCToken {
     fn scan_token<'text>(scanner: &mut Scanner<'text>) -> Option<(Self, TokenSpan<'text>)> {
         if let Ok((r, _s)) = scanner.scan_jsonc_line_comment() {
             return Some((Self::LineComment, scanner.span(r)));
-        } else if let Ok((r, _s)) = scanner.scan_jsonc_block_comment() {
-            return Some((Self::BlockComment, scanner.span(r)));
+        } else if let Ok((r, outcome)) = scanner.scan_jsonc_block_comment_outcome() {
+            return Some((Self::BlockComment, scanner.span_outcome(r, &outcome)));
         }
 
         let (tok, span) = JsonToken::scan_token(scanner)?;
@@ -70,6 +95,71 @@ impl<'text> JsonCLexer<'text> {
 
 impl_lexer_from_scanner!('text, JsonCLexer<'text>, JsonCToken, scanner);
 
+/// Attaches the comments and whitespace of [JSON with Comments] `tokens`
+/// to the surrounding tokens, and returns the [`Attached`] trivia of all
+/// object keys, i.e. strings followed by `:`.
+///
+/// Comments on the lines before a key are attached to the key as leading
+/// trivia, while a comment following a value on the same line is attached
+/// to the value, or to the `,` following it, as trailing trivia. See
+/// [`attach_trivia()`] for the exact rules.
+///
+/// This allows config editors to keep the comments of a key when rewriting
+/// its value, and to move or remove a key along with its comments, see
+/// [`Attached::text_range()`].
+///
+/// # Example
+///
+/// ```rust
+/// use any_lexer::{attach_jsonc_key_comments, JsonCLexer, JsonCToken};
+///
+/// let json = r#"{
+///     // Port to listen on
+///     "port": 8080, // Default
+///     /* Host */ "host": "localhost"
+/// }"#;
+/// let tokens = JsonCLexer::new(json).collect::<Vec<_>>();
+///
+/// let keys = attach_jsonc_key_comments(&tokens)
+///     .into_iter()
+///     .map(|attached| {
+///         let key = tokens[attached.token].1.as_str();
+///         let comments = attached
+///             .leading(&tokens)
+///             .iter()
+///             .filter(|(tok, _span)| *tok != JsonCToken::Space)
+///             .map(|(_tok, span)| span.as_str())
+///             .collect::<Vec<_>>();
+///         (key, comments)
+///     })
+///     .collect::<Vec<_>>();
+///
+/// assert_eq!(
+///     keys,
+///     [
+///         ("\"port\"", vec!["// Port to listen on"]),
+///         ("\"host\"", vec!["/* Host */"]),
+///     ]
+/// );
+/// ```
+///
+/// [JSON with Comments]: https://code.visualstudio.com/docs/languages/json#_json-with-comments
+pub fn attach_jsonc_key_comments(tokens: &[(JsonCToken, TokenSpan<'_>)]) -> Vec<Attached> {
+    let attached = attach_trivia(tokens, JsonCToken::TRIVIA);
+    let is_key = |a: &Attached, next: &Attached| {
+        let (next_tok, next_span) = &tokens[next.token];
+        (tokens[a.token].0 == JsonCToken::String)
+            && (*next_tok == JsonCToken::Punct)
+            && (next_span.as_str() == ":")
+    };
+    attached
+        .iter()
+        .zip(attached.iter().skip(1))
+        .filter(|(a, next)| is_key(a, next))
+        .map(|(a, _next)| a.clone())
+        .collect()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -88,4 +178,42 @@ mod tests {
 
         assert_eq!(input, output);
     }
+
+    #[test]
+    fn test_attach_jsonc_key_comments() {
+        fn non_space<'text>(tokens: &[(JsonCToken, TokenSpan<'text>)]) -> Vec<&'text str> {
+            tokens
+                .iter()
+                .filter(|(tok, _span)| *tok != JsonCToken::Space)
+                .map(|(_tok, span)| span.as_str())
+                .collect()
+        }
+
+        let input = r#"// File
+{
+    "a": "b", // After a
+    /* Before c */ "c" /* Before : */ : [
+        "not a key", // After string
+        { "d": null } // After d
+    ]
+}"#;
+        let tokens = JsonCLexer::new(input).collect::<Vec<_>>();
+        let keys = attach_jsonc_key_comments(&tokens)
+            .into_iter()
+            .map(|attached| {
+                (
+                    tokens[attached.token].1.as_str(),
+                    non_space(attached.leading(&tokens)),
+                    non_space(attached.trailing(&tokens)),
+                )
+            })
+            .collect::<Vec<_>>();
+
+        let expected = [
+            ("\"a\"", vec![], vec![]),
+            ("\"c\"", vec!["/* Before c */"], vec!["/* Before : */"]),
+            ("\"d\"", vec![], vec![]),
+        ];
+        assert_eq!(keys, expected);
+    }
 }
@@ -0,0 +1,222 @@
+use text_scanner::{
+    ext::{CssScannerExt, LessScannerExt},
+    Scanner,
+};
+
+use crate::{
+    impl_lexer_from_scanner, impl_token_kind_sets, impl_token_names, CssToken, ScanToken,
+    ScannerExt, TokenSpan,
+};
+
+// Comparison operators used in mixin guards, and selector combinators
+const PUNCTUATIONS: [char; 4] = ['>', '<', '&', '~'];
+
+/// Keywords used in [mixin guards], e.g. `.mixin(@a) when (@a > 0)`.
+///
+/// [mixin guards]: https://lesscss.org/features/#mixin-guards-feature
+const GUARD_KEYWORDS: &[&str] = &["when", "and", "or", "not"];
+
+/// CSS at-rules, i.e. at-keywords which are not Less variables.
+const AT_RULES: &[&str] = &[
+    "charset",
+    "container",
+    "counter-style",
+    "document",
+    "font-face",
+    "font-feature-values",
+    "import",
+    "keyframes",
+    "layer",
+    "media",
+    "namespace",
+    "page",
+    "plugin",
+    "property",
+    "supports",
+    "viewport",
+];
+
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+pub enum LessToken {
+    Space,
+    LineComment,
+    BlockComment,
+    Ident,
+    /// Mixin guard keyword, e.g. `when`, `and`, `not`.
+    Keyword,
+    /// CSS at-rule, e.g. `@media`, `@import`.
+    AtKeyword,
+    /// Variable, e.g. `@color`, `@@name`, and `@{name}`.
+    Variable,
+    /// Escaped string, e.g. `~"calc(100% - 10px)"`.
+    Escape,
+    Hash,
+    String,
+    Number,
+    /// Attribute selector, e.g. `[data-x="y"]`.
+    AttrSelector,
+    /// Pseudo-class in a selector, e.g. `:hover` and `:nth-child`.
+    PseudoClass,
+    /// Pseudo-element in a selector, e.g. `::before`.
+    PseudoElement,
+    /// Combinator in a selector, e.g. `+` and `||`, where `>` and `~`
+    /// are produced as [`Punct`](Self::Punct).
+    Combinator,
+    /// Punctuation e.g. `:`, `,`, `>`.
+    Punct,
+    /// Delimiter e.g. `{`, `}`, `[`, and `]`.
+    Delim,
+    /// Given valid Less, then this variant should never be encountered. If is
+    /// is encountered, then check if an issue has already been submitted,
+    /// otherwise please [submit an issue].
+    ///
+    /// [submit an issue]: https://github.com/vallentin/colorblast/issues
+    Unknown,
+}
+
+impl_token_names!(LessToken {
+    Space => "space",
+    LineComment => "line_comment",
+    BlockComment => "block_comment",
+    Ident => "ident",
+    Keyword => "keyword",
+    AtKeyword => "at_keyword",
+    Variable => "variable",
+    Escape => "escape",
+    Hash => "hash",
+    String => "string",
+    Number => "number",
+    AttrSelector => "attr_selector",
+    PseudoClass => "pseudo_class",
+    PseudoElement => "pseudo_element",
+    Combinator => "combinator",
+    Punct => "punct",
+    Delim => "delim",
+    Unknown => "unknown",
+});
+
+impl_token_kind_sets!(LessToken {
+    /// Whitespace and comments.
+    TRIVIA => [Space, LineComment, BlockComment],
+    /// String and number literals.
+    LITERALS => [String, Number],
+    /// Parts of selectors, which are not identifiers or hashes.
+    SELECTORS => [AttrSelector, PseudoClass, PseudoElement, Combinator],
+});
+
+impl ScanToken for LessToken {
+    fn scan_token<'text>(scanner: &mut Scanner<'text>) -> Option<(Self, TokenSpan<'text>)> {
+        if let Ok((r, _s)) = scanner.scan_less_line_comment() {
+            return Some((Self::LineComment, scanner.span(r)));
+        } else if let Ok((r, _s)) = scanner.scan_less_escape() {
+            return Some((Self::Escape, scanner.span(r)));
+        }
+
+        if let Ok((r, s)) = scanner.scan_css_at_keyword() {
+            // Also accept vendor prefixed at-rules, e.g. `@-webkit-keyframes`
+            let name = &s[1..];
+            let is_at_rule = AT_RULES.contains(&name)
+                || (name.starts_with('-') && AT_RULES.iter().any(|rule| name.ends_with(rule)));
+            let tok = if is_at_rule {
+                Self::AtKeyword
+            } else {
+                Self::Variable
+            };
+            return Some((tok, scanner.span(r)));
+        } else if let Ok((r, _s)) = scanner.scan_less_variable() {
+            return Some((Self::Variable, scanner.span(r)));
+        }
+
+        if let Ok((r, _c)) = scanner.accept_char_any(&PUNCTUATIONS) {
+            return Some((Self::Punct, scanner.span(r)));
+        }
+
+        let (tok, span) = CssToken::scan_token(scanner)?;
+        match tok {
+            CssToken::Space => Some((LessToken::Space, span)),
+            CssToken::BlockComment => Some((LessToken::BlockComment, span)),
+            CssToken::Ident if GUARD_KEYWORDS.contains(&span.as_str()) => {
+                Some((LessToken::Keyword, span))
+            }
+            CssToken::Ident => Some((LessToken::Ident, span)),
+            CssToken::AtKeyword => Some((LessToken::AtKeyword, span)),
+            CssToken::Hash => Some((LessToken::Hash, span)),
+            CssToken::String => Some((LessToken::String, span)),
+            CssToken::Number => Some((LessToken::Number, span)),
+            CssToken::AttrSelector => Some((LessToken::AttrSelector, span)),
+            CssToken::PseudoClass => Some((LessToken::PseudoClass, span)),
+            CssToken::PseudoElement => Some((LessToken::PseudoElement, span)),
+            CssToken::Combinator => Some((LessToken::Combinator, span)),
+            CssToken::Punct => Some((LessToken::Punct, span)),
+            CssToken::Delim => Some((LessToken::Delim, span)),
+            CssToken::Unknown => Some((LessToken::Unknown, span)),
+        }
+    }
+}
+
+/// [Less] lexer producing [`LessToken`]s.
+///
+/// **Note:** Cloning `LessLexer` is essentially a copy, as it just contains
+/// a `&str` and a `usize` for its `cursor`. However, `Copy` is not
+/// implemented, to avoid accidentally copying immutable `LessLexer`s.
+///
+/// See also [`CssLexer`].
+///
+/// [Less]: https://lesscss.org
+/// [`CssLexer`]: super::CssLexer
+#[derive(Clone, Debug)]
+pub struct LessLexer<'text> {
+    scanner: Scanner<'text>,
+}
+
+impl<'text> LessLexer<'text> {
+    #[inline]
+    pub fn new(text: &'text str) -> Self {
+        Self {
+            scanner: Scanner::new(text),
+        }
+    }
+}
+
+impl_lexer_from_scanner!('text, LessLexer<'text>, LessToken, scanner);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_less_lexer_spans() {
+        // This intentionally uses Rust code as input, as it is
+        // only testing that LessLexer returns all characters
+        let input = include_str!("../../../text-scanner/src/ext/rust.rs");
+        let mut output = String::new();
+
+        let lexer = LessLexer::new(input);
+        for (_tok, span) in lexer {
+            output.push_str(span.as_str());
+        }
+
+        assert_eq!(input, output);
+    }
+
+    #[test]
+    fn test_less_lexer_tokens() {
+        let input = "@media { .m(@a) when (@a > 0) { w: ~\"calc(@{a})\"; } }";
+        let tokens = LessLexer::new(input)
+            .filter(|(tok, _span)| *tok != LessToken::Space)
+            .map(|(tok, span)| (tok, span.as_str()))
+            .collect::<Vec<_>>();
+
+        use LessToken::*;
+        #[rustfmt::skip]
+        let expected = [
+            (AtKeyword, "@media"), (Delim, "{"),
+            (Punct, "."), (Ident, "m"), (Delim, "("), (Variable, "@a"), (Delim, ")"),
+            (Keyword, "when"), (Delim, "("), (Variable, "@a"), (Punct, ">"), (Number, "0"), (Delim, ")"),
+            (Delim, "{"), (Ident, "w"), (Punct, ":"), (Escape, "~\"calc(@{a})\""), (Punct, ";"), (Delim, "}"),
+            (Delim, "}"),
+        ];
+
+        assert_eq!(tokens, expected);
+    }
+}
@@ -1,7 +1,10 @@
 use text_scanner::ext::{LispLikeScannerExt, LispLikeToken as LispLikeTok};
 use text_scanner::Scanner;
 
-use crate::{impl_lexer_from_scanner, ScanToken, ScannerExt, TokenSpan};
+use crate::{
+    impl_lexer_from_scanner, impl_token_kind_sets, impl_token_names, ScanToken, ScannerExt,
+    TokenSpan,
+};
 
 #[derive(PartialEq, Eq, Clone, Copy, Debug)]
 pub enum LispLikeToken {
@@ -20,6 +23,24 @@ pub enum LispLikeToken {
     Unknown,
 }
 
+impl_token_names!(LispLikeToken {
+    Space => "space",
+    SymbolName => "symbol_name",
+    Delim => "delim",
+    Int => "int",
+    Float => "float",
+    Ratio => "ratio",
+    String => "string",
+    Unknown => "unknown",
+});
+
+impl_token_kind_sets!(LispLikeToken {
+    /// Whitespace.
+    TRIVIA => [Space],
+    /// Number and string literals.
+    LITERALS => [Int, Float, Ratio, String],
+});
+
 impl ScanToken for LispLikeToken {
     fn scan_token<'text>(scanner: &mut Scanner<'text>) -> Option<(Self, TokenSpan<'text>)> {
         let (r, _s) = scanner.skip_whitespace();
@@ -0,0 +1,292 @@
+use text_scanner::{ext::MarkdownScannerExt, Scanner};
+
+use crate::{
+    impl_lexer_from_scanner, impl_token_kind_sets, impl_token_names, ScanToken, ScannerExt,
+    TokenSpan,
+};
+
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+pub enum MarkdownToken {
+    Space,
+    /// ATX heading including the `#`s, e.g. `# Heading`.
+    Heading,
+    /// Emphasis including the delimiters, e.g. `*a*` and `_a_`.
+    Emphasis,
+    /// Strong emphasis including the delimiters, e.g. `**a**` and `__a__`.
+    Strong,
+    /// Code span including the backticks, e.g. `` `code` ``.
+    InlineCode,
+    /// Fenced code block including the fences and info string.
+    CodeBlock,
+    /// Link, e.g. `[text](url)`, `[text][ref]`, and `<https://example.com>`.
+    Link,
+    /// Image, e.g. `![alt](src)`.
+    Image,
+    /// List marker, e.g. `-`, `*`, and `1.`.
+    ListMarker,
+    /// Block quote marker, i.e. `>`.
+    BlockQuote,
+    /// Thematic break, e.g. `---` and `* * *`.
+    ThematicBreak,
+    /// GFM task list item marker, i.e. `[ ]`, `[x]`, and `[X]` following
+    /// a list marker.
+    TaskMarker,
+    /// GFM table delimiter row, e.g. `| :-- | --: |`.
+    TableDelimiterRow,
+    /// GFM table cell separator, i.e. `|` in table rows.
+    TablePipe,
+    /// Text content, which might contain whitespace.
+    Text,
+}
+
+impl_token_names!(MarkdownToken {
+    Space => "space",
+    Heading => "heading",
+    Emphasis => "emphasis",
+    Strong => "strong",
+    InlineCode => "inline_code",
+    CodeBlock => "code_block",
+    Link => "link",
+    Image => "image",
+    ListMarker => "list_marker",
+    BlockQuote => "block_quote",
+    ThematicBreak => "thematic_break",
+    TaskMarker => "task_marker",
+    TableDelimiterRow => "table_delimiter_row",
+    TablePipe => "table_pipe",
+    Text => "text",
+});
+
+impl_token_kind_sets!(MarkdownToken {
+    /// Whitespace.
+    TRIVIA => [Space],
+});
+
+impl ScanToken for MarkdownToken {
+    fn scan_token<'text>(scanner: &mut Scanner<'text>) -> Option<(Self, TokenSpan<'text>)> {
+        let at_line_start = scanner.is_line_start();
+
+        if at_line_start || scanner.remaining_text().starts_with(['\n', '\r']) {
+            let (r, _s) = scanner.skip_whitespace();
+            if !r.is_empty() {
+                return Some((Self::Space, scanner.span(r)));
+            }
+        }
+
+        if at_line_start {
+            if let Ok((r, _s)) = scanner.scan_markdown_table_delimiter_row() {
+                return Some((Self::TableDelimiterRow, scanner.span(r)));
+            } else if let Ok((r, _s)) = scanner.scan_markdown_heading() {
+                return Some((Self::Heading, scanner.span(r)));
+            } else if let Ok((r, _s)) = scanner.scan_markdown_code_fence() {
+                return Some((Self::CodeBlock, scanner.span(r)));
+            } else if let Ok((r, _s)) = scanner.scan_markdown_thematic_break() {
+                return Some((Self::ThematicBreak, scanner.span(r)));
+            } else if let Ok((r, _s)) = scanner.scan_markdown_list_marker() {
+                return Some((Self::ListMarker, scanner.span(r)));
+            } else if let Ok((r, _s)) = scanner.scan_markdown_block_quote() {
+                return Some((Self::BlockQuote, scanner.span(r)));
+            }
+        }
+
+        if follows_list_marker(scanner) {
+            if let Ok((r, _s)) = scanner.scan_markdown_task_marker() {
+                return Some((Self::TaskMarker, scanner.span(r)));
+            }
+        }
+
+        if scanner.remaining_text().starts_with('|') && is_table_row(scanner) {
+            let (r, _c) = scanner.next().ok()?;
+            return Some((Self::TablePipe, scanner.span(r)));
+        }
+
+        if let Ok((r, s)) = scanner.scan_markdown_emphasis() {
+            let tok = if s.starts_with("**") || s.starts_with("__") {
+                Self::Strong
+            } else {
+                Self::Emphasis
+            };
+            return Some((tok, scanner.span(r)));
+        } else if let Ok((r, _s)) = scanner.scan_markdown_inline_code() {
+            return Some((Self::InlineCode, scanner.span(r)));
+        } else if let Ok((r, s)) = scanner.scan_markdown_link() {
+            let tok = if s.starts_with('!') {
+                Self::Image
+            } else {
+                Self::Link
+            };
+            return Some((tok, scanner.span(r)));
+        }
+
+        // Text continues until the end of the line, or until something
+        // which might start an inline token
+        let start = scanner.cursor_pos();
+        while let Ok((_r, c)) = scanner.next() {
+            if c == '\\' {
+                // Skip the next character as it is escaped
+                _ = scanner.accept_if(|c| !matches!(c, '\n' | '\r'));
+            }
+            scanner.skip_until(|c| {
+                matches!(
+                    c,
+                    '\\' | '*' | '_' | '`' | '[' | '!' | '<' | '|' | '\n' | '\r'
+                )
+            });
+            let next = scanner.peek().map(|(_r, c)| c);
+            if next != Ok('\\') {
+                break;
+            }
+        }
+
+        let r = start..scanner.cursor_pos();
+        if r.is_empty() {
+            return None;
+        }
+        Some((Self::Text, scanner.span(r)))
+    }
+}
+
+/// Returns `true` if only a list marker surrounded by spaces and tabs is
+/// between the cursor and the start of the line, e.g. `- ` and `  1. `.
+fn follows_list_marker(scanner: &Scanner<'_>) -> bool {
+    let before = &scanner.text()[..scanner.cursor_pos()];
+    let line = before.rsplit(['\n', '\r']).next().unwrap_or_default();
+    if !line.ends_with([' ', '\t']) {
+        return false;
+    }
+
+    let mut scanner = Scanner::new(line.trim());
+    scanner.scan_markdown_list_marker().is_ok() && !scanner.has_remaining_text()
+}
+
+/// Returns `true` if the line at the cursor is a GFM table row, i.e. the
+/// header row followed by the delimiter row, or a row following the
+/// delimiter row without any blank lines in between.
+fn is_table_row(scanner: &Scanner<'_>) -> bool {
+    let text = scanner.text();
+    let line_start = text[..scanner.cursor_pos()]
+        .rfind(['\n', '\r'])
+        .map_or(0, |i| i + 1);
+
+    // Header row
+    let mut lines = text[line_start..].lines().skip(1);
+    if lines.next().is_some_and(is_table_delimiter_row) {
+        return true;
+    }
+
+    for line in text[..line_start].lines().rev() {
+        if line.trim().is_empty() {
+            break;
+        } else if is_table_delimiter_row(line) {
+            return true;
+        }
+    }
+    false
+}
+
+/// Returns `true` if the whole `line` is a GFM table delimiter row.
+fn is_table_delimiter_row(line: &str) -> bool {
+    let mut scanner = Scanner::new(line.trim());
+    scanner.scan_markdown_table_delimiter_row().is_ok() && !scanner.has_remaining_text()
+}
+
+/// Markdown lexer producing [`MarkdownToken`]s.
+///
+/// **Note:** Cloning `MarkdownLexer` is essentially a copy, as it just contains
+/// a `&str` and a `usize` for its `cursor`. However, `Copy` is not
+/// implemented, to avoid accidentally copying immutable `MarkdownLexer`s.
+#[derive(Clone, Debug)]
+pub struct MarkdownLexer<'text> {
+    scanner: Scanner<'text>,
+}
+
+impl<'text> MarkdownLexer<'text> {
+    #[inline]
+    pub fn new(text: &'text str) -> Self {
+        Self {
+            scanner: Scanner::new(text),
+        }
+    }
+}
+
+impl_lexer_from_scanner!('text, MarkdownLexer<'text>, MarkdownToken, scanner);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_markdown_lexer_spans() {
+        // This intentionally uses Rust code as input, as it is
+        // only testing that MarkdownLexer returns all characters
+        let input = include_str!("../../../text-scanner/src/ext/rust.rs");
+        let mut output = String::new();
+
+        let lexer = MarkdownLexer::new(input);
+        for (_tok, span) in lexer {
+            output.push_str(span.as_str());
+        }
+
+        assert_eq!(input, output);
+    }
+
+    #[test]
+    fn test_markdown_lexer_tokens() {
+        let input = "# Title\n\n- **a** and `b`\n> [c](d) # e\n\n```rs\nx\n```\n";
+        let tokens = MarkdownLexer::new(input)
+            .map(|(tok, span)| (tok, span.as_str()))
+            .collect::<Vec<_>>();
+
+        use MarkdownToken::*;
+        #[rustfmt::skip]
+        let expected = [
+            (Heading, "# Title"), (Space, "\n\n"),
+            (ListMarker, "-"), (Text, " "), (Strong, "**a**"), (Text, " and "), (InlineCode, "`b`"), (Space, "\n"),
+            (BlockQuote, ">"), (Text, " "), (Link, "[c](d)"), (Text, " # e"), (Space, "\n\n"),
+            (CodeBlock, "```rs\nx\n```"), (Space, "\n"),
+        ];
+
+        assert_eq!(tokens, expected);
+    }
+
+    #[test]
+    fn test_markdown_lexer_table() {
+        let input = "| a | b |\n| :-- | --: |\n| `c` | d |\n\ne | f\n";
+        let tokens = MarkdownLexer::new(input)
+            .map(|(tok, span)| (tok, span.as_str()))
+            .collect::<Vec<_>>();
+
+        use MarkdownToken::*;
+        #[rustfmt::skip]
+        let expected = [
+            (TablePipe, "|"), (Text, " a "), (TablePipe, "|"), (Text, " b "), (TablePipe, "|"), (Space, "\n"),
+            (TableDelimiterRow, "| :-- | --: |"), (Space, "\n"),
+            (TablePipe, "|"), (Text, " "), (InlineCode, "`c`"), (Text, " "), (TablePipe, "|"), (Text, " d "), (TablePipe, "|"), (Space, "\n\n"),
+            // Not a table, as the blank line ends the table
+            (Text, "e "), (Text, "| f"), (Space, "\n"),
+        ];
+
+        assert_eq!(tokens, expected);
+    }
+
+    #[test]
+    fn test_markdown_lexer_task_list() {
+        let input = "- [ ] a\n- [x] [b](c)\n1. [X]\n[x] d\n- e [x]\n";
+        let tokens = MarkdownLexer::new(input)
+            .map(|(tok, span)| (tok, span.as_str()))
+            .collect::<Vec<_>>();
+
+        use MarkdownToken::*;
+        #[rustfmt::skip]
+        let expected = [
+            (ListMarker, "-"), (Text, " "), (TaskMarker, "[ ]"), (Text, " a"), (Space, "\n"),
+            (ListMarker, "-"), (Text, " "), (TaskMarker, "[x]"), (Text, " "), (Link, "[b](c)"), (Space, "\n"),
+            (ListMarker, "1."), (Text, " "), (TaskMarker, "[X]"), (Space, "\n"),
+            // Not following a list marker
+            (Link, "[x]"), (Text, " d"), (Space, "\n"),
+            (ListMarker, "-"), (Text, " e "), (Link, "[x]"), (Space, "\n"),
+        ];
+
+        assert_eq!(tokens, expected);
+    }
+}
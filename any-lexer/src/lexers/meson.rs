@@ -0,0 +1,125 @@
+use text_scanner::ext::{MesonScannerExt, MesonStrExt};
+use text_scanner::Scanner;
+
+use crate::{
+    impl_lexer_from_scanner, impl_token_kind_sets, impl_token_names, ScanToken, ScannerExt,
+    TokenSpan,
+};
+
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+pub enum MesonToken {
+    Space,
+    LineComment,
+    Ident,
+    Keyword,
+    /// String, e.g. `'text'`, `'''text'''`, and `f'@name@'`.
+    String,
+    Int,
+    Delim,
+    Punct,
+    /// Given valid Meson code, then this variant should never be encountered.
+    /// If is is encountered, then check if an issue has already been submitted,
+    /// otherwise please [submit an issue].
+    ///
+    /// [submit an issue]: https://github.com/vallentin/colorblast/issues
+    Unknown,
+}
+
+impl_token_names!(MesonToken {
+    Space => "space",
+    LineComment => "line_comment",
+    Ident => "ident",
+    Keyword => "keyword",
+    String => "string",
+    Int => "int",
+    Delim => "delim",
+    Punct => "punct",
+    Unknown => "unknown",
+});
+
+impl_token_kind_sets!(MesonToken {
+    /// Whitespace and comments.
+    TRIVIA => [Space, LineComment],
+    /// String and integer literals.
+    LITERALS => [String, Int],
+});
+
+impl ScanToken for MesonToken {
+    fn scan_token<'text>(scanner: &mut Scanner<'text>) -> Option<(Self, TokenSpan<'text>)> {
+        let (r, _s) = scanner.skip_whitespace();
+        if !r.is_empty() {
+            return Some((Self::Space, scanner.span(r)));
+        }
+
+        if let Ok((r, _s)) = scanner.scan_meson_line_comment() {
+            return Some((Self::LineComment, scanner.span(r)));
+        }
+
+        if let Ok((r, outcome)) = scanner.scan_meson_string_outcome() {
+            return Some((Self::String, scanner.span_outcome(r, &outcome)));
+        }
+
+        if let Ok((r, ident)) = scanner.scan_meson_identifier() {
+            let tok = if ident.is_meson_keyword() {
+                Self::Keyword
+            } else {
+                Self::Ident
+            };
+            return Some((tok, scanner.span(r)));
+        }
+
+        if let Ok((r, _s)) = scanner.scan_meson_int() {
+            return Some((Self::Int, scanner.span(r)));
+        }
+
+        if let Ok((r, _s)) = scanner.scan_meson_delimiter() {
+            return Some((Self::Delim, scanner.span(r)));
+        } else if let Ok((r, _s)) = scanner.scan_meson_operator() {
+            return Some((Self::Punct, scanner.span(r)));
+        }
+
+        let (r, _c) = scanner.next().ok()?;
+        Some((Self::Unknown, scanner.span(r)))
+    }
+}
+
+/// Meson lexer producing [`MesonToken`]s.
+///
+/// **Note:** Cloning `MesonLexer` is essentially a copy, as it just contains
+/// a `&str` and a `usize` for its `cursor`. However, `Copy` is not
+/// implemented, to avoid accidentally copying immutable `MesonLexer`s.
+#[derive(Clone, Debug)]
+pub struct MesonLexer<'text> {
+    scanner: Scanner<'text>,
+}
+
+impl<'text> MesonLexer<'text> {
+    #[inline]
+    pub fn new(text: &'text str) -> Self {
+        Self {
+            scanner: Scanner::new(text),
+        }
+    }
+}
+
+impl_lexer_from_scanner!('text, MesonLexer<'text>, MesonToken, scanner);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_meson_lexer_spans() {
+        // This intentionally uses Rust code as input, as it is
+        // only testing that MesonLexer returns all characters
+        let input = include_str!("../../../text-scanner/src/ext/rust.rs");
+        let mut output = String::new();
+
+        let lexer = MesonLexer::new(input);
+        for (_tok, span) in lexer {
+            output.push_str(span.as_str());
+        }
+
+        assert_eq!(input, output);
+    }
+}
@@ -1,21 +1,105 @@
+#[cfg(feature = "lang-apache")]
+mod apache;
+#[cfg(feature = "lang-c")]
 mod c;
+#[cfg(feature = "lang-cmake")]
+mod cmake;
+#[cfg(feature = "lang-cpp")]
 mod cpp;
+#[cfg(feature = "lang-crontab")]
+mod crontab;
+#[cfg(feature = "lang-csharp")]
+mod csharp;
+#[cfg(feature = "lang-css")]
 mod css;
+#[cfg(feature = "lang-fortran")]
+mod fortran;
+#[cfg(feature = "lang-html")]
+mod html;
+#[cfg(feature = "lang-java")]
+mod java;
+#[cfg(feature = "lang-javascript")]
+mod javascript;
+#[cfg(feature = "lang-json")]
 mod json;
+#[cfg(feature = "lang-jsonc")]
 mod jsonc;
+#[cfg(feature = "lang-less")]
+mod less;
+#[cfg(feature = "lang-lisp")]
 mod lisp;
+#[cfg(feature = "lang-markdown")]
+mod markdown;
+#[cfg(feature = "lang-meson")]
+mod meson;
+#[cfg(feature = "lang-nginx")]
+mod nginx;
+#[cfg(feature = "lang-python")]
 mod python;
+#[cfg(feature = "lang-rust")]
 mod rust;
+#[cfg(feature = "lang-scss")]
 mod scss;
+#[cfg(feature = "lang-stylus")]
+mod stylus;
+#[cfg(feature = "lang-swift")]
 mod swift;
+#[cfg(feature = "lang-systemd")]
+mod systemd;
+#[cfg(feature = "lang-toml")]
+mod toml;
+#[cfg(feature = "lang-xml")]
+mod xml;
 
+#[cfg(feature = "lang-apache")]
+pub use self::apache::*;
+#[cfg(feature = "lang-c")]
 pub use self::c::*;
+#[cfg(feature = "lang-cmake")]
+pub use self::cmake::*;
+#[cfg(feature = "lang-cpp")]
 pub use self::cpp::*;
+#[cfg(feature = "lang-crontab")]
+pub use self::crontab::*;
+#[cfg(feature = "lang-csharp")]
+pub use self::csharp::*;
+#[cfg(feature = "lang-css")]
 pub use self::css::*;
+#[cfg(feature = "lang-fortran")]
+pub use self::fortran::*;
+#[cfg(feature = "lang-html")]
+pub use self::html::*;
+#[cfg(feature = "lang-java")]
+pub use self::java::*;
+#[cfg(feature = "lang-javascript")]
+pub use self::javascript::*;
+#[cfg(feature = "lang-json")]
 pub use self::json::*;
+#[cfg(feature = "lang-jsonc")]
 pub use self::jsonc::*;
+#[cfg(feature = "lang-less")]
+pub use self::less::*;
+#[cfg(feature = "lang-lisp")]
 pub use self::lisp::*;
+#[cfg(feature = "lang-markdown")]
+pub use self::markdown::*;
+#[cfg(feature = "lang-meson")]
+pub use self::meson::*;
+#[cfg(feature = "lang-nginx")]
+pub use self::nginx::*;
+#[cfg(feature = "lang-python")]
 pub use self::python::*;
+#[cfg(feature = "lang-rust")]
 pub use self::rust::*;
+#[cfg(feature = "lang-scss")]
 pub use self::scss::*;
+#[cfg(feature = "lang-stylus")]
+pub use self::stylus::*;
+#[cfg(feature = "lang-swift")]
 pub use self::swift::*;
+#[cfg(feature = "lang-systemd")]
+pub use self::systemd::*;
+#[cfg(feature = "lang-toml")]
+pub use self::toml::*;
+#[cfg(feature = "lang-xml")]
+pub use self::xml::*;
@@ -0,0 +1,185 @@
+use text_scanner::ext::NginxScannerExt;
+use text_scanner::Scanner;
+
+use crate::{
+    impl_lexer_from_scanner, impl_token_kind_sets, impl_token_names, ScanToken, ScannerExt,
+    TokenSpan,
+};
+
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+pub enum NginxToken {
+    Space,
+    LineComment,
+    /// Directive names and unquoted arguments, e.g. `server_name`,
+    /// `/var/www/html`, and `~*`.
+    Word,
+    /// Unquoted arguments which are numbers, sizes, or times,
+    /// e.g. `80`, `10m`, and `30s`.
+    Number,
+    /// Variable, e.g. `$host` and `${host}`.
+    Var,
+    /// String, e.g. `"$host:$server_port"`.
+    String,
+    /// Delimiter, i.e. `{`, `}`, and `;`.
+    Delim,
+    /// Given valid nginx configuration, then this variant should never be
+    /// encountered. If is is encountered, then check if an issue has already
+    /// been submitted, otherwise please [submit an issue].
+    ///
+    /// [submit an issue]: https://github.com/vallentin/colorblast/issues
+    Unknown,
+}
+
+impl_token_names!(NginxToken {
+    Space => "space",
+    LineComment => "line_comment",
+    Word => "word",
+    Number => "number",
+    Var => "var",
+    String => "string",
+    Delim => "delim",
+    Unknown => "unknown",
+});
+
+impl_token_kind_sets!(NginxToken {
+    /// Whitespace and comments.
+    TRIVIA => [Space, LineComment],
+    /// String and number arguments.
+    LITERALS => [String, Number],
+});
+
+impl ScanToken for NginxToken {
+    fn scan_token<'text>(scanner: &mut Scanner<'text>) -> Option<(Self, TokenSpan<'text>)> {
+        let (r, _s) = scanner.skip_whitespace();
+        if !r.is_empty() {
+            return Some((Self::Space, scanner.span(r)));
+        }
+
+        if let Ok((r, _s)) = scanner.scan_nginx_line_comment() {
+            return Some((Self::LineComment, scanner.span(r)));
+        }
+
+        if let Ok((r, outcome)) = scanner.scan_nginx_string_outcome() {
+            return Some((Self::String, scanner.span_outcome(r, &outcome)));
+        } else if let Ok((r, _s)) = scanner.scan_nginx_variable() {
+            return Some((Self::Var, scanner.span(r)));
+        }
+
+        if let Ok((r, s)) = scanner.scan_nginx_word() {
+            let tok = if is_nginx_number(s) {
+                Self::Number
+            } else {
+                Self::Word
+            };
+            return Some((tok, scanner.span(r)));
+        }
+
+        if let Ok((r, _s)) = scanner.scan_nginx_delimiter() {
+            return Some((Self::Delim, scanner.span(r)));
+        }
+
+        let (r, _c) = scanner.next().ok()?;
+        Some((Self::Unknown, scanner.span(r)))
+    }
+}
+
+/// Returns `true` if `s` is a number, optionally followed by a size or
+/// time unit, e.g. `80`, `1.5`, `10m`, `64k`, and `500ms`.
+///
+/// See [Syntax](https://nginx.org/en/docs/syntax.html).
+fn is_nginx_number(s: &str) -> bool {
+    let unit = s.trim_start_matches(|c: char| c.is_ascii_digit() || (c == '.'));
+    let number = &s[..(s.len() - unit.len())];
+    number.starts_with(|c: char| c.is_ascii_digit())
+        && (number.matches('.').count() <= 1)
+        && matches!(
+            unit,
+            "" | "k" | "K" | "m" | "M" | "g" | "G" | "ms" | "s" | "h" | "d" | "w" | "y"
+        )
+}
+
+/// Nginx lexer producing [`NginxToken`]s.
+///
+/// **Note:** Cloning `NginxLexer` is essentially a copy, as it just contains
+/// a `&str` and a `usize` for its `cursor`. However, `Copy` is not
+/// implemented, to avoid accidentally copying immutable `NginxLexer`s.
+#[derive(Clone, Debug)]
+pub struct NginxLexer<'text> {
+    scanner: Scanner<'text>,
+}
+
+impl<'text> NginxLexer<'text> {
+    #[inline]
+    pub fn new(text: &'text str) -> Self {
+        Self {
+            scanner: Scanner::new(text),
+        }
+    }
+}
+
+impl_lexer_from_scanner!('text, NginxLexer<'text>, NginxToken, scanner);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_nginx_lexer_spans() {
+        // This intentionally uses Rust code as input, as it is
+        // only testing that NginxLexer returns all characters
+        let input = include_str!("../../../text-scanner/src/ext/rust.rs");
+        let mut output = String::new();
+
+        let lexer = NginxLexer::new(input);
+        for (_tok, span) in lexer {
+            output.push_str(span.as_str());
+        }
+
+        assert_eq!(input, output);
+    }
+
+    #[test]
+    fn test_nginx_lexer_tokens() {
+        use NginxToken as Tok;
+
+        let code =
+            r#"location ~* \.(gif)$ { root /var/$host/; expires 30d; add_header X "$1"; } # c"#;
+        let tokens = NginxLexer::new(code)
+            .filter(|(tok, _span)| *tok != Tok::Space)
+            .map(|(tok, span)| (tok, span.as_str()))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            tokens,
+            [
+                (Tok::Word, "location"),
+                (Tok::Word, "~*"),
+                (Tok::Word, r"\.(gif)$"),
+                (Tok::Delim, "{"),
+                (Tok::Word, "root"),
+                (Tok::Word, "/var/"),
+                (Tok::Var, "$host"),
+                (Tok::Word, "/"),
+                (Tok::Delim, ";"),
+                (Tok::Word, "expires"),
+                (Tok::Number, "30d"),
+                (Tok::Delim, ";"),
+                (Tok::Word, "add_header"),
+                (Tok::Word, "X"),
+                (Tok::String, "\"$1\""),
+                (Tok::Delim, ";"),
+                (Tok::Delim, "}"),
+                (Tok::LineComment, "# c"),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_is_nginx_number() {
+        for s in ["80", "1.5", "10m", "64k", "1G", "500ms", "30s", "1y"] {
+            assert!(is_nginx_number(s), "{s:?}");
+        }
+        for s in ["", "m", ".5", "1.2.3", "127.0.0.1", "10mb", "8080:80"] {
+            assert!(!is_nginx_number(s), "{s:?}");
+        }
+    }
+}
@@ -1,7 +1,20 @@
 use text_scanner::ext::{PythonScannerExt, PythonStrExt};
 use text_scanner::Scanner;
 
-use crate::{impl_lexer_from_scanner, ScanToken, ScannerExt, TokenSpan};
+use crate::{
+    impl_from_str_for_lexer, impl_iter_for_lexer, impl_token_kind_sets, impl_token_names,
+    ScanToken, ScannerExt, TokenSpan,
+};
+
+/// Python version affecting e.g. which identifiers are keywords.
+#[derive(PartialEq, Eq, Clone, Copy, Default, Debug)]
+pub enum PythonDialect {
+    /// Python 2, where e.g. `print` and `exec` are keywords, `ur''` is a
+    /// string, `0777` is an octal integer, and `<>` is an operator.
+    Py2,
+    #[default]
+    Py3,
+}
 
 #[derive(PartialEq, Eq, Clone, Copy, Debug)]
 pub enum PythonToken {
@@ -17,6 +30,10 @@ pub enum PythonToken {
     LongBytes,
     Int,
     Float,
+    /// Imaginary number literal, e.g. `3j` and `2.5J`.
+    Imaginary,
+    /// The `...` literal, i.e. `Ellipsis`.
+    Ellipsis,
     Delim,
     Punct,
     /// Given valid Python code, then this variant should never be encountered.
@@ -27,71 +44,149 @@ pub enum PythonToken {
     Unknown,
 }
 
+impl_token_names!(PythonToken {
+    Space => "space",
+    LineComment => "line_comment",
+    ExplicitLineJoiner => "explicit_line_joiner",
+    Ident => "ident",
+    Keyword => "keyword",
+    SoftKeyword => "soft_keyword",
+    ShortString => "short_string",
+    LongString => "long_string",
+    ShortBytes => "short_bytes",
+    LongBytes => "long_bytes",
+    Int => "int",
+    Float => "float",
+    Imaginary => "imaginary",
+    Ellipsis => "ellipsis",
+    Delim => "delim",
+    Punct => "punct",
+    Unknown => "unknown",
+});
+
+impl_token_kind_sets!(PythonToken {
+    /// Whitespace, comments, and explicit line joiners, i.e. `\`.
+    TRIVIA => [Space, LineComment, ExplicitLineJoiner],
+    /// String, bytes, number, and ellipsis literals.
+    LITERALS => [ShortString, LongString, ShortBytes, LongBytes, Int, Float, Imaginary, Ellipsis],
+});
+
 impl ScanToken for PythonToken {
+    #[inline]
     fn scan_token<'text>(scanner: &mut Scanner<'text>) -> Option<(Self, TokenSpan<'text>)> {
-        let (r, _s) = scanner.skip_whitespace();
-        if !r.is_empty() {
-            return Some((Self::Space, scanner.span(r)));
-        }
+        scan_token(scanner, PythonDialect::Py3)
+    }
+}
 
-        if let Ok((r, _s)) = scanner.scan_python_line_comment() {
-            return Some((Self::LineComment, scanner.span(r)));
-        }
+fn scan_token<'text>(
+    scanner: &mut Scanner<'text>,
+    dialect: PythonDialect,
+) -> Option<(PythonToken, TokenSpan<'text>)> {
+    use PythonToken as Tok;
 
-        if let Ok((r, ident)) = scanner.scan_python_identifier() {
-            let tok = if ident.is_python_keyword() {
-                Self::Keyword
-            } else if ident.is_python_soft_keyword() {
-                Self::SoftKeyword
-            } else {
-                Self::Ident
-            };
-            return Some((tok, scanner.span(r)));
-        }
+    let is_py2 = dialect == PythonDialect::Py2;
 
-        if let Ok((r, _s)) = scanner.scan_python_long_string() {
-            return Some((Self::LongString, scanner.span(r)));
-        } else if let Ok((r, _s)) = scanner.scan_python_short_string() {
-            return Some((Self::ShortString, scanner.span(r)));
-        } else if let Ok((r, _s)) = scanner.scan_python_long_bytes() {
-            return Some((Self::LongBytes, scanner.span(r)));
-        } else if let Ok((r, _s)) = scanner.scan_python_short_bytes() {
-            return Some((Self::ShortBytes, scanner.span(r)));
-        }
+    let (r, _s) = scanner.skip_whitespace();
+    if !r.is_empty() {
+        return Some((Tok::Space, scanner.span(r)));
+    }
 
-        if let Ok((r, _s)) = scanner.scan_python_float() {
-            return Some((Self::Float, scanner.span(r)));
-        } else if let Ok((r, _s)) = scanner
-            .scan_python_int_hex()
-            .or_else(|_| scanner.scan_python_int_oct())
-            .or_else(|_| scanner.scan_python_int_dec())
-        {
-            return Some((Self::Int, scanner.span(r)));
-        }
+    if let Ok((r, _s)) = scanner.scan_python_line_comment() {
+        return Some((Tok::LineComment, scanner.span(r)));
+    }
 
-        if let Ok((r, _c)) = scanner.scan_python_delimiter() {
-            return Some((Self::Delim, scanner.span(r)));
-        } else if let Ok((r, _c)) = scanner.scan_python_operator() {
-            return Some((Self::Punct, scanner.span(r)));
-        }
+    let long_string = if is_py2 {
+        scanner.scan_python2_long_string_outcome()
+    } else {
+        scanner.scan_python_long_string_outcome()
+    };
+    if let Ok((r, outcome)) = long_string {
+        return Some((Tok::LongString, scanner.span_outcome(r, &outcome)));
+    }
+    let short_string = if is_py2 {
+        scanner.scan_python2_short_string_outcome()
+    } else {
+        scanner.scan_python_short_string_outcome()
+    };
+    if let Ok((r, outcome)) = short_string {
+        return Some((Tok::ShortString, scanner.span_outcome(r, &outcome)));
+    }
+
+    if let Ok((r, _s)) = scanner.scan_python_long_bytes() {
+        return Some((Tok::LongBytes, scanner.span(r)));
+    } else if let Ok((r, _s)) = scanner.scan_python_short_bytes() {
+        return Some((Tok::ShortBytes, scanner.span(r)));
+    }
+
+    if let Ok((r, ident)) = scanner.scan_python_identifier() {
+        let tok = if is_py2 {
+            if ident.is_python2_keyword() {
+                Tok::Keyword
+            } else {
+                Tok::Ident
+            }
+        } else if ident.is_python_keyword() {
+            Tok::Keyword
+        } else if ident.is_python_soft_keyword() {
+            Tok::SoftKeyword
+        } else {
+            Tok::Ident
+        };
+        return Some((tok, scanner.span(r)));
+    }
+
+    if let Ok((r, _s)) = scanner.scan_python_ellipsis() {
+        return Some((Tok::Ellipsis, scanner.span(r)));
+    }
 
-        if let Ok((r, _s)) = scanner.scan_python_explicit_line_joiner() {
-            return Some((Self::ExplicitLineJoiner, scanner.span(r)));
+    if let Ok((r, _s)) = scanner.scan_python_imaginary() {
+        return Some((Tok::Imaginary, scanner.span(r)));
+    } else if let Ok((r, _s)) = scanner.scan_python_float() {
+        return Some((Tok::Float, scanner.span(r)));
+    } else if let Ok((r, _s)) = scanner.scan_python_int_hex().or_else(|_| {
+        if is_py2 {
+            scanner.scan_python2_int_oct()
+        } else {
+            scanner.scan_python_int_oct()
         }
+    }) {
+        return Some((Tok::Int, scanner.span(r)));
+    } else if let Ok((r, _s)) = scanner
+        .scan_python_int_bin()
+        .or_else(|_| scanner.scan_python_int_dec())
+    {
+        return Some((Tok::Int, scanner.span(r)));
+    }
+
+    if let Ok((r, _c)) = scanner.scan_python_delimiter() {
+        return Some((Tok::Delim, scanner.span(r)));
+    }
+    let operator = if is_py2 {
+        scanner.scan_python2_operator()
+    } else {
+        scanner.scan_python_operator()
+    };
+    if let Ok((r, _s)) = operator {
+        return Some((Tok::Punct, scanner.span(r)));
+    }
 
-        let (r, _c) = scanner.next().ok()?;
-        Some((Self::Unknown, scanner.span(r)))
+    if let Ok((r, _s)) = scanner.scan_python_explicit_line_joiner() {
+        return Some((Tok::ExplicitLineJoiner, scanner.span(r)));
     }
+
+    let (r, _c) = scanner.next().ok()?;
+    Some((Tok::Unknown, scanner.span(r)))
 }
 
 /// Python lexer producing [`PythonToken`]s.
 ///
 /// **Note:** Cloning `PythonLexer` is essentially a copy, as it just contains
-/// a `&str` and a `usize` for its `cursor`. However, `Copy` is not
-/// implemented, to avoid accidentally copying immutable `PythonLexer`s.
+/// a `&str`, a `usize` for its `cursor`, and its `dialect`. However, `Copy`
+/// is not implemented, to avoid accidentally copying immutable `PythonLexer`s.
 #[derive(Clone, Debug)]
 pub struct PythonLexer<'text> {
     scanner: Scanner<'text>,
+    dialect: PythonDialect,
 }
 
 impl<'text> PythonLexer<'text> {
@@ -99,11 +194,45 @@ impl<'text> PythonLexer<'text> {
     pub fn new(text: &'text str) -> Self {
         Self {
             scanner: Scanner::new(text),
+            dialect: PythonDialect::Py3,
         }
     }
+
+    /// Sets the [`PythonDialect`], which defaults to [`PythonDialect::Py3`].
+    #[inline]
+    pub fn dialect(mut self, dialect: PythonDialect) -> Self {
+        self.dialect = dialect;
+        self
+    }
 }
 
-impl_lexer_from_scanner!('text, PythonLexer<'text>, PythonToken, scanner);
+impl<'text> crate::Lexer<'text> for PythonLexer<'text> {
+    type Token = PythonToken;
+
+    #[inline]
+    fn next_token(&mut self) -> Option<(Self::Token, TokenSpan<'text>)> {
+        scan_token(&mut self.scanner, self.dialect)
+    }
+
+    #[inline]
+    fn peek_token(&self) -> Option<(Self::Token, TokenSpan<'text>)> {
+        self.scanner
+            .peeking(|scanner| scan_token(scanner, self.dialect))
+    }
+
+    #[inline]
+    fn scanner(&self) -> &Scanner<'text> {
+        &self.scanner
+    }
+
+    #[inline]
+    fn scanner_mut(&mut self) -> &mut Scanner<'text> {
+        &mut self.scanner
+    }
+}
+
+impl_iter_for_lexer!('text, PythonLexer<'text>);
+impl_from_str_for_lexer!('text, PythonLexer<'text>);
 
 #[cfg(test)]
 mod tests {
@@ -123,4 +252,52 @@ mod tests {
 
         assert_eq!(input, output);
     }
+
+    #[test]
+    fn test_python_lexer_dialect() {
+        let input = "print ur'x' if 0777 <> nonlocal else f'y'";
+
+        use PythonToken::*;
+        #[rustfmt::skip]
+        let cases = [
+            (PythonDialect::Py2, &[
+                (Keyword, "print"), (ShortString, "ur'x'"), (Keyword, "if"), (Int, "0777"),
+                (Punct, "<>"), (Ident, "nonlocal"), (Keyword, "else"), (Ident, "f"), (ShortString, "'y'"),
+            ][..]),
+            (PythonDialect::Py3, &[
+                (Ident, "print"), (Ident, "ur"), (ShortString, "'x'"), (Keyword, "if"), (Int, "0777"),
+                (Punct, "<"), (Punct, ">"), (Keyword, "nonlocal"), (Keyword, "else"), (ShortString, "f'y'"),
+            ]),
+        ];
+
+        for (dialect, expected) in cases {
+            let tokens = PythonLexer::new(input)
+                .dialect(dialect)
+                .filter(|(tok, _span)| *tok != Space)
+                .map(|(tok, span)| (tok, span.as_str()))
+                .collect::<Vec<_>>();
+            assert_eq!(tokens, expected, "{dialect:?}");
+        }
+    }
+
+    #[test]
+    fn test_python_lexer_numbers_and_operators() {
+        let input = "z = 3j + 2.5J * 1e3j; x[...]; if (n := 10) >= .5j: ...";
+
+        use PythonToken::*;
+        #[rustfmt::skip]
+        let expected = [
+            (Ident, "z"), (Punct, "="), (Imaginary, "3j"), (Punct, "+"), (Imaginary, "2.5J"),
+            (Punct, "*"), (Imaginary, "1e3j"), (Punct, ";"), (Ident, "x"), (Delim, "["),
+            (Ellipsis, "..."), (Delim, "]"), (Punct, ";"), (Keyword, "if"), (Delim, "("),
+            (Ident, "n"), (Punct, ":="), (Int, "10"), (Delim, ")"), (Punct, ">="),
+            (Imaginary, ".5j"), (Punct, ":"), (Ellipsis, "..."),
+        ];
+
+        let tokens = PythonLexer::new(input)
+            .filter(|(tok, _span)| *tok != Space)
+            .map(|(tok, span)| (tok, span.as_str()))
+            .collect::<Vec<_>>();
+        assert_eq!(tokens, expected);
+    }
 }
@@ -1,17 +1,12 @@
-use text_scanner::{ext::RustScannerExt, Scanner};
+use text_scanner::{
+    ext::{RustEdition, RustScannerExt, RustStrExt},
+    ScanOutcome, Scanner,
+};
 
-use crate::{impl_lexer_from_scanner, ScanToken, ScannerExt, TokenSpan};
-
-#[rustfmt::skip]
-const KEYWORDS: [&str; 53] = [
-    "as", "break", "const", "continue", "crate", "else", "enum", "extern",
-    "false", "fn", "for", "if", "impl", "in", "let", "loop", "macro_rules",
-    "match", "mod", "move", "mut", "pub", "ref", "return", "self", "Self",
-    "static", "struct", "super", "trait", "true", "type", "union", "unsafe",
-    "use", "where", "while", "async", "await", "dyn", "abstract", "become",
-    "box", "do", "final", "macro", "override", "priv", "typeof", "unsized",
-    "virtual", "yield", "try",
-];
+use crate::{
+    impl_from_str_for_lexer, impl_iter_for_lexer, impl_token_kind_sets, impl_token_names,
+    ScanToken, ScannerExt, TokenSpan,
+};
 
 #[derive(PartialEq, Eq, Clone, Copy, Debug)]
 pub enum RustToken {
@@ -36,8 +31,44 @@ pub enum RustToken {
     Unknown,
 }
 
+impl_token_names!(RustToken {
+    Space => "space",
+    LineComment => "line_comment",
+    BlockComment => "block_comment",
+    Ident => "ident",
+    Keyword => "keyword",
+    Lifetime => "lifetime",
+    Char => "char",
+    String => "string",
+    RawString => "raw_string",
+    Int => "int",
+    Float => "float",
+    Delim => "delim",
+    Punct => "punct",
+    Unknown => "unknown",
+});
+
+impl_token_kind_sets!(RustToken {
+    /// Whitespace and comments.
+    TRIVIA => [Space, LineComment, BlockComment],
+    /// Character, string, and number literals.
+    LITERALS => [Char, String, RawString, Int, Float],
+});
+
 impl ScanToken for RustToken {
+    /// Scans a token using the default [`RustEdition`],
+    /// see [`RustLexer::edition()`].
+    #[inline]
     fn scan_token<'text>(scanner: &mut Scanner<'text>) -> Option<(Self, TokenSpan<'text>)> {
+        Self::scan_token_edition(scanner, RustEdition::default())
+    }
+}
+
+impl RustToken {
+    fn scan_token_edition<'text>(
+        scanner: &mut Scanner<'text>,
+        edition: RustEdition,
+    ) -> Option<(Self, TokenSpan<'text>)> {
         let (r, _s) = scanner.skip_whitespace();
         if !r.is_empty() {
             return Some((Self::Space, scanner.span(r)));
@@ -45,22 +76,45 @@ impl ScanToken for RustToken {
 
         if let Ok((r, _s)) = scanner.scan_rust_line_comment() {
             return Some((Self::LineComment, scanner.span(r)));
-        } else if let Ok((r, _s)) = scanner.scan_rust_block_comment() {
-            return Some((Self::BlockComment, scanner.span(r)));
+        } else if let Ok((r, outcome)) = scanner.scan_rust_block_comment_outcome() {
+            return Some((Self::BlockComment, scanner.span_outcome(r, &outcome)));
         }
 
-        if let Ok((r, _s)) = scanner
-            .scan_rust_raw_string()
-            .or_else(|_| scanner.scan_rust_string())
+        if let Ok((r, outcome)) = scanner
+            .scan_rust_raw_string_outcome()
+            .or_else(|_| scanner.scan_rust_string_outcome())
         {
-            return Some((Self::String, scanner.span(r)));
+            return Some((Self::String, scanner.span_outcome(r, &outcome)));
+        }
+
+        // Byte strings and bytes, e.g. `b"foo"`, `br"foo"`, and `b'a'`
+        let mut outcome = ScanOutcome::Terminated;
+        let res = scanner.scan_with(|scanner| {
+            scanner.accept_char('b')?;
+            (_, outcome) = scanner
+                .scan_rust_raw_string_outcome()
+                .or_else(|_| scanner.scan_rust_string_outcome())?;
+            Ok(())
+        });
+        if let Ok((r, _s)) = res {
+            return Some((Self::String, scanner.span_outcome(r, &outcome)));
+        }
+        let res = scanner.scan_with(|scanner| {
+            scanner.accept_char('b')?;
+            scanner.scan_rust_char()?;
+            Ok(())
+        });
+        if let Ok((r, _s)) = res {
+            return Some((Self::Char, scanner.span(r)));
         }
 
         if let Ok((r, ident)) = scanner
             .scan_rust_raw_identifier()
             .or_else(|_| scanner.scan_rust_identifier())
         {
-            let tok = if KEYWORDS.contains(&ident) {
+            let tok = if ident.is_rust_keyword(edition)
+                || (ident.is_rust_weak_keyword(edition) && is_weak_keyword(scanner, ident))
+            {
                 Self::Keyword
             } else {
                 Self::Ident
@@ -86,15 +140,25 @@ impl ScanToken for RustToken {
             return Some((Self::Unknown, scanner.span(r)));
         }
 
-        if let Ok((r, _s)) = scanner.scan_rust_float() {
-            return Some((Self::Float, scanner.span(r)));
+        let number = if let Ok((r, _s)) = scanner.scan_rust_float() {
+            Some((Self::Float, r))
         } else if let Ok((r, _s)) = scanner
             .scan_rust_int_hex()
             .or_else(|_| scanner.scan_rust_int_oct())
             .or_else(|_| scanner.scan_rust_int_bin())
             .or_else(|_| scanner.scan_rust_int_dec())
         {
-            return Some((Self::Int, scanner.span(r)));
+            Some((Self::Int, r))
+        } else {
+            None
+        };
+        if let Some((tok, r)) = number {
+            // Include the suffix, e.g. `1u8` and `1.0f64`
+            let end = match scanner.scan_rust_identifier() {
+                Ok((suffix, _s)) => suffix.end,
+                Err(_) => r.end,
+            };
+            return Some((tok, scanner.span(r.start..end)));
         }
 
         if let Ok((r, _c)) = scanner.accept_char_any(&['{', '}', '[', ']', '(', ')']) {
@@ -149,14 +213,73 @@ impl ScanToken for RustToken {
     }
 }
 
+/// Returns `true` if the weak keyword `ident`, which has just been scanned,
+/// is used as a keyword, based on the text following it, e.g. `union Foo`
+/// as opposed to `union(a, b)`.
+///
+/// Reference: <https://doc.rust-lang.org/reference/keywords.html#weak-keywords>
+fn is_weak_keyword(scanner: &Scanner<'_>, ident: &str) -> bool {
+    let mut scanner = scanner.clone();
+    if ident == "macro_rules" {
+        return scanner.accept_char('!').is_ok();
+    }
+
+    if scanner.skip_whitespace().0.is_empty() {
+        return false;
+    }
+    match ident {
+        // e.g. `union Foo { ... }`
+        "union" => scanner.scan_rust_identifier().is_ok(),
+        // e.g. `&raw const x` and `&raw mut x`
+        "raw" => matches!(scanner.scan_rust_identifier(), Ok((_, "const" | "mut"))),
+        // e.g. `safe fn f();` and `safe static X: i32;` in `extern` blocks
+        "safe" => matches!(scanner.scan_rust_identifier(), Ok((_, "fn" | "static"))),
+        // e.g. `dyn Trait` and `dyn 'a + Trait` in the 2015 edition
+        "dyn" => {
+            scanner.scan_rust_identifier().is_ok()
+                || scanner.accept_char_any(&['\'', '?', '(']).is_ok()
+        }
+        _ => false,
+    }
+}
+
 /// Rust lexer producing [`RustToken`]s.
 ///
 /// **Note:** Cloning `RustLexer` is essentially a copy, as it just contains
-/// a `&str` and a `usize` for its `cursor`. However, `Copy` is not
-/// implemented, to avoid accidentally copying immutable `RustLexer`s.
+/// a `&str`, a `usize` for its `cursor`, and its `edition`. However, `Copy`
+/// is not implemented, to avoid accidentally copying immutable `RustLexer`s.
+///
+/// # Keywords
+///
+/// Identifiers are produced as [`RustToken::Keyword`], if they are strict
+/// or reserved keywords in the [`edition()`], e.g. `try` is an identifier
+/// in the 2015 edition, see [`RustStrExt`]. Weak keywords, e.g. `union`
+/// and `macro_rules`, are only produced as [`RustToken::Keyword`] when
+/// used as keywords.
+///
+/// ```rust
+/// use any_lexer::{RustLexer, RustToken};
+/// use text_scanner::ext::RustEdition;
+///
+/// let code = "union U {} union(); try dyn Tr";
+/// let keywords = |edition| {
+///     RustLexer::new(code)
+///         .edition(edition)
+///         .filter(|(tok, _span)| *tok == RustToken::Keyword)
+///         .map(|(_tok, span)| span.as_str())
+///         .collect::<Vec<_>>()
+/// };
+///
+/// assert_eq!(keywords(RustEdition::Rust2015), ["union", "dyn"]);
+/// assert_eq!(keywords(RustEdition::Rust2021), ["union", "try", "dyn"]);
+/// ```
+///
+/// [`edition()`]: Self::edition
+/// [`RustStrExt`]: text_scanner::ext::RustStrExt
 #[derive(Clone, Debug)]
 pub struct RustLexer<'text> {
     scanner: Scanner<'text>,
+    edition: RustEdition,
 }
 
 impl<'text> RustLexer<'text> {
@@ -164,11 +287,48 @@ impl<'text> RustLexer<'text> {
     pub fn new(text: &'text str) -> Self {
         Self {
             scanner: Scanner::new(text),
+            edition: RustEdition::default(),
         }
     }
+
+    /// Sets the [`RustEdition`] used to determine which identifiers
+    /// are keywords, which is [`RustEdition::Rust2021`] by default.
+    ///
+    /// See [Keywords](Self#keywords) for more information.
+    #[inline]
+    pub fn edition(mut self, edition: RustEdition) -> Self {
+        self.edition = edition;
+        self
+    }
 }
 
-impl_lexer_from_scanner!('text, RustLexer<'text>, RustToken, scanner);
+impl<'text> crate::Lexer<'text> for RustLexer<'text> {
+    type Token = RustToken;
+
+    #[inline]
+    fn next_token(&mut self) -> Option<(Self::Token, TokenSpan<'text>)> {
+        RustToken::scan_token_edition(&mut self.scanner, self.edition)
+    }
+
+    #[inline]
+    fn peek_token(&self) -> Option<(Self::Token, TokenSpan<'text>)> {
+        self.scanner
+            .peeking(|scanner| RustToken::scan_token_edition(scanner, self.edition))
+    }
+
+    #[inline]
+    fn scanner(&self) -> &Scanner<'text> {
+        &self.scanner
+    }
+
+    #[inline]
+    fn scanner_mut(&mut self) -> &mut Scanner<'text> {
+        &mut self.scanner
+    }
+}
+
+impl_iter_for_lexer!('text, RustLexer<'text>);
+impl_from_str_for_lexer!('text, RustLexer<'text>);
 
 #[cfg(test)]
 mod tests {
@@ -186,4 +346,73 @@ mod tests {
 
         assert_eq!(input, output);
     }
+
+    #[test]
+    fn test_rust_lexer_literals() {
+        let input =
+            "0..1 1.max(2) 1e10 1.5f32 0xFF_u8 1_i32 b'a' b'\\x7F' '\\u{85}' b\"a\" br#\"b\"#";
+
+        use RustToken::*;
+        #[rustfmt::skip]
+        let expected = [
+            (Int, "0"), (Punct, ".."), (Int, "1"),
+            (Int, "1"), (Punct, "."), (Ident, "max"), (Delim, "("), (Int, "2"), (Delim, ")"),
+            (Float, "1e10"), (Float, "1.5f32"), (Int, "0xFF_u8"), (Int, "1_i32"),
+            (Char, "b'a'"), (Char, "b'\\x7F'"), (Char, "'\\u{85}'"),
+            (String, "b\"a\""), (String, "br#\"b\"#"),
+        ];
+
+        let tokens = RustLexer::new(input)
+            .filter(|(tok, _span)| *tok != Space)
+            .map(|(tok, span)| (tok, span.as_str()))
+            .collect::<Vec<_>>();
+        assert_eq!(tokens, expected);
+    }
+
+    #[test]
+    fn test_rust_lexer_weak_keywords() {
+        let input =
+            "macro_rules! m {} union U {} union(1); &raw const x; raw; safe fn f(); r#fn gen";
+
+        use RustToken::*;
+        #[rustfmt::skip]
+        let expected = [
+            (Keyword, "macro_rules"), (Punct, "!"), (Ident, "m"), (Delim, "{"), (Delim, "}"),
+            (Keyword, "union"), (Ident, "U"), (Delim, "{"), (Delim, "}"),
+            (Ident, "union"), (Delim, "("), (Int, "1"), (Delim, ")"), (Punct, ";"),
+            (Punct, "&"), (Keyword, "raw"), (Keyword, "const"), (Ident, "x"), (Punct, ";"),
+            (Ident, "raw"), (Punct, ";"),
+            (Keyword, "safe"), (Keyword, "fn"), (Ident, "f"), (Delim, "("), (Delim, ")"), (Punct, ";"),
+            (Ident, "r#fn"), (Ident, "gen"),
+        ];
+
+        let tokens = RustLexer::new(input)
+            .filter(|(tok, _span)| *tok != Space)
+            .map(|(tok, span)| (tok, span.as_str()))
+            .collect::<Vec<_>>();
+        assert_eq!(tokens, expected);
+
+        let tokens = RustLexer::new("gen dyn Trait dyn::x")
+            .edition(RustEdition::Rust2015)
+            .filter(|(tok, _span)| *tok != Space)
+            .map(|(tok, span)| (tok, span.as_str()))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            tokens,
+            [
+                (Ident, "gen"),
+                (Keyword, "dyn"),
+                (Ident, "Trait"),
+                (Ident, "dyn"),
+                (Punct, "::"),
+                (Ident, "x"),
+            ]
+        );
+
+        let (tok, _span) = RustLexer::new("gen")
+            .edition(RustEdition::Rust2024)
+            .next()
+            .unwrap();
+        assert_eq!(tok, Keyword);
+    }
 }
@@ -1,6 +1,9 @@
 use text_scanner::{ext::ScssScannerExt, Scanner};
 
-use crate::{impl_lexer_from_scanner, CssToken, ScanToken, ScannerExt, TokenSpan};
+use crate::{
+    impl_lexer_from_scanner, impl_token_kind_sets, impl_token_names, CssToken, ScanToken,
+    ScannerExt, TokenSpan,
+};
 
 #[derive(PartialEq, Eq, Clone, Copy, Debug)]
 pub enum ScssToken {
@@ -9,9 +12,23 @@ pub enum ScssToken {
     BlockComment,
     Ident,
     AtKeyword,
+    /// Name of a mixin, following `@mixin` or `@include`.
+    MixinName,
+    /// Placeholder selector, e.g. `%foo`.
+    Placeholder,
+    /// Interpolation, e.g. `#{$foo}`.
+    Interpolation,
     Hash,
     String,
     Number,
+    /// Attribute selector, e.g. `[data-x="y"]`.
+    AttrSelector,
+    /// Pseudo-class in a selector, e.g. `:hover` and `:nth-child`.
+    PseudoClass,
+    /// Pseudo-element in a selector, e.g. `::before`.
+    PseudoElement,
+    /// Combinator in a selector, i.e. `>`, `+`, `~`, and `||`.
+    Combinator,
     /// Punctuation e.g. `:`, `,`.
     Punct,
     /// Delimiter e.g. `{`, `}`, `[`, and `]`.
@@ -24,21 +41,62 @@ pub enum ScssToken {
     Unknown,
 }
 
+impl_token_names!(ScssToken {
+    Space => "space",
+    LineComment => "line_comment",
+    BlockComment => "block_comment",
+    Ident => "ident",
+    AtKeyword => "at_keyword",
+    MixinName => "mixin_name",
+    Placeholder => "placeholder",
+    Interpolation => "interpolation",
+    Hash => "hash",
+    String => "string",
+    Number => "number",
+    AttrSelector => "attr_selector",
+    PseudoClass => "pseudo_class",
+    PseudoElement => "pseudo_element",
+    Combinator => "combinator",
+    Punct => "punct",
+    Delim => "delim",
+    Unknown => "unknown",
+});
+
+impl_token_kind_sets!(ScssToken {
+    /// Whitespace and comments.
+    TRIVIA => [Space, LineComment, BlockComment],
+    /// String and number literals.
+    LITERALS => [String, Number],
+    /// Parts of selectors, which are not identifiers or hashes.
+    SELECTORS => [AttrSelector, PseudoClass, PseudoElement, Combinator],
+});
+
 impl ScanToken for ScssToken {
     fn scan_token<'text>(scanner: &mut Scanner<'text>) -> Option<(Self, TokenSpan<'text>)> {
         if let Ok((r, _s)) = scanner.scan_scss_line_comment() {
             return Some((Self::LineComment, scanner.span(r)));
+        } else if let Ok((r, _s)) = scanner.scan_scss_interpolation() {
+            return Some((Self::Interpolation, scanner.span(r)));
+        } else if let Ok((r, _s)) = scanner.scan_scss_placeholder() {
+            return Some((Self::Placeholder, scanner.span(r)));
         }
 
+        let before = &scanner.text()[..scanner.cursor_pos()];
+
         let (tok, span) = CssToken::scan_token(scanner)?;
         match tok {
             CssToken::Space => Some((ScssToken::Space, span)),
             CssToken::BlockComment => Some((ScssToken::BlockComment, span)),
+            CssToken::Ident if is_after_mixin_keyword(before) => Some((ScssToken::MixinName, span)),
             CssToken::Ident => Some((ScssToken::Ident, span)),
             CssToken::AtKeyword => Some((ScssToken::AtKeyword, span)),
             CssToken::Hash => Some((ScssToken::Hash, span)),
             CssToken::String => Some((ScssToken::String, span)),
             CssToken::Number => Some((ScssToken::Number, span)),
+            CssToken::AttrSelector => Some((ScssToken::AttrSelector, span)),
+            CssToken::PseudoClass => Some((ScssToken::PseudoClass, span)),
+            CssToken::PseudoElement => Some((ScssToken::PseudoElement, span)),
+            CssToken::Combinator => Some((ScssToken::Combinator, span)),
             CssToken::Punct => Some((ScssToken::Punct, span)),
             CssToken::Delim => Some((ScssToken::Delim, span)),
             CssToken::Unknown => Some((ScssToken::Unknown, span)),
@@ -46,6 +104,19 @@ impl ScanToken for ScssToken {
     }
 }
 
+/// Returns `true` if `before` ends with `@mixin` or `@include`,
+/// followed by whitespace.
+fn is_after_mixin_keyword(before: &str) -> bool {
+    let trimmed = before.trim_end();
+    if trimmed.len() == before.len() {
+        return false;
+    }
+
+    ["@mixin", "@include"]
+        .iter()
+        .any(|keyword| trimmed.ends_with(keyword))
+}
+
 /// SCSS lexer producing [`ScssToken`]s.
 ///
 /// **Note:** Cloning `ScssLexer` is essentially a copy, as it just contains
@@ -89,4 +160,26 @@ mod tests {
 
         assert_eq!(input, output);
     }
+
+    #[test]
+    fn test_scss_lexer_tokens() {
+        let input = "@mixin foo { %bar { .a-#{$b} { } } } @include foo;";
+        let tokens = ScssLexer::new(input)
+            .filter(|(tok, _span)| *tok != ScssToken::Space)
+            .map(|(tok, span)| (tok, span.as_str()))
+            .collect::<Vec<_>>();
+
+        use ScssToken::*;
+        #[rustfmt::skip]
+        let expected = [
+            (AtKeyword, "@mixin"), (MixinName, "foo"), (Delim, "{"),
+            (Placeholder, "%bar"), (Delim, "{"),
+            (Punct, "."), (Ident, "a-"), (Interpolation, "#{$b}"), (Delim, "{"), (Delim, "}"),
+            (Delim, "}"),
+            (Delim, "}"),
+            (AtKeyword, "@include"), (MixinName, "foo"), (Punct, ";"),
+        ];
+
+        assert_eq!(tokens, expected);
+    }
 }
@@ -0,0 +1,197 @@
+use text_scanner::{ext::StylusScannerExt, Scanner};
+
+use crate::{
+    impl_lexer_from_scanner, impl_token_kind_sets, impl_token_names, CssToken, ScanToken,
+    ScannerExt, TokenSpan,
+};
+
+// Operators and selector combinators, which are not valid in plain CSS
+const PUNCTUATIONS: [char; 11] = ['&', '>', '<', '~', '/', '?', '|', '^', '$', '\\', '`'];
+
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+pub enum StylusToken {
+    /// Whitespace, which is not [`Indent`](Self::Indent).
+    Space,
+    /// Leading whitespace of a line, which determines the nesting of
+    /// blocks in Stylus, as braces are optional.
+    Indent,
+    LineComment,
+    BlockComment,
+    Ident,
+    AtKeyword,
+    /// `$`-prefixed variable, e.g. `$color`.
+    Variable,
+    Hash,
+    String,
+    Number,
+    /// Attribute selector, e.g. `[data-x="y"]`.
+    AttrSelector,
+    /// Pseudo-class in a selector, e.g. `:hover` and `:nth-child`.
+    PseudoClass,
+    /// Pseudo-element in a selector, e.g. `::before`.
+    PseudoElement,
+    /// Combinator `+` in a selector, where the combinators `>`, `~`,
+    /// and `||` are produced as [`Punct`](Self::Punct).
+    Combinator,
+    /// Punctuation e.g. `:`, `,`, `&`, `>`.
+    Punct,
+    /// Delimiter e.g. `{`, `}`, `[`, and `]`.
+    Delim,
+    /// Given valid Stylus, then this variant should never be encountered. If
+    /// is is encountered, then check if an issue has already been submitted,
+    /// otherwise please [submit an issue].
+    ///
+    /// [submit an issue]: https://github.com/vallentin/colorblast/issues
+    Unknown,
+}
+
+impl_token_names!(StylusToken {
+    Space => "space",
+    Indent => "indent",
+    LineComment => "line_comment",
+    BlockComment => "block_comment",
+    Ident => "ident",
+    AtKeyword => "at_keyword",
+    Variable => "variable",
+    Hash => "hash",
+    String => "string",
+    Number => "number",
+    AttrSelector => "attr_selector",
+    PseudoClass => "pseudo_class",
+    PseudoElement => "pseudo_element",
+    Combinator => "combinator",
+    Punct => "punct",
+    Delim => "delim",
+    Unknown => "unknown",
+});
+
+impl_token_kind_sets!(StylusToken {
+    /// Whitespace and comments, excluding the significant [`Indent`](Self::Indent).
+    TRIVIA => [Space, LineComment, BlockComment],
+    /// String and number literals.
+    LITERALS => [String, Number],
+    /// Parts of selectors, which are not identifiers or hashes.
+    SELECTORS => [AttrSelector, PseudoClass, PseudoElement, Combinator],
+});
+
+impl ScanToken for StylusToken {
+    fn scan_token<'text>(scanner: &mut Scanner<'text>) -> Option<(Self, TokenSpan<'text>)> {
+        let start = scanner.cursor_pos();
+        let is_line_start = scanner.text()[..start].ends_with('\n') || (start == 0);
+
+        if is_line_start {
+            let (r, _s) = scanner.skip_while_char_any(&[' ', '\t']);
+            if !r.is_empty() {
+                return Some((Self::Indent, scanner.span(r)));
+            }
+        }
+
+        // Whitespace is split after newlines, such that
+        // the indentation of the next line is separate
+        scanner.skip_whitespace_no_newline();
+        _ = scanner.next_line_terminator();
+        if scanner.cursor_pos() != start {
+            return Some((Self::Space, scanner.span(start..scanner.cursor_pos())));
+        }
+
+        if let Ok((r, _s)) = scanner.scan_stylus_line_comment() {
+            return Some((Self::LineComment, scanner.span(r)));
+        } else if let Ok((r, _s)) = scanner.scan_stylus_block_comment() {
+            return Some((Self::BlockComment, scanner.span(r)));
+        } else if let Ok((r, _s)) = scanner.scan_stylus_variable() {
+            return Some((Self::Variable, scanner.span(r)));
+        }
+
+        if let Ok((r, _c)) = scanner.accept_char_any(&PUNCTUATIONS) {
+            return Some((Self::Punct, scanner.span(r)));
+        }
+
+        let (tok, span) = CssToken::scan_token(scanner)?;
+        match tok {
+            CssToken::Space => Some((StylusToken::Space, span)),
+            CssToken::BlockComment => Some((StylusToken::BlockComment, span)),
+            CssToken::Ident => Some((StylusToken::Ident, span)),
+            CssToken::AtKeyword => Some((StylusToken::AtKeyword, span)),
+            CssToken::Hash => Some((StylusToken::Hash, span)),
+            CssToken::String => Some((StylusToken::String, span)),
+            CssToken::Number => Some((StylusToken::Number, span)),
+            CssToken::AttrSelector => Some((StylusToken::AttrSelector, span)),
+            CssToken::PseudoClass => Some((StylusToken::PseudoClass, span)),
+            CssToken::PseudoElement => Some((StylusToken::PseudoElement, span)),
+            CssToken::Combinator => Some((StylusToken::Combinator, span)),
+            CssToken::Punct => Some((StylusToken::Punct, span)),
+            CssToken::Delim => Some((StylusToken::Delim, span)),
+            CssToken::Unknown => Some((StylusToken::Unknown, span)),
+        }
+    }
+}
+
+/// [Stylus] lexer producing [`StylusToken`]s.
+///
+/// Braces, colons, and semicolons are optional in Stylus, and are
+/// produced as [`StylusToken::Delim`] and [`StylusToken::Punct`]
+/// when present. Use [`StylusToken::Indent`] to determine nesting.
+///
+/// **Note:** Cloning `StylusLexer` is essentially a copy, as it just contains
+/// a `&str` and a `usize` for its `cursor`. However, `Copy` is not
+/// implemented, to avoid accidentally copying immutable `StylusLexer`s.
+///
+/// See also [`CssLexer`].
+///
+/// [Stylus]: https://stylus-lang.com
+/// [`CssLexer`]: super::CssLexer
+#[derive(Clone, Debug)]
+pub struct StylusLexer<'text> {
+    scanner: Scanner<'text>,
+}
+
+impl<'text> StylusLexer<'text> {
+    #[inline]
+    pub fn new(text: &'text str) -> Self {
+        Self {
+            scanner: Scanner::new(text),
+        }
+    }
+}
+
+impl_lexer_from_scanner!('text, StylusLexer<'text>, StylusToken, scanner);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_stylus_lexer_spans() {
+        // This intentionally uses Rust code as input, as it is
+        // only testing that StylusLexer returns all characters
+        let input = include_str!("../../../text-scanner/src/ext/rust.rs");
+        let mut output = String::new();
+
+        let lexer = StylusLexer::new(input);
+        for (_tok, span) in lexer {
+            output.push_str(span.as_str());
+        }
+
+        assert_eq!(input, output);
+    }
+
+    #[test]
+    fn test_stylus_lexer_indent() {
+        let input = "body\n  color red\n\n  &:hover\n    color $c\n";
+        let tokens = StylusLexer::new(input)
+            .map(|(tok, span)| (tok, span.as_str()))
+            .collect::<Vec<_>>();
+
+        use StylusToken::*;
+        #[rustfmt::skip]
+        let expected = [
+            (Ident, "body"), (Space, "\n"),
+            (Indent, "  "), (Ident, "color"), (Space, " "), (Ident, "red"), (Space, "\n"),
+            (Space, "\n"),
+            (Indent, "  "), (Punct, "&"), (Punct, ":"), (Ident, "hover"), (Space, "\n"),
+            (Indent, "    "), (Ident, "color"), (Space, " "), (Variable, "$c"), (Space, "\n"),
+        ];
+
+        assert_eq!(tokens, expected);
+    }
+}
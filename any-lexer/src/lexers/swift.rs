@@ -1,7 +1,10 @@
 use text_scanner::ext::{SwiftScannerExt, SwiftStrExt};
 use text_scanner::Scanner;
 
-use crate::{impl_lexer_from_scanner, ScanToken, ScannerExt, TokenSpan};
+use crate::{
+    impl_lexer_from_scanner, impl_token_kind_sets, impl_token_names, ScanToken, ScannerExt,
+    TokenSpan,
+};
 
 #[derive(PartialEq, Eq, Clone, Copy, Debug)]
 pub enum SwiftToken {
@@ -27,6 +30,31 @@ pub enum SwiftToken {
     Unknown,
 }
 
+impl_token_names!(SwiftToken {
+    Space => "space",
+    LineComment => "line_comment",
+    BlockComment => "block_comment",
+    Ident => "ident",
+    AttribName => "attrib_name",
+    Keyword => "keyword",
+    Punct => "punct",
+    Delim => "delim",
+    Nil => "nil",
+    Boolean => "boolean",
+    Int => "int",
+    Float => "float",
+    String => "string",
+    Regex => "regex",
+    Unknown => "unknown",
+});
+
+impl_token_kind_sets!(SwiftToken {
+    /// Whitespace and comments.
+    TRIVIA => [Space, LineComment, BlockComment],
+    /// `nil`, boolean, number, string, and regex literals.
+    LITERALS => [Nil, Boolean, Int, Float, String, Regex],
+});
+
 impl ScanToken for SwiftToken {
     fn scan_token<'text>(scanner: &mut Scanner<'text>) -> Option<(Self, TokenSpan<'text>)> {
         let (r, _s) = scanner.skip_whitespace();
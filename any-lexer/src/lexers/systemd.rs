@@ -0,0 +1,166 @@
+use text_scanner::ext::SystemdScannerExt;
+use text_scanner::Scanner;
+
+use crate::{
+    impl_lexer_from_scanner, impl_token_kind_sets, impl_token_names, ScanToken, ScannerExt,
+    TokenSpan,
+};
+
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+pub enum SystemdToken {
+    Space,
+    LineComment,
+    /// Section header, e.g. `[Service]`.
+    Section,
+    /// Key at the start of a line, e.g. `ExecStart`.
+    Key,
+    /// Assignment, i.e. `=` following a key.
+    Equals,
+    /// Value text, e.g. `/usr/bin/app` and `multi-user.target`.
+    Value,
+    /// Specifier, e.g. `%i` and `%n`.
+    Specifier,
+    /// Environment variable substitution, e.g. `$MAINPID` and `${OPTIONS}`.
+    Var,
+    /// Line continuation, i.e. `\` at the end of a line.
+    Continuation,
+    /// Given a valid unit file, then this variant should never be
+    /// encountered. If is is encountered, then check if an issue has already
+    /// been submitted, otherwise please [submit an issue].
+    ///
+    /// [submit an issue]: https://github.com/vallentin/colorblast/issues
+    Unknown,
+}
+
+impl_token_names!(SystemdToken {
+    Space => "space",
+    LineComment => "line_comment",
+    Section => "section",
+    Key => "key",
+    Equals => "equals",
+    Value => "value",
+    Specifier => "specifier",
+    Var => "var",
+    Continuation => "continuation",
+    Unknown => "unknown",
+});
+
+impl_token_kind_sets!(SystemdToken {
+    /// Whitespace and comments.
+    TRIVIA => [Space, LineComment],
+});
+
+impl ScanToken for SystemdToken {
+    fn scan_token<'text>(scanner: &mut Scanner<'text>) -> Option<(Self, TokenSpan<'text>)> {
+        let (r, _s) = scanner.skip_whitespace();
+        if !r.is_empty() {
+            return Some((Self::Space, scanner.span(r)));
+        }
+
+        if scanner.is_line_start() && !scanner.follows_continuation() {
+            if let Ok((r, _s)) = scanner.scan_systemd_line_comment() {
+                return Some((Self::LineComment, scanner.span(r)));
+            } else if let Ok((r, _s)) = scanner.scan_systemd_section() {
+                return Some((Self::Section, scanner.span(r)));
+            } else if let Ok((r, _s)) = scanner.scan_systemd_key() {
+                return Some((Self::Key, scanner.span(r)));
+            }
+        }
+
+        if scanner.remaining_text().starts_with('=') && follows_key(scanner) {
+            let (r, _c) = scanner.next().ok()?;
+            return Some((Self::Equals, scanner.span(r)));
+        }
+
+        if let Ok((r, _s)) = scanner.scan_systemd_specifier() {
+            return Some((Self::Specifier, scanner.span(r)));
+        } else if let Ok((r, _s)) = scanner.scan_systemd_variable() {
+            return Some((Self::Var, scanner.span(r)));
+        } else if let Ok((r, _s)) = scanner.scan_systemd_continuation() {
+            return Some((Self::Continuation, scanner.span(r)));
+        } else if let Ok((r, _s)) = scanner.scan_systemd_value() {
+            return Some((Self::Value, scanner.span(r)));
+        }
+
+        let (r, _c) = scanner.next().ok()?;
+        Some((Self::Unknown, scanner.span(r)))
+    }
+}
+
+/// Returns `true` if only a key surrounded by spaces and tabs is between
+/// the cursor and the start of the line, e.g. `ExecStart `.
+fn follows_key(scanner: &Scanner<'_>) -> bool {
+    let before = &scanner.text()[..scanner.cursor_pos()];
+    let line = before.rsplit(['\n', '\r']).next().unwrap_or_default();
+    let key = line.trim();
+    !key.is_empty()
+        && key.starts_with(|c: char| c.is_ascii_alphanumeric())
+        && key
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | '.'))
+}
+
+/// systemd unit file lexer producing [`SystemdToken`]s.
+///
+/// **Note:** Cloning `SystemdLexer` is essentially a copy, as it just contains
+/// a `&str` and a `usize` for its `cursor`. However, `Copy` is not
+/// implemented, to avoid accidentally copying immutable `SystemdLexer`s.
+#[derive(Clone, Debug)]
+pub struct SystemdLexer<'text> {
+    scanner: Scanner<'text>,
+}
+
+impl<'text> SystemdLexer<'text> {
+    #[inline]
+    pub fn new(text: &'text str) -> Self {
+        Self {
+            scanner: Scanner::new(text),
+        }
+    }
+}
+
+impl_lexer_from_scanner!('text, SystemdLexer<'text>, SystemdToken, scanner);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_systemd_lexer_spans() {
+        // This intentionally uses Rust code as input, as it is
+        // only testing that SystemdLexer returns all characters
+        let input = include_str!("../../../text-scanner/src/ext/rust.rs");
+        let mut output = String::new();
+
+        let lexer = SystemdLexer::new(input);
+        for (_tok, span) in lexer {
+            output.push_str(span.as_str());
+        }
+
+        assert_eq!(input, output);
+    }
+
+    #[test]
+    fn test_systemd_lexer_tokens() {
+        let input = "; c\n[Service]\nNoKey\nExecStart=/usr/bin/app --name=%i $OPTS \\\n  Key=x\n";
+        let tokens = SystemdLexer::new(input)
+            .filter(|(tok, _span)| *tok != SystemdToken::Space)
+            .map(|(tok, span)| (tok, span.as_str()))
+            .collect::<Vec<_>>();
+
+        use SystemdToken::*;
+        #[rustfmt::skip]
+        let expected = [
+            (LineComment, "; c"),
+            (Section, "[Service]"),
+            // Keys must be followed by `=`
+            (Value, "NoKey"),
+            (Key, "ExecStart"), (Equals, "="), (Value, "/usr/bin/app"),
+            (Value, "--name="), (Specifier, "%i"), (Var, "$OPTS"), (Continuation, "\\"),
+            // Continued lines do not start with a key
+            (Value, "Key=x"),
+        ];
+
+        assert_eq!(tokens, expected);
+    }
+}
@@ -0,0 +1,385 @@
+use text_scanner::ext::TomlScannerExt;
+use text_scanner::Scanner;
+
+use crate::{
+    impl_from_str_for_lexer, impl_iter_for_lexer, impl_token_kind_sets, impl_token_names,
+    ScanToken, ScannerExt, TokenSpan,
+};
+
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+pub enum TomlToken {
+    Space,
+    LineComment,
+    /// Table header, e.g. `[package]` and `[dependencies.serde]`.
+    Table,
+    /// Array of tables header, e.g. `[[bin]]`.
+    ArrayTable,
+    /// Key, including any dotted keys, e.g. `name`, `"127.0.0.1"`,
+    /// and `serde.version`.
+    Key,
+    /// Basic or literal string, which is either single-line or multi-line.
+    String,
+    Integer,
+    Float,
+    Boolean,
+    /// Offset date-time, local date-time, local date, or local time,
+    /// e.g. `1979-05-27T07:32:00Z` and `07:32:00`.
+    Datetime,
+    /// Delimiter, i.e. `[`, `]`, `{`, and `}` of arrays and inline tables.
+    Delim,
+    /// Punctuation, i.e. `=` and `,`.
+    Punct,
+    /// Given valid TOML, then this variant should never be encountered. If
+    /// is is encountered, then check if an issue has already been submitted,
+    /// otherwise please [submit an issue].
+    ///
+    /// [submit an issue]: https://github.com/vallentin/colorblast/issues
+    Unknown,
+}
+
+impl_token_names!(TomlToken {
+    Space => "space",
+    LineComment => "line_comment",
+    Table => "table",
+    ArrayTable => "array_table",
+    Key => "key",
+    String => "string",
+    Integer => "integer",
+    Float => "float",
+    Boolean => "boolean",
+    Datetime => "datetime",
+    Delim => "delim",
+    Punct => "punct",
+    Unknown => "unknown",
+});
+
+impl_token_kind_sets!(TomlToken {
+    /// Whitespace and comments.
+    TRIVIA => [Space, LineComment],
+    /// Values, excluding arrays and inline tables.
+    VALUES => [String, Integer, Float, Boolean, Datetime],
+});
+
+impl ScanToken for TomlToken {
+    /// Scans a token outside of arrays and inline tables.
+    ///
+    /// **Note:** TOML tokenization depends on whether the scanner is inside
+    /// an array or an inline table, so use [`TomlLexer`] to tokenize complete
+    /// TOML documents.
+    fn scan_token<'text>(scanner: &mut Scanner<'text>) -> Option<(Self, TokenSpan<'text>)> {
+        let mut state = State::default();
+        scan_token(scanner, &mut state)
+    }
+}
+
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+enum Nesting {
+    /// Inside `[ ... ]` of an array value.
+    Array,
+    /// Inside `{ ... }` of an inline table.
+    InlineTable,
+}
+
+/// Maximum nesting depth of arrays and inline tables, which is tracked
+/// exactly. Anything nested deeper is assumed to be an array.
+const MAX_NESTING: u32 = u64::BITS;
+
+#[derive(Clone, Copy, Default, Debug)]
+struct State {
+    /// Bit `n` is set if the container at depth `n` is an inline table,
+    /// otherwise it is an array.
+    nesting: u64,
+    depth: u32,
+    /// Whether a key is expected inside an inline table, i.e. after
+    /// `{` or `,`.
+    expect_key: bool,
+}
+
+impl State {
+    fn innermost(&self) -> Option<Nesting> {
+        let n = self.depth.checked_sub(1)?;
+        if (n < MAX_NESTING) && ((self.nesting >> n) & 1 == 1) {
+            Some(Nesting::InlineTable)
+        } else {
+            Some(Nesting::Array)
+        }
+    }
+
+    fn push(&mut self, nesting: Nesting) {
+        if self.depth < MAX_NESTING {
+            let bit = 1 << self.depth;
+            match nesting {
+                Nesting::Array => self.nesting &= !bit,
+                Nesting::InlineTable => self.nesting |= bit,
+            }
+        }
+        self.depth = self.depth.saturating_add(1);
+    }
+
+    /// Closes the innermost container, if it is `nesting`, or if it is
+    /// nested too deep to be known.
+    fn pop(&mut self, nesting: Nesting) {
+        if (self.depth > MAX_NESTING) || (self.innermost() == Some(nesting)) {
+            self.depth -= 1;
+        }
+    }
+
+    fn is_key_expected(&self, scanner: &Scanner<'_>) -> bool {
+        match self.innermost() {
+            None => scanner.is_line_start(),
+            Some(Nesting::InlineTable) => self.expect_key,
+            Some(Nesting::Array) => false,
+        }
+    }
+}
+
+fn scan_token<'text>(
+    scanner: &mut Scanner<'text>,
+    state: &mut State,
+) -> Option<(TomlToken, TokenSpan<'text>)> {
+    let (r, _s) = scanner.skip_whitespace();
+    if !r.is_empty() {
+        return Some((TomlToken::Space, scanner.span(r)));
+    }
+
+    if let Ok((r, _s)) = scanner.scan_toml_line_comment() {
+        return Some((TomlToken::LineComment, scanner.span(r)));
+    }
+
+    let key_expected = state.is_key_expected(scanner);
+    if key_expected {
+        if state.depth == 0 {
+            if let Ok((r, s)) = scanner.scan_toml_table_header() {
+                let tok = match s.starts_with("[[") {
+                    true => TomlToken::ArrayTable,
+                    false => TomlToken::Table,
+                };
+                return Some((tok, scanner.span(r)));
+            }
+        }
+
+        if let Ok((r, _s)) = scanner.scan_toml_key() {
+            state.expect_key = false;
+            return Some((TomlToken::Key, scanner.span(r)));
+        }
+    } else if let Some(tok) = scan_value(scanner) {
+        return Some(tok);
+    }
+
+    let (r, c) = scanner.next().ok()?;
+    let tok = match c {
+        '=' => TomlToken::Punct,
+        ',' => {
+            state.expect_key = state.innermost() == Some(Nesting::InlineTable);
+            TomlToken::Punct
+        }
+        '{' => {
+            state.push(Nesting::InlineTable);
+            state.expect_key = true;
+            TomlToken::Delim
+        }
+        '[' if !key_expected => {
+            state.push(Nesting::Array);
+            TomlToken::Delim
+        }
+        '}' | ']' => {
+            state.pop(match c {
+                '}' => Nesting::InlineTable,
+                _ => Nesting::Array,
+            });
+            state.expect_key = false;
+            TomlToken::Delim
+        }
+        _ => TomlToken::Unknown,
+    };
+    Some((tok, scanner.span(r)))
+}
+
+fn scan_value<'text>(scanner: &mut Scanner<'text>) -> Option<(TomlToken, TokenSpan<'text>)> {
+    // Dates and times must be scanned before numbers,
+    // and floats must be scanned before integers
+    if let Ok((r, _s)) = scanner.scan_toml_string() {
+        return Some((TomlToken::String, scanner.span(r)));
+    } else if let Ok((r, _s)) = scanner.scan_toml_datetime() {
+        return Some((TomlToken::Datetime, scanner.span(r)));
+    } else if let Ok((r, _s)) = scanner.scan_toml_float() {
+        return Some((TomlToken::Float, scanner.span(r)));
+    } else if let Ok((r, _s)) = scanner.scan_toml_integer() {
+        return Some((TomlToken::Integer, scanner.span(r)));
+    } else if let Ok((r, _s)) = scanner.scan_toml_boolean() {
+        return Some((TomlToken::Boolean, scanner.span(r)));
+    }
+    None
+}
+
+/// TOML lexer producing [`TomlToken`]s.
+///
+/// Keys are produced including any dotted keys, e.g. `serde.version`,
+/// and table headers are produced including the brackets, e.g. `[package]`,
+/// see [`TomlScannerExt::scan_toml_key()`].
+///
+/// **Note:** As opposed to most other lexers, `TomlLexer` keeps track of
+/// whether it is inside arrays and inline tables, to be able to tell keys
+/// and values apart, e.g. `true` is a valid key. So [`set_cursor_pos()`]
+/// should only be used to restore a previous position of the same state.
+///
+/// Cloning `TomlLexer` is essentially a copy, as it just contains a `&str`,
+/// a `usize` for its `cursor`, and its state. However, `Copy` is not
+/// implemented, to avoid accidentally copying immutable `TomlLexer`s.
+///
+/// # Example
+///
+/// ```rust
+/// use any_lexer::{TomlLexer, TomlToken};
+///
+/// let toml = "[package]\nname = \"colorblast\"\n[dependencies]\nserde = { version = \"1\" }";
+/// let tokens = TomlLexer::new(toml)
+///     .filter(|(tok, _span)| *tok != TomlToken::Space)
+///     .map(|(tok, span)| (tok, span.as_str()))
+///     .collect::<Vec<_>>();
+///
+/// use TomlToken::*;
+/// assert_eq!(
+///     tokens,
+///     [
+///         (Table, "[package]"),
+///         (Key, "name"), (Punct, "="), (String, "\"colorblast\""),
+///         (Table, "[dependencies]"),
+///         (Key, "serde"), (Punct, "="),
+///         (Delim, "{"), (Key, "version"), (Punct, "="), (String, "\"1\""), (Delim, "}"),
+///     ]
+/// );
+/// ```
+///
+/// [`set_cursor_pos()`]: crate::Lexer::set_cursor_pos
+/// [`TomlScannerExt::scan_toml_key()`]: text_scanner::ext::TomlScannerExt::scan_toml_key
+#[derive(Clone, Debug)]
+pub struct TomlLexer<'text> {
+    scanner: Scanner<'text>,
+    state: State,
+}
+
+impl<'text> TomlLexer<'text> {
+    #[inline]
+    pub fn new(text: &'text str) -> Self {
+        Self {
+            scanner: Scanner::new(text),
+            state: State::default(),
+        }
+    }
+}
+
+impl<'text> crate::Lexer<'text> for TomlLexer<'text> {
+    type Token = TomlToken;
+
+    #[inline]
+    fn next_token(&mut self) -> Option<(Self::Token, TokenSpan<'text>)> {
+        scan_token(&mut self.scanner, &mut self.state)
+    }
+
+    #[inline]
+    fn peek_token(&self) -> Option<(Self::Token, TokenSpan<'text>)> {
+        self.clone().next_token()
+    }
+
+    #[inline]
+    fn reset(&mut self) -> usize {
+        self.state = State::default();
+        self.set_cursor_pos(0)
+    }
+
+    #[inline]
+    fn scanner(&self) -> &Scanner<'text> {
+        &self.scanner
+    }
+
+    #[inline]
+    fn scanner_mut(&mut self) -> &mut Scanner<'text> {
+        &mut self.scanner
+    }
+}
+
+impl_iter_for_lexer!('text, TomlLexer<'text>);
+impl_from_str_for_lexer!('text, TomlLexer<'text>);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_toml_lexer_spans() {
+        // This intentionally uses Rust code as input, as it is
+        // only testing that TomlLexer returns all characters
+        let input = include_str!("../../../text-scanner/src/ext/rust.rs");
+        let mut output = String::new();
+
+        let lexer = TomlLexer::new(input);
+        for (_tok, span) in lexer {
+            output.push_str(span.as_str());
+        }
+
+        assert_eq!(input, output);
+    }
+
+    #[test]
+    fn test_toml_lexer_tokens() {
+        let input = r#"# c
+[[bin]]
+true = false
+a.b = [1, 2.0, [0x1F], "s", 1979-05-27]
+t = { x = 1, "y" = 07:32:00, z = { } }
+s = """
+[not.a.table]
+"""
+[ a . 'b' ]"#;
+        let tokens = TomlLexer::new(input)
+            .filter(|(tok, _span)| *tok != TomlToken::Space)
+            .map(|(tok, span)| (tok, span.as_str()))
+            .collect::<Vec<_>>();
+
+        use TomlToken::*;
+        #[rustfmt::skip]
+        let expected = [
+            (LineComment, "# c"),
+            (ArrayTable, "[[bin]]"),
+            (Key, "true"), (Punct, "="), (Boolean, "false"),
+            (Key, "a.b"), (Punct, "="),
+            (Delim, "["), (Integer, "1"), (Punct, ","), (Float, "2.0"), (Punct, ","),
+            (Delim, "["), (Integer, "0x1F"), (Delim, "]"), (Punct, ","),
+            (String, "\"s\""), (Punct, ","), (Datetime, "1979-05-27"), (Delim, "]"),
+            (Key, "t"), (Punct, "="),
+            (Delim, "{"), (Key, "x"), (Punct, "="), (Integer, "1"), (Punct, ","),
+            (Key, "\"y\""), (Punct, "="), (Datetime, "07:32:00"), (Punct, ","),
+            (Key, "z"), (Punct, "="), (Delim, "{"), (Delim, "}"), (Delim, "}"),
+            (Key, "s"), (Punct, "="), (String, "\"\"\"\n[not.a.table]\n\"\"\""),
+            (Table, "[ a . 'b' ]"),
+        ];
+
+        assert_eq!(tokens, expected);
+    }
+
+    #[test]
+    fn test_toml_lexer_deep_nesting() {
+        let depth = (MAX_NESTING as usize) + 8;
+        let input = format!(
+            "a = {}{{ b = 1 }}{}\nc = 2",
+            "[".repeat(depth),
+            "]".repeat(depth),
+        );
+        let tokens = TomlLexer::new(&input)
+            .filter(|(tok, _span)| !matches!(tok, TomlToken::Space | TomlToken::Delim))
+            .map(|(tok, span)| (tok, span.as_str()))
+            .collect::<Vec<_>>();
+
+        use TomlToken::*;
+        #[rustfmt::skip]
+        let expected = [
+            (Key, "a"), (Punct, "="),
+            // Inline tables nested too deep are assumed to be arrays
+            (Unknown, "b"), (Punct, "="), (Integer, "1"),
+            (Key, "c"), (Punct, "="), (Integer, "2"),
+        ];
+
+        assert_eq!(tokens, expected);
+    }
+}
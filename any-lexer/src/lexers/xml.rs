@@ -0,0 +1,353 @@
+use text_scanner::{ext::XmlScannerExt, Scanner, ScannerResult};
+
+use crate::{
+    impl_from_str_for_lexer, impl_iter_for_lexer, impl_token_kind_sets, impl_token_names,
+    ScanToken, ScannerExt, TokenSpan,
+};
+
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+pub enum XmlToken {
+    Space,
+    /// Comment, e.g. `<!-- ... -->`.
+    Comment,
+    /// Processing instruction, e.g. `<?xml version="1.0"?>`.
+    ProcessingInstruction,
+    /// Markup declaration, including any internal subset, e.g.
+    /// `<!DOCTYPE note SYSTEM "note.dtd">` and `<!ENTITY copy "&#169;">`.
+    Doctype,
+    /// CDATA section, e.g. `<![CDATA[ x > y ]]>`.
+    Cdata,
+    /// Tag name, including any namespace prefix, e.g. `svg:rect`
+    /// in `<svg:rect>` and `</svg:rect>`.
+    TagName,
+    /// Attribute name, including any namespace prefix, e.g. `xlink:href`.
+    AttrName,
+    /// Quoted attribute value, i.e. including the quotes.
+    AttrValue,
+    /// Text content, which might contain whitespace.
+    Text,
+    /// Entity or character reference in text content,
+    /// e.g. `&amp;` and `&#x27;`.
+    Entity,
+    /// Punctuation e.g. `<`, `</`, `>`, `/>`, and `=`.
+    Punct,
+    /// Given valid XML, then this variant should never be encountered. If
+    /// is is encountered, then check if an issue has already been submitted,
+    /// otherwise please [submit an issue].
+    ///
+    /// [submit an issue]: https://github.com/vallentin/colorblast/issues
+    Unknown,
+}
+
+impl_token_names!(XmlToken {
+    Space => "space",
+    Comment => "comment",
+    ProcessingInstruction => "processing_instruction",
+    Doctype => "doctype",
+    Cdata => "cdata",
+    TagName => "tag_name",
+    AttrName => "attr_name",
+    AttrValue => "attr_value",
+    Text => "text",
+    Entity => "entity",
+    Punct => "punct",
+    Unknown => "unknown",
+});
+
+impl_token_kind_sets!(XmlToken {
+    /// Whitespace and comments.
+    TRIVIA => [Space, Comment],
+});
+
+impl ScanToken for XmlToken {
+    /// Scans a token outside of tags.
+    ///
+    /// **Note:** XML tokenization depends on whether the scanner is inside
+    /// a tag, so use [`XmlLexer`] to tokenize complete XML documents.
+    fn scan_token<'text>(scanner: &mut Scanner<'text>) -> Option<(Self, TokenSpan<'text>)> {
+        let mut state = State::Text;
+        scan_token(scanner, &mut state)
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+enum State {
+    Text,
+    /// After `<` or `</`, i.e. expecting a tag name.
+    TagName,
+    /// Inside a tag.
+    Tag,
+    /// After `=` inside a tag, i.e. expecting an attribute value.
+    AttrValue,
+}
+
+fn scan_token<'text>(
+    scanner: &mut Scanner<'text>,
+    state: &mut State,
+) -> Option<(XmlToken, TokenSpan<'text>)> {
+    match *state {
+        State::Text => {
+            let (r, _s) = scanner.skip_whitespace();
+            if !r.is_empty() {
+                return Some((XmlToken::Space, scanner.span(r)));
+            }
+
+            if let Ok((r, _s)) = scanner.scan_xml_comment() {
+                return Some((XmlToken::Comment, scanner.span(r)));
+            } else if let Ok((r, _s)) = scanner.scan_xml_cdata() {
+                return Some((XmlToken::Cdata, scanner.span(r)));
+            } else if let Ok((r, _s)) = scanner.scan_xml_processing_instruction() {
+                return Some((XmlToken::ProcessingInstruction, scanner.span(r)));
+            } else if let Ok((r, _s)) = scan_markup_decl(scanner) {
+                return Some((XmlToken::Doctype, scanner.span(r)));
+            } else if let Ok((r, _s)) = scanner.scan_xml_entity() {
+                return Some((XmlToken::Entity, scanner.span(r)));
+            }
+
+            let is_tag_start = |s: &str| {
+                let s = s.strip_prefix('/').unwrap_or(s);
+                Scanner::new(s).scan_xml_qname().is_ok()
+            };
+
+            if let Some(after) = scanner.remaining_text().strip_prefix('<') {
+                if is_tag_start(after) {
+                    let end_tag = after.starts_with('/');
+                    let (r, _s) = scanner.next_str(if end_tag { 2 } else { 1 }).ok()?;
+                    *state = State::TagName;
+                    return Some((XmlToken::Punct, scanner.span(r)));
+                }
+            }
+
+            // Text continues until the next tag, comment, declaration, or
+            // reference, where a lone `<` or `&` is part of the text
+            let start = scanner.cursor_pos();
+            scanner.next().ok()?;
+            loop {
+                scanner.skip_until_char_any(&['<', '&']);
+                let rest = scanner.remaining_text();
+                let after = rest.get(1..).unwrap_or_default();
+                if rest.is_empty()
+                    || (rest.starts_with('<')
+                        && (is_tag_start(after) || after.starts_with(['!', '?'])))
+                    || (rest.starts_with('&') && scanner.clone().scan_xml_entity().is_ok())
+                {
+                    break;
+                }
+                _ = scanner.next();
+            }
+
+            let r = start..scanner.cursor_pos();
+            Some((XmlToken::Text, scanner.span(r)))
+        }
+        State::TagName => {
+            *state = State::Tag;
+
+            if let Ok((r, _s)) = scanner.scan_xml_qname() {
+                return Some((XmlToken::TagName, scanner.span(r)));
+            }
+            scan_token(scanner, state)
+        }
+        State::Tag => {
+            let (r, _s) = scanner.skip_whitespace();
+            if !r.is_empty() {
+                return Some((XmlToken::Space, scanner.span(r)));
+            }
+
+            if let Ok((r, _s)) = scanner.accept_str("/>") {
+                *state = State::Text;
+                return Some((XmlToken::Punct, scanner.span(r)));
+            } else if let Ok((r, _c)) = scanner.accept_char('>') {
+                *state = State::Text;
+                return Some((XmlToken::Punct, scanner.span(r)));
+            } else if let Ok((r, _c)) = scanner.accept_char('=') {
+                *state = State::AttrValue;
+                return Some((XmlToken::Punct, scanner.span(r)));
+            }
+
+            if let Ok((r, _s)) = scanner.scan_xml_qname() {
+                return Some((XmlToken::AttrName, scanner.span(r)));
+            } else if let Ok((r, _s)) = scanner.scan_xml_literal() {
+                return Some((XmlToken::AttrValue, scanner.span(r)));
+            }
+
+            let (r, _c) = scanner.next().ok()?;
+            Some((XmlToken::Unknown, scanner.span(r)))
+        }
+        State::AttrValue => {
+            let (r, _s) = scanner.skip_whitespace();
+            if !r.is_empty() {
+                return Some((XmlToken::Space, scanner.span(r)));
+            }
+
+            *state = State::Tag;
+
+            if let Ok((r, _s)) = scanner.scan_xml_literal() {
+                return Some((XmlToken::AttrValue, scanner.span(r)));
+            }
+            scan_token(scanner, state)
+        }
+    }
+}
+
+/// Scans a markup declaration until its closing `>`, including the
+/// internal subset of a `<!DOCTYPE`, i.e. `[ ... ]`, where any `>` in
+/// literals, comments, and processing instructions is skipped.
+///
+/// If the declaration is unterminated, then it ends at the end of the text.
+///
+/// Reference: <https://www.w3.org/TR/xml/#NT-doctypedecl>
+fn scan_markup_decl<'text>(scanner: &mut Scanner<'text>) -> ScannerResult<'text, &'text str> {
+    scanner.scan_with(|scanner| {
+        scanner.scan_xml_markup_decl_start()?;
+
+        let mut depth = 0usize;
+        loop {
+            scanner.skip_until(|c| matches!(c, '"' | '\'' | '<' | '[' | ']' | '>'));
+
+            if scanner.scan_xml_literal().is_ok()
+                || scanner.scan_xml_comment().is_ok()
+                || scanner.scan_xml_processing_instruction().is_ok()
+            {
+                continue;
+            }
+
+            match scanner.next() {
+                Ok((_r, '[')) => depth += 1,
+                Ok((_r, ']')) => depth = depth.saturating_sub(1),
+                Ok((_r, '>')) if depth == 0 => break,
+                Ok(_) => {}
+                Err(_) => break,
+            }
+        }
+        Ok(())
+    })
+}
+
+/// XML lexer producing [`XmlToken`]s.
+///
+/// Names of tags and attributes are produced including any namespace
+/// prefix, e.g. `xlink:href`, see [`XmlScannerExt::scan_xml_qname()`].
+///
+/// **Note:** As opposed to most other lexers, `XmlLexer` keeps track of
+/// whether it is inside a tag. So [`set_cursor_pos()`] should only be used
+/// to restore a previous position of the same state.
+///
+/// Cloning `XmlLexer` is essentially a copy, as it just contains a `&str`,
+/// a `usize` for its `cursor`, and its state. However, `Copy` is not
+/// implemented, to avoid accidentally copying immutable `XmlLexer`s.
+///
+/// # Example
+///
+/// ```rust
+/// use any_lexer::{XmlLexer, XmlToken};
+///
+/// let xml = r#"<?xml version="1.0"?><svg:a xlink:href="b.svg">&amp;</svg:a>"#;
+/// let tokens = XmlLexer::new(xml)
+///     .map(|(tok, span)| (tok, span.as_str()))
+///     .collect::<Vec<_>>();
+///
+/// use XmlToken::*;
+/// assert_eq!(
+///     tokens,
+///     [
+///         (ProcessingInstruction, r#"<?xml version="1.0"?>"#),
+///         (Punct, "<"), (TagName, "svg:a"), (Space, " "),
+///         (AttrName, "xlink:href"), (Punct, "="), (AttrValue, "\"b.svg\""), (Punct, ">"),
+///         (Entity, "&amp;"),
+///         (Punct, "</"), (TagName, "svg:a"), (Punct, ">"),
+///     ]
+/// );
+/// ```
+///
+/// [`set_cursor_pos()`]: crate::Lexer::set_cursor_pos
+/// [`XmlScannerExt::scan_xml_qname()`]: text_scanner::ext::XmlScannerExt::scan_xml_qname
+#[derive(Clone, Debug)]
+pub struct XmlLexer<'text> {
+    scanner: Scanner<'text>,
+    state: State,
+}
+
+impl<'text> XmlLexer<'text> {
+    #[inline]
+    pub fn new(text: &'text str) -> Self {
+        Self {
+            scanner: Scanner::new(text),
+            state: State::Text,
+        }
+    }
+}
+
+impl<'text> crate::Lexer<'text> for XmlLexer<'text> {
+    type Token = XmlToken;
+
+    #[inline]
+    fn next_token(&mut self) -> Option<(Self::Token, TokenSpan<'text>)> {
+        scan_token(&mut self.scanner, &mut self.state)
+    }
+
+    #[inline]
+    fn peek_token(&self) -> Option<(Self::Token, TokenSpan<'text>)> {
+        self.clone().next_token()
+    }
+
+    #[inline]
+    fn reset(&mut self) -> usize {
+        self.state = State::Text;
+        self.set_cursor_pos(0)
+    }
+
+    #[inline]
+    fn scanner(&self) -> &Scanner<'text> {
+        &self.scanner
+    }
+
+    #[inline]
+    fn scanner_mut(&mut self) -> &mut Scanner<'text> {
+        &mut self.scanner
+    }
+}
+
+impl_iter_for_lexer!('text, XmlLexer<'text>);
+impl_from_str_for_lexer!('text, XmlLexer<'text>);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_xml_lexer_spans() {
+        // This intentionally uses Rust code as input, as it is
+        // only testing that XmlLexer returns all characters
+        let input = include_str!("../../../text-scanner/src/ext/rust.rs");
+        let mut output = String::new();
+
+        let lexer = XmlLexer::new(input);
+        for (_tok, span) in lexer {
+            output.push_str(span.as_str());
+        }
+
+        assert_eq!(input, output);
+    }
+
+    #[test]
+    fn test_xml_lexer_tokens() {
+        let input =
+            r#"<!DOCTYPE n [<!ENTITY e "<>">]><n a = 'x > y'>1 < 2 &e; &<![CDATA[<]]><?pi ?></n>"#;
+        let tokens = XmlLexer::new(input)
+            .map(|(tok, span)| (tok, span.as_str()))
+            .collect::<Vec<_>>();
+
+        use XmlToken::*;
+        #[rustfmt::skip]
+        let expected = [
+            (Doctype, r#"<!DOCTYPE n [<!ENTITY e "<>">]>"#),
+            (Punct, "<"), (TagName, "n"), (Space, " "),
+            (AttrName, "a"), (Space, " "), (Punct, "="), (Space, " "), (AttrValue, "'x > y'"), (Punct, ">"),
+            (Text, "1 < 2 "), (Entity, "&e;"), (Space, " "), (Text, "&"),
+            (Cdata, "<![CDATA[<]]>"), (ProcessingInstruction, "<?pi ?>"),
+            (Punct, "</"), (TagName, "n"), (Punct, ">"),
+        ];
+
+        assert_eq!(tokens, expected);
+    }
+}
@@ -4,27 +4,75 @@
 #![forbid(unsafe_code)]
 #![forbid(elided_lifetimes_in_paths)]
 
+pub mod debug;
 mod lexers;
+pub mod lookup;
+mod resume;
+mod roundtrip;
+pub mod trivia;
 
 pub use text_scanner as scanner;
 
+pub use crate::resume::{LineStates, StateHint};
+pub use crate::roundtrip::{reconstruct, verify_roundtrip, RoundtripError};
+
+// Unused if all `lang-*` features are disabled
+#[allow(unused_imports)]
 pub use self::lexers::*;
 
+use std::cmp::Ordering;
 use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::marker::PhantomData;
 use std::ops::Range;
 
-use text_scanner::Scanner;
+use text_scanner::{ScanOutcome, Scanner};
 
 #[derive(Eq, Clone)]
 pub struct TokenSpan<'text> {
     text: &'text str,
     range: Range<usize>,
+    unterminated: bool,
 }
 
 impl<'text> TokenSpan<'text> {
     #[inline]
     pub fn new(text: &'text str, range: Range<usize>) -> Self {
-        Self { text, range }
+        Self {
+            text,
+            range,
+            unterminated: false,
+        }
+    }
+
+    /// Sets whether the token is unterminated, see [`is_unterminated()`].
+    ///
+    /// [`is_unterminated()`]: Self::is_unterminated
+    #[inline]
+    pub fn with_unterminated(mut self, unterminated: bool) -> Self {
+        self.unterminated = unterminated;
+        self
+    }
+
+    /// Returns `true` if the token is a string or comment, which is
+    /// missing its closing delimiter, and instead ends at the end of
+    /// the text (or line).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use any_lexer::{RustLexer, RustToken};
+    ///
+    /// let code = r#"let s = "Hello World"#;
+    /// let (tok, span) = RustLexer::new(code).last().unwrap();
+    ///
+    /// assert_eq!(tok, RustToken::String);
+    /// assert_eq!(span.as_str(), r#""Hello World"#);
+    /// assert!(span.is_unterminated());
+    /// ```
+    #[inline]
+    pub fn is_unterminated(&self) -> bool {
+        self.unterminated
     }
 
     #[inline]
@@ -72,17 +120,30 @@ impl<'text> TokenSpan<'text> {
     pub fn join_unchecked(&self, other: &Self) -> Self {
         let start = self.range.start.min(other.range.start);
         let end = self.range.end.max(other.range.end);
-        Self::new(self.text, start..end)
+        Self::new(self.text, start..end).with_unterminated(self.unterminated || other.unterminated)
     }
 }
 
+/// Long token text is truncated, unless the alternate
+/// flag is used, i.e. `{:#?}`.
 impl fmt::Debug for TokenSpan<'_> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        f.debug_struct("TokenSpan")
-            .field("start", &self.range.start)
-            .field("end", &self.range.end)
-            .field("string", &self.as_str())
-            .finish()
+        let alternate = f.alternate();
+        let mut s = f.debug_struct("TokenSpan");
+        s.field("start", &self.range.start);
+        s.field("end", &self.range.end);
+        match debug::truncate(self.as_str()) {
+            Some(truncated) if !alternate => {
+                s.field("string", &format_args!("{truncated:?}…"));
+            }
+            _ => {
+                s.field("string", &self.as_str());
+            }
+        }
+        if self.unterminated {
+            s.field("unterminated", &true);
+        }
+        s.finish()
     }
 }
 
@@ -92,12 +153,90 @@ impl fmt::Display for TokenSpan<'_> {
     }
 }
 
+/// Spans are equal if they refer to the same range of the same text,
+/// regardless of [`is_unterminated()`](TokenSpan::is_unterminated).
 impl PartialEq for TokenSpan<'_> {
     fn eq(&self, other: &Self) -> bool {
         (self.text.as_ptr() == other.text.as_ptr()) && (self.range == other.range)
     }
 }
 
+/// Hashes the same parts as [`PartialEq`], i.e. the text and range,
+/// regardless of [`is_unterminated()`](TokenSpan::is_unterminated).
+impl Hash for TokenSpan<'_> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.text.as_ptr().hash(state);
+        self.range.hash(state);
+    }
+}
+
+/// Spans are ordered by range, i.e. by [`start()`] and then by [`end()`],
+/// such that the tokens produced by a lexer are sorted.
+///
+/// Spans of different texts with the same range are ordered by
+/// the address of their text, to remain consistent with [`PartialEq`].
+///
+/// [`start()`]: TokenSpan::start
+/// [`end()`]: TokenSpan::end
+impl Ord for TokenSpan<'_> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        (self.range.start, self.range.end, self.text.as_ptr()).cmp(&(
+            other.range.start,
+            other.range.end,
+            other.text.as_ptr(),
+        ))
+    }
+}
+
+impl PartialOrd for TokenSpan<'_> {
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// Lexer producing tokens of a text, where each token is
+/// a [`Self::Token`] along with its [`TokenSpan`].
+///
+/// # Allocations
+///
+/// Producing tokens with [`next_token()`] does not allocate, as tokens
+/// only refer to the text, and the lexers only keep a fixed amount
+/// of state. So lexers can be used in hot loops, e.g. highlighting
+/// on every keystroke. The exceptions are:
+///
+/// - [`JavaScriptLexer`] allocates when entering nested [JSX] elements
+///   and embedded expressions, to keep track of the nesting
+/// - [`CppLexer`] allocates when encountering inactive regions, if
+///   [`inactive_regions()`] is enabled
+///
+/// # Cloning
+///
+/// All lexers implement [`Clone`], which is essentially a copy, as lexers
+/// only refer to the text. So a lexer can be cloned to look ahead
+/// speculatively, without affecting the original lexer. The exceptions
+/// are the same as for allocations, where cloning clones the nested
+/// [JSX] elements and the upcoming inactive regions.
+///
+/// ```rust
+/// use any_lexer::{Lexer, RustLexer, RustToken};
+///
+/// let mut lexer = RustLexer::new("foo /* Comment */ (1)");
+/// let (_tok, ident) = lexer.next_token().unwrap();
+///
+/// // Check if `foo` is followed by `(`, ignoring trivia
+/// let mut ahead = lexer.clone();
+/// ahead.skip_while_kind(RustToken::TRIVIA);
+/// let is_call = matches!(ahead.next_token(), Some((_tok, span)) if span.as_str() == "(");
+/// assert!(is_call, "{ident}");
+///
+/// // `lexer` is unaffected
+/// assert_eq!(lexer.next_token().unwrap().0, RustToken::Space);
+/// ```
+///
+/// [`next_token()`]: Self::next_token
+/// [JSX]: https://facebook.github.io/jsx/
+/// [`inactive_regions()`]: CppLexer::inactive_regions
 pub trait Lexer<'text> {
     type Token: ScanToken;
 
@@ -126,6 +265,88 @@ pub trait Lexer<'text> {
         self.set_cursor_pos(0)
     }
 
+    /// Creates a lexer for `text`, which resumes lexing at `offset`,
+    /// e.g. to only highlight the visible part of a huge file, without
+    /// lexing all the text before it.
+    ///
+    /// If `offset` is inside a token, e.g. a multiline block comment or
+    /// string, then [`StateHint::Inside`] must be given, otherwise the
+    /// remaining part of the token is lexed as if it was code. In that case,
+    /// lexing resumes at the start of the token, so the first token
+    /// starts before `offset`. The [`StateHint`] is computed from a previous
+    /// run, so the text before `offset` must not have changed since.
+    ///
+    /// **Note:** Lexers with additional state, e.g. [`JavaScriptLexer`]
+    /// inside [JSX], resume in their initial state.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use any_lexer::{Lexer, RustLexer, RustToken, StateHint};
+    ///
+    /// let code = "/* Multiline\n   comment */ fn";
+    ///
+    /// // Previous run, e.g. when the file was opened
+    /// let tokens = RustLexer::new(code).collect::<Vec<_>>();
+    ///
+    /// // Resume at the second line, which is inside the block comment
+    /// let offset = code.find('\n').unwrap() + 1;
+    /// let hint = StateHint::from_tokens(&tokens, offset);
+    /// assert_eq!(hint, StateHint::Inside { start: 0 });
+    ///
+    /// let tokens = RustLexer::resume_at(code, offset, hint)
+    ///     .map(|(tok, span)| (tok, span.as_str()))
+    ///     .collect::<Vec<_>>();
+    /// assert_eq!(
+    ///     tokens,
+    ///     [
+    ///         (RustToken::BlockComment, "/* Multiline\n   comment */"),
+    ///         (RustToken::Space, " "),
+    ///         (RustToken::Keyword, "fn"),
+    ///     ]
+    /// );
+    /// ```
+    ///
+    /// [`JavaScriptLexer`]: crate::JavaScriptLexer
+    /// [JSX]: https://facebook.github.io/jsx/
+    fn resume_at(text: &'text str, offset: usize, hint: StateHint) -> Self
+    where
+        Self: Sized + From<&'text str>,
+    {
+        let mut lexer = Self::from(text);
+        lexer.set_cursor_pos(hint.resume_pos(offset));
+        lexer
+    }
+
+    /// Skips tokens while their kind is contained in `kinds`, and
+    /// returns the amount of skipped tokens.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use any_lexer::{Lexer, RustLexer, RustToken};
+    ///
+    /// let mut lexer = RustLexer::new("  // Comment\nfn");
+    /// assert_eq!(lexer.skip_while_kind(RustToken::TRIVIA), 3);
+    ///
+    /// let (tok, span) = lexer.next_token().unwrap();
+    /// assert_eq!((tok, span.as_str()), (RustToken::Keyword, "fn"));
+    /// ```
+    fn skip_while_kind(&mut self, kinds: TokenKindSet<Self::Token>) -> usize
+    where
+        Self::Token: TokenKind,
+    {
+        let mut count = 0;
+        while let Some((tok, _span)) = self.peek_token() {
+            if !kinds.contains(tok) {
+                break;
+            }
+            _ = self.next_token();
+            count += 1;
+        }
+        count
+    }
+
     fn scanner(&self) -> &Scanner<'text>;
     fn scanner_mut(&mut self) -> &mut Scanner<'text>;
 }
@@ -134,6 +355,166 @@ pub trait ScanToken: Sized {
     fn scan_token<'text>(scanner: &mut Scanner<'text>) -> Option<(Self, TokenSpan<'text>)>;
 }
 
+/// Token kind which can be contained in a [`TokenKindSet`].
+///
+/// Implemented for all token types in this crate.
+pub trait TokenKind: Copy {
+    /// Returns the index of this token kind, which must be unique
+    /// for each token kind and less than 64.
+    fn index(self) -> u32;
+}
+
+/// Set of token kinds, implemented as a bitset for fast
+/// [`contains()`](Self::contains).
+///
+/// Token types expose predefined sets, e.g. [`RustToken::TRIVIA`]
+/// and [`RustToken::LITERALS`], which can be used with
+/// [`Lexer::skip_while_kind()`].
+///
+/// # Example
+///
+/// ```rust
+/// use any_lexer::{RustToken, TokenKindSet};
+///
+/// let set = [RustToken::Ident, RustToken::Keyword]
+///     .into_iter()
+///     .collect::<TokenKindSet<_>>();
+/// assert!(set.contains(RustToken::Ident));
+/// assert!(!set.contains(RustToken::Int));
+///
+/// assert!(RustToken::TRIVIA.contains(RustToken::LineComment));
+/// assert!(RustToken::LITERALS.contains(RustToken::Int));
+/// ```
+pub struct TokenKindSet<T> {
+    bits: u64,
+    phantom: PhantomData<fn() -> T>,
+}
+
+impl<T> TokenKindSet<T> {
+    /// Set containing no token kinds.
+    pub const EMPTY: Self = Self::from_bits(0);
+
+    /// Constructs a set from `bits`, where bit `n` represents the token
+    /// kind with [`index()`] `n`.
+    ///
+    /// [`index()`]: TokenKind::index
+    #[inline]
+    pub const fn from_bits(bits: u64) -> Self {
+        Self {
+            bits,
+            phantom: PhantomData,
+        }
+    }
+
+    /// Returns the bits of the set, see [`from_bits()`](Self::from_bits).
+    #[inline]
+    pub const fn bits(&self) -> u64 {
+        self.bits
+    }
+
+    /// Returns a set containing the token kinds in `self` and in `other`.
+    #[inline]
+    pub const fn union(self, other: Self) -> Self {
+        Self::from_bits(self.bits | other.bits)
+    }
+
+    /// Returns a set containing the token kinds in both `self` and `other`.
+    #[inline]
+    pub const fn intersection(self, other: Self) -> Self {
+        Self::from_bits(self.bits & other.bits)
+    }
+
+    #[inline]
+    pub const fn len(&self) -> usize {
+        self.bits.count_ones() as usize
+    }
+
+    #[inline]
+    pub const fn is_empty(&self) -> bool {
+        self.bits == 0
+    }
+}
+
+impl<T: TokenKind> TokenKindSet<T> {
+    #[inline]
+    pub fn contains(&self, tok: T) -> bool {
+        (self.bits & Self::bit(tok)) != 0
+    }
+
+    /// Adds `tok` to the set, and returns `true` if it was not
+    /// already contained.
+    #[inline]
+    pub fn insert(&mut self, tok: T) -> bool {
+        let contained = self.contains(tok);
+        self.bits |= Self::bit(tok);
+        !contained
+    }
+
+    /// Removes `tok` from the set, and returns `true` if it was contained.
+    #[inline]
+    pub fn remove(&mut self, tok: T) -> bool {
+        let contained = self.contains(tok);
+        self.bits &= !Self::bit(tok);
+        contained
+    }
+
+    #[inline]
+    fn bit(tok: T) -> u64 {
+        let index = tok.index();
+        debug_assert!(index < u64::BITS, "token kind index is out of range");
+        1 << index
+    }
+}
+
+impl<T> Clone for TokenKindSet<T> {
+    #[inline]
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for TokenKindSet<T> {}
+
+impl<T> PartialEq for TokenKindSet<T> {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.bits == other.bits
+    }
+}
+
+impl<T> Eq for TokenKindSet<T> {}
+
+impl<T> Default for TokenKindSet<T> {
+    #[inline]
+    fn default() -> Self {
+        Self::EMPTY
+    }
+}
+
+impl<T> fmt::Debug for TokenKindSet<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "TokenKindSet({:#b})", self.bits)
+    }
+}
+
+impl<T: TokenKind> FromIterator<T> for TokenKindSet<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut set = Self::EMPTY;
+        set.extend(iter);
+        set
+    }
+}
+
+impl<T: TokenKind> Extend<T> for TokenKindSet<T> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for tok in iter {
+            self.insert(tok);
+        }
+    }
+}
+
+// Unused if all `lang-*` features are disabled
+#[allow(unused_macros)]
 macro_rules! impl_lexer_from_scanner {
     ($lifetime:lifetime, $lexer:ty, $token:ty, $scanner:ident) => {
         impl<$lifetime> $crate::Lexer<$lifetime> for $lexer {
@@ -151,11 +532,33 @@ macro_rules! impl_lexer_from_scanner {
         }
 
         $crate::impl_iter_for_lexer!($lifetime, $lexer);
+        $crate::impl_from_str_for_lexer!($lifetime, $lexer);
     };
 }
 
+#[allow(unused_imports)]
 pub(crate) use impl_lexer_from_scanner;
 
+/// Implements `From<&str>` for a lexer, using its `new()`,
+/// as required by [`Lexer::resume_at()`].
+// Unused if all `lang-*` features are disabled
+#[allow(unused_macros)]
+macro_rules! impl_from_str_for_lexer {
+    ($lifetime:lifetime, $lexer:ty) => {
+        impl<$lifetime> From<&$lifetime str> for $lexer {
+            #[inline]
+            fn from(text: &$lifetime str) -> Self {
+                Self::new(text)
+            }
+        }
+    };
+}
+
+#[allow(unused_imports)]
+pub(crate) use impl_from_str_for_lexer;
+
+// Unused if all `lang-*` features are disabled
+#[allow(unused_macros)]
 macro_rules! impl_iter_for_lexer {
     ($lifetime:lifetime, $lexer:ty) => {
         impl<$lifetime> Iterator for $lexer {
@@ -174,10 +577,86 @@ macro_rules! impl_iter_for_lexer {
     };
 }
 
+#[allow(unused_imports)]
 pub(crate) use impl_iter_for_lexer;
 
+/// Implements `name()` and `from_name()`, along with [`TokenKind`],
+/// for a token enum.
+///
+/// The names are part of the public API, and are guaranteed to remain
+/// stable across versions, such that e.g. external configuration files
+/// can refer to token kinds by name. Variants being renamed does not
+/// change their name.
+// Unused if all `lang-*` features are disabled
+#[allow(unused_macros)]
+macro_rules! impl_token_names {
+    ($token:ty { $($variant:ident => $name:literal),+ $(,)? }) => {
+        impl $token {
+            /// Returns the stable machine-readable name of this token kind.
+            ///
+            /// See also [`from_name()`](Self::from_name).
+            #[inline]
+            pub const fn name(&self) -> &'static str {
+                match self {
+                    $(Self::$variant => $name,)+
+                }
+            }
+
+            /// Returns the token kind with the given `name`, if any.
+            ///
+            /// See also [`name()`](Self::name).
+            #[inline]
+            pub fn from_name(name: &str) -> Option<Self> {
+                match name {
+                    $($name => Some(Self::$variant),)+
+                    _ => None,
+                }
+            }
+        }
+
+        impl $crate::TokenKind for $token {
+            #[inline]
+            fn index(self) -> u32 {
+                self as u32
+            }
+        }
+    };
+}
+
+#[allow(unused_imports)]
+pub(crate) use impl_token_names;
+
+/// Implements [`TokenKindSet`] constants for a token enum.
+// Unused if all `lang-*` features are disabled
+#[allow(unused_macros)]
+macro_rules! impl_token_kind_sets {
+    ($token:ty { $($(#[$meta:meta])* $name:ident => [$($variant:ident),* $(,)?]),+ $(,)? }) => {
+        impl $token {
+            $(
+                $(#[$meta])*
+                pub const $name: $crate::TokenKindSet<Self> =
+                    $crate::TokenKindSet::from_bits(0 $(| (1 << (Self::$variant as u32)))*);
+            )+
+        }
+    };
+}
+
+#[allow(unused_imports)]
+pub(crate) use impl_token_kind_sets;
+
+#[allow(dead_code)]
 pub(crate) trait ScannerExt<'text> {
     fn span(&self, range: Range<usize>) -> TokenSpan<'text>;
+
+    fn span_outcome(&self, range: Range<usize>, outcome: &ScanOutcome) -> TokenSpan<'text>;
+
+    /// Returns `true` if only spaces and tabs are between the cursor
+    /// and the start of the line.
+    fn is_line_start(&self) -> bool;
+
+    /// Returns `true` if the line at the cursor continues the previous line,
+    /// i.e. if the previous line ends with `\`.
+    fn follows_continuation(&self) -> bool;
 }
 
 impl<'text> ScannerExt<'text> for Scanner<'text> {
@@ -185,4 +664,25 @@ impl<'text> ScannerExt<'text> for Scanner<'text> {
     fn span(&self, range: Range<usize>) -> TokenSpan<'text> {
         TokenSpan::new(self.text(), range)
     }
+
+    #[inline]
+    fn span_outcome(&self, range: Range<usize>, outcome: &ScanOutcome) -> TokenSpan<'text> {
+        self.span(range)
+            .with_unterminated(outcome.is_unterminated())
+    }
+
+    fn is_line_start(&self) -> bool {
+        let before = &self.text()[..self.cursor_pos()];
+        let line = before.rsplit(['\n', '\r']).next().unwrap_or_default();
+        line.chars().all(|c| matches!(c, ' ' | '\t'))
+    }
+
+    fn follows_continuation(&self) -> bool {
+        let before = &self.text()[..self.cursor_pos()];
+        let before = before.trim_end_matches([' ', '\t']);
+        match before.strip_suffix(['\n', '\r']) {
+            Some(line) => line.trim_end_matches('\r').ends_with('\\'),
+            None => false,
+        }
+    }
 }
@@ -0,0 +1,214 @@
+//! Utilities for finding tokens by offset, e.g. the token under
+//! the cursor in an editor.
+//!
+//! All functions expect `tokens` to be sorted by their spans, i.e. in the
+//! order they were produced by a lexer, and use binary search.
+//!
+//! For repeatedly querying the same tokens, e.g. on every keystroke,
+//! see [`TokenIndex`].
+//!
+//! # Example
+//!
+//! ```rust
+//! use any_lexer::lookup;
+//! use any_lexer::{RustLexer, RustToken};
+//!
+//! let code = "let x = 1;";
+//! let tokens = RustLexer::new(code).collect::<Vec<_>>();
+//!
+//! let (tok, span) = lookup::token_at(&tokens, 4).unwrap();
+//! assert_eq!(*tok, RustToken::Ident);
+//! assert_eq!(span.as_str(), "x");
+//! ```
+
+use std::ops::Range;
+
+use crate::{TokenKind, TokenKindSet, TokenSpan};
+
+/// Returns the index of the token containing `offset`, i.e. where
+/// `span.start() <= offset < span.end()`.
+///
+/// If `offset` is at the boundary between two tokens, then the index
+/// of the token starting at `offset` is returned. Returns `None` if no
+/// token contains `offset`, e.g. if `offset` is at the end of the text.
+///
+/// # Example
+///
+/// ```rust
+/// use any_lexer::lookup;
+/// use any_lexer::RustLexer;
+///
+/// let code = "a+b";
+/// let tokens = RustLexer::new(code).collect::<Vec<_>>();
+///
+/// assert_eq!(lookup::token_index_at(&tokens, 0), Some(0));
+/// assert_eq!(lookup::token_index_at(&tokens, 1), Some(1));
+/// assert_eq!(lookup::token_index_at(&tokens, 2), Some(2));
+/// assert_eq!(lookup::token_index_at(&tokens, 3), None);
+/// ```
+pub fn token_index_at<Tok>(tokens: &[(Tok, TokenSpan<'_>)], offset: usize) -> Option<usize> {
+    let i = tokens.partition_point(|(_tok, span)| span.end() <= offset);
+    match tokens.get(i) {
+        Some((_tok, span)) if span.start() <= offset => Some(i),
+        _ => None,
+    }
+}
+
+/// Returns the token containing `offset`, see [`token_index_at()`]
+/// for how boundaries between tokens are handled.
+///
+/// Use `token_at(tokens, offset - 1)` to instead get the token ending
+/// at `offset`, e.g. the word left of the cursor.
+///
+/// # Example
+///
+/// ```rust
+/// use any_lexer::lookup;
+/// use any_lexer::{RustLexer, RustToken};
+///
+/// let code = "let x";
+/// let tokens = RustLexer::new(code).collect::<Vec<_>>();
+///
+/// // Boundary between `let` and ` `
+/// assert_eq!(lookup::token_at(&tokens, 3).unwrap().0, RustToken::Space);
+/// assert_eq!(lookup::token_at(&tokens, 3 - 1).unwrap().0, RustToken::Keyword);
+///
+/// assert_eq!(lookup::token_at(&tokens, code.len()), None);
+/// ```
+#[inline]
+pub fn token_at<'a, 'text, Tok>(
+    tokens: &'a [(Tok, TokenSpan<'text>)],
+    offset: usize,
+) -> Option<&'a (Tok, TokenSpan<'text>)> {
+    token_index_at(tokens, offset).map(|i| &tokens[i])
+}
+
+/// Index of a token stream, for repeatedly querying tokens by offset,
+/// where all queries are _O(log n)_.
+///
+/// # Example
+///
+/// ```rust
+/// use any_lexer::lookup::TokenIndex;
+/// use any_lexer::{RustLexer, RustToken};
+///
+/// let code = "let x = /* one */ 1;";
+/// let index = TokenIndex::new(RustLexer::new(code), RustToken::TRIVIA);
+///
+/// let (tok, span) = index.token_at(9).unwrap();
+/// assert_eq!((*tok, span.as_str()), (RustToken::BlockComment, "/* one */"));
+///
+/// let (tok, span) = index.next_non_trivia(7).unwrap();
+/// assert_eq!((*tok, span.as_str()), (RustToken::Int, "1"));
+///
+/// let (tok, span) = index.prev_non_trivia(18).unwrap();
+/// assert_eq!((*tok, span.as_str()), (RustToken::Punct, "="));
+///
+/// let strs = index
+///     .tokens_in_range(4..8)
+///     .iter()
+///     .map(|(_tok, span)| span.as_str())
+///     .collect::<Vec<_>>();
+/// assert_eq!(strs, ["x", " ", "=", " "]);
+/// ```
+#[derive(Clone, Debug)]
+pub struct TokenIndex<'text, Tok> {
+    tokens: Vec<(Tok, TokenSpan<'text>)>,
+    /// Indices of all tokens not contained in the trivia set,
+    /// in ascending order.
+    non_trivia: Vec<usize>,
+}
+
+impl<'text, Tok> TokenIndex<'text, Tok>
+where
+    Tok: TokenKind,
+{
+    /// Constructs an index of `tokens`, which must be in the order they
+    /// were produced by a lexer, where `trivia` are the token kinds skipped
+    /// by [`next_non_trivia()`] and [`prev_non_trivia()`], e.g.
+    /// [`RustToken::TRIVIA`].
+    ///
+    /// [`next_non_trivia()`]: Self::next_non_trivia
+    /// [`prev_non_trivia()`]: Self::prev_non_trivia
+    /// [`RustToken::TRIVIA`]: crate::RustToken::TRIVIA
+    pub fn new<I>(tokens: I, trivia: TokenKindSet<Tok>) -> Self
+    where
+        I: IntoIterator<Item = (Tok, TokenSpan<'text>)>,
+    {
+        let tokens = tokens.into_iter().collect::<Vec<_>>();
+        let non_trivia = tokens
+            .iter()
+            .enumerate()
+            .filter(|(_i, (tok, _span))| !trivia.contains(*tok))
+            .map(|(i, _)| i)
+            .collect();
+        Self { tokens, non_trivia }
+    }
+}
+
+impl<'text, Tok> TokenIndex<'text, Tok> {
+    #[inline]
+    pub fn tokens(&self) -> &[(Tok, TokenSpan<'text>)] {
+        &self.tokens
+    }
+
+    #[inline]
+    pub fn into_tokens(self) -> Vec<(Tok, TokenSpan<'text>)> {
+        self.tokens
+    }
+
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.tokens.len()
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.tokens.is_empty()
+    }
+
+    /// Returns the index of the token containing `offset`,
+    /// see [`token_index_at()`].
+    #[inline]
+    pub fn token_index_at(&self, offset: usize) -> Option<usize> {
+        token_index_at(&self.tokens, offset)
+    }
+
+    /// Returns the token containing `offset`, see [`token_at()`].
+    #[inline]
+    pub fn token_at(&self, offset: usize) -> Option<&(Tok, TokenSpan<'text>)> {
+        token_at(&self.tokens, offset)
+    }
+
+    /// Returns all tokens overlapping `range`, including tokens only
+    /// partially contained in `range`, i.e. where
+    /// `span.start() < range.end` and `range.start < span.end()`.
+    pub fn tokens_in_range(&self, range: Range<usize>) -> &[(Tok, TokenSpan<'text>)] {
+        let start = self
+            .tokens
+            .partition_point(|(_tok, span)| span.end() <= range.start);
+        let end = self
+            .tokens
+            .partition_point(|(_tok, span)| span.start() < range.end);
+        self.tokens.get(start..end).unwrap_or_default()
+    }
+
+    /// Returns the first token not contained in the trivia set,
+    /// which starts at or after `offset`.
+    pub fn next_non_trivia(&self, offset: usize) -> Option<&(Tok, TokenSpan<'text>)> {
+        let i = self
+            .non_trivia
+            .partition_point(|&i| self.tokens[i].1.start() < offset);
+        self.non_trivia.get(i).map(|&i| &self.tokens[i])
+    }
+
+    /// Returns the last token not contained in the trivia set,
+    /// which ends at or before `offset`.
+    pub fn prev_non_trivia(&self, offset: usize) -> Option<&(Tok, TokenSpan<'text>)> {
+        let i = self
+            .non_trivia
+            .partition_point(|&i| self.tokens[i].1.end() <= offset);
+        let i = i.checked_sub(1)?;
+        Some(&self.tokens[self.non_trivia[i]])
+    }
+}
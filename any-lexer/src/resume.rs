@@ -0,0 +1,317 @@
+use std::ops::Range;
+
+use text_scanner::LineIndex;
+
+use crate::{lookup, Lexer, TokenSpan};
+
+/// Hint of the lexer state at an offset, used to resume lexing at
+/// the offset with [`Lexer::resume_at()`].
+///
+/// The hint is computed from a previous run, e.g. using
+/// [`from_tokens()`], and captures whether the offset is inside
+/// a token, e.g. a multiline block comment or string.
+///
+/// See also [`LineStates`].
+///
+/// [`from_tokens()`]: Self::from_tokens
+#[derive(PartialEq, Eq, Clone, Copy, Default, Debug)]
+pub enum StateHint {
+    /// The offset is at the start of a token, or at the end of the text.
+    #[default]
+    Boundary,
+    /// The offset is inside a token, e.g. a multiline block comment
+    /// or string, which starts at `start`.
+    Inside { start: usize },
+}
+
+impl StateHint {
+    /// Returns the hint for resuming at `offset`, given the `span` of the
+    /// token containing `offset`.
+    ///
+    /// If `span` does not contain `offset`, then [`StateHint::Boundary`]
+    /// is returned.
+    #[inline]
+    pub fn from_span(span: &TokenSpan<'_>, offset: usize) -> Self {
+        if (span.start() < offset) && (offset < span.end()) {
+            Self::Inside {
+                start: span.start(),
+            }
+        } else {
+            Self::Boundary
+        }
+    }
+
+    /// Returns the hint for resuming at `offset`, given all `tokens`
+    /// produced by a previous run, in the order they were produced.
+    ///
+    /// See [`Lexer::resume_at()`] for an example.
+    pub fn from_tokens<Tok>(tokens: &[(Tok, TokenSpan<'_>)], offset: usize) -> Self {
+        match lookup::token_at(tokens, offset) {
+            Some((_tok, span)) => Self::from_span(span, offset),
+            None => Self::Boundary,
+        }
+    }
+
+    /// Returns the position lexing resumes at, i.e. `offset` or the start
+    /// of the token containing `offset`.
+    #[inline]
+    pub fn resume_pos(self, offset: usize) -> usize {
+        match self {
+            Self::Boundary => offset,
+            Self::Inside { start } => start.min(offset),
+        }
+    }
+}
+
+/// The [`StateHint`] at the start of each line, like classic editor
+/// highlighters, such that each line can be highlighted independently
+/// using [`Lexer::resume_at()`].
+///
+/// When lines are changed, [`replace_lines()`] only lexes the changed
+/// lines, along with the following lines whose state is affected, e.g.
+/// when a block comment is opened. The returned range of lines is what
+/// must be highlighted again.
+///
+/// Lines are separated by `\n`, such that text ending with `\n` ends with
+/// an empty line.
+///
+/// **Note:** Lexers with additional state, e.g. [`JavaScriptLexer`]
+/// inside [JSX], resume in their initial state, see [`Lexer::resume_at()`].
+///
+/// # Example
+///
+/// ```rust
+/// use any_lexer::{LineStates, RustLexer, StateHint};
+///
+/// let code = "a\nb\nc\nd";
+/// let mut states = LineStates::new(RustLexer::new(code));
+/// assert_eq!(states.len(), 4);
+/// assert_eq!(states.get(2), Some(StateHint::Boundary));
+///
+/// // Line 1 is changed to open a block comment
+/// let code = "a\n/* b\nc */\nd";
+/// let changed = states.replace_lines(RustLexer::new(code), 1..2, 1);
+///
+/// // Line 2 is now inside the block comment, while line 3 is unaffected
+/// assert_eq!(changed, 1..3);
+/// assert_eq!(states.get(2), Some(StateHint::Inside { start: 2 }));
+/// assert_eq!(states.get(3), Some(StateHint::Boundary));
+/// ```
+///
+/// [`replace_lines()`]: Self::replace_lines
+/// [`JavaScriptLexer`]: crate::JavaScriptLexer
+/// [JSX]: https://facebook.github.io/jsx/
+#[derive(PartialEq, Eq, Clone, Debug)]
+pub struct LineStates {
+    lines: LineIndex,
+    /// The state at the start of each line.
+    states: Vec<StateHint>,
+}
+
+impl LineStates {
+    /// Lexes all the text of `lexer`, and returns the state at the
+    /// start of each line.
+    pub fn new<'text, L>(lexer: L) -> Self
+    where
+        L: Lexer<'text>,
+    {
+        let text = lexer.scanner().text();
+        let mut states = Self {
+            lines: LineIndex::new(text),
+            states: vec![StateHint::Boundary],
+        };
+        let len = states.len();
+        states.lex(lexer, 0, len, &[]);
+        states
+    }
+
+    /// Returns the amount of lines.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.lines.line_count()
+    }
+
+    /// Always returns `false`, as there is always at least one line.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        false
+    }
+
+    /// Returns the state at the start of `line`.
+    #[inline]
+    pub fn get(&self, line: usize) -> Option<StateHint> {
+        self.states.get(line).copied()
+    }
+
+    /// Returns the byte offset of the start of `line`.
+    #[inline]
+    pub fn line_start(&self, line: usize) -> Option<usize> {
+        self.lines.line_start(line)
+    }
+
+    /// Returns the [`LineIndex`] of the lines of the text.
+    #[inline]
+    pub fn line_index(&self) -> &LineIndex {
+        &self.lines
+    }
+
+    /// Updates the states after `old_lines` were replaced by
+    /// `new_line_count` lines, where `lexer` is a lexer for the
+    /// changed text, and returns the range of lines whose state
+    /// or text changed, i.e. which must be highlighted again.
+    ///
+    /// Lexing stops as soon as the state of a line following the
+    /// replaced lines is unchanged.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `old_lines` is empty or out of bounds, or if the changed
+    /// text does not contain `new_line_count` lines in place of `old_lines`.
+    pub fn replace_lines<'text, L>(
+        &mut self,
+        lexer: L,
+        old_lines: Range<usize>,
+        new_line_count: usize,
+    ) -> Range<usize>
+    where
+        L: Lexer<'text>,
+    {
+        assert!(
+            (old_lines.start < old_lines.end) && (old_lines.end <= self.len()),
+            "lines out of bounds"
+        );
+
+        let text = lexer.scanner().text();
+        let first = old_lines.start;
+        let old_end = self.lines.line_range(old_lines.end - 1).unwrap().end;
+
+        // The states of the lines following the replaced lines,
+        // with the positions shifted to the changed text
+        let old_text_len = self.lines.text_len();
+        let suffix = self.states[old_lines.end..]
+            .iter()
+            .map(|&state| match state {
+                StateHint::Inside { start } if start >= old_end => StateHint::Inside {
+                    start: start + text.len() - old_text_len,
+                },
+                state => state,
+            })
+            .collect::<Vec<_>>();
+
+        self.lines.reindex_from(text, first);
+        self.states.truncate(first + 1);
+
+        let suffix_first = first + new_line_count;
+        assert_eq!(
+            self.lines.line_count(),
+            suffix_first + suffix.len(),
+            "line count mismatch"
+        );
+
+        let end = self.lex(lexer, first, suffix_first, &suffix);
+        first..end
+    }
+
+    /// Lexes from the start of line `first`, pushing the state of each
+    /// following line, until the state of a line at or after `suffix_first`
+    /// equals its state in `suffix`. Then the remaining `suffix` is pushed,
+    /// and that line is returned.
+    fn lex<'text, L>(
+        &mut self,
+        mut lexer: L,
+        first: usize,
+        suffix_first: usize,
+        suffix: &[StateHint],
+    ) -> usize
+    where
+        L: Lexer<'text>,
+    {
+        debug_assert_eq!(self.states.len(), first + 1);
+
+        let line_starts = self.lines.line_starts();
+        let first_start = line_starts[first];
+        lexer.set_cursor_pos(self.states[first].resume_pos(first_start));
+
+        let push = |states: &mut Vec<StateHint>, line: usize, state: StateHint| {
+            if let Some(suffix) = line.checked_sub(suffix_first).map(|i| &suffix[i..]) {
+                if suffix.first() == Some(&state) {
+                    states.extend_from_slice(suffix);
+                    return true;
+                }
+            }
+            states.push(state);
+            false
+        };
+
+        let mut line = first + 1;
+        while let Some((_tok, span)) = lexer.next_token() {
+            while (line < line_starts.len()) && (line_starts[line] < span.end()) {
+                let state = StateHint::from_span(&span, line_starts[line]);
+                if push(&mut self.states, line, state) {
+                    return line;
+                }
+                line += 1;
+            }
+        }
+
+        // Lines at the end of the text, e.g. the empty line after a trailing `\n`
+        while line < line_starts.len() {
+            if push(&mut self.states, line, StateHint::Boundary) {
+                return line;
+            }
+            line += 1;
+        }
+
+        line
+    }
+}
+
+#[cfg(all(test, feature = "lang-python"))]
+mod tests {
+    use crate::{Lexer, LineStates, PythonLexer, StateHint};
+
+    #[test]
+    fn test_resume_at_every_offset() {
+        let code = "x = '''a\nb'''  # c\n\"\"\"d\n\ne\"\"\"\ny = 1\n";
+        let tokens = PythonLexer::new(code).collect::<Vec<_>>();
+
+        for offset in (0..=code.len()).filter(|&i| code.is_char_boundary(i)) {
+            let hint = StateHint::from_tokens(&tokens, offset);
+            let resumed = PythonLexer::resume_at(code, offset, hint).collect::<Vec<_>>();
+
+            let i = tokens.partition_point(|(_tok, span)| span.end() <= offset);
+            assert_eq!(resumed, tokens[i..], "offset {offset}");
+        }
+    }
+
+    #[test]
+    fn test_line_states_replace_lines() {
+        let code = "x = 1\ny = '''a\nb\nc'''\n# d\nz = 2\n";
+
+        #[rustfmt::skip]
+        let edits = [
+            // old lines, new line count, new code
+            (0..1, 1, "x = '''\ny = '''a\nb\nc'''\n# d\nz = 2\n"),
+            (1..2, 1, "x = 1\ny = 'a'\nb\nc'''\n# d\nz = 2\n"),
+            (2..3, 2, "x = 1\ny = '''a\nb\nb\nc'''\n# d\nz = 2\n"),
+            (1..4, 1, "x = 1\ny = 'abc'\n# d\nz = 2\n"),
+            (5..6, 1, "x = 1\ny = '''a\nb\nc'''\n# d\n\"\"\"\n"),
+            (6..7, 1, "x = 1\ny = '''a\nb\nc'''\n# d\nz = 2\n'''"),
+            (0..7, 1, ""),
+        ];
+
+        for (old_lines, new_line_count, new_code) in edits {
+            let mut states = LineStates::new(PythonLexer::new(code));
+            let changed = states.replace_lines(
+                PythonLexer::new(new_code),
+                old_lines.clone(),
+                new_line_count,
+            );
+
+            let expected = LineStates::new(PythonLexer::new(new_code));
+            assert_eq!(states, expected, "{new_code:?}");
+            assert_eq!(changed.start, old_lines.start);
+            assert!(changed.end >= old_lines.start + new_line_count);
+        }
+    }
+}
@@ -0,0 +1,160 @@
+use std::error;
+use std::fmt;
+
+use crate::TokenSpan;
+
+/// Returns the text of all `tokens` concatenated.
+///
+/// As all lexers produce tokens covering the whole input, this
+/// reconstructs the original text, given an unmodified token stream.
+///
+/// # Example
+///
+/// ```rust
+/// use any_lexer::{reconstruct, JsonLexer, JsonToken};
+///
+/// let json = r#"{ "a": 1 }"#;
+/// let minified = JsonLexer::new(json).filter(|(tok, _span)| *tok != JsonToken::Space);
+///
+/// assert_eq!(reconstruct(JsonLexer::new(json)), json);
+/// assert_eq!(reconstruct(minified), r#"{"a":1}"#);
+/// ```
+pub fn reconstruct<'text, Tok, I>(tokens: I) -> String
+where
+    I: IntoIterator<Item = (Tok, TokenSpan<'text>)>,
+{
+    tokens
+        .into_iter()
+        .map(|(_tok, span)| span.as_str())
+        .collect()
+}
+
+/// Verifies that the text of all `tokens` concatenated is exactly `text`,
+/// without allocating, e.g. to assert that a pipeline transforming a
+/// token stream did not lose or duplicate any text.
+///
+/// Unlike a `debug_assert!()`, this is also available in release builds.
+///
+/// See also [`reconstruct()`].
+///
+/// # Example
+///
+/// ```rust
+/// use any_lexer::{verify_roundtrip, JsonLexer, JsonToken, RoundtripError};
+///
+/// let json = r#"{ "a": 1 }"#;
+/// assert_eq!(verify_roundtrip(JsonLexer::new(json), json), Ok(()));
+///
+/// let minified = JsonLexer::new(json).filter(|(tok, _span)| *tok != JsonToken::Space);
+/// assert_eq!(
+///     verify_roundtrip(minified, json),
+///     Err(RoundtripError { pos: 1, token: Some(1) }),
+/// );
+/// ```
+pub fn verify_roundtrip<'text, Tok, I>(tokens: I, text: &str) -> Result<(), RoundtripError>
+where
+    I: IntoIterator<Item = (Tok, TokenSpan<'text>)>,
+{
+    let mut pos = 0;
+    for (i, (_tok, span)) in tokens.into_iter().enumerate() {
+        let s = span.as_str();
+        if !text[pos..].starts_with(s) {
+            let offset = text[pos..]
+                .bytes()
+                .zip(s.bytes())
+                .position(|(a, b)| a != b)
+                .unwrap_or(text.len() - pos);
+            return Err(RoundtripError {
+                pos: pos + offset,
+                token: Some(i),
+            });
+        }
+        pos += s.len();
+    }
+
+    if pos != text.len() {
+        return Err(RoundtripError { pos, token: None });
+    }
+
+    Ok(())
+}
+
+/// Error returned by [`verify_roundtrip()`].
+#[derive(PartialEq, Eq, Clone, Debug)]
+pub struct RoundtripError {
+    /// The byte offset in the original text, where the
+    /// reconstructed text first differs.
+    pub pos: usize,
+    /// The index of the token, where the reconstructed text first differs,
+    /// or `None` if the tokens ended before the original text.
+    pub token: Option<usize>,
+}
+
+impl fmt::Display for RoundtripError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.token {
+            Some(token) => write!(
+                f,
+                "token {token} differs from the original text at byte {}",
+                self.pos
+            ),
+            None => write!(
+                f,
+                "tokens ended before the original text at byte {}",
+                self.pos
+            ),
+        }
+    }
+}
+
+impl error::Error for RoundtripError {}
+
+#[cfg(all(test, feature = "lang-rust"))]
+mod tests {
+    use super::*;
+    use crate::{RustLexer, RustToken};
+
+    #[test]
+    fn test_verify_roundtrip() {
+        let text = "fn main() {\n    let x = \"🦀\";\n}\n";
+        assert_eq!(reconstruct(RustLexer::new(text)), text);
+        assert_eq!(verify_roundtrip(RustLexer::new(text), text), Ok(()));
+
+        let tokens = RustLexer::new(text).take(3);
+        let err = verify_roundtrip(tokens, text).unwrap_err();
+        assert_eq!(
+            err,
+            RoundtripError {
+                pos: 7,
+                token: None
+            }
+        );
+        assert_eq!(
+            err.to_string(),
+            "tokens ended before the original text at byte 7"
+        );
+
+        // Duplicated token
+        let tokens = RustLexer::new(text).flat_map(|tok| match tok.0 {
+            RustToken::Ident => vec![tok.clone(), tok],
+            _ => vec![tok],
+        });
+        let err = verify_roundtrip(tokens, text).unwrap_err();
+        assert_eq!(
+            err,
+            RoundtripError {
+                pos: 7,
+                token: Some(3)
+            }
+        );
+
+        // Tokens of a different text
+        let other = text.replace('x', "y");
+        let err = verify_roundtrip(RustLexer::new(&other), text).unwrap_err();
+        assert_eq!(err.pos, text.find('x').unwrap());
+
+        // Tokens beyond the end of the text
+        let err = verify_roundtrip(RustLexer::new(text), &text[..10]).unwrap_err();
+        assert_eq!(err.pos, 10);
+    }
+}
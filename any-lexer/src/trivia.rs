@@ -0,0 +1,185 @@
+//! Utilities for attaching trivia, e.g. whitespace and comments, to the
+//! surrounding tokens, e.g. for tooling which rewrites parts of the text,
+//! while keeping comments next to the code they describe.
+//!
+//! All functions expect `tokens` to be in the order they were produced
+//! by a lexer.
+//!
+//! # Example
+//!
+//! ```rust
+//! use any_lexer::trivia::attach_trivia;
+//! use any_lexer::{RustLexer, RustToken, TokenSpan};
+//!
+//! fn comments<'text>(tokens: &[(RustToken, TokenSpan<'text>)]) -> Vec<&'text str> {
+//!     tokens
+//!         .iter()
+//!         .filter(|(tok, _span)| *tok == RustToken::LineComment)
+//!         .map(|(_tok, span)| span.as_str())
+//!         .collect()
+//! }
+//!
+//! let code = "// One\nlet a = 1; // Two\n\n// Three\nlet b = 2;";
+//! let tokens = RustLexer::new(code).collect::<Vec<_>>();
+//! let attached = attach_trivia(&tokens, RustToken::TRIVIA);
+//!
+//! // First `let`
+//! assert_eq!(comments(attached[0].leading(&tokens)), ["// One"]);
+//! // First `;`
+//! assert_eq!(comments(attached[4].trailing(&tokens)), ["// Two"]);
+//! // Second `let`
+//! assert_eq!(comments(attached[5].leading(&tokens)), ["// Three"]);
+//! ```
+
+use std::ops::Range;
+
+use crate::{TokenKind, TokenKindSet, TokenSpan};
+
+/// Non-trivia token and the trivia attached to it, as indices into the
+/// tokens given to [`attach_trivia()`].
+#[derive(PartialEq, Eq, Clone, Debug)]
+pub struct Attached {
+    /// Index of the non-trivia token.
+    pub token: usize,
+    /// Indices of the trivia tokens preceding the token, which are not
+    /// trailing trivia of the previous non-trivia token.
+    pub leading: Range<usize>,
+    /// Indices of the trivia tokens following the token on the same line,
+    /// i.e. until the first trivia token containing a line break.
+    ///
+    /// For the last non-trivia token, this includes all remaining trivia.
+    pub trailing: Range<usize>,
+}
+
+impl Attached {
+    /// Returns the leading trivia tokens of `tokens`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `tokens` is not the tokens given to [`attach_trivia()`].
+    #[inline]
+    pub fn leading<'a, 'text, Tok>(
+        &self,
+        tokens: &'a [(Tok, TokenSpan<'text>)],
+    ) -> &'a [(Tok, TokenSpan<'text>)] {
+        &tokens[self.leading.clone()]
+    }
+
+    /// Returns the trailing trivia tokens of `tokens`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `tokens` is not the tokens given to [`attach_trivia()`].
+    #[inline]
+    pub fn trailing<'a, 'text, Tok>(
+        &self,
+        tokens: &'a [(Tok, TokenSpan<'text>)],
+    ) -> &'a [(Tok, TokenSpan<'text>)] {
+        &tokens[self.trailing.clone()]
+    }
+
+    /// Returns the range of the text covered by the token, including its
+    /// leading and trailing trivia, e.g. for moving or removing the token
+    /// along with its comments.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `tokens` is not the tokens given to [`attach_trivia()`].
+    pub fn text_range<Tok>(&self, tokens: &[(Tok, TokenSpan<'_>)]) -> Range<usize> {
+        tokens[self.leading.start].1.start()..tokens[self.trailing.end - 1].1.end()
+    }
+}
+
+/// Attaches all tokens contained in the `trivia` set, e.g.
+/// [`RustToken::TRIVIA`], to the surrounding non-trivia tokens.
+///
+/// Trivia following a non-trivia token on the same line is attached to it
+/// as trailing trivia, e.g. `// Comment` in `a = 1; // Comment`, while the
+/// remaining trivia is attached as leading trivia to the next non-trivia
+/// token. Trivia before the first non-trivia token is attached as leading
+/// trivia to it, while trivia after the last non-trivia token is attached
+/// as trailing trivia to it.
+///
+/// So every token is either a non-trivia token or attached to exactly one,
+/// unless `tokens` only contains trivia, in which case an empty `Vec`
+/// is returned.
+///
+/// See the [module-level documentation] for an example.
+///
+/// [`RustToken::TRIVIA`]: crate::RustToken::TRIVIA
+/// [module-level documentation]: self
+pub fn attach_trivia<Tok>(
+    tokens: &[(Tok, TokenSpan<'_>)],
+    trivia: TokenKindSet<Tok>,
+) -> Vec<Attached>
+where
+    Tok: TokenKind,
+{
+    let is_trivia = |i: usize| trivia.contains(tokens[i].0);
+
+    let mut attached = Vec::new();
+    let mut leading_start = 0;
+    let mut i = 0;
+    while i < tokens.len() {
+        if is_trivia(i) {
+            i += 1;
+            continue;
+        }
+
+        let token = i;
+        i += 1;
+
+        let trailing_start = i;
+        while (i < tokens.len()) && is_trivia(i) && !tokens[i].1.as_str().contains(['\n', '\r']) {
+            i += 1;
+        }
+
+        attached.push(Attached {
+            token,
+            leading: leading_start..token,
+            trailing: trailing_start..i,
+        });
+        leading_start = i;
+    }
+
+    if let Some(last) = attached.last_mut() {
+        last.trailing.end = tokens.len();
+    }
+
+    attached
+}
+
+#[cfg(all(test, feature = "lang-rust"))]
+mod tests {
+    use super::*;
+    use crate::{RustLexer, RustToken};
+
+    #[test]
+    fn test_attach_trivia_covers_all_tokens() {
+        let input = include_str!("../../text-scanner/src/ext/rust.rs");
+        let tokens = RustLexer::new(input).collect::<Vec<_>>();
+        let attached = attach_trivia(&tokens, RustToken::TRIVIA);
+
+        let mut next = 0;
+        for a in &attached {
+            assert_eq!(a.leading, next..a.token);
+            assert!(!RustToken::TRIVIA.contains(tokens[a.token].0));
+            assert_eq!(a.trailing.start, a.token + 1);
+            next = a.trailing.end;
+        }
+        assert_eq!(next, tokens.len());
+
+        let first = attached.first().unwrap();
+        let last = attached.last().unwrap();
+        assert_eq!(first.text_range(&tokens).start, 0);
+        assert_eq!(last.text_range(&tokens).end, input.len());
+    }
+
+    #[test]
+    fn test_attach_trivia_empty() {
+        for input in ["", "  // Comment\n"] {
+            let tokens = RustLexer::new(input).collect::<Vec<_>>();
+            assert_eq!(attach_trivia(&tokens, RustToken::TRIVIA), []);
+        }
+    }
+}
@@ -0,0 +1,414 @@
+//! Tests that [`Lexer::next_token()`] does not allocate, by counting
+//! the allocations performed by the current thread.
+//!
+//! Each lexer is constructed before counting, such that only producing
+//! the tokens is counted. Additionally, all lexers must implement [`Clone`]
+//! without allocating, such that they can be cloned to look ahead.
+
+// Every test requires a lexer enabled by a `lang-*` feature
+#![cfg(any(
+    feature = "lang-apache",
+    feature = "lang-c",
+    feature = "lang-cmake",
+    feature = "lang-cpp",
+    feature = "lang-crontab",
+    feature = "lang-csharp",
+    feature = "lang-css",
+    feature = "lang-fortran",
+    feature = "lang-html",
+    feature = "lang-java",
+    feature = "lang-javascript",
+    feature = "lang-json",
+    feature = "lang-jsonc",
+    feature = "lang-less",
+    feature = "lang-lisp",
+    feature = "lang-markdown",
+    feature = "lang-meson",
+    feature = "lang-nginx",
+    feature = "lang-python",
+    feature = "lang-rust",
+    feature = "lang-scss",
+    feature = "lang-stylus",
+    feature = "lang-swift",
+    feature = "lang-systemd",
+    feature = "lang-toml",
+    feature = "lang-xml",
+))]
+
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::cell::Cell;
+
+use any_lexer::Lexer;
+
+struct CountingAlloc;
+
+thread_local! {
+    static ALLOCS: Cell<usize> = const { Cell::new(0) };
+}
+
+unsafe impl GlobalAlloc for CountingAlloc {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        ALLOCS.with(|allocs| allocs.set(allocs.get() + 1));
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout)
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        ALLOCS.with(|allocs| allocs.set(allocs.get() + 1));
+        System.realloc(ptr, layout, new_size)
+    }
+}
+
+#[global_allocator]
+static GLOBAL: CountingAlloc = CountingAlloc;
+
+/// Produces all tokens of `lexer` and of a clone of `lexer`, and asserts
+/// that no allocations were performed.
+fn assert_no_alloc<'text, L>(name: &str, mut lexer: L)
+where
+    L: Lexer<'text> + Clone,
+{
+    let before = ALLOCS.with(Cell::get);
+    let mut cloned = lexer.clone();
+    let mut count = 0usize;
+    while lexer.next_token().is_some() {
+        count += 1;
+    }
+    let mut cloned_count = 0usize;
+    while cloned.next_token().is_some() {
+        cloned_count += 1;
+    }
+    let allocs = ALLOCS.with(Cell::get) - before;
+
+    assert!(count > 0, "{name} produced no tokens");
+    assert_eq!(
+        count, cloned_count,
+        "{name} produced other tokens when cloned"
+    );
+    assert_eq!(allocs, 0, "{name} allocated while producing {count} tokens");
+}
+
+/// Any text is valid input for all lexers, so this is used for
+/// all lexers, in addition to input of their own language.
+const RUST: &str = include_str!("fixtures/rust/corpus.rs");
+
+#[cfg(feature = "lang-apache")]
+#[test]
+fn test_apache_lexer_no_alloc() {
+    use any_lexer::ApacheLexer;
+
+    let code = r#"
+# comment
+<VirtualHost *:80>
+    DocumentRoot "${SRVROOT}/htdocs"
+    RewriteRule ^/old/(.*)$ /new/$1 [R=301,L]
+</VirtualHost>
+"#;
+    assert_no_alloc("ApacheLexer", ApacheLexer::new(code));
+    assert_no_alloc("ApacheLexer", ApacheLexer::new(RUST));
+}
+
+#[cfg(feature = "lang-c")]
+#[test]
+fn test_c_lexer_no_alloc() {
+    use any_lexer::CLexer;
+
+    let code = r#"
+#include <stdio.h>
+/* block */ int main(void) { char c = '\n'; printf("%d\n", 0x1Fu + 1.5e3f); }
+"#;
+    assert_no_alloc("CLexer", CLexer::new(code));
+    assert_no_alloc("CLexer", CLexer::new(RUST));
+}
+
+#[cfg(feature = "lang-cmake")]
+#[test]
+fn test_cmake_lexer_no_alloc() {
+    use any_lexer::CMakeLexer;
+
+    let code = r#"
+#[[ bracket ]] cmake_minimum_required(VERSION 3.10)
+add_executable(app src/main.c "${NAME}.c" [=[raw]=]) # comment
+"#;
+    assert_no_alloc("CMakeLexer", CMakeLexer::new(code));
+    assert_no_alloc("CMakeLexer", CMakeLexer::new(RUST));
+}
+
+#[cfg(feature = "lang-crontab")]
+#[test]
+fn test_crontab_lexer_no_alloc() {
+    use any_lexer::CrontabLexer;
+
+    let code = r#"
+# m h dom mon dow command
+MAILTO=root
+*/15 9-17 * * mon-fri /usr/bin/backup --quiet
+@reboot sleep 60 && /usr/bin/app
+"#;
+    assert_no_alloc("CrontabLexer", CrontabLexer::new(code));
+    assert_no_alloc("CrontabLexer", CrontabLexer::new(RUST));
+}
+
+#[cfg(feature = "lang-cpp")]
+#[test]
+fn test_cpp_lexer_no_alloc() {
+    use any_lexer::CppLexer;
+
+    let code = r#"
+#if 1
+auto s = R"x(raw)x"; int n = 1'000;
+#endif
+"#;
+    assert_no_alloc("CppLexer", CppLexer::new(code));
+    assert_no_alloc("CppLexer", CppLexer::new(code).inactive_regions(true));
+    assert_no_alloc("CppLexer", CppLexer::new(RUST));
+}
+
+#[cfg(feature = "lang-csharp")]
+#[test]
+fn test_csharp_lexer_no_alloc() {
+    use any_lexer::CSharpLexer;
+
+    let code = r#"
+var s = $"{x,10:N2} {(b ? "}" : $"{y[(1)]}")}" + @"C:\" + """raw""";
+var json = $$"""{ "x": {{x}} }""";
+"#;
+    assert_no_alloc("CSharpLexer", CSharpLexer::new(code));
+    assert_no_alloc("CSharpLexer", CSharpLexer::new(RUST));
+}
+
+#[cfg(feature = "lang-css")]
+#[test]
+fn test_css_lexer_no_alloc() {
+    use any_lexer::CssLexer;
+
+    let code = r#"@media screen { a:hover { color: #FFF; width: calc(100% - 1.5em); } }"#;
+    assert_no_alloc("CssLexer", CssLexer::new(code));
+    assert_no_alloc("CssLexer", CssLexer::new(RUST));
+}
+
+#[cfg(feature = "lang-fortran")]
+#[test]
+fn test_fortran_lexer_no_alloc() {
+    use any_lexer::{FortranForm, FortranLexer};
+
+    let code =
+        "C comment\n  100 FORMAT('It''s', Z'FF')\n      X = 1.5D0 .EQ.\n     &    .TRUE. ! c\n";
+    assert_no_alloc("FortranLexer", FortranLexer::new(code));
+    assert_no_alloc(
+        "FortranLexer",
+        FortranLexer::new(code).form(FortranForm::Fixed),
+    );
+    assert_no_alloc("FortranLexer", FortranLexer::new(RUST));
+}
+
+#[cfg(feature = "lang-html")]
+#[test]
+fn test_html_lexer_no_alloc() {
+    use any_lexer::HtmlLexer;
+
+    let code =
+        r#"<!DOCTYPE html><p class="a">Hi &amp; <!-- c --></p><script>let x = "</p>";</script>"#;
+    assert_no_alloc("HtmlLexer", HtmlLexer::new(code));
+    assert_no_alloc("HtmlLexer", HtmlLexer::new(RUST));
+}
+
+#[cfg(feature = "lang-java")]
+#[test]
+fn test_java_lexer_no_alloc() {
+    use any_lexer::JavaLexer;
+
+    let code = "String s = \"\"\"\n    text block\n    \"\"\"; long n = 1_000L;";
+    assert_no_alloc("JavaLexer", JavaLexer::new(code));
+    assert_no_alloc("JavaLexer", JavaLexer::new(RUST));
+}
+
+#[cfg(feature = "lang-javascript")]
+#[test]
+fn test_javascript_lexer_no_alloc() {
+    use any_lexer::JavaScriptLexer;
+
+    // Only JSX allocates, for keeping track of nested elements
+    let code = r#"const re = /a+/g; const s = `a ${ { b: [1, 2] }["b"] } c`; x = y / 2;"#;
+    assert_no_alloc("JavaScriptLexer", JavaScriptLexer::new(code));
+    assert_no_alloc(
+        "JavaScriptLexer",
+        JavaScriptLexer::new(code).typescript(true),
+    );
+    assert_no_alloc("JavaScriptLexer", JavaScriptLexer::new(RUST).jsx(false));
+}
+
+#[cfg(feature = "lang-json")]
+#[test]
+fn test_json_lexer_no_alloc() {
+    use any_lexer::JsonLexer;
+
+    let code = r#"{ "a": [1, -2.5e3, true, null], "b": "\u00e9" }"#;
+    assert_no_alloc("JsonLexer", JsonLexer::new(code));
+    assert_no_alloc("JsonLexer", JsonLexer::new(code).loose(true));
+    assert_no_alloc("JsonLexer", JsonLexer::new(RUST));
+}
+
+#[cfg(feature = "lang-jsonc")]
+#[test]
+fn test_jsonc_lexer_no_alloc() {
+    use any_lexer::JsonCLexer;
+
+    let code = "{\n  // comment\n  \"a\": 1, /* block */\n}";
+    assert_no_alloc("JsonCLexer", JsonCLexer::new(code));
+    assert_no_alloc("JsonCLexer", JsonCLexer::new(RUST));
+}
+
+#[cfg(feature = "lang-less")]
+#[test]
+fn test_less_lexer_no_alloc() {
+    use any_lexer::LessLexer;
+
+    let code = "@w: 10px; .a { .mixin(); width: ~\"calc(@{w})\"; // c\n}";
+    assert_no_alloc("LessLexer", LessLexer::new(code));
+    assert_no_alloc("LessLexer", LessLexer::new(RUST));
+}
+
+#[cfg(feature = "lang-lisp")]
+#[test]
+fn test_lisp_lexer_no_alloc() {
+    use any_lexer::LispLikeLexer;
+
+    let code = "(defun f (x) ; comment\n  '(1 2.5 \"s\" #\\a))";
+    assert_no_alloc("LispLikeLexer", LispLikeLexer::new(code));
+    assert_no_alloc("LispLikeLexer", LispLikeLexer::new(RUST));
+}
+
+#[cfg(feature = "lang-markdown")]
+#[test]
+fn test_markdown_lexer_no_alloc() {
+    use any_lexer::MarkdownLexer;
+
+    let code = "# Heading\n\nSome *emphasis*, __strong__, `code`, and [a link](url).\n\n```rust\nfn main() {}\n```\n";
+    assert_no_alloc("MarkdownLexer", MarkdownLexer::new(code));
+    assert_no_alloc("MarkdownLexer", MarkdownLexer::new(RUST));
+}
+
+#[cfg(feature = "lang-meson")]
+#[test]
+fn test_meson_lexer_no_alloc() {
+    use any_lexer::MesonLexer;
+
+    let code = "project('app', 'c', version : '1.0') # comment\nif x != 0x1F\n  message(f'''@x@''')\nendif\n";
+    assert_no_alloc("MesonLexer", MesonLexer::new(code));
+    assert_no_alloc("MesonLexer", MesonLexer::new(RUST));
+}
+
+#[cfg(feature = "lang-nginx")]
+#[test]
+fn test_nginx_lexer_no_alloc() {
+    use any_lexer::NginxLexer;
+
+    let code = r#"
+# comment
+server {
+    listen 80;
+    location ~* \.(gif|jpg)$ { expires 30d; }
+    return 301 "https://$host${request_uri}";
+}
+"#;
+    assert_no_alloc("NginxLexer", NginxLexer::new(code));
+    assert_no_alloc("NginxLexer", NginxLexer::new(RUST));
+}
+
+#[cfg(feature = "lang-python")]
+#[test]
+fn test_python_lexer_no_alloc() {
+    use any_lexer::PythonLexer;
+
+    let code = include_str!("fixtures/python/corpus.py");
+    assert_no_alloc("PythonLexer", PythonLexer::new(code));
+    assert_no_alloc("PythonLexer", PythonLexer::new(RUST));
+}
+
+#[cfg(feature = "lang-rust")]
+#[test]
+fn test_rust_lexer_no_alloc() {
+    use any_lexer::RustLexer;
+
+    assert_no_alloc("RustLexer", RustLexer::new(RUST));
+}
+
+#[cfg(feature = "lang-scss")]
+#[test]
+fn test_scss_lexer_no_alloc() {
+    use any_lexer::ScssLexer;
+
+    let code = "$w: 10px; .a { &:hover { width: #{$w * 2}; } // c\n}";
+    assert_no_alloc("ScssLexer", ScssLexer::new(code));
+    assert_no_alloc("ScssLexer", ScssLexer::new(RUST));
+}
+
+#[cfg(feature = "lang-stylus")]
+#[test]
+fn test_stylus_lexer_no_alloc() {
+    use any_lexer::StylusLexer;
+
+    let code = "w = 10px\n.a\n  width w * 2 // c\n";
+    assert_no_alloc("StylusLexer", StylusLexer::new(code));
+    assert_no_alloc("StylusLexer", StylusLexer::new(RUST));
+}
+
+#[cfg(feature = "lang-swift")]
+#[test]
+fn test_swift_lexer_no_alloc() {
+    use any_lexer::SwiftLexer;
+
+    let code = r##"let s = "a \(f("b", [1, 2]) ) c"; let r = #"raw \#(x)"#; /* a /* b */ */"##;
+    assert_no_alloc("SwiftLexer", SwiftLexer::new(code));
+    assert_no_alloc("SwiftLexer", SwiftLexer::new(RUST));
+}
+
+#[cfg(feature = "lang-systemd")]
+#[test]
+fn test_systemd_lexer_no_alloc() {
+    use any_lexer::SystemdLexer;
+
+    let code = r#"
+# comment
+[Service]
+ExecStart=/usr/bin/app --name=%i $OPTIONS \
+    --verbose
+Restart=on-failure
+"#;
+    assert_no_alloc("SystemdLexer", SystemdLexer::new(code));
+    assert_no_alloc("SystemdLexer", SystemdLexer::new(RUST));
+}
+
+#[cfg(feature = "lang-toml")]
+#[test]
+fn test_toml_lexer_no_alloc() {
+    use any_lexer::TomlLexer;
+
+    let code = r#"
+# comment
+[[bin]]
+name = "app"
+deps = { serde = { version = "1.0", features = ["derive"] } }
+matrix = [[1, 2.5], [0x1F, 1979-05-27T07:32:00Z]]
+"#;
+    assert_no_alloc("TomlLexer", TomlLexer::new(code));
+    assert_no_alloc("TomlLexer", TomlLexer::new(RUST));
+}
+
+#[cfg(feature = "lang-xml")]
+#[test]
+fn test_xml_lexer_no_alloc() {
+    use any_lexer::XmlLexer;
+
+    let code = r#"
+<?xml version="1.0"?>
+<!DOCTYPE note [<!ENTITY e "x">]>
+<svg:rect xlink:href="a.svg">1 &lt; 2 &e;<![CDATA[<]]></svg:rect>
+"#;
+    assert_no_alloc("XmlLexer", XmlLexer::new(code));
+    assert_no_alloc("XmlLexer", XmlLexer::new(RUST));
+}
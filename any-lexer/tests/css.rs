@@ -0,0 +1,72 @@
+//! Tests tokenizing a corpus of real-world CSS patterns, i.e.
+//! `tests/fixtures/css/corpus.css`, as a whole file.
+
+#![cfg(feature = "lang-css")]
+
+use any_lexer::{CssLexer, CssToken};
+
+const CORPUS: &str = include_str!("fixtures/css/corpus.css");
+
+#[test]
+fn test_css_corpus_spans() {
+    let mut output = String::new();
+    for (tok, span) in CssLexer::new(CORPUS) {
+        let line = CORPUS[..span.start()].matches('\n').count() + 1;
+        assert_ne!(tok, CssToken::Unknown, "corpus.css:{line}: {span:?}");
+        output.push_str(span.as_str());
+    }
+    assert_eq!(output, CORPUS);
+}
+
+#[cfg(feature = "lang-scss")]
+#[test]
+fn test_css_corpus_scss() {
+    use any_lexer::ScssLexer;
+
+    // SCSS is a superset of CSS, so the tokens must be the same
+    let css = CssLexer::new(CORPUS).map(|(tok, span)| (tok.name(), span));
+    let scss = ScssLexer::new(CORPUS).map(|(tok, span)| (tok.name(), span));
+    assert!(css.eq(scss));
+}
+
+#[test]
+fn test_css_corpus_selectors() {
+    let selectors = CssLexer::new(CORPUS)
+        .filter(|(tok, _span)| CssToken::SELECTORS.contains(*tok))
+        .map(|(tok, span)| (tok, span.as_str()))
+        .collect::<Vec<_>>();
+
+    use CssToken::*;
+    #[rustfmt::skip]
+    let expected = [
+        (PseudoClass, ":root"),
+        (PseudoElement, "::before"),
+        (PseudoElement, "::after"),
+        (PseudoClass, ":not"), (AttrSelector, "[href]"), (PseudoClass, ":not"), (AttrSelector, "[class]"),
+        (PseudoClass, ":not"), (AttrSelector, "[href]"), (PseudoClass, ":not"), (AttrSelector, "[class]"), (PseudoClass, ":hover"),
+        (AttrSelector, "[title]"),
+        (AttrSelector, "[type=\"button\"]"),
+        (AttrSelector, "[type='reset']"),
+        (AttrSelector, "[type=submit]"),
+        (PseudoElement, "::-moz-focus-inner"),
+        (AttrSelector, "[type=\"button\"]"), (PseudoElement, "::-moz-focus-inner"),
+        (AttrSelector, "[type=\"search\" i]"), (PseudoElement, "::-webkit-search-decoration"),
+        (AttrSelector, "[href^=\"http\"]"), (PseudoClass, ":not"), (AttrSelector, "[href*=\"example.com\"]"), (PseudoElement, "::after"),
+        (AttrSelector, "[href$=\".pdf\"]"), (PseudoElement, "::after"),
+        (Combinator, ">"), (Combinator, "+"),
+        (PseudoClass, ":hover"),
+        (PseudoClass, ":focus-visible"),
+        (Combinator, "~"), (PseudoClass, ":first-of-type"), (PseudoElement, "::first-letter"),
+        (PseudoClass, ":nth-child"), (PseudoClass, ":not"), (PseudoClass, ":last-child"),
+        (PseudoClass, ":has"), (Combinator, ">"),
+        (AttrSelector, "[xlink|href]"),
+        (PseudoClass, ":hover"),
+        (Combinator, ">"),
+        (PseudoClass, ":not"), (Combinator, ">"),
+        (PseudoClass, ":not"), (PseudoClass, ":focus"), (PseudoClass, ":not"), (PseudoClass, ":active"),
+        (Combinator, ">"), (PseudoClass, ":not"), (Combinator, ">"), (Combinator, ">"),
+        (Combinator, ">"), (Combinator, ">"), (PseudoClass, ":nth-of-type"), (Combinator, ">"),
+    ];
+
+    assert_eq!(selectors, expected);
+}
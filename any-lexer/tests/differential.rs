@@ -0,0 +1,153 @@
+//! Differential tests comparing the token boundaries of the lexers
+//! against reference implementations.
+//!
+//! - `RustLexer` is compared against [`rustc_lexer`]
+//! - `PythonLexer` is compared against CPython's `tokenize`, using the
+//!   tokens recorded in `tests/fixtures/python/corpus.tokens`
+//!
+//! Only tokens with a well-defined extent are compared, e.g. identifiers,
+//! literals, and comments. Punctuation is not compared for Rust, as
+//! `rustc_lexer` produces single character punctuation, e.g. `:` `:`
+//! instead of `::`.
+//!
+//! Each test only runs when the lexer's `lang-*` feature is enabled.
+//! However, `rustc_lexer` is always a dev-dependency, as Cargo does not
+//! support optional dev-dependencies.
+//!
+//! When `tests/fixtures/python/corpus.py` is changed, then the recorded
+//! tokens must be updated by running:
+//!
+//! ```text
+//! python3 any-lexer/tests/fixtures/python/generate.py
+//! ```
+
+#![cfg(any(feature = "lang-rust", feature = "lang-python"))]
+
+use std::collections::HashSet;
+use std::fmt::Write;
+use std::ops::Range;
+
+use any_lexer::TokenSpan;
+
+/// Returns a report of the `expected` token ranges, which are
+/// not produced by `lexer`, or `None` if all are produced.
+fn mismatches<'text, Tok, I>(
+    name: &str,
+    text: &'text str,
+    lexer: I,
+    expected: impl IntoIterator<Item = (&'static str, Range<usize>)>,
+) -> Option<String>
+where
+    I: Iterator<Item = (Tok, TokenSpan<'text>)>,
+    Tok: std::fmt::Debug,
+{
+    let tokens = lexer
+        .map(|(tok, span)| (span.range(), tok))
+        .collect::<Vec<_>>();
+    let ranges = tokens
+        .iter()
+        .map(|(r, _tok)| r.clone())
+        .collect::<HashSet<_>>();
+
+    let mut report = String::new();
+    for (kind, r) in expected {
+        if ranges.contains(&r) {
+            continue;
+        }
+
+        let line = text[..r.start].matches('\n').count() + 1;
+        let found = tokens
+            .iter()
+            .filter(|(found, _tok)| (found.start < r.end) && (r.start < found.end))
+            .map(|(found, tok)| format!("{tok:?}({:?})", &text[found.clone()]))
+            .collect::<Vec<_>>();
+        writeln!(
+            report,
+            "{name}:{line}: expected {kind}({:?}), found {}",
+            &text[r],
+            found.join(" "),
+        )
+        .unwrap();
+    }
+
+    (!report.is_empty()).then_some(report)
+}
+
+#[cfg(feature = "lang-rust")]
+#[test]
+fn test_rust_lexer_differential() {
+    use any_lexer::RustLexer;
+    use rustc_lexer::TokenKind;
+
+    const CORPUS: &[(&str, &str)] = &[
+        (
+            "fixtures/rust/corpus.rs",
+            include_str!("fixtures/rust/corpus.rs"),
+        ),
+        ("any-lexer/src/lib.rs", include_str!("../src/lib.rs")),
+        (
+            "any-lexer/src/lexers/rust.rs",
+            include_str!("../src/lexers/rust.rs"),
+        ),
+        (
+            "text-scanner/src/lib.rs",
+            include_str!("../../text-scanner/src/lib.rs"),
+        ),
+        (
+            "text-scanner/src/ext/rust.rs",
+            include_str!("../../text-scanner/src/ext/rust.rs"),
+        ),
+    ];
+
+    let mut report = String::new();
+    for &(name, text) in CORPUS {
+        let mut pos = 0;
+        let expected = rustc_lexer::tokenize(text).filter_map(|tok| {
+            let r = pos..(pos + tok.len);
+            pos = r.end;
+
+            let kind = match tok.kind {
+                TokenKind::LineComment => "LineComment",
+                TokenKind::BlockComment { .. } => "BlockComment",
+                TokenKind::Ident | TokenKind::RawIdent => "Ident",
+                TokenKind::Literal { .. } => "Literal",
+                TokenKind::Lifetime { .. } => "Lifetime",
+                _ => return None,
+            };
+            Some((kind, r))
+        });
+
+        if let Some(mismatches) = mismatches(name, text, RustLexer::new(text), expected) {
+            report.push_str(&mismatches);
+        }
+    }
+
+    assert!(report.is_empty(), "token boundary mismatches:\n{report}");
+}
+
+#[cfg(feature = "lang-python")]
+#[test]
+fn test_python_lexer_differential() {
+    use any_lexer::PythonLexer;
+
+    let text = include_str!("fixtures/python/corpus.py");
+    let recorded = include_str!("fixtures/python/corpus.tokens");
+
+    let expected = recorded.lines().map(|line| {
+        let mut parts = line.split(' ');
+        let mut next = || parts.next().expect("invalid recorded token");
+        let kind = next();
+        let start = next().parse().unwrap();
+        let end = next().parse().unwrap();
+        (kind, start..end)
+    });
+
+    let report = mismatches(
+        "fixtures/python/corpus.py",
+        text,
+        PythonLexer::new(text),
+        expected,
+    )
+    .unwrap_or_default();
+    assert!(report.is_empty(), "token boundary mismatches:\n{report}");
+}
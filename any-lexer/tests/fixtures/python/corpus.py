@@ -0,0 +1,75 @@
+#!/usr/bin/env python3
+# -*- coding: utf-8 -*-
+"""Corpus for the differential tests of `PythonLexer`."""
+
+import os.path
+from typing import Any, Optional
+
+__all__ = ["Point", "walk"]
+
+INT = 1_000_000 + 0x_FF + 0o777 + 0b1010_1010 + 0
+FLOAT = 1. + .5 + 1.5e-10 + 3.14_15 + 1E+3
+COMPLEX = 3j + 2.5J + 1e3j + .5j + 1_0j
+ELLIPSIS = ...
+
+
+class Point:
+    """A point in 2D space."""
+
+    __slots__ = ("x", "y")
+
+    def __init__(self, x: float = 0.0, y: float = 0.0) -> None:
+        self.x, self.y = x, y
+
+    @property
+    def length(self) -> float:
+        return (self.x ** 2 + self.y ** 2) ** 0.5
+
+    def __matmul__(self, other: "Point") -> float:
+        return self.x * other.x + self.y * other.y
+
+    def __repr__(self):
+        return f"Point({self.x!r}, {self.y!r})"
+
+
+def walk(path, *args, depth=0, **kwargs) -> Optional[Any]:
+    if (n := len(args)) >= 2 and not depth:
+        pass
+    elif n != 1 or depth <= -1:
+        return None
+    result = [p @ p for p in args if p is not None]
+    result += {k: v for k, v in kwargs.items()}.keys() or ()
+    mask = ~depth & 0xF | 1 << 2 ^ 3 >> 1
+    mask //= 2; mask %= 3; mask **= 2; mask <<= 1; mask >>= 1
+    mask &= 1; mask |= 2; mask ^= 3; mask -= 1; mask *= 2; mask /= 1
+    lambda x, /, y, *, z: x[1:2, ::3]
+    total = 1 + \
+        2
+    return os.path.join(path, *result) if total else depth
+
+
+strings = [
+    'single', "double", '''triple
+single''', """triple
+double""",
+    r'\d+', R"raw", b'bytes', B"BYTES", rb'\x00', Br"raw bytes",
+    u'unicode', f'{1 + 1}', F"{'nested'}", fr'\{path}', RF'{x!r:>10}',
+    'escaped \' quote', "escaped \" quote", 'line \
+continuation',
+]
+
+try:
+    assert strings, "not empty"
+except (ValueError, TypeError) as e:
+    raise RuntimeError("failed") from e
+finally:
+    del strings
+
+match COMPLEX:
+    case 0 | 1:
+        print(True, False, None)
+    case _:
+        async def f():
+            await g()
+            yield from h()
+        global INT
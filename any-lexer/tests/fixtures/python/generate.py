@@ -0,0 +1,52 @@
+"""Records the tokens of `corpus.py` produced by CPython's `tokenize`.
+
+The tokens are written to `corpus.tokens`, one token per line, as
+`KIND START END`, where `START` and `END` are byte offsets.
+
+Run from any directory:
+
+    python3 any-lexer/tests/fixtures/python/generate.py
+"""
+
+import io
+import tokenize
+from pathlib import Path
+
+DIR = Path(__file__).parent
+
+# Tokens without text, e.g. `INDENT`, are not compared
+KINDS = {
+    tokenize.NAME: "NAME",
+    tokenize.NUMBER: "NUMBER",
+    tokenize.STRING: "STRING",
+    tokenize.COMMENT: "COMMENT",
+    tokenize.OP: "OP",
+}
+
+
+def main():
+    text = (DIR / "corpus.py").read_bytes().decode("utf-8")
+
+    # Byte offset of the start of each line
+    line_starts = [0]
+    for line in text.splitlines(keepends=True):
+        line_starts.append(line_starts[-1] + len(line.encode("utf-8")))
+
+    def offset(row, col):
+        line = text.splitlines(keepends=True)[row - 1]
+        return line_starts[row - 1] + len(line[:col].encode("utf-8"))
+
+    lines = []
+    for tok in tokenize.generate_tokens(io.StringIO(text).readline):
+        kind = KINDS.get(tok.type)
+        if kind is None:
+            continue
+        start = offset(*tok.start)
+        end = offset(*tok.end)
+        lines.append(f"{kind} {start} {end}\n")
+
+    (DIR / "corpus.tokens").write_text("".join(lines), newline="\n")
+
+
+if __name__ == "__main__":
+    main()
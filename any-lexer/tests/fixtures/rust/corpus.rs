@@ -0,0 +1,63 @@
+//! Corpus for the differential tests of `RustLexer`.
+
+#![allow(dead_code)]
+
+/* Block comment /* nested */ still comment */
+/** Outer block doc comment */
+
+use std::collections::HashMap;
+
+const INTS: [u64; 6] = [1_000, 0xFF_u64 as u64, 0o777, 0b1010, 1u64, 2usize as u64];
+const FLOATS: [f64; 5] = [1.0, 1e10, 2.5E-3, 1_0.0_1f64, 3f64];
+
+static CHARS: [char; 6] = ['a', '\'', '\\', '\n', '\u{1F980}', '🦀'];
+static BYTES: [u8; 3] = [b'a', b'\'', b'\x7F'];
+
+struct Wrapper<'a, T: 'a + ?Sized> {
+    inner: &'a T,
+    map: HashMap<&'static str, Vec<Option<u8>>>,
+}
+
+impl<'a, T: ?Sized> Wrapper<'a, T> {
+    fn strings() -> [&'static str; 6] {
+        [
+            "string",
+            "escaped \" quote",
+            "multi
+line",
+            r"raw \string",
+            r#"raw "hash" string"#,
+            r##"raw "#" string"##,
+        ]
+    }
+
+    fn byte_strings() -> [&'static [u8]; 2] {
+        [b"bytes", br#"raw "bytes""#]
+    }
+
+    fn r#match(&self, r#type: u8) -> bool {
+        let x = 1..=2;
+        let y = 0..r#type;
+        'outer: loop {
+            break 'outer x.contains(&r#type) && !y.is_empty();
+        }
+    }
+}
+
+macro_rules! square {
+    ($x:expr) => {
+        $x * $x
+    };
+}
+
+fn main() {
+    let tuple = (1, 2.0, "three");
+    let _ = tuple.0 + tuple.1 as i32;
+    let _ = square!(4) << 1 >> 1 | 2 & 3 ^ 4;
+    let mut n = -1_i32;
+    n += 1; n -= 1; n *= 2; n /= 2; n %= 3; n <<= 1; n >>= 1; n &= 1; n |= 2; n ^= 3;
+    let _ = |a: i32, b| -> i32 { a + b };
+    let _ = if n <= 0 || n >= 10 && n != 5 { n } else { !n };
+    let _: Option<&dyn Fn(u8) -> u8> = None;
+    println!("{n:?} {}", format_args!("{}", 'c'));
+}
@@ -1,29 +1,91 @@
-#![forbid(unsafe_code)]
+#![cfg_attr(not(feature = "plugin"), forbid(unsafe_code))]
+#![cfg_attr(feature = "plugin", deny(unsafe_code))]
 #![forbid(elided_lifetimes_in_paths)]
 
 use std::error;
+use std::fmt::Write as _;
 use std::fs;
 use std::io::{self, Write};
-use std::path::PathBuf;
+use std::ops::Range;
+use std::path::{Path, PathBuf};
 use std::process::exit;
 
-use colorblast::{Color, Lexer, StylizeToken};
-use image::{Pixel, Rgba, RgbaImage};
+use colorblast::lines::{LineBackgrounds, LineClass};
+#[cfg(feature = "plugin")]
+use colorblast::plugin::{PluginLexer, Plugins};
+use colorblast::registry::LexerRegistry;
+use colorblast::{Color, Error, Lexer, LineIndex, Style, Theme, TokenSpan};
+use image::imageops::{self, FilterType};
+use image::{ImageFormat, Pixel, Rgba, RgbaImage};
 use rusttype::{point, Font, GlyphId, Point, PositionedGlyph, Rect, Scale};
 use structopt::StructOpt;
+use ttf_parser::{Face, RasterImageFormat};
 
 #[derive(StructOpt, Debug)]
 pub struct Opt {
-    #[structopt(parse(from_os_str))]
-    font_file: PathBuf,
-    #[structopt(parse(from_os_str))]
-    file: PathBuf,
+    #[structopt(parse(from_os_str), required_unless = "list-themes")]
+    font_file: Option<PathBuf>,
+    #[structopt(parse(from_os_str), required_unless = "list-themes")]
+    file: Option<PathBuf>,
     #[structopt(short, long, parse(from_os_str), default_value = "code.png")]
     output: PathBuf,
+    /// Write the dimensions of the image, and the pixel rectangles of each
+    /// line and token as JSON, e.g. for overlaying annotations on tokens
+    #[structopt(long, parse(from_os_str))]
+    report: Option<PathBuf>,
+    /// Language used for highlighting, e.g. `rust` or `json`, defaults to
+    /// the language matching the extension of `file`, otherwise `rust`
+    #[structopt(short, long)]
+    lang: Option<String>,
+    /// Config file overriding the extensions and names of languages,
+    /// with `[extensions]` and `[aliases]` sections, e.g. `conf = nginx`
+    #[structopt(long, parse(from_os_str))]
+    lang_config: Option<PathBuf>,
+    /// Dynamic library providing additional languages, which take
+    /// precedence over the built-in languages with the same name
+    #[cfg(feature = "plugin")]
+    #[structopt(long, parse(from_os_str), number_of_values = 1)]
+    plugin: Vec<PathBuf>,
+    /// Theme used for highlighting, either the name of a built-in theme,
+    /// e.g. `high-contrast`, or the path to a Visual Studio Code theme
+    /// `.json` file
+    #[structopt(short, long, default_value = "default")]
+    theme: String,
+    /// List the names of the built-in themes and exit
+    #[structopt(long)]
+    list_themes: bool,
     #[structopt(long)]
     font_size: Option<f32>,
     #[structopt(long)]
     margin: Option<u32>,
+    /// Disable kerning, for strict monospace rendering
+    ///
+    /// Ligatures are never applied, as glyphs are not shaped,
+    /// i.e. each character is rendered as its own glyph.
+    #[structopt(long)]
+    no_kerning: bool,
+    /// Line height multiplier, relative to the font's line height
+    #[structopt(long)]
+    line_height: Option<f32>,
+    /// Font used for characters missing in `font_file`, e.g. an emoji font
+    ///
+    /// Color bitmap glyphs (CBDT/sbix) are rendered in color, while
+    /// color vector glyphs (COLR/SVG) are rendered from their outlines
+    /// in the token color.
+    #[structopt(long, parse(from_os_str))]
+    fallback_font: Option<PathBuf>,
+    /// Stripe the background of every other line
+    #[structopt(long)]
+    striped: bool,
+    /// Highlight lines as added, e.g. `3` or `3-5`, where lines are 1-indexed
+    #[structopt(long, parse(try_from_str = parse_lines), number_of_values = 1)]
+    added: Vec<Range<usize>>,
+    /// Highlight lines as removed, e.g. `3` or `3-5`, where lines are 1-indexed
+    #[structopt(long, parse(try_from_str = parse_lines), number_of_values = 1)]
+    removed: Vec<Range<usize>>,
+    /// Highlight lines in focus, e.g. `3` or `3-5`, where lines are 1-indexed
+    #[structopt(long, parse(try_from_str = parse_lines), number_of_values = 1)]
+    focus: Vec<Range<usize>>,
 }
 
 fn main() {
@@ -43,46 +105,113 @@ fn main() {
 }
 
 fn try_main(opt: &Opt) -> Result<(), Box<dyn error::Error>> {
-    println!("Loading code `{}`", opt.file.display());
-    let code = fs::read_to_string(&opt.file)?;
+    if opt.list_themes {
+        for name in Theme::BUILTIN {
+            println!("{name}");
+        }
+        return Ok(());
+    }
+
+    let font_file = opt
+        .font_file
+        .as_ref()
+        .expect("required unless `--list-themes`");
+    let file = opt.file.as_ref().expect("required unless `--list-themes`");
+
+    let registry = load_registry(opt.lang_config.as_deref())?;
+    #[cfg(not(feature = "plugin"))]
+    let lexer = select_lexer(&registry, opt.lang.as_deref(), file)?;
+    #[cfg(feature = "plugin")]
+    let plugins = load_plugins(&opt.plugin)?;
+    #[cfg(feature = "plugin")]
+    let plugin = match &opt.lang {
+        Some(lang) => plugins.by_name(lang),
+        None => file
+            .extension()
+            .and_then(|ext| ext.to_str())
+            .and_then(|ext| plugins.by_extension(ext)),
+    };
+    #[cfg(feature = "plugin")]
+    let lexer = match plugin {
+        Some(lexer) => CodeLexer::Plugin(lexer),
+        None => CodeLexer::Builtin(select_lexer(&registry, opt.lang.as_deref(), file)?),
+    };
+    let theme = load_theme(&opt.theme)?;
+
+    println!("Loading code `{}`", file.display());
+    let code = fs::read_to_string(file)?;
+    // Remove escape sequences, e.g. from the already colored output of
+    // another tool, such that they are not classified and rendered as code
+    let code = colorblast::strip_ansi(&code).text;
 
     if code.is_empty() {
-        println!("`{}` is empty", opt.file.display());
+        println!("`{}` is empty", file.display());
         println!("no image rendered");
         return Ok(());
     }
 
-    println!("Loading font `{}`", opt.font_file.display());
-    let font_data = fs::read(&opt.font_file)?;
-    let font = Font::try_from_bytes(&font_data).expect("invalid font data");
+    println!("Loading font `{}`", font_file.display());
+    let font_data = fs::read(font_file)?;
+    let font = Font::try_from_bytes(&font_data)
+        .ok_or_else(|| Error::Render(format!("invalid font data in `{}`", font_file.display())))?;
+
+    let fallback_font_data = match &opt.fallback_font {
+        Some(fallback_font) => {
+            println!("Loading fallback font `{}`", fallback_font.display());
+            Some((fallback_font, fs::read(fallback_font)?))
+        }
+        None => None,
+    };
+    let fallback_font =
+        match &fallback_font_data {
+            Some((path, data)) => Some(Font::try_from_bytes(data).ok_or_else(|| {
+                Error::Render(format!("invalid font data in `{}`", path.display()))
+            })?),
+            None => None,
+        };
+
+    let fonts = [Some(&font), fallback_font.as_ref()];
+    let fonts = fonts.into_iter().flatten().collect::<Vec<_>>();
 
     let font_size = opt.font_size.unwrap_or(12.0);
     let scale = Scale::uniform(font_size);
 
+    let fonts_data = [
+        Some(font_data.as_slice()),
+        fallback_font_data.as_ref().map(|(_, data)| data.as_slice()),
+    ];
+    let fonts_data = fonts_data.into_iter().flatten();
+    let color_glyphs = ColorGlyphs::new(fonts.iter().copied().zip(fonts_data), scale);
+
     let margin = opt.margin.unwrap_or(20);
     let start = point(margin as f32, margin as f32);
 
-    let mut layout = Layout::new(&font, start, scale);
+    let line_height = opt.line_height.unwrap_or(1.0);
+    let mut layout = Layout::new(&fonts, start, scale, line_height);
+    layout.kerning = !opt.no_kerning;
 
     let (w, h) = {
         println!("Measuring...");
 
         let mut layout = layout.clone();
-        let rect = code.chars().map(|c| layout.next_glyph(c)).fold(
-            Rect {
-                min: point(i32::MAX, i32::MAX),
-                max: point(i32::MIN, i32::MIN),
-            },
-            |mut rect, glyph| {
-                if let Some(bounding_box) = glyph.pixel_bounding_box() {
-                    rect.min.x = rect.min.x.min(bounding_box.min.x);
-                    rect.min.y = rect.min.y.min(bounding_box.min.y);
-                    rect.max.x = rect.max.x.max(bounding_box.max.x);
-                    rect.max.y = rect.max.y.max(bounding_box.max.y);
-                }
-                rect
-            },
-        );
+        let rect = code
+            .chars()
+            .map(|c| glyph_bounding_box(&mut layout, &color_glyphs, c))
+            .fold(
+                Rect {
+                    min: point(i32::MAX, i32::MAX),
+                    max: point(i32::MIN, i32::MIN),
+                },
+                |mut rect, bounding_box| {
+                    if let Some(bounding_box) = bounding_box {
+                        rect.min.x = rect.min.x.min(bounding_box.min.x);
+                        rect.min.y = rect.min.y.min(bounding_box.min.y);
+                        rect.max.x = rect.max.x.max(bounding_box.max.x);
+                        rect.max.y = rect.max.y.max(bounding_box.max.y);
+                    }
+                    rect
+                },
+            );
 
         let w = rect.max.x - rect.min.x;
         let h = rect.max.y - rect.min.y;
@@ -97,26 +226,177 @@ fn try_main(opt: &Opt) -> Result<(), Box<dyn error::Error>> {
     };
 
     println!("Rendering...");
-    let (bg_r, bg_g, bg_b) = (30, 30, 30);
+    let background = theme
+        .background_color()
+        .unwrap_or(Color::from((30, 30, 30)));
+    let backgrounds = line_backgrounds(opt, &theme);
+
+    #[cfg(not(feature = "plugin"))]
+    let tokens = theme.highlight(lexer, &code);
+    #[cfg(feature = "plugin")]
+    let tokens = lexer.highlight(&theme, &code);
+
+    let (img, report) = render_image(
+        layout,
+        &color_glyphs,
+        tokens,
+        (w, h),
+        background,
+        (&backgrounds, &LineIndex::new(&code)),
+    );
+
+    img.save(&opt.output)?;
+    println!("Rendered `{}`", opt.output.display());
+
+    if let Some(path) = &opt.report {
+        fs::write(path, report.to_json(&code))?;
+        println!("Wrote report `{}`", path.display());
+    }
+
+    Ok(())
+}
+
+/// Returns the line backgrounds selected by `--striped`, `--added`,
+/// `--removed`, and `--focus`.
+fn line_backgrounds(opt: &Opt, theme: &Theme) -> LineBackgrounds {
+    let mut backgrounds = LineBackgrounds::for_theme(theme);
+    if opt.striped {
+        backgrounds = backgrounds.striped();
+    }
+    let classes = [
+        (&opt.added, LineClass::Added),
+        (&opt.removed, LineClass::Removed),
+        (&opt.focus, LineClass::Focus),
+    ];
+    for (lines, class) in classes {
+        for lines in lines {
+            backgrounds = backgrounds.lines(lines.clone(), class);
+        }
+    }
+    backgrounds
+}
+
+/// Parses 1-indexed lines, e.g. `3` or `3-5`, into 0-indexed lines.
+fn parse_lines(s: &str) -> Result<Range<usize>, String> {
+    let parse = |line: &str| match line.trim().parse::<usize>() {
+        Ok(line) if line > 0 => Ok(line),
+        _ => Err(format!(
+            "invalid line `{line}`, expected a line number from 1"
+        )),
+    };
+    let (first, last) = match s.split_once('-') {
+        Some((first, last)) => (parse(first)?, parse(last)?),
+        None => (parse(s)?, parse(s)?),
+    };
+    if first > last {
+        return Err(format!("invalid lines `{s}`, expected `first-last`"));
+    }
+    Ok((first - 1)..last)
+}
+
+/// Renders `tokens` onto an image of size `(w, h)`, and returns the image
+/// along with the pixel rectangles of each line and token.
+///
+/// The `backgrounds` of the lines in `index` cover
+/// the whole width of the image.
+fn render_image<'text, I>(
+    mut layout: Layout<'_, '_>,
+    color_glyphs: &ColorGlyphs<'_>,
+    tokens: I,
+    (w, h): (u32, u32),
+    background: Color,
+    (backgrounds, index): (&LineBackgrounds, &LineIndex),
+) -> (RgbaImage, RenderReport)
+where
+    I: IntoIterator<Item = (Style, TokenSpan<'text>)>,
+{
+    let Color([bg_r, bg_g, bg_b, _a]) = background;
     let mut img = RgbaImage::from_pixel(w, h, Rgba([bg_r, bg_g, bg_b, 255]));
 
-    let lexer = Lexer::Rust.into_lexer(&code);
-    for (tok, span) in lexer {
-        let style = tok.style(&span);
+    // Backgrounds are drawn before the glyphs, such that they
+    // do not cover glyphs extending into neighbouring lines
+    for (line, range, bg) in backgrounds.line_ranges(index) {
+        // The empty line after a trailing line break is not rendered
+        if range.is_empty() {
+            continue;
+        }
+        let Color([r, g, b, _a]) = bg;
+        let top = layout.line_top() + (line as f32) * layout.line_height();
+        let bottom = top + layout.line_height();
+        let (top, bottom) = (top.round().max(0.0) as u32, bottom.round() as u32);
+        for y in top..bottom.min(h) {
+            for x in 0..w {
+                img.put_pixel(x, y, Rgba([r, g, b, 255]));
+            }
+        }
+    }
+    let mut report = RenderReport {
+        width: w,
+        height: h,
+        lines: Vec::new(),
+        tokens: Vec::new(),
+    };
 
+    let mut pos = 0;
+    for (index, (style, span)) in tokens.into_iter().enumerate() {
         let (r, g, b) = match style.fg {
             Some(Color([r, g, b, _a])) => (r, g, b),
             _ => (255, 255, 255),
         };
 
+        // Part of the token on the current line
+        let mut part: Option<TokenBox> = None;
+
         for c in span.as_str().chars() {
+            let (line, top) = (layout.line(), layout.line_top());
             let glyph = layout.next_glyph(c);
 
+            let range = pos..(pos + c.len_utf8());
+            pos = range.end;
+
+            let bottom = top + layout.line_height();
+            let left = glyph.position().x;
+
+            // Line breaks are excluded from the boxes, but
+            // still result in a box for empty lines
+            if c == '\n' {
+                let rect = PixelRect::new(point(left, top), point(left, bottom));
+                report.push_line(line, range.start..range.start, rect);
+                report.tokens.extend(part.take());
+                continue;
+            }
+
+            let rect = PixelRect::new(point(left, top), point(layout.caret().x, bottom));
+            report.push_line(line, range.clone(), rect);
+            match &mut part {
+                Some(part) => {
+                    part.range.end = range.end;
+                    part.rect = part.rect.union(rect);
+                }
+                None => {
+                    part = Some(TokenBox {
+                        token: index,
+                        line,
+                        range,
+                        rect,
+                    });
+                }
+            }
+
             if c.is_control() {
                 continue;
             }
 
-            if let Some(bounding_box) = glyph.pixel_bounding_box() {
+            if let Some((min, bitmap)) = color_glyphs.rasterize(&layout, &glyph) {
+                for (x, y, &pixel) in bitmap.enumerate_pixels() {
+                    let x = x.wrapping_add_signed(min.x);
+                    let y = y.wrapping_add_signed(min.y);
+
+                    if let Some(dst) = img.get_pixel_mut_checked(x, y) {
+                        dst.blend(&pixel);
+                    }
+                }
+            } else if let Some(bounding_box) = glyph.pixel_bounding_box() {
                 glyph.draw(|x, y, v| {
                     let x = x.wrapping_add_signed(bounding_box.min.x);
                     let y = y.wrapping_add_signed(bounding_box.min.y);
@@ -127,54 +407,417 @@ fn try_main(opt: &Opt) -> Result<(), Box<dyn error::Error>> {
                 });
             }
         }
+
+        report.tokens.extend(part);
     }
 
-    img.save(&opt.output)?;
-    println!("Rendered `{}`", opt.output.display());
+    (img, report)
+}
 
-    Ok(())
+/// Dimensions of a rendered image, and the pixel rectangles of its
+/// lines and tokens, see `--report`.
+#[derive(Clone, Debug)]
+pub struct RenderReport {
+    pub width: u32,
+    pub height: u32,
+    /// Box of each line, excluding the line break.
+    pub lines: Vec<LineBox>,
+    /// Box of each token, where tokens spanning multiple
+    /// lines have a box for each line.
+    pub tokens: Vec<TokenBox>,
+}
+
+impl RenderReport {
+    /// Extends the box of `line` to include `range` and `rect`.
+    fn push_line(&mut self, line: usize, range: Range<usize>, rect: PixelRect) {
+        match self.lines.last_mut() {
+            Some(last) if last.line == line => {
+                last.range.end = range.end;
+                last.rect = last.rect.union(rect);
+            }
+            _ => self.lines.push(LineBox { line, range, rect }),
+        }
+    }
+
+    /// Returns the report as JSON, where each token
+    /// includes its text in `code`.
+    pub fn to_json(&self, code: &str) -> String {
+        let mut json = String::new();
+        _ = write!(
+            json,
+            "{{\"width\":{},\"height\":{},\"lines\":[",
+            self.width, self.height
+        );
+        for (i, line) in self.lines.iter().enumerate() {
+            if i > 0 {
+                json.push(',');
+            }
+            _ = write!(json, "{{\"line\":{},", line.line);
+            push_json_range(&mut json, &line.range, line.rect);
+            json.push('}');
+        }
+        json.push_str("],\"tokens\":[");
+        for (i, tok) in self.tokens.iter().enumerate() {
+            if i > 0 {
+                json.push(',');
+            }
+            _ = write!(json, "{{\"token\":{},\"line\":{},", tok.token, tok.line);
+            push_json_range(&mut json, &tok.range, tok.rect);
+            json.push_str(",\"text\":");
+            push_json_str(&mut json, &code[tok.range.clone()]);
+            json.push('}');
+        }
+        json.push_str("]}\n");
+        json
+    }
+}
+
+/// Box of a line, see [`RenderReport`].
+#[derive(Clone, Debug)]
+pub struct LineBox {
+    /// 0-indexed line number.
+    pub line: usize,
+    /// Byte range of the line in the code.
+    pub range: Range<usize>,
+    pub rect: PixelRect,
+}
+
+/// Box of a token on a single line, see [`RenderReport`].
+#[derive(Clone, Debug)]
+pub struct TokenBox {
+    /// Index of the token, in the order produced by the lexer.
+    pub token: usize,
+    /// 0-indexed line number.
+    pub line: usize,
+    /// Byte range of the token, on this line, in the code.
+    pub range: Range<usize>,
+    pub rect: PixelRect,
+}
+
+/// Rectangle in pixels, where `(x, y)` is the top left corner.
+///
+/// The height is the height of the line, i.e. it is the same
+/// for all characters regardless of their glyphs.
+#[derive(Clone, Copy, Debug)]
+pub struct PixelRect {
+    pub x: i32,
+    pub y: i32,
+    pub width: u32,
+    pub height: u32,
+}
+
+impl PixelRect {
+    /// Returns the smallest rectangle containing `min` and `max`.
+    fn new(min: Point<f32>, max: Point<f32>) -> Self {
+        let (x, y) = (min.x.floor() as i32, min.y.floor() as i32);
+        let (right, bottom) = (max.x.ceil() as i32, max.y.ceil() as i32);
+        Self {
+            x,
+            y,
+            width: right.saturating_sub(x).max(0) as u32,
+            height: bottom.saturating_sub(y).max(0) as u32,
+        }
+    }
+
+    fn union(self, other: Self) -> Self {
+        let right = (self.x + self.width as i32).max(other.x + other.width as i32);
+        let bottom = (self.y + self.height as i32).max(other.y + other.height as i32);
+        let (x, y) = (self.x.min(other.x), self.y.min(other.y));
+        Self {
+            x,
+            y,
+            width: (right - x) as u32,
+            height: (bottom - y) as u32,
+        }
+    }
+}
+
+fn push_json_range(json: &mut String, range: &Range<usize>, rect: PixelRect) {
+    _ = write!(
+        json,
+        "\"start\":{},\"end\":{},\"x\":{},\"y\":{},\"width\":{},\"height\":{}",
+        range.start, range.end, rect.x, rect.y, rect.width, rect.height
+    );
+}
+
+fn push_json_str(json: &mut String, s: &str) {
+    json.push('"');
+    for c in s.chars() {
+        match c {
+            '"' => json.push_str("\\\""),
+            '\\' => json.push_str("\\\\"),
+            '\n' => json.push_str("\\n"),
+            '\r' => json.push_str("\\r"),
+            '\t' => json.push_str("\\t"),
+            c if c.is_control() => {
+                _ = write!(json, "\\u{:04X}", c as u32);
+            }
+            c => json.push(c),
+        }
+    }
+    json.push('"');
+}
+
+/// Lexer selected by `--lang`, which is either built-in or provided by a plugin.
+#[cfg(feature = "plugin")]
+#[derive(Clone, Copy)]
+enum CodeLexer<'a> {
+    Builtin(Lexer),
+    Plugin(&'a PluginLexer),
+}
+
+#[cfg(feature = "plugin")]
+impl<'a> CodeLexer<'a> {
+    fn highlight<'text>(
+        self,
+        theme: &'a Theme,
+        code: &'text str,
+    ) -> Box<dyn Iterator<Item = (Style, TokenSpan<'text>)> + 'a>
+    where
+        'text: 'a,
+    {
+        match self {
+            Self::Builtin(lexer) => Box::new(theme.highlight(lexer, code)),
+            Self::Plugin(lexer) => Box::new(
+                lexer
+                    .lex(code)
+                    .map(move |(tok, span)| (theme.style(tok), span)),
+            ),
+        }
+    }
+}
+
+/// Loads the plugins at `paths`, see `colorblast::plugin`.
+#[cfg(feature = "plugin")]
+#[allow(unsafe_code)]
+fn load_plugins(paths: &[PathBuf]) -> Result<Plugins, Box<dyn error::Error>> {
+    let mut plugins = Plugins::new();
+    for path in paths {
+        println!("Loading plugin `{}`", path.display());
+        // Safety: Plugins are explicitly passed by the user, and thereby trusted
+        let count = unsafe { plugins.load(path) }?;
+        if count == 0 {
+            return Err(format!("plugin `{}` registered no languages", path.display()).into());
+        }
+    }
+    Ok(plugins)
+}
+
+/// Loads the language overrides at `path`, if any,
+/// see `colorblast::registry`.
+fn load_registry(path: Option<&Path>) -> Result<LexerRegistry, Box<dyn error::Error>> {
+    let Some(path) = path else {
+        return Ok(LexerRegistry::new());
+    };
+    println!("Loading language config `{}`", path.display());
+    let config = fs::read_to_string(path)?;
+    LexerRegistry::from_config(&config).map_err(|err| format!("`{}`: {err}", path.display()).into())
+}
+
+/// Returns the lexer named `lang` if any, otherwise the lexer matching
+/// the extension of `file`, and otherwise the Rust lexer.
+fn select_lexer(
+    registry: &LexerRegistry,
+    lang: Option<&str>,
+    file: &Path,
+) -> Result<Lexer, Box<dyn error::Error>> {
+    match lang {
+        Some(lang) => Ok(registry.by_name(lang)?),
+        None => Ok(registry.by_path(file).unwrap_or(Lexer::Rust)),
+    }
+}
+
+/// Loads the theme at `theme` if it is a path to a `.json` file, otherwise
+/// returns the built-in theme named `theme`.
+fn load_theme(theme: &str) -> Result<Theme, Box<dyn error::Error>> {
+    let path = Path::new(theme);
+    if path
+        .extension()
+        .is_some_and(|ext| ext.eq_ignore_ascii_case("json"))
+    {
+        println!("Loading theme `{}`", path.display());
+        let json = fs::read_to_string(path)?;
+        return Ok(Theme::from_vscode_json(&json)?);
+    }
+
+    Theme::by_name(theme).map_err(|err| {
+        let names = Theme::BUILTIN.join("`, `");
+        format!("{err}, expected `{names}` or a `.json` file").into()
+    })
+}
+
+/// Returns the pixel bounding box of the next glyph of `layout` for `c`,
+/// which for color bitmap glyphs is the box of the bitmap.
+fn glyph_bounding_box(
+    layout: &mut Layout<'_, '_>,
+    color_glyphs: &ColorGlyphs<'_>,
+    c: char,
+) -> Option<Rect<i32>> {
+    let glyph = layout.next_glyph(c);
+    match color_glyphs.rasterize(layout, &glyph) {
+        Some((min, bitmap)) => Some(Rect {
+            min,
+            max: point(
+                min.x + bitmap.width() as i32,
+                min.y + bitmap.height() as i32,
+            ),
+        }),
+        None => glyph.pixel_bounding_box(),
+    }
+}
+
+/// Color bitmap glyphs (CBDT/sbix) of the fonts used by a [`Layout`],
+/// e.g. of emoji fonts, which `rusttype` does not rasterize as they
+/// are PNG images instead of outlines.
+pub struct ColorGlyphs<'font> {
+    /// Face and pixels per em of each font, in the order of the fonts
+    /// of the [`Layout`], or `None` if the font could not be parsed.
+    faces: Vec<Option<(Face<'font>, f32)>>,
+}
+
+impl<'font> ColorGlyphs<'font> {
+    pub fn new<'a, I>(fonts: I, scale: Scale) -> Self
+    where
+        I: IntoIterator<Item = (&'a Font<'a>, &'font [u8])>,
+    {
+        let faces = fonts
+            .into_iter()
+            .map(|(font, data)| {
+                let face = Face::from_slice(data, 0).ok()?;
+                // `Scale` is relative to the height of the font, not its em
+                let ppem = font.scale_for_pixel_height(scale.y) * f32::from(font.units_per_em());
+                Some((face, ppem))
+            })
+            .collect();
+        Self { faces }
+    }
+
+    /// Returns the color bitmap of `glyph`, i.e. the last glyph of `layout`,
+    /// scaled to the font size, along with the position of its top left
+    /// corner, or `None` if `glyph` has no color bitmap.
+    pub fn rasterize(
+        &self,
+        layout: &Layout<'_, '_>,
+        glyph: &PositionedGlyph<'_>,
+    ) -> Option<(Point<i32>, RgbaImage)> {
+        let (face, ppem) = self.faces.get(layout.last_font_index()?)?.as_ref()?;
+
+        let glyph_id = ttf_parser::GlyphId(glyph.id().0);
+        let raster = face.glyph_raster_image(glyph_id, ppem.round() as u16)?;
+        let RasterImageFormat::PNG = raster.format;
+        let bitmap = image::load_from_memory_with_format(raster.data, ImageFormat::Png).ok()?;
+
+        // Strikes are only available in some sizes
+        let factor = ppem / f32::from(raster.pixels_per_em.max(1));
+        let width = (bitmap.width() as f32 * factor).round() as u32;
+        let height = (bitmap.height() as f32 * factor).round() as u32;
+        if (width == 0) || (height == 0) {
+            return None;
+        }
+        let bitmap = imageops::resize(&bitmap.to_rgba8(), width, height, FilterType::Triangle);
+
+        // The offsets are from the origin of the glyph to the
+        // bottom left corner of the bitmap, with y pointing up
+        let pos = glyph.position();
+        let x = (pos.x + f32::from(raster.x) * factor).round() as i32;
+        let y = (pos.y - f32::from(raster.y) * factor).round() as i32 - (height as i32);
+        Some((point(x, y), bitmap))
+    }
 }
 
 #[derive(Clone)]
 pub struct Layout<'a, 'font> {
-    font: &'a Font<'font>,
+    /// Fonts in order of priority, where the first font
+    /// containing a glyph for a character is used.
+    fonts: &'a [&'a Font<'font>],
     scale: Scale,
     start: Point<f32>,
     caret: Point<f32>,
+    ascent: f32,
     advance_height: f32,
-    last_glyph_id: Option<GlyphId>,
+    /// 0-indexed line of the caret.
+    line: usize,
+    /// Index into `fonts` and the id of the last glyph.
+    last_glyph: Option<(usize, GlyphId)>,
+    pub kerning: bool,
 }
 
 impl<'a, 'font> Layout<'a, 'font> {
-    pub fn new(font: &'a Font<'font>, start: Point<f32>, scale: Scale) -> Self {
-        let v_metrics = font.v_metrics(scale);
+    /// # Panics
+    ///
+    /// Panics if `fonts` is empty.
+    pub fn new(
+        fonts: &'a [&'a Font<'font>],
+        start: Point<f32>,
+        scale: Scale,
+        line_height: f32,
+    ) -> Self {
+        let v_metrics = fonts[0].v_metrics(scale);
         let advance_height = v_metrics.ascent - v_metrics.descent + v_metrics.line_gap;
+        let advance_height = advance_height * line_height;
         Self {
-            font,
+            fonts,
             scale,
             start,
             caret: point(start.x, start.y + v_metrics.ascent),
+            ascent: v_metrics.ascent,
             advance_height,
-            last_glyph_id: None,
+            line: 0,
+            last_glyph: None,
+            kerning: true,
         }
     }
 
-    pub fn next_glyph(&mut self, c: char) -> PositionedGlyph<'font> {
-        let base_glyph = self.font.glyph(c);
+    /// Returns the position of the next glyph on the baseline.
+    pub fn caret(&self) -> Point<f32> {
+        self.caret
+    }
+
+    /// Returns the 0-indexed line of the caret.
+    pub fn line(&self) -> usize {
+        self.line
+    }
 
-        if let Some(last_glyph_id) = self.last_glyph_id.take() {
-            self.caret.x += self
-                .font
-                .pair_kerning(self.scale, last_glyph_id, base_glyph.id());
+    /// Returns the top of the line of the caret.
+    pub fn line_top(&self) -> f32 {
+        self.caret.y - self.ascent
+    }
+
+    /// Returns the distance between the tops of two lines,
+    /// including the line height multiplier.
+    pub fn line_height(&self) -> f32 {
+        self.advance_height
+    }
+
+    /// Returns the index into the fonts of the last glyph,
+    /// i.e. the font containing it.
+    pub fn last_font_index(&self) -> Option<usize> {
+        self.last_glyph.map(|(font_index, _glyph_id)| font_index)
+    }
+
+    pub fn next_glyph(&mut self, c: char) -> PositionedGlyph<'font> {
+        let (font_index, font) = self
+            .fonts
+            .iter()
+            .enumerate()
+            .find(|(_, font)| font.glyph(c).id() != GlyphId(0))
+            .unwrap_or((0, &self.fonts[0]));
+        let base_glyph = font.glyph(c);
+
+        if let Some((last_font_index, last_glyph_id)) = self.last_glyph.take() {
+            if self.kerning && (last_font_index == font_index) {
+                self.caret.x += font.pair_kerning(self.scale, last_glyph_id, base_glyph.id());
+            }
         }
 
-        self.last_glyph_id = Some(base_glyph.id());
+        self.last_glyph = Some((font_index, base_glyph.id()));
 
         let glyph = base_glyph.scaled(self.scale).positioned(self.caret);
 
         if c == '\n' {
             self.caret.x = self.start.x;
             self.caret.y += self.advance_height;
+            self.line += 1;
         } else {
             self.caret.x += glyph.unpositioned().h_metrics().advance_width;
         }
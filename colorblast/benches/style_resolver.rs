@@ -0,0 +1,74 @@
+use std::collections::HashMap;
+use std::hint::black_box;
+use std::sync::OnceLock;
+
+use colorblast::{Lexer, Style, StyleResolver, StylizeToken, Token, TokenSpan};
+use criterion::{criterion_group, criterion_main, Criterion};
+
+/// Token styled by name, like a theme loaded at runtime.
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+struct NamedToken(Token);
+
+impl StylizeToken for NamedToken {
+    fn style(&self, span: &TokenSpan<'_>) -> Style {
+        static STYLES: OnceLock<HashMap<String, Style>> = OnceLock::new();
+        let styles = STYLES.get_or_init(|| {
+            Token::VARIANTS
+                .iter()
+                .map(|tok| (format!("token.{}", tok.name()), tok.style(span)))
+                .collect()
+        });
+        styles[&format!("token.{}", self.0.name())].clone()
+    }
+
+    fn depends_on_span(&self) -> bool {
+        false
+    }
+}
+
+fn bench_style_resolver(c: &mut Criterion) {
+    let text = include_str!("../../text-scanner/src/lib.rs").repeat(10);
+    let tokens = Lexer::Rust
+        .into_lexer(&text)
+        .collect::<Vec<(Token, TokenSpan<'_>)>>();
+    let named = tokens
+        .iter()
+        .map(|(tok, span)| (NamedToken(*tok), span.clone()))
+        .collect::<Vec<_>>();
+
+    let mut group = c.benchmark_group("style_resolver");
+    group.bench_function("token", |b| {
+        b.iter(|| {
+            for (tok, span) in black_box(&tokens) {
+                black_box(tok.style(span));
+            }
+        });
+    });
+    group.bench_function("token_resolver", |b| {
+        b.iter(|| {
+            let mut resolver = StyleResolver::new();
+            for (tok, span) in black_box(&tokens) {
+                black_box(resolver.resolve(*tok, span));
+            }
+        });
+    });
+    group.bench_function("named", |b| {
+        b.iter(|| {
+            for (tok, span) in black_box(&named) {
+                black_box(tok.style(span));
+            }
+        });
+    });
+    group.bench_function("named_resolver", |b| {
+        b.iter(|| {
+            let mut resolver = StyleResolver::new();
+            for (tok, span) in black_box(&named) {
+                black_box(resolver.resolve(*tok, span));
+            }
+        });
+    });
+    group.finish();
+}
+
+criterion_group!(benches, bench_style_resolver);
+criterion_main!(benches);
@@ -1,13 +1,802 @@
-use std::fmt;
+use std::env;
+use std::fmt::{self, Write};
+use std::io::{self, IsTerminal};
+use std::ops::Range;
+#[cfg(unix)]
+use std::process::{Command, Stdio};
+
+use unicode_width::UnicodeWidthChar;
+
+use crate::style::{AsStyle, Color};
+
+/// Options used by [`render_ansi()`] and [`render_ansi_into()`].
+///
+/// # Example
+///
+/// ```rust
+/// use colorblast::{render_ansi, AnsiOptions, Color, ColorChoice};
+///
+/// let options = AnsiOptions::new()
+///     .color(ColorChoice::Always)
+///     .hyperlinks(true);
+///
+/// let tokens = [(Some(Color::WHITE), "// See https://example.com")];
+/// let ansi = render_ansi(tokens, &options);
+///
+/// assert!(ansi.contains("\x1b]8;;https://example.com\x1b\\"));
+/// ```
+#[derive(Clone, Debug)]
+#[non_exhaustive]
+pub struct AnsiOptions {
+    /// Whether to render colors or plain text, defaults to [`ColorChoice::Auto`].
+    ///
+    /// When colors are disabled, then no escape sequences are rendered
+    /// at all, including hyperlinks.
+    ///
+    /// The rendered `String` is not necessarily printed to stdout, so
+    /// [`ColorChoice::Auto`] renders colors the same as
+    /// [`ColorChoice::Always`]. Use [`ColorChoice::should_colorize()`]
+    /// to resolve it before rendering for stdout.
+    pub color: ColorChoice,
+
+    /// Wrap URLs found in tokens, in [OSC 8] hyperlink escape sequences,
+    /// which makes them clickable in terminals supporting it.
+    ///
+    /// Terminals not supporting [OSC 8] should ignore the escape sequences,
+    /// however some older terminals print them as is. Thereby this is
+    /// disabled by default.
+    ///
+    /// [OSC 8]: https://gist.github.com/egmontkob/eb114294efbcd5adb1944c9f3cb5feda
+    pub hyperlinks: bool,
+
+    /// The color depth used when rendering colors. If `None`, then it is
+    /// detected using [`ColorDepth::detect()`].
+    ///
+    /// Colors are quantized when the depth is less than [`ColorDepth::TrueColor`].
+    pub color_depth: Option<ColorDepth>,
+
+    /// How the escape sequences of the original input are rendered by
+    /// [`render_ansi_with_escapes()`], defaults to [`OriginalColors::Discard`].
+    pub original_colors: OriginalColors,
+
+    /// How lines wider than [`width`](Self::width) are rendered by
+    /// [`render_ansi()`], defaults to [`Overflow::Visible`].
+    pub overflow: Overflow,
+
+    /// The maximum width of lines in columns, used by [`overflow`].
+    /// If `None`, then it is detected using [`terminal_width()`], and
+    /// `Some(0)` renders lines as is, same as [`Overflow::Visible`].
+    ///
+    /// Columns are counted by the display width of each `char`, e.g.
+    /// two columns for most CJK characters and emoji. When wrapping or
+    /// truncating, tabs are expanded to spaces up to the next multiple
+    /// of 8 columns.
+    ///
+    /// [`overflow`]: Self::overflow
+    pub width: Option<usize>,
+}
+
+impl AnsiOptions {
+    pub const DEFAULT: Self = Self {
+        color: ColorChoice::Auto,
+        hyperlinks: false,
+        color_depth: None,
+        original_colors: OriginalColors::Discard,
+        overflow: Overflow::Visible,
+        width: None,
+    };
+
+    #[inline]
+    pub fn new() -> Self {
+        Self::DEFAULT
+    }
+
+    #[inline]
+    pub fn color(mut self, color: ColorChoice) -> Self {
+        self.color = color;
+        self
+    }
+
+    #[inline]
+    pub fn hyperlinks(mut self, hyperlinks: bool) -> Self {
+        self.hyperlinks = hyperlinks;
+        self
+    }
+
+    #[inline]
+    pub fn color_depth(mut self, color_depth: impl Into<Option<ColorDepth>>) -> Self {
+        self.color_depth = color_depth.into();
+        self
+    }
+
+    #[inline]
+    pub fn original_colors(mut self, original_colors: OriginalColors) -> Self {
+        self.original_colors = original_colors;
+        self
+    }
+
+    #[inline]
+    pub fn overflow(mut self, overflow: Overflow) -> Self {
+        self.overflow = overflow;
+        self
+    }
+
+    #[inline]
+    pub fn width(mut self, width: impl Into<Option<usize>>) -> Self {
+        self.width = width.into();
+        self
+    }
+}
+
+impl Default for AnsiOptions {
+    #[inline]
+    fn default() -> Self {
+        Self::DEFAULT
+    }
+}
+
+/// Renders all styled `tokens` into a `String` containing ANSI escape
+/// sequences, e.g. for printing to the terminal.
+///
+/// Escape sequences are only rendered when the style changes, i.e.
+/// adjacent tokens with the same style share escape sequences, and
+/// whitespace between them keeps the style if the background color is
+/// the same. Styles are reset at the end of each line.
+///
+/// # Example
+///
+/// ```rust
+/// use colorblast::{render_ansi, AnsiOptions, Color, ColorChoice, ColorDepth};
+///
+/// let red = Some(Color::from([255, 0, 0]));
+/// let tokens = [(red, "foo"), (None, " "), (red, "bar"), (None, "\n")];
+///
+/// let options = AnsiOptions::new()
+///     .color(ColorChoice::Always)
+///     .color_depth(ColorDepth::Ansi16);
+/// let ansi = render_ansi(tokens, &options);
+///
+/// assert_eq!(ansi, "\x1b[91mfoo bar\x1b[0m\n");
+/// ```
+///
+/// See also [`render_ansi_into()`].
+pub fn render_ansi<Sty, Tok, I>(tokens: I, options: &AnsiOptions) -> String
+where
+    I: IntoIterator<Item = (Sty, Tok)>,
+    Sty: AsStyle,
+    Tok: fmt::Display,
+{
+    let mut ansi = String::new();
+    render_ansi_into(&mut ansi, tokens, options);
+    ansi
+}
+
+/// Same as [`render_ansi()`], but renders into an existing `String`.
+pub fn render_ansi_into<Sty, Tok, I>(ansi: &mut String, tokens: I, options: &AnsiOptions)
+where
+    I: IntoIterator<Item = (Sty, Tok)>,
+    Sty: AsStyle,
+    Tok: fmt::Display,
+{
+    let width = match options.overflow {
+        Overflow::Visible => None,
+        Overflow::Wrap | Overflow::Truncate => options.width.or_else(terminal_width),
+    };
+    let width = width.filter(|&width| width > 0);
+
+    if options.color == ColorChoice::Never {
+        if let Some(width) = width {
+            let tokens = tokens
+                .into_iter()
+                .map(|(_sty, tok)| (None, tok.to_string()));
+            push_fitted(ansi, tokens, width, options.overflow, false);
+            return;
+        }
+
+        for (_sty, tok) in tokens {
+            // Writing to a `String` never fails
+            _ = write!(ansi, "{tok}");
+        }
+        return;
+    }
+
+    let depth = options.color_depth.unwrap_or_else(ColorDepth::detect);
+
+    if let Some(width) = width {
+        let tokens = tokens
+            .into_iter()
+            .map(|(sty, tok)| (ansi_style(sty, depth), tok.to_string()));
+        push_fitted(ansi, tokens, width, options.overflow, options.hyperlinks);
+        return;
+    }
+
+    let mut active = ActiveStyle::default();
+    let mut text = String::new();
+    for (sty, tok) in tokens {
+        text.clear();
+        _ = write!(text, "{tok}");
+
+        let style = ansi_style(sty, depth);
+        active.push(ansi, style.as_ref(), &[], &text, options.hyperlinks);
+    }
+    active.reset(ansi);
+}
+
+/// Amount of columns between tab stops, used for expanding tabs when
+/// wrapping or truncating lines, see [`AnsiOptions::width`].
+const TAB_WIDTH: usize = 8;
+
+/// Pushes the styled `tokens`, while wrapping or truncating lines wider
+/// than `width`. Line breaks and ellipses are pushed unstyled, such that
+/// styles never span across a cut.
+fn push_fitted(
+    ansi: &mut String,
+    tokens: impl IntoIterator<Item = (Option<AnsiStyle>, String)>,
+    width: usize,
+    overflow: Overflow,
+    hyperlinks: bool,
+) {
+    // The whole text is needed up front, to know whether a line
+    // is truncated before reaching the end of it
+    let tokens = tokens.into_iter().collect::<Vec<_>>();
+    let text = tokens
+        .iter()
+        .map(|(_style, tok)| tok.as_str())
+        .collect::<String>();
+    let mut line_widths = text.split('\n').map(display_width);
+
+    let mut line_width = line_widths.next().unwrap_or(0);
+    let mut column = 0;
+    let mut truncated = false;
+
+    let mut active = ActiveStyle::default();
+    let mut part = String::new();
+    for (style, tok) in &tokens {
+        part.clear();
+
+        for c in tok.chars() {
+            if c == '\n' {
+                part.push('\n');
+
+                line_width = line_widths.next().unwrap_or(0);
+                column = 0;
+                truncated = false;
+                continue;
+            }
+
+            if truncated {
+                continue;
+            }
+
+            let (c, count, c_width) = match c {
+                '\t' => (' ', TAB_WIDTH - (column % TAB_WIDTH), 1),
+                c => (c, 1, c.width().unwrap_or(0)),
+            };
+            for _ in 0..count {
+                match overflow {
+                    Overflow::Wrap if (column > 0) && (column + c_width > width) => {
+                        part.push('\n');
+                        column = 0;
+                    }
+                    // Leave room for the ellipsis
+                    Overflow::Truncate if (line_width > width) && (column + c_width >= width) => {
+                        active.push(ansi, style.as_ref(), &[], &part, hyperlinks);
+                        part.clear();
+                        active.reset(ansi);
+                        ansi.push('…');
+                        truncated = true;
+                        break;
+                    }
+                    _ => {}
+                }
+
+                part.push(c);
+                column += c_width;
+            }
+        }
+
+        active.push(ansi, style.as_ref(), &[], &part, hyperlinks);
+    }
+    active.reset(ansi);
+}
+
+/// Returns the display width of `line` in columns, with tabs
+/// expanded, see [`AnsiOptions::width`].
+fn display_width(line: &str) -> usize {
+    line.chars().fold(0, |column, c| match c {
+        '\t' => column + TAB_WIDTH - (column % TAB_WIDTH),
+        c => column + c.width().unwrap_or(0),
+    })
+}
+
+/// Detects the width of the terminal in columns, based on the `COLUMNS`
+/// environment variable. On Unix, this falls back to querying the
+/// controlling terminal using `stty`, which also works when stdout
+/// is piped, e.g. into `less -R`.
+///
+/// Returns `None` if the width could not be detected.
+pub fn terminal_width() -> Option<usize> {
+    let columns = env::var("COLUMNS")
+        .ok()
+        .and_then(|cols| cols.trim().parse().ok());
+    match columns {
+        Some(0) | None => stty_width(),
+        Some(columns) => Some(columns),
+    }
+}
+
+#[cfg(unix)]
+fn stty_width() -> Option<usize> {
+    let tty = std::fs::File::open("/dev/tty").ok()?;
+    let output = Command::new("stty")
+        .arg("size")
+        .stdin(tty)
+        .stderr(Stdio::null())
+        .output()
+        .ok()?;
+    if !output.status.success() {
+        return None;
+    }
+
+    // Outputs "<rows> <columns>"
+    let output = String::from_utf8(output.stdout).ok()?;
+    let columns = output.split_whitespace().nth(1)?.parse().ok()?;
+    (columns > 0).then_some(columns)
+}
+
+#[cfg(not(unix))]
+#[inline]
+fn stty_width() -> Option<usize> {
+    None
+}
+
+/// Same as [`render_ansi_into()`], but re-inserts the `escapes` stripped from
+/// the original input, as returned by [`strip_ansi()`]. The `tokens` must
+/// cover the [stripped text] in order.
+///
+/// How the original escape sequences are rendered is controlled by
+/// [`AnsiOptions::original_colors`].
+///
+/// This allows re-highlighting text which was already colored, e.g. the
+/// output of another tool, without escape sequences being classified
+/// as code or being rendered twice.
+///
+/// # Example
+///
+/// ```rust
+/// use colorblast::{
+///     render_ansi_with_escapes, strip_ansi, AnsiOptions, ColorChoice, OriginalColors,
+/// };
+///
+/// let input = "\x1b[1mfn\x1b[0m main";
+/// let stripped = strip_ansi(input);
+/// assert_eq!(stripped.text, "fn main");
+///
+/// let tokens = [(None::<colorblast::Color>, "fn"), (None, " main")];
+///
+/// let options = AnsiOptions::new()
+///     .color(ColorChoice::Always)
+///     .original_colors(OriginalColors::Merge);
+/// let ansi = render_ansi_with_escapes(tokens, &stripped.escapes, &options);
+/// assert_eq!(ansi, "\x1b[1mfn\x1b[0m main");
+/// ```
+///
+/// [stripped text]: StrippedAnsi::text
+pub fn render_ansi_with_escapes<Sty, Tok, I>(
+    tokens: I,
+    escapes: &[AnsiEscape<'_>],
+    options: &AnsiOptions,
+) -> String
+where
+    I: IntoIterator<Item = (Sty, Tok)>,
+    Sty: AsStyle,
+    Tok: AsRef<str>,
+{
+    if (options.color == ColorChoice::Never) || (options.original_colors == OriginalColors::Discard)
+    {
+        let tokens = tokens.into_iter().map(|(sty, tok)| (sty, Display(tok)));
+        return render_ansi(tokens, options);
+    }
+
+    let depth = options.color_depth.unwrap_or_else(ColorDepth::detect);
+
+    let mut ansi = String::new();
+    let mut escapes = escapes.iter().peekable();
+    // SGR sequences of the original input, which are active at `pos`
+    let mut original: Vec<&str> = Vec::new();
+    let mut active = ActiveStyle::default();
+    let mut pos = 0;
+
+    for (sty, tok) in tokens {
+        let style = ansi_style(sty, depth);
+
+        let mut text = tok.as_ref();
+        loop {
+            while let Some(escape) = escapes.next_if(|escape| escape.pos <= pos) {
+                escape.apply(&mut ansi, &mut original);
+            }
+
+            let len = match escapes.peek() {
+                Some(escape) if escape.pos < (pos + text.len()) => escape.pos - pos,
+                _ => text.len(),
+            };
+            let (part, rest) = text.split_at(len);
+
+            if !part.is_empty() {
+                let style = match options.original_colors {
+                    OriginalColors::Preserve if !original.is_empty() => None,
+                    _ => style.as_ref(),
+                };
+                active.push(&mut ansi, style, &original, part, options.hyperlinks);
+            }
+
+            pos += len;
+            text = rest;
+            if text.is_empty() {
+                break;
+            }
+        }
+    }
+
+    active.reset(&mut ansi);
+
+    for escape in escapes {
+        if !escape.is_sgr() {
+            ansi.push_str(escape.code);
+        }
+    }
+
+    ansi
+}
+
+fn ansi_style<Sty: AsStyle>(sty: Sty, depth: ColorDepth) -> Option<AnsiStyle> {
+    sty.as_style().map(|style| AnsiStyle {
+        fg: style.fg.map(AnsiColor::from),
+        bg: style.bg.map(AnsiColor::from),
+        depth,
+    })
+}
+
+/// The colors and original SGR sequences set by the escape sequences pushed
+/// so far, such that escape sequences are only pushed when the style changes
+/// between tokens.
+#[derive(Default)]
+struct ActiveStyle {
+    fg: Option<AnsiColor>,
+    bg: Option<AnsiColor>,
+    /// Concatenated SGR sequences of the original input,
+    /// see [`render_ansi_with_escapes()`].
+    original: String,
+}
+
+impl ActiveStyle {
+    /// Pushes `text` styled by `style` and the `original` SGR sequences,
+    /// where styles are reset before line breaks, such that each line
+    /// is styled independently.
+    ///
+    /// Whitespace keeps the active style if the background color is
+    /// the same, as the foreground color of whitespace is not visible.
+    fn push(
+        &mut self,
+        ansi: &mut String,
+        style: Option<&AnsiStyle>,
+        original: &[&str],
+        text: &str,
+        hyperlinks: bool,
+    ) {
+        for (i, line) in text.split('\n').enumerate() {
+            if i > 0 {
+                self.reset(ansi);
+                ansi.push('\n');
+            }
+            if line.is_empty() {
+                continue;
+            }
+
+            let bg = style.and_then(|style| style.bg);
+            if !line.trim().is_empty() || (bg != self.bg) || !self.is_original(original) {
+                self.set(ansi, style, original);
+            }
+
+            if hyperlinks {
+                push_hyperlinked(ansi, line);
+            } else {
+                ansi.push_str(line);
+            }
+        }
+    }
+
+    /// Pushes the escape sequences changing the active style to `style`
+    /// and the `original` SGR sequences, if it is different.
+    fn set(&mut self, ansi: &mut String, style: Option<&AnsiStyle>, original: &[&str]) {
+        let (fg, bg) = style.map_or((None, None), |style| (style.fg, style.bg));
+        let is_original = self.is_original(original);
+        if (fg == self.fg) && (bg == self.bg) && is_original {
+            return;
+        }
+
+        // Removing a color or an original sequence requires a reset,
+        // after which the remaining colors must be set again
+        if (self.fg.is_some() && fg.is_none())
+            || (self.bg.is_some() && bg.is_none())
+            || (!is_original && !self.original.is_empty())
+        {
+            _ = write!(ansi, "{}", AnsiCode::Reset);
+            self.fg = None;
+            self.bg = None;
+            self.original.clear();
+        }
+
+        let changed = AnsiStyle {
+            fg: fg.filter(|_| fg != self.fg),
+            bg: bg.filter(|_| bg != self.bg),
+            depth: style.map_or(ColorDepth::TrueColor, |style| style.depth),
+        };
+        // Writing to a `String` never fails
+        _ = write!(ansi, "{changed}");
+
+        self.fg = fg;
+        self.bg = bg;
+
+        // The original sequences are pushed after the colors,
+        // such that the original colors take precedence
+        if !original.is_empty() {
+            self.original.clear();
+            for code in original {
+                ansi.push_str(code);
+                self.original.push_str(code);
+            }
+        }
+    }
+
+    /// Pushes a reset, if any colors or original sequences are active.
+    #[inline]
+    fn reset(&mut self, ansi: &mut String) {
+        self.set(ansi, None, &[]);
+    }
+
+    /// Returns `true` if `original` are the active original sequences.
+    fn is_original(&self, original: &[&str]) -> bool {
+        let mut rest = self.original.as_str();
+        for code in original {
+            match rest.strip_prefix(code) {
+                Some(after) => rest = after,
+                None => return false,
+            }
+        }
+        rest.is_empty()
+    }
+}
+
+/// Wrapper for rendering `AsRef<str>` using [`fmt::Display`].
+struct Display<T>(T);
+
+impl<T: AsRef<str>> fmt::Display for Display<T> {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.0.as_ref())
+    }
+}
+
+/// Controls how lines wider than [`AnsiOptions::width`] are rendered by
+/// [`render_ansi()`] and [`render_ansi_into()`].
+#[derive(PartialEq, Eq, Clone, Copy, Default, Debug)]
+pub enum Overflow {
+    /// Render lines as is, i.e. leave wrapping to the terminal.
+    #[default]
+    Visible,
+    /// Wrap lines onto the next line, when exceeding the width.
+    Wrap,
+    /// Truncate lines exceeding the width, and end them with `…`.
+    Truncate,
+}
+
+/// Controls how escape sequences of the original input are rendered by
+/// [`render_ansi_with_escapes()`].
+#[derive(PartialEq, Eq, Clone, Copy, Default, Debug)]
+pub enum OriginalColors {
+    /// Discard all original escape sequences, i.e. only render
+    /// the highlighted tokens.
+    #[default]
+    Discard,
+    /// Render text styled by the original input using the original
+    /// styling only, while unstyled text is highlighted.
+    ///
+    /// Escape sequences other than SGR (colors and text attributes),
+    /// e.g. hyperlinks, are rendered as is.
+    Preserve,
+    /// Render the original styling on top of the highlighting, such that
+    /// e.g. bold text remains bold, while original colors take precedence.
+    ///
+    /// Escape sequences other than SGR (colors and text attributes),
+    /// e.g. hyperlinks, are rendered as is.
+    Merge,
+}
+
+/// Text with all ANSI escape sequences stripped, as returned by [`strip_ansi()`].
+#[derive(Clone, Debug)]
+pub struct StrippedAnsi<'a> {
+    /// The input with all escape sequences removed.
+    pub text: String,
+    /// The escape sequences removed from the input, in order.
+    pub escapes: Vec<AnsiEscape<'a>>,
+}
+
+/// An escape sequence removed by [`strip_ansi()`].
+#[derive(PartialEq, Eq, Clone, Debug)]
+pub struct AnsiEscape<'a> {
+    /// The byte position in the [stripped text], where the
+    /// escape sequence occurred.
+    ///
+    /// [stripped text]: StrippedAnsi::text
+    pub pos: usize,
+    /// The byte range of the escape sequence in the original input.
+    pub range: Range<usize>,
+    /// The escape sequence itself, e.g. `"\x1b[1;31m"`.
+    pub code: &'a str,
+}
+
+impl AnsiEscape<'_> {
+    /// Returns `true` if the escape sequence is a Select Graphic Rendition
+    /// (SGR) sequence, i.e. `ESC [ ... m` which sets colors and text attributes.
+    #[inline]
+    pub fn is_sgr(&self) -> bool {
+        self.code.starts_with("\x1b[") && self.code.ends_with('m')
+    }
+
+    /// Returns `true` if the escape sequence is an SGR sequence
+    /// starting with a reset, e.g. `ESC [ m` and `ESC [ 0 ; 1 m`.
+    fn is_sgr_reset(&self) -> bool {
+        if !self.is_sgr() {
+            return false;
+        }
+        let params = &self.code[2..(self.code.len() - 1)];
+        matches!(params.split(';').next(), Some("" | "0" | "00"))
+    }
+
+    /// Pushes the escape sequence into `ansi` or into the `active`
+    /// SGR sequences, if it is an SGR sequence.
+    fn apply<'a>(&'a self, ansi: &mut String, active: &mut Vec<&'a str>) {
+        if !self.is_sgr() {
+            ansi.push_str(self.code);
+            return;
+        }
+
+        if self.is_sgr_reset() {
+            active.clear();
+            // Sequences such as `ESC [ 0 ; 1 m` reset and then set attributes
+            if self.code.contains(';') {
+                active.push(self.code);
+            }
+        } else {
+            active.push(self.code);
+        }
+    }
+}
+
+/// Removes all ANSI escape sequences from `input`, while recording
+/// their positions, such that they can be re-inserted after
+/// highlighting using [`render_ansi_with_escapes()`].
+///
+/// Control Sequence Introducer (CSI) sequences, e.g. colors and cursor
+/// movement, Operating System Command (OSC) sequences, e.g. hyperlinks,
+/// and two-byte escape sequences are recognized.
+///
+/// # Example
+///
+/// ```rust
+/// use colorblast::strip_ansi;
+///
+/// let stripped = strip_ansi("\x1b[31merror\x1b[0m: foo");
+/// assert_eq!(stripped.text, "error: foo");
+///
+/// let escapes = stripped.escapes.iter().map(|e| (e.pos, e.code)).collect::<Vec<_>>();
+/// assert_eq!(escapes, [(0, "\x1b[31m"), (5, "\x1b[0m")]);
+/// ```
+pub fn strip_ansi(input: &str) -> StrippedAnsi<'_> {
+    let mut text = String::with_capacity(input.len());
+    let mut escapes = Vec::new();
+
+    let mut rest = input;
+    while let Some(i) = rest.find('\x1b') {
+        text.push_str(&rest[..i]);
+
+        let start = input.len() - rest.len() + i;
+        let len = escape_len(&rest[i..]);
+        let range = start..(start + len);
+
+        escapes.push(AnsiEscape {
+            pos: text.len(),
+            range: range.clone(),
+            code: &input[range],
+        });
+
+        rest = &rest[(i + len)..];
+    }
+    text.push_str(rest);
+
+    StrippedAnsi { text, escapes }
+}
+
+/// Returns the byte length of the escape sequence at the start of `s`,
+/// where `s` starts with `ESC`.
+fn escape_len(s: &str) -> usize {
+    let bytes = s.as_bytes();
+    debug_assert_eq!(bytes.first(), Some(&0x1B));
+
+    match bytes.get(1) {
+        // CSI: parameter bytes, intermediate bytes, and a final byte
+        Some(b'[') => {
+            let end = bytes[2..]
+                .iter()
+                .position(|b| (0x40..=0x7E).contains(b))
+                .map_or(bytes.len(), |i| 2 + i + 1);
+            // Stop at invalid bytes, e.g. `ESC [` followed by text
+            bytes[2..end]
+                .iter()
+                .position(|b| !(0x20..=0x7E).contains(b))
+                .map_or(end, |i| 2 + i)
+        }
+        // OSC: terminated by `BEL` or `ESC \`
+        Some(b']') => {
+            let mut i = 2;
+            while i < bytes.len() {
+                match bytes[i] {
+                    0x07 => return i + 1,
+                    0x1B if bytes.get(i + 1) == Some(&b'\\') => return i + 2,
+                    _ => i += 1,
+                }
+            }
+            bytes.len()
+        }
+        Some(0x20..=0x7E) => 2,
+        _ => 1,
+    }
+}
+
+/// Pushes `text` into `ansi`, while wrapping all URLs
+/// in OSC 8 hyperlink escape sequences.
+fn push_hyperlinked(ansi: &mut String, mut text: &str) {
+    let find_url = |text: &str| {
+        text.match_indices("http")
+            .map(|(i, _)| i)
+            .find(|&i| text[i..].starts_with("http://") || text[i..].starts_with("https://"))
+    };
+
+    while let Some(start) = find_url(text) {
+        let end = text[start..]
+            .find(|c: char| {
+                c.is_whitespace()
+                    || c.is_control()
+                    || matches!(c, '"' | '\'' | '<' | '>' | '`' | '\\')
+            })
+            .map_or(text.len(), |end| start + end);
+
+        // Exclude trailing punctuation, e.g. in "See https://example.com."
+        let url = text[start..end].trim_end_matches(['.', ',', ';', ':', '!', '?', ')']);
+        let end = start + url.len();
+
+        ansi.push_str(&text[..start]);
+        _ = write!(ansi, "\x1b]8;;{url}\x1b\\{url}\x1b]8;;\x1b\\");
+
+        text = &text[end..];
+    }
+    ansi.push_str(text);
+}
 
 #[derive(Clone, Debug)]
 pub struct AnsiStyle {
     pub fg: Option<AnsiColor>,
     pub bg: Option<AnsiColor>,
+    pub depth: ColorDepth,
 }
 
 impl AnsiStyle {
-    pub const DEFAULT: Self = Self { fg: None, bg: None };
+    pub const DEFAULT: Self = Self {
+        fg: None,
+        bg: None,
+        depth: ColorDepth::TrueColor,
+    };
 }
 
 impl Default for AnsiStyle {
@@ -25,14 +814,14 @@ impl fmt::Display for AnsiStyle {
         match codes.next() {
             Some(code) => {
                 write!(f, "\x1b[")?;
-                code.write_code(f)?;
+                code.write_code(f, self.depth)?;
             }
             None => return Ok(()),
         }
 
         for code in codes {
             write!(f, ";")?;
-            code.write_code(f)?;
+            code.write_code(f, self.depth)?;
         }
 
         write!(f, "m")?;
@@ -49,11 +838,11 @@ pub enum AnsiCode {
 }
 
 impl AnsiCode {
-    fn write_code(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    fn write_code(&self, f: &mut fmt::Formatter<'_>, depth: ColorDepth) -> fmt::Result {
         match self {
             Self::Reset => write!(f, "0"),
-            Self::Fg(c) => c.write_fg_code(f),
-            Self::Bg(c) => c.write_bg_code(f),
+            Self::Fg(c) => c.write_fg_code(f, depth),
+            Self::Bg(c) => c.write_bg_code(f, depth),
         }
     }
 }
@@ -61,23 +850,513 @@ impl AnsiCode {
 impl fmt::Display for AnsiCode {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(f, "\x1b[")?;
-        self.write_code(f)?;
+        self.write_code(f, ColorDepth::TrueColor)?;
         write!(f, "m")?;
         Ok(())
     }
 }
 
-#[derive(Clone, Copy, Debug)]
+/// Controls whether colors are rendered by [`render_ansi()`] and
+/// the `print_*()` functions.
+#[derive(PartialEq, Eq, Clone, Copy, Default, Debug)]
+pub enum ColorChoice {
+    /// Render colors, unless the [`NO_COLOR`] environment variable is set
+    /// to a non-empty value, or stdout is not a terminal.
+    ///
+    /// This is only checked by the `print_*()` functions and
+    /// [`should_colorize()`]. Functions rendering into a `String`, e.g.
+    /// [`render_ansi()`], render colors the same as [`ColorChoice::Always`].
+    ///
+    /// [`NO_COLOR`]: https://no-color.org
+    /// [`should_colorize()`]: Self::should_colorize
+    #[default]
+    Auto,
+    /// Always render colors.
+    Always,
+    /// Never render colors, i.e. only render plain text.
+    Never,
+}
+
+impl ColorChoice {
+    /// Returns `true` if colors should be rendered to stdout.
+    ///
+    /// For [`ColorChoice::Auto`] this checks `NO_COLOR` and whether
+    /// stdout is a terminal.
+    ///
+    /// With the `windows` feature enabled, this also enables ANSI escape
+    /// sequences for the Windows console. Legacy consoles not supporting
+    /// them, i.e. prior to Windows 10, are unsupported, and both
+    /// [`ColorChoice::Auto`] and [`ColorChoice::Always`] fall back to
+    /// plain text, instead of printing raw escape sequences.
+    pub fn should_colorize(self) -> bool {
+        match self {
+            Self::Always => enable_ansi_support(),
+            Self::Never => false,
+            Self::Auto => {
+                let no_color = env::var_os("NO_COLOR").is_some_and(|v| !v.is_empty());
+                !no_color && io::stdout().is_terminal() && enable_ansi_support()
+            }
+        }
+    }
+}
+
+/// Enables `ENABLE_VIRTUAL_TERMINAL_PROCESSING` for the Windows console,
+/// such that escape sequences are interpreted instead of printed as is.
+///
+/// Returns `false` if the console does not support it, e.g. on Windows
+/// versions prior to Windows 10.
+#[cfg(all(windows, feature = "windows"))]
+fn enable_ansi_support() -> bool {
+    use std::sync::OnceLock;
+
+    static ENABLED: OnceLock<bool> = OnceLock::new();
+    *ENABLED.get_or_init(|| enable_ansi_support::enable_ansi_support().is_ok())
+}
+
+#[cfg(not(all(windows, feature = "windows")))]
+#[inline]
+fn enable_ansi_support() -> bool {
+    true
+}
+
+/// The amount of colors supported by a terminal.
+#[derive(PartialEq, Eq, PartialOrd, Ord, Clone, Copy, Debug)]
+pub enum ColorDepth {
+    /// The 16 standard and bright ANSI colors.
+    Ansi16,
+    /// The 256-color xterm palette.
+    Ansi256,
+    /// 24-bit RGB colors.
+    TrueColor,
+}
+
+impl ColorDepth {
+    /// Detects the color depth supported by the terminal, based on the
+    /// `COLORTERM` and `TERM` environment variables.
+    ///
+    /// Defaults to [`ColorDepth::Ansi16`] if neither indicate support
+    /// for more colors.
+    pub fn detect() -> Self {
+        let var = |name| env::var(name).unwrap_or_default().to_ascii_lowercase();
+
+        let colorterm = var("COLORTERM");
+        if matches!(colorterm.as_str(), "truecolor" | "24bit") {
+            return Self::TrueColor;
+        }
+
+        // Windows Terminal supports truecolor, but does not set `COLORTERM`
+        if env::var_os("WT_SESSION").is_some() {
+            return Self::TrueColor;
+        }
+
+        let term = var("TERM");
+        if term.ends_with("-direct") || term.ends_with("truecolor") {
+            Self::TrueColor
+        } else if term.contains("256color") {
+            Self::Ansi256
+        } else {
+            Self::Ansi16
+        }
+    }
+}
+
+/// An RGB color, rendered as an ANSI escape sequence.
+///
+/// Depending on the [`ColorDepth`], the color is either rendered
+/// as is, or quantized using [`quantize_256()`] or [`quantize_16()`].
+///
+/// [`quantize_256()`]: Self::quantize_256
+/// [`quantize_16()`]: Self::quantize_16
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
 pub struct AnsiColor(pub [u8; 3]);
 
 impl AnsiColor {
-    fn write_fg_code(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        let Self([r, g, b]) = self;
-        write!(f, "38;2;{r};{g};{b}")
+    /// The RGB values of the 16 ANSI colors, as used by xterm.
+    const PALETTE_16: [[u8; 3]; 16] = [
+        [0, 0, 0],
+        [205, 0, 0],
+        [0, 205, 0],
+        [205, 205, 0],
+        [0, 0, 238],
+        [205, 0, 205],
+        [0, 205, 205],
+        [229, 229, 229],
+        [127, 127, 127],
+        [255, 0, 0],
+        [0, 255, 0],
+        [255, 255, 0],
+        [92, 92, 255],
+        [255, 0, 255],
+        [0, 255, 255],
+        [255, 255, 255],
+    ];
+
+    /// The channel levels of the 6x6x6 color cube in the 256-color palette.
+    const CUBE_LEVELS: [u8; 6] = [0, 95, 135, 175, 215, 255];
+
+    /// Returns the index of the closest color in the 256-color xterm palette.
+    ///
+    /// Only the 6x6x6 color cube (`16..=231`) and the grayscale ramp
+    /// (`232..=255`) are considered, as the first 16 colors are commonly
+    /// redefined by terminal themes.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use colorblast::AnsiColor;
+    ///
+    /// assert_eq!(AnsiColor([255, 0, 0]).quantize_256(), 196);
+    /// assert_eq!(AnsiColor([128, 128, 128]).quantize_256(), 244);
+    /// ```
+    pub fn quantize_256(&self) -> u8 {
+        let Self([r, g, b]) = *self;
+
+        let cube_index = |c: u8| -> u8 {
+            (0..6u8)
+                .min_by_key(|&i| Self::CUBE_LEVELS[i as usize].abs_diff(c))
+                .unwrap()
+        };
+        let (ri, gi, bi) = (cube_index(r), cube_index(g), cube_index(b));
+        let cube = [ri, gi, bi].map(|i| Self::CUBE_LEVELS[i as usize]);
+        let cube_index = 16 + 36 * ri + 6 * gi + bi;
+
+        // The grayscale ramp is `8 + 10 * i` for `i` in `0..24`
+        let avg = ((r as u16 + g as u16 + b as u16) / 3) as u8;
+        let gray_index = (avg.saturating_sub(3) / 10).min(23);
+        let gray_level = 8 + 10 * gray_index;
+        let gray = [gray_level; 3];
+
+        if distance(self.0, gray) < distance(self.0, cube) {
+            232 + gray_index
+        } else {
+            cube_index
+        }
+    }
+
+    /// Returns the index (`0..16`) of the closest of the 16 ANSI colors,
+    /// where `8..16` are the bright variants.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use colorblast::AnsiColor;
+    ///
+    /// assert_eq!(AnsiColor([0, 0, 0]).quantize_16(), 0);
+    /// assert_eq!(AnsiColor([250, 30, 30]).quantize_16(), 9);
+    /// ```
+    pub fn quantize_16(&self) -> u8 {
+        (0..16u8)
+            .min_by_key(|&i| distance(self.0, Self::PALETTE_16[i as usize]))
+            .unwrap()
+    }
+
+    fn write_fg_code(&self, f: &mut fmt::Formatter<'_>, depth: ColorDepth) -> fmt::Result {
+        match depth {
+            ColorDepth::TrueColor => {
+                let Self([r, g, b]) = self;
+                write!(f, "38;2;{r};{g};{b}")
+            }
+            ColorDepth::Ansi256 => write!(f, "38;5;{}", self.quantize_256()),
+            ColorDepth::Ansi16 => match self.quantize_16() {
+                i @ 0..=7 => write!(f, "{}", 30 + i),
+                i => write!(f, "{}", 90 + (i - 8)),
+            },
+        }
+    }
+
+    fn write_bg_code(&self, f: &mut fmt::Formatter<'_>, depth: ColorDepth) -> fmt::Result {
+        match depth {
+            ColorDepth::TrueColor => {
+                let Self([r, g, b]) = self;
+                write!(f, "48;2;{r};{g};{b}")
+            }
+            ColorDepth::Ansi256 => write!(f, "48;5;{}", self.quantize_256()),
+            ColorDepth::Ansi16 => match self.quantize_16() {
+                i @ 0..=7 => write!(f, "{}", 40 + i),
+                i => write!(f, "{}", 100 + (i - 8)),
+            },
+        }
+    }
+}
+
+impl From<Color> for AnsiColor {
+    #[inline]
+    fn from(Color([r, g, b, _a]): Color) -> Self {
+        Self([r, g, b])
+    }
+}
+
+/// Returns the squared "redmean" distance between two colors, which is
+/// a cheap approximation of the perceived difference between colors.
+fn distance([r1, g1, b1]: [u8; 3], [r2, g2, b2]: [u8; 3]) -> u32 {
+    let rmean = (r1 as i32 + r2 as i32) / 2;
+    let dr = r1 as i32 - r2 as i32;
+    let dg = g1 as i32 - g2 as i32;
+    let db = b1 as i32 - b2 as i32;
+    let d = (((512 + rmean) * dr * dr) >> 8) + 4 * dg * dg + (((767 - rmean) * db * db) >> 8);
+    d as u32
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::style::Style;
+
+    #[test]
+    fn test_render_ansi_hyperlinks() {
+        let options = AnsiOptions::new()
+            .color(ColorChoice::Always)
+            .hyperlinks(true);
+
+        #[rustfmt::skip]
+        let cases = [
+            ("foo", "foo"),
+            ("http://a.b", "\x1b]8;;http://a.b\x1b\\http://a.b\x1b]8;;\x1b\\"),
+            ("https://a http://b", "\x1b]8;;https://a\x1b\\https://a\x1b]8;;\x1b\\ \x1b]8;;http://b\x1b\\http://b\x1b]8;;\x1b\\"),
+            ("// https://a.b.", "// \x1b]8;;https://a.b\x1b\\https://a.b\x1b]8;;\x1b\\."),
+            ("\"https://a.b/c?d\"", "\"\x1b]8;;https://a.b/c?d\x1b\\https://a.b/c?d\x1b]8;;\x1b\\\""),
+        ];
+
+        for (text, expected) in cases {
+            let actual = render_ansi([(None::<Color>, text)], &options);
+            assert_eq!(actual, expected);
+
+            let options = options.clone().hyperlinks(false);
+            let actual = render_ansi([(None::<Color>, text)], &options);
+            assert_eq!(actual, text);
+        }
+    }
+
+    #[test]
+    fn test_render_ansi_color_choice() {
+        let red = Some(Color::from([255, 0, 0]));
+        let options = AnsiOptions::new().color_depth(ColorDepth::Ansi16);
+
+        // `Auto` is only resolved when printing, regardless of stdout
+        for color in [ColorChoice::Auto, ColorChoice::Always] {
+            let options = options.clone().color(color);
+            assert_eq!(render_ansi([(red, "a")], &options), "\x1b[91ma\x1b[0m");
+            #[rustfmt::skip]
+            assert_eq!(render_ansi_with_escapes([(red, "a")], &[], &options), "\x1b[91ma\x1b[0m");
+        }
+
+        let options = options.color(ColorChoice::Never);
+        assert_eq!(render_ansi([(red, "a")], &options), "a");
+        assert_eq!(render_ansi_with_escapes([(red, "a")], &[], &options), "a");
+    }
+
+    #[test]
+    fn test_render_ansi_overflow() {
+        let red = Some(Color::from([255, 0, 0]));
+        let tokens = [(red, "abc"), (None, "de\nfg\n"), (red, "hijk")];
+
+        #[rustfmt::skip]
+        let cases = [
+            (Overflow::Visible, 4, "abcde\nfg\nhijk"),
+            (Overflow::Wrap, 4, "abcd\ne\nfg\nhijk"),
+            (Overflow::Wrap, 2, "ab\ncd\ne\nfg\nhi\njk"),
+            (Overflow::Truncate, 4, "abc…\nfg\nhijk"),
+            (Overflow::Truncate, 3, "ab…\nfg\nhi…"),
+            (Overflow::Truncate, 1, "…\n…\n…"),
+            (Overflow::Wrap, 0, "abcde\nfg\nhijk"),
+            (Overflow::Truncate, 0, "abcde\nfg\nhijk"),
+        ];
+
+        for (overflow, width, expected) in cases {
+            let options = AnsiOptions::new()
+                .color(ColorChoice::Never)
+                .overflow(overflow)
+                .width(width);
+            let actual = render_ansi(tokens, &options);
+            assert_eq!(actual, expected, "{overflow:?} {width}");
+        }
+
+        // Styles are reset before line breaks and ellipses
+        let options = AnsiOptions::new()
+            .color(ColorChoice::Always)
+            .color_depth(ColorDepth::Ansi16)
+            .overflow(Overflow::Wrap)
+            .width(2);
+        let actual = render_ansi([(red, "abc")], &options);
+        assert_eq!(actual, "\x1b[91mab\x1b[0m\n\x1b[91mc\x1b[0m");
+
+        let options = options.overflow(Overflow::Truncate);
+        let actual = render_ansi([(red, "abc"), (red, "d")], &options);
+        assert_eq!(actual, "\x1b[91ma\x1b[0m…");
+    }
+
+    #[test]
+    fn test_render_ansi_overflow_display_width() {
+        #[rustfmt::skip]
+        let cases = [
+            // Wide characters take two columns
+            ("東京都", Overflow::Wrap, 4, "東京\n都"),
+            ("東京都", Overflow::Wrap, 5, "東京\n都"),
+            ("東京都", Overflow::Truncate, 5, "東京…"),
+            ("東京都", Overflow::Truncate, 4, "東…"),
+            ("a東", Overflow::Wrap, 2, "a\n東"),
+            // Combining characters take no columns
+            ("e\u{301}e\u{301}e\u{301}", Overflow::Truncate, 3, "e\u{301}e\u{301}e\u{301}"),
+            // Tabs are expanded to the next tab stop
+            ("a\tb", Overflow::Wrap, 10, "a       b"),
+            ("a\tb", Overflow::Wrap, 4, "a   \n    \nb"),
+            ("a\tb\n\tc", Overflow::Truncate, 8, "a      …\n       …"),
+        ];
+
+        for (text, overflow, width, expected) in cases {
+            let options = AnsiOptions::new()
+                .color(ColorChoice::Never)
+                .overflow(overflow)
+                .width(width);
+            let actual = render_ansi([(None::<Color>, text)], &options);
+            assert_eq!(actual, expected, "{text:?} {overflow:?} {width}");
+        }
+    }
+
+    #[test]
+    fn test_render_ansi_color_depth() {
+        let tokens = [(Some(Color::from([255, 0, 0])), "foo")];
+
+        #[rustfmt::skip]
+        let cases = [
+            (ColorDepth::TrueColor, "\x1b[38;2;255;0;0mfoo\x1b[0m"),
+            (ColorDepth::Ansi256, "\x1b[38;5;196mfoo\x1b[0m"),
+            (ColorDepth::Ansi16, "\x1b[91mfoo\x1b[0m"),
+        ];
+
+        for (depth, expected) in cases {
+            let options = AnsiOptions::new()
+                .color(ColorChoice::Always)
+                .color_depth(depth);
+            let actual = render_ansi(tokens, &options);
+            assert_eq!(actual, expected);
+        }
+    }
+
+    #[test]
+    fn test_render_ansi_style_changes() {
+        let red = Style::new().fg(Color::from([255, 0, 0]));
+        let on_red = red.clone().bg(Color::from([255, 0, 0]));
+        let blue = Style::new().fg(Color::from([0, 0, 255]));
+        let (red, on_red, blue) = (Some(&red), Some(&on_red), Some(&blue));
+
+        #[rustfmt::skip]
+        let cases = [
+            (&[(red, "a"), (red, "b")][..], "\x1b[91mab\x1b[0m"),
+            (&[(red, "a"), (None, " \t"), (red, "b")][..], "\x1b[91ma \tb\x1b[0m"),
+            (&[(red, "a"), (None, " b")][..], "\x1b[91ma\x1b[0m b"),
+            (&[(red, "a"), (blue, "b")][..], "\x1b[91ma\x1b[34mb\x1b[0m"),
+            (&[(red, "a"), (on_red, "b"), (red, "c")][..], "\x1b[91ma\x1b[101mb\x1b[0m\x1b[91mc\x1b[0m"),
+            (&[(on_red, "a"), (None, " "), (on_red, "b")][..], "\x1b[91;101ma\x1b[0m \x1b[91;101mb\x1b[0m"),
+            (&[(red, "a\n"), (red, "\nb")][..], "\x1b[91ma\x1b[0m\n\n\x1b[91mb\x1b[0m"),
+            (&[(None, "a"), (red, ""), (None, "b")][..], "ab"),
+        ];
+
+        let options = AnsiOptions::new()
+            .color(ColorChoice::Always)
+            .color_depth(ColorDepth::Ansi16);
+        for (tokens, expected) in cases {
+            let actual = render_ansi(tokens.iter().copied(), &options);
+            assert_eq!(actual, expected, "{tokens:?}");
+        }
     }
 
-    fn write_bg_code(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        let Self([r, g, b]) = self;
-        write!(f, "48;2;{r};{g};{b}")
+    #[test]
+    fn test_render_ansi_color_never() {
+        let tokens = [(Some(Color::WHITE), "foo"), (None, " https://a.b")];
+
+        let options = AnsiOptions::new()
+            .color(ColorChoice::Never)
+            .hyperlinks(true);
+        let actual = render_ansi(tokens, &options);
+        assert_eq!(actual, "foo https://a.b");
+    }
+
+    #[test]
+    fn test_strip_ansi() {
+        #[rustfmt::skip]
+        let cases = [
+            ("foo", "foo", &[][..]),
+            ("\x1b[1;31mfoo\x1b[0m bar", "foo bar", &[(0, "\x1b[1;31m"), (3, "\x1b[0m")][..]),
+            ("a\x1b[2Kb", "ab", &[(1, "\x1b[2K")][..]),
+            ("\x1b]8;;http://a.b\x1b\\a\x1b]8;;\x07", "a", &[(0, "\x1b]8;;http://a.b\x1b\\"), (1, "\x1b]8;;\x07")][..]),
+            ("a\x1b[", "a", &[(1, "\x1b[")][..]),
+            ("\x1b\x1bc", "", &[(0, "\x1b"), (0, "\x1bc")][..]),
+        ];
+
+        for (input, expected_text, expected_escapes) in cases {
+            let stripped = strip_ansi(input);
+            assert_eq!(stripped.text, expected_text, "{input:?}");
+
+            let escapes = stripped
+                .escapes
+                .iter()
+                .map(|escape| {
+                    assert_eq!(&input[escape.range.clone()], escape.code);
+                    (escape.pos, escape.code)
+                })
+                .collect::<Vec<_>>();
+            assert_eq!(escapes, expected_escapes, "{input:?}");
+        }
+    }
+
+    #[test]
+    fn test_render_ansi_with_escapes() {
+        let stripped = strip_ansi("\x1b[1mfn\x1b[0m \x1b[2Kmain");
+        let red = Some(Color::from([255, 0, 0]));
+        let tokens = [(red, "fn m"), (None, "ain")];
+
+        #[rustfmt::skip]
+        let cases = [
+            (OriginalColors::Discard, "\x1b[38;2;255;0;0mfn m\x1b[0main"),
+            (OriginalColors::Preserve, "\x1b[1mfn\x1b[0m\x1b[38;2;255;0;0m \x1b[2Km\x1b[0main"),
+            (OriginalColors::Merge, "\x1b[38;2;255;0;0m\x1b[1mfn\x1b[0m\x1b[38;2;255;0;0m \x1b[2Km\x1b[0main"),
+        ];
+
+        for (original_colors, expected) in cases {
+            let options = AnsiOptions::new()
+                .color(ColorChoice::Always)
+                .color_depth(ColorDepth::TrueColor)
+                .original_colors(original_colors);
+            let actual = render_ansi_with_escapes(tokens, &stripped.escapes, &options);
+            assert_eq!(actual, expected, "{original_colors:?}");
+        }
+
+        let options = AnsiOptions::new().color(ColorChoice::Never);
+        let actual = render_ansi_with_escapes(tokens, &stripped.escapes, &options);
+        assert_eq!(actual, "fn main");
+
+        // Styles are only pushed when changed, also within original sequences
+        let stripped = strip_ansi("\x1b[1mab c\x1b[0md");
+        let tokens = [(red, "a"), (red, "b"), (None, " "), (red, "c"), (red, "d")];
+        let options = AnsiOptions::new()
+            .color(ColorChoice::Always)
+            .color_depth(ColorDepth::TrueColor)
+            .original_colors(OriginalColors::Merge);
+        let actual = render_ansi_with_escapes(tokens, &stripped.escapes, &options);
+        assert_eq!(
+            actual,
+            "\x1b[38;2;255;0;0m\x1b[1mab c\x1b[0m\x1b[38;2;255;0;0md\x1b[0m"
+        );
+    }
+
+    #[test]
+    fn test_quantize() {
+        #[rustfmt::skip]
+        let cases = [
+            ([0, 0, 0], 16, 0),
+            ([255, 255, 255], 231, 15),
+            ([0, 0, 255], 21, 4),
+            ([0, 255, 0], 46, 10),
+            ([95, 135, 175], 67, 8),
+            ([30, 30, 30], 234, 0),
+            ([200, 200, 200], 251, 7),
+        ];
+
+        for (rgb, expected_256, expected_16) in cases {
+            let color = AnsiColor(rgb);
+            assert_eq!(color.quantize_256(), expected_256, "{rgb:?}");
+            assert_eq!(color.quantize_16(), expected_16, "{rgb:?}");
+        }
     }
 }
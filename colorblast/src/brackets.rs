@@ -0,0 +1,186 @@
+//! Classification of brackets, e.g. for rainbow brackets and folding.
+
+use crate::{Token, TokenSpan};
+
+/// Returns `tokens` where `<` and `>` enclosing generic arguments or
+/// parameters are classified as [`Token::Delimiter`], while comparison
+/// operators remain [`Token::Operator`].
+///
+/// This is a heuristic based on the surrounding tokens, intended for
+/// languages using `<>` for generics, e.g. Rust, Java, and C++. A `<` is
+/// considered generic if a matching `>` follows, and only tokens valid
+/// inside generics are encountered in between, e.g. `Vec<Option<u8>>`
+/// and `HashMap<K, V>`. As such `a < b && c > d` is a comparison, while
+/// `f(a < b, c > d)` is ambiguous and considered generic.
+///
+/// A closing `>>` is classified as a single delimiter, if it closes two
+/// generics, e.g. in `Vec<Vec<u8>>`.
+///
+/// # Example
+///
+/// ```rust
+/// use colorblast::brackets::disambiguate_angle_brackets;
+/// use colorblast::{Lexer, Token};
+///
+/// let code = "let v: Vec<u8> = if a < b { f::<u8>() } else { x };";
+/// let tokens = disambiguate_angle_brackets(Lexer::Rust.into_lexer(code));
+///
+/// let brackets = tokens
+///     .iter()
+///     .filter(|(_tok, span)| matches!(span.as_str(), "<" | ">"))
+///     .map(|(tok, _span)| *tok)
+///     .collect::<Vec<_>>();
+/// assert_eq!(
+///     brackets,
+///     [
+///         Token::Delimiter,
+///         Token::Delimiter,
+///         Token::Operator,
+///         Token::Delimiter,
+///         Token::Delimiter,
+///     ]
+/// );
+/// ```
+pub fn disambiguate_angle_brackets<'text, I>(tokens: I) -> Vec<(Token, TokenSpan<'text>)>
+where
+    I: IntoIterator<Item = (Token, TokenSpan<'text>)>,
+{
+    let mut tokens = tokens.into_iter().collect::<Vec<_>>();
+
+    // Index of the previous non-whitespace and non-comment token
+    let mut prev = None;
+    for i in 0..tokens.len() {
+        let (tok, ref span) = tokens[i];
+        if matches!(tok, Token::Space | Token::Comment) {
+            continue;
+        }
+
+        let is_open = (tok == Token::Operator) && (span.as_str() == "<");
+        if is_open && !prev.is_some_and(|prev| ends_expr(&tokens[prev])) {
+            if let Some(brackets) = find_generic_brackets(&tokens, i) {
+                for j in brackets {
+                    tokens[j].0 = Token::Delimiter;
+                }
+            }
+        }
+
+        prev = Some(i);
+    }
+
+    tokens
+}
+
+/// Returns `true` if `(tok, span)` can only be the end of an expression,
+/// such that a following `<` must be a comparison, e.g. `1 < x`.
+fn ends_expr((tok, span): &(Token, TokenSpan<'_>)) -> bool {
+    match tok {
+        Token::Number | Token::String => true,
+        Token::Delimiter => matches!(span.as_str(), ")" | "]"),
+        _ => false,
+    }
+}
+
+/// Returns the indices of all `<` and `>` of the generic opened at
+/// `tokens[start]`, including nested generics, or `None` if `tokens[start]`
+/// does not open a generic.
+fn find_generic_brackets(tokens: &[(Token, TokenSpan<'_>)], start: usize) -> Option<Vec<usize>> {
+    let mut brackets = vec![start];
+    let mut depth = 1;
+    // Depth of `()` and `[]`, e.g. `Fn(u8)` and `[u8; 4]`
+    let mut parens = 0;
+
+    for (i, (tok, span)) in tokens.iter().enumerate().skip(start + 1) {
+        let s = span.as_str();
+        match tok {
+            Token::Space | Token::Comment => {}
+            Token::Operator => match s {
+                "<" => {
+                    depth += 1;
+                    brackets.push(i);
+                }
+                ">" => {
+                    depth -= 1;
+                    brackets.push(i);
+                }
+                ">>" if depth >= 2 => {
+                    depth -= 2;
+                    brackets.push(i);
+                }
+                ";" if parens > 0 => {}
+                "," | "::" | ":" | "." | "&" | "*" | "?" | "=" | "+" | "->" | "'" => {}
+                _ => return None,
+            },
+            Token::Delimiter => match s {
+                "(" | "[" => parens += 1,
+                ")" | "]" if parens > 0 => parens -= 1,
+                _ => return None,
+            },
+            Token::Number
+            | Token::Keyword
+            | Token::Keyword2
+            | Token::Var
+            | Token::Var2
+            | Token::Var3
+            | Token::Var4
+            | Token::Var5
+            | Token::PrimitiveType => {}
+            _ => return None,
+        }
+
+        if depth == 0 {
+            return (parens == 0).then_some(brackets);
+        }
+    }
+
+    None
+}
+
+#[cfg(all(test, feature = "lang-rust"))]
+mod tests {
+    use super::*;
+    use crate::Lexer;
+
+    #[test]
+    fn test_disambiguate_angle_brackets() {
+        #[rustfmt::skip]
+        let cases = [
+            // code, whether each `<`, `>`, and `>>` is generic
+            ("a < b", &[false][..]),
+            ("a > b", &[false]),
+            ("a < b && c > d", &[false, false]),
+            ("if a < b { c > d }", &[false, false]),
+            ("1 < x; (a) < b > c", &[false, false, false]),
+            ("a << b >> c", &[false]),
+            ("a < b >> c", &[false, false]),
+            ("Vec<u8>", &[true, true]),
+            ("Vec<Vec<u8>>", &[true, true, true]),
+            ("Vec<Vec<Vec<u8>>>", &[true, true, true, true, true]),
+            ("HashMap<&'a str, [u8; 4]>", &[true, true]),
+            ("Box<dyn Fn(u8) -> u8 + Send>", &[true, true]),
+            ("impl<T: Iterator<Item = u8>> X<T>", &[true, true, true, true, true]),
+            ("f::<u8>() < g::<u8>()", &[true, true, false, true, true]),
+            ("<T as Default>::default()", &[true, true]),
+            ("a<b>=c", &[false]),
+        ];
+
+        for (code, expected) in cases {
+            let actual = disambiguate_angle_brackets(Lexer::Rust.into_lexer(code))
+                .into_iter()
+                .filter(|(_tok, span)| matches!(span.as_str(), "<" | ">" | ">>"))
+                .map(|(tok, _span)| tok == Token::Delimiter)
+                .collect::<Vec<_>>();
+            assert_eq!(actual, expected, "{code:?}");
+        }
+    }
+
+    #[test]
+    fn test_disambiguate_angle_brackets_spans() {
+        let code = "fn f<T>(x: Vec<T>) -> bool { x.len() < 2 }";
+        let tokens = disambiguate_angle_brackets(Lexer::Rust.into_lexer(code));
+        let actual = tokens
+            .iter()
+            .map(|(_tok, span)| span.as_str())
+            .collect::<String>();
+        assert_eq!(actual, code);
+    }
+}
@@ -0,0 +1,174 @@
+//! Detecting the language of code from its content, e.g. for code read
+//! from stdin or pasted, where there is no file name to go by.
+
+use crate::{Lexer, Token};
+
+/// Maximum amount of bytes of the text, which is lexed by
+/// [`detect_by_lexing()`].
+pub const DETECT_PREFIX_LEN: usize = 4096;
+
+/// Returns the languages `text` is likely written in, ranked by their
+/// score from `0.0` to `1.0`, with the most likely language first.
+///
+/// Each language is scored by lexing a prefix of `text`, i.e. at most
+/// [`DETECT_PREFIX_LEN`] bytes, using its [`Lexer`]. The score is lowered
+/// by the density of [`Token::Invalid`] and unterminated tokens, as well
+/// as by text which is only classified as [`Token::Text`]. The score
+/// is raised by the ratio of words which are keywords of the language.
+///
+/// Languages with the same score are ordered as in [`Lexer::VARIANTS`].
+/// [`Lexer::PlainText`] is never returned, as it accepts any text. If
+/// `text` only contains whitespace, then no languages are returned.
+///
+/// **Note:** The scores are heuristic, and short snippets can commonly
+/// be valid in multiple languages, e.g. `x = 1` is valid Python, Rust,
+/// and JavaScript.
+///
+/// # Example
+///
+/// ```rust
+/// use colorblast::detect::detect_by_lexing;
+/// use colorblast::Lexer;
+///
+/// let code = r#"
+/// def main():
+///     for i in range(10):
+///         print(f"{i}")
+/// "#;
+///
+/// let ranked = detect_by_lexing(code);
+/// assert_eq!(ranked[0].0, Lexer::Python);
+/// ```
+pub fn detect_by_lexing(text: &str) -> Vec<(Lexer, f32)> {
+    let text = prefix(text, DETECT_PREFIX_LEN);
+    if text.trim().is_empty() {
+        return Vec::new();
+    }
+
+    let mut ranked = Lexer::VARIANTS
+        .iter()
+        .copied()
+        .filter(|&lexer| lexer != Lexer::PlainText)
+        .map(|lexer| (lexer, score(lexer, text)))
+        .collect::<Vec<_>>();
+    // Stable sort, such that ties remain in the order of `Lexer::VARIANTS`
+    ranked.sort_by(|(_, a), (_, b)| b.total_cmp(a));
+    ranked
+}
+
+/// Returns the score of `lexer` for `text`, from `0.0` to `1.0`.
+fn score(lexer: Lexer, text: &str) -> f32 {
+    let mut bytes = 0_usize;
+    let mut error_bytes = 0_usize;
+    let mut text_bytes = 0_usize;
+    let mut words = 0_usize;
+    let mut keywords = 0_usize;
+
+    for (tok, span) in lexer.into_lexer(text) {
+        if tok == Token::Space {
+            continue;
+        }
+
+        let s = span.as_str();
+        bytes += s.len();
+
+        if (tok == Token::Invalid) || span.is_unterminated() {
+            error_bytes += s.len();
+            continue;
+        }
+
+        match tok {
+            Token::Text => text_bytes += s.len(),
+            Token::Comment | Token::String => continue,
+            _ => {}
+        }
+
+        if matches!(tok, Token::Keyword | Token::Keyword2 | Token::PrimitiveType) {
+            words += 1;
+            keywords += 1;
+        } else if s.starts_with(|c: char| c.is_alphabetic() || (c == '_')) {
+            words += 1;
+        }
+    }
+
+    if bytes == 0 {
+        return 0.0;
+    }
+
+    let errors = error_bytes as f32 / bytes as f32;
+    let unclassified = text_bytes as f32 / bytes as f32;
+    let keywords = if words == 0 {
+        0.0
+    } else {
+        keywords as f32 / words as f32
+    };
+
+    (1.0 - errors) * (1.0 - 0.25 * unclassified) * (0.6 + 0.4 * keywords)
+}
+
+/// Returns at most the first `max_len` bytes of `text`, excluding any
+/// trailing partial line, unless `text` is a single line.
+fn prefix(text: &str, max_len: usize) -> &str {
+    if text.len() <= max_len {
+        return text;
+    }
+
+    let mut end = max_len;
+    while !text.is_char_boundary(end) {
+        end -= 1;
+    }
+    let text = &text[..end];
+
+    match text.rfind('\n') {
+        Some(i) => &text[..i],
+        None => text,
+    }
+}
+
+#[cfg(all(test, feature = "all-langs"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_detect_by_lexing() {
+        #[rustfmt::skip]
+        let cases = [
+            (Lexer::Rust, "fn main() {\n    let x: i32 = 1;\n    println!(\"{x}\");\n}\n"),
+            (Lexer::Rust, "pub struct Foo<'a> {\n    s: &'a str,\n}\n\nimpl Foo<'_> {}\n"),
+            (Lexer::Python, "import os\n\ndef f(x):\n    return x is not None\n"),
+            (Lexer::Python, "class Foo:\n    def __init__(self):\n        self.x = 'it''s'\n"),
+            (Lexer::Json, "{\n  \"a\": [1, 2.5, true, null],\n  \"b\": { \"c\": \"d\" }\n}\n"),
+            (Lexer::JsonC, "{\n  // Comment\n  \"a\": 1,\n}\n"),
+            (Lexer::JavaScript, "const x = require('x');\nfunction f() { return `${x}`; }\n"),
+            (Lexer::TypeScript, "interface Foo {\n  x: number;\n}\ntype Bar = Foo | null;\n"),
+            (Lexer::Markdown, "# Title\n\nSome *emphasis* and a [link](url).\n\n- Item\n"),
+            (Lexer::CMake, "cmake_minimum_required(VERSION 3.10)\nproject(App LANGUAGES C)\nadd_executable(app ${SOURCES})\n"),
+        ];
+        for (expected, text) in cases {
+            let ranked = detect_by_lexing(text);
+            assert_eq!(ranked[0].0, expected, "{text:?}\n{ranked:?}");
+        }
+    }
+
+    #[test]
+    fn test_detect_by_lexing_scores() {
+        let ranked = detect_by_lexing("fn main() {}");
+        assert_eq!(ranked.len(), Lexer::VARIANTS.len() - 1);
+        assert!(ranked.iter().all(|&(lexer, _)| lexer != Lexer::PlainText));
+        assert!(ranked
+            .iter()
+            .all(|&(_, score)| (0.0..=1.0).contains(&score)));
+        assert!(ranked.windows(2).all(|w| w[0].1 >= w[1].1));
+
+        assert!(detect_by_lexing("").is_empty());
+        assert!(detect_by_lexing(" \n\t").is_empty());
+    }
+
+    #[test]
+    fn test_prefix() {
+        assert_eq!(prefix("abc", 3), "abc");
+        assert_eq!(prefix("abcd", 3), "abc");
+        assert_eq!(prefix("ab\ncd", 4), "ab");
+        assert_eq!(prefix("aé", 2), "a");
+    }
+}
@@ -0,0 +1,510 @@
+//! Highlighting of code blocks embedded in Markdown and HTML documents.
+//!
+//! Everything but the code blocks is left untouched, e.g. for highlighting
+//! the code in Markdown before passing it to a Markdown renderer, or the
+//! HTML produced by a Markdown renderer.
+
+use crate::html::render_html_into;
+use crate::{Lexer, Theme};
+
+/// Options for [`highlight_markdown()`] and [`highlight_html()`].
+///
+/// # Example
+///
+/// ```rust
+/// use colorblast::document::Options;
+/// use colorblast::{Lexer, Theme};
+///
+/// let options = Options::new()
+///     .theme(Theme::high_contrast())
+///     .default_lexer(Lexer::PlainText);
+/// # let _ = options;
+/// ```
+#[derive(Clone, Debug)]
+#[non_exhaustive]
+pub struct Options {
+    /// The theme used for highlighting, defaults to `None`,
+    /// i.e. [`Theme::default()`].
+    pub theme: Option<Theme>,
+
+    /// The lexer used for code blocks without a language, or with an
+    /// unknown language. Defaults to `None`, i.e. such code blocks are
+    /// left untouched.
+    pub default_lexer: Option<Lexer>,
+}
+
+impl Options {
+    pub const DEFAULT: Self = Self {
+        theme: None,
+        default_lexer: None,
+    };
+
+    #[inline]
+    pub fn new() -> Self {
+        Self::DEFAULT
+    }
+
+    #[inline]
+    pub fn theme(mut self, theme: impl Into<Option<Theme>>) -> Self {
+        self.theme = theme.into();
+        self
+    }
+
+    #[inline]
+    pub fn default_lexer(mut self, lexer: impl Into<Option<Lexer>>) -> Self {
+        self.default_lexer = lexer.into();
+        self
+    }
+}
+
+impl Default for Options {
+    #[inline]
+    fn default() -> Self {
+        Self::DEFAULT
+    }
+}
+
+/// Replaces all fenced code blocks in the Markdown `md` with highlighted
+/// HTML `<pre><code>` blocks, leaving the rest of `md` untouched.
+///
+/// The language of a code block is resolved from its info string using
+/// [`Lexer::from_markdown_tag()`], falling back to
+/// [`Options::default_lexer`].
+///
+/// Only top-level fences are recognized, i.e. fences in block quotes and
+/// list items are left untouched. An unclosed fence extends to the end of
+/// the document, as per CommonMark.
+///
+/// # Example
+///
+/// ```rust
+/// use colorblast::document::{highlight_markdown, Options};
+///
+/// let md = "# Example\n\n```rust\nfn main() {}\n```\n\nThe end.\n";
+/// let html = highlight_markdown(md, &Options::new());
+///
+/// assert!(html.starts_with("# Example\n\n<pre><code class=\"language-rust\">"));
+/// assert!(html.contains("<span style=\"color:#569CD6\">fn</span>"));
+/// assert!(html.ends_with("</code></pre>\n\nThe end.\n"));
+/// ```
+pub fn highlight_markdown(md: &str, options: &Options) -> String {
+    let theme = options.theme.clone().unwrap_or_default();
+
+    let mut out = String::with_capacity(md.len());
+    let mut lines = md.split_inclusive('\n');
+    while let Some(line) = lines.next() {
+        let Some(fence) = Fence::parse(line) else {
+            out.push_str(line);
+            continue;
+        };
+
+        // Byte range of the fenced code block in `md`
+        let start = offset_in(md, line);
+        let mut end = md.len();
+
+        let mut code = String::new();
+        let mut line_ending = "";
+        for line in lines.by_ref() {
+            if fence.is_closing(line) {
+                end = offset_in(md, line) + line.len();
+                line_ending = &line[line.trim_end_matches(['\n', '\r']).len()..];
+                break;
+            }
+            code.push_str(fence.strip_indent(line));
+        }
+
+        let lexer = Lexer::from_markdown_tag(fence.info).or(options.default_lexer);
+        match lexer {
+            Some(lexer) => {
+                push_code_block(&mut out, &theme, lexer, &code);
+                out.push_str(line_ending);
+            }
+            None => out.push_str(&md[start..end]),
+        }
+    }
+    out
+}
+
+/// Highlights the contents of all `<pre><code>` blocks in the HTML `html`,
+/// leaving the rest of `html` untouched, including the tags themselves.
+///
+/// The language of a code block is resolved from a `language-*` or
+/// `lang-*` class on either the `<pre>` or `<code>` element, as produced
+/// by most Markdown renderers, falling back to [`Options::default_lexer`].
+///
+/// Code blocks containing elements, e.g. already highlighted code blocks,
+/// are left untouched.
+///
+/// # Example
+///
+/// ```rust
+/// use colorblast::document::{highlight_html, Options};
+///
+/// let html = "<h1>Example</h1>\n<pre><code class=\"language-rust\">let x = &quot;a&quot;;\n</code></pre>\n";
+/// let html = highlight_html(html, &Options::new());
+///
+/// assert!(html.starts_with("<h1>Example</h1>\n<pre><code class=\"language-rust\"><span"));
+/// assert!(html.contains("<span style=\"color:#CE9178\">&quot;a&quot;</span>"));
+/// assert!(html.ends_with("</code></pre>\n"));
+/// ```
+pub fn highlight_html(html: &str, options: &Options) -> String {
+    let theme = options.theme.clone().unwrap_or_default();
+
+    let mut out = String::with_capacity(html.len());
+    let mut rest = html;
+    while let Some(block) = CodeBlock::find(rest) {
+        let lexer = block
+            .language
+            .and_then(Lexer::from_markdown_tag)
+            .or(options.default_lexer);
+        let code = &rest[block.code.clone()];
+
+        match lexer {
+            Some(lexer) if !code.contains('<') => {
+                out.push_str(&rest[..block.code.start]);
+                render_html_into(
+                    &mut out,
+                    theme
+                        .highlight(lexer, &unescape_html(code))
+                        .map(|(style, span)| (style, span.as_str())),
+                );
+            }
+            _ => out.push_str(&rest[..block.code.end]),
+        }
+
+        rest = &rest[block.code.end..];
+    }
+    out.push_str(rest);
+    out
+}
+
+fn push_code_block(out: &mut String, theme: &Theme, lexer: Lexer, code: &str) {
+    out.push_str("<pre><code");
+    if let Some(alias) = lexer.info().aliases.first() {
+        out.push_str(" class=\"language-");
+        out.push_str(alias);
+        out.push('"');
+    }
+    out.push('>');
+    render_html_into(
+        out,
+        theme
+            .highlight(lexer, code)
+            .map(|(style, span)| (style, span.as_str())),
+    );
+    out.push_str("</code></pre>");
+}
+
+/// Returns the byte offset of `sub` in `text`, where `sub` must be a
+/// substring of `text`.
+fn offset_in(text: &str, sub: &str) -> usize {
+    (sub.as_ptr() as usize) - (text.as_ptr() as usize)
+}
+
+/// Opening code fence, e.g. ```` ```rust ````.
+#[derive(Clone, Copy, Debug)]
+struct Fence<'a> {
+    /// Amount of spaces before the fence, at most 3.
+    indent: usize,
+    /// Either `` ` `` or `~`.
+    c: char,
+    /// Amount of `c`, at least 3.
+    len: usize,
+    info: &'a str,
+}
+
+impl<'a> Fence<'a> {
+    fn parse(line: &'a str) -> Option<Self> {
+        let (indent, c, len, rest) = parse_fence_run(line)?;
+        let info = rest.trim();
+        if (c == '`') && info.contains('`') {
+            return None;
+        }
+        Some(Self {
+            indent,
+            c,
+            len,
+            info,
+        })
+    }
+
+    fn is_closing(&self, line: &str) -> bool {
+        match parse_fence_run(line) {
+            Some((_indent, c, len, rest)) => {
+                (c == self.c) && (len >= self.len) && rest.trim().is_empty()
+            }
+            None => false,
+        }
+    }
+
+    /// Removes up to [`indent`](Self::indent) spaces from the start of `line`.
+    fn strip_indent<'b>(&self, line: &'b str) -> &'b str {
+        let spaces = line.len() - line.trim_start_matches(' ').len();
+        &line[spaces.min(self.indent)..]
+    }
+}
+
+/// Returns the indent, fence character, fence length, and the remaining
+/// text of `line`, if `line` starts with a code fence.
+fn parse_fence_run(line: &str) -> Option<(usize, char, usize, &str)> {
+    let rest = line.trim_start_matches(' ');
+    let indent = line.len() - rest.len();
+    if indent > 3 {
+        return None;
+    }
+
+    let c = rest.chars().next().filter(|&c| matches!(c, '`' | '~'))?;
+    let after = rest.trim_start_matches(c);
+    let len = rest.len() - after.len();
+    (len >= 3).then_some((indent, c, len, after))
+}
+
+/// `<pre><code>` block in HTML.
+#[derive(Clone, Debug)]
+struct CodeBlock<'a> {
+    /// Byte range of the contents of the `<code>` element.
+    code: std::ops::Range<usize>,
+    language: Option<&'a str>,
+}
+
+impl<'a> CodeBlock<'a> {
+    fn find(html: &'a str) -> Option<Self> {
+        let mut from = 0;
+        loop {
+            let (pre, pre_end) = find_start_tag(html, from, "pre")?;
+            from = pre_end;
+
+            let after_pre = &html[pre_end..];
+            let trimmed = after_pre.trim_start();
+            let code_start = pre_end + (after_pre.len() - trimmed.len());
+            let Some((code, code_end)) = find_start_tag(html, code_start, "code")
+                .filter(|&(code, _)| html[code_start..].starts_with(code))
+            else {
+                continue;
+            };
+
+            let Some(len) = find_ignore_ascii_case(&html[code_end..], "</code>") else {
+                continue;
+            };
+
+            let language = language_of(code).or_else(|| language_of(pre));
+            return Some(Self {
+                code: code_end..(code_end + len),
+                language,
+            });
+        }
+    }
+}
+
+/// Returns the first start tag `<name ...>` at or after `from`, and the
+/// byte offset after the tag.
+fn find_start_tag<'a>(html: &'a str, mut from: usize, name: &str) -> Option<(&'a str, usize)> {
+    loop {
+        let start = from + html[from..].find('<')?;
+        from = start + 1;
+
+        let rest = &html[from..];
+        let is_name = rest
+            .get(..name.len())
+            .is_some_and(|s| s.eq_ignore_ascii_case(name));
+        if !is_name {
+            continue;
+        }
+        let is_end_of_name =
+            rest[name.len()..].starts_with(|c: char| (c == '>') || c.is_ascii_whitespace());
+        if !is_end_of_name {
+            continue;
+        }
+
+        let end = from + rest.find('>')? + 1;
+        return Some((&html[start..end], end));
+    }
+}
+
+fn find_ignore_ascii_case(haystack: &str, needle: &str) -> Option<usize> {
+    haystack
+        .as_bytes()
+        .windows(needle.len())
+        .position(|window| window.eq_ignore_ascii_case(needle.as_bytes()))
+}
+
+/// Returns the language of a `language-*` or `lang-*` class in `tag`.
+fn language_of(tag: &str) -> Option<&str> {
+    let start = find_ignore_ascii_case(tag, "class=")? + "class=".len();
+    let value = &tag[start..];
+    let value = match value.chars().next()? {
+        quote @ ('"' | '\'') => value[1..].split(quote).next()?,
+        _ => value
+            .split(|c: char| (c == '>') || c.is_ascii_whitespace())
+            .next()?,
+    };
+
+    value.split_ascii_whitespace().find_map(|class| {
+        class
+            .strip_prefix("language-")
+            .or_else(|| class.strip_prefix("lang-"))
+    })
+}
+
+fn unescape_html(text: &str) -> String {
+    const ENTITIES: &[(&str, &str)] = &[
+        ("&lt;", "<"),
+        ("&gt;", ">"),
+        ("&quot;", "\""),
+        ("&#x27;", "'"),
+        ("&#39;", "'"),
+        ("&apos;", "'"),
+        ("&amp;", "&"),
+    ];
+
+    let mut unescaped = String::with_capacity(text.len());
+    let mut rest = text;
+    while let Some(i) = rest.find('&') {
+        unescaped.push_str(&rest[..i]);
+        rest = &rest[i..];
+        match ENTITIES.iter().find(|(entity, _)| rest.starts_with(entity)) {
+            Some((entity, c)) => {
+                unescaped.push_str(c);
+                rest = &rest[entity.len()..];
+            }
+            None => {
+                unescaped.push('&');
+                rest = &rest[1..];
+            }
+        }
+    }
+    unescaped.push_str(rest);
+    unescaped
+}
+
+#[cfg(all(test, feature = "lang-rust", feature = "lang-python"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_highlight_markdown_untouched() {
+        let md = "\
+# Title
+
+Text with `code` and ``` inline ``` fences.
+
+```unknown
+fn main() {}
+```
+
+    ```rust
+    indented code block
+    ```
+
+> ```rust
+> fn quoted() {}
+> ```
+";
+        assert_eq!(highlight_markdown(md, &Options::new()), md);
+    }
+
+    #[test]
+    fn test_highlight_markdown() {
+        let md = "\
+Before
+
+```rust
+let x = 1;
+```
+Middle
+  ~~~~ python title=\"example\"
+  x = 1
+ y = 2
+  ~~~~~
+
+```
+text
+```
+After";
+        let expected = "\
+Before
+
+<pre><code class=\"language-rust\">CODE(rust:let x = 1;\n)</code></pre>
+Middle
+<pre><code class=\"language-python\">CODE(python:x = 1\ny = 2\n)</code></pre>
+
+```
+text
+```
+After";
+        let actual = highlight_markdown(md, &Options::new());
+        assert_eq!(actual, with_code(expected));
+    }
+
+    #[test]
+    fn test_highlight_markdown_default_lexer() {
+        let md = "```\nfn f() {}\n```\n\n```foo\nx\n";
+        let expected = "\
+<pre><code class=\"language-rust\">CODE(rust:fn f() {}\n)</code></pre>
+
+<pre><code class=\"language-rust\">CODE(rust:x\n)</code></pre>";
+        let options = Options::new().default_lexer(Lexer::Rust);
+        assert_eq!(highlight_markdown(md, &options), with_code(expected));
+    }
+
+    #[test]
+    fn test_highlight_html() {
+        let html = "\
+<p>Text</p>
+<pre><code class=\"language-rust\">if a &lt; b &amp;&amp; c { }
+</code></pre>
+<PRE class='lang-python'>
+<CODE>x = 'a'
+</CODE></PRE>
+<pre><code>no language</code></pre>
+<pre><code class=\"language-rust\"><span>highlighted</span></code></pre>
+<pre class=\"language-rust\">no code</pre>
+<code class=\"language-rust\">inline</code>
+";
+        let expected = "\
+<p>Text</p>
+<pre><code class=\"language-rust\">CODE(rust:if a < b && c { }
+)</code></pre>
+<PRE class='lang-python'>
+<CODE>CODE(python:x = 'a'
+)</CODE></PRE>
+<pre><code>no language</code></pre>
+<pre><code class=\"language-rust\"><span>highlighted</span></code></pre>
+<pre class=\"language-rust\">no code</pre>
+<code class=\"language-rust\">inline</code>
+";
+        assert_eq!(highlight_html(html, &Options::new()), with_code(expected));
+    }
+
+    #[test]
+    fn test_unescape_html() {
+        assert_eq!(
+            unescape_html("&lt;a&gt; &amp;lt; &#x27;&#39;&quot; & &x"),
+            "<a> &lt; ''\" & &x"
+        );
+    }
+
+    /// Replaces all `CODE(lang:code)` in `expected` with `code`
+    /// highlighted using the default theme.
+    fn with_code(expected: &str) -> String {
+        let mut out = String::new();
+        let mut rest = expected;
+        while let Some(start) = rest.find("CODE(") {
+            out.push_str(&rest[..start]);
+            rest = &rest[(start + "CODE(".len())..];
+
+            let (lang, after) = rest.split_once(':').unwrap();
+            let end = after.find(")</").unwrap();
+            let lexer = Lexer::by_name(lang).unwrap();
+            render_html_into(
+                &mut out,
+                Theme::default()
+                    .highlight(lexer, &after[..end])
+                    .map(|(style, span)| (style, span.as_str())),
+            );
+            rest = &after[(end + 1)..];
+        }
+        out.push_str(rest);
+        out
+    }
+}
@@ -0,0 +1,63 @@
+use std::error;
+use std::fmt;
+use std::io;
+
+/// Errors returned by fallible [`colorblast`] APIs.
+///
+/// [`colorblast`]: crate
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum Error {
+    /// No [`Lexer`] exists for the given language name.
+    ///
+    /// [`Lexer`]: crate::Lexer
+    UnknownLanguage(String),
+    /// No built-in [`Theme`] exists for the given name.
+    ///
+    /// [`Theme`]: crate::Theme
+    UnknownTheme(String),
+    /// Rendering failed, e.g. due to invalid font data.
+    Render(String),
+    /// A theme could not be parsed.
+    ThemeParse(String),
+    /// A Jupyter notebook could not be parsed.
+    Notebook(String),
+    /// A plugin could not be loaded.
+    Plugin(String),
+    /// A [`LexerRegistry`] config could not be parsed.
+    ///
+    /// [`LexerRegistry`]: crate::registry::LexerRegistry
+    Config(String),
+    Io(io::Error),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::UnknownLanguage(name) => write!(f, "unknown language `{name}`"),
+            Self::UnknownTheme(name) => write!(f, "unknown theme `{name}`"),
+            Self::Render(msg) => write!(f, "render error: {msg}"),
+            Self::ThemeParse(msg) => write!(f, "invalid theme: {msg}"),
+            Self::Notebook(msg) => write!(f, "invalid notebook: {msg}"),
+            Self::Plugin(msg) => write!(f, "plugin error: {msg}"),
+            Self::Config(msg) => write!(f, "invalid config: {msg}"),
+            Self::Io(err) => err.fmt(f),
+        }
+    }
+}
+
+impl error::Error for Error {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match self {
+            Self::Io(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+impl From<io::Error> for Error {
+    #[inline]
+    fn from(err: io::Error) -> Self {
+        Self::Io(err)
+    }
+}
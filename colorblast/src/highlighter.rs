@@ -0,0 +1,364 @@
+//! Highlighting on a background thread pool, for offloading highlighting
+//! from e.g. async web servers.
+//!
+//! See [`Highlighter`] for more information.
+
+use std::any::Any;
+use std::fmt;
+use std::future::Future;
+use std::num::NonZeroUsize;
+use std::panic::{self, AssertUnwindSafe};
+use std::pin::Pin;
+use std::sync::mpsc;
+use std::sync::{Arc, Mutex};
+use std::task::{Context, Poll, Waker};
+use std::thread::{self, JoinHandle};
+
+use crate::html::render_html_into;
+use crate::registry::LexerRegistry;
+use crate::{Error, Lexer, Theme};
+
+type Job = Box<dyn FnOnce() + Send + 'static>;
+
+/// Service rendering code into HTML on a pool of background threads.
+///
+/// The results can either be awaited using [`highlight_async()`], or
+/// received by a callback using [`highlight_with()`]. The futures do
+/// not depend on any specific async runtime.
+///
+/// Languages are looked up by name using the [`LexerRegistry`] set with
+/// [`registry()`], and tokens are styled using the [`Theme`] set with
+/// [`theme()`]. If rendering panics, then the panic is returned as an
+/// [`Error::Render`], and the thread continues with the next job.
+///
+/// When the `Highlighter` is dropped, then any pending jobs are completed
+/// before the threads are joined.
+///
+/// # Example
+///
+/// ```rust
+/// use std::sync::mpsc;
+///
+/// use colorblast::highlighter::Highlighter;
+/// use colorblast::Theme;
+///
+/// let highlighter = Highlighter::new().theme(Theme::high_contrast());
+///
+/// let (tx, rx) = mpsc::channel();
+/// highlighter.highlight_with("rust", "fn main() {}", move |html| {
+///     tx.send(html).unwrap();
+/// });
+///
+/// let html = rx.recv().unwrap().unwrap();
+/// assert!(html.contains("main"));
+/// ```
+///
+/// [`highlight_async()`]: Self::highlight_async
+/// [`highlight_with()`]: Self::highlight_with
+/// [`registry()`]: Self::registry
+/// [`theme()`]: Self::theme
+pub struct Highlighter {
+    sender: Option<mpsc::Sender<Job>>,
+    workers: Vec<JoinHandle<()>>,
+    theme: Arc<Theme>,
+    registry: Arc<LexerRegistry>,
+}
+
+impl Highlighter {
+    /// Constructs a new [`Highlighter`] with a thread per available CPU,
+    /// see [`available_parallelism()`], using [`Theme::default()`] and
+    /// [`LexerRegistry::new()`].
+    ///
+    /// [`available_parallelism()`]: std::thread::available_parallelism
+    #[inline]
+    pub fn new() -> Self {
+        let threads = thread::available_parallelism().unwrap_or(NonZeroUsize::MIN);
+        Self::with_threads(threads)
+    }
+
+    /// Constructs a new [`Highlighter`] with `threads` background threads,
+    /// using [`Theme::default()`] and [`LexerRegistry::new()`].
+    pub fn with_threads(threads: NonZeroUsize) -> Self {
+        let (sender, receiver) = mpsc::channel::<Job>();
+        let receiver = Arc::new(Mutex::new(receiver));
+
+        let workers = (0..threads.get())
+            .map(|i| {
+                let receiver = Arc::clone(&receiver);
+                thread::Builder::new()
+                    .name(format!("colorblast-highlighter-{i}"))
+                    .spawn(move || loop {
+                        // The lock is released before running the job
+                        let job = match receiver.lock() {
+                            Ok(receiver) => receiver.recv(),
+                            Err(_) => break,
+                        };
+                        match job {
+                            // A panicking callback must not take down the thread
+                            Ok(job) => _ = panic::catch_unwind(AssertUnwindSafe(job)),
+                            // The `Highlighter` was dropped
+                            Err(_) => break,
+                        }
+                    })
+                    .expect("failed to spawn highlighter thread")
+            })
+            .collect();
+
+        Self {
+            sender: Some(sender),
+            workers,
+            theme: Arc::new(Theme::default()),
+            registry: Arc::new(LexerRegistry::new()),
+        }
+    }
+
+    /// Sets the [`Theme`] used to style the tokens of subsequent jobs.
+    #[inline]
+    pub fn theme(mut self, theme: Theme) -> Self {
+        self.theme = Arc::new(theme);
+        self
+    }
+
+    /// Sets the [`LexerRegistry`] used to look up the
+    /// language of subsequent jobs.
+    #[inline]
+    pub fn registry(mut self, registry: LexerRegistry) -> Self {
+        self.registry = Arc::new(registry);
+        self
+    }
+
+    /// Returns the amount of background threads.
+    #[inline]
+    pub fn threads(&self) -> usize {
+        self.workers.len()
+    }
+
+    /// Renders `code` in the language `lang` into HTML on a background
+    /// thread, and returns a future resolving to the HTML.
+    ///
+    /// Same as [`highlight_with()`], except the result is awaitable.
+    ///
+    /// [`highlight_with()`]: Self::highlight_with
+    pub fn highlight_async(&self, lang: &str, code: impl Into<String>) -> HighlightFuture {
+        let shared = Arc::new(Mutex::new(Shared::default()));
+
+        let state = Arc::clone(&shared);
+        self.highlight_with(lang, code, move |html| {
+            let mut state = state.lock().unwrap_or_else(|err| err.into_inner());
+            state.html = Some(html);
+            if let Some(waker) = state.waker.take() {
+                waker.wake();
+            }
+        });
+
+        HighlightFuture { shared }
+    }
+
+    /// Renders `code` in the language `lang` into HTML on a background
+    /// thread, and calls `callback` with the HTML on that thread.
+    ///
+    /// The HTML is the same as produced by [`render_html()`] with the
+    /// tokens styled by [`Theme::highlight()`].
+    ///
+    /// # Errors
+    ///
+    /// Calls `callback` with [`Error::UnknownLanguage`] if the registry
+    /// does not contain `lang`, and with [`Error::Render`] if
+    /// rendering panicked.
+    ///
+    /// [`render_html()`]: crate::render_html
+    pub fn highlight_with<F>(&self, lang: &str, code: impl Into<String>, callback: F)
+    where
+        F: FnOnce(Result<String, Error>) + Send + 'static,
+    {
+        let lexer = self.registry.by_name(lang);
+        let theme = Arc::clone(&self.theme);
+        let code = code.into();
+        let job = Box::new(move || {
+            let html = lexer.and_then(|lexer| {
+                panic::catch_unwind(AssertUnwindSafe(|| highlight(&theme, lexer, &code)))
+                    .map_err(|payload| Error::Render(panic_message(&*payload)))
+            });
+            callback(html)
+        });
+
+        // The sender is only `None` while dropping
+        if let Some(sender) = &self.sender {
+            // Sending only fails if all workers have exited,
+            // which only happens while dropping
+            _ = sender.send(job);
+        }
+    }
+}
+
+impl Default for Highlighter {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Drop for Highlighter {
+    fn drop(&mut self) {
+        // Dropping the sender makes the workers exit,
+        // after completing all pending jobs
+        drop(self.sender.take());
+
+        for worker in self.workers.drain(..) {
+            _ = worker.join();
+        }
+    }
+}
+
+impl fmt::Debug for Highlighter {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Highlighter")
+            .field("threads", &self.threads())
+            .finish_non_exhaustive()
+    }
+}
+
+fn highlight(theme: &Theme, lexer: Lexer, code: &str) -> String {
+    let tokens = theme
+        .highlight(lexer, code)
+        .map(|(style, span)| (style, span.as_str()));
+
+    let mut html = String::new();
+    render_html_into(&mut html, tokens);
+    html
+}
+
+fn panic_message(payload: &(dyn Any + Send)) -> String {
+    let msg = payload
+        .downcast_ref::<&str>()
+        .copied()
+        .or_else(|| payload.downcast_ref::<String>().map(String::as_str))
+        .unwrap_or("unknown panic");
+    format!("highlighting panicked: {msg}")
+}
+
+#[derive(Default)]
+struct Shared {
+    html: Option<Result<String, Error>>,
+    waker: Option<Waker>,
+}
+
+/// Future returned by [`Highlighter::highlight_async()`],
+/// resolving to the rendered HTML, or the error passed to
+/// the callback of [`Highlighter::highlight_with()`].
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct HighlightFuture {
+    shared: Arc<Mutex<Shared>>,
+}
+
+impl Future for HighlightFuture {
+    type Output = Result<String, Error>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let mut state = self.shared.lock().unwrap_or_else(|err| err.into_inner());
+        match state.html.take() {
+            Some(html) => Poll::Ready(html),
+            None => {
+                state.waker = Some(cx.waker().clone());
+                Poll::Pending
+            }
+        }
+    }
+}
+
+impl fmt::Debug for HighlightFuture {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("HighlightFuture").finish_non_exhaustive()
+    }
+}
+
+#[cfg(all(test, feature = "lang-rust"))]
+mod tests {
+    use std::task::Wake;
+    use std::thread::Thread;
+
+    use super::*;
+
+    struct ThreadWaker(Thread);
+
+    impl Wake for ThreadWaker {
+        fn wake(self: Arc<Self>) {
+            self.0.unpark();
+        }
+    }
+
+    fn block_on<F: Future>(fut: F) -> F::Output {
+        let waker = Waker::from(Arc::new(ThreadWaker(thread::current())));
+        let mut cx = Context::from_waker(&waker);
+
+        let mut fut = std::pin::pin!(fut);
+        loop {
+            match fut.as_mut().poll(&mut cx) {
+                Poll::Ready(output) => return output,
+                Poll::Pending => thread::park(),
+            }
+        }
+    }
+
+    #[test]
+    fn test_highlight_async() {
+        let highlighter = Highlighter::with_threads(NonZeroUsize::new(2).unwrap());
+        assert_eq!(highlighter.threads(), 2);
+
+        let codes = ["fn a() {}", "let b = 1;", "struct C;"];
+        let futures = codes
+            .iter()
+            .map(|&code| highlighter.highlight_async("rust", code))
+            .collect::<Vec<_>>();
+
+        let theme = Theme::default();
+        for (code, fut) in codes.into_iter().zip(futures) {
+            assert_eq!(block_on(fut).unwrap(), highlight(&theme, Lexer::Rust, code));
+        }
+    }
+
+    #[test]
+    fn test_highlight_theme_and_registry() {
+        let theme = Theme::high_contrast();
+        let registry = LexerRegistry::new().alias("rs", Lexer::Rust);
+        let highlighter = Highlighter::with_threads(NonZeroUsize::MIN)
+            .theme(theme.clone())
+            .registry(registry);
+
+        let html = block_on(highlighter.highlight_async("rs", "fn a() {}"));
+        assert_eq!(html.unwrap(), highlight(&theme, Lexer::Rust, "fn a() {}"));
+
+        let err = block_on(highlighter.highlight_async("unknown", "")).unwrap_err();
+        assert!(matches!(err, Error::UnknownLanguage(_)), "{err}");
+    }
+
+    #[test]
+    fn test_highlight_panic_keeps_thread_alive() {
+        let highlighter = Highlighter::with_threads(NonZeroUsize::MIN);
+
+        // Panics on the only thread, which must still run the next job
+        highlighter.highlight_with("rust", "", |_html| panic!("callback"));
+
+        let html = block_on(highlighter.highlight_async("rust", "fn a() {}"));
+        assert!(html.unwrap().contains("fn"));
+
+        assert_eq!(panic_message(&"render"), "highlighting panicked: render");
+    }
+
+    #[test]
+    fn test_highlight_drop_completes_jobs() {
+        let (tx, rx) = mpsc::channel();
+
+        let highlighter = Highlighter::with_threads(NonZeroUsize::MIN);
+        for i in 0..10 {
+            let tx = tx.clone();
+            highlighter.highlight_with("rust", i.to_string(), move |html| {
+                tx.send(html).unwrap();
+            });
+        }
+        drop(highlighter);
+        drop(tx);
+
+        assert_eq!(rx.iter().count(), 10);
+    }
+}
@@ -1,8 +1,69 @@
 use std::fmt::Write;
 use std::iter;
 
-use crate::style::{AsStyle, Color};
+use crate::style::{AsStyle, Color, Style};
+use crate::Token;
 
+/// Options used by [`render_html_with_options()`].
+///
+/// # Example
+///
+/// ```rust
+/// use colorblast::{render_html_with_options, Color, HtmlOptions};
+///
+/// let red = Some(Color::from([255, 0, 0]));
+/// let tokens = [(red, "a"), (red, "b")];
+///
+/// let html = render_html_with_options(tokens, &HtmlOptions::new());
+/// assert_eq!(
+///     html,
+///     "<span style=\"color:#FF0000\">a</span><span style=\"color:#FF0000\">b</span>"
+/// );
+///
+/// let html = render_html_with_options(tokens, &HtmlOptions::new().coalesce(true));
+/// assert_eq!(html, "<span style=\"color:#FF0000\">ab</span>");
+/// ```
+#[derive(Clone, Debug)]
+#[non_exhaustive]
+pub struct HtmlOptions {
+    /// Render adjacent tokens with the same style in a single `<span>`,
+    /// defaults to `false`.
+    ///
+    /// Whitespace is also rendered in the current `<span>`, if its
+    /// background color is the same, as the foreground color of
+    /// whitespace is not visible.
+    pub coalesce: bool,
+}
+
+impl HtmlOptions {
+    pub const DEFAULT: Self = Self { coalesce: false };
+
+    #[inline]
+    pub fn new() -> Self {
+        Self::DEFAULT
+    }
+
+    #[inline]
+    pub fn coalesce(mut self, coalesce: bool) -> Self {
+        self.coalesce = coalesce;
+        self
+    }
+}
+
+impl Default for HtmlOptions {
+    #[inline]
+    fn default() -> Self {
+        Self::DEFAULT
+    }
+}
+
+/// Renders all styled `tokens` as HTML, where each style is rendered
+/// as a `<span>` with an inline `style` attribute.
+///
+/// Same as [`render_html_with_options()`] using [`HtmlOptions::DEFAULT`],
+/// i.e. each token is rendered in its own `<span>`. Use
+/// [`HtmlOptions::coalesce()`] to render adjacent tokens with
+/// the same style in a single `<span>`.
 pub fn render_html<Sty, Tok, I>(tokens: I) -> String
 where
     I: IntoIterator<Item = (Sty, Tok)>,
@@ -14,55 +75,173 @@ where
     html
 }
 
+/// Same as [`render_html()`], but renders into `html`.
+#[inline]
 pub fn render_html_into<Sty, Tok, I>(html: &mut String, tokens: I)
 where
     I: IntoIterator<Item = (Sty, Tok)>,
     Sty: AsStyle,
     Tok: AsRef<str>,
 {
+    render_html_with_options_into(html, tokens, &HtmlOptions::DEFAULT);
+}
+
+/// Same as [`render_html()`], but rendered using `options`.
+pub fn render_html_with_options<Sty, Tok, I>(tokens: I, options: &HtmlOptions) -> String
+where
+    I: IntoIterator<Item = (Sty, Tok)>,
+    Sty: AsStyle,
+    Tok: AsRef<str>,
+{
+    let mut html = String::new();
+    render_html_with_options_into(&mut html, tokens, options);
+    html
+}
+
+/// Same as [`render_html_with_options()`], but renders into `html`.
+pub fn render_html_with_options_into<Sty, Tok, I>(
+    html: &mut String,
+    tokens: I,
+    options: &HtmlOptions,
+) where
+    I: IntoIterator<Item = (Sty, Tok)>,
+    Sty: AsStyle,
+    Tok: AsRef<str>,
+{
+    // The style of the `<span>` which is still open, when coalescing
+    let mut open: Option<Style> = None;
+
     for (sty, tok) in tokens {
-        let style = match sty.as_style() {
-            Some(style) => style,
-            None => {
-                for part in escape_html(tok.as_ref()) {
-                    html.push_str(part);
-                }
+        let tok = tok.as_ref();
+        let style = sty.as_style();
+
+        if options.coalesce {
+            if tok.is_empty() {
                 continue;
             }
+
+            if let Some(open_style) = &open {
+                let bg = style.as_ref().and_then(|style| style.bg);
+                let is_same = style.as_ref() == Some(open_style);
+                if is_same || (tok.trim().is_empty() && (bg == open_style.bg)) {
+                    push_escaped(html, tok);
+                    continue;
+                }
+
+                html.push_str("</span>");
+                open = None;
+            }
+        }
+
+        let Some(style) = style else {
+            push_escaped(html, tok);
+            continue;
         };
 
-        html.push_str("<span style=\"");
+        push_span_start(html, &style);
+        push_escaped(html, tok);
+
+        if options.coalesce {
+            open = Some(style);
+        } else {
+            html.push_str("</span>");
+        }
+    }
 
-        let style = style
-            .fg
-            .map(|col| ("color:", col))
-            .into_iter()
-            .chain(style.bg.map(|col| ("background-color:", col)));
+    if open.is_some() {
+        html.push_str("</span>");
+    }
+}
 
-        for (i, (name, col)) in style.enumerate() {
-            if i > 0 {
-                html.push(';');
-            }
+/// Pushes the start tag of a `<span>` styled by `style`.
+fn push_span_start(html: &mut String, style: &Style) {
+    html.push_str("<span style=\"");
 
-            html.push_str(name);
-
-            let Color([r, g, b, a]) = col;
-            if a == 255 {
-                html.push('#');
-                html.extend(u8_to_hex(r));
-                html.extend(u8_to_hex(g));
-                html.extend(u8_to_hex(b));
-            } else {
-                html.push_str("rgb(");
-                // TODO: unwrap
-                write!(html, "{r},{g},{b},{}", (a as f32) / 255.0).unwrap();
-                html.push(')');
+    let style = style
+        .fg
+        .map(|col| ("color:", col))
+        .into_iter()
+        .chain(style.bg.map(|col| ("background-color:", col)));
+
+    for (i, (name, col)) in style.enumerate() {
+        if i > 0 {
+            html.push(';');
+        }
+
+        html.push_str(name);
+        push_css_color(html, col);
+    }
+
+    html.push_str("\">");
+}
+
+#[inline]
+fn push_escaped(html: &mut String, text: &str) {
+    for part in escape_html(text) {
+        html.push_str(part);
+    }
+}
+
+/// Renders `tokens` as HTML, where the color of each token references
+/// a [CSS custom property], e.g. `style="color:var(--tok-keyword)"`,
+/// instead of a specific color.
+///
+/// The custom properties are defined using [`Theme::to_css_variables()`],
+/// such that e.g. light and dark themes can be switched using only CSS,
+/// without rendering the HTML again.
+///
+/// [`Token::Space`] is not styled, and neither are per-token background
+/// colors, see [`Theme::to_css_variables()`].
+///
+/// # Example
+///
+/// ```rust
+/// use colorblast::{render_html_css_variables, Lexer};
+///
+/// let tokens = Lexer::Rust.into_lexer("let x;");
+/// let html = render_html_css_variables(tokens.map(|(tok, span)| (tok, span.as_str())));
+///
+/// assert_eq!(
+///     html,
+///     concat!(
+///         "<span style=\"color:var(--tok-keyword)\">let</span> ",
+///         "<span style=\"color:var(--tok-var)\">x</span>",
+///         "<span style=\"color:var(--tok-operator)\">;</span>",
+///     )
+/// );
+/// ```
+///
+/// [CSS custom property]: https://developer.mozilla.org/en-US/docs/Web/CSS/--*
+/// [`Theme::to_css_variables()`]: crate::Theme::to_css_variables
+pub fn render_html_css_variables<Tok, I>(tokens: I) -> String
+where
+    I: IntoIterator<Item = (Token, Tok)>,
+    Tok: AsRef<str>,
+{
+    let mut html = String::new();
+    render_html_css_variables_into(&mut html, tokens);
+    html
+}
+
+/// Same as [`render_html_css_variables()`], but renders into `html`.
+pub fn render_html_css_variables_into<Tok, I>(html: &mut String, tokens: I)
+where
+    I: IntoIterator<Item = (Token, Tok)>,
+    Tok: AsRef<str>,
+{
+    for (tok, text) in tokens {
+        if tok == Token::Space {
+            for part in escape_html(text.as_ref()) {
+                html.push_str(part);
             }
+            continue;
         }
 
-        html.push_str("\">");
+        html.push_str("<span style=\"color:var(");
+        push_css_variable_name(html, tok);
+        html.push_str(")\">");
 
-        for part in escape_html(tok.as_ref()) {
+        for part in escape_html(text.as_ref()) {
             html.push_str(part);
         }
 
@@ -70,7 +249,79 @@ where
     }
 }
 
-fn escape_html(mut text: &str) -> impl Iterator<Item = &str> {
+/// Truncates `tokens` to at most `max_chars` visible characters, e.g.
+/// for rendering a preview of highlighted code using [`render_html()`].
+///
+/// As opposed to truncating the rendered HTML, the tokens are truncated
+/// before rendering, such that all tags remain closed, and no escape
+/// sequence or tag is cut in half. The token containing the last visible
+/// character is cut short, and all following tokens are dropped.
+///
+/// Characters are counted as [`char`]s, i.e. a `&` escaped as `&amp;`
+/// is a single visible character.
+///
+/// # Example
+///
+/// ```rust
+/// use colorblast::{render_html, truncate_visible, Lexer, StylizeToken};
+///
+/// let code = "let s = \"<html>\";";
+/// let tokens = Lexer::Rust
+///     .into_lexer(code)
+///     .map(|(tok, span)| (tok.style(&span), span.as_str()));
+///
+/// let html = render_html(truncate_visible(tokens, 13));
+/// assert!(html.ends_with("&quot;&lt;htm</span>"));
+/// ```
+pub fn truncate_visible<'text, Sty, I>(
+    tokens: I,
+    max_chars: usize,
+) -> impl Iterator<Item = (Sty, &'text str)>
+where
+    I: IntoIterator<Item = (Sty, &'text str)>,
+{
+    let mut remaining = max_chars;
+    tokens.into_iter().map_while(move |(sty, text)| {
+        if remaining == 0 {
+            return None;
+        }
+        match text.char_indices().nth(remaining) {
+            Some((i, _c)) => {
+                remaining = 0;
+                Some((sty, &text[..i]))
+            }
+            None => {
+                remaining -= text.chars().count();
+                Some((sty, text))
+            }
+        }
+    })
+}
+
+/// Pushes the name of the CSS custom property of `tok`,
+/// e.g. `--tok-primitive-type` for [`Token::PrimitiveType`].
+pub(crate) fn push_css_variable_name(css: &mut String, tok: Token) {
+    css.push_str("--tok-");
+    css.extend(tok.name().chars().map(|c| if c == '_' { '-' } else { c }));
+}
+
+/// Pushes `col` as a CSS color, e.g. `#FF8000`.
+pub(crate) fn push_css_color(css: &mut String, col: Color) {
+    let Color([r, g, b, a]) = col;
+    if a == 255 {
+        css.push('#');
+        css.extend(u8_to_hex(r));
+        css.extend(u8_to_hex(g));
+        css.extend(u8_to_hex(b));
+    } else {
+        css.push_str("rgb(");
+        // TODO: unwrap
+        write!(css, "{r},{g},{b},{}", (a as f32) / 255.0).unwrap();
+        css.push(')');
+    }
+}
+
+pub(crate) fn escape_html(mut text: &str) -> impl Iterator<Item = &str> {
     let mut next = None;
     iter::from_fn(move || {
         if next.is_some() {
@@ -116,3 +367,33 @@ fn u8_low_to_hex(i: u8) -> u8 {
         b'0' + low
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_truncate_visible() {
+        let red = Some(Color::from((255, 0, 0)));
+        let tokens = [(red, "<a>"), (None, " & "), (red, "ÆØÅ")];
+
+        let cases = [
+            (0, ""),
+            (2, "<span style=\"color:#FF0000\">&lt;a</span>"),
+            (3, "<span style=\"color:#FF0000\">&lt;a&gt;</span>"),
+            (5, "<span style=\"color:#FF0000\">&lt;a&gt;</span> &amp;"),
+            (
+                8,
+                "<span style=\"color:#FF0000\">&lt;a&gt;</span> &amp; <span style=\"color:#FF0000\">ÆØ</span>",
+            ),
+            (
+                100,
+                "<span style=\"color:#FF0000\">&lt;a&gt;</span> &amp; <span style=\"color:#FF0000\">ÆØÅ</span>",
+            ),
+        ];
+        for (max_chars, expected) in cases {
+            let html = render_html(truncate_visible(tokens, max_chars));
+            assert_eq!(html, expected, "{max_chars}");
+        }
+    }
+}
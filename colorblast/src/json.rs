@@ -0,0 +1,280 @@
+//! Minimal JSON parser, used for reading notebooks and themes.
+
+use std::iter::Peekable;
+
+use any_lexer::JsonToken;
+
+/// Minimal JSON value, only supporting what is needed for reading
+/// notebooks and themes.
+#[derive(Debug)]
+pub(crate) enum Value {
+    /// `null`, `true`, `false`, and numbers.
+    Other,
+    String(String),
+    Array(Vec<Value>),
+    Object(Vec<(String, Value)>),
+}
+
+impl Value {
+    pub(crate) fn get(&self, key: &str) -> Option<&Value> {
+        match self {
+            Self::Object(entries) => entries
+                .iter()
+                .find(|(k, _)| k == key)
+                .map(|(_, value)| value),
+            _ => None,
+        }
+    }
+
+    pub(crate) fn as_str(&self) -> Option<&str> {
+        match self {
+            Self::String(s) => Some(s),
+            _ => None,
+        }
+    }
+
+    pub(crate) fn as_array(&self) -> Option<&[Value]> {
+        match self {
+            Self::Array(values) => Some(values),
+            _ => None,
+        }
+    }
+
+    /// Returns the text of a multiline string, which in notebooks is
+    /// either a string or an array of strings.
+    #[cfg(feature = "notebook")]
+    pub(crate) fn to_text(&self) -> String {
+        match self {
+            Self::String(s) => s.clone(),
+            Self::Array(lines) => lines.iter().filter_map(Value::as_str).collect(),
+            _ => String::new(),
+        }
+    }
+}
+
+#[cfg(feature = "notebook")]
+pub(crate) fn parse_json(json: &str) -> Result<Value, String> {
+    let tokens = any_lexer::JsonLexer::new(json)
+        .filter(|(tok, _span)| *tok != JsonToken::Space)
+        .map(|(tok, span)| (tok, span.as_str()));
+    parse(tokens, false)
+}
+
+/// Parses [JSON with Comments], i.e. JSON which allows comments and
+/// trailing commas, e.g. as used by Visual Studio Code themes.
+///
+/// [JSON with Comments]: https://code.visualstudio.com/docs/languages/json#_json-with-comments
+#[cfg(feature = "lang-jsonc")]
+pub(crate) fn parse_jsonc(jsonc: &str) -> Result<Value, String> {
+    use any_lexer::JsonCToken;
+
+    let tokens = any_lexer::JsonCLexer::new(jsonc).filter_map(|(tok, span)| {
+        let tok = match tok {
+            JsonCToken::Space | JsonCToken::LineComment | JsonCToken::BlockComment => {
+                return None;
+            }
+            JsonCToken::String => JsonToken::String,
+            JsonCToken::Number => JsonToken::Number,
+            JsonCToken::Null => JsonToken::Null,
+            JsonCToken::True => JsonToken::True,
+            JsonCToken::False => JsonToken::False,
+            JsonCToken::Punct => JsonToken::Punct,
+            JsonCToken::Delim => JsonToken::Delim,
+            JsonCToken::Unknown => JsonToken::Unknown,
+        };
+        Some((tok, span.as_str()))
+    });
+    parse(tokens, true)
+}
+
+/// Maximum nesting depth of arrays and objects, such that deeply
+/// nested input results in an error instead of overflowing the stack.
+const MAX_DEPTH: usize = 128;
+
+/// Parses a single JSON value from `tokens`, which must not contain
+/// whitespace or comments.
+fn parse<'a>(
+    tokens: impl Iterator<Item = (JsonToken, &'a str)>,
+    trailing_commas: bool,
+) -> Result<Value, String> {
+    let mut tokens = tokens.peekable();
+    let value = parse_value(&mut tokens, trailing_commas, 0)?;
+    match tokens.next() {
+        None => Ok(value),
+        Some((_tok, s)) => Err(unexpected(s)),
+    }
+}
+
+fn parse_value<'a, I>(
+    tokens: &mut Peekable<I>,
+    trailing_commas: bool,
+    depth: usize,
+) -> Result<Value, String>
+where
+    I: Iterator<Item = (JsonToken, &'a str)>,
+{
+    let (tok, s) = tokens
+        .next()
+        .ok_or_else(|| "unexpected end of input".to_owned())?;
+    match tok {
+        JsonToken::Null | JsonToken::True | JsonToken::False | JsonToken::Number => {
+            Ok(Value::Other)
+        }
+        JsonToken::String => unescape(s).map(Value::String),
+        JsonToken::Delim if (s == "[" || s == "{") && (depth >= MAX_DEPTH) => {
+            Err(format!("exceeded maximum nesting depth of {MAX_DEPTH}"))
+        }
+        JsonToken::Delim if s == "[" => {
+            let mut values = Vec::new();
+            if next_if_eq(tokens, "]") {
+                return Ok(Value::Array(values));
+            }
+            loop {
+                values.push(parse_value(tokens, trailing_commas, depth + 1)?);
+                if next_if_eq(tokens, "]") {
+                    return Ok(Value::Array(values));
+                }
+                expect(tokens, ",")?;
+                if trailing_commas && next_if_eq(tokens, "]") {
+                    return Ok(Value::Array(values));
+                }
+            }
+        }
+        JsonToken::Delim if s == "{" => {
+            let mut entries = Vec::new();
+            if next_if_eq(tokens, "}") {
+                return Ok(Value::Object(entries));
+            }
+            loop {
+                let key = match parse_value(tokens, trailing_commas, depth + 1)? {
+                    Value::String(key) => key,
+                    _ => return Err("expected string key".to_owned()),
+                };
+                expect(tokens, ":")?;
+                entries.push((key, parse_value(tokens, trailing_commas, depth + 1)?));
+                if next_if_eq(tokens, "}") {
+                    return Ok(Value::Object(entries));
+                }
+                expect(tokens, ",")?;
+                if trailing_commas && next_if_eq(tokens, "}") {
+                    return Ok(Value::Object(entries));
+                }
+            }
+        }
+        _ => Err(unexpected(s)),
+    }
+}
+
+fn next_if_eq<'a, I>(tokens: &mut Peekable<I>, expected: &str) -> bool
+where
+    I: Iterator<Item = (JsonToken, &'a str)>,
+{
+    tokens.next_if(|&(_tok, s)| s == expected).is_some()
+}
+
+fn expect<'a, I>(tokens: &mut Peekable<I>, expected: &str) -> Result<(), String>
+where
+    I: Iterator<Item = (JsonToken, &'a str)>,
+{
+    match tokens.next() {
+        Some((_tok, s)) if s == expected => Ok(()),
+        Some((_tok, s)) => Err(unexpected(s)),
+        None => Err(format!("expected `{expected}`")),
+    }
+}
+
+fn unexpected(s: &str) -> String {
+    format!("unexpected `{s}`")
+}
+
+/// Unescapes the JSON string literal `s`, including the quotes.
+fn unescape(s: &str) -> Result<String, String> {
+    let inner = s
+        .strip_prefix('"')
+        .and_then(|s| s.strip_suffix('"'))
+        .ok_or_else(|| unexpected(s))?;
+
+    let mut unescaped = String::with_capacity(inner.len());
+    let mut chars = inner.chars();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            unescaped.push(c);
+            continue;
+        }
+
+        let c = match chars.next() {
+            Some('"') => '"',
+            Some('\\') => '\\',
+            Some('/') => '/',
+            Some('b') => '\u{8}',
+            Some('f') => '\u{C}',
+            Some('n') => '\n',
+            Some('r') => '\r',
+            Some('t') => '\t',
+            Some('u') => {
+                let high = parse_hex4(&mut chars)?;
+                if (0xD800..0xDC00).contains(&high) {
+                    // Surrogate pair, e.g. `\ud83d\ude00`
+                    if !(chars.next() == Some('\\') && chars.next() == Some('u')) {
+                        return Err("unpaired surrogate".to_owned());
+                    }
+                    let low = parse_hex4(&mut chars)?;
+                    if !(0xDC00..0xE000).contains(&low) {
+                        return Err("invalid low surrogate".to_owned());
+                    }
+                    let c = 0x10000 + ((high - 0xD800) << 10) + (low - 0xDC00);
+                    char::from_u32(c)
+                } else {
+                    char::from_u32(high)
+                }
+                .ok_or_else(|| "invalid unicode escape".to_owned())?
+            }
+            _ => return Err("invalid escape".to_owned()),
+        };
+        unescaped.push(c);
+    }
+    Ok(unescaped)
+}
+
+fn parse_hex4(chars: &mut impl Iterator<Item = char>) -> Result<u32, String> {
+    let mut n = 0;
+    for _ in 0..4 {
+        let digit = chars
+            .next()
+            .and_then(|c| c.to_digit(16))
+            .ok_or_else(|| "invalid unicode escape".to_owned())?;
+        n = (n << 4) | digit;
+    }
+    Ok(n)
+}
+
+#[cfg(all(test, feature = "notebook"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_unescape_surrogates() {
+        assert_eq!(unescape(r#""\ud83d\ude00""#), Ok("😀".to_owned()));
+
+        #[rustfmt::skip]
+        let cases = [
+            (r#""\ud800""#, "unpaired surrogate"),
+            (r#""\ud800\u0000""#, "invalid low surrogate"),
+            (r#""\ud800\ue000""#, "invalid low surrogate"),
+            (r#""\ud800\ud800""#, "invalid low surrogate"),
+        ];
+        for (s, expected) in cases {
+            assert_eq!(unescape(s), Err(expected.to_owned()), "{s}");
+        }
+    }
+
+    #[test]
+    fn test_parse_json_nesting() {
+        let nested = |depth: usize| "[".repeat(depth) + &"]".repeat(depth);
+
+        assert!(parse_json(&nested(MAX_DEPTH)).is_ok());
+        assert!(parse_json(&nested(MAX_DEPTH + 1)).is_err());
+        assert!(parse_json(&"[".repeat(200_000)).is_err());
+        assert!(parse_json(&r#"{"a":"#.repeat(200_000)).is_err());
+    }
+}
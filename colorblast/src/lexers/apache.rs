@@ -0,0 +1,53 @@
+use any_lexer::ApacheToken;
+
+use super::{impl_iter, Token, TokenSpan};
+
+/// Apache HTTP Server configuration lexer producing
+/// <code>([`Token`], [`TokenSpan`])</code> for classifying
+/// `httpd.conf` and `.htaccess` files.
+///
+/// # Warning
+///
+/// If you are about to use `ApacheLexer` for anything outside the scope of the
+/// [`colorblast` crate], then please see the warning in the [`lexers` module].
+///
+/// [`colorblast` crate]: crate
+/// [`lexers` module]: super#warning
+#[derive(Clone, Debug)]
+pub struct ApacheLexer<'code> {
+    lexer: any_lexer::ApacheLexer<'code>,
+}
+
+impl<'code> ApacheLexer<'code> {
+    #[inline]
+    pub fn new(code: &'code str) -> Self {
+        Self {
+            lexer: any_lexer::ApacheLexer::new(code),
+        }
+    }
+
+    fn next_token(&mut self) -> Option<(Token, TokenSpan<'code>)> {
+        let (tok, span) = self.lexer.next()?;
+        let tok = match tok {
+            ApacheToken::Space => Token::Space,
+            ApacheToken::LineComment => Token::Comment,
+            // Directive names are case-insensitive, but are conventionally
+            // CamelCase, e.g. `ServerName`, which avoids highlighting the
+            // first word of every line of arbitrary text
+            ApacheToken::Directive if span.as_str().starts_with(char::is_uppercase) => {
+                Token::Keyword
+            }
+            ApacheToken::Directive => Token::Text,
+            ApacheToken::SectionOpen | ApacheToken::SectionClose => Token::Keyword2,
+            ApacheToken::Word => Token::Text,
+            ApacheToken::Number => Token::Number,
+            ApacheToken::Var => Token::Var,
+            ApacheToken::String => Token::String,
+            ApacheToken::Continuation => Token::Operator,
+            ApacheToken::Unknown => Token::Invalid,
+        };
+        Some((tok, span))
+    }
+}
+
+impl_iter!('code, ApacheLexer<'code>);
@@ -0,0 +1,89 @@
+use any_lexer::CMakeToken;
+
+use super::{impl_iter, LexerExt, Token, TokenSpan};
+
+const COMMANDS_CONTROL_FLOW: &[&str] = &[
+    "block",
+    "break",
+    "continue",
+    "else",
+    "elseif",
+    "endblock",
+    "endforeach",
+    "endfunction",
+    "endif",
+    "endmacro",
+    "endwhile",
+    "foreach",
+    "function",
+    "if",
+    "macro",
+    "return",
+    "while",
+];
+
+/// CMake lexer producing <code>([`Token`], [`TokenSpan`])</code>
+/// for classifying CMake code.
+///
+/// # Warning
+///
+/// If you are about to use `CMakeLexer` for anything outside the scope of the
+/// [`colorblast` crate], then please see the warning in the [`lexers` module].
+///
+/// [`colorblast` crate]: crate
+/// [`lexers` module]: super#warning
+#[derive(Clone, Debug)]
+pub struct CMakeLexer<'code> {
+    lexer: any_lexer::CMakeLexer<'code>,
+}
+
+impl<'code> CMakeLexer<'code> {
+    #[inline]
+    pub fn new(code: &'code str) -> Self {
+        Self {
+            lexer: any_lexer::CMakeLexer::new(code),
+        }
+    }
+
+    fn next_token(&mut self) -> Option<(Token, TokenSpan<'code>)> {
+        let (tok, span) = self.lexer.next()?;
+        let tok = match tok {
+            CMakeToken::Space => Token::Space,
+            CMakeToken::LineComment | CMakeToken::BracketComment => Token::Comment,
+            CMakeToken::Ident => {
+                let next_token = self.lexer.peek_find_token(|tok| {
+                    !matches!(
+                        tok,
+                        CMakeToken::Space | CMakeToken::LineComment | CMakeToken::BracketComment
+                    )
+                });
+                match next_token {
+                    // Command names are case-insensitive, e.g. `IF()`
+                    Some((CMakeToken::Delim, next)) if next.as_str() == "(" => {
+                        let is_control_flow = COMMANDS_CONTROL_FLOW
+                            .iter()
+                            .any(|cmd| cmd.eq_ignore_ascii_case(span.as_str()));
+                        if is_control_flow {
+                            Token::Keyword2
+                        } else {
+                            Token::Var2
+                        }
+                    }
+                    // Uppercase arguments are commonly keywords,
+                    // e.g. `PRIVATE` and `VERSION`
+                    _ if !span.as_str().contains(char::is_lowercase) => Token::Keyword,
+                    _ => Token::Text,
+                }
+            }
+            CMakeToken::VarRef => Token::Var,
+            CMakeToken::QuotedArgument | CMakeToken::BracketArgument => Token::String,
+            CMakeToken::Number => Token::Number,
+            CMakeToken::UnquotedArgument => Token::Text,
+            CMakeToken::Delim => Token::Delimiter,
+            CMakeToken::Unknown => Token::Invalid,
+        };
+        Some((tok, span))
+    }
+}
+
+impl_iter!('code, CMakeLexer<'code>);
@@ -0,0 +1,45 @@
+use any_lexer::CrontabToken;
+
+use super::{impl_iter, Token, TokenSpan};
+
+/// Crontab lexer producing <code>([`Token`], [`TokenSpan`])</code>
+/// for classifying crontab files.
+///
+/// # Warning
+///
+/// If you are about to use `CrontabLexer` for anything outside the scope of the
+/// [`colorblast` crate], then please see the warning in the [`lexers` module].
+///
+/// [`colorblast` crate]: crate
+/// [`lexers` module]: super#warning
+#[derive(Clone, Debug)]
+pub struct CrontabLexer<'code> {
+    lexer: any_lexer::CrontabLexer<'code>,
+}
+
+impl<'code> CrontabLexer<'code> {
+    #[inline]
+    pub fn new(code: &'code str) -> Self {
+        Self {
+            lexer: any_lexer::CrontabLexer::new(code),
+        }
+    }
+
+    fn next_token(&mut self) -> Option<(Token, TokenSpan<'code>)> {
+        let (tok, span) = self.lexer.next()?;
+        let tok = match tok {
+            CrontabToken::Space => Token::Space,
+            CrontabToken::LineComment => Token::Comment,
+            CrontabToken::Field => Token::Number,
+            CrontabToken::Nickname => Token::Keyword,
+            CrontabToken::Command => Token::Text,
+            CrontabToken::EnvName => Token::Var,
+            CrontabToken::Equals => Token::Operator,
+            CrontabToken::EnvValue => Token::String,
+            CrontabToken::Unknown => Token::Invalid,
+        };
+        Some((tok, span))
+    }
+}
+
+impl_iter!('code, CrontabLexer<'code>);
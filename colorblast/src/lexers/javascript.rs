@@ -0,0 +1,154 @@
+use any_lexer::JavaScriptToken;
+
+use super::{impl_iter, LexerExt, Token, TokenSpan};
+
+const KEYWORDS_CONTROL_FLOW: &[&str] = &[
+    "await", "break", "case", "catch", "continue", "default", "do", "else", "finally", "for", "if",
+    "return", "switch", "throw", "try", "while", "yield",
+];
+
+/// JavaScript lexer producing <code>([`Token`], [`TokenSpan`])</code>
+/// for classifying JavaScript, including [JSX].
+///
+/// # Warning
+///
+/// If you are about to use `JavaScriptLexer` for anything outside the scope
+/// of the [`colorblast` crate], then please see the warning in the
+/// [`lexers` module].
+///
+/// [`colorblast` crate]: crate
+/// [`lexers` module]: super#warning
+/// [JSX]: https://facebook.github.io/jsx/
+#[derive(Clone, Debug)]
+pub struct JavaScriptLexer<'js> {
+    lexer: any_lexer::JavaScriptLexer<'js>,
+}
+
+impl<'js> JavaScriptLexer<'js> {
+    #[inline]
+    pub fn new(js: &'js str) -> Self {
+        Self {
+            lexer: any_lexer::JavaScriptLexer::new(js),
+        }
+    }
+
+    #[inline]
+    fn next_token(&mut self) -> Option<(Token, TokenSpan<'js>)> {
+        next_token(&mut self.lexer)
+    }
+}
+
+impl_iter!('js, JavaScriptLexer<'js>);
+
+/// TypeScript lexer producing <code>([`Token`], [`TokenSpan`])</code>
+/// for classifying TypeScript.
+///
+/// **Note:** As type assertions, e.g. `<T>x`, are ambiguous with JSX, then
+/// JSX is not tokenized. Use [`TsxLexer`] for TypeScript with JSX.
+///
+/// # Warning
+///
+/// If you are about to use `TypeScriptLexer` for anything outside the scope
+/// of the [`colorblast` crate], then please see the warning in the
+/// [`lexers` module].
+///
+/// [`colorblast` crate]: crate
+/// [`lexers` module]: super#warning
+#[derive(Clone, Debug)]
+pub struct TypeScriptLexer<'ts> {
+    lexer: any_lexer::JavaScriptLexer<'ts>,
+}
+
+impl<'ts> TypeScriptLexer<'ts> {
+    #[inline]
+    pub fn new(ts: &'ts str) -> Self {
+        Self {
+            lexer: any_lexer::JavaScriptLexer::new(ts)
+                .jsx(false)
+                .typescript(true),
+        }
+    }
+
+    #[inline]
+    fn next_token(&mut self) -> Option<(Token, TokenSpan<'ts>)> {
+        next_token(&mut self.lexer)
+    }
+}
+
+impl_iter!('ts, TypeScriptLexer<'ts>);
+
+/// TSX lexer producing <code>([`Token`], [`TokenSpan`])</code>
+/// for classifying TypeScript, including [JSX].
+///
+/// # Warning
+///
+/// If you are about to use `TsxLexer` for anything outside the scope of the
+/// [`colorblast` crate], then please see the warning in the [`lexers` module].
+///
+/// [`colorblast` crate]: crate
+/// [`lexers` module]: super#warning
+/// [JSX]: https://facebook.github.io/jsx/
+#[derive(Clone, Debug)]
+pub struct TsxLexer<'tsx> {
+    lexer: any_lexer::JavaScriptLexer<'tsx>,
+}
+
+impl<'tsx> TsxLexer<'tsx> {
+    #[inline]
+    pub fn new(tsx: &'tsx str) -> Self {
+        Self {
+            lexer: any_lexer::JavaScriptLexer::new(tsx).typescript(true),
+        }
+    }
+
+    #[inline]
+    fn next_token(&mut self) -> Option<(Token, TokenSpan<'tsx>)> {
+        next_token(&mut self.lexer)
+    }
+}
+
+impl_iter!('tsx, TsxLexer<'tsx>);
+
+fn next_token<'text>(
+    lexer: &mut any_lexer::JavaScriptLexer<'text>,
+) -> Option<(Token, TokenSpan<'text>)> {
+    let (tok, span) = lexer.next()?;
+    let tok = match tok {
+        JavaScriptToken::Space => Token::Space,
+        JavaScriptToken::LineComment | JavaScriptToken::BlockComment => Token::Comment,
+        JavaScriptToken::Ident => {
+            let next_token = lexer.peek_find_token(|tok| {
+                !matches!(
+                    tok,
+                    JavaScriptToken::Space
+                        | JavaScriptToken::LineComment
+                        | JavaScriptToken::BlockComment
+                )
+            });
+            match next_token {
+                Some((JavaScriptToken::Delim, next)) if next.as_str() == "(" => Token::Var2,
+                _ if span.as_str().starts_with(char::is_uppercase) => Token::Var3,
+                _ => Token::Var,
+            }
+        }
+        JavaScriptToken::Keyword if KEYWORDS_CONTROL_FLOW.contains(&span.as_str()) => {
+            Token::Keyword2
+        }
+        JavaScriptToken::Keyword => Token::Keyword,
+        JavaScriptToken::Decorator => Token::Meta,
+        JavaScriptToken::Number => Token::Number,
+        JavaScriptToken::String | JavaScriptToken::TemplateString | JavaScriptToken::Regex => {
+            Token::String
+        }
+        JavaScriptToken::Delim => Token::Delimiter,
+        JavaScriptToken::Punct => Token::Operator,
+        JavaScriptToken::JsxTagName => Token::Keyword,
+        JavaScriptToken::JsxAttrName => Token::Var,
+        JavaScriptToken::JsxText => Token::Text,
+        JavaScriptToken::JsxPunct if span.as_str() == "=" => Token::Operator,
+        JavaScriptToken::JsxPunct => Token::Delimiter,
+        JavaScriptToken::JsxBrace => Token::Meta,
+        JavaScriptToken::Unknown => Token::Invalid,
+    };
+    Some((tok, span))
+}
@@ -4,8 +4,9 @@ use super::{impl_iter, LexerExt, Token, TokenSpan};
 /// for classifying JSON.
 ///
 /// **Note:** Cloning `JsonLexer` is essentially a copy, as it just contains
-/// a `&str` and a `usize` for its `cursor`. However, `Copy` is not
-/// implemented, to avoid accidentally copying immutable `JsonLexer`s.
+/// a `&str`, a `usize` for its `cursor`, and whether it is `loose`.
+/// However, `Copy` is not implemented, to avoid accidentally copying
+/// immutable `JsonLexer`s.
 ///
 /// # Warning
 ///
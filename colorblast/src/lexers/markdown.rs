@@ -0,0 +1,118 @@
+use std::collections::VecDeque;
+
+use any_lexer::MarkdownToken;
+
+use super::{impl_iter, Lexer, Token, TokenSpan};
+
+/// Markdown lexer producing <code>([`Token`], [`TokenSpan`])</code>
+/// for classifying Markdown.
+///
+/// The contents of fenced code blocks are tokenized by the [`Lexer`]
+/// matching the info string, see [`Lexer::from_markdown_tag()`].
+/// If the info string is empty or unknown, then the contents
+/// are classified as [`Token::String`].
+///
+/// # Warning
+///
+/// If you are about to use `MarkdownLexer` for anything outside the scope
+/// of the [`colorblast` crate], then please see the warning in the
+/// [`lexers` module].
+///
+/// [`colorblast` crate]: crate
+/// [`lexers` module]: super#warning
+#[derive(Clone, Debug)]
+pub struct MarkdownLexer<'md> {
+    text: &'md str,
+    lexer: any_lexer::MarkdownLexer<'md>,
+    /// Tokens produced by splitting code blocks.
+    pending: VecDeque<(Token, TokenSpan<'md>)>,
+}
+
+impl<'md> MarkdownLexer<'md> {
+    #[inline]
+    pub fn new(md: &'md str) -> Self {
+        Self {
+            text: md,
+            lexer: any_lexer::MarkdownLexer::new(md),
+            pending: VecDeque::new(),
+        }
+    }
+
+    fn next_token(&mut self) -> Option<(Token, TokenSpan<'md>)> {
+        if let Some(item) = self.pending.pop_front() {
+            return Some(item);
+        }
+
+        let (tok, span) = self.lexer.next()?;
+        let tok = match tok {
+            MarkdownToken::Space => Token::Space,
+            MarkdownToken::Heading => Token::Keyword,
+            MarkdownToken::Strong => Token::Var2,
+            MarkdownToken::Emphasis => Token::Var3,
+            MarkdownToken::InlineCode => Token::String,
+            MarkdownToken::Link | MarkdownToken::Image => Token::Var,
+            MarkdownToken::ListMarker
+            | MarkdownToken::BlockQuote
+            | MarkdownToken::ThematicBreak
+            | MarkdownToken::TableDelimiterRow => Token::Operator,
+            MarkdownToken::TablePipe => Token::Delimiter,
+            MarkdownToken::TaskMarker => Token::Keyword2,
+            MarkdownToken::Text => Token::Text,
+            MarkdownToken::CodeBlock => {
+                self.push_code_block(span);
+                return self.pending.pop_front();
+            }
+        };
+        Some((tok, span))
+    }
+
+    /// Splits a fenced code block into its fences and contents, and
+    /// delegates the contents to the lexer matching the info string.
+    fn push_code_block(&mut self, span: TokenSpan<'md>) {
+        let block = span.as_str();
+
+        let open_len = block.find('\n').unwrap_or(block.len());
+        let open = &block[..open_len];
+        let fence = open.trim_start();
+        let fence_char = fence.chars().next().unwrap_or('`');
+        let info = fence.trim_start_matches(fence_char);
+
+        let rest = &block[open_len..];
+        let close_len = match rest.rfind('\n') {
+            Some(i) if rest[(i + 1)..].trim().starts_with(fence_char) => rest.len() - (i + 1),
+            _ => 0,
+        };
+        let code = &rest[..(rest.len() - close_len)];
+
+        let start = span.start();
+        self.pending.push_back((
+            Token::Meta,
+            TokenSpan::new(self.text, start..(start + open_len)),
+        ));
+
+        let offset = start + open_len;
+        match Lexer::from_markdown_tag(info) {
+            Some(lexer) if !code.is_empty() => {
+                // Translate the spans from `code` into `text`
+                for (tok, span) in lexer.into_lexer(code) {
+                    let r = (offset + span.start())..(offset + span.end());
+                    self.pending.push_back((tok, TokenSpan::new(self.text, r)));
+                }
+            }
+            _ if !code.is_empty() => {
+                let r = offset..(offset + code.len());
+                self.pending
+                    .push_back((Token::String, TokenSpan::new(self.text, r)));
+            }
+            _ => {}
+        }
+
+        if close_len > 0 {
+            let r = (span.end() - close_len)..span.end();
+            self.pending
+                .push_back((Token::Meta, TokenSpan::new(self.text, r)));
+        }
+    }
+}
+
+impl_iter!('md, MarkdownLexer<'md>);
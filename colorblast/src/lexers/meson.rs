@@ -0,0 +1,71 @@
+use any_lexer::MesonToken;
+
+use super::{impl_iter, LexerExt, Token, TokenSpan};
+
+const KEYWORDS_CONTROL_FLOW: &[&str] = &[
+    "break",
+    "continue",
+    "elif",
+    "else",
+    "endforeach",
+    "endif",
+    "endtestcase",
+    "foreach",
+    "if",
+    "testcase",
+];
+
+/// Meson lexer producing <code>([`Token`], [`TokenSpan`])</code>
+/// for classifying Meson build definitions.
+///
+/// # Warning
+///
+/// If you are about to use `MesonLexer` for anything outside the scope of the
+/// [`colorblast` crate], then please see the warning in the [`lexers` module].
+///
+/// [`colorblast` crate]: crate
+/// [`lexers` module]: super#warning
+#[derive(Clone, Debug)]
+pub struct MesonLexer<'code> {
+    lexer: any_lexer::MesonLexer<'code>,
+}
+
+impl<'code> MesonLexer<'code> {
+    #[inline]
+    pub fn new(code: &'code str) -> Self {
+        Self {
+            lexer: any_lexer::MesonLexer::new(code),
+        }
+    }
+
+    fn next_token(&mut self) -> Option<(Token, TokenSpan<'code>)> {
+        let (tok, span) = self.lexer.next()?;
+        let tok = match tok {
+            MesonToken::Space => Token::Space,
+            MesonToken::LineComment => Token::Comment,
+            MesonToken::Ident => {
+                let next_token = self.lexer.peek_find_token(|tok| {
+                    !matches!(tok, MesonToken::Space | MesonToken::LineComment)
+                });
+                match next_token {
+                    Some((MesonToken::Delim, next)) if next.as_str() == "(" => Token::Var2,
+                    // Keyword arguments, e.g. `version : '1.0'`
+                    Some((MesonToken::Punct, next)) if next.as_str() == ":" => Token::Var3,
+                    _ => Token::Var,
+                }
+            }
+            MesonToken::Keyword if KEYWORDS_CONTROL_FLOW.contains(&span.as_str()) => {
+                Token::Keyword2
+            }
+            MesonToken::Keyword => Token::Keyword,
+            MesonToken::String => Token::String,
+            MesonToken::Int => Token::Number,
+            MesonToken::Delim => Token::Delimiter,
+            MesonToken::Punct => Token::Operator,
+            MesonToken::Unknown => Token::Invalid,
+        };
+        Some((tok, span))
+    }
+}
+
+impl_iter!('code, MesonLexer<'code>);
@@ -10,26 +10,102 @@
 //! [`colorblast::lexers`]: self
 
 pub mod prelude {
-    pub use super::Lexer;
+    pub use super::{Lexer, LexerInfo};
 }
 
+#[cfg(feature = "lang-apache")]
+mod apache;
+#[cfg(feature = "lang-cmake")]
+mod cmake;
+#[cfg(feature = "lang-crontab")]
+mod crontab;
+#[cfg(any(
+    feature = "lang-javascript",
+    feature = "lang-typescript",
+    feature = "lang-html",
+    feature = "lang-vue",
+    feature = "lang-svelte"
+))]
+mod javascript;
+#[cfg(feature = "lang-json")]
 mod json;
+#[cfg(feature = "lang-jsonc")]
 mod jsonc;
+#[cfg(feature = "lang-markdown")]
+mod markdown;
+#[cfg(feature = "lang-meson")]
+mod meson;
+#[cfg(feature = "lang-nginx")]
+mod nginx;
+#[cfg(feature = "lang-python")]
+mod python;
+#[cfg(feature = "lang-rust")]
 mod rust;
+#[cfg(any(feature = "lang-html", feature = "lang-vue", feature = "lang-svelte"))]
+mod sfc;
+#[cfg(feature = "lang-systemd")]
+mod systemd;
+#[cfg(any(feature = "lang-jinja", feature = "lang-handlebars"))]
+mod template;
 mod text;
+#[cfg(feature = "lang-toml")]
+mod toml;
+#[cfg(feature = "lang-xml")]
+mod xml;
 
+#[cfg(feature = "lang-apache")]
+pub use self::apache::*;
+#[cfg(feature = "lang-cmake")]
+pub use self::cmake::*;
+#[cfg(feature = "lang-crontab")]
+pub use self::crontab::*;
+#[cfg(any(
+    feature = "lang-javascript",
+    feature = "lang-typescript",
+    feature = "lang-html",
+    feature = "lang-vue",
+    feature = "lang-svelte"
+))]
+pub use self::javascript::*;
+#[cfg(feature = "lang-json")]
 pub use self::json::*;
+#[cfg(feature = "lang-jsonc")]
 pub use self::jsonc::*;
+#[cfg(feature = "lang-markdown")]
+pub use self::markdown::*;
+#[cfg(feature = "lang-meson")]
+pub use self::meson::*;
+#[cfg(feature = "lang-nginx")]
+pub use self::nginx::*;
+#[cfg(feature = "lang-python")]
+pub use self::python::*;
+#[cfg(feature = "lang-rust")]
 pub use self::rust::*;
+#[cfg(any(feature = "lang-html", feature = "lang-vue", feature = "lang-svelte"))]
+pub use self::sfc::*;
+#[cfg(feature = "lang-systemd")]
+pub use self::systemd::*;
+#[cfg(any(feature = "lang-jinja", feature = "lang-handlebars"))]
+pub use self::template::*;
 pub use self::text::*;
+#[cfg(feature = "lang-toml")]
+pub use self::toml::*;
+#[cfg(feature = "lang-xml")]
+pub use self::xml::*;
 
-use crate::{IntoSimpleToken, SimpleTokenIter, Token, TokenSpan};
+use crate::{Error, Token, TokenSpan};
 
 macro_rules! impl_enum_lexer {
     (
         $(
             $(#[$attr:meta])*
-            $name:ident => $lexer:ident
+            $name:ident => $lexer:ident {
+                $(feature: $feature:literal,)?
+                name: $display_name:literal,
+                aliases: [$($alias:literal),* $(,)?],
+                extensions: [$($ext:literal),* $(,)?],
+                mime_types: [$($mime:literal),* $(,)?] $(,)?
+            }
         ),+ $(,)?
     ) => {
         #[derive(PartialEq, Eq, Clone, Copy, Debug)]
@@ -37,13 +113,17 @@ macro_rules! impl_enum_lexer {
         pub enum Lexer {
             $(
                 $(#[$attr])*
+                $(#[cfg(feature = $feature)])?
                 $name
             ),+
         }
 
         impl Lexer {
             pub const VARIANTS: &[Self] = &[
-                $(Self::$name),+
+                $(
+                    $(#[cfg(feature = $feature)])?
+                    Self::$name
+                ),+
             ];
 
             pub fn into_lexer<'text>(
@@ -51,27 +131,421 @@ macro_rules! impl_enum_lexer {
                 text: &'text str,
             ) -> Box<dyn Iterator<Item = (Token, TokenSpan<'text>)> + 'text> {
                 match self {
-                    $(Self::$name => Box::new($lexer::new(text)),)+
+                    $(
+                        $(#[cfg(feature = $feature)])?
+                        Self::$name => Box::new($lexer::new(text)),
+                    )+
+                }
+            }
+
+            /// Returns metadata about the language, e.g. for
+            /// building language pickers.
+            ///
+            /// # Example
+            ///
+            /// ```rust
+            /// use colorblast::Lexer;
+            ///
+            /// let info = Lexer::Rust.info();
+            /// assert_eq!(info.display_name, "Rust");
+            /// assert_eq!(info.extensions, ["rs"]);
+            /// assert_eq!(info.mime_types, ["text/rust"]);
+            /// ```
+            pub fn info(self) -> &'static LexerInfo {
+                match self {
+                    $(
+                        $(#[cfg(feature = $feature)])?
+                        Self::$name => &LexerInfo {
+                            display_name: $display_name,
+                            aliases: &[$($alias),*],
+                            extensions: &[$($ext),*],
+                            mime_types: &[$($mime),*],
+                        },
+                    )+
                 }
             }
         }
     };
 }
 
+/// Metadata about the language of a [`Lexer`], see [`Lexer::info()`].
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+#[non_exhaustive]
+pub struct LexerInfo {
+    /// Human readable name of the language, e.g. `"JavaScript"`.
+    pub display_name: &'static str,
+    /// Names of the language matched by [`Lexer::by_name()`] and
+    /// [`Lexer::from_markdown_tag()`], e.g. `["javascript", "js"]`.
+    ///
+    /// These are the aliases used by [GitHub Linguist] and Pandoc
+    /// for code fence info strings.
+    ///
+    /// [GitHub Linguist]: https://github.com/github-linguist/linguist/blob/master/lib/linguist/languages.yml
+    pub aliases: &'static [&'static str],
+    /// File extensions without the leading `.`, e.g. `["js", "mjs"]`.
+    pub extensions: &'static [&'static str],
+    /// MIME types of the language, e.g. `["text/javascript"]`.
+    pub mime_types: &'static [&'static str],
+}
+
 impl_enum_lexer!(
+    /// [Apache HTTP Server] configuration, e.g. `httpd.conf` and
+    /// `.htaccess` files.
+    ///
+    /// [Apache HTTP Server]: https://httpd.apache.org/docs/current/configuring.html
+    Apache => ApacheLexer {
+        feature: "lang-apache",
+        name: "Apache Conf",
+        aliases: ["apache", "apacheconf", "aconf", "httpd"],
+        extensions: ["htaccess", "apacheconf"],
+        mime_types: ["text/x-apacheconf"],
+    },
+    /// [CMake] scripts, e.g. `CMakeLists.txt` files.
+    ///
+    /// [CMake]: https://cmake.org/cmake/help/latest/manual/cmake-language.7.html
+    CMake => CMakeLexer {
+        feature: "lang-cmake",
+        name: "CMake",
+        aliases: ["cmake"],
+        extensions: ["cmake"],
+        mime_types: ["text/x-cmake"],
+    },
+    /// [Crontab] files, i.e. schedules for `cron`.
+    ///
+    /// [Crontab]: https://man7.org/linux/man-pages/man5/crontab.5.html
+    Crontab => CrontabLexer {
+        feature: "lang-crontab",
+        name: "Crontab",
+        aliases: ["crontab", "cron"],
+        extensions: ["crontab", "cron"],
+        mime_types: ["text/x-crontab"],
+    },
     /// If the JSON might contain JavaScript-like comments, then
     /// use [`Lexer::JsonC`] instead, i.e. [JSON with Comments].
     ///
     /// [JSON with Comments]: https://code.visualstudio.com/docs/languages/json#_json-with-comments
-    Json => JsonLexer,
+    Json => JsonLexer {
+        feature: "lang-json",
+        name: "JSON",
+        aliases: ["json", "geojson", "jsonl", "topojson"],
+        extensions: ["json", "geojson", "topojson"],
+        mime_types: ["application/json"],
+    },
     /// [JSON with Comments].
     ///
     /// [JSON with Comments]: https://code.visualstudio.com/docs/languages/json#_json-with-comments
-    JsonC => JsonCLexer,
-    Rust => RustLexer,
-    PlainText => PlainTextLexer,
+    JsonC => JsonCLexer {
+        feature: "lang-jsonc",
+        name: "JSON with Comments",
+        aliases: ["jsonc", "json-with-comments", "json5"],
+        extensions: ["jsonc", "code-workspace"],
+        mime_types: ["application/jsonc"],
+    },
+    /// JavaScript, including [JSX].
+    ///
+    /// [JSX]: https://facebook.github.io/jsx/
+    JavaScript => JavaScriptLexer {
+        feature: "lang-javascript",
+        name: "JavaScript",
+        aliases: ["javascript", "js", "jsx", "mjs", "cjs", "node"],
+        extensions: ["js", "jsx", "mjs", "cjs"],
+        mime_types: ["text/javascript", "application/javascript"],
+    },
+    TypeScript => TypeScriptLexer {
+        feature: "lang-typescript",
+        name: "TypeScript",
+        aliases: ["typescript", "ts", "mts", "cts"],
+        extensions: ["ts", "mts", "cts"],
+        mime_types: ["application/typescript"],
+    },
+    /// TypeScript, including [JSX].
+    ///
+    /// [JSX]: https://facebook.github.io/jsx/
+    Tsx => TsxLexer {
+        feature: "lang-typescript",
+        name: "TSX",
+        aliases: ["tsx"],
+        extensions: ["tsx"],
+        mime_types: ["text/tsx"],
+    },
+    /// Markdown, where fenced code blocks are tokenized by the
+    /// lexer matching the info string.
+    Markdown => MarkdownLexer {
+        feature: "lang-markdown",
+        name: "Markdown",
+        aliases: ["markdown", "md"],
+        extensions: ["md", "markdown"],
+        mime_types: ["text/markdown"],
+    },
+    /// [Meson] build definitions, i.e. `meson.build` files.
+    ///
+    /// [Meson]: https://mesonbuild.com/Syntax.html
+    Meson => MesonLexer {
+        feature: "lang-meson",
+        name: "Meson",
+        aliases: ["meson"],
+        extensions: [],
+        mime_types: ["text/x-meson"],
+    },
+    /// [nginx] configuration, e.g. `nginx.conf` files.
+    ///
+    /// [nginx]: https://nginx.org/en/docs/beginners_guide.html#conf_structure
+    Nginx => NginxLexer {
+        feature: "lang-nginx",
+        name: "Nginx",
+        aliases: ["nginx", "nginxconf", "nginx-conf"],
+        extensions: ["nginx", "nginxconf"],
+        mime_types: ["text/x-nginx-conf"],
+    },
+    Python => PythonLexer {
+        feature: "lang-python",
+        name: "Python",
+        aliases: ["python", "py", "python3", "py3"],
+        extensions: ["py", "pyi", "pyw"],
+        mime_types: ["text/x-python"],
+    },
+    Rust => RustLexer {
+        feature: "lang-rust",
+        name: "Rust",
+        aliases: ["rust", "rs"],
+        extensions: ["rs"],
+        mime_types: ["text/rust"],
+    },
+    /// [systemd] unit files, e.g. `.service` and `.timer` files.
+    ///
+    /// [systemd]: https://www.freedesktop.org/software/systemd/man/latest/systemd.unit.html
+    Systemd => SystemdLexer {
+        feature: "lang-systemd",
+        name: "systemd",
+        aliases: ["systemd"],
+        extensions: [
+            "service",
+            "socket",
+            "device",
+            "mount",
+            "automount",
+            "swap",
+            "target",
+            "path",
+            "timer",
+            "slice",
+            "scope",
+        ],
+        mime_types: ["text/x-systemd-unit"],
+    },
+    /// [TOML] files, e.g. `Cargo.toml` and `pyproject.toml` files.
+    ///
+    /// [TOML]: https://toml.io/en/v1.0.0
+    Toml => TomlLexer {
+        feature: "lang-toml",
+        name: "TOML",
+        aliases: ["toml"],
+        extensions: ["toml"],
+        mime_types: ["application/toml"],
+    },
+    /// HTML, including embedded `<script>` and `<style>` elements.
+    Html => HtmlLexer {
+        feature: "lang-html",
+        name: "HTML",
+        aliases: ["html", "htm", "xhtml"],
+        extensions: ["html", "htm", "xhtml"],
+        mime_types: ["text/html", "application/xhtml+xml"],
+    },
+    /// XML, including namespaced names, processing instructions,
+    /// and document type definitions (DTDs).
+    Xml => XmlLexer {
+        feature: "lang-xml",
+        name: "XML",
+        aliases: ["xml", "rss", "xsd", "wsdl"],
+        extensions: ["xml", "xsd", "xsl", "xslt", "svg", "rss", "atom", "plist"],
+        mime_types: ["text/xml", "application/xml"],
+    },
+    /// [Vue] single-file components.
+    ///
+    /// [Vue]: https://vuejs.org/guide/scaling-up/sfc.html
+    Vue => VueLexer {
+        feature: "lang-vue",
+        name: "Vue",
+        aliases: ["vue"],
+        extensions: ["vue"],
+        mime_types: ["text/x-vue"],
+    },
+    /// [Svelte] components.
+    ///
+    /// [Svelte]: https://svelte.dev/docs/svelte-components
+    Svelte => SvelteLexer {
+        feature: "lang-svelte",
+        name: "Svelte",
+        aliases: ["svelte"],
+        extensions: ["svelte"],
+        mime_types: ["text/x-svelte"],
+    },
+    /// [Jinja] templates, including [Twig] and Django templates,
+    /// where the surrounding text is HTML, see [`TemplateLexer`].
+    ///
+    /// [Jinja]: https://jinja.palletsprojects.com/en/3.1.x/templates/
+    /// [Twig]: https://twig.symfony.com/doc/3.x/templates.html
+    Jinja => JinjaLexer {
+        feature: "lang-jinja",
+        name: "Jinja",
+        aliases: ["jinja", "jinja2", "j2", "twig", "django", "htmldjango", "html+jinja"],
+        extensions: ["jinja", "jinja2", "j2", "twig"],
+        mime_types: ["text/x-jinja2", "text/x-twig", "text/x-django"],
+    },
+    /// [Handlebars] templates, including [Mustache] templates,
+    /// where the surrounding text is HTML, see [`TemplateLexer`].
+    ///
+    /// [Handlebars]: https://handlebarsjs.com/guide/
+    /// [Mustache]: https://mustache.github.io/mustache.5.html
+    Handlebars => HandlebarsLexer {
+        feature: "lang-handlebars",
+        name: "Handlebars",
+        aliases: ["handlebars", "hbs", "htmlbars", "mustache"],
+        extensions: ["handlebars", "hbs", "mustache"],
+        mime_types: ["text/x-handlebars-template"],
+    },
+    PlainText => PlainTextLexer {
+        name: "Plain Text",
+        aliases: ["text", "txt", "plain", "plaintext", "plain-text"],
+        extensions: ["txt", "text"],
+        mime_types: ["text/plain"],
+    },
 );
 
+impl Lexer {
+    /// Returns the human readable name of the language, e.g. `"JavaScript"`.
+    ///
+    /// Shorthand for <code>[info()].[display_name]</code>.
+    ///
+    /// [info()]: Self::info
+    /// [display_name]: LexerInfo::display_name
+    #[inline]
+    pub fn display_name(self) -> &'static str {
+        self.info().display_name
+    }
+
+    /// Returns the file extensions of the language, without the leading `.`.
+    ///
+    /// Shorthand for <code>[info()].[extensions]</code>.
+    ///
+    /// [info()]: Self::info
+    /// [extensions]: LexerInfo::extensions
+    #[inline]
+    pub fn extensions(self) -> &'static [&'static str] {
+        self.info().extensions
+    }
+
+    /// Returns the MIME types of the language.
+    ///
+    /// Shorthand for <code>[info()].[mime_types]</code>.
+    ///
+    /// [info()]: Self::info
+    /// [mime_types]: LexerInfo::mime_types
+    #[inline]
+    pub fn mime_types(self) -> &'static [&'static str] {
+        self.info().mime_types
+    }
+
+    /// Returns the [`Lexer`] for the language `name`, e.g. `rust` or `rs`
+    /// for <code>[Lexer]::[Rust]</code>. The name is matched case-insensitively.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::UnknownLanguage`] if `name` is unknown.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use colorblast::{Error, Lexer};
+    ///
+    /// assert_eq!(Lexer::by_name("Rust").ok(), Some(Lexer::Rust));
+    /// assert_eq!(Lexer::by_name("jsonc").ok(), Some(Lexer::JsonC));
+    ///
+    /// let err = Lexer::by_name("unknown").unwrap_err();
+    /// assert!(matches!(err, Error::UnknownLanguage(name) if name == "unknown"));
+    /// ```
+    ///
+    /// [Rust]: Lexer::Rust
+    pub fn by_name(name: &str) -> Result<Self, Error> {
+        Self::by_alias(name.trim()).ok_or_else(|| Error::UnknownLanguage(name.to_owned()))
+    }
+
+    /// Returns the first [`Lexer`] in [`Lexer::VARIANTS`] with the file
+    /// extension `ext`, e.g. `rs` or `.rs` for <code>[Lexer]::[Rust]</code>.
+    /// The extension is matched case-insensitively.
+    ///
+    /// Use a [`LexerRegistry`] for overriding ambiguous extensions, and for
+    /// detecting the language of file paths.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use colorblast::Lexer;
+    ///
+    /// assert_eq!(Lexer::by_extension("rs"), Some(Lexer::Rust));
+    /// assert_eq!(Lexer::by_extension(".PY"), Some(Lexer::Python));
+    /// assert_eq!(Lexer::by_extension("unknown"), None);
+    /// ```
+    ///
+    /// [Rust]: Lexer::Rust
+    /// [`LexerRegistry`]: crate::registry::LexerRegistry
+    pub fn by_extension(ext: &str) -> Option<Self> {
+        let ext = ext.strip_prefix('.').unwrap_or(ext);
+        Self::VARIANTS.iter().copied().find(|lexer| {
+            lexer
+                .extensions()
+                .iter()
+                .any(|e| e.eq_ignore_ascii_case(ext))
+        })
+    }
+
+    /// Returns the [`Lexer`] matching a Markdown code fence info string,
+    /// e.g. `rust` or `rs` for <code>[Lexer]::[Rust]</code>.
+    ///
+    /// The tag is matched case-insensitively against the aliases used by
+    /// GitHub and Pandoc. Only the first word of `tag` is considered,
+    /// and Pandoc-style attributes such as `{.rust}` are accepted.
+    ///
+    /// Returns `None` if `tag` is empty or unknown.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use colorblast::Lexer;
+    ///
+    /// assert_eq!(Lexer::from_markdown_tag("rust"), Some(Lexer::Rust));
+    /// assert_eq!(Lexer::from_markdown_tag("RS"), Some(Lexer::Rust));
+    /// assert_eq!(Lexer::from_markdown_tag("rust,ignore"), Some(Lexer::Rust));
+    /// assert_eq!(Lexer::from_markdown_tag("{.json}"), Some(Lexer::Json));
+    /// assert_eq!(Lexer::from_markdown_tag("unknown"), None);
+    /// ```
+    ///
+    /// [Rust]: Lexer::Rust
+    pub fn from_markdown_tag(tag: &str) -> Option<Self> {
+        let tag = tag.trim_start();
+        let tag = tag.strip_prefix('{').unwrap_or(tag).trim_start();
+        let tag = tag.strip_prefix('.').unwrap_or(tag);
+        let tag = tag
+            .split(|c: char| c.is_whitespace() || matches!(c, ',' | '}' | '{'))
+            .next()?;
+        if tag.is_empty() {
+            return None;
+        }
+
+        Self::by_alias(tag)
+    }
+
+    fn by_alias(alias: &str) -> Option<Self> {
+        Self::VARIANTS.iter().copied().find(|lexer| {
+            lexer
+                .info()
+                .aliases
+                .iter()
+                .any(|a| a.eq_ignore_ascii_case(alias))
+        })
+    }
+}
+
 macro_rules! impl_iter {
     ($lifetime:lifetime, $ty:ty) => {
         impl<$lifetime> Iterator for $ty {
@@ -89,20 +563,25 @@ macro_rules! impl_iter {
 
 pub(crate) use impl_iter;
 
+#[cfg(any(
+    feature = "lang-cmake",
+    feature = "lang-javascript",
+    feature = "lang-typescript",
+    feature = "lang-html",
+    feature = "lang-vue",
+    feature = "lang-svelte",
+    feature = "lang-json",
+    feature = "lang-jsonc",
+    feature = "lang-meson",
+    feature = "lang-nginx",
+    feature = "lang-python"
+))]
 trait LexerExt<'text, Tok>
 where
     Self: Clone,
     Self: Iterator<Item = (Tok, TokenSpan<'text>)>,
     Tok: Copy,
 {
-    #[inline]
-    fn peek_find<P>(&self, mut predicate: P) -> Option<Self::Item>
-    where
-        P: FnMut((Tok, &TokenSpan<'text>)) -> bool,
-    {
-        self.clone().find(|(tok, span)| predicate((*tok, span)))
-    }
-
     #[inline]
     fn peek_find_token<P>(&self, mut predicate: P) -> Option<Self::Item>
     where
@@ -112,6 +591,19 @@ where
     }
 }
 
+#[cfg(any(
+    feature = "lang-cmake",
+    feature = "lang-javascript",
+    feature = "lang-typescript",
+    feature = "lang-html",
+    feature = "lang-vue",
+    feature = "lang-svelte",
+    feature = "lang-json",
+    feature = "lang-jsonc",
+    feature = "lang-meson",
+    feature = "lang-nginx",
+    feature = "lang-python"
+))]
 impl<'text, Tok, I> LexerExt<'text, Tok> for I
 where
     I: Clone,
@@ -0,0 +1,68 @@
+use any_lexer::NginxToken;
+
+use super::{impl_iter, LexerExt, Token, TokenSpan};
+
+/// Nginx lexer producing <code>([`Token`], [`TokenSpan`])</code>
+/// for classifying nginx configuration.
+///
+/// # Warning
+///
+/// If you are about to use `NginxLexer` for anything outside the scope of the
+/// [`colorblast` crate], then please see the warning in the [`lexers` module].
+///
+/// [`colorblast` crate]: crate
+/// [`lexers` module]: super#warning
+#[derive(Clone, Debug)]
+pub struct NginxLexer<'code> {
+    lexer: any_lexer::NginxLexer<'code>,
+    /// Whether the next word is a directive name, i.e. whether
+    /// it is the first word of a statement.
+    statement_start: bool,
+}
+
+impl<'code> NginxLexer<'code> {
+    #[inline]
+    pub fn new(code: &'code str) -> Self {
+        Self {
+            lexer: any_lexer::NginxLexer::new(code),
+            statement_start: true,
+        }
+    }
+
+    fn next_token(&mut self) -> Option<(Token, TokenSpan<'code>)> {
+        let (tok, span) = self.lexer.next()?;
+        let statement_start = self.statement_start;
+        if !matches!(tok, NginxToken::Space | NginxToken::LineComment) {
+            self.statement_start = tok == NginxToken::Delim;
+        }
+
+        let tok = match tok {
+            NginxToken::Space => Token::Space,
+            NginxToken::LineComment => Token::Comment,
+            // Directive names, as opposed to e.g. `text/html` in `types { ... }`
+            NginxToken::Word if statement_start && is_directive_name(span.as_str()) => {
+                let next_delim = self.lexer.peek_find_token(|tok| tok == NginxToken::Delim);
+                match next_delim {
+                    // Block directives, e.g. `server {` and `location / {`
+                    Some((_, next)) if next.as_str() == "{" => Token::Keyword2,
+                    _ => Token::Keyword,
+                }
+            }
+            NginxToken::Word => Token::Text,
+            NginxToken::Number => Token::Number,
+            NginxToken::Var => Token::Var,
+            NginxToken::String => Token::String,
+            NginxToken::Delim => Token::Delimiter,
+            NginxToken::Unknown => Token::Invalid,
+        };
+        Some((tok, span))
+    }
+}
+
+impl_iter!('code, NginxLexer<'code>);
+
+/// Returns `true` if `s` is a valid directive name, e.g. `server_name`.
+fn is_directive_name(s: &str) -> bool {
+    s.starts_with(|c: char| c.is_ascii_alphabetic())
+        && s.chars().all(|c| c.is_ascii_alphanumeric() || (c == '_'))
+}
@@ -0,0 +1,67 @@
+use any_lexer::PythonToken;
+
+use super::{impl_iter, LexerExt, Token, TokenSpan};
+
+const KEYWORDS_CONTROL_FLOW: &[&str] = &[
+    "async", "await", "break", "continue", "elif", "else", "except", "finally", "for", "if",
+    "raise", "return", "try", "while", "with", "yield",
+];
+
+/// Python lexer producing <code>([`Token`], [`TokenSpan`])</code>
+/// for classifying Python code.
+///
+/// # Warning
+///
+/// If you are about to use `PythonLexer` for anything outside the scope of the
+/// [`colorblast` crate], then please see the warning in the [`lexers` module].
+///
+/// [`colorblast` crate]: crate
+/// [`lexers` module]: super#warning
+#[derive(Clone, Debug)]
+pub struct PythonLexer<'code> {
+    lexer: any_lexer::PythonLexer<'code>,
+}
+
+impl<'code> PythonLexer<'code> {
+    #[inline]
+    pub fn new(code: &'code str) -> Self {
+        Self {
+            lexer: any_lexer::PythonLexer::new(code),
+        }
+    }
+
+    fn next_token(&mut self) -> Option<(Token, TokenSpan<'code>)> {
+        let (tok, span) = self.lexer.next()?;
+        let tok = match tok {
+            PythonToken::Space | PythonToken::ExplicitLineJoiner => Token::Space,
+            PythonToken::LineComment => Token::Comment,
+            PythonToken::Ident => {
+                let next_token = self.lexer.peek_find_token(|tok| {
+                    !matches!(tok, PythonToken::Space | PythonToken::LineComment)
+                });
+                match next_token {
+                    Some((PythonToken::Delim, next)) if next.as_str() == "(" => Token::Var2,
+                    _ if span.as_str().starts_with(char::is_uppercase) => Token::Var3,
+                    _ => Token::Var,
+                }
+            }
+            PythonToken::Keyword if KEYWORDS_CONTROL_FLOW.contains(&span.as_str()) => {
+                Token::Keyword2
+            }
+            PythonToken::Keyword | PythonToken::SoftKeyword => Token::Keyword,
+            PythonToken::ShortString
+            | PythonToken::LongString
+            | PythonToken::ShortBytes
+            | PythonToken::LongBytes => Token::String,
+            PythonToken::Int | PythonToken::Float | PythonToken::Imaginary => Token::Number,
+            PythonToken::Ellipsis => Token::Keyword,
+            PythonToken::Delim => Token::Delimiter,
+            PythonToken::Punct if span.as_str() == "@" => Token::Meta,
+            PythonToken::Punct => Token::Operator,
+            PythonToken::Unknown => Token::Invalid,
+        };
+        Some((tok, span))
+    }
+}
+
+impl_iter!('code, PythonLexer<'code>);
@@ -1,6 +1,7 @@
 use std::mem;
 
-use super::{impl_iter, IntoSimpleToken, SimpleTokenIter, Token, TokenSpan};
+use super::{impl_iter, Token, TokenSpan};
+use crate::{IntoSimpleToken, SimpleTokenIter};
 
 const KEYWORDS_CONTROL_FLOW: &[&str] = &[
     "await", "break", "continue", "do", "else", "for", "if", "in", "loop", "match", "return",
@@ -93,7 +94,7 @@ impl<'code> RustLexer<'code> {
                 if tok == Token::Var {
                     let tok = match state {
                         RustLexerState::NextIsFnName => Token::Var2,
-                        RustLexerState::NextIsMacroName => Token::Var4,
+                        RustLexerState::NextIsMacroName => Token::Macro,
                         RustLexerState::NextIsModName => Token::Var3,
                         _ => unreachable!(),
                     };
@@ -132,7 +133,7 @@ impl<'code> RustLexer<'code> {
                 tok = Token::Keyword2;
             }
             Token::Var if PRIMITIVE_TYPES.contains(&span.as_str()) => {
-                tok = Token::Var3;
+                tok = Token::PrimitiveType;
             }
             Token::Var if VARIANTS.contains(&span.as_str()) => {
                 tok = Token::Var5;
@@ -149,13 +150,13 @@ impl<'code> RustLexer<'code> {
                         .as_str()
                         .chars()
                         .next()
-                        .map_or(false, |c| c.is_uppercase())
+                        .is_some_and(|c| c.is_uppercase())
                 {
                     tok = Token::Var3;
                 } else if let Some((_, next_span)) = self.tokens.next_non_space_simple_token_if(
                     |(tok, span)| matches!(tok, Token::Operator if span.as_str() == "!"),
                 ) {
-                    tok = Token::Var4;
+                    tok = Token::Macro;
                     span = span.join_unchecked(&next_span);
 
                     self.state = RustLexerState::NextIsMacroName;
@@ -201,3 +202,32 @@ enum RustLexerState {
     NextIsMacroName,
     NextIsModName,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rust_lexer_macros_and_primitive_types() {
+        let input =
+            "macro_rules! m {}\nfn f(x: &str) -> u32 { println !(\"{x}\"); m!(); u32::MAX }";
+
+        let tokens = RustLexer::new(input)
+            .filter(|(tok, _span)| matches!(tok, Token::Macro | Token::PrimitiveType))
+            .map(|(tok, span)| (tok, span.as_str()))
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            tokens,
+            [
+                (Token::Macro, "macro_rules!"),
+                (Token::Macro, "m"),
+                (Token::PrimitiveType, "str"),
+                (Token::PrimitiveType, "u32"),
+                (Token::Macro, "println !"),
+                (Token::Macro, "m!"),
+                (Token::PrimitiveType, "u32"),
+            ]
+        );
+    }
+}
@@ -0,0 +1,393 @@
+use std::collections::VecDeque;
+
+use any_lexer::scanner::ext::JavaScriptScannerExt;
+use any_lexer::scanner::Scanner;
+use any_lexer::{CssToken, HtmlToken, LessToken, ScssToken, StylusToken};
+
+use super::{impl_iter, JavaScriptLexer, Token, TokenSpan, TsxLexer, TypeScriptLexer};
+
+/// HTML lexer producing <code>([`Token`], [`TokenSpan`])</code>
+/// for classifying HTML.
+///
+/// The `<style>` elements are tokenized based on their `lang` attribute,
+/// i.e. `css`, `scss`, `less`, or `stylus`, defaulting to CSS. Likewise,
+/// the `<script>` elements are tokenized as JavaScript, or TypeScript if
+/// `lang` is `ts`.
+///
+/// # Warning
+///
+/// If you are about to use `HtmlLexer` for anything outside the scope of the
+/// [`colorblast` crate], then please see the warning in the [`lexers` module].
+///
+/// [`colorblast` crate]: crate
+/// [`lexers` module]: super#warning
+#[derive(Clone, Debug)]
+pub struct HtmlLexer<'html> {
+    lexer: SfcLexer<'html>,
+}
+
+impl<'html> HtmlLexer<'html> {
+    #[inline]
+    pub fn new(html: &'html str) -> Self {
+        Self {
+            lexer: SfcLexer::new(html, Flavor::Html),
+        }
+    }
+
+    #[inline]
+    fn next_token(&mut self) -> Option<(Token, TokenSpan<'html>)> {
+        self.lexer.next_token()
+    }
+}
+
+impl_iter!('html, HtmlLexer<'html>);
+
+/// [Vue] single-file component lexer producing
+/// <code>([`Token`], [`TokenSpan`])</code> for classifying Vue SFCs.
+///
+/// The `<template>` block is tokenized as HTML, including `{{ ... }}`
+/// interpolations and directives such as `v-if` and `@click`. The `<style>`
+/// block is tokenized based on its `lang` attribute, i.e. `css`, `scss`,
+/// `less`, or `stylus`. Likewise, the `<script>` block is tokenized as
+/// JavaScript, or TypeScript if `lang` is `ts`.
+///
+/// # Warning
+///
+/// If you are about to use `VueLexer` for anything outside the scope of the
+/// [`colorblast` crate], then please see the warning in the [`lexers` module].
+///
+/// [`colorblast` crate]: crate
+/// [`lexers` module]: super#warning
+/// [Vue]: https://vuejs.org/guide/scaling-up/sfc.html
+#[derive(Clone, Debug)]
+pub struct VueLexer<'vue> {
+    lexer: SfcLexer<'vue>,
+}
+
+impl<'vue> VueLexer<'vue> {
+    #[inline]
+    pub fn new(vue: &'vue str) -> Self {
+        Self {
+            lexer: SfcLexer::new(vue, Flavor::Vue),
+        }
+    }
+
+    #[inline]
+    fn next_token(&mut self) -> Option<(Token, TokenSpan<'vue>)> {
+        self.lexer.next_token()
+    }
+}
+
+impl_iter!('vue, VueLexer<'vue>);
+
+/// [Svelte] component lexer producing <code>([`Token`], [`TokenSpan`])</code>
+/// for classifying Svelte components.
+///
+/// The markup is tokenized as HTML, including `{...}` expressions, block tags
+/// such as `{#if ...}`, and directives such as `on:click`. The `<style>`
+/// block is tokenized based on its `lang` attribute, i.e. `css`, `scss`,
+/// `less`, or `stylus`. Likewise, the `<script>` block is tokenized as
+/// JavaScript, or TypeScript if `lang` is `ts`.
+///
+/// # Warning
+///
+/// If you are about to use `SvelteLexer` for anything outside the scope of the
+/// [`colorblast` crate], then please see the warning in the [`lexers` module].
+///
+/// [`colorblast` crate]: crate
+/// [`lexers` module]: super#warning
+/// [Svelte]: https://svelte.dev/docs/svelte-components
+#[derive(Clone, Debug)]
+pub struct SvelteLexer<'svelte> {
+    lexer: SfcLexer<'svelte>,
+}
+
+impl<'svelte> SvelteLexer<'svelte> {
+    #[inline]
+    pub fn new(svelte: &'svelte str) -> Self {
+        Self {
+            lexer: SfcLexer::new(svelte, Flavor::Svelte),
+        }
+    }
+
+    #[inline]
+    fn next_token(&mut self) -> Option<(Token, TokenSpan<'svelte>)> {
+        self.lexer.next_token()
+    }
+}
+
+impl_iter!('svelte, SvelteLexer<'svelte>);
+
+#[derive(Clone, Copy, Debug)]
+enum Flavor {
+    Html,
+    Vue,
+    Svelte,
+}
+
+impl Flavor {
+    /// Returns the delimiters of interpolations in text, if any.
+    fn interpolation(self) -> Option<(&'static str, &'static str)> {
+        match self {
+            Self::Html => None,
+            Self::Vue => Some(("{{", "}}")),
+            Self::Svelte => Some(("{", "}")),
+        }
+    }
+
+    fn is_directive(self, attr_name: &str) -> bool {
+        match self {
+            Self::Html => false,
+            Self::Vue => attr_name.starts_with("v-") || attr_name.starts_with([':', '@', '#']),
+            Self::Svelte => attr_name.contains(':') || attr_name.starts_with('{'),
+        }
+    }
+}
+
+/// Lexer for single-file components, which splits the `<template>`,
+/// `<script>`, and `<style>` blocks, and delegates each to the
+/// lexer matching the block and its `lang` attribute.
+#[derive(Clone, Debug)]
+struct SfcLexer<'text> {
+    text: &'text str,
+    lexer: any_lexer::HtmlLexer<'text>,
+    flavor: Flavor,
+    /// Name of the last tag.
+    tag: &'text str,
+    /// Name of the last attribute.
+    attr: &'text str,
+    /// Value of the `lang` attribute of the last tag.
+    lang: Option<&'text str>,
+    /// Tokens produced by delegated lexers or by splitting text.
+    pending: VecDeque<(Token, TokenSpan<'text>)>,
+}
+
+impl<'text> SfcLexer<'text> {
+    fn new(text: &'text str, flavor: Flavor) -> Self {
+        Self {
+            text,
+            lexer: any_lexer::HtmlLexer::new(text),
+            flavor,
+            tag: "",
+            attr: "",
+            lang: None,
+            pending: VecDeque::new(),
+        }
+    }
+
+    fn next_token(&mut self) -> Option<(Token, TokenSpan<'text>)> {
+        if let Some(item) = self.pending.pop_front() {
+            return Some(item);
+        }
+
+        let (tok, span) = self.lexer.next()?;
+        let tok = match tok {
+            HtmlToken::Space => Token::Space,
+            HtmlToken::Comment => Token::Comment,
+            HtmlToken::Doctype => Token::Meta,
+            HtmlToken::Cdata => Token::String,
+            HtmlToken::TagName => {
+                self.tag = span.as_str();
+                self.lang = None;
+                Token::Keyword
+            }
+            HtmlToken::AttrName => {
+                self.attr = span.as_str();
+                if self.flavor.is_directive(self.attr) {
+                    Token::Keyword2
+                } else {
+                    Token::Var
+                }
+            }
+            HtmlToken::AttrValue => {
+                if self.attr.eq_ignore_ascii_case("lang") {
+                    self.lang = Some(span.as_str().trim_matches(['"', '\'']));
+                }
+                Token::String
+            }
+            HtmlToken::Text => {
+                self.push_text(span);
+                return self.pending.pop_front();
+            }
+            HtmlToken::Entity => Token::Var4,
+            HtmlToken::RawText => {
+                self.push_raw_text(span);
+                return self.pending.pop_front();
+            }
+            HtmlToken::Punct if span.as_str() == "=" => Token::Operator,
+            HtmlToken::Punct => Token::Delimiter,
+            HtmlToken::Unknown => Token::Invalid,
+        };
+        Some((tok, span))
+    }
+
+    /// Splits text into [`Token::Text`] and interpolations.
+    fn push_text(&mut self, span: TokenSpan<'text>) {
+        let Some((open, close)) = self.flavor.interpolation() else {
+            self.pending.push_back((Token::Text, span));
+            return;
+        };
+
+        let mut start = span.start();
+        while start < span.end() {
+            let s = &self.text[start..span.end()];
+
+            let (text_len, interpolation_len) = match s.find(open) {
+                Some(i) => {
+                    // Braces and strings within the expression are skipped,
+                    // e.g. `{JSON.stringify({ a: "}" })}`
+                    let mut scanner = Scanner::new(&s[i..]);
+                    let r = match scanner.scan_interpolation(open, close, &[('{', '}')], |s| {
+                        s.scan_javascript_string()
+                            .or_else(|_| s.scan_javascript_template_string())
+                    }) {
+                        Ok((r, _s)) | Err((r, _s)) => r,
+                    };
+                    (i, r.len())
+                }
+                None => (s.len(), 0),
+            };
+
+            if text_len > 0 {
+                let span = TokenSpan::new(self.text, start..(start + text_len));
+                self.pending.push_back((Token::Text, span));
+                start += text_len;
+            }
+
+            if interpolation_len > 0 {
+                let r = start..(start + interpolation_len);
+                let inner = &self.text[r.clone()][open.len()..];
+
+                // Svelte block tags, e.g. `{#if ...}`, `{:else}`, `{/if}`, and `{@html ...}`
+                let is_block = matches!(self.flavor, Flavor::Svelte)
+                    && inner.starts_with(['#', ':', '/', '@']);
+                let tok = if is_block {
+                    Token::Keyword2
+                } else {
+                    Token::Var
+                };
+
+                self.pending.push_back((tok, TokenSpan::new(self.text, r)));
+                start += interpolation_len;
+            }
+        }
+    }
+
+    /// Delegates the contents of `<script>` and `<style>` blocks
+    /// to the lexer matching the `lang` attribute.
+    fn push_raw_text(&mut self, span: TokenSpan<'text>) {
+        let code = span.as_str();
+        let lang = self.lang.map(str::to_ascii_lowercase);
+
+        let tokens: Box<dyn Iterator<Item = (Token, TokenSpan<'text>)>> = if self
+            .tag
+            .eq_ignore_ascii_case("script")
+        {
+            match lang.as_deref() {
+                Some("ts" | "typescript") => Box::new(TypeScriptLexer::new(code)),
+                Some("tsx") => Box::new(TsxLexer::new(code)),
+                _ => Box::new(JavaScriptLexer::new(code)),
+            }
+        } else if self.tag.eq_ignore_ascii_case("style") {
+            match lang.as_deref() {
+                Some("scss" | "sass") => {
+                    Box::new(any_lexer::ScssLexer::new(code).map(|(tok, span)| (scss(tok), span)))
+                }
+                Some("less") => {
+                    Box::new(any_lexer::LessLexer::new(code).map(|(tok, span)| (less(tok), span)))
+                }
+                Some("stylus" | "styl") => Box::new(
+                    any_lexer::StylusLexer::new(code).map(|(tok, span)| (stylus(tok), span)),
+                ),
+                _ => Box::new(any_lexer::CssLexer::new(code).map(|(tok, span)| (css(tok), span))),
+            }
+        } else {
+            self.pending.push_back((Token::Text, span));
+            return;
+        };
+
+        // Translate the spans from `code` into `text`
+        let offset = span.start();
+        for (tok, span) in tokens {
+            let r = (offset + span.start())..(offset + span.end());
+            self.pending.push_back((tok, TokenSpan::new(self.text, r)));
+        }
+    }
+}
+
+fn css(tok: CssToken) -> Token {
+    match tok {
+        CssToken::Space => Token::Space,
+        CssToken::BlockComment => Token::Comment,
+        CssToken::Ident => Token::Var,
+        CssToken::AtKeyword => Token::Keyword,
+        CssToken::Hash => Token::Var3,
+        CssToken::String => Token::String,
+        CssToken::Number => Token::Number,
+        CssToken::AttrSelector => Token::Var4,
+        CssToken::PseudoClass | CssToken::PseudoElement => Token::Keyword2,
+        CssToken::Combinator => Token::Operator,
+        CssToken::Punct => Token::Operator,
+        CssToken::Delim => Token::Delimiter,
+        CssToken::Unknown => Token::Invalid,
+    }
+}
+
+fn scss(tok: ScssToken) -> Token {
+    match tok {
+        ScssToken::Space => Token::Space,
+        ScssToken::LineComment | ScssToken::BlockComment => Token::Comment,
+        ScssToken::Ident => Token::Var,
+        ScssToken::AtKeyword => Token::Keyword,
+        ScssToken::MixinName => Token::Var2,
+        ScssToken::Placeholder | ScssToken::Hash => Token::Var3,
+        ScssToken::Interpolation => Token::Var5,
+        ScssToken::String => Token::String,
+        ScssToken::Number => Token::Number,
+        ScssToken::AttrSelector => Token::Var4,
+        ScssToken::PseudoClass | ScssToken::PseudoElement => Token::Keyword2,
+        ScssToken::Combinator => Token::Operator,
+        ScssToken::Punct => Token::Operator,
+        ScssToken::Delim => Token::Delimiter,
+        ScssToken::Unknown => Token::Invalid,
+    }
+}
+
+fn less(tok: LessToken) -> Token {
+    match tok {
+        LessToken::Space => Token::Space,
+        LessToken::LineComment | LessToken::BlockComment => Token::Comment,
+        LessToken::Ident => Token::Var,
+        LessToken::Keyword => Token::Keyword2,
+        LessToken::AtKeyword => Token::Keyword,
+        LessToken::Variable => Token::Var5,
+        LessToken::Hash => Token::Var3,
+        LessToken::Escape | LessToken::String => Token::String,
+        LessToken::Number => Token::Number,
+        LessToken::AttrSelector => Token::Var4,
+        LessToken::PseudoClass | LessToken::PseudoElement => Token::Keyword2,
+        LessToken::Combinator => Token::Operator,
+        LessToken::Punct => Token::Operator,
+        LessToken::Delim => Token::Delimiter,
+        LessToken::Unknown => Token::Invalid,
+    }
+}
+
+fn stylus(tok: StylusToken) -> Token {
+    match tok {
+        StylusToken::Space | StylusToken::Indent => Token::Space,
+        StylusToken::LineComment | StylusToken::BlockComment => Token::Comment,
+        StylusToken::Ident => Token::Var,
+        StylusToken::AtKeyword => Token::Keyword,
+        StylusToken::Variable => Token::Var5,
+        StylusToken::Hash => Token::Var3,
+        StylusToken::String => Token::String,
+        StylusToken::Number => Token::Number,
+        StylusToken::AttrSelector => Token::Var4,
+        StylusToken::PseudoClass | StylusToken::PseudoElement => Token::Keyword2,
+        StylusToken::Combinator => Token::Operator,
+        StylusToken::Punct => Token::Operator,
+        StylusToken::Delim => Token::Delimiter,
+        StylusToken::Unknown => Token::Invalid,
+    }
+}
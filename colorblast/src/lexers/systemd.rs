@@ -0,0 +1,49 @@
+use any_lexer::SystemdToken;
+
+use super::{impl_iter, Token, TokenSpan};
+
+/// systemd unit file lexer producing <code>([`Token`], [`TokenSpan`])</code>
+/// for classifying e.g. `.service` and `.timer` files.
+///
+/// # Warning
+///
+/// If you are about to use `SystemdLexer` for anything outside the scope of the
+/// [`colorblast` crate], then please see the warning in the [`lexers` module].
+///
+/// [`colorblast` crate]: crate
+/// [`lexers` module]: super#warning
+#[derive(Clone, Debug)]
+pub struct SystemdLexer<'code> {
+    lexer: any_lexer::SystemdLexer<'code>,
+}
+
+impl<'code> SystemdLexer<'code> {
+    #[inline]
+    pub fn new(code: &'code str) -> Self {
+        Self {
+            lexer: any_lexer::SystemdLexer::new(code),
+        }
+    }
+
+    fn next_token(&mut self) -> Option<(Token, TokenSpan<'code>)> {
+        let (tok, span) = self.lexer.next()?;
+        let tok = match tok {
+            SystemdToken::Space => Token::Space,
+            SystemdToken::LineComment => Token::Comment,
+            SystemdToken::Section => Token::Keyword2,
+            SystemdToken::Key => Token::Keyword,
+            SystemdToken::Equals => Token::Operator,
+            SystemdToken::Value if span.as_str().chars().all(|c| c.is_ascii_digit()) => {
+                Token::Number
+            }
+            SystemdToken::Value => Token::Text,
+            SystemdToken::Specifier => Token::Var2,
+            SystemdToken::Var => Token::Var,
+            SystemdToken::Continuation => Token::Operator,
+            SystemdToken::Unknown => Token::Invalid,
+        };
+        Some((tok, span))
+    }
+}
+
+impl_iter!('code, SystemdLexer<'code>);
@@ -0,0 +1,749 @@
+use std::collections::VecDeque;
+use std::ops::Range;
+
+use any_lexer::scanner::Scanner;
+use any_lexer::HtmlToken;
+
+use super::{impl_iter, Lexer, Token, TokenSpan};
+
+// Reference: https://jinja.palletsprojects.com/en/3.1.x/templates/
+// Reference: https://twig.symfony.com/doc/3.x/templates.html
+const JINJA_KEYWORDS: &[&str] = &[
+    "and",
+    "as",
+    "context",
+    "else",
+    "false",
+    "False",
+    "from",
+    "ignore",
+    "if",
+    "import",
+    "in",
+    "is",
+    "missing",
+    "none",
+    "None",
+    "not",
+    "only",
+    "or",
+    "recursive",
+    "true",
+    "True",
+    "with",
+    "without",
+];
+
+// Reference: https://handlebarsjs.com/guide/expressions.html
+const HANDLEBARS_KEYWORDS: &[&str] = &["as", "else", "false", "null", "this", "true", "undefined"];
+
+/// Syntax of the template regions recognized by a [`TemplateLexer`].
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+#[non_exhaustive]
+pub enum TemplateSyntax {
+    /// [Jinja] and [Twig], i.e. `{{ expression }}`, `{% statement %}`,
+    /// and `{# comment #}`.
+    ///
+    /// The contents of `{% raw %}` and `{% verbatim %}` blocks are
+    /// delegated to the host lexer as is.
+    ///
+    /// [Jinja]: https://jinja.palletsprojects.com/en/3.1.x/templates/
+    /// [Twig]: https://twig.symfony.com/doc/3.x/templates.html
+    Jinja,
+    /// [Handlebars] and [Mustache], i.e. `{{ expression }}`,
+    /// `{{{ unescaped }}}`, `{{#block}}`, `{{/block}}`, `{{> partial}}`,
+    /// and `{{! comment }}`.
+    ///
+    /// [Handlebars]: https://handlebarsjs.com/guide/
+    /// [Mustache]: https://mustache.github.io/mustache.5.html
+    Handlebars,
+}
+
+/// Template lexer producing <code>([`Token`], [`TokenSpan`])</code> for
+/// classifying templates, e.g. [Jinja] and [Handlebars], see [`TemplateSyntax`].
+///
+/// Template regions, e.g. `{{ ... }}` and `{% ... %}`, are tokenized by the
+/// template lexer, while the text surrounding them is delegated to a host
+/// lexer, which is HTML by default, see [`with_host()`]. Regions take
+/// precedence over the host, e.g. regions inside HTML attribute values
+/// and comments are still recognized.
+///
+/// # Example
+///
+/// ```rust
+/// use colorblast::lexers::{TemplateLexer, TemplateSyntax};
+/// use colorblast::Token;
+///
+/// let code = "<b>{{ user.name|title }}</b>";
+/// let tokens = TemplateLexer::new(code, TemplateSyntax::Jinja)
+///     .filter(|(tok, _span)| *tok != Token::Space)
+///     .map(|(tok, span)| (tok, span.as_str()))
+///     .collect::<Vec<_>>();
+///
+/// assert_eq!(
+///     tokens,
+///     [
+///         (Token::Delimiter, "<"),
+///         (Token::Keyword, "b"),
+///         (Token::Delimiter, ">"),
+///         (Token::Meta, "{{"),
+///         (Token::Var, "user"),
+///         (Token::Delimiter, "."),
+///         (Token::Var, "name"),
+///         (Token::Operator, "|"),
+///         (Token::Var2, "title"),
+///         (Token::Meta, "}}"),
+///         (Token::Delimiter, "</"),
+///         (Token::Keyword, "b"),
+///         (Token::Delimiter, ">"),
+///     ]
+/// );
+/// ```
+///
+/// # Warning
+///
+/// If you are about to use `TemplateLexer` for anything outside the scope of
+/// the [`colorblast` crate], then please see the warning in the [`lexers` module].
+///
+/// [`with_host()`]: Self::with_host
+/// [`colorblast` crate]: crate
+/// [`lexers` module]: super#warning
+/// [Jinja]: https://jinja.palletsprojects.com/en/3.1.x/templates/
+/// [Handlebars]: https://handlebarsjs.com/guide/
+#[derive(Clone, Debug)]
+pub struct TemplateLexer<'text> {
+    text: &'text str,
+    syntax: TemplateSyntax,
+    host: Host<'text>,
+    /// End of the text already tokenized.
+    pos: usize,
+    /// Next template region at or after `pos`, if any.
+    region: Option<Range<usize>>,
+    /// Name of the tag ending the current raw block, e.g. `endraw`.
+    raw_end: Option<&'static str>,
+    /// Tokens produced by splitting host tokens and template regions.
+    pending: VecDeque<(Token, TokenSpan<'text>)>,
+}
+
+#[derive(Clone, Debug)]
+enum Host<'text> {
+    Html(any_lexer::HtmlLexer<'text>),
+    Tokens(std::vec::IntoIter<(Token, TokenSpan<'text>)>),
+}
+
+impl<'text> TemplateLexer<'text> {
+    /// Constructs a template lexer for `text`, where
+    /// the surrounding text is tokenized as HTML.
+    #[inline]
+    pub fn new(text: &'text str, syntax: TemplateSyntax) -> Self {
+        Self::with_host_lexer(text, syntax, Host::Html(any_lexer::HtmlLexer::new(text)))
+    }
+
+    /// Constructs a template lexer for `text`, where the surrounding
+    /// text is tokenized by `host`, e.g. templated YAML or SQL.
+    ///
+    /// **Note:** The host tokens are produced upfront, as opposed
+    /// to the HTML host used by [`new()`].
+    ///
+    /// [`new()`]: Self::new
+    pub fn with_host(text: &'text str, syntax: TemplateSyntax, host: Lexer) -> Self {
+        let tokens = host.into_lexer(text).collect::<Vec<_>>();
+        Self::with_host_lexer(text, syntax, Host::Tokens(tokens.into_iter()))
+    }
+
+    fn with_host_lexer(text: &'text str, syntax: TemplateSyntax, host: Host<'text>) -> Self {
+        let mut lexer = Self {
+            text,
+            syntax,
+            host,
+            pos: 0,
+            region: None,
+            raw_end: None,
+            pending: VecDeque::new(),
+        };
+        lexer.region = lexer.find_region(0);
+        lexer
+    }
+
+    /// Returns the syntax of the template regions.
+    #[inline]
+    pub fn syntax(&self) -> TemplateSyntax {
+        self.syntax
+    }
+
+    fn next_token(&mut self) -> Option<(Token, TokenSpan<'text>)> {
+        loop {
+            if let Some(item) = self.pending.pop_front() {
+                return Some(item);
+            }
+
+            let (tok, span) = match &mut self.host {
+                Host::Html(lexer) => {
+                    let (tok, span) = lexer.next()?;
+                    (html(tok, span.as_str()), span)
+                }
+                Host::Tokens(tokens) => tokens.next()?,
+            };
+            self.push_host_token(tok, span);
+        }
+    }
+
+    /// Pushes the parts of a host token, which are not covered
+    /// by template regions, along with the template regions
+    /// starting inside the host token.
+    fn push_host_token(&mut self, tok: Token, span: TokenSpan<'text>) {
+        let end = span.end();
+        let mut start = span.start().max(self.pos);
+
+        while start < end {
+            match self.region.clone() {
+                Some(region) if region.start < end => {
+                    if start < region.start {
+                        let part = TokenSpan::new(self.text, start..region.start);
+                        self.pending.push_back((tok, part));
+                    }
+
+                    start = region.end;
+                    self.pos = region.end;
+                    self.push_region(region);
+                    self.region = self.find_region(self.pos);
+                }
+                _ => {
+                    let part = TokenSpan::new(self.text, start..end)
+                        .with_unterminated(span.is_unterminated());
+                    self.pending.push_back((tok, part));
+                    start = end;
+                }
+            }
+        }
+
+        self.pos = self.pos.max(end);
+    }
+
+    /// Returns the next template region at or after `from`.
+    fn find_region(&self, from: usize) -> Option<Range<usize>> {
+        let mut from = from;
+        loop {
+            let start = from + self.text[from..].find('{')?;
+            let s = &self.text[start..];
+
+            let delims = match self.syntax {
+                TemplateSyntax::Jinja => jinja_delimiters(s),
+                TemplateSyntax::Handlebars if self.text[..start].ends_with('\\') => None,
+                TemplateSyntax::Handlebars => handlebars_delimiters(s),
+            };
+            let Some((open, close)) = delims else {
+                from = start + 1;
+                continue;
+            };
+
+            let inner = start + open.len();
+            let end = find_close(&self.text[inner..], close, !is_comment(open))
+                .map_or(self.text.len(), |i| inner + i + close.len());
+
+            // Inside raw blocks, only the tag ending the raw block is a region
+            if let Some(raw_end) = self.raw_end {
+                if jinja_tag_name(&self.text[start..end]) != Some(raw_end) {
+                    from = start + 1;
+                    continue;
+                }
+            }
+
+            return Some(start..end);
+        }
+    }
+
+    fn push_region(&mut self, region: Range<usize>) {
+        let s = &self.text[region.clone()];
+        let (open, close) = match self.syntax {
+            TemplateSyntax::Jinja => jinja_delimiters(s),
+            TemplateSyntax::Handlebars => handlebars_delimiters(s),
+        }
+        .unwrap();
+        let is_terminated = s.len() >= (open.len() + close.len()) && s.ends_with(close);
+
+        if is_comment(open) {
+            let span = TokenSpan::new(self.text, region).with_unterminated(!is_terminated);
+            self.pending.push_back((Token::Comment, span));
+            return;
+        }
+
+        if self.syntax == TemplateSyntax::Jinja {
+            let tag = jinja_tag_name(s);
+            self.raw_end = match tag {
+                Some("raw") => Some("endraw"),
+                Some("verbatim") => Some("endverbatim"),
+                _ => None,
+            };
+        }
+
+        // Whitespace control, e.g. `{%-` and `-%}` in Jinja and `{{~` in Handlebars
+        let trim = ['-', '+', '~'];
+        let open_len = open.len() + usize::from(s[open.len()..].starts_with(trim));
+        let close_len = if is_terminated {
+            let inner = &s[open_len..(s.len() - close.len())];
+            close.len() + usize::from(inner.ends_with(trim))
+        } else {
+            0
+        };
+
+        let start = region.start;
+        let inner = (start + open_len)..(region.end - close_len);
+
+        let open = TokenSpan::new(self.text, start..inner.start).with_unterminated(!is_terminated);
+        self.pending.push_back((Token::Meta, open));
+
+        match self.syntax {
+            TemplateSyntax::Jinja => self.push_jinja(inner.clone(), s.starts_with("{%")),
+            TemplateSyntax::Handlebars => self.push_handlebars(inner.clone()),
+        }
+
+        if close_len > 0 {
+            let close = TokenSpan::new(self.text, inner.end..region.end);
+            self.pending.push_back((Token::Meta, close));
+        }
+    }
+
+    /// Pushes the tokens of a Jinja expression or statement,
+    /// where `is_statement` is `true` for `{% ... %}`.
+    fn push_jinja(&mut self, inner: Range<usize>, is_statement: bool) {
+        let mut scanner = Scanner::new(&self.text[..inner.end]);
+        scanner.set_cursor_pos(inner.start);
+
+        let mut is_first = is_statement;
+        let mut prev = "";
+        while let Some((tok, r)) = scan_token(&mut scanner) {
+            let s = &self.text[r.clone()];
+            let tok = match tok {
+                Token::Var if is_first => Token::Keyword2,
+                Token::Var if JINJA_KEYWORDS.contains(&s) => Token::Keyword,
+                Token::Var if prev == "|" || scanner.remaining_text().starts_with('(') => {
+                    Token::Var2
+                }
+                tok => tok,
+            };
+            if tok != Token::Space {
+                is_first = false;
+                prev = s;
+            }
+            self.pending.push_back((tok, TokenSpan::new(self.text, r)));
+        }
+    }
+
+    /// Pushes the tokens of a Handlebars expression.
+    fn push_handlebars(&mut self, inner: Range<usize>) {
+        let mut scanner = Scanner::new(&self.text[..inner.end]);
+        scanner.set_cursor_pos(inner.start);
+
+        // Block helpers and partials, e.g. `{{#if}}`, `{{/if}}`, `{{^}}`, and `{{> name}}`
+        let sigil = scanner.skip_while(|c| matches!(c, '#' | '/' | '^' | '>' | '&' | '*'));
+        if !sigil.1.is_empty() {
+            let tok = match sigil.1 {
+                "&" => Token::Operator,
+                _ => Token::Keyword2,
+            };
+            self.pending
+                .push_back((tok, TokenSpan::new(self.text, sigil.0)));
+        }
+
+        let mut is_first = true;
+        while let Some((tok, r)) = scan_token(&mut scanner) {
+            let s = &self.text[r.clone()];
+            let tok = match tok {
+                Token::Var if s.starts_with('@') => Token::Var3,
+                Token::Var if is_first && sigil.1.contains('>') => Token::Var3,
+                Token::Var if is_first && matches!(sigil.1, "#" | "/" | "^" | "#*") => {
+                    Token::Keyword2
+                }
+                Token::Var if is_first && s == "else" => Token::Keyword2,
+                Token::Var if HANDLEBARS_KEYWORDS.contains(&s) => Token::Keyword,
+                // Helper calls, e.g. `{{loud name}}`
+                Token::Var if is_first && has_arguments(scanner.remaining_text()) => Token::Var2,
+                tok => tok,
+            };
+            if tok != Token::Space {
+                is_first = false;
+            }
+            self.pending.push_back((tok, TokenSpan::new(self.text, r)));
+        }
+    }
+}
+
+/// Scans a single token inside a template region, where all identifiers
+/// are produced as [`Token::Var`] to be classified by the caller.
+fn scan_token(scanner: &mut Scanner<'_>) -> Option<(Token, Range<usize>)> {
+    let (r, c) = scanner.peek().ok()?;
+    let start = r.start;
+
+    let tok = match c {
+        c if c.is_whitespace() => {
+            scanner.skip_whitespace();
+            Token::Space
+        }
+        '"' | '\'' => {
+            _ = scanner.next();
+            loop {
+                match scanner.next() {
+                    Ok((_r, '\\')) => _ = scanner.next(),
+                    Ok((_r, ch)) if ch == c => break,
+                    Ok(_) => {}
+                    Err(_) => {
+                        let r = start..scanner.cursor_pos();
+                        return Some((Token::String, r));
+                    }
+                }
+            }
+            Token::String
+        }
+        '0'..='9' => {
+            scanner.skip_while(|c| c.is_ascii_digit() || (c == '_'));
+            let rest = scanner.remaining_text();
+            if rest.starts_with('.') && rest[1..].starts_with(|c: char| c.is_ascii_digit()) {
+                _ = scanner.next();
+                scanner.skip_while(|c| c.is_ascii_digit() || (c == '_'));
+            }
+            Token::Number
+        }
+        c if c.is_alphabetic() || matches!(c, '_' | '@' | '$') => {
+            _ = scanner.next();
+            scanner.skip_while(|c| c.is_alphanumeric() || matches!(c, '_' | '-'));
+            Token::Var
+        }
+        '(' | ')' | '[' | ']' | '{' | '}' | ',' | '.' | ':' | ';' => {
+            _ = scanner.next();
+            Token::Delimiter
+        }
+        c if c.is_ascii_punctuation() => {
+            _ = scanner.next();
+            scanner.skip_while(|c| matches!(c, '=' | '*' | '/' | '>' | '<' | '?'));
+            Token::Operator
+        }
+        _ => {
+            _ = scanner.next();
+            Token::Invalid
+        }
+    };
+
+    Some((tok, start..scanner.cursor_pos()))
+}
+
+/// Returns `true` if `rest` of a Handlebars expression
+/// after the first identifier contains arguments.
+fn has_arguments(rest: &str) -> bool {
+    rest.starts_with(char::is_whitespace) && !rest.trim().is_empty()
+}
+
+/// Returns the delimiters of the Jinja region starting at
+/// the start of `s`, if any.
+fn jinja_delimiters(s: &str) -> Option<(&'static str, &'static str)> {
+    let delims = match s.get(..2)? {
+        "{{" => ("{{", "}}"),
+        "{%" => ("{%", "%}"),
+        "{#" => ("{#", "#}"),
+        _ => return None,
+    };
+    Some(delims)
+}
+
+/// Returns the delimiters of the Handlebars region starting at
+/// the start of `s`, if any.
+fn handlebars_delimiters(s: &str) -> Option<(&'static str, &'static str)> {
+    if !s.starts_with("{{") {
+        None
+    } else if s.starts_with("{{{") {
+        Some(("{{{", "}}}"))
+    } else if s.starts_with("{{!--") {
+        Some(("{{!--", "--}}"))
+    } else if s.starts_with("{{!") {
+        Some(("{{!", "}}"))
+    } else {
+        Some(("{{", "}}"))
+    }
+}
+
+#[inline]
+fn is_comment(open: &str) -> bool {
+    matches!(open, "{#" | "{{!" | "{{!--")
+}
+
+/// Returns the offset of `close` in `s`, where `close` is
+/// not matched inside string literals if `skip_strings`.
+fn find_close(s: &str, close: &str, skip_strings: bool) -> Option<usize> {
+    if !skip_strings {
+        return s.find(close);
+    }
+
+    let mut quote = None;
+    let mut chars = s.char_indices();
+    while let Some((i, c)) = chars.next() {
+        match quote {
+            Some(_) if c == '\\' => _ = chars.next(),
+            Some(q) if c == q => quote = None,
+            Some(_) => {}
+            None if matches!(c, '"' | '\'') => quote = Some(c),
+            None if s[i..].starts_with(close) => return Some(i),
+            None => {}
+        }
+    }
+    None
+}
+
+/// Returns the tag name of a Jinja statement, e.g. `endraw` for `{%- endraw %}`.
+fn jinja_tag_name(region: &str) -> Option<&str> {
+    let inner = region.strip_prefix("{%")?;
+    let inner = inner.trim_start_matches(['-', '+']).trim_start();
+    let len = inner
+        .find(|c: char| !(c.is_alphanumeric() || (c == '_')))
+        .unwrap_or(inner.len());
+    Some(&inner[..len])
+}
+
+fn html(tok: HtmlToken, s: &str) -> Token {
+    match tok {
+        HtmlToken::Space => Token::Space,
+        HtmlToken::Comment => Token::Comment,
+        HtmlToken::Doctype => Token::Meta,
+        HtmlToken::Cdata => Token::String,
+        HtmlToken::TagName => Token::Keyword,
+        HtmlToken::AttrName => Token::Var,
+        HtmlToken::AttrValue => Token::String,
+        HtmlToken::Text | HtmlToken::RawText => Token::Text,
+        HtmlToken::Entity => Token::Var4,
+        HtmlToken::Punct if s == "=" => Token::Operator,
+        HtmlToken::Punct => Token::Delimiter,
+        HtmlToken::Unknown => Token::Invalid,
+    }
+}
+
+/// [Jinja] template lexer producing <code>([`Token`], [`TokenSpan`])</code>,
+/// which also supports [Twig] and Django templates.
+///
+/// Shorthand for a [`TemplateLexer`] using [`TemplateSyntax::Jinja`]
+/// with an HTML host.
+///
+/// # Warning
+///
+/// If you are about to use `JinjaLexer` for anything outside the scope of the
+/// [`colorblast` crate], then please see the warning in the [`lexers` module].
+///
+/// [`colorblast` crate]: crate
+/// [`lexers` module]: super#warning
+/// [Jinja]: https://jinja.palletsprojects.com/en/3.1.x/templates/
+/// [Twig]: https://twig.symfony.com/doc/3.x/templates.html
+#[derive(Clone, Debug)]
+pub struct JinjaLexer<'text> {
+    lexer: TemplateLexer<'text>,
+}
+
+impl<'text> JinjaLexer<'text> {
+    #[inline]
+    pub fn new(text: &'text str) -> Self {
+        Self {
+            lexer: TemplateLexer::new(text, TemplateSyntax::Jinja),
+        }
+    }
+
+    #[inline]
+    fn next_token(&mut self) -> Option<(Token, TokenSpan<'text>)> {
+        self.lexer.next_token()
+    }
+}
+
+/// [Handlebars] template lexer producing <code>([`Token`], [`TokenSpan`])</code>,
+/// which also supports [Mustache] templates.
+///
+/// Shorthand for a [`TemplateLexer`] using [`TemplateSyntax::Handlebars`]
+/// with an HTML host.
+///
+/// # Warning
+///
+/// If you are about to use `HandlebarsLexer` for anything outside the scope of
+/// the [`colorblast` crate], then please see the warning in the [`lexers` module].
+///
+/// [`colorblast` crate]: crate
+/// [`lexers` module]: super#warning
+/// [Handlebars]: https://handlebarsjs.com/guide/
+/// [Mustache]: https://mustache.github.io/mustache.5.html
+#[derive(Clone, Debug)]
+pub struct HandlebarsLexer<'text> {
+    lexer: TemplateLexer<'text>,
+}
+
+impl<'text> HandlebarsLexer<'text> {
+    #[inline]
+    pub fn new(text: &'text str) -> Self {
+        Self {
+            lexer: TemplateLexer::new(text, TemplateSyntax::Handlebars),
+        }
+    }
+
+    #[inline]
+    fn next_token(&mut self) -> Option<(Token, TokenSpan<'text>)> {
+        self.lexer.next_token()
+    }
+}
+
+impl_iter!('text, TemplateLexer<'text>);
+impl_iter!('text, JinjaLexer<'text>);
+impl_iter!('text, HandlebarsLexer<'text>);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn tokens(lexer: TemplateLexer<'_>) -> Vec<(Token, &str)> {
+        lexer
+            .filter(|(tok, _span)| *tok != Token::Space)
+            .map(|(tok, span)| (tok, span.as_str()))
+            .collect()
+    }
+
+    #[test]
+    fn test_template_roundtrip() {
+        let cases = [
+            (
+                TemplateSyntax::Jinja,
+                "<a href=\"{{ url_for('x') }}\">{% if a %}b{% endif %}</a>",
+            ),
+            (TemplateSyntax::Jinja, "{# unterminated comment"),
+            (TemplateSyntax::Jinja, "<p>{{ unterminated"),
+            (TemplateSyntax::Jinja, "{% raw %}{{ x }}{% endraw %}"),
+            (TemplateSyntax::Jinja, "{{ \"}}\" }}<!-- {{ x }} -->"),
+            (
+                TemplateSyntax::Handlebars,
+                "<ul>{{#each items}}<li>{{this}}</li>{{/each}}</ul>",
+            ),
+            (
+                TemplateSyntax::Handlebars,
+                "{{!-- {{ x }} --}}{{{raw}}}\\{{escaped}}",
+            ),
+            (TemplateSyntax::Handlebars, "{{> partial}}{{"),
+        ];
+
+        for (syntax, text) in cases {
+            let lexer = TemplateLexer::new(text, syntax);
+            let mut end = 0;
+            for (_tok, span) in lexer {
+                assert_eq!(span.start(), end, "{text:?}");
+                assert!(!span.is_empty(), "{text:?}");
+                end = span.end();
+            }
+            assert_eq!(end, text.len(), "{text:?}");
+        }
+    }
+
+    #[test]
+    fn test_jinja() {
+        let text = "{%- for x in xs if x is not none -%}{{ x.y(1.5, \"%}\") }}{% endfor %}";
+        let actual = tokens(TemplateLexer::new(text, TemplateSyntax::Jinja));
+        assert_eq!(
+            actual,
+            [
+                (Token::Meta, "{%-"),
+                (Token::Keyword2, "for"),
+                (Token::Var, "x"),
+                (Token::Keyword, "in"),
+                (Token::Var, "xs"),
+                (Token::Keyword, "if"),
+                (Token::Var, "x"),
+                (Token::Keyword, "is"),
+                (Token::Keyword, "not"),
+                (Token::Keyword, "none"),
+                (Token::Meta, "-%}"),
+                (Token::Meta, "{{"),
+                (Token::Var, "x"),
+                (Token::Delimiter, "."),
+                (Token::Var2, "y"),
+                (Token::Delimiter, "("),
+                (Token::Number, "1.5"),
+                (Token::Delimiter, ","),
+                (Token::String, "\"%}\""),
+                (Token::Delimiter, ")"),
+                (Token::Meta, "}}"),
+                (Token::Meta, "{%"),
+                (Token::Keyword2, "endfor"),
+                (Token::Meta, "%}"),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_jinja_raw() {
+        let text = "{% raw %}<b>{{ x }}</b>{% endraw %}{# c #}";
+        let actual = tokens(TemplateLexer::new(text, TemplateSyntax::Jinja));
+        assert_eq!(
+            actual,
+            [
+                (Token::Meta, "{%"),
+                (Token::Keyword2, "raw"),
+                (Token::Meta, "%}"),
+                (Token::Delimiter, "<"),
+                (Token::Keyword, "b"),
+                (Token::Delimiter, ">"),
+                (Token::Text, "{{ x }}"),
+                (Token::Delimiter, "</"),
+                (Token::Keyword, "b"),
+                (Token::Delimiter, ">"),
+                (Token::Meta, "{%"),
+                (Token::Keyword2, "endraw"),
+                (Token::Meta, "%}"),
+                (Token::Comment, "{# c #}"),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_handlebars() {
+        let text =
+            "{{#each items as |item|}}{{> card title=item.title}}{{@index}}{{else}}{{/each}}";
+        let actual = tokens(TemplateLexer::new(text, TemplateSyntax::Handlebars));
+        assert_eq!(
+            actual,
+            [
+                (Token::Meta, "{{"),
+                (Token::Keyword2, "#"),
+                (Token::Keyword2, "each"),
+                (Token::Var, "items"),
+                (Token::Keyword, "as"),
+                (Token::Operator, "|"),
+                (Token::Var, "item"),
+                (Token::Operator, "|"),
+                (Token::Meta, "}}"),
+                (Token::Meta, "{{"),
+                (Token::Keyword2, ">"),
+                (Token::Var3, "card"),
+                (Token::Var, "title"),
+                (Token::Operator, "="),
+                (Token::Var, "item"),
+                (Token::Delimiter, "."),
+                (Token::Var, "title"),
+                (Token::Meta, "}}"),
+                (Token::Meta, "{{"),
+                (Token::Var3, "@index"),
+                (Token::Meta, "}}"),
+                (Token::Meta, "{{"),
+                (Token::Keyword2, "else"),
+                (Token::Meta, "}}"),
+                (Token::Meta, "{{"),
+                (Token::Keyword2, "/"),
+                (Token::Keyword2, "each"),
+                (Token::Meta, "}}"),
+            ]
+        );
+    }
+
+    #[cfg(feature = "lang-json")]
+    #[test]
+    fn test_template_host() {
+        let text = "{\"a\": {{ a | tojson }}}";
+        let actual = tokens(TemplateLexer::with_host(
+            text,
+            TemplateSyntax::Jinja,
+            Lexer::Json,
+        ));
+        assert_eq!(actual[0], (Token::Delimiter, "{"));
+        assert_eq!(actual[1], (Token::Var, "\"a\""));
+        assert!(actual.contains(&(Token::Var2, "tojson")));
+        assert_eq!(actual.last(), Some(&(Token::Delimiter, "}")));
+    }
+}
@@ -0,0 +1,47 @@
+use any_lexer::TomlToken;
+
+use super::{impl_iter, Token, TokenSpan};
+
+/// TOML lexer producing <code>([`Token`], [`TokenSpan`])</code>
+/// for classifying e.g. `Cargo.toml` and `pyproject.toml` files.
+///
+/// # Warning
+///
+/// If you are about to use `TomlLexer` for anything outside the scope of the
+/// [`colorblast` crate], then please see the warning in the [`lexers` module].
+///
+/// [`colorblast` crate]: crate
+/// [`lexers` module]: super#warning
+#[derive(Clone, Debug)]
+pub struct TomlLexer<'code> {
+    lexer: any_lexer::TomlLexer<'code>,
+}
+
+impl<'code> TomlLexer<'code> {
+    #[inline]
+    pub fn new(code: &'code str) -> Self {
+        Self {
+            lexer: any_lexer::TomlLexer::new(code),
+        }
+    }
+
+    fn next_token(&mut self) -> Option<(Token, TokenSpan<'code>)> {
+        let (tok, span) = self.lexer.next()?;
+        let tok = match tok {
+            TomlToken::Space => Token::Space,
+            TomlToken::LineComment => Token::Comment,
+            TomlToken::Table | TomlToken::ArrayTable => Token::Keyword2,
+            TomlToken::Key => Token::Var,
+            TomlToken::String => Token::String,
+            TomlToken::Integer | TomlToken::Float => Token::Number,
+            TomlToken::Boolean => Token::Keyword,
+            TomlToken::Datetime => Token::Var2,
+            TomlToken::Delim => Token::Delimiter,
+            TomlToken::Punct => Token::Operator,
+            TomlToken::Unknown => Token::Invalid,
+        };
+        Some((tok, span))
+    }
+}
+
+impl_iter!('code, TomlLexer<'code>);
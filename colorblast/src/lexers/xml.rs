@@ -0,0 +1,51 @@
+use super::{impl_iter, Token, TokenSpan};
+
+/// XML lexer producing <code>([`Token`], [`TokenSpan`])</code>
+/// for classifying XML.
+///
+/// **Note:** Cloning `XmlLexer` is essentially a copy, as it just contains
+/// a `&str`, a `usize` for its `cursor`, and its state. However, `Copy` is
+/// not implemented, to avoid accidentally copying immutable `XmlLexer`s.
+///
+/// # Warning
+///
+/// If you are about to use `XmlLexer` for anything outside the scope of the
+/// [`colorblast` crate], then please see the warning in the [`lexers` module].
+///
+/// [`colorblast` crate]: crate
+/// [`lexers` module]: super#warning
+#[derive(Clone, Debug)]
+pub struct XmlLexer<'xml> {
+    lexer: any_lexer::XmlLexer<'xml>,
+}
+
+impl<'xml> XmlLexer<'xml> {
+    #[inline]
+    pub fn new(xml: &'xml str) -> Self {
+        Self {
+            lexer: any_lexer::XmlLexer::new(xml),
+        }
+    }
+
+    fn next_token(&mut self) -> Option<(Token, TokenSpan<'xml>)> {
+        use any_lexer::XmlToken;
+        let (tok, span) = self.lexer.next()?;
+        let tok = match tok {
+            XmlToken::Space => Token::Space,
+            XmlToken::Comment => Token::Comment,
+            XmlToken::ProcessingInstruction | XmlToken::Doctype => Token::Meta,
+            XmlToken::Cdata => Token::String,
+            XmlToken::TagName => Token::Keyword,
+            XmlToken::AttrName => Token::Var,
+            XmlToken::AttrValue => Token::String,
+            XmlToken::Text => Token::Text,
+            XmlToken::Entity => Token::Var4,
+            XmlToken::Punct if span.as_str() == "=" => Token::Operator,
+            XmlToken::Punct => Token::Delimiter,
+            XmlToken::Unknown => Token::Invalid,
+        };
+        Some((tok, span))
+    }
+}
+
+impl_iter!('xml, XmlLexer<'xml>);
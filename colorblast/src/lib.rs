@@ -1,36 +1,75 @@
 //! Render syntax highlighted code into HTML or ANSI codes for the terminal.
 
-#![forbid(unsafe_code)]
+#![cfg_attr(not(feature = "plugin"), forbid(unsafe_code))]
+#![cfg_attr(feature = "plugin", deny(unsafe_code))]
 #![forbid(elided_lifetimes_in_paths)]
 
+pub mod brackets;
+pub mod detect;
+pub mod document;
+#[cfg(feature = "threadpool")]
+pub mod highlighter;
 pub mod lexers;
+pub mod lines;
+#[cfg(feature = "notebook")]
+pub mod notebook;
+#[cfg(feature = "plugin")]
+pub mod plugin;
+pub mod registry;
+pub mod search;
+pub mod stats;
+pub mod symbols;
+#[cfg(feature = "test-util")]
+pub mod test_util;
+pub mod transform;
 
 pub mod prelude {
     pub use super::lexers::prelude::*;
     pub use super::style::prelude::*;
     pub use super::token::prelude::*;
 
-    pub use super::html::{render_html, render_html_into};
+    pub use super::ansi::{
+        render_ansi, render_ansi_into, render_ansi_with_escapes, strip_ansi, terminal_width,
+        AnsiColor, AnsiOptions, ColorChoice, ColorDepth, OriginalColors, Overflow,
+    };
+    pub use super::html::{
+        render_html, render_html_css_variables, render_html_css_variables_into, render_html_into,
+        render_html_with_options, render_html_with_options_into, truncate_visible, HtmlOptions,
+    };
+    pub use super::theme::{ContrastIssue, Theme};
     pub use super::{print_code, println_code};
     pub use super::{print_styled_tokens, println_styled_tokens};
     pub use super::{print_stylized_tokens, println_stylized_tokens};
 }
 
 mod ansi;
+mod error;
 mod html;
+#[cfg(any(feature = "notebook", feature = "lang-jsonc"))]
+mod json;
 mod style;
 mod stylize;
+mod theme;
 mod token;
 
-pub use crate::html::{render_html, render_html_into};
-pub use crate::lexers::Lexer;
+pub use crate::ansi::{
+    render_ansi, render_ansi_into, render_ansi_with_escapes, strip_ansi, terminal_width, AnsiColor,
+    AnsiEscape, AnsiOptions, ColorChoice, ColorDepth, OriginalColors, Overflow, StrippedAnsi,
+};
+pub use crate::error::Error;
+pub use crate::html::{
+    render_html, render_html_css_variables, render_html_css_variables_into, render_html_into,
+    render_html_with_options, render_html_with_options_into, truncate_visible, HtmlOptions,
+};
+pub use crate::lexers::{Lexer, LexerInfo};
 pub use crate::style::*;
-pub use crate::stylize::StylizeToken;
+pub use crate::stylize::{RuledToken, StyleResolver, StyleRules, StyleRulesBuilder, StylizeToken};
+pub use crate::theme::{ContrastIssue, Theme};
 pub use crate::token::*;
 
-use std::fmt;
+pub use any_lexer::scanner::LineIndex;
 
-use crate::ansi::{AnsiCode, AnsiColor, AnsiStyle};
+use std::fmt;
 
 #[inline]
 pub fn print_code(lexer: Lexer, code: impl AsRef<str>) {
@@ -52,9 +91,10 @@ where
     I: IntoIterator<Item = (Tok, TokenSpan<'text>)>,
     Tok: StylizeToken,
 {
-    for (tok, span) in tokens {
-        print_styled_token(&span, Some(tok.style(&span)));
-    }
+    let tokens = tokens
+        .into_iter()
+        .map(|(tok, span)| (tok.style(&span), span));
+    print_styled_tokens(tokens);
 }
 
 /// Same as [`print_stylized_tokens()`] + `println!()` after.
@@ -69,6 +109,10 @@ where
 }
 
 /// Prints all styled `tokens` to the standard output.
+///
+/// Colors are only printed if stdout is a terminal and `NO_COLOR` is not set,
+/// see [`ColorChoice::Auto`]. Use [`render_ansi()`] with [`AnsiOptions`] for
+/// more control.
 #[inline]
 pub fn print_styled_tokens<Sty, Tok, I>(tokens: I)
 where
@@ -76,9 +120,13 @@ where
     Sty: AsStyle,
     Tok: fmt::Display,
 {
-    for (sty, tok) in tokens {
-        print_styled_token(tok, sty.as_style());
-    }
+    let color = if ColorChoice::Auto.should_colorize() {
+        ColorChoice::Always
+    } else {
+        ColorChoice::Never
+    };
+    let ansi = render_ansi(tokens, &AnsiOptions::new().color(color));
+    print!("{ansi}");
 }
 
 /// Same as [`print_styled_tokens()`] + `println!()` after.
@@ -92,25 +140,3 @@ where
     print_styled_tokens(tokens);
     println!();
 }
-
-#[inline]
-fn print_styled_token<Tok>(token: Tok, style: Option<Style>)
-where
-    Tok: fmt::Display,
-{
-    use AnsiCode::Reset;
-
-    let style = match style {
-        Some(style) => style,
-        None => {
-            print!("{token}");
-            return;
-        }
-    };
-
-    let fg = style.fg.map(|Color([r, g, b, _a])| AnsiColor([r, g, b]));
-    let bg = style.bg.map(|Color([r, g, b, _a])| AnsiColor([r, g, b]));
-    let style = AnsiStyle { fg, bg };
-
-    print!("{style}{token}{Reset}");
-}
@@ -0,0 +1,387 @@
+//! Per-line backgrounds on top of syntax highlighting, e.g. alternating
+//! line backgrounds and highlighting added, removed, and focused lines.
+//!
+//! Use [`render_html_lines()`] for HTML, where the background covers the
+//! whole width of each line, and [`apply_line_backgrounds()`] for other
+//! renderers, e.g. [`render_ansi()`].
+//!
+//! # Example
+//!
+//! ```rust
+//! use colorblast::lines::{render_html_lines, LineBackgrounds, LineClass};
+//! use colorblast::{Lexer, Theme};
+//!
+//! let code = "fn main() {\n    let x = 1;\n    let y = 2;\n}\n";
+//! let theme = Theme::default();
+//! let tokens = theme.highlight(Lexer::Rust, code);
+//!
+//! let backgrounds = LineBackgrounds::for_theme(&theme)
+//!     .striped()
+//!     .line(2, LineClass::Added);
+//! let html = render_html_lines(tokens.map(|(style, span)| (style, span.as_str())), &backgrounds);
+//!
+//! assert_eq!(html.matches("display:block").count(), 4);
+//! ```
+//!
+//! [`render_ansi()`]: crate::render_ansi
+
+use std::ops::Range;
+
+use crate::html::{push_css_color, render_html_into};
+use crate::{AsStyle, Color, LineIndex, Style, Theme};
+
+/// Class of a line, which has a distinct background,
+/// see [`LineBackgrounds::line()`].
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+pub enum LineClass {
+    /// Line added in a diff.
+    Added,
+    /// Line removed in a diff.
+    Removed,
+    /// Line in focus, e.g. the line being explained on a slide.
+    Focus,
+}
+
+impl LineClass {
+    const VARIANTS: [Self; 3] = [Self::Added, Self::Removed, Self::Focus];
+
+    /// Returns the color mixed into the background of the theme,
+    /// see [`LineBackgrounds::for_theme()`].
+    fn tint(self) -> Color {
+        match self {
+            Self::Added => Color::from((46, 160, 67)),
+            Self::Removed => Color::from((248, 81, 73)),
+            Self::Focus => Color::from((255, 210, 90)),
+        }
+    }
+}
+
+/// Background colors of lines, used by [`render_html_lines()`] and
+/// [`apply_line_backgrounds()`].
+///
+/// The background of a line with a [`LineClass`] takes precedence over
+/// [striping], while the background of a token takes precedence over the
+/// background of its line, e.g. for [`highlight_matches()`].
+///
+/// Lines are 0-indexed.
+///
+/// [striping]: Self::striped
+/// [`highlight_matches()`]: crate::search::highlight_matches
+#[derive(Clone, Debug)]
+pub struct LineBackgrounds {
+    /// The background color of the theme.
+    base: Color,
+    /// Background of every other line, if any.
+    stripe: Option<Color>,
+    /// Background of each [`LineClass`], in the order of `VARIANTS`.
+    colors: [Option<Color>; 3],
+    /// Lines with a class, sorted by line.
+    lines: Vec<(usize, LineClass)>,
+}
+
+impl LineBackgrounds {
+    /// Returns [`LineBackgrounds::default()`].
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns line backgrounds, where the background of each [`LineClass`]
+    /// is derived from the background color of `theme`.
+    ///
+    /// Lines are not striped by default, see [`striped()`].
+    ///
+    /// [`striped()`]: Self::striped
+    pub fn for_theme(theme: &Theme) -> Self {
+        let base = theme
+            .background_color()
+            .or_else(|| Theme::default().background_color())
+            .unwrap_or(Color::BLACK);
+        let colors = LineClass::VARIANTS.map(|class| Some(base.mix(class.tint(), 0.25)));
+        Self {
+            base,
+            stripe: None,
+            colors,
+            lines: Vec::new(),
+        }
+    }
+
+    /// Stripes every other line, i.e. lines 1, 3, 5, and so on, using a
+    /// color derived from the background color of the theme.
+    pub fn striped(self) -> Self {
+        let contrast = if self.base.relative_luminance() < 0.5 {
+            Color::WHITE
+        } else {
+            Color::BLACK
+        };
+        let stripe = self.base.mix(contrast, 0.04);
+        self.stripe(stripe)
+    }
+
+    /// Stripes every other line, i.e. lines 1, 3, 5, and so on,
+    /// using `color`.
+    #[inline]
+    pub fn stripe(mut self, color: impl Into<Color>) -> Self {
+        self.stripe = Some(color.into());
+        self
+    }
+
+    /// Sets the background of lines with the given `class`.
+    #[inline]
+    pub fn class_color(mut self, class: LineClass, color: impl Into<Color>) -> Self {
+        self.colors[class as usize] = Some(color.into());
+        self
+    }
+
+    /// Sets the class of `line`, replacing any previous class.
+    pub fn line(mut self, line: usize, class: LineClass) -> Self {
+        match self.lines.binary_search_by_key(&line, |&(l, _class)| l) {
+            Ok(i) => self.lines[i].1 = class,
+            Err(i) => self.lines.insert(i, (line, class)),
+        }
+        self
+    }
+
+    /// Sets the class of all `lines`, see [`line()`].
+    ///
+    /// [`line()`]: Self::line
+    pub fn lines(self, lines: Range<usize>, class: LineClass) -> Self {
+        lines.fold(self, |backgrounds, line| backgrounds.line(line, class))
+    }
+
+    /// Returns the class of `line`, if any.
+    pub fn class(&self, line: usize) -> Option<LineClass> {
+        self.lines
+            .binary_search_by_key(&line, |&(l, _class)| l)
+            .ok()
+            .map(|i| self.lines[i].1)
+    }
+
+    /// Returns the background color of `line`, if any.
+    pub fn background(&self, line: usize) -> Option<Color> {
+        match self.class(line) {
+            Some(class) => self.colors[class as usize],
+            None if line % 2 == 1 => self.stripe,
+            None => None,
+        }
+    }
+
+    /// Returns the line, byte range including the line ending, and background
+    /// color of each line with a background, of the text indexed by `index`.
+    ///
+    /// This is for renderers positioning lines by byte offsets or line,
+    /// e.g. image renderers, instead of rendering tokens, see
+    /// [`render_html_lines()`] and [`apply_line_backgrounds()`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use colorblast::lines::LineBackgrounds;
+    /// use colorblast::{Color, LineIndex};
+    ///
+    /// let index = LineIndex::new("a\nb\nc\nd");
+    /// let backgrounds = LineBackgrounds::new().stripe(Color::WHITE);
+    ///
+    /// let lines = backgrounds.line_ranges(&index).collect::<Vec<_>>();
+    /// assert_eq!(lines, [(1, 2..4, Color::WHITE), (3, 6..7, Color::WHITE)]);
+    /// ```
+    pub fn line_ranges<'a>(
+        &'a self,
+        index: &'a LineIndex,
+    ) -> impl Iterator<Item = (usize, Range<usize>, Color)> + 'a {
+        (0..index.line_count()).filter_map(move |line| {
+            let bg = self.background(line)?;
+            Some((line, index.line_range(line)?, bg))
+        })
+    }
+}
+
+/// Returns line backgrounds for [`Theme::default()`].
+impl Default for LineBackgrounds {
+    #[inline]
+    fn default() -> Self {
+        Self::for_theme(&Theme::default())
+    }
+}
+
+/// Renders `tokens` as HTML, see [`render_html()`], where each line is
+/// wrapped in a `<span>` with `display:block` and the background color
+/// of the line, if any.
+///
+/// As the lines are blocks, their backgrounds cover the whole width of
+/// the containing element, e.g. `<pre>`, and not just the text.
+///
+/// [`render_html()`]: crate::render_html
+pub fn render_html_lines<Sty, Tok, I>(tokens: I, backgrounds: &LineBackgrounds) -> String
+where
+    I: IntoIterator<Item = (Sty, Tok)>,
+    Sty: AsStyle,
+    Tok: AsRef<str>,
+{
+    let mut html = String::new();
+    render_html_lines_into(&mut html, tokens, backgrounds);
+    html
+}
+
+/// Same as [`render_html_lines()`], but renders into `html`.
+pub fn render_html_lines_into<Sty, Tok, I>(
+    html: &mut String,
+    tokens: I,
+    backgrounds: &LineBackgrounds,
+) where
+    I: IntoIterator<Item = (Sty, Tok)>,
+    Sty: AsStyle,
+    Tok: AsRef<str>,
+{
+    let mut line = 0;
+    let mut is_line_open = false;
+    for (sty, tok) in tokens {
+        let style = sty.as_style();
+        for part in tok.as_ref().split_inclusive('\n') {
+            if !is_line_open {
+                html.push_str("<span style=\"display:block");
+                if let Some(bg) = backgrounds.background(line) {
+                    html.push_str(";background-color:");
+                    push_css_color(html, bg);
+                }
+                html.push_str("\">");
+                is_line_open = true;
+            }
+
+            render_html_into(html, [(style.clone(), part)]);
+
+            // The line break is inside the block, such that
+            // empty lines retain their height
+            if part.ends_with('\n') {
+                html.push_str("</span>");
+                is_line_open = false;
+                line += 1;
+            }
+        }
+    }
+    if is_line_open {
+        html.push_str("</span>");
+    }
+}
+
+/// Layers the background of each line below the style of the `tokens`,
+/// e.g. for [`render_ansi()`]. Tokens spanning multiple lines are split
+/// after each line break.
+///
+/// See [`render_html_lines()`] for HTML.
+///
+/// # Example
+///
+/// ```rust
+/// use colorblast::lines::{apply_line_backgrounds, LineBackgrounds};
+/// use colorblast::{Color, Style};
+///
+/// let red = Style::new().fg((255, 0, 0));
+/// let tokens = [(Some(red), "a\nb"), (None, "\nc")];
+///
+/// let backgrounds = LineBackgrounds::new().stripe(Color::WHITE);
+/// let spans = apply_line_backgrounds(tokens, &backgrounds);
+///
+/// let bgs = spans
+///     .iter()
+///     .map(|(style, s)| (*s, style.as_ref().and_then(|style| style.bg)))
+///     .collect::<Vec<_>>();
+/// assert_eq!(
+///     bgs,
+///     [("a\n", None), ("b", Some(Color::WHITE)), ("\n", Some(Color::WHITE)), ("c", None)]
+/// );
+/// ```
+///
+/// [`render_ansi()`]: crate::render_ansi
+pub fn apply_line_backgrounds<'text, Sty, I>(
+    tokens: I,
+    backgrounds: &LineBackgrounds,
+) -> Vec<(Option<Style>, &'text str)>
+where
+    I: IntoIterator<Item = (Sty, &'text str)>,
+    Sty: AsStyle,
+{
+    let mut line = 0;
+    let mut spans = Vec::new();
+    for (sty, tok) in tokens {
+        let style = sty.as_style();
+        for part in tok.split_inclusive('\n') {
+            let style = match backgrounds.background(line) {
+                Some(bg) => {
+                    let line_style = Style::new().bg(bg);
+                    Some(match &style {
+                        Some(style) => line_style.overlay(style),
+                        None => line_style,
+                    })
+                }
+                None => style.clone(),
+            };
+            spans.push((style, part));
+
+            if part.ends_with('\n') {
+                line += 1;
+            }
+        }
+    }
+    spans
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_line_backgrounds() {
+        let stripe = Color::from((1, 1, 1));
+        let added = Color::from((2, 2, 2));
+        let backgrounds = LineBackgrounds::new()
+            .stripe(stripe)
+            .class_color(LineClass::Added, added)
+            .lines(2..4, LineClass::Added)
+            .line(3, LineClass::Focus);
+
+        let expected = [None, Some(stripe), Some(added)];
+        for (line, expected) in expected.into_iter().enumerate() {
+            assert_eq!(backgrounds.background(line), expected, "{line}");
+        }
+        assert_eq!(backgrounds.class(3), Some(LineClass::Focus));
+        assert_ne!(backgrounds.background(3), Some(stripe));
+        assert_eq!(backgrounds.background(5), Some(stripe));
+    }
+
+    #[test]
+    fn test_render_html_lines() {
+        let backgrounds = LineBackgrounds::new()
+            .stripe(Color::WHITE)
+            .class_color(LineClass::Removed, Color::BLACK)
+            .line(2, LineClass::Removed);
+        let red = Some(Color::from((255, 0, 0)));
+        let tokens = [(red, "a\n"), (None, "\n<b"), (red, "\nc\n")];
+
+        let html = render_html_lines(tokens, &backgrounds);
+        assert_eq!(
+            html,
+            concat!(
+                "<span style=\"display:block\"><span style=\"color:#FF0000\">a\n</span></span>",
+                "<span style=\"display:block;background-color:#FFFFFF\">\n</span>",
+                "<span style=\"display:block;background-color:#000000\">&lt;b",
+                "<span style=\"color:#FF0000\">\n</span></span>",
+                "<span style=\"display:block;background-color:#FFFFFF\">",
+                "<span style=\"color:#FF0000\">c\n</span></span>",
+            )
+        );
+    }
+
+    #[test]
+    fn test_line_backgrounds_for_theme() {
+        let dark = LineBackgrounds::for_theme(&Theme::default()).striped();
+        let light = LineBackgrounds::for_theme(&Theme::new().background(Color::WHITE)).striped();
+
+        let bg = |backgrounds: &LineBackgrounds| backgrounds.background(1).unwrap();
+        assert!(bg(&dark).relative_luminance() > Color::from((30, 30, 30)).relative_luminance());
+        assert!(bg(&light).relative_luminance() < Color::WHITE.relative_luminance());
+
+        let added = light.line(0, LineClass::Added).background(0).unwrap();
+        assert_ne!(added, Color::WHITE);
+    }
+}
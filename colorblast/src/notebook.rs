@@ -0,0 +1,267 @@
+//! Rendering of [Jupyter notebooks] (`.ipynb`) into HTML.
+//!
+//! [Jupyter notebooks]: https://nbformat.readthedocs.io/en/latest/format_description.html
+
+use crate::html::{escape_html, render_html_into};
+use crate::json::{parse_json, Value};
+use crate::{Error, Lexer, StylizeToken};
+
+/// The lexer used for code cells, if the notebook language is unknown.
+#[cfg(feature = "lang-python")]
+const DEFAULT_LEXER: Lexer = Lexer::Python;
+#[cfg(not(feature = "lang-python"))]
+const DEFAULT_LEXER: Lexer = Lexer::PlainText;
+
+const STYLE: &str = "\
+body{margin:0 auto;max-width:960px;padding:16px;font-family:sans-serif}\
+.cell{margin:8px 0}\
+pre{margin:0;padding:8px;overflow-x:auto;background-color:#1E1E1E;color:#D4D4D4}\
+.output pre{background-color:transparent;color:inherit;border-left:2px solid #CCCCCC}";
+
+/// Options for [`render_notebook_html()`].
+///
+/// # Example
+///
+/// ```rust
+/// use colorblast::notebook::Options;
+/// use colorblast::Lexer;
+///
+/// let options = Options::new()
+///     .title("Notebook")
+///     .outputs(false)
+///     .lexer(Lexer::Python);
+/// # let _ = options;
+/// ```
+#[derive(Clone, Debug)]
+#[non_exhaustive]
+pub struct Options {
+    /// The `<title>` of the HTML document, defaults to `None`,
+    /// i.e. no `<title>` is rendered.
+    pub title: Option<String>,
+
+    /// Whether to render the outputs of code cells, defaults to `true`.
+    ///
+    /// Only textual outputs are rendered, i.e. streams, `text/plain`
+    /// results, and errors.
+    pub outputs: bool,
+
+    /// The lexer used for code cells. If `None`, then the lexer is
+    /// resolved from the language in the notebook metadata, and
+    /// falls back to Python, or plain text if the `lang-python`
+    /// feature is disabled.
+    pub lexer: Option<Lexer>,
+}
+
+impl Options {
+    pub const DEFAULT: Self = Self {
+        title: None,
+        outputs: true,
+        lexer: None,
+    };
+
+    #[inline]
+    pub fn new() -> Self {
+        Self::DEFAULT
+    }
+
+    #[inline]
+    pub fn title(mut self, title: impl Into<String>) -> Self {
+        self.title = Some(title.into());
+        self
+    }
+
+    #[inline]
+    pub fn outputs(mut self, outputs: bool) -> Self {
+        self.outputs = outputs;
+        self
+    }
+
+    #[inline]
+    pub fn lexer(mut self, lexer: impl Into<Option<Lexer>>) -> Self {
+        self.lexer = lexer.into();
+        self
+    }
+}
+
+impl Default for Options {
+    #[inline]
+    fn default() -> Self {
+        Self::DEFAULT
+    }
+}
+
+/// Renders the Jupyter notebook `json` into a standalone HTML document.
+///
+/// Code cells are highlighted using the lexer of the notebook language,
+/// i.e. `metadata.kernelspec.language` or `metadata.language_info.name`,
+/// unless overridden by [`Options::lexer`]. Markdown cells are highlighted
+/// using [`Lexer::Markdown`].
+///
+/// # Errors
+///
+/// Returns [`Error::Notebook`] if `json` is not a valid notebook.
+///
+/// # Example
+///
+/// ```rust
+/// use colorblast::notebook::{render_notebook_html, Options};
+///
+/// let json = r##"{
+///     "metadata": { "kernelspec": { "language": "python" } },
+///     "cells": [
+///         { "cell_type": "markdown", "source": ["# Title"] },
+///         { "cell_type": "code", "source": "print(1)", "outputs": [] }
+///     ]
+/// }"##;
+///
+/// let html = render_notebook_html(json, &Options::new()).unwrap();
+/// assert!(html.starts_with("<!DOCTYPE html>"));
+/// assert!(html.contains("# Title"));
+/// ```
+pub fn render_notebook_html(json: &str, options: &Options) -> Result<String, Error> {
+    let notebook = parse_json(json).map_err(Error::Notebook)?;
+
+    let cells = notebook
+        .get("cells")
+        .and_then(Value::as_array)
+        .ok_or_else(|| Error::Notebook("missing `cells`".to_owned()))?;
+
+    let lexer = options.lexer.unwrap_or_else(|| {
+        let metadata = notebook.get("metadata");
+        let language = metadata
+            .and_then(|m| m.get("kernelspec"))
+            .and_then(|k| k.get("language"))
+            .or_else(|| {
+                metadata
+                    .and_then(|m| m.get("language_info"))
+                    .and_then(|l| l.get("name"))
+            })
+            .and_then(Value::as_str);
+        language
+            .and_then(|lang| Lexer::by_name(lang).ok())
+            .unwrap_or(DEFAULT_LEXER)
+    });
+
+    let mut html = String::new();
+    html.push_str("<!DOCTYPE html>\n<html>\n<head>\n<meta charset=\"utf-8\">\n");
+    if let Some(title) = &options.title {
+        html.push_str("<title>");
+        html.extend(escape_html(title));
+        html.push_str("</title>\n");
+    }
+    html.push_str("<style>");
+    html.push_str(STYLE);
+    html.push_str("</style>\n</head>\n<body>\n");
+
+    for cell in cells {
+        let cell_type = cell
+            .get("cell_type")
+            .and_then(Value::as_str)
+            .ok_or_else(|| Error::Notebook("missing `cell_type`".to_owned()))?;
+        let source = cell.get("source").map(Value::to_text).unwrap_or_default();
+
+        match cell_type {
+            "code" => {
+                html.push_str("<div class=\"cell code\">\n");
+                push_code(&mut html, lexer, &source);
+                if options.outputs {
+                    push_outputs(&mut html, cell);
+                }
+            }
+            "markdown" => {
+                html.push_str("<div class=\"cell markdown\">\n");
+                push_code(&mut html, Lexer::Markdown, &source);
+            }
+            _ => {
+                html.push_str("<div class=\"cell raw\">\n");
+                push_code(&mut html, Lexer::PlainText, &source);
+            }
+        }
+        html.push_str("</div>\n");
+    }
+
+    html.push_str("</body>\n</html>\n");
+    Ok(html)
+}
+
+fn push_code(html: &mut String, lexer: Lexer, code: &str) {
+    html.push_str("<pre>");
+    let tokens = lexer
+        .into_lexer(code)
+        .map(|(tok, span)| (tok.style(&span), span.as_str()));
+    render_html_into(html, tokens);
+    html.push_str("</pre>\n");
+}
+
+fn push_outputs(html: &mut String, cell: &Value) {
+    let outputs = match cell.get("outputs").and_then(Value::as_array) {
+        Some(outputs) => outputs,
+        None => return,
+    };
+
+    for output in outputs {
+        let text = match output.get("output_type").and_then(Value::as_str) {
+            Some("stream") => output.get("text").map(Value::to_text),
+            Some("execute_result" | "display_data") => output
+                .get("data")
+                .and_then(|data| data.get("text/plain"))
+                .map(Value::to_text),
+            Some("error") => {
+                let ename = output.get("ename").and_then(Value::as_str);
+                let evalue = output.get("evalue").and_then(Value::as_str);
+                Some(format!("{}: {}", ename.unwrap_or(""), evalue.unwrap_or("")))
+            }
+            _ => None,
+        };
+
+        if let Some(text) = text {
+            html.push_str("<div class=\"output\"><pre>");
+            html.extend(escape_html(&text));
+            html.push_str("</pre></div>\n");
+        }
+    }
+}
+
+#[cfg(all(test, feature = "all-langs"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_render_notebook_html() {
+        let json = r##"{
+            "metadata": { "language_info": { "name": "rust" } },
+            "nbformat": 4,
+            "cells": [
+                { "cell_type": "markdown", "metadata": {}, "source": ["# A \u00e9\n", "b"] },
+                {
+                    "cell_type": "code",
+                    "execution_count": 1,
+                    "source": "let x = \"<\";",
+                    "outputs": [
+                        { "output_type": "stream", "name": "stdout", "text": ["1 < 2\n"] },
+                        { "output_type": "error", "ename": "E", "evalue": "\ud83d\ude00", "traceback": [] }
+                    ]
+                }
+            ]
+        }"##;
+
+        let html = render_notebook_html(json, &Options::new().title("a & b")).unwrap();
+
+        assert!(html.contains("<title>a &amp; b</title>"));
+        assert!(html.contains("# A \u{e9}"));
+        assert!(html.contains("&quot;&lt;&quot;"));
+        assert!(html.contains("<pre>1 &lt; 2\n</pre>"));
+        assert!(html.contains("E: \u{1F600}"));
+
+        let html = render_notebook_html(json, &Options::new().outputs(false)).unwrap();
+        assert!(!html.contains("class=\"output\""));
+    }
+
+    #[test]
+    fn test_render_notebook_html_invalid() {
+        for json in ["", "[]", "{\"cells\": [}", "{\"cells\": [{}]}", "{} {}"] {
+            let err = render_notebook_html(json, &Options::new()).unwrap_err();
+            assert!(matches!(err, Error::Notebook(_)), "{json:?}");
+        }
+    }
+}
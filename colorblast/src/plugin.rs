@@ -0,0 +1,651 @@
+//! Loading lexers from dynamic libraries, e.g. for adding lexers for
+//! proprietary languages to the `colorblast` CLI, without recompiling it.
+//!
+//! # Writing a Plugin
+//!
+//! A plugin is a `cdylib`, which exports a [`RegisterFn`] named
+//! [`PLUGIN_REGISTER_SYMBOL`], i.e. `register`. When loaded, `register`
+//! is called with a [`RawRegistry`], to which the plugin registers each
+//! of its lexers, by calling [`register_lexer`] with a [`RawLexer`].
+//!
+//! All types are `#[repr(C)]` and all functions are `extern "C"`, such
+//! that plugins do not need to be compiled with the same Rust compiler
+//! as `colorblast`, or even be written in Rust.
+//!
+//! Each lexer declares the names of its token kinds in
+//! [`token_names`], e.g. `"comment"` and `"keyword"`, which are mapped
+//! to [`Token`]s using [`Token::from_name()`]. Unknown names are mapped
+//! to [`Token::Text`]. The tokens produced by [`next_token`] then refer
+//! to their kind by index into `token_names`.
+//!
+//! ```rust,no_run
+//! use std::ffi::c_void;
+//!
+//! use colorblast::plugin::{RawLexer, RawRegistry, RawStr, RawToken, PLUGIN_ABI_VERSION};
+//!
+//! const TOKEN_NAMES: &[RawStr] = &[RawStr::new("space"), RawStr::new("text")];
+//!
+//! #[no_mangle]
+//! pub unsafe extern "C" fn register(registry: *mut RawRegistry) {
+//!     let registry = &*registry;
+//!     if registry.abi_version != PLUGIN_ABI_VERSION {
+//!         return;
+//!     }
+//!
+//!     let lexer = RawLexer {
+//!         abi_version: PLUGIN_ABI_VERSION,
+//!         name: RawStr::new("words"),
+//!         extensions: [].as_ptr(),
+//!         extensions_len: 0,
+//!         token_names: TOKEN_NAMES.as_ptr(),
+//!         token_names_len: TOKEN_NAMES.len(),
+//!         new: words_new,
+//!         next_token: words_next_token,
+//!         drop: words_drop,
+//!     };
+//!     (registry.register_lexer)(registry.ctx, &lexer);
+//! }
+//! # unsafe extern "C" fn words_new(text: RawStr) -> *mut c_void { todo!() }
+//! # unsafe extern "C" fn words_next_token(state: *mut c_void, token: *mut RawToken) -> bool { todo!() }
+//! # unsafe extern "C" fn words_drop(state: *mut c_void) {}
+//! ```
+//!
+//! # Safety
+//!
+//! Loading a plugin executes arbitrary code, and `colorblast` cannot
+//! verify that the plugin upholds the ABI. Thereby, only load plugins
+//! which are trusted. However, the tokens produced by plugins are
+//! validated, i.e. spans which are out of bounds, overlapping, or not
+//! on `char` boundaries end the tokens, and the remaining text is
+//! produced as a single [`Token::Text`].
+//!
+//! [`register_lexer`]: RawRegistry::register_lexer
+//! [`token_names`]: RawLexer::token_names
+//! [`next_token`]: RawLexer::next_token
+
+#![allow(unsafe_code)]
+
+use std::ffi::c_void;
+use std::ops::Range;
+use std::path::Path;
+use std::slice;
+use std::str;
+
+use crate::{Error, Token, TokenSpan};
+
+/// Version of the plugin ABI, which is incremented whenever any of the
+/// `Raw*` types or their semantics change.
+///
+/// Plugins must only register lexers, if [`RawRegistry::abi_version`]
+/// is equal to the version they were compiled against.
+pub const PLUGIN_ABI_VERSION: u32 = 1;
+
+/// Name of the [`RegisterFn`] symbol, which plugins must export.
+pub const PLUGIN_REGISTER_SYMBOL: &str = "register";
+
+/// Signature of the [`PLUGIN_REGISTER_SYMBOL`] function exported by plugins.
+pub type RegisterFn = unsafe extern "C" fn(registry: *mut RawRegistry);
+
+/// Registry passed to the [`RegisterFn`] of a plugin.
+#[repr(C)]
+#[derive(Debug)]
+pub struct RawRegistry {
+    /// Always [`PLUGIN_ABI_VERSION`] of the loading `colorblast`.
+    pub abi_version: u32,
+    /// Opaque context, which must be passed to `register_lexer`.
+    pub ctx: *mut c_void,
+    /// Registers `lexer`, which is copied, i.e. `lexer` is only required
+    /// to be valid during the call. However, the strings and functions
+    /// it points to must remain valid while the plugin is loaded.
+    ///
+    /// Returns `false` if the lexer is rejected, e.g. if its `abi_version`
+    /// is not [`PLUGIN_ABI_VERSION`], if any of its strings are invalid
+    /// UTF-8, or if a lexer with the same name is already registered.
+    pub register_lexer: unsafe extern "C" fn(ctx: *mut c_void, lexer: *const RawLexer) -> bool,
+}
+
+/// UTF-8 string slice, which is not NUL-terminated.
+#[repr(C)]
+#[derive(Clone, Copy, Debug)]
+pub struct RawStr {
+    pub ptr: *const u8,
+    pub len: usize,
+}
+
+impl RawStr {
+    #[inline]
+    pub const fn new(s: &'static str) -> Self {
+        Self {
+            ptr: s.as_ptr(),
+            len: s.len(),
+        }
+    }
+
+    /// Same as [`RawStr::new()`], but for a non-`'static` `s`.
+    #[inline]
+    fn new_unbounded(s: &str) -> Self {
+        Self {
+            ptr: s.as_ptr(),
+            len: s.len(),
+        }
+    }
+
+    /// # Safety
+    ///
+    /// `ptr` must be valid for reads of `len` bytes for `'a`,
+    /// unless `len` is `0`.
+    unsafe fn to_str<'a>(self) -> Option<&'a str> {
+        if self.len == 0 {
+            return Some("");
+        } else if self.ptr.is_null() {
+            return None;
+        }
+        str::from_utf8(slice::from_raw_parts(self.ptr, self.len)).ok()
+    }
+}
+
+/// Vtable of a lexer provided by a plugin.
+#[repr(C)]
+#[derive(Clone, Copy, Debug)]
+pub struct RawLexer {
+    /// Must be [`PLUGIN_ABI_VERSION`].
+    pub abi_version: u32,
+    /// Name of the language, e.g. `"foo"`, which is matched
+    /// case-insensitively by [`Plugins::by_name()`].
+    pub name: RawStr,
+    /// File extensions of the language without the leading `.`, e.g. `"foo"`.
+    pub extensions: *const RawStr,
+    pub extensions_len: usize,
+    /// Names of the token kinds, see the [module documentation](self).
+    pub token_names: *const RawStr,
+    pub token_names_len: usize,
+    /// Creates the state for lexing `text`, which remains valid until
+    /// `drop` is called. Returning null is treated as producing no tokens.
+    pub new: unsafe extern "C" fn(text: RawStr) -> *mut c_void,
+    /// Writes the next token to `token` and returns `true`, or returns
+    /// `false` if there are no more tokens. Tokens must be produced in
+    /// order, and must not be empty. Text skipped between tokens is
+    /// produced as [`Token::Text`].
+    pub next_token: unsafe extern "C" fn(state: *mut c_void, token: *mut RawToken) -> bool,
+    /// Drops the state returned by `new`.
+    pub drop: unsafe extern "C" fn(state: *mut c_void),
+}
+
+/// Token produced by [`RawLexer::next_token`].
+#[repr(C)]
+#[derive(Clone, Copy, Default, Debug)]
+pub struct RawToken {
+    /// Index into [`RawLexer::token_names`].
+    pub kind: u32,
+    /// Byte offset of the start of the token in the text.
+    pub start: usize,
+    /// Byte offset of the end of the token in the text.
+    pub end: usize,
+}
+
+/// Lexer registered by a plugin, see [`Plugins`].
+#[derive(Debug)]
+pub struct PluginLexer {
+    name: String,
+    extensions: Vec<String>,
+    tokens: Vec<Token>,
+    raw: RawLexer,
+}
+
+impl PluginLexer {
+    /// Returns the name of the language.
+    #[inline]
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns the file extensions of the language, without the leading `.`.
+    #[inline]
+    pub fn extensions(&self) -> &[String] {
+        &self.extensions
+    }
+
+    /// Returns an iterator producing <code>([`Token`], [`TokenSpan`])</code>
+    /// for `text`, by calling into the plugin.
+    ///
+    /// The tokens are validated, see the [module documentation](self#safety).
+    pub fn lex<'a, 'text>(&'a self, text: &'text str) -> PluginTokens<'a, 'text> {
+        // Safety: `text` outlives the state, which is dropped
+        // by `PluginTokens` before `text` is released
+        let state = unsafe { (self.raw.new)(RawStr::new_unbounded(text)) };
+        PluginTokens {
+            lexer: self,
+            text,
+            state,
+            pos: 0,
+            pending: None,
+        }
+    }
+
+    /// # Safety
+    ///
+    /// The pointers in `raw` must be valid, as documented for [`RawLexer`].
+    unsafe fn from_raw(raw: &RawLexer) -> Option<Self> {
+        if raw.abi_version != PLUGIN_ABI_VERSION {
+            return None;
+        }
+
+        let name = raw.name.to_str()?.trim();
+        if name.is_empty() {
+            return None;
+        }
+
+        let extensions = raw_slice(raw.extensions, raw.extensions_len)
+            .iter()
+            .map(|ext| ext.to_str().map(str::to_owned))
+            .collect::<Option<Vec<_>>>()?;
+
+        let tokens = raw_slice(raw.token_names, raw.token_names_len)
+            .iter()
+            .map(|name| {
+                name.to_str()
+                    .map(|name| Token::from_name(name).unwrap_or(Token::Text))
+            })
+            .collect::<Option<Vec<_>>>()?;
+
+        Some(Self {
+            name: name.to_owned(),
+            extensions,
+            tokens,
+            raw: *raw,
+        })
+    }
+}
+
+/// Iterator producing <code>([`Token`], [`TokenSpan`])</code>,
+/// see [`PluginLexer::lex()`].
+#[derive(Debug)]
+pub struct PluginTokens<'a, 'text> {
+    lexer: &'a PluginLexer,
+    text: &'text str,
+    /// Null when there are no more tokens.
+    state: *mut c_void,
+    pos: usize,
+    /// Token following a gap, which has been produced as [`Token::Text`].
+    pending: Option<(Token, Range<usize>)>,
+}
+
+impl<'a, 'text> PluginTokens<'a, 'text> {
+    fn finish(&mut self) {
+        if !self.state.is_null() {
+            // Safety: `state` was returned by `new` and not yet dropped
+            unsafe { (self.lexer.raw.drop)(self.state) };
+            self.state = std::ptr::null_mut();
+        }
+    }
+
+    /// Produces the remaining text as a single [`Token::Text`].
+    fn rest(&mut self) -> Option<(Token, TokenSpan<'text>)> {
+        self.finish();
+        if self.pos >= self.text.len() {
+            return None;
+        }
+        let r = self.pos..self.text.len();
+        self.pos = self.text.len();
+        Some((Token::Text, TokenSpan::new(self.text, r)))
+    }
+}
+
+impl<'a, 'text> Iterator for PluginTokens<'a, 'text> {
+    type Item = (Token, TokenSpan<'text>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if let Some((tok, r)) = self.pending.take() {
+            self.pos = r.end;
+            return Some((tok, TokenSpan::new(self.text, r)));
+        }
+
+        if self.state.is_null() {
+            return self.rest();
+        }
+
+        let mut raw = RawToken::default();
+        // Safety: `state` was returned by `new` and not yet dropped
+        if !unsafe { (self.lexer.raw.next_token)(self.state, &mut raw) } {
+            return self.rest();
+        }
+
+        let RawToken { kind, start, end } = raw;
+        let is_valid = (self.pos <= start)
+            && (start < end)
+            && (end <= self.text.len())
+            && self.text.is_char_boundary(start)
+            && self.text.is_char_boundary(end);
+        if !is_valid {
+            return self.rest();
+        }
+
+        let tok = match self.lexer.tokens.get(kind as usize) {
+            Some(&tok) => tok,
+            None => Token::Text,
+        };
+
+        if start > self.pos {
+            self.pending = Some((tok, start..end));
+            let r = self.pos..start;
+            self.pos = start;
+            return Some((Token::Text, TokenSpan::new(self.text, r)));
+        }
+
+        self.pos = end;
+        Some((tok, TokenSpan::new(self.text, start..end)))
+    }
+}
+
+impl Drop for PluginTokens<'_, '_> {
+    #[inline]
+    fn drop(&mut self) {
+        self.finish();
+    }
+}
+
+/// Collection of loaded plugins and the lexers they registered.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use colorblast::plugin::Plugins;
+///
+/// let mut plugins = Plugins::new();
+/// // Safety: The plugin is trusted
+/// unsafe { plugins.load("libfoo_lexer.so") }.unwrap();
+///
+/// let lexer = plugins.by_name("foo").unwrap();
+/// for (tok, span) in lexer.lex("foo code") {
+///     println!("{tok:?} {:?}", span.as_str());
+/// }
+/// ```
+#[derive(Default, Debug)]
+pub struct Plugins {
+    /// Dropped before `libraries`, as they point into the libraries.
+    lexers: Vec<PluginLexer>,
+    libraries: Vec<libloading::Library>,
+}
+
+impl Plugins {
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Loads the dynamic library at `path` and calls its [`RegisterFn`].
+    ///
+    /// Returns the number of lexers registered by the plugin.
+    ///
+    /// Returns [`Error::Plugin`] if the library cannot be loaded,
+    /// or if it does not export [`PLUGIN_REGISTER_SYMBOL`].
+    ///
+    /// # Safety
+    ///
+    /// Loading a library executes its initialization code, and calling its
+    /// functions requires the plugin to uphold the ABI. Thereby, the plugin
+    /// must be trusted, see the [module documentation](self#safety).
+    pub unsafe fn load(&mut self, path: impl AsRef<Path>) -> Result<usize, Error> {
+        let path = path.as_ref();
+        let err = |err: libloading::Error| Error::Plugin(format!("`{}`: {err}", path.display()));
+
+        let library = libloading::Library::new(path).map_err(err)?;
+        let register = *library
+            .get::<RegisterFn>(PLUGIN_REGISTER_SYMBOL.as_bytes())
+            .map_err(err)?;
+
+        let count = self.register(register);
+        self.libraries.push(library);
+        Ok(count)
+    }
+
+    /// Calls `register`, e.g. for a plugin which is statically linked,
+    /// and returns the number of lexers it registered.
+    ///
+    /// # Safety
+    ///
+    /// `register` and the lexers it registers must uphold the ABI,
+    /// and must remain valid for the lifetime of `self`.
+    pub unsafe fn register(&mut self, register: RegisterFn) -> usize {
+        let before = self.lexers.len();
+
+        let mut registry = RawRegistry {
+            abi_version: PLUGIN_ABI_VERSION,
+            ctx: (self as *mut Self).cast(),
+            register_lexer,
+        };
+        register(&mut registry);
+
+        self.lexers.len() - before
+    }
+
+    /// Returns all lexers, in the order they were registered.
+    #[inline]
+    pub fn lexers(&self) -> &[PluginLexer] {
+        &self.lexers
+    }
+
+    /// Returns the lexer with the given `name`, matched case-insensitively.
+    pub fn by_name(&self, name: &str) -> Option<&PluginLexer> {
+        let name = name.trim();
+        self.lexers
+            .iter()
+            .find(|lexer| lexer.name.eq_ignore_ascii_case(name))
+    }
+
+    /// Returns the first lexer with the given file extension, e.g. `"foo"`,
+    /// matched case-insensitively.
+    pub fn by_extension(&self, ext: &str) -> Option<&PluginLexer> {
+        let ext = ext.strip_prefix('.').unwrap_or(ext);
+        self.lexers
+            .iter()
+            .find(|lexer| lexer.extensions.iter().any(|e| e.eq_ignore_ascii_case(ext)))
+    }
+}
+
+unsafe extern "C" fn register_lexer(ctx: *mut c_void, lexer: *const RawLexer) -> bool {
+    if ctx.is_null() || lexer.is_null() {
+        return false;
+    }
+    // Safety: `ctx` is the `Plugins` passed by `Plugins::register()`
+    let plugins = &mut *ctx.cast::<Plugins>();
+
+    let Some(lexer) = PluginLexer::from_raw(&*lexer) else {
+        return false;
+    };
+    if plugins.by_name(&lexer.name).is_some() {
+        return false;
+    }
+
+    plugins.lexers.push(lexer);
+    true
+}
+
+/// # Safety
+///
+/// `ptr` must be valid for reads of `len` elements, unless `len` is `0`.
+unsafe fn raw_slice<'a, T>(ptr: *const T, len: usize) -> &'a [T] {
+    if (len == 0) || ptr.is_null() {
+        &[]
+    } else {
+        slice::from_raw_parts(ptr, len)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TOKEN_NAMES: &[RawStr] = &[
+        RawStr::new("space"),
+        RawStr::new("keyword"),
+        RawStr::new("unknown-name"),
+    ];
+    const EXTENSIONS: &[RawStr] = &[RawStr::new("words")];
+
+    /// Produces words as `keyword` and spaces as `space`, skipping
+    /// anything else, or with `BAD` producing an out of bounds token.
+    struct WordsState {
+        text: &'static str,
+        pos: usize,
+    }
+
+    unsafe extern "C" fn words_new(text: RawStr) -> *mut c_void {
+        let text = text.to_str().unwrap();
+        // Safety: The state never outlives `text`, see `PluginLexer::lex()`
+        let text = std::mem::transmute::<&str, &'static str>(text);
+        Box::into_raw(Box::new(WordsState { text, pos: 0 })).cast()
+    }
+
+    unsafe extern "C" fn words_next_token(state: *mut c_void, token: *mut RawToken) -> bool {
+        let state = &mut *state.cast::<WordsState>();
+        let rest = &state.text[state.pos..];
+
+        let Some(i) = rest.find(|c: char| c.is_alphanumeric() || (c == ' ')) else {
+            return false;
+        };
+        let start = state.pos + i;
+        if state.text[start..].starts_with("BAD") {
+            *token = RawToken {
+                kind: 1,
+                start,
+                end: state.text.len() + 1,
+            };
+            return true;
+        }
+
+        let is_space = state.text[start..].starts_with(' ');
+        let len = state.text[start..]
+            .find(|c: char| {
+                if is_space {
+                    c != ' '
+                } else {
+                    !c.is_alphanumeric()
+                }
+            })
+            .unwrap_or(state.text.len() - start);
+        state.pos = start + len;
+
+        *token = RawToken {
+            kind: if is_space { 0 } else { 1 },
+            start,
+            end: state.pos,
+        };
+        true
+    }
+
+    unsafe extern "C" fn words_drop(state: *mut c_void) {
+        drop(Box::from_raw(state.cast::<WordsState>()));
+    }
+
+    const fn words_lexer(name: &'static str) -> RawLexer {
+        RawLexer {
+            abi_version: PLUGIN_ABI_VERSION,
+            name: RawStr::new(name),
+            extensions: EXTENSIONS.as_ptr(),
+            extensions_len: EXTENSIONS.len(),
+            token_names: TOKEN_NAMES.as_ptr(),
+            token_names_len: TOKEN_NAMES.len(),
+            new: words_new,
+            next_token: words_next_token,
+            drop: words_drop,
+        }
+    }
+
+    unsafe extern "C" fn register(registry: *mut RawRegistry) {
+        let registry = &*registry;
+        assert_eq!(registry.abi_version, PLUGIN_ABI_VERSION);
+
+        assert!((registry.register_lexer)(
+            registry.ctx,
+            &words_lexer("Words")
+        ));
+        // Duplicate name
+        assert!(!(registry.register_lexer)(
+            registry.ctx,
+            &words_lexer("words")
+        ));
+
+        let mut lexer = words_lexer("other");
+        lexer.abi_version = PLUGIN_ABI_VERSION + 1;
+        assert!(!(registry.register_lexer)(registry.ctx, &lexer));
+
+        let mut lexer = words_lexer("other");
+        let invalid = b"\xFF";
+        lexer.name = RawStr {
+            ptr: invalid.as_ptr(),
+            len: invalid.len(),
+        };
+        assert!(!(registry.register_lexer)(registry.ctx, &lexer));
+    }
+
+    fn plugins() -> Plugins {
+        let mut plugins = Plugins::new();
+        let count = unsafe { plugins.register(register) };
+        assert_eq!(count, 1);
+        plugins
+    }
+
+    fn lex(text: &str) -> Vec<(Token, &str)> {
+        let plugins = plugins();
+        let lexer = plugins.by_name("words").unwrap();
+        lexer
+            .lex(text)
+            .map(|(tok, span)| (tok, span.as_str()))
+            .collect()
+    }
+
+    #[test]
+    fn test_plugin_register() {
+        let plugins = plugins();
+        assert_eq!(plugins.lexers().len(), 1);
+
+        let lexer = plugins.by_name(" WORDS ").unwrap();
+        assert_eq!(lexer.name(), "Words");
+        assert_eq!(lexer.extensions(), ["words"]);
+        assert_eq!(lexer.tokens, [Token::Space, Token::Keyword, Token::Text]);
+
+        assert!(plugins.by_extension(".words").is_some());
+        assert!(plugins.by_name("other").is_none());
+        assert!(plugins.by_extension("rs").is_none());
+    }
+
+    #[test]
+    fn test_plugin_lex() {
+        use Token::*;
+        assert_eq!(lex(""), []);
+        assert_eq!(
+            lex("foo bar"),
+            [(Keyword, "foo"), (Space, " "), (Keyword, "bar")]
+        );
+        // Skipped text is produced as `Token::Text`
+        assert_eq!(
+            lex("foo, (bar)"),
+            [
+                (Keyword, "foo"),
+                (Text, ","),
+                (Space, " "),
+                (Text, "("),
+                (Keyword, "bar"),
+                (Text, ")"),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_plugin_lex_invalid() {
+        use Token::*;
+        assert_eq!(
+            lex("foo BAD bar"),
+            [(Keyword, "foo"), (Space, " "), (Text, "BAD bar")]
+        );
+    }
+
+    #[test]
+    fn test_plugin_load_error() {
+        let mut plugins = Plugins::new();
+        let err = unsafe { plugins.load("does-not-exist.so") }.unwrap_err();
+        assert!(matches!(err, Error::Plugin(_)), "{err:?}");
+        assert!(plugins.lexers().is_empty());
+    }
+}
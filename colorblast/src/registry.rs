@@ -0,0 +1,320 @@
+//! Selecting the [`Lexer`] for a file name or language name, where
+//! user-supplied overrides take precedence over the built-in
+//! extensions and aliases, e.g. for ambiguous extensions.
+//!
+//! # Example
+//!
+//! ```rust
+//! use colorblast::registry::LexerRegistry;
+//! use colorblast::Lexer;
+//!
+//! let registry = LexerRegistry::new()
+//!     .extension("conf", Lexer::Nginx)
+//!     .alias("rs2021", Lexer::Rust);
+//!
+//! assert_eq!(registry.by_path("/etc/app.conf"), Some(Lexer::Nginx));
+//! assert_eq!(registry.by_path("src/main.py"), Some(Lexer::Python));
+//! assert_eq!(registry.by_name("rs2021").ok(), Some(Lexer::Rust));
+//! ```
+//!
+//! # Config
+//!
+//! Overrides can be loaded from a config, see [`LexerRegistry::load_config()`],
+//! which consists of `[extensions]` and `[aliases]` sections, mapping
+//! extensions and aliases to the name of a built-in language:
+//!
+//! ```text
+//! # Comments start with `#`
+//! [extensions]
+//! conf = nginx
+//! html.j2 = jinja
+//!
+//! [aliases]
+//! rs2021 = rust
+//! ```
+
+use std::path::Path;
+
+use crate::{Error, Lexer};
+
+/// Registry of extension and alias overrides, which take precedence
+/// over the built-in [`LexerInfo`], see the [module documentation](self).
+///
+/// Extensions and aliases are matched case-insensitively, and adding
+/// the same extension or alias again replaces the previous override.
+///
+/// [`LexerInfo`]: crate::LexerInfo
+#[derive(Clone, Default, Debug)]
+pub struct LexerRegistry {
+    /// Extensions without the leading `.`, in lowercase.
+    extensions: Vec<(String, Lexer)>,
+    /// Aliases in lowercase.
+    aliases: Vec<(String, Lexer)>,
+}
+
+impl LexerRegistry {
+    /// Returns a registry without overrides, i.e. which only
+    /// uses the built-in extensions and aliases.
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns a registry with the overrides in `config`,
+    /// see [`load_config()`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Config`] if `config` is invalid.
+    ///
+    /// [`load_config()`]: Self::load_config
+    pub fn from_config(config: &str) -> Result<Self, Error> {
+        let mut registry = Self::new();
+        registry.load_config(config)?;
+        Ok(registry)
+    }
+
+    /// Overrides the file extension `ext` to use `lexer`, e.g. `h` or `.h`.
+    ///
+    /// Extensions consisting of multiple parts, e.g. `html.j2`, take
+    /// precedence over their last part, see [`by_path()`].
+    ///
+    /// [`by_path()`]: Self::by_path
+    pub fn extension(mut self, ext: &str, lexer: Lexer) -> Self {
+        let ext = ext.strip_prefix('.').unwrap_or(ext);
+        insert(&mut self.extensions, ext, lexer);
+        self
+    }
+
+    /// Overrides the language name `alias` to refer to `lexer`,
+    /// see [`by_name()`].
+    ///
+    /// [`by_name()`]: Self::by_name
+    pub fn alias(mut self, alias: &str, lexer: Lexer) -> Self {
+        insert(&mut self.aliases, alias.trim(), lexer);
+        self
+    }
+
+    /// Loads the overrides in `config`, see the [module documentation](self#config)
+    /// for the format. Languages are referred to by their built-in names, see
+    /// [`Lexer::by_name()`].
+    ///
+    /// Overrides in `config` replace existing overrides of
+    /// the same extensions and aliases.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Config`] if `config` is invalid, e.g. if it contains
+    /// an unknown language or section. If so, then no overrides are loaded.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use colorblast::registry::LexerRegistry;
+    /// use colorblast::Lexer;
+    ///
+    /// let config = "[extensions]\nh = rust\n";
+    /// let registry = LexerRegistry::from_config(config).unwrap();
+    /// assert_eq!(registry.by_path("lib.h"), Some(Lexer::Rust));
+    ///
+    /// let err = LexerRegistry::from_config("[extensions]\nh = unknown\n").unwrap_err();
+    /// assert_eq!(err.to_string(), "invalid config: line 2: unknown language `unknown`");
+    /// ```
+    pub fn load_config(&mut self, config: &str) -> Result<(), Error> {
+        let mut registry = self.clone();
+        let mut section = None;
+
+        for (i, line) in config.lines().enumerate() {
+            let err = |msg: String| Error::Config(format!("line {}: {msg}", i + 1));
+
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            if let Some(name) = line.strip_prefix('[').and_then(|s| s.strip_suffix(']')) {
+                section = match name.trim() {
+                    "extensions" => Some(Section::Extensions),
+                    "aliases" => Some(Section::Aliases),
+                    name => return Err(err(format!("unknown section `{name}`"))),
+                };
+                continue;
+            }
+
+            let Some((key, lang)) = line.split_once('=') else {
+                return Err(err(format!("expected `key = language`, found `{line}`")));
+            };
+            let (key, lang) = (key.trim(), lang.trim());
+            if key.is_empty() {
+                return Err(err("missing key before `=`".to_owned()));
+            }
+            let lexer =
+                Lexer::by_name(lang).map_err(|_| err(format!("unknown language `{lang}`")))?;
+
+            registry = match section {
+                Some(Section::Extensions) => registry.extension(key, lexer),
+                Some(Section::Aliases) => registry.alias(key, lexer),
+                None => return Err(err(format!("`{key}` is outside a section"))),
+            };
+        }
+
+        *self = registry;
+        Ok(())
+    }
+
+    /// Returns the [`Lexer`] for the language `name`, where aliases
+    /// overridden by [`alias()`] take precedence over [`Lexer::by_name()`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::UnknownLanguage`] if `name` is unknown.
+    ///
+    /// [`alias()`]: Self::alias
+    pub fn by_name(&self, name: &str) -> Result<Lexer, Error> {
+        match find(&self.aliases, name.trim()) {
+            Some(lexer) => Ok(lexer),
+            None => Lexer::by_name(name),
+        }
+    }
+
+    /// Returns the [`Lexer`] for the file extension `ext`, e.g. `h` or `.h`,
+    /// where extensions overridden by [`extension()`] take precedence over
+    /// [`Lexer::by_extension()`].
+    ///
+    /// [`extension()`]: Self::extension
+    pub fn by_extension(&self, ext: &str) -> Option<Lexer> {
+        let ext = ext.strip_prefix('.').unwrap_or(ext);
+        find(&self.extensions, ext).or_else(|| Lexer::by_extension(ext))
+    }
+
+    /// Returns the [`Lexer`] for the file name of `path`.
+    ///
+    /// The extensions of the file name are tried from most to least
+    /// specific, e.g. for `index.html.j2` first `html.j2` and then `j2`,
+    /// where each is looked up using [`by_extension()`]. For dotfiles, e.g.
+    /// `.htaccess`, the name after the leading `.` is used as the extension.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use colorblast::registry::LexerRegistry;
+    /// use colorblast::Lexer;
+    ///
+    /// let registry = LexerRegistry::new().extension("d.ts", Lexer::Rust);
+    ///
+    /// assert_eq!(registry.by_path("types.d.ts"), Some(Lexer::Rust));
+    /// assert_eq!(registry.by_path("app.ts"), Some(Lexer::TypeScript));
+    /// assert_eq!(registry.by_path(".htaccess"), Some(Lexer::Apache));
+    /// assert_eq!(registry.by_path("README"), None);
+    /// ```
+    ///
+    /// [`by_extension()`]: Self::by_extension
+    pub fn by_path(&self, path: impl AsRef<Path>) -> Option<Lexer> {
+        let name = path.as_ref().file_name()?.to_str()?;
+        name.match_indices('.')
+            .map(|(i, _)| &name[(i + 1)..])
+            .filter(|ext| !ext.is_empty())
+            .find_map(|ext| self.by_extension(ext))
+    }
+}
+
+#[derive(Clone, Copy)]
+enum Section {
+    Extensions,
+    Aliases,
+}
+
+fn insert(overrides: &mut Vec<(String, Lexer)>, key: &str, lexer: Lexer) {
+    let key = key.to_lowercase();
+    match overrides.iter_mut().find(|(k, _)| *k == key) {
+        Some((_, l)) => *l = lexer,
+        None => overrides.push((key, lexer)),
+    }
+}
+
+fn find(overrides: &[(String, Lexer)], key: &str) -> Option<Lexer> {
+    overrides
+        .iter()
+        .find(|(k, _)| k.eq_ignore_ascii_case(key))
+        .map(|&(_, lexer)| lexer)
+}
+
+#[cfg(all(test, feature = "all-langs"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_registry_by_path() {
+        let registry = LexerRegistry::new()
+            .extension(".RS", Lexer::Python)
+            .extension("html.j2", Lexer::Jinja)
+            .extension("j2", Lexer::PlainText);
+
+        let cases = [
+            ("src/main.rs", Some(Lexer::Python)),
+            ("src/main.RS", Some(Lexer::Python)),
+            ("index.html.j2", Some(Lexer::Jinja)),
+            ("index.txt.j2", Some(Lexer::PlainText)),
+            ("config.json", Some(Lexer::Json)),
+            ("archive.tar.json", Some(Lexer::Json)),
+            (".htaccess", Some(Lexer::Apache)),
+            ("file.", None),
+            ("Makefile", None),
+            ("", None),
+        ];
+        for (path, expected) in cases {
+            assert_eq!(registry.by_path(path), expected, "{path:?}");
+        }
+
+        assert_eq!(LexerRegistry::new().by_path("main.rs"), Some(Lexer::Rust));
+    }
+
+    #[test]
+    fn test_registry_by_name() {
+        let registry = LexerRegistry::new()
+            .alias("py", Lexer::Rust)
+            .alias("Py", Lexer::Json);
+
+        assert_eq!(registry.by_name(" PY ").ok(), Some(Lexer::Json));
+        assert_eq!(registry.by_name("python").ok(), Some(Lexer::Python));
+        assert!(matches!(
+            registry.by_name("unknown"),
+            Err(Error::UnknownLanguage(name)) if name == "unknown"
+        ));
+    }
+
+    #[test]
+    fn test_registry_load_config() {
+        let config = "
+            # Comment
+            [extensions]
+            rs = python
+            .html.j2 = Jinja
+
+            [aliases]
+            snake = python
+        ";
+        let mut registry = LexerRegistry::from_config(config).unwrap();
+        assert_eq!(registry.by_path("main.rs"), Some(Lexer::Python));
+        assert_eq!(registry.by_path("a.html.j2"), Some(Lexer::Jinja));
+        assert_eq!(registry.by_name("snake").ok(), Some(Lexer::Python));
+
+        let invalid = [
+            ("rs = rust", "line 1: `rs` is outside a section"),
+            ("[files]", "line 1: unknown section `files`"),
+            (
+                "[aliases]\nrs",
+                "line 2: expected `key = language`, found `rs`",
+            ),
+            ("[aliases]\n = rust", "line 2: missing key before `=`"),
+            ("[aliases]\nx = rust\ny = z", "line 3: unknown language `z`"),
+        ];
+        for (config, expected) in invalid {
+            let err = registry.load_config(config).unwrap_err();
+            assert_eq!(err.to_string(), format!("invalid config: {expected}"));
+        }
+
+        // Invalid configs are not partially loaded
+        assert!(registry.by_name("x").is_err());
+    }
+}
@@ -0,0 +1,181 @@
+//! Highlighting of search results on top of syntax highlighting.
+
+use std::ops::Range;
+
+use crate::{AsStyle, Style, TokenSpan};
+
+/// A query used by [`highlight_matches()`].
+#[derive(Clone, Copy, Debug)]
+pub enum Query<'a> {
+    /// Matches the string literally. An empty string matches nothing.
+    Literal(&'a str),
+    /// Matches the regex. Empty matches are ignored.
+    #[cfg(feature = "regex")]
+    Regex(&'a regex::Regex),
+}
+
+impl<'a> Query<'a> {
+    /// Returns the non-overlapping byte ranges matching `self` in `text`.
+    fn find_matches(self, text: &str) -> Vec<Range<usize>> {
+        match self {
+            Self::Literal("") => Vec::new(),
+            Self::Literal(query) => text
+                .match_indices(query)
+                .map(|(i, m)| i..(i + m.len()))
+                .collect(),
+            #[cfg(feature = "regex")]
+            Self::Regex(re) => re
+                .find_iter(text)
+                .map(|m| m.range())
+                .filter(|r| !r.is_empty())
+                .collect(),
+        }
+    }
+}
+
+impl<'a> From<&'a str> for Query<'a> {
+    #[inline]
+    fn from(query: &'a str) -> Self {
+        Self::Literal(query)
+    }
+}
+
+#[cfg(feature = "regex")]
+impl<'a> From<&'a regex::Regex> for Query<'a> {
+    #[inline]
+    fn from(re: &'a regex::Regex) -> Self {
+        Self::Regex(re)
+    }
+}
+
+/// Highlights all matches of `query` in `text`, by layering `style` on top
+/// of the style of the `tokens`, see [`Style::overlay()`].
+///
+/// The `tokens` must be spans of `text`. Tokens partially overlapping
+/// a match are split, such that only the matched part is highlighted.
+/// Matches spanning multiple tokens are supported.
+///
+/// The returned spans can be rendered using e.g. [`render_html()`]
+/// and [`render_ansi()`].
+///
+/// Enable the `regex` feature for `Query::Regex`.
+///
+/// # Example
+///
+/// ```rust
+/// use colorblast::search::highlight_matches;
+/// use colorblast::{Color, Lexer, StylizeToken, Style};
+///
+/// let code = "let foo = foo_bar;";
+/// let tokens = Lexer::Rust
+///     .into_lexer(code)
+///     .map(|(tok, span)| (tok.style(&span), span));
+///
+/// let highlight = Style::new().bg(Color::WHITE);
+/// let spans = highlight_matches(tokens, code, "foo", &highlight);
+///
+/// let matched = spans
+///     .iter()
+///     .filter(|(style, _)| style.as_ref().and_then(|s| s.bg) == Some(Color::WHITE))
+///     .map(|&(_, s)| s)
+///     .collect::<Vec<_>>();
+/// assert_eq!(matched, ["foo", "foo"]);
+/// ```
+///
+/// [`render_html()`]: crate::render_html
+/// [`render_ansi()`]: crate::render_ansi
+pub fn highlight_matches<'text, 'q, Sty, I>(
+    tokens: I,
+    text: &'text str,
+    query: impl Into<Query<'q>>,
+    style: &Style,
+) -> Vec<(Option<Style>, &'text str)>
+where
+    I: IntoIterator<Item = (Sty, TokenSpan<'text>)>,
+    Sty: AsStyle,
+{
+    let matches = query.into().find_matches(text);
+    let mut matches = matches.iter().peekable();
+
+    let mut spans = Vec::new();
+    for (sty, span) in tokens {
+        let sty = sty.as_style();
+
+        let mut start = span.start();
+        while start < span.end() {
+            // Skip matches ending before the current position
+            while matches.next_if(|m| m.end <= start).is_some() {}
+
+            let (end, matched) = match matches.peek() {
+                Some(m) if m.start <= start => (m.end.min(span.end()), true),
+                Some(m) => (m.start.min(span.end()), false),
+                None => (span.end(), false),
+            };
+
+            let sty = if matched {
+                Some(sty.clone().unwrap_or_default().overlay(style))
+            } else {
+                sty.clone()
+            };
+            spans.push((sty, &text[start..end]));
+
+            start = end;
+        }
+    }
+    spans
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Color;
+
+    fn highlight<'a>(query: impl Into<Query<'a>>) -> Vec<(&'static str, bool)> {
+        let text = "ab cd";
+        let red = Color::from([255, 0, 0]);
+        #[rustfmt::skip]
+        let tokens = [
+            (Some(red), TokenSpan::new(text, 0..2)),
+            (None, TokenSpan::new(text, 2..3)),
+            (Some(red), TokenSpan::new(text, 3..5)),
+        ];
+
+        let highlight = Style::new().bg(Color::WHITE);
+        highlight_matches(tokens, text, query, &highlight)
+            .into_iter()
+            .map(|(sty, s)| {
+                let matched = sty.as_ref().and_then(|sty| sty.bg) == Some(Color::WHITE);
+                if let Some(sty) = sty.filter(|_| s != " ") {
+                    // The original color is kept
+                    assert_eq!(sty.fg, Some(red));
+                }
+                (s, matched)
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_highlight_matches() {
+        #[rustfmt::skip]
+        let cases: [(&str, &[(&str, bool)]); 5] = [
+            ("", &[("ab", false), (" ", false), ("cd", false)]),
+            ("x", &[("ab", false), (" ", false), ("cd", false)]),
+            ("b", &[("a", false), ("b", true), (" ", false), ("cd", false)]),
+            ("b c", &[("a", false), ("b", true), (" ", true), ("c", true), ("d", false)]),
+            ("ab cd", &[("ab", true), (" ", true), ("cd", true)]),
+        ];
+
+        for (query, expected) in cases {
+            assert_eq!(highlight(query), expected, "{query:?}");
+        }
+    }
+
+    #[cfg(feature = "regex")]
+    #[test]
+    fn test_highlight_matches_regex() {
+        let re = regex::Regex::new(r"[bc]|x*").unwrap();
+        #[rustfmt::skip]
+        let expected = [("a", false), ("b", true), (" ", false), ("c", true), ("d", false)];
+        assert_eq!(highlight(&re), expected);
+    }
+}
@@ -0,0 +1,142 @@
+//! Statistics about code, e.g. counting lines of code and comments.
+
+use std::ops::AddAssign;
+
+use crate::{Lexer, LineIndex, Token};
+
+/// Line counts returned by [`count_lines()`].
+///
+/// Each line is counted exactly once, i.e. the sum of all the counts
+/// is the total amount of lines, see [`total()`](Self::total).
+#[derive(PartialEq, Eq, Clone, Copy, Default, Debug)]
+pub struct LineCounts {
+    /// Lines only containing code.
+    pub code: usize,
+    /// Lines only containing comments.
+    pub comment: usize,
+    /// Lines only containing whitespace.
+    pub blank: usize,
+    /// Lines containing both code and comments, e.g. `x = 1 # Comment`.
+    pub mixed: usize,
+}
+
+impl LineCounts {
+    /// Returns the total amount of lines.
+    #[inline]
+    pub fn total(&self) -> usize {
+        self.code + self.comment + self.blank + self.mixed
+    }
+}
+
+/// Summing the counts of multiple texts, e.g. all files in a project.
+impl AddAssign for LineCounts {
+    #[inline]
+    fn add_assign(&mut self, other: Self) {
+        self.code += other.code;
+        self.comment += other.comment;
+        self.blank += other.blank;
+        self.mixed += other.mixed;
+    }
+}
+
+/// Returns the amount of code, comment, blank, and mixed lines in `text`.
+///
+/// Lines are classified by the tokens produced by `lexer`, such that e.g.
+/// `//` inside a string is not counted as a comment. Tokens spanning
+/// multiple lines, e.g. block comments and multiline strings, count
+/// towards all lines they span, except the lines where the token
+/// only contains whitespace.
+///
+/// A trailing line break does not count as an additional line.
+///
+/// # Example
+///
+/// ```rust
+/// use colorblast::stats::{count_lines, LineCounts};
+/// use colorblast::Lexer;
+///
+/// let code = r#"
+/// // Comment
+/// fn main() {
+///     println!("// Not a comment"); // Comment
+/// }
+/// "#;
+///
+/// assert_eq!(
+///     count_lines(Lexer::Rust, code),
+///     LineCounts {
+///         code: 2,
+///         comment: 1,
+///         blank: 1,
+///         mixed: 1,
+///     }
+/// );
+/// ```
+pub fn count_lines(lexer: Lexer, text: &str) -> LineCounts {
+    let line_count = text.lines().count();
+    // Whether each line contains (code, comment)
+    let mut lines = vec![(false, false); line_count];
+
+    let index = LineIndex::new(text);
+    for (tok, span) in lexer.into_lexer(text) {
+        if tok != Token::Space {
+            let line = index.line(span.start());
+            for (i, part) in span.as_str().split('\n').enumerate() {
+                if part.trim().is_empty() {
+                    continue;
+                }
+                // Always `Some`, as `lines()` also counts text after the last line break
+                if let Some((code, comment)) = lines.get_mut(line + i) {
+                    match tok {
+                        Token::Comment => *comment = true,
+                        _ => *code = true,
+                    }
+                }
+            }
+        }
+    }
+
+    let mut counts = LineCounts::default();
+    for line in lines {
+        match line {
+            (true, true) => counts.mixed += 1,
+            (true, false) => counts.code += 1,
+            (false, true) => counts.comment += 1,
+            (false, false) => counts.blank += 1,
+        }
+    }
+    counts
+}
+
+#[cfg(all(test, feature = "all-langs"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_count_lines() {
+        #[rustfmt::skip]
+        let cases = [
+            (Lexer::Rust, "", (0, 0, 0, 0)),
+            (Lexer::Rust, "\n", (0, 0, 1, 0)),
+            (Lexer::Rust, "a", (1, 0, 0, 0)),
+            (Lexer::Rust, "a\n\n  \nb\n", (2, 0, 2, 0)),
+            (Lexer::Rust, "/* a\n\n   b */ c\r\n", (0, 1, 1, 1)),
+            (Lexer::Rust, "let s = \"a\n\n// b\";", (2, 0, 1, 0)),
+            (Lexer::Python, "# a\nb = 1  # c\n\"\"\"\nd\n\"\"\"\n", (3, 1, 0, 1)),
+            (Lexer::JsonC, "{\n  // a\n  \"b\": 1 /* c */\n}", (2, 1, 0, 1)),
+            (Lexer::Json, "{\n\n}", (2, 0, 1, 0)),
+        ];
+
+        for (lexer, text, (code, comment, blank, mixed)) in cases {
+            let expected = LineCounts {
+                code,
+                comment,
+                blank,
+                mixed,
+            };
+            let actual = count_lines(lexer, text);
+            assert_eq!(actual, expected, "{lexer:?} {text:?}");
+            assert_eq!(actual.total(), text.lines().count());
+        }
+    }
+}
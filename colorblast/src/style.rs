@@ -79,7 +79,7 @@ impl AsStyle for Option<[u8; 3]> {
     }
 }
 
-#[derive(Clone, Debug)]
+#[derive(PartialEq, Eq, Clone, Debug)]
 #[non_exhaustive]
 pub struct Style {
     pub fg: Option<Color>,
@@ -113,6 +113,25 @@ impl Style {
         self
     }
 
+    /// Returns `self` with the colors set in `top` layered on top,
+    /// i.e. colors set in `top` take precedence.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use colorblast::{Color, Style};
+    ///
+    /// let style = Style::new().fg(Color::WHITE).overlay(&Style::new().bg(Color::BLACK));
+    /// assert_eq!(style.fg, Some(Color::WHITE));
+    /// assert_eq!(style.bg, Some(Color::BLACK));
+    /// ```
+    #[inline]
+    pub fn overlay(mut self, top: &Style) -> Self {
+        self.fg = top.fg.or(self.fg);
+        self.bg = top.bg.or(self.bg);
+        self
+    }
+
     #[inline]
     pub fn invert(mut self) -> Self {
         mem::swap(&mut self.fg, &mut self.bg);
@@ -126,6 +145,66 @@ pub struct Color(pub [u8; 4]);
 impl Color {
     pub const BLACK: Self = Self([0x00, 0x00, 0x00, 0xFF]);
     pub const WHITE: Self = Self([0xFF, 0xFF, 0xFF, 0xFF]);
+
+    /// Returns the [relative luminance] of the color, in the range
+    /// `0.0` (black) to `1.0` (white). The alpha channel is ignored.
+    ///
+    /// [relative luminance]: https://www.w3.org/TR/WCAG21/#dfn-relative-luminance
+    pub fn relative_luminance(self) -> f64 {
+        let channel = |c: u8| {
+            let c = f64::from(c) / 255.0;
+            if c <= 0.03928 {
+                c / 12.92
+            } else {
+                ((c + 0.055) / 1.055).powf(2.4)
+            }
+        };
+        let [r, g, b, _a] = self.0;
+        (0.2126 * channel(r)) + (0.7152 * channel(g)) + (0.0722 * channel(b))
+    }
+
+    /// Returns the [contrast ratio] between `self` and `other`, in the
+    /// range `1.0` (same luminance) to `21.0` (black and white).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use colorblast::Color;
+    ///
+    /// assert_eq!(Color::BLACK.contrast_ratio(Color::WHITE), 21.0);
+    /// assert_eq!(Color::WHITE.contrast_ratio(Color::BLACK), 21.0);
+    /// assert_eq!(Color::WHITE.contrast_ratio(Color::WHITE), 1.0);
+    /// ```
+    ///
+    /// [contrast ratio]: https://www.w3.org/TR/WCAG21/#dfn-contrast-ratio
+    pub fn contrast_ratio(self, other: Color) -> f64 {
+        let (a, b) = (self.relative_luminance(), other.relative_luminance());
+        let (lighter, darker) = if a > b { (a, b) } else { (b, a) };
+        (lighter + 0.05) / (darker + 0.05)
+    }
+
+    /// Returns `self` mixed with `other`, where `amount` is in the range
+    /// `0.0` (only `self`) to `1.0` (only `other`). All channels are mixed,
+    /// including the alpha channel.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use colorblast::Color;
+    ///
+    /// assert_eq!(Color::BLACK.mix(Color::WHITE, 0.0), Color::BLACK);
+    /// assert_eq!(Color::BLACK.mix(Color::WHITE, 0.5), Color::from((128, 128, 128)));
+    /// assert_eq!(Color::BLACK.mix(Color::WHITE, 1.0), Color::WHITE);
+    /// ```
+    pub fn mix(self, other: Color, amount: f64) -> Color {
+        let amount = amount.clamp(0.0, 1.0);
+        let mut rgba = self.0;
+        for (a, b) in rgba.iter_mut().zip(other.0) {
+            let mixed = f64::from(*a) + (f64::from(b) - f64::from(*a)) * amount;
+            *a = mixed.round() as u8;
+        }
+        Color(rgba)
+    }
 }
 
 impl From<[u8; 4]> for Color {
@@ -1,6 +1,8 @@
 // mod rust;
+mod rules;
 
 // pub use self::rust::*;
+pub use self::rules::{RuledToken, StyleRules, StyleRulesBuilder};
 
 use any_lexer::TokenSpan;
 
@@ -8,4 +10,162 @@ use crate::style::Style;
 
 pub trait StylizeToken {
     fn style(&self, span: &TokenSpan<'_>) -> Style;
+
+    /// Returns `true` if [`style()`] depends on the `span` and not only
+    /// on the token kind, e.g. styling `TODO` comments differently.
+    ///
+    /// If `false`, then [`StyleResolver`] resolves the style once
+    /// per token kind, instead of once per token.
+    ///
+    /// Defaults to `true`.
+    ///
+    /// [`style()`]: Self::style
+    #[inline]
+    fn depends_on_span(&self) -> bool {
+        true
+    }
+}
+
+/// Memoized [`StylizeToken::style()`], resolving the style of each token
+/// kind once, such that subsequent lookups avoid calling `style()`.
+///
+/// Tokens where [`StylizeToken::depends_on_span()`] is `true`
+/// are always resolved and never cached.
+///
+/// This is only beneficial when `style()` is more expensive than a few
+/// comparisons, e.g. when styles are looked up by name in a theme loaded
+/// at runtime. [`Token`]'s `style()` is a plain `match`, which is faster
+/// than the cache, see `benches/style_resolver.rs`.
+///
+/// [`Token`]: crate::Token
+///
+/// # Example
+///
+/// ```rust
+/// use colorblast::{render_html, Lexer, StyleResolver};
+///
+/// let code = "fn main() {}";
+/// let mut resolver = StyleResolver::new();
+/// let tokens = resolver
+///     .resolve_all(Lexer::Rust.into_lexer(code))
+///     .map(|(style, span)| (style, span.as_str()));
+/// let html = render_html(tokens);
+/// ```
+#[derive(Clone, Debug)]
+pub struct StyleResolver<Tok> {
+    /// Token kinds are few, so a linear search outperforms hashing.
+    cache: Vec<(Tok, Style)>,
+}
+
+impl<Tok> StyleResolver<Tok>
+where
+    Tok: StylizeToken + Copy + Eq,
+{
+    #[inline]
+    pub fn new() -> Self {
+        Self { cache: Vec::new() }
+    }
+
+    /// Returns the style of `tok`, see [`StylizeToken::style()`].
+    pub fn resolve(&mut self, tok: Tok, span: &TokenSpan<'_>) -> Style {
+        if tok.depends_on_span() {
+            return tok.style(span);
+        }
+        if let Some((_tok, style)) = self.cache.iter().find(|(t, _style)| *t == tok) {
+            return style.clone();
+        }
+        let style = tok.style(span);
+        self.cache.push((tok, style.clone()));
+        style
+    }
+
+    /// Returns `tokens` with each token replaced by its style,
+    /// see [`resolve()`](Self::resolve).
+    pub fn resolve_all<'a, 'text, I>(
+        &'a mut self,
+        tokens: I,
+    ) -> impl Iterator<Item = (Style, TokenSpan<'text>)> + 'a
+    where
+        I: IntoIterator<Item = (Tok, TokenSpan<'text>)>,
+        I::IntoIter: 'a,
+    {
+        tokens
+            .into_iter()
+            .map(move |(tok, span)| (self.resolve(tok, &span), span))
+    }
+
+    /// Removes all cached styles.
+    #[inline]
+    pub fn clear(&mut self) {
+        self.cache.clear();
+    }
+}
+
+impl<Tok> Default for StyleResolver<Tok>
+where
+    Tok: StylizeToken + Copy + Eq,
+{
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(all(test, feature = "lang-rust"))]
+mod tests {
+    use std::cell::Cell;
+
+    use super::*;
+    use crate::{Lexer, Token};
+
+    #[test]
+    fn test_style_resolver() {
+        let code = "fn main() { let x = 1 + 2; }";
+        let mut resolver = StyleResolver::new();
+        let resolved = resolver
+            .resolve_all(Lexer::Rust.into_lexer(code))
+            .map(|(style, span)| (style.fg, style.bg, span.range()))
+            .collect::<Vec<_>>();
+        let expected = Lexer::Rust
+            .into_lexer(code)
+            .map(|(tok, span)| {
+                let style = tok.style(&span);
+                (style.fg, style.bg, span.range())
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(resolved, expected);
+    }
+
+    #[test]
+    fn test_style_resolver_cache() {
+        thread_local! {
+            static CALLS: Cell<usize> = const { Cell::new(0) };
+        }
+
+        #[derive(PartialEq, Eq, Clone, Copy, Debug)]
+        struct Tok(Token, bool);
+
+        impl StylizeToken for Tok {
+            fn style(&self, span: &TokenSpan<'_>) -> Style {
+                CALLS.with(|calls| calls.set(calls.get() + 1));
+                self.0.style(span)
+            }
+
+            fn depends_on_span(&self) -> bool {
+                self.1
+            }
+        }
+
+        let code = "fn main() { let x = 1 + 2; }";
+        let count = Lexer::Rust.into_lexer(code).count();
+
+        for (depends_on_span, expected) in [(false, 1), (true, count)] {
+            CALLS.with(|calls| calls.set(0));
+            let tokens = Lexer::Rust
+                .into_lexer(code)
+                .map(|(_tok, span)| (Tok(Token::Text, depends_on_span), span));
+            StyleResolver::new().resolve_all(tokens).for_each(drop);
+            assert_eq!(CALLS.with(Cell::get), expected);
+        }
+    }
 }
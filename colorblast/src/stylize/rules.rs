@@ -0,0 +1,184 @@
+use any_lexer::TokenSpan;
+
+use crate::style::{Color, Style};
+use crate::stylize::StylizeToken;
+use crate::Token;
+
+/// Styles of [`Token`]s, for tweaking the colors of a few token kinds,
+/// without implementing [`StylizeToken`]. Token kinds without a rule
+/// use their default style, see [`Theme::default()`].
+///
+/// Use [`StyleRules::builder()`] to create the rules, and [`stylize()`]
+/// to pair tokens with the rules, such that they implement
+/// [`StylizeToken`].
+///
+/// For more control, e.g. per-language styles, see [`Theme`].
+///
+/// # Example
+///
+/// ```rust
+/// use colorblast::{println_stylized_tokens, Lexer, StyleRules};
+///
+/// let rules = StyleRules::builder()
+///     .keyword((255, 128, 0))
+///     .string((0, 200, 0))
+///     .build();
+///
+/// let code = "fn main() { println!(\"Hello World\"); }";
+/// println_stylized_tokens(rules.stylize(Lexer::Rust.into_lexer(code)));
+/// ```
+///
+/// [`stylize()`]: Self::stylize
+/// [`Theme`]: crate::Theme
+/// [`Theme::default()`]: crate::Theme::default
+#[derive(Clone, Default, Debug)]
+pub struct StyleRules {
+    styles: Vec<(Token, Style)>,
+}
+
+impl StyleRules {
+    #[inline]
+    pub fn builder() -> StyleRulesBuilder {
+        StyleRulesBuilder::new()
+    }
+
+    /// Returns the style of `tok`, falling back to its default style.
+    pub fn style(&self, tok: Token) -> Style {
+        self.styles
+            .iter()
+            .find(|(t, _style)| *t == tok)
+            .map(|(_tok, style)| style.clone())
+            .unwrap_or_else(|| tok.default_style())
+    }
+
+    /// Returns `tokens` with each token paired with these rules,
+    /// such that they can be passed to e.g. [`print_stylized_tokens()`].
+    ///
+    /// [`print_stylized_tokens()`]: crate::print_stylized_tokens
+    pub fn stylize<'a, 'text, I>(
+        &'a self,
+        tokens: I,
+    ) -> impl Iterator<Item = (RuledToken<'a>, TokenSpan<'text>)> + 'a
+    where
+        I: IntoIterator<Item = (Token, TokenSpan<'text>)>,
+        I::IntoIter: 'a,
+    {
+        tokens
+            .into_iter()
+            .map(move |(tok, span)| (RuledToken { tok, rules: self }, span))
+    }
+}
+
+/// Builder for [`StyleRules`], see [`StyleRules::builder()`].
+///
+/// The methods named after token kinds set the foreground color of
+/// that token kind, e.g. [`keyword()`] for [`Token::Keyword`]. Use
+/// [`token()`] for any other token kind, or for setting a whole [`Style`].
+///
+/// [`keyword()`]: Self::keyword
+/// [`token()`]: Self::token
+#[derive(Clone, Default, Debug)]
+#[must_use]
+pub struct StyleRulesBuilder {
+    rules: StyleRules,
+}
+
+macro_rules! impl_token_rules {
+    ($($(#[$attr:meta])* $method:ident => $tok:ident,)+) => {
+        $(
+            $(#[$attr])*
+            #[inline]
+            pub fn $method(self, color: impl Into<Color>) -> Self {
+                self.token(Token::$tok, Style::new().fg(color))
+            }
+        )+
+    };
+}
+
+impl StyleRulesBuilder {
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the `style` of `tok`, replacing any previous rule for `tok`.
+    pub fn token(mut self, tok: Token, style: Style) -> Self {
+        let styles = &mut self.rules.styles;
+        match styles.iter_mut().find(|(t, _style)| *t == tok) {
+            Some((_tok, s)) => *s = style,
+            None => styles.push((tok, style)),
+        }
+        self
+    }
+
+    impl_token_rules!(
+        text => Text,
+        comment => Comment,
+        keyword => Keyword,
+        /// Sets the color of [`Token::Keyword2`], e.g. control flow
+        /// keywords such as `if` and `return`.
+        keyword2 => Keyword2,
+        primitive_type => PrimitiveType,
+        var => Var,
+        number => Number,
+        string => String,
+        operator => Operator,
+        delimiter => Delimiter,
+        meta => Meta,
+        invalid => Invalid,
+    );
+
+    #[inline]
+    pub fn build(self) -> StyleRules {
+        self.rules
+    }
+}
+
+/// [`Token`] paired with [`StyleRules`], see [`StyleRules::stylize()`].
+#[derive(Clone, Copy, Debug)]
+pub struct RuledToken<'a> {
+    pub tok: Token,
+    pub rules: &'a StyleRules,
+}
+
+impl StylizeToken for RuledToken<'_> {
+    #[inline]
+    fn style(&self, _span: &TokenSpan<'_>) -> Style {
+        self.rules.style(self.tok)
+    }
+
+    #[inline]
+    fn depends_on_span(&self) -> bool {
+        false
+    }
+}
+
+#[cfg(all(test, feature = "lang-rust"))]
+mod tests {
+    use super::*;
+    use crate::Lexer;
+
+    #[test]
+    fn test_style_rules() {
+        let red = Color::from((255, 0, 0));
+        let rules = StyleRules::builder()
+            .keyword((0, 0, 255))
+            .keyword(red)
+            .token(Token::Number, Style::new().bg(red))
+            .build();
+
+        let code = "fn main() { let x = 1; }";
+        for (tok, span) in rules.stylize(Lexer::Rust.into_lexer(code)) {
+            let style = tok.style(&span);
+            let expected = match tok.tok {
+                Token::Keyword => (Some(red), None),
+                Token::Number => (None, Some(red)),
+                _ => {
+                    let style = tok.tok.default_style();
+                    (style.fg, style.bg)
+                }
+            };
+            assert_eq!((style.fg, style.bg), expected, "{:?}", span.as_str());
+        }
+    }
+}
@@ -0,0 +1,294 @@
+//! Extraction of symbols, e.g. functions and headings, for outline views.
+//!
+//! The symbols are found using per-language heuristics on the tokens
+//! produced by the lexers, e.g. `fn` followed by an identifier in Rust.
+//! Thereby, this is not a parser, and the symbols are not guaranteed
+//! to be complete or correct for all inputs.
+
+use std::ops::Range;
+
+#[cfg(any(feature = "lang-javascript", feature = "lang-typescript"))]
+use any_lexer::JavaScriptToken;
+#[cfg(feature = "lang-markdown")]
+use any_lexer::MarkdownToken;
+#[cfg(feature = "lang-python")]
+use any_lexer::PythonToken;
+#[cfg(feature = "lang-rust")]
+use any_lexer::RustToken;
+use any_lexer::TokenSpan;
+
+use crate::Lexer;
+
+/// A symbol found by [`extract_symbols()`].
+#[derive(PartialEq, Eq, Clone, Debug)]
+pub struct Symbol<'text> {
+    /// The name of the symbol, e.g. the function name or the heading text.
+    pub name: &'text str,
+    pub kind: SymbolKind,
+    /// The byte range of [`name`](Self::name) in the text.
+    pub range: Range<usize>,
+}
+
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+#[non_exhaustive]
+pub enum SymbolKind {
+    Function,
+    Class,
+    Struct,
+    Enum,
+    Trait,
+    Interface,
+    Module,
+    Macro,
+    /// Type alias, e.g. `type Foo = Bar;`.
+    Type,
+    Constant,
+    Static,
+    /// Markdown heading, where `level` is the amount of `#`s.
+    Heading {
+        level: u8,
+    },
+}
+
+/// Returns the symbols found in `text`, in the order they occur.
+///
+/// Symbols are extracted for [Rust], [Python], [JavaScript], [TypeScript],
+/// [Tsx], and [Markdown]. For all other languages, no symbols are returned.
+///
+/// # Example
+///
+/// ```rust
+/// use colorblast::symbols::{extract_symbols, SymbolKind};
+/// use colorblast::Lexer;
+///
+/// let code = "struct Foo;\n\nfn bar() {}\n";
+/// let symbols = extract_symbols(Lexer::Rust, code)
+///     .into_iter()
+///     .map(|sym| (sym.name, sym.kind))
+///     .collect::<Vec<_>>();
+///
+/// assert_eq!(symbols, [("Foo", SymbolKind::Struct), ("bar", SymbolKind::Function)]);
+/// ```
+///
+/// [Rust]: Lexer::Rust
+/// [Python]: Lexer::Python
+/// [JavaScript]: Lexer::JavaScript
+/// [TypeScript]: Lexer::TypeScript
+/// [Tsx]: Lexer::Tsx
+/// [Markdown]: Lexer::Markdown
+// `text` is unused if all supported languages are disabled
+#[allow(unused_variables)]
+pub fn extract_symbols(lexer: Lexer, text: &str) -> Vec<Symbol<'_>> {
+    match lexer {
+        #[cfg(feature = "lang-rust")]
+        Lexer::Rust => rust_symbols(text),
+        #[cfg(feature = "lang-python")]
+        Lexer::Python => python_symbols(text),
+        #[cfg(feature = "lang-javascript")]
+        Lexer::JavaScript => javascript_symbols(any_lexer::JavaScriptLexer::new(text)),
+        #[cfg(feature = "lang-typescript")]
+        Lexer::TypeScript => javascript_symbols(
+            any_lexer::JavaScriptLexer::new(text)
+                .jsx(false)
+                .typescript(true),
+        ),
+        #[cfg(feature = "lang-typescript")]
+        Lexer::Tsx => javascript_symbols(any_lexer::JavaScriptLexer::new(text).typescript(true)),
+        #[cfg(feature = "lang-markdown")]
+        Lexer::Markdown => markdown_symbols(text),
+        _ => Vec::new(),
+    }
+}
+
+#[cfg(feature = "lang-rust")]
+fn rust_symbols(text: &str) -> Vec<Symbol<'_>> {
+    let tokens = any_lexer::RustLexer::new(text).filter(|(tok, _span)| {
+        !matches!(
+            tok,
+            RustToken::Space | RustToken::LineComment | RustToken::BlockComment
+        )
+    });
+    scan_symbols(
+        tokens,
+        |tok, s| match (tok, s) {
+            (RustToken::Keyword, "fn") => Some(SymbolKind::Function),
+            (RustToken::Keyword, "struct" | "union") => Some(SymbolKind::Struct),
+            (RustToken::Keyword, "enum") => Some(SymbolKind::Enum),
+            (RustToken::Keyword, "trait") => Some(SymbolKind::Trait),
+            (RustToken::Keyword, "mod") => Some(SymbolKind::Module),
+            (RustToken::Keyword, "type") => Some(SymbolKind::Type),
+            (RustToken::Keyword, "const") => Some(SymbolKind::Constant),
+            (RustToken::Keyword, "static") => Some(SymbolKind::Static),
+            (RustToken::Keyword, "macro_rules") => Some(SymbolKind::Macro),
+            _ => None,
+        },
+        |tok| tok == RustToken::Ident,
+        // `macro_rules! name` and `static mut NAME`
+        |_tok, s| matches!(s, "!" | "mut"),
+    )
+}
+
+#[cfg(feature = "lang-python")]
+fn python_symbols(text: &str) -> Vec<Symbol<'_>> {
+    let tokens = any_lexer::PythonLexer::new(text)
+        .filter(|(tok, _span)| !matches!(tok, PythonToken::Space | PythonToken::LineComment));
+    scan_symbols(
+        tokens,
+        |tok, s| match (tok, s) {
+            (PythonToken::Keyword, "def") => Some(SymbolKind::Function),
+            (PythonToken::Keyword, "class") => Some(SymbolKind::Class),
+            _ => None,
+        },
+        |tok| tok == PythonToken::Ident,
+        |_tok, _s| false,
+    )
+}
+
+#[cfg(any(feature = "lang-javascript", feature = "lang-typescript"))]
+fn javascript_symbols(lexer: any_lexer::JavaScriptLexer<'_>) -> Vec<Symbol<'_>> {
+    let tokens = lexer.filter(|(tok, _span)| {
+        !matches!(
+            tok,
+            JavaScriptToken::Space | JavaScriptToken::LineComment | JavaScriptToken::BlockComment
+        )
+    });
+    scan_symbols(
+        tokens,
+        |tok, s| match (tok, s) {
+            (JavaScriptToken::Keyword, "function") => Some(SymbolKind::Function),
+            (JavaScriptToken::Keyword, "class") => Some(SymbolKind::Class),
+            (JavaScriptToken::Keyword, "enum") => Some(SymbolKind::Enum),
+            (JavaScriptToken::Keyword, "interface") => Some(SymbolKind::Interface),
+            (JavaScriptToken::Keyword, "namespace" | "module") => Some(SymbolKind::Module),
+            (JavaScriptToken::Keyword, "type") => Some(SymbolKind::Type),
+            _ => None,
+        },
+        |tok| tok == JavaScriptToken::Ident,
+        // Generator functions, i.e. `function* name`
+        |tok, s| tok == JavaScriptToken::Punct && s == "*",
+    )
+}
+
+#[cfg(feature = "lang-markdown")]
+fn markdown_symbols(text: &str) -> Vec<Symbol<'_>> {
+    any_lexer::MarkdownLexer::new(text)
+        .filter(|(tok, _span)| *tok == MarkdownToken::Heading)
+        .filter_map(|(_tok, span)| {
+            let heading = span.as_str();
+            let hashes = heading.trim_start();
+            let after_hashes = hashes.trim_start_matches('#');
+            let level = (hashes.len() - after_hashes.len()) as u8;
+
+            // Exclude the optional closing sequence, e.g. `# Heading #`
+            let name = after_hashes.trim_end().trim_end_matches('#');
+            let name = if name.ends_with([' ', '\t']) || name.is_empty() {
+                name
+            } else {
+                after_hashes.trim_end()
+            };
+            let name = name.trim();
+            if name.is_empty() {
+                return None;
+            }
+
+            let start = span.start() + (name.as_ptr() as usize - heading.as_ptr() as usize);
+            Some(Symbol {
+                name,
+                kind: SymbolKind::Heading { level },
+                range: start..(start + name.len()),
+            })
+        })
+        .collect()
+}
+
+/// Returns a symbol for each name token following a token, for which
+/// `symbol_kind` returns `Some`. Tokens for which `is_skipped` returns
+/// `true` are allowed between the two.
+#[allow(dead_code)]
+fn scan_symbols<'text, Tok, I>(
+    tokens: I,
+    symbol_kind: impl Fn(Tok, &str) -> Option<SymbolKind>,
+    is_name: impl Fn(Tok) -> bool,
+    is_skipped: impl Fn(Tok, &str) -> bool,
+) -> Vec<Symbol<'text>>
+where
+    I: Iterator<Item = (Tok, TokenSpan<'text>)>,
+    Tok: Copy,
+{
+    let mut symbols = Vec::new();
+    let mut pending = None;
+
+    for (tok, span) in tokens {
+        if let Some(kind) = pending {
+            if is_name(tok) {
+                symbols.push(Symbol {
+                    name: span.as_str(),
+                    kind,
+                    range: span.range(),
+                });
+                pending = None;
+                continue;
+            } else if is_skipped(tok, span.as_str()) {
+                continue;
+            }
+        }
+
+        pending = symbol_kind(tok, span.as_str());
+    }
+
+    symbols
+}
+
+#[cfg(all(test, feature = "all-langs"))]
+mod tests {
+    use super::*;
+
+    fn symbols(lexer: Lexer, text: &str) -> Vec<(&str, SymbolKind)> {
+        extract_symbols(lexer, text)
+            .into_iter()
+            .map(|sym| {
+                assert_eq!(&text[sym.range.clone()], sym.name);
+                (sym.name, sym.kind)
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_extract_symbols() {
+        use SymbolKind::*;
+
+        #[rustfmt::skip]
+        let cases = [
+            (
+                Lexer::Rust,
+                "mod a { pub const fn b() {} }\nstatic mut C: u8 = 0;\nmacro_rules! d {}\nimpl E { type F = G; }\nlet fn_ = 1;",
+                &[("a", Module), ("b", Function), ("C", Static), ("d", Macro), ("F", Type)][..],
+            ),
+            (
+                Lexer::Python,
+                "class A:\n    async def b(self):\n        pass\n# def c\n",
+                &[("A", Class), ("b", Function)][..],
+            ),
+            (
+                Lexer::JavaScript,
+                "export function* a() {}\nclass B extends C {}\nconst d = function () {};",
+                &[("a", Function), ("B", Class)][..],
+            ),
+            (
+                Lexer::TypeScript,
+                "interface A {}\ntype B = A;\nenum C {}\nlet type = 1;",
+                &[("A", Interface), ("B", Type), ("C", Enum)][..],
+            ),
+            (
+                Lexer::Markdown,
+                "# A\n\n## B ##\n\n```\n# c\n```\n### D#\n#\n",
+                &[("A", Heading { level: 1 }), ("B", Heading { level: 2 }), ("D#", Heading { level: 3 })][..],
+            ),
+            (Lexer::Json, "{\"fn\": 1}", &[][..]),
+        ];
+
+        for (lexer, text, expected) in cases {
+            assert_eq!(symbols(lexer, text), expected, "{lexer:?}");
+        }
+    }
+}
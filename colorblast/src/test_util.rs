@@ -0,0 +1,246 @@
+//! Test utilities for downstream crates, e.g. implementing custom
+//! [`ScanToken`]s or [`StylizeToken`]s.
+//!
+//! - [`assert_tokens!`] asserts the tokens produced by a lexer
+//! - [`assert_highlight_snapshot!`] asserts highlighted ANSI output
+//!   against a golden snapshot file
+//!
+//! Snapshot files are created or updated by running the tests with the
+//! `COLORBLAST_BLESS` environment variable set:
+//!
+//! ```text
+//! COLORBLAST_BLESS=1 cargo test
+//! ```
+//!
+//! [`ScanToken`]: any_lexer::ScanToken
+//! [`assert_tokens!`]: crate::assert_tokens
+//! [`assert_highlight_snapshot!`]: crate::assert_highlight_snapshot
+
+use std::env;
+use std::fmt::{self, Write};
+use std::fs;
+use std::path::Path;
+
+use any_lexer::TokenSpan;
+
+use crate::ansi::{render_ansi, AnsiOptions, ColorChoice, ColorDepth, Overflow};
+use crate::StylizeToken;
+
+/// Environment variable, which when set, makes
+/// [`assert_highlight_snapshot!`](crate::assert_highlight_snapshot)
+/// write snapshot files instead of comparing against them.
+pub const BLESS_ENV: &str = "COLORBLAST_BLESS";
+
+/// Asserts that a lexer produces the expected tokens, given as
+/// `(token, text)` pairs.
+///
+/// On failure, the first differing token is reported,
+/// along with both token streams.
+///
+/// # Example
+///
+/// ```rust
+/// use any_lexer::{JsonLexer, JsonToken};
+/// use colorblast::assert_tokens;
+///
+/// assert_tokens!(
+///     JsonLexer::new("[1, true]"),
+///     [
+///         (JsonToken::Delim, "["),
+///         (JsonToken::Number, "1"),
+///         (JsonToken::Punct, ","),
+///         (JsonToken::Space, " "),
+///         (JsonToken::True, "true"),
+///         (JsonToken::Delim, "]"),
+///     ]
+/// );
+/// ```
+#[macro_export]
+macro_rules! assert_tokens {
+    ($tokens:expr, $expected:expr $(,)?) => {
+        $crate::test_util::assert_tokens($tokens, $expected)
+    };
+}
+
+/// Asserts that highlighting tokens into ANSI matches the snapshot file
+/// at a path relative to the crate root, i.e. `CARGO_MANIFEST_DIR`.
+///
+/// The tokens are rendered with [`ColorChoice::Always`] and
+/// [`ColorDepth::TrueColor`], such that snapshots do not depend
+/// on the terminal running the tests.
+///
+/// If the [`BLESS_ENV`] environment variable is set, then the snapshot
+/// file is written instead.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use colorblast::{assert_highlight_snapshot, Lexer};
+///
+/// let code = "fn main() {}";
+/// assert_highlight_snapshot!(Lexer::Rust.into_lexer(code), "tests/snapshots/main.ansi");
+/// ```
+///
+/// [`ColorChoice::Always`]: crate::ColorChoice::Always
+/// [`ColorDepth::TrueColor`]: crate::ColorDepth::TrueColor
+/// [`BLESS_ENV`]: crate::test_util::BLESS_ENV
+#[macro_export]
+macro_rules! assert_highlight_snapshot {
+    ($tokens:expr, $path:expr $(,)?) => {
+        $crate::test_util::assert_highlight_snapshot(
+            $tokens,
+            ::std::path::Path::new(env!("CARGO_MANIFEST_DIR")).join($path),
+        )
+    };
+}
+
+/// See [`assert_tokens!`](crate::assert_tokens).
+#[track_caller]
+pub fn assert_tokens<'text, Tok, I>(tokens: I, expected: impl AsRef<[(Tok, &'text str)]>)
+where
+    I: IntoIterator<Item = (Tok, TokenSpan<'text>)>,
+    Tok: PartialEq + fmt::Debug,
+{
+    let actual = tokens
+        .into_iter()
+        .map(|(tok, span)| (tok, span.as_str()))
+        .collect::<Vec<_>>();
+    let expected = expected.as_ref();
+
+    let Some(i) = first_difference(&actual, expected) else {
+        return;
+    };
+
+    panic!(
+        "tokens differ at index {i}\n\nexpected:\n{}\nactual:\n{}",
+        list_tokens(expected),
+        list_tokens(&actual),
+    );
+}
+
+/// See [`assert_highlight_snapshot!`](crate::assert_highlight_snapshot).
+#[track_caller]
+pub fn assert_highlight_snapshot<'text, Tok, I>(tokens: I, path: impl AsRef<Path>)
+where
+    I: IntoIterator<Item = (Tok, TokenSpan<'text>)>,
+    Tok: StylizeToken,
+{
+    let path = path.as_ref();
+
+    let options = AnsiOptions::new()
+        .color(ColorChoice::Always)
+        .color_depth(ColorDepth::TrueColor)
+        .overflow(Overflow::Visible);
+    let tokens = tokens
+        .into_iter()
+        .map(|(tok, span)| (tok.style(&span), span.as_str()));
+    let actual = render_ansi(tokens, &options);
+
+    if env::var_os(BLESS_ENV).is_some() {
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir)
+                .unwrap_or_else(|err| panic!("failed creating `{}`: {err}", dir.display()));
+        }
+        fs::write(path, &actual)
+            .unwrap_or_else(|err| panic!("failed writing `{}`: {err}", path.display()));
+        return;
+    }
+
+    let expected = match fs::read_to_string(path) {
+        // Snapshots checked out with `\r\n` line endings still compare equal
+        Ok(expected) => expected.replace("\r\n", "\n"),
+        Err(err) => panic!(
+            "failed reading snapshot `{}`: {err}\nrerun with `{BLESS_ENV}=1` to create it",
+            path.display()
+        ),
+    };
+    if actual == expected {
+        return;
+    }
+
+    let expected_lines = expected.lines().collect::<Vec<_>>();
+    let actual_lines = actual.lines().collect::<Vec<_>>();
+    let line = first_difference(&actual_lines, &expected_lines).unwrap_or(0);
+
+    panic!(
+        "output differs from snapshot `{}` at line {}, rerun with `{BLESS_ENV}=1` if intended\n\nexpected: {:?}\n  actual: {:?}",
+        path.display(),
+        line + 1,
+        expected_lines.get(line).copied().unwrap_or_default(),
+        actual_lines.get(line).copied().unwrap_or_default(),
+    );
+}
+
+/// Returns the index of the first differing element, where a missing
+/// element in the shorter slice counts as a difference.
+fn first_difference<T: PartialEq>(a: &[T], b: &[T]) -> Option<usize> {
+    match a.iter().zip(b).position(|(a, b)| a != b) {
+        Some(i) => Some(i),
+        None if a.len() != b.len() => Some(a.len().min(b.len())),
+        None => None,
+    }
+}
+
+fn list_tokens<Tok: fmt::Debug>(tokens: &[(Tok, &str)]) -> String {
+    let mut list = String::new();
+    for (i, (tok, text)) in tokens.iter().enumerate() {
+        // Writing to a `String` never fails
+        _ = writeln!(list, "{i:>4}: {tok:?} {text:?}");
+    }
+    list
+}
+
+#[cfg(all(test, feature = "lang-rust"))]
+mod tests {
+    use any_lexer::{RustLexer, RustToken};
+
+    use super::*;
+    use crate::Lexer;
+
+    #[test]
+    fn test_assert_tokens() {
+        assert_tokens!(
+            RustLexer::new("let x"),
+            [
+                (RustToken::Keyword, "let"),
+                (RustToken::Space, " "),
+                (RustToken::Ident, "x"),
+            ],
+        );
+        assert_tokens!(RustLexer::new(""), &[] as &[(RustToken, &str)]);
+    }
+
+    #[test]
+    #[should_panic(expected = "tokens differ at index 2")]
+    fn test_assert_tokens_missing() {
+        assert_tokens!(
+            RustLexer::new("let x"),
+            [(RustToken::Keyword, "let"), (RustToken::Space, " ")],
+        );
+    }
+
+    #[test]
+    fn test_assert_highlight_snapshot() {
+        let path =
+            env::temp_dir().join(format!("colorblast-test-util-{}.ansi", std::process::id()));
+
+        let code = "fn main() {}";
+        let tokens = Lexer::Rust.into_lexer(code);
+        let ansi = render_ansi(
+            tokens.map(|(tok, span)| (tok.style(&span), span.as_str())),
+            &AnsiOptions::new()
+                .color(ColorChoice::Always)
+                .color_depth(ColorDepth::TrueColor),
+        );
+        fs::write(&path, ansi).unwrap();
+
+        assert_highlight_snapshot(Lexer::Rust.into_lexer(code), &path);
+
+        let result = std::panic::catch_unwind(|| {
+            assert_highlight_snapshot(Lexer::Rust.into_lexer("fn other() {}"), &path);
+        });
+        _ = fs::remove_file(&path);
+
+        assert!(result.is_err());
+    }
+}
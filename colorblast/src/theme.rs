@@ -0,0 +1,749 @@
+use crate::html::{push_css_color, push_css_variable_name};
+use crate::{Color, Error, Lexer, Style, Token, TokenSpan};
+
+/// Mapping of [`Token`]s to [`Style`]s, with optional per-language
+/// overrides, e.g. a different [`Token::String`] color in JSON.
+///
+/// A theme only needs to contain the styles it changes, such that it can
+/// be layered on top of another theme using [`with_overrides()`]. Tokens
+/// without a style in the theme are unstyled, i.e. [`Style::NONE`].
+///
+/// Styles are kept in the order of [`Token::VARIANTS`] and
+/// [`Lexer::VARIANTS`], regardless of the order they are set in. As such,
+/// themes containing the same styles produce the same output, e.g. for
+/// [`check_contrast()`] and [`to_css_variables()`], across runs
+/// and platforms.
+///
+/// [`Theme::default()`] is the theme used by [`StylizeToken`] for [`Token`].
+///
+/// # Example
+///
+/// ```rust
+/// use colorblast::{render_html, Lexer, Style, Theme, Token};
+///
+/// let overrides = Theme::new()
+///     .token(Token::Comment, Style::new().fg((128, 128, 128)))
+///     .language(Lexer::Json, Token::String, Style::new().fg((255, 0, 0)));
+/// let theme = Theme::with_overrides(&Theme::default(), &overrides);
+///
+/// assert_eq!(theme.style(Token::Comment).fg, Some((128, 128, 128).into()));
+/// assert_eq!(theme.style(Token::Number).fg, Some((181, 206, 168).into()));
+/// assert_eq!(theme.style(Token::String).fg, Some((206, 145, 120).into()));
+///
+/// let json = theme.style_for(Lexer::Json, Token::String);
+/// assert_eq!(json.fg, Some((255, 0, 0).into()));
+///
+/// let tokens = theme.highlight(Lexer::Json, r#"{"a": 1}"#);
+/// let html = render_html(tokens.map(|(style, span)| (style, span.as_str())));
+/// ```
+///
+/// [`with_overrides()`]: Self::with_overrides
+/// [`check_contrast()`]: Self::check_contrast
+/// [`to_css_variables()`]: Self::to_css_variables
+/// [`StylizeToken`]: crate::StylizeToken
+#[derive(Clone, Debug)]
+pub struct Theme {
+    styles: Vec<(Token, Style)>,
+    languages: Vec<(Lexer, Token, Style)>,
+    background: Option<Color>,
+}
+
+impl Theme {
+    /// Theme without any styles, e.g. for building overrides.
+    pub const EMPTY: Self = Self {
+        styles: Vec::new(),
+        languages: Vec::new(),
+        background: None,
+    };
+
+    /// Names of the built-in themes, accepted by [`by_name()`].
+    ///
+    /// [`by_name()`]: Self::by_name
+    pub const BUILTIN: &'static [&'static str] = &["default", "high-contrast"];
+
+    /// Minimum [contrast ratio] for normal text to pass [WCAG AA],
+    /// used by [`check_contrast()`].
+    ///
+    /// [contrast ratio]: Color::contrast_ratio
+    /// [WCAG AA]: https://www.w3.org/TR/WCAG21/#contrast-minimum
+    /// [`check_contrast()`]: Self::check_contrast
+    pub const MIN_CONTRAST_RATIO: f64 = 4.5;
+
+    /// Returns [`Theme::EMPTY`], see also [`Theme::default()`].
+    #[inline]
+    pub fn new() -> Self {
+        Self::EMPTY
+    }
+
+    /// Returns the built-in theme with the given `name`,
+    /// see [`Theme::BUILTIN`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::UnknownTheme`] if no built-in theme
+    /// is named `name`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use colorblast::{Color, Theme};
+    ///
+    /// for name in Theme::BUILTIN {
+    ///     assert!(Theme::by_name(name).is_ok());
+    /// }
+    ///
+    /// let theme = Theme::by_name("high-contrast").unwrap();
+    /// assert_eq!(theme.background_color(), Some(Color::BLACK));
+    ///
+    /// assert!(Theme::by_name("unknown").is_err());
+    /// ```
+    pub fn by_name(name: &str) -> Result<Self, Error> {
+        match name.trim() {
+            "default" => Ok(Self::default()),
+            "high-contrast" => Ok(Self::high_contrast()),
+            _ => Err(Error::UnknownTheme(name.to_owned())),
+        }
+    }
+
+    /// High-contrast dark theme for a black background.
+    ///
+    /// All colors have a contrast ratio of at least 7:1 against black,
+    /// i.e. they pass WCAG AAA. Token kinds are distinguished by hue
+    /// families that remain distinct with the common forms of color
+    /// blindness, i.e. the theme does not rely on red versus green.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use colorblast::{Color, Theme};
+    ///
+    /// let theme = Theme::high_contrast();
+    /// assert!(theme.check_contrast(Color::BLACK).is_empty());
+    /// ```
+    pub fn high_contrast() -> Self {
+        const WHITE: (u8, u8, u8) = (255, 255, 255);
+        let theme = Self::new().background(Color::BLACK);
+        Token::VARIANTS.iter().fold(theme, |theme, &tok| {
+            let style = match tok {
+                Token::Space | Token::Text | Token::Operator | Token::Delimiter => {
+                    Style::new().fg(WHITE)
+                }
+                Token::Meta => Style::new().fg((220, 220, 220)),
+                Token::Comment => Style::new().fg((140, 220, 120)),
+                Token::Var => Style::new().fg((170, 230, 255)),
+                Token::Var2 => Style::new().fg((255, 250, 160)),
+                Token::Var3 | Token::PrimitiveType => Style::new().fg((110, 235, 200)),
+                Token::Var4 | Token::Macro | Token::Keyword => Style::new().fg((120, 190, 255)),
+                Token::Var5 => Style::new().fg((130, 210, 255)),
+                Token::Keyword2 => Style::new().fg((240, 160, 255)),
+                Token::Number => Style::new().fg((255, 210, 130)),
+                Token::String => Style::new().fg((255, 170, 110)),
+                Token::Invalid => Style::new().fg(WHITE).bg((170, 0, 0)),
+            };
+            theme.token(tok, style)
+        })
+    }
+
+    /// Sets the background color of the theme, i.e. the color that
+    /// the code is rendered on.
+    #[inline]
+    pub fn background(mut self, color: impl Into<Color>) -> Self {
+        self.background = Some(color.into());
+        self
+    }
+
+    /// Returns the background color of the theme, if any,
+    /// see [`background()`].
+    ///
+    /// [`background()`]: Self::background
+    #[inline]
+    pub fn background_color(&self) -> Option<Color> {
+        self.background
+    }
+
+    /// Sets the `style` of `tok` for all languages.
+    pub fn token(mut self, tok: Token, style: Style) -> Self {
+        match self
+            .styles
+            .binary_search_by_key(&(tok as usize), |(t, _style)| *t as usize)
+        {
+            Ok(i) => self.styles[i].1 = style,
+            Err(i) => self.styles.insert(i, (tok, style)),
+        }
+        self
+    }
+
+    /// Sets the `style` of `tok` for the language of `lexer` only,
+    /// taking precedence over the style set using [`token()`].
+    ///
+    /// [`token()`]: Self::token
+    pub fn language(mut self, lexer: Lexer, tok: Token, style: Style) -> Self {
+        let key = (lexer as usize, tok as usize);
+        match self
+            .languages
+            .binary_search_by_key(&key, |(l, t, _style)| (*l as usize, *t as usize))
+        {
+            Ok(i) => self.languages[i].2 = style,
+            Err(i) => self.languages.insert(i, (lexer, tok, style)),
+        }
+        self
+    }
+
+    /// Returns `base` extended by `overrides`, i.e. all styles set in
+    /// `overrides` replace the styles in `base`, including per-language
+    /// styles and the background color, while all other styles are
+    /// inherited from `base`.
+    ///
+    /// Styles are replaced entirely, e.g. overriding only the foreground
+    /// color also removes the background color of `base`.
+    pub fn with_overrides(base: &Theme, overrides: &Theme) -> Self {
+        let mut theme = base.clone();
+        theme.background = overrides.background.or(base.background);
+        for (tok, style) in &overrides.styles {
+            theme = theme.token(*tok, style.clone());
+        }
+        for (lexer, tok, style) in &overrides.languages {
+            theme = theme.language(*lexer, *tok, style.clone());
+        }
+        theme
+    }
+
+    /// Returns the style of `tok`, ignoring per-language styles.
+    pub fn style(&self, tok: Token) -> Style {
+        self.styles
+            .iter()
+            .find(|(t, _style)| *t == tok)
+            .map(|(_tok, style)| style.clone())
+            .unwrap_or_default()
+    }
+
+    /// Returns the style of `tok` for the language of `lexer`,
+    /// falling back to [`style()`] if it is not overridden.
+    ///
+    /// [`style()`]: Self::style
+    pub fn style_for(&self, lexer: Lexer, tok: Token) -> Style {
+        self.languages
+            .iter()
+            .find(|(l, t, _style)| (*l == lexer) && (*t == tok))
+            .map(|(_lexer, _tok, style)| style.clone())
+            .unwrap_or_else(|| self.style(tok))
+    }
+
+    /// Returns all styles with a foreground color, that has a contrast
+    /// ratio less than [`MIN_CONTRAST_RATIO`] against its background.
+    /// Where `bg` is the background color of the theme, used for styles
+    /// without a background color.
+    ///
+    /// Styles without a foreground color, and [`Token::Space`], are
+    /// not checked. Issues are returned in the order of [`Token::VARIANTS`],
+    /// followed by per-language styles.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use colorblast::{Style, Theme, Token};
+    ///
+    /// let theme = Theme::new()
+    ///     .token(Token::Text, Style::new().fg((212, 212, 212)))
+    ///     .token(Token::Comment, Style::new().fg((80, 80, 80)));
+    ///
+    /// let issues = theme.check_contrast((30, 30, 30));
+    /// assert_eq!(issues.len(), 1);
+    /// assert_eq!(issues[0].token, Token::Comment);
+    /// assert!(issues[0].ratio < Theme::MIN_CONTRAST_RATIO);
+    /// ```
+    ///
+    /// [`MIN_CONTRAST_RATIO`]: Self::MIN_CONTRAST_RATIO
+    pub fn check_contrast(&self, bg: impl Into<Color>) -> Vec<ContrastIssue> {
+        let bg = bg.into();
+        let styles = self
+            .styles
+            .iter()
+            .map(|(tok, style)| (None, *tok, style))
+            .chain(
+                self.languages
+                    .iter()
+                    .map(|(lexer, tok, style)| (Some(*lexer), *tok, style)),
+            );
+        styles
+            .filter(|&(_lexer, tok, _style)| tok != Token::Space)
+            .filter_map(|(lexer, token, style)| {
+                let fg = style.fg?;
+                let bg = style.bg.unwrap_or(bg);
+                let ratio = fg.contrast_ratio(bg);
+                (ratio < Self::MIN_CONTRAST_RATIO).then_some(ContrastIssue {
+                    token,
+                    lexer,
+                    fg,
+                    bg,
+                    ratio,
+                })
+            })
+            .collect()
+    }
+
+    /// Returns the [CSS custom properties] referenced by HTML rendered using
+    /// [`render_html_css_variables()`], i.e. the foreground color of each
+    /// token, e.g. `--tok-keyword: #569CD6;`, one declaration per line.
+    ///
+    /// The background color of the theme is included as `--tok-background`,
+    /// if any. Per-token background colors and per-language styles are
+    /// not included.
+    ///
+    /// The declarations are meant to be wrapped in a rule, such that
+    /// themes can be switched using only CSS, e.g. for light and dark
+    /// themes using [`prefers-color-scheme`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use colorblast::{Style, Theme, Token};
+    ///
+    /// let dark = Theme::new()
+    ///     .background((30, 30, 30))
+    ///     .token(Token::Keyword, Style::new().fg((86, 156, 214)))
+    ///     .token(Token::PrimitiveType, Style::new().fg((78, 201, 176)));
+    /// assert_eq!(
+    ///     dark.to_css_variables(),
+    ///     "--tok-background: #1E1E1E;\n--tok-primitive-type: #4EC9B0;\n--tok-keyword: #569CD6;\n"
+    /// );
+    ///
+    /// let light = Theme::new().token(Token::Keyword, Style::new().fg((0, 0, 255)));
+    /// let css = format!(
+    ///     ":root {{\n{}}}\n@media (prefers-color-scheme: dark) {{\n:root {{\n{}}}\n}}\n",
+    ///     light.to_css_variables(),
+    ///     dark.to_css_variables(),
+    /// );
+    /// # assert!(css.starts_with(":root {\n--tok-keyword: #0000FF;\n}\n"));
+    /// ```
+    ///
+    /// [CSS custom properties]: https://developer.mozilla.org/en-US/docs/Web/CSS/--*
+    /// [`render_html_css_variables()`]: crate::render_html_css_variables
+    /// [`prefers-color-scheme`]: https://developer.mozilla.org/en-US/docs/Web/CSS/@media/prefers-color-scheme
+    pub fn to_css_variables(&self) -> String {
+        let mut css = String::new();
+        if let Some(bg) = self.background {
+            css.push_str("--tok-background: ");
+            push_css_color(&mut css, bg);
+            css.push_str(";\n");
+        }
+        for &tok in Token::VARIANTS {
+            let Some(fg) = self.style(tok).fg else {
+                continue;
+            };
+            push_css_variable_name(&mut css, tok);
+            css.push_str(": ");
+            push_css_color(&mut css, fg);
+            css.push_str(";\n");
+        }
+        css
+    }
+
+    /// Returns the tokens of `code` produced by `lexer`, each paired
+    /// with its style, e.g. for [`render_html()`] and [`render_ansi()`].
+    ///
+    /// [`render_html()`]: crate::render_html
+    /// [`render_ansi()`]: crate::render_ansi
+    pub fn highlight<'a, 'text>(
+        &'a self,
+        lexer: Lexer,
+        code: &'text str,
+    ) -> impl Iterator<Item = (Style, TokenSpan<'text>)> + 'a
+    where
+        'text: 'a,
+    {
+        lexer
+            .into_lexer(code)
+            .map(move |(tok, span)| (self.style_for(lexer, tok), span))
+    }
+
+    /// Parses a [Visual Studio Code color theme], e.g. as generated by
+    /// _Developer: Generate Color Theme From Current Settings_.
+    ///
+    /// The `tokenColors` are mapped from TextMate scopes to [`Token`]s,
+    /// e.g. `comment` to [`Token::Comment`], and `entity.name.function`
+    /// to [`Token::Var2`]. Tokens without a matching scope use the
+    /// `editor.foreground` color, and `editor.background` is used as
+    /// the [`background_color()`].
+    ///
+    /// Only foreground colors are used, i.e. `fontStyle` is ignored.
+    /// Scope selectors for nested scopes, e.g. `source.rust comment`,
+    /// are ignored, and `include` is not supported.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::ThemeParse`] if `json` is not valid [JSON with
+    /// Comments], or if it contains an invalid color.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use colorblast::{Theme, Token};
+    ///
+    /// let json = r##"{
+    ///     // Comments and trailing commas are allowed
+    ///     "colors": {
+    ///         "editor.background": "#1E1E1E",
+    ///         "editor.foreground": "#D4D4D4",
+    ///     },
+    ///     "tokenColors": [
+    ///         { "scope": "comment", "settings": { "foreground": "#6A9955" } },
+    ///         { "scope": ["keyword", "storage"], "settings": { "foreground": "#569CD6" } },
+    ///         { "scope": "keyword.control", "settings": { "foreground": "#C586C0" } },
+    ///     ],
+    /// }"##;
+    ///
+    /// let theme = Theme::from_vscode_json(json).unwrap();
+    /// assert_eq!(theme.background_color(), Some((30, 30, 30).into()));
+    /// assert_eq!(theme.style(Token::Comment).fg, Some((106, 153, 85).into()));
+    /// assert_eq!(theme.style(Token::Keyword).fg, Some((86, 156, 214).into()));
+    /// assert_eq!(theme.style(Token::Keyword2).fg, Some((197, 134, 192).into()));
+    /// assert_eq!(theme.style(Token::Number).fg, Some((212, 212, 212).into()));
+    /// ```
+    ///
+    /// [Visual Studio Code color theme]: https://code.visualstudio.com/api/extension-guides/color-theme
+    /// [JSON with Comments]: https://code.visualstudio.com/docs/languages/json#_json-with-comments
+    /// [`background_color()`]: Self::background_color
+    #[cfg(feature = "lang-jsonc")]
+    pub fn from_vscode_json(json: &str) -> Result<Self, Error> {
+        use crate::json::{parse_jsonc, Value};
+
+        let json = parse_jsonc(json).map_err(Error::ThemeParse)?;
+        let color = |value: Option<&Value>| value.and_then(Value::as_str).map(parse_hex_color);
+
+        let colors = json.get("colors");
+        let mut foreground = color(colors.and_then(|c| c.get("editor.foreground"))).transpose()?;
+        let background = color(colors.and_then(|c| c.get("editor.background"))).transpose()?;
+
+        // Later rules take precedence over earlier rules
+        let mut rules = Vec::new();
+        let token_colors = json.get("tokenColors").and_then(Value::as_array);
+        for rule in token_colors.unwrap_or_default() {
+            let fg = match color(rule.get("settings").and_then(|s| s.get("foreground"))) {
+                Some(fg) => fg?,
+                None => continue,
+            };
+            let selectors = match rule.get("scope") {
+                Some(Value::String(scope)) => scope.split(',').collect(),
+                Some(Value::Array(scopes)) => scopes.iter().filter_map(Value::as_str).collect(),
+                // A rule without a scope sets the default foreground color
+                _ => {
+                    foreground = foreground.or(Some(fg));
+                    Vec::new()
+                }
+            };
+            rules.extend(
+                selectors
+                    .into_iter()
+                    .map(str::trim)
+                    .filter(|selector| !selector.is_empty() && !selector.contains(' '))
+                    .map(|selector| (selector, fg)),
+            );
+        }
+
+        // The most specific selector matching `scope`, e.g. `keyword.control`
+        // is more specific than `keyword` for `keyword.control.rust`
+        let find = |scope: &str| {
+            rules
+                .iter()
+                .enumerate()
+                .filter(|(_i, (selector, _fg))| {
+                    scope
+                        .strip_prefix(selector)
+                        .is_some_and(|rest| rest.is_empty() || rest.starts_with('.'))
+                })
+                .max_by_key(|(i, (selector, _fg))| (selector.len(), *i))
+                .map(|(_i, (_selector, fg))| *fg)
+        };
+
+        let mut theme = Token::VARIANTS.iter().fold(Self::new(), |theme, &tok| {
+            let fg = vscode_scopes(tok)
+                .iter()
+                .find_map(|scope| find(scope))
+                .or(foreground);
+            match fg {
+                Some(fg) => theme.token(tok, Style::new().fg(fg)),
+                None => theme,
+            }
+        });
+        theme.background = background;
+        Ok(theme)
+    }
+}
+
+/// Returns the TextMate scopes representing `tok`, in order of priority.
+#[cfg(feature = "lang-jsonc")]
+fn vscode_scopes(tok: Token) -> &'static [&'static str] {
+    match tok {
+        Token::Space | Token::Text => &[],
+        Token::Comment => &["comment"],
+        Token::Var => &["variable.other", "variable"],
+        Token::Var2 => &["entity.name.function", "support.function"],
+        Token::Var3 => &["entity.name.type", "support.type", "support.class"],
+        Token::Var4 => &["entity.name.type.lifetime", "storage.modifier.lifetime"],
+        Token::Var5 => &["variable.other.constant", "constant.other", "variable"],
+        Token::Macro => &["entity.name.function.macro", "support.function.macro"],
+        Token::PrimitiveType => &[
+            "entity.name.type.primitive",
+            "support.type.primitive",
+            "storage.type.primitive",
+        ],
+        Token::Keyword => &["keyword.other", "storage.type", "keyword", "storage"],
+        Token::Keyword2 => &["keyword.control", "keyword"],
+        Token::Operator => &["keyword.operator"],
+        Token::Delimiter => &["punctuation"],
+        Token::Number => &["constant.numeric"],
+        Token::String => &["string"],
+        Token::Meta => &["meta.attribute", "meta.decorator", "meta.preprocessor"],
+        Token::Invalid => &["invalid"],
+    }
+}
+
+/// Parses a `#RGB`, `#RGBA`, `#RRGGBB`, or `#RRGGBBAA` color.
+#[cfg(feature = "lang-jsonc")]
+fn parse_hex_color(s: &str) -> Result<Color, Error> {
+    let invalid = || Error::ThemeParse(format!("invalid color `{s}`"));
+    let hex = s
+        .strip_prefix('#')
+        .filter(|hex| hex.bytes().all(|b| b.is_ascii_hexdigit()))
+        .ok_or_else(invalid)?;
+
+    let digits = match hex.len() {
+        3 | 4 => 1,
+        6 | 8 => 2,
+        _ => return Err(invalid()),
+    };
+    let mut rgba = [255; 4];
+    for (i, c) in rgba.iter_mut().enumerate().take(hex.len() / digits) {
+        let n = u8::from_str_radix(&hex[(i * digits)..((i + 1) * digits)], 16)
+            .map_err(|_| invalid())?;
+        // E.g. `#F80` is the same as `#FF8800`
+        *c = if digits == 1 { n * 17 } else { n };
+    }
+    Ok(Color(rgba))
+}
+
+/// The default dark theme, inspired by Visual Studio Code's Dark+ theme.
+///
+/// The theme is designed for a `(30, 30, 30)` background.
+impl Default for Theme {
+    fn default() -> Self {
+        Self {
+            styles: Token::VARIANTS
+                .iter()
+                .map(|&tok| (tok, tok.default_style()))
+                .collect(),
+            languages: Vec::new(),
+            background: Some(Color::from((30, 30, 30))),
+        }
+    }
+}
+
+/// Style with insufficient contrast, see [`Theme::check_contrast()`].
+#[derive(Clone, Copy, Debug)]
+#[non_exhaustive]
+pub struct ContrastIssue {
+    pub token: Token,
+    /// The language of a per-language style, see [`Theme::language()`].
+    pub lexer: Option<Lexer>,
+    pub fg: Color,
+    pub bg: Color,
+    /// The contrast ratio between [`fg`](Self::fg) and [`bg`](Self::bg).
+    pub ratio: f64,
+}
+
+#[cfg(all(test, feature = "all-langs"))]
+mod tests {
+    use super::*;
+    use crate::{Color, StylizeToken};
+
+    #[test]
+    fn test_theme_default() {
+        let theme = Theme::default();
+        for (tok, span) in Lexer::Rust.into_lexer("fn main() { let x = \"🦀\"; }") {
+            assert_eq!(theme.style(tok).fg, tok.style(&span).fg, "{tok:?}");
+            assert_eq!(theme.style(tok).bg, tok.style(&span).bg, "{tok:?}");
+        }
+    }
+
+    #[test]
+    fn test_theme_with_overrides() {
+        let red = Style::new().fg(Color::from((255, 0, 0)));
+        let green = Style::new().fg(Color::from((0, 255, 0)));
+        let blue = Style::new().bg(Color::from((0, 0, 255)));
+
+        let base = Theme::new()
+            .token(Token::String, red.clone())
+            .token(Token::Number, red.clone())
+            .language(Lexer::Rust, Token::String, green.clone());
+        let overrides = Theme::new().token(Token::Number, blue.clone()).language(
+            Lexer::Json,
+            Token::String,
+            blue.clone(),
+        );
+        let theme = Theme::with_overrides(&base, &overrides);
+
+        let fg = |style: Style| (style.fg, style.bg);
+        assert_eq!(fg(theme.style(Token::String)), fg(red.clone()));
+        assert_eq!(fg(theme.style(Token::Number)), fg(blue.clone()));
+        assert_eq!(fg(theme.style(Token::Comment)), fg(Style::NONE));
+
+        assert_eq!(fg(theme.style_for(Lexer::Rust, Token::String)), fg(green));
+        assert_eq!(fg(theme.style_for(Lexer::Json, Token::String)), fg(blue));
+        assert_eq!(fg(theme.style_for(Lexer::Python, Token::String)), fg(red));
+
+        // `base` is unchanged
+        assert_eq!(fg(base.style(Token::Number)).1, None);
+    }
+
+    #[test]
+    fn test_theme_to_css_variables() {
+        let css = Theme::default().to_css_variables();
+        let html = crate::render_html_css_variables(
+            Lexer::Rust
+                .into_lexer("fn main() { let x: u8 = 1; /* \"<a>\" */ }")
+                .map(|(tok, span)| (tok, span.as_str())),
+        );
+
+        // All referenced custom properties are defined by the theme
+        for var in html.split("var(").skip(1) {
+            let name = &var[..var.find(')').unwrap()];
+            assert!(css.contains(&format!("{name}: #")), "{name}");
+        }
+        assert!(html.contains("/* &quot;&lt;a&gt;&quot; */"));
+    }
+
+    #[test]
+    fn test_theme_deterministic() {
+        let dark = Style::new().fg((10, 10, 10));
+        let darker = Style::new().fg((5, 5, 5));
+
+        let a = Theme::new()
+            .token(Token::String, dark.clone())
+            .token(Token::Comment, darker.clone())
+            .language(Lexer::Rust, Token::Number, dark.clone())
+            .language(Lexer::Json, Token::String, darker.clone());
+        let b = Theme::new()
+            .language(Lexer::Json, Token::String, darker.clone())
+            .token(Token::Comment, darker)
+            .language(Lexer::Rust, Token::Number, dark.clone())
+            .token(Token::String, dark);
+
+        assert_eq!(format!("{a:?}"), format!("{b:?}"));
+        assert_eq!(a.to_css_variables(), b.to_css_variables());
+        assert_eq!(
+            a.to_css_variables(),
+            "--tok-comment: #050505;\n--tok-string: #0A0A0A;\n"
+        );
+
+        let issues = |theme: &Theme| {
+            theme
+                .check_contrast(Color::BLACK)
+                .iter()
+                .map(|issue| (issue.lexer, issue.token))
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(issues(&a), issues(&b));
+        assert_eq!(
+            issues(&a),
+            [
+                (None, Token::Comment),
+                (None, Token::String),
+                (Some(Lexer::Json), Token::String),
+                (Some(Lexer::Rust), Token::Number),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_css_variable_names() {
+        let names = Token::VARIANTS
+            .iter()
+            .map(|&tok| {
+                let mut name = String::new();
+                push_css_variable_name(&mut name, tok);
+                name
+            })
+            .collect::<Vec<_>>();
+
+        // The names are referenced by user stylesheets, and must remain stable
+        assert_eq!(
+            names,
+            [
+                "--tok-space",
+                "--tok-comment",
+                "--tok-text",
+                "--tok-var",
+                "--tok-var2",
+                "--tok-var3",
+                "--tok-var4",
+                "--tok-var5",
+                "--tok-macro",
+                "--tok-primitive-type",
+                "--tok-keyword",
+                "--tok-keyword2",
+                "--tok-operator",
+                "--tok-delimiter",
+                "--tok-number",
+                "--tok-string",
+                "--tok-meta",
+                "--tok-invalid",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_theme_check_contrast() {
+        assert!(Theme::high_contrast()
+            .check_contrast(Color::BLACK)
+            .is_empty());
+
+        let issues = Theme::default().check_contrast((30, 30, 30));
+        let issues = issues.iter().map(|issue| issue.token).collect::<Vec<_>>();
+        assert_eq!(issues, [Token::Invalid]);
+
+        let issues = Theme::high_contrast().check_contrast(Color::WHITE);
+        assert!(issues.iter().all(|issue| issue.bg == Color::WHITE));
+        assert!(issues.iter().all(|issue| issue.token != Token::Invalid));
+
+        let theme = Theme::new().language(Lexer::Json, Token::String, Style::new().fg((0, 0, 1)));
+        let issues = theme.check_contrast(Color::BLACK);
+        assert_eq!(issues.len(), 1);
+        assert_eq!(issues[0].lexer, Some(Lexer::Json));
+        assert_eq!(issues[0].token, Token::String);
+    }
+
+    #[test]
+    fn test_theme_from_vscode_json() {
+        let json = r##"{
+            "tokenColors": [
+                { "settings": { "foreground": "#808080" } },
+                { "scope": "keyword.control.rust", "settings": { "foreground": "#F00" } },
+                { "scope": "keyword, string", "settings": { "foreground": "#00FF0080" } },
+                { "scope": "source.rust comment", "settings": { "foreground": "#0000FF" } },
+                { "scope": "keyword", "settings": { "fontStyle": "bold" } }
+            ]
+        }"##;
+        let theme = Theme::from_vscode_json(json).unwrap();
+
+        assert_eq!(theme.background_color(), None);
+        assert_eq!(
+            theme.style(Token::Keyword).fg,
+            Some(Color([0, 255, 0, 128]))
+        );
+        assert_eq!(
+            theme.style(Token::Keyword2).fg,
+            Some(Color([0, 255, 0, 128]))
+        );
+        assert_eq!(theme.style(Token::String).fg, Some(Color([0, 255, 0, 128])));
+        assert_eq!(
+            theme.style(Token::Comment).fg,
+            Some(Color([128, 128, 128, 255]))
+        );
+
+        for json in ["", "[", r#"{"colors": {"editor.foreground": "red"}}"#] {
+            let err = Theme::from_vscode_json(json).unwrap_err();
+            assert!(matches!(err, Error::ThemeParse(_)), "{json:?}");
+        }
+    }
+}
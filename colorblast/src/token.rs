@@ -15,7 +15,7 @@ macro_rules! impl_enum_token {
     (
         $(
             $(#[$attr:meta])*
-            $name:ident,
+            $name:ident => $str:literal,
         )+
     ) => {
         /// Generic `Token` with variants useful for applying syntax
@@ -58,37 +58,87 @@ macro_rules! impl_enum_token {
             pub const VARIANTS: &[Self] = &[
                 $(Self::$name,)+
             ];
+
+            /// Returns the stable machine-readable name of this token kind,
+            /// e.g. `"comment"` for <code>[Token]::[Comment]</code>.
+            ///
+            /// As opposed to the variants themselves, the names are
+            /// guaranteed to remain stable across versions, such that
+            /// e.g. themes and configuration files can refer to token
+            /// kinds by name.
+            ///
+            /// See also [`from_name()`].
+            ///
+            /// [Comment]: Token::Comment
+            /// [`from_name()`]: Self::from_name
+            #[inline]
+            pub const fn name(&self) -> &'static str {
+                match self {
+                    $(Self::$name => $str,)+
+                }
+            }
+
+            /// Returns the token kind with the given `name`, if any.
+            ///
+            /// See also [`name()`].
+            ///
+            /// [`name()`]: Self::name
+            #[inline]
+            pub fn from_name(name: &str) -> Option<Self> {
+                match name {
+                    $($str => Some(Self::$name),)+
+                    _ => None,
+                }
+            }
         }
     };
 }
 
 impl_enum_token!(
-    Space,
-    Comment,
+    Space => "space",
+    Comment => "comment",
     /// Token representing text, which might contain whitespace.
-    Text,
-    Var,
-    Var2,
-    Var3,
-    Var4,
-    Var5,
-    Keyword,
-    Keyword2,
-    Operator,
-    Delimiter,
-    Number,
-    String,
-    Meta,
+    Text => "text",
+    Var => "var",
+    Var2 => "var2",
+    Var3 => "var3",
+    Var4 => "var4",
+    Var5 => "var5",
+    /// Macro invocations and definitions, e.g. `println!` in Rust.
+    Macro => "macro",
+    /// Types built into the language, e.g. `u32` and `str` in Rust.
+    PrimitiveType => "primitive_type",
+    Keyword => "keyword",
+    Keyword2 => "keyword2",
+    Operator => "operator",
+    Delimiter => "delimiter",
+    Number => "number",
+    String => "string",
+    Meta => "meta",
     /// Given valid code, then this variant should never be encountered. If
     /// is is encountered, then check if an issue has already been submitted,
     /// otherwise please [submit an issue].
     ///
     /// [submit an issue]: https://github.com/vallentin/colorblast/issues
-    Invalid,
+    Invalid => "invalid",
 );
 
 impl StylizeToken for Token {
+    #[inline]
     fn style(&self, _span: &TokenSpan<'_>) -> Style {
+        self.default_style()
+    }
+
+    #[inline]
+    fn depends_on_span(&self) -> bool {
+        false
+    }
+}
+
+impl Token {
+    /// Returns the style of this token kind in the default theme,
+    /// see [`Theme::default()`](crate::Theme::default).
+    pub(crate) fn default_style(self) -> Style {
         match self {
             // TODO: Background: (30, 30, 30)
             Self::Space => Style::new().fg((212, 212, 212)),
@@ -99,6 +149,8 @@ impl StylizeToken for Token {
             Self::Var3 => Style::new().fg((78, 201, 176)),
             Self::Var4 => Style::new().fg((86, 156, 214)),
             Self::Var5 => Style::new().fg((79, 193, 255)),
+            Self::Macro => Style::new().fg((86, 156, 214)),
+            Self::PrimitiveType => Style::new().fg((78, 201, 176)),
             Self::Keyword => Style::new().fg((86, 156, 214)),
             Self::Keyword2 => Style::new().fg((197, 134, 192)),
             Self::Operator => Style::new().fg((212, 212, 212)),
@@ -114,15 +166,19 @@ impl StylizeToken for Token {
     }
 }
 
+// Only used by `RustLexer`
+#[cfg_attr(not(feature = "lang-rust"), allow(dead_code))]
 pub(crate) trait IntoSimpleToken {
     fn into_simple_token(self) -> Token;
 }
 
+#[cfg_attr(not(feature = "lang-rust"), allow(dead_code))]
 #[derive(Clone, Debug)]
 pub(crate) struct SimpleTokenIter<I> {
     iter: I,
 }
 
+#[cfg_attr(not(feature = "lang-rust"), allow(dead_code))]
 impl<'text, T, I> SimpleTokenIter<I>
 where
     I: Iterator<Item = (T, TokenSpan<'text>)>,
@@ -227,3 +283,18 @@ where
         Self::new(iter)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_token_names() {
+        for &tok in Token::VARIANTS {
+            assert_eq!(Token::from_name(tok.name()), Some(tok));
+        }
+
+        assert_eq!(Token::from_name("Comment"), None);
+        assert_eq!(Token::from_name(""), None);
+    }
+}
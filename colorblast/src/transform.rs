@@ -0,0 +1,314 @@
+//! Transformations of code, built on the token streams of the lexers.
+//!
+//! As the lexers produce tokens covering the whole input, transformations
+//! only need to decide which tokens to keep, and what to emit between them.
+
+#[cfg(feature = "lang-css")]
+use any_lexer::{CssLexer, CssToken};
+#[cfg(feature = "lang-json")]
+use any_lexer::{JsonLexer, JsonToken};
+
+use crate::{Lexer, Token};
+
+/// Returns `text` with all comments removed, i.e. all tokens classified
+/// as [`Token::Comment`] by `lexer`.
+///
+/// Whitespace preceding a comment on the same line is removed, and lines
+/// only containing comments are removed entirely. A space is inserted
+/// in place of a comment, if it would otherwise join two tokens.
+///
+/// See also [`strip_comments_keep_lines()`], which preserves the lines,
+/// e.g. such that line numbers remain the same.
+///
+/// # Example
+///
+/// ```rust
+/// use colorblast::transform::strip_comments;
+/// use colorblast::Lexer;
+///
+/// let code = "// Comment\nfn main() { // Comment\n    let x = 1/* Comment */+ 2;\n}\n";
+/// assert_eq!(
+///     strip_comments(Lexer::Rust, code),
+///     "fn main() {\n    let x = 1 + 2;\n}\n"
+/// );
+/// ```
+#[inline]
+pub fn strip_comments(lexer: Lexer, text: &str) -> String {
+    strip_comments_impl(lexer, text, false)
+}
+
+/// Same as [`strip_comments()`], except lines are never removed, and line
+/// breaks inside comments are preserved, such that the result has the same
+/// amount of lines as `text`.
+///
+/// # Example
+///
+/// ```rust
+/// use colorblast::transform::strip_comments_keep_lines;
+/// use colorblast::Lexer;
+///
+/// let code = "# Comment\nx = 1  # Comment\ny = 2\n";
+/// assert_eq!(
+///     strip_comments_keep_lines(Lexer::Python, code),
+///     "\nx = 1\ny = 2\n"
+/// );
+/// ```
+#[inline]
+pub fn strip_comments_keep_lines(lexer: Lexer, text: &str) -> String {
+    strip_comments_impl(lexer, text, true)
+}
+
+fn strip_comments_impl(lexer: Lexer, text: &str, keep_lines: bool) -> String {
+    let mut code = String::new();
+    // The lines of `code` in ascending order, where comments were removed
+    let mut stripped_lines = Vec::new();
+    let mut line = 0;
+    // The start of the trailing `Token::Space` in `code`, if any
+    let mut space_start = None;
+    let mut pending_space = false;
+
+    for (tok, span) in lexer.into_lexer(text) {
+        let s = span.as_str();
+
+        if tok != Token::Comment {
+            // Delimiters are never joined into other tokens, e.g. `(` and `;`
+            let is_separated_before = code.is_empty() || code.ends_with(char::is_whitespace);
+            let is_separated_before = is_separated_before || code.ends_with(['(', '[', '{']);
+            let is_separated_after = s.starts_with(char::is_whitespace);
+            let is_separated_after = is_separated_after || s.starts_with([')', ']', '}', ',', ';']);
+            if pending_space && !is_separated_before && !is_separated_after {
+                code.push(' ');
+            }
+            pending_space = false;
+
+            space_start = match tok {
+                Token::Space => space_start.or(Some(code.len())),
+                _ => None,
+            };
+
+            code.push_str(s);
+            line += s.matches('\n').count();
+            continue;
+        }
+
+        // Remove whitespace preceding the comment on the same line
+        if let Some(start) = space_start {
+            let trimmed = code.trim_end_matches([' ', '\t']).len();
+            code.truncate(trimmed.max(start));
+        }
+
+        stripped_lines.push(line);
+
+        let line_breaks = s.matches('\n').count();
+        if keep_lines && (line_breaks > 0) {
+            for line in s.split_inclusive('\n').take(line_breaks) {
+                code.push_str(if line.ends_with("\r\n") { "\r\n" } else { "\n" });
+            }
+            line += line_breaks;
+            stripped_lines.push(line);
+            space_start = Some(code.len());
+        } else {
+            pending_space = true;
+        }
+    }
+
+    if keep_lines || stripped_lines.is_empty() {
+        return code;
+    }
+
+    code.split_inclusive('\n')
+        .enumerate()
+        .filter(|(i, line)| !(stripped_lines.binary_search(i).is_ok() && line.trim().is_empty()))
+        .map(|(_i, line)| line)
+        .collect()
+}
+
+/// Returns `text` with all whitespace between tokens removed.
+///
+/// Invalid JSON is not rejected, and is minified on a best-effort basis.
+///
+/// # Example
+///
+/// ```rust
+/// use colorblast::transform::minify_json;
+///
+/// let json = r#"{
+///     "name": "colorblast",
+///     "keywords": ["syntax", "highlighting"]
+/// }"#;
+///
+/// assert_eq!(
+///     minify_json(json),
+///     r#"{"name":"colorblast","keywords":["syntax","highlighting"]}"#
+/// );
+/// ```
+#[cfg(feature = "lang-json")]
+pub fn minify_json(text: &str) -> String {
+    JsonLexer::new(text)
+        .filter(|(tok, _span)| !JsonToken::TRIVIA.contains(*tok))
+        .map(|(_tok, span)| span.as_str())
+        .collect()
+}
+
+/// Returns `text` with comments removed, and whitespace removed or
+/// collapsed into a single space, where it is significant, e.g.
+/// between selectors in `div p` and values in `margin: 0 auto`.
+///
+/// Comments starting with `/*!`, e.g. license headers, are preserved.
+/// A `;` preceding a `}` is removed.
+///
+/// Invalid CSS is not rejected, and is minified on a best-effort basis.
+///
+/// # Example
+///
+/// ```rust
+/// use colorblast::transform::minify_css;
+///
+/// let css = "
+/// /* Header */
+/// .header  h1,
+/// .header h2 {
+///     margin: 0 auto;
+///     color: rgb(0, 0, 0);
+/// }
+/// ";
+///
+/// assert_eq!(
+///     minify_css(css),
+///     ".header h1,.header h2{margin:0 auto;color:rgb(0,0,0)}"
+/// );
+/// ```
+#[cfg(feature = "lang-css")]
+pub fn minify_css(text: &str) -> String {
+    let mut css = String::new();
+    let mut prev: Option<(CssToken, &str)> = None;
+    let mut separated = false;
+
+    for (tok, span) in CssLexer::new(text) {
+        let s = span.as_str();
+
+        let is_preserved = (tok == CssToken::BlockComment) && s.starts_with("/*!");
+        if CssToken::TRIVIA.contains(tok) && !is_preserved {
+            // Comments separate tokens same as whitespace,
+            // e.g. `a/**/b` is not the same as `ab`
+            separated = true;
+            continue;
+        }
+
+        if let Some(prev) = prev {
+            if separated && !is_css_space_removable(prev, (tok, s)) {
+                css.push(' ');
+            }
+        }
+        separated = false;
+
+        if (tok, s) == (CssToken::Delim, "}") && (prev == Some((CssToken::Punct, ";"))) {
+            css.pop();
+        }
+
+        css.push_str(s);
+        prev = Some((tok, s));
+    }
+
+    css
+}
+
+/// Returns `true` if whitespace between `before` and `after` can
+/// be removed, without changing the meaning of the CSS.
+#[cfg(feature = "lang-css")]
+fn is_css_space_removable(before: (CssToken, &str), after: (CssToken, &str)) -> bool {
+    // Whitespace is significant before `(`, e.g. `and (` in media
+    // queries, and before `:`, e.g. `a :hover` in selectors
+    let is_removable_after = matches!(
+        before,
+        (CssToken::Delim, "{" | "}" | "(")
+            | (CssToken::Punct, ";" | "," | ":")
+            | (CssToken::Combinator, _)
+            | (CssToken::BlockComment, _)
+    );
+    let is_removable_before = matches!(
+        after,
+        (CssToken::Delim, "{" | "}" | ")")
+            | (CssToken::Punct, ";" | ",")
+            | (CssToken::Combinator, _)
+            | (CssToken::BlockComment, _)
+    );
+    is_removable_after || is_removable_before
+}
+
+#[cfg(all(test, feature = "all-langs"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_minify_json() {
+        #[rustfmt::skip]
+        let cases = [
+            ("", ""),
+            ("  1  ", "1"),
+            ("[ 1 , 2 ]", "[1,2]"),
+            ("{ \"a b\" : \" c \" }", "{\"a b\":\" c \"}"),
+            ("\t[\r\n\ttrue,\r\n\tnull\r\n]\r\n", "[true,null]"),
+        ];
+
+        for (text, expected) in cases {
+            assert_eq!(minify_json(text), expected, "{text:?}");
+        }
+    }
+
+    #[test]
+    fn test_minify_css() {
+        #[rustfmt::skip]
+        let cases = [
+            ("", ""),
+            ("/* a */", ""),
+            ("/*! License */\na{}", "/*! License */a{}"),
+            ("a  b { }", "a b{}"),
+            ("a/**/b{}", "a b{}"),
+            ("a :hover { color : red ; }", "a :hover{color :red}"),
+            ("a { border: 1px solid red; margin: 0 auto }", "a{border:1px solid red;margin:0 auto}"),
+            ("a { width: calc( 1px + 2px ); }", "a{width:calc(1px + 2px)}"),
+            ("@media screen and (max-width: 1px) { a { b: c } }", "@media screen and (max-width:1px){a{b:c}}"),
+            ("a::before { content: \" ; } \"; }", "a::before{content:\" ; } \"}"),
+            ("a, b ,c {}", "a,b,c{}"),
+            ("ul > li + li ~ p { }", "ul>li+li~p{}"),
+            ("a [href] { }", "a [href]{}"),
+        ];
+
+        for (text, expected) in cases {
+            assert_eq!(minify_css(text), expected, "{text:?}");
+        }
+    }
+    #[test]
+    fn test_strip_comments() {
+        #[rustfmt::skip]
+        let cases = [
+            (Lexer::Rust, "", "", ""),
+            (Lexer::Rust, "a\n", "a\n", "a\n"),
+            (Lexer::Rust, "// a\n", "", "\n"),
+            (Lexer::Rust, "a // b\nc", "a\nc", "a\nc"),
+            (Lexer::Rust, "a\n    // b\n    c\n", "a\n    c\n", "a\n\n    c\n"),
+            (Lexer::Rust, "a/* b */c", "a c", "a c"),
+            (Lexer::Rust, "a /* b */ c", "a c", "a c"),
+            (Lexer::Rust, "a(/* b */)", "a()", "a()"),
+            (Lexer::Rust, "a /* b\nc */ d\ne", "a d\ne", "a\n d\ne"),
+            (Lexer::Rust, "/* a\r\nb */\r\nc\r\n", "c\r\n", "\r\n\r\nc\r\n"),
+            (Lexer::Rust, "\"a // b \" // c", "\"a // b \"", "\"a // b \""),
+            (Lexer::Python, "a = 1  # b\n# c\n\nd = 2", "a = 1\n\nd = 2", "a = 1\n\n\nd = 2"),
+            (Lexer::JsonC, "{\n  // a\n  \"b\": 1\n}", "{\n  \"b\": 1\n}", "{\n\n  \"b\": 1\n}"),
+            (Lexer::Json, "{\"a\": 1}", "{\"a\": 1}", "{\"a\": 1}"),
+        ];
+
+        for (lexer, text, expected, expected_keep_lines) in cases {
+            assert_eq!(strip_comments(lexer, text), expected, "{lexer:?} {text:?}");
+
+            let actual = strip_comments_keep_lines(lexer, text);
+            assert_eq!(actual, expected_keep_lines, "{lexer:?} {text:?}");
+            assert_eq!(
+                actual.lines().count(),
+                text.lines().count(),
+                "{lexer:?} {text:?}"
+            );
+        }
+    }
+}
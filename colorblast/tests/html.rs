@@ -0,0 +1,169 @@
+//! Golden file tests for the HTML renderer.
+//!
+//! Each snippet in `tests/snippets` is rendered using the matching lexer,
+//! and compared against the expected output in `tests/golden`. Each snippet
+//! is additionally rendered with every built-in theme, and compared against
+//! the expected output in `tests/golden/<theme>`.
+//!
+//! When the output intentionally changes, then run the tests with the
+//! `COLORBLAST_BLESS` environment variable set, to update the golden files:
+//!
+//! ```text
+//! COLORBLAST_BLESS=1 cargo test -p colorblast --test html
+//! ```
+
+#![cfg(feature = "all-langs")]
+
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use colorblast::{render_html, render_html_with_options, HtmlOptions, Lexer, StylizeToken, Theme};
+
+const BLESS_ENV: &str = "COLORBLAST_BLESS";
+
+const SNIPPETS: &[(Lexer, &str)] = &[
+    (Lexer::Apache, "apache.htaccess"),
+    (Lexer::CMake, "cmake.cmake"),
+    (Lexer::Crontab, "crontab.crontab"),
+    (Lexer::Json, "json.json"),
+    (Lexer::JsonC, "jsonc.jsonc"),
+    (Lexer::JavaScript, "javascript.jsx"),
+    (Lexer::TypeScript, "typescript.ts"),
+    (Lexer::Markdown, "markdown.md"),
+    (Lexer::Meson, "meson.build"),
+    (Lexer::Nginx, "nginx.conf"),
+    (Lexer::Python, "python.py"),
+    (Lexer::Rust, "rust.rs"),
+    (Lexer::Systemd, "systemd.service"),
+    (Lexer::Toml, "toml.toml"),
+    (Lexer::Html, "html.html"),
+    (Lexer::Xml, "xml.xml"),
+    (Lexer::Vue, "vue.vue"),
+    (Lexer::Svelte, "svelte.svelte"),
+    (Lexer::Jinja, "jinja.jinja"),
+    (Lexer::Handlebars, "handlebars.hbs"),
+    (Lexer::PlainText, "plain_text.txt"),
+];
+
+#[test]
+fn test_html_golden_files() {
+    let dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests");
+
+    assert_golden_files(&dir.join("golden"), |lexer, code| {
+        let tokens = lexer.into_lexer(code);
+        render_html(tokens.map(|(tok, span)| (tok.style(&span), span.as_str())))
+    });
+}
+
+#[test]
+fn test_html_golden_files_themes() {
+    let dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests");
+
+    for name in Theme::BUILTIN {
+        let theme = Theme::by_name(name).unwrap();
+        assert_golden_files(&dir.join("golden").join(name), |lexer, code| {
+            let tokens = theme.highlight(lexer, code);
+            render_html(tokens.map(|(style, span)| (style, span.as_str())))
+        });
+    }
+}
+
+/// Asserts that each snippet rendered by `render` matches
+/// its golden file in `golden_dir`, or updates the golden
+/// files if blessing.
+fn assert_golden_files<F>(golden_dir: &Path, mut render: F)
+where
+    F: FnMut(Lexer, &str) -> String,
+{
+    let dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests");
+    let bless = env::var_os(BLESS_ENV).is_some();
+
+    let mut mismatches = Vec::new();
+
+    for &(lexer, snippet) in SNIPPETS {
+        let code = read_normalized(&dir.join("snippets").join(snippet));
+
+        let mut actual = render(lexer, &code);
+        actual.push('\n');
+
+        let golden = golden_path(golden_dir, snippet);
+        if bless {
+            fs::create_dir_all(golden_dir).unwrap();
+            fs::write(&golden, &actual).unwrap();
+            continue;
+        }
+
+        let expected = read_normalized(&golden);
+        if actual != expected {
+            mismatches.push(golden);
+        }
+    }
+
+    assert!(
+        mismatches.is_empty(),
+        "output differs from golden files, rerun with `{BLESS_ENV}=1` if intended: {mismatches:#?}"
+    );
+}
+
+/// Asserts that coalescing adjacent tokens with the same style
+/// reduces the size of the HTML, without changing the text.
+#[test]
+fn test_html_coalesce_size() {
+    let dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests");
+
+    let (mut total, mut total_coalesced) = (0, 0);
+    for &(lexer, snippet) in SNIPPETS {
+        let code = read_normalized(&dir.join("snippets").join(snippet));
+        let render = |options: &HtmlOptions| {
+            let tokens = lexer.into_lexer(&code);
+            let tokens = tokens.map(|(tok, span)| (tok.style(&span), span.as_str()));
+            render_html_with_options(tokens, options)
+        };
+
+        let html = render(&HtmlOptions::new().coalesce(false));
+        let coalesced = render(&HtmlOptions::new().coalesce(true));
+
+        assert_eq!(strip_tags(&coalesced), strip_tags(&html), "{snippet}");
+        assert!(coalesced.len() <= html.len(), "{snippet}");
+        if lexer != Lexer::PlainText {
+            assert!(coalesced.len() < html.len(), "{snippet}");
+        }
+
+        total += html.len();
+        total_coalesced += coalesced.len();
+    }
+
+    // Currently the HTML is reduced by roughly 40%
+    assert!(
+        total_coalesced * 4 < total * 3,
+        "expected at least 25% reduction, {total} -> {total_coalesced} bytes"
+    );
+}
+
+/// Removes all tags from `html`.
+fn strip_tags(html: &str) -> String {
+    let mut text = String::with_capacity(html.len());
+    let mut rest = html;
+    while let Some(start) = rest.find('<') {
+        text.push_str(&rest[..start]);
+        let end = rest[start..].find('>').unwrap();
+        rest = &rest[(start + end + 1)..];
+    }
+    text.push_str(rest);
+    text
+}
+
+fn golden_path(golden_dir: &Path, snippet: &str) -> PathBuf {
+    let mut name = snippet.replace('.', "_");
+    name.push_str(".html");
+    golden_dir.join(name)
+}
+
+/// Reads `path` and normalizes line endings, such that golden files
+/// checked out with `\r\n` line endings still compare equal.
+fn read_normalized(path: &Path) -> String {
+    let text = fs::read_to_string(path)
+        .unwrap_or_else(|err| panic!("failed reading `{}`: {err}", path.display()));
+    text.replace("\r\n", "\n")
+}
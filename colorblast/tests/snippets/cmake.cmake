@@ -0,0 +1,11 @@
+#[[ Bracket comment ]]
+cmake_minimum_required(VERSION 3.10)
+project(App LANGUAGES C)
+
+set(SOURCES src/main.c src/util.c)
+if(WIN32)
+  add_compile_options(-W4) # Comment
+endif()
+
+add_executable(app ${SOURCES})
+message(STATUS "Building ${PROJECT_NAME} in $ENV{HOME}" [=[raw]=])
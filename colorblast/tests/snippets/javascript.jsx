@@ -0,0 +1,17 @@
+// Counter component
+import { useState } from "react";
+
+export default function Counter({ initial = 0 }) {
+  const [count, setCount] = useState(initial);
+  const label = `Count: ${count}`;
+  const isValid = /^\d+$/.test(String(count));
+
+  return (
+    <>
+      <button className="btn" onClick={() => setCount(count + 1)} disabled={!isValid}>
+        {label}
+      </button>
+      <Foo.Bar {...props} aria-label='x' />
+    </>
+  );
+}
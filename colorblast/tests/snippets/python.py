@@ -0,0 +1,10 @@
+@dataclass
+class Point:
+    x: int = 0
+
+def main():
+    # Comment
+    for i in range(10):
+        if i % 2 == 0:
+            print(f"{i} is even", Point(x=i))
+    return None
@@ -0,0 +1,25 @@
+use std::fmt;
+
+/// Outer doc comment
+#[derive(Clone, Debug)]
+pub struct Point<'a> {
+    name: &'a str,
+    x: f32,
+    y: f32,
+}
+
+impl fmt::Display for Point<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} ({}, {})", self.name, self.x, self.y)
+    }
+}
+
+fn main() {
+    // Line comment
+    let p = Point { name: "origin", x: 0.0, y: 1e-3 };
+    let c = '<';
+    let s = r#"raw & "quoted""#;
+    if let Some(n) = Some(0xFF_u8) {
+        println!("{p} {c} {s} {n}");
+    }
+}
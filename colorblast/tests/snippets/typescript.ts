@@ -0,0 +1,20 @@
+interface Point {
+  readonly x: number;
+  y?: number;
+}
+
+type Pair<T> = [T, T];
+
+export class Vec2 implements Point {
+  constructor(public x: number, public y = 0) {}
+
+  static from(p: Point): Vec2 {
+    return new Vec2(p.x, p.y ?? 0);
+  }
+
+  len(): number {
+    return Math.sqrt(this.x ** 2 + this.y ** 2);
+  }
+}
+
+const pairs: Pair<number>[] = [[1, 2], [0x10, 1e-3]];
@@ -0,0 +1,89 @@
+use std::hint::black_box;
+
+use criterion::{criterion_group, criterion_main, Criterion};
+use text_scanner::ext::JsonScannerExt;
+use text_scanner::{Dispatcher, Scanner, ScannerResult};
+
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+enum Token {
+    Space,
+    String,
+    Number,
+    Keyword,
+    Punct,
+}
+
+#[inline]
+fn scan_chain<'text>(scanner: &mut Scanner<'text>) -> ScannerResult<'text, Token> {
+    if let Ok((r, _s)) = scanner.scan_json_string() {
+        return Ok((r, Token::String));
+    }
+    if let Ok((r, _s)) = scanner.scan_json_number() {
+        return Ok((r, Token::Number));
+    }
+    if let Ok((r, _s)) = scanner.accept_str_any(&["true", "false", "null"]) {
+        return Ok((r, Token::Keyword));
+    }
+    if let Ok((r, _c)) = scanner.accept_char_any(&['{', '}', '[', ']', ':', ',']) {
+        return Ok((r, Token::Punct));
+    }
+    let (r, _s) = scanner.skip_whitespace();
+    if !r.is_empty() {
+        return Ok((r, Token::Space));
+    }
+    Err((r, ""))
+}
+
+fn dispatcher() -> Dispatcher<Token> {
+    Dispatcher::new()
+        .on('"', |scanner| {
+            scanner.scan_json_string().map(|(r, _s)| (r, Token::String))
+        })
+        .on(
+            ['-', '0', '1', '2', '3', '4', '5', '6', '7', '8', '9'],
+            |scanner| scanner.scan_json_number().map(|(r, _s)| (r, Token::Number)),
+        )
+        .on(['t', 'f', 'n'], |scanner| {
+            scanner
+                .accept_str_any(&["true", "false", "null"])
+                .map(|(r, _s)| (r, Token::Keyword))
+        })
+        .on(['{', '}', '[', ']', ':', ','], |scanner| {
+            scanner.next().map(|(r, _c)| (r, Token::Punct))
+        })
+        .on(char::is_whitespace as fn(char) -> bool, |scanner| {
+            let (r, _s) = scanner.skip_whitespace();
+            Ok((r, Token::Space))
+        })
+}
+
+fn bench_dispatch(c: &mut Criterion) {
+    let text = r#"{"name": "colorblast", "version": [0, 0, 3], "stable": false, "ratio": -1.5e3, "parent": null}
+"#
+    .repeat(100);
+    let dispatcher = dispatcher();
+
+    let mut group = c.benchmark_group("dispatch");
+    group.bench_function("chain", |b| {
+        b.iter(|| {
+            let mut scanner = Scanner::new(black_box(&text));
+            while let Ok(tok) = scan_chain(&mut scanner) {
+                black_box(tok);
+            }
+            assert!(!scanner.has_remaining_text());
+        });
+    });
+    group.bench_function("dispatcher", |b| {
+        b.iter(|| {
+            let mut scanner = Scanner::new(black_box(&text));
+            while let Ok(tok) = dispatcher.scan(&mut scanner) {
+                black_box(tok);
+            }
+            assert!(!scanner.has_remaining_text());
+        });
+    });
+    group.finish();
+}
+
+criterion_group!(benches, bench_dispatch);
+criterion_main!(benches);
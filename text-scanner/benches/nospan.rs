@@ -0,0 +1,109 @@
+//! Compares [`Scanner::accept_if()`] and [`Scanner::skip_while()`] against
+//! variants which do not construct a [`Range`](std::ops::Range), i.e. which
+//! only return the `char` or `&str`.
+//!
+//! At the time of writing, the variants without ranges are not faster, as
+//! the ranges are optimized out when unused. So the variants are kept here,
+//! instead of being added to [`Scanner`].
+
+use std::hint::black_box;
+
+use criterion::{criterion_group, criterion_main, Criterion};
+use text_scanner::Scanner;
+
+trait NoSpan<'text> {
+    fn accept_if_nospan<F>(&mut self, f: F) -> Option<char>
+    where
+        F: FnOnce(char) -> bool;
+
+    fn skip_while_nospan<F>(&mut self, f: F) -> &'text str
+    where
+        F: FnMut(char) -> bool;
+}
+
+impl<'text> NoSpan<'text> for Scanner<'text> {
+    #[inline]
+    fn accept_if_nospan<F>(&mut self, f: F) -> Option<char>
+    where
+        F: FnOnce(char) -> bool,
+    {
+        let c = self.remaining_text().chars().next()?;
+        if f(c) {
+            self.set_cursor_pos(self.cursor_pos() + c.len_utf8());
+            Some(c)
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    fn skip_while_nospan<F>(&mut self, mut f: F) -> &'text str
+    where
+        F: FnMut(char) -> bool,
+    {
+        let text = self.remaining_text();
+        let len = text
+            .char_indices()
+            .find(|&(_, c)| !f(c))
+            .map_or(text.len(), |(i, _)| i);
+        self.set_cursor_pos(self.cursor_pos() + len);
+        &text[..len]
+    }
+}
+
+fn bench_nospan(c: &mut Criterion) {
+    // Many tiny tokens, i.e. where range bookkeeping would dominate
+    let text = black_box("a1 b2 c3 d4 e5 f6 g7 h8 i9 j0 ".repeat(16));
+
+    let mut group = c.benchmark_group("accept_if");
+    group.bench_function("accept_if", |b| {
+        b.iter(|| {
+            let mut scanner = Scanner::new(&text);
+            let mut count = 0usize;
+            while let Ok((_r, c)) = scanner.accept_if(|c| c != '\n') {
+                count += c.len_utf8();
+            }
+            count
+        });
+    });
+    group.bench_function("accept_if_nospan", |b| {
+        b.iter(|| {
+            let mut scanner = Scanner::new(&text);
+            let mut count = 0usize;
+            while let Some(c) = scanner.accept_if_nospan(|c| c != '\n') {
+                count += c.len_utf8();
+            }
+            count
+        });
+    });
+    group.finish();
+
+    let mut group = c.benchmark_group("skip_while");
+    group.bench_function("skip_while", |b| {
+        b.iter(|| {
+            let mut scanner = Scanner::new(&text);
+            let mut count = 0usize;
+            while scanner.has_remaining_text() {
+                let (_r, s) = scanner.skip_while(char::is_alphanumeric);
+                count += s.len();
+                scanner.skip_while(char::is_whitespace);
+            }
+            count
+        });
+    });
+    group.bench_function("skip_while_nospan", |b| {
+        b.iter(|| {
+            let mut scanner = Scanner::new(&text);
+            let mut count = 0usize;
+            while scanner.has_remaining_text() {
+                count += scanner.skip_while_nospan(char::is_alphanumeric).len();
+                scanner.skip_while_nospan(char::is_whitespace);
+            }
+            count
+        });
+    });
+    group.finish();
+}
+
+criterion_group!(benches, bench_nospan);
+criterion_main!(benches);
@@ -35,11 +35,11 @@ where
             continue;
         }
 
-        if let Ok(_) = f(scanner) {
+        if f(scanner).is_ok() {
             continue;
         }
 
-        if let Ok(_) = scanner.next_line_terminator() {
+        if scanner.next_line_terminator().is_ok() {
             continue;
         }
 
@@ -0,0 +1,65 @@
+use std::hint::black_box;
+
+use criterion::{criterion_group, criterion_main, Criterion};
+use text_scanner::{Scanner, ScannerItem};
+
+/// The previous implementations, which repeatedly called `accept_str()`.
+trait SkipWhileStrAccept<'text> {
+    fn skip_while_str_accept(&mut self, expected: &str) -> ScannerItem<&'text str>;
+    fn skip_while_str_any_accept(&mut self, expected: &[&str]) -> ScannerItem<&'text str>;
+}
+
+impl<'text> SkipWhileStrAccept<'text> for Scanner<'text> {
+    #[inline]
+    fn skip_while_str_accept(&mut self, expected: &str) -> ScannerItem<&'text str> {
+        let start = self.cursor_pos();
+        while self.accept_str(expected).is_ok() {}
+        self.ranged_text(start..self.cursor_pos())
+    }
+
+    #[inline]
+    fn skip_while_str_any_accept(&mut self, expected: &[&str]) -> ScannerItem<&'text str> {
+        let start = self.cursor_pos();
+        while self.accept_str_any(expected).is_ok() {}
+        self.ranged_text(start..self.cursor_pos())
+    }
+}
+
+fn bench_skip_while_str(c: &mut Criterion) {
+    // Long repeated delimiter, e.g. a Markdown horizontal rule
+    let text = "-=".repeat(1024) + "-";
+    let scanner = Scanner::new(black_box(&text));
+
+    let mut group = c.benchmark_group("skip_while_str");
+    group.bench_function("accept_str", |b| {
+        b.iter(|| {
+            let mut scanner = scanner.clone();
+            scanner.skip_while_str_accept(black_box("-="))
+        });
+    });
+    group.bench_function("memcmp", |b| {
+        b.iter(|| {
+            let mut scanner = scanner.clone();
+            scanner.skip_while_str(black_box("-="))
+        });
+    });
+    group.finish();
+
+    let mut group = c.benchmark_group("skip_while_str_any");
+    group.bench_function("accept_str_any", |b| {
+        b.iter(|| {
+            let mut scanner = scanner.clone();
+            scanner.skip_while_str_any_accept(&black_box(["--", "-="]))
+        });
+    });
+    group.bench_function("memcmp", |b| {
+        b.iter(|| {
+            let mut scanner = scanner.clone();
+            scanner.skip_while_str_any(&black_box(["--", "-="]))
+        });
+    });
+    group.finish();
+}
+
+criterion_group!(benches, bench_skip_while_str);
+criterion_main!(benches);
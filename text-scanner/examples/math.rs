@@ -184,9 +184,7 @@ impl<'text> ExprParser<'text> {
     }
 
     fn parse_expr_value(&mut self) -> Result<Expr<'text>, Box<dyn error::Error>> {
-        let tok = self
-            .next_token()?
-            .ok_or_else(|| "unexpected end of input")?;
+        let tok = self.next_token()?.ok_or("unexpected end of input")?;
         match tok {
             Token::Ident(ident) => Ok(Expr::Ident(ident)),
             Token::Int(i) => Ok(Expr::Int(i)),
@@ -194,9 +192,7 @@ impl<'text> ExprParser<'text> {
             Token::Sym(Sym::LParen) => {
                 let expr = self.parse_expr()?;
 
-                let tok = self
-                    .next_token()?
-                    .ok_or_else(|| "unexpected end of input")?;
+                let tok = self.next_token()?.ok_or("unexpected end of input")?;
                 if tok != Token::Sym(Sym::RParen) {
                     return Err(format!("expected `)` found {:?}", tok).into());
                 }
@@ -272,7 +268,7 @@ fn main() {
                 break;
             }
 
-            eval(&input);
+            eval(input);
         }
     }
 }
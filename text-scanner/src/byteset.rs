@@ -0,0 +1,235 @@
+use std::fmt;
+
+/// Set of ASCII characters, implemented as a bitmap, used by
+/// [`Scanner::skip_while_in()`] and [`Scanner::skip_until_in()`] as
+/// a fast path over closures like <code>|c| c.is_alphanumeric() || c == '_'</code>.
+///
+/// Non-ASCII characters are never contained in the bitmap. Instead
+/// they can be matched by a predicate, set using [`with_non_ascii()`].
+///
+/// # Example
+///
+/// ```rust
+/// # use text_scanner::{ByteSet, Scanner};
+/// const HEX_OR_UNDERSCORE: ByteSet = ByteSet::ASCII_HEXDIGIT.union(ByteSet::from_bytes(b"_"));
+///
+/// let mut scanner = Scanner::new("FF_00 rest");
+/// assert_eq!(scanner.skip_while_in(&HEX_OR_UNDERSCORE), (0..5, "FF_00"));
+/// assert_eq!(scanner.remaining_text(), " rest");
+///
+/// let mut scanner = Scanner::new("ÆØÅ_1 rest");
+/// assert_eq!(scanner.skip_while_in(&ByteSet::IDENT_CONTINUE), (0..8, "ÆØÅ_1"));
+/// ```
+///
+/// [`Scanner::skip_while_in()`]: crate::Scanner::skip_while_in
+/// [`Scanner::skip_until_in()`]: crate::Scanner::skip_until_in
+/// [`with_non_ascii()`]: Self::with_non_ascii
+#[derive(Clone, Copy)]
+pub struct ByteSet {
+    ascii: u128,
+    non_ascii: Option<fn(char) -> bool>,
+}
+
+impl ByteSet {
+    /// Set containing no characters.
+    pub const EMPTY: Self = Self {
+        ascii: 0,
+        non_ascii: None,
+    };
+
+    /// `0-9`, same as [`char::is_ascii_digit()`].
+    pub const ASCII_DIGIT: Self = Self::from_range(b'0', b'9');
+
+    /// `0-9`, `a-f`, and `A-F`, same as [`char::is_ascii_hexdigit()`].
+    pub const ASCII_HEXDIGIT: Self = Self::ASCII_DIGIT
+        .union(Self::from_range(b'a', b'f'))
+        .union(Self::from_range(b'A', b'F'));
+
+    /// `a-z` and `A-Z`, same as [`char::is_ascii_alphabetic()`].
+    pub const ASCII_ALPHABETIC: Self =
+        Self::from_range(b'a', b'z').union(Self::from_range(b'A', b'Z'));
+
+    /// `0-9`, `a-z`, and `A-Z`, same as [`char::is_ascii_alphanumeric()`].
+    pub const ASCII_ALPHANUMERIC: Self = Self::ASCII_DIGIT.union(Self::ASCII_ALPHABETIC);
+
+    /// Same as [`char::is_ascii_whitespace()`].
+    pub const ASCII_WHITESPACE: Self = Self::from_bytes(b" \t\n\x0C\r");
+
+    /// Alphanumeric characters and `_`, i.e. the characters continuing an
+    /// identifier in many languages, same as
+    /// <code>|c| c.is_alphanumeric() || c == '_'</code>.
+    pub const IDENT_CONTINUE: Self = Self::ASCII_ALPHANUMERIC
+        .union(Self::from_bytes(b"_"))
+        .with_non_ascii(char::is_alphanumeric);
+
+    pub(crate) const ASCII_DIGIT_OR_UNDERSCORE: Self =
+        Self::ASCII_DIGIT.union(Self::from_bytes(b"_"));
+    #[cfg(feature = "ext")]
+    pub(crate) const ASCII_HEXDIGIT_OR_UNDERSCORE: Self =
+        Self::ASCII_HEXDIGIT.union(Self::from_bytes(b"_"));
+
+    /// Returns a set containing the ASCII characters in `bytes`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `bytes` contains non-ASCII bytes.
+    pub const fn from_bytes(bytes: &[u8]) -> Self {
+        let mut ascii = 0;
+        let mut i = 0;
+        while i < bytes.len() {
+            assert!(bytes[i].is_ascii(), "`bytes` must be ASCII");
+            ascii |= 1 << bytes[i];
+            i += 1;
+        }
+        Self {
+            ascii,
+            non_ascii: None,
+        }
+    }
+
+    /// Returns a set containing the ASCII characters in
+    /// the inclusive range `start..=end`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `end` is not ASCII.
+    pub const fn from_range(start: u8, end: u8) -> Self {
+        assert!(end.is_ascii(), "`end` must be ASCII");
+        let mut ascii = 0;
+        let mut b = start;
+        while b <= end {
+            ascii |= 1 << b;
+            b += 1;
+        }
+        Self {
+            ascii,
+            non_ascii: None,
+        }
+    }
+
+    /// Returns a set matching non-ASCII characters using `f`.
+    ///
+    /// Replaces any existing predicate.
+    #[inline]
+    pub const fn with_non_ascii(self, f: fn(char) -> bool) -> Self {
+        Self {
+            ascii: self.ascii,
+            non_ascii: Some(f),
+        }
+    }
+
+    /// Returns the union of `self` and `other`.
+    ///
+    /// The non-ASCII predicate of `self` is used if it has one,
+    /// otherwise the one of `other`.
+    #[inline]
+    pub const fn union(self, other: Self) -> Self {
+        Self {
+            ascii: self.ascii | other.ascii,
+            non_ascii: match self.non_ascii {
+                Some(f) => Some(f),
+                None => other.non_ascii,
+            },
+        }
+    }
+
+    /// Returns `true` if `b` is an ASCII character contained in the set.
+    #[inline]
+    pub const fn contains(&self, b: u8) -> bool {
+        b.is_ascii() && ((self.ascii >> b) & 1) == 1
+    }
+
+    /// Returns `true` if `c` is contained in the set, or `c` is non-ASCII
+    /// and matched by the non-ASCII predicate.
+    #[inline]
+    pub fn contains_char(&self, c: char) -> bool {
+        if c.is_ascii() {
+            self.contains(c as u8)
+        } else {
+            self.non_ascii.is_some_and(|f| f(c))
+        }
+    }
+}
+
+impl Default for ByteSet {
+    #[inline]
+    fn default() -> Self {
+        Self::EMPTY
+    }
+}
+
+impl fmt::Debug for ByteSet {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let chars = (0..128u8)
+            .filter(|&b| self.contains(b))
+            .map(char::from)
+            .collect::<String>();
+        f.debug_struct("ByteSet")
+            .field("ascii", &chars)
+            .field("non_ascii", &self.non_ascii.is_some())
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Scanner;
+
+    #[test]
+    fn test_byte_set() {
+        #[rustfmt::skip]
+        let sets = [
+            (ByteSet::ASCII_DIGIT, char::is_ascii_digit as fn(&char) -> bool),
+            (ByteSet::ASCII_HEXDIGIT, char::is_ascii_hexdigit),
+            (ByteSet::ASCII_ALPHABETIC, char::is_ascii_alphabetic),
+            (ByteSet::ASCII_ALPHANUMERIC, char::is_ascii_alphanumeric),
+            (ByteSet::ASCII_WHITESPACE, char::is_ascii_whitespace),
+        ];
+        for (set, f) in sets {
+            for c in (0..=255u8).map(char::from) {
+                assert_eq!(set.contains_char(c), f(&c), "{set:?} {c:?}");
+            }
+        }
+
+        for c in ['a', 'Z', '0', '_', 'æ', '東', '-', ' ', '🦀'] {
+            let expected = c.is_alphanumeric() || (c == '_');
+            assert_eq!(ByteSet::IDENT_CONTINUE.contains_char(c), expected, "{c:?}");
+        }
+
+        assert!(!ByteSet::EMPTY.contains(0));
+        assert!(ByteSet::from_range(0, 127).contains(127));
+        assert!(!ByteSet::from_range(0, 127).contains(128));
+    }
+
+    #[test]
+    fn test_skip_in() {
+        let set = ByteSet::IDENT_CONTINUE;
+
+        let cases = [
+            ("", ""),
+            ("abc", "abc"),
+            ("a_1 b", "a_1"),
+            ("æø東_ x", "æø東_"),
+            ("a🦀", "a"),
+            ("-a", ""),
+        ];
+        for (text, expected) in cases {
+            let mut scanner = Scanner::new(text);
+            assert_eq!(scanner.skip_while_in(&set), (0..expected.len(), expected));
+            assert_eq!(scanner.remaining_text(), &text[expected.len()..]);
+
+            let mut scanner = Scanner::new(text);
+            let expected = scanner.clone().skip_until(|c| set.contains_char(c)).1;
+            assert_eq!(scanner.skip_until_in(&set), (0..expected.len(), expected));
+        }
+
+        let mut scanner = Scanner::new("ab 12");
+        assert_eq!(
+            scanner.skip_while_in(&ByteSet::ASCII_ALPHABETIC),
+            (0..2, "ab")
+        );
+        assert_eq!(scanner.skip_until_in(&ByteSet::ASCII_DIGIT), (2..3, " "));
+        assert_eq!(scanner.skip_while_in(&ByteSet::ASCII_DIGIT), (3..5, "12"));
+    }
+}
@@ -0,0 +1,72 @@
+/// Stack of the closing delimiters expected by nested delimiters, e.g. `)`
+/// after `(`, for skipping nested delimiters without allocating.
+///
+/// Only the outermost [`Closers::CAPACITY`] closing delimiters are stored.
+/// Any deeper nesting is only counted, and is assumed to be closed by the
+/// innermost stored closing delimiter.
+#[derive(Clone, Debug)]
+pub(crate) struct Closers {
+    closers: [char; Self::CAPACITY],
+    len: usize,
+}
+
+impl Closers {
+    pub(crate) const CAPACITY: usize = 32;
+
+    #[inline]
+    pub(crate) const fn new() -> Self {
+        Self {
+            closers: ['\0'; Self::CAPACITY],
+            len: 0,
+        }
+    }
+
+    #[inline]
+    pub(crate) fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    #[inline]
+    pub(crate) fn push(&mut self, close: char) {
+        if let Some(slot) = self.closers.get_mut(self.len) {
+            *slot = close;
+        }
+        self.len += 1;
+    }
+
+    /// Pops the innermost closing delimiter, if it is `c`,
+    /// and returns whether it was popped.
+    #[inline]
+    pub(crate) fn pop_if(&mut self, c: char) -> bool {
+        if self.is_empty() {
+            return false;
+        }
+        let last = self.closers[self.len.min(Self::CAPACITY) - 1];
+        if last == c {
+            self.len -= 1;
+        }
+        last == c
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_closers_overflow() {
+        let mut closers = Closers::new();
+        for _ in 0..(Closers::CAPACITY + 2) {
+            closers.push(')');
+        }
+        closers.push(']');
+
+        // The outermost closers are still balanced
+        for _ in 0..(Closers::CAPACITY + 3) {
+            assert!(!closers.pop_if('}'));
+            assert!(closers.pop_if(')'));
+        }
+        assert!(closers.is_empty());
+        assert!(!closers.pop_if(')'));
+    }
+}
@@ -0,0 +1,314 @@
+use std::fmt;
+use std::ops::{Range, RangeInclusive};
+
+use crate::{ByteSet, Scanner, ScannerResult};
+
+/// Scan function used by [`Dispatcher`], e.g. a closure
+/// <code>|scanner| scanner.[scan_digits]().map(|(r, _s)| (r, Token::Number))</code>.
+///
+/// [scan_digits]: Scanner::scan_digits
+pub type ScanFn<T> = for<'text> fn(&mut Scanner<'text>) -> ScannerResult<'text, T>;
+
+/// Class of characters a [`Dispatcher`] rule can start with.
+///
+/// Implemented for [`char`], <code>[RangeInclusive]&lt;[char]&gt;</code>,
+/// `[char; N]`, [`ByteSet`], and `fn(char) -> bool`.
+pub trait CharClass {
+    fn contains(&self, c: char) -> bool;
+}
+
+impl CharClass for char {
+    #[inline]
+    fn contains(&self, c: char) -> bool {
+        *self == c
+    }
+}
+
+impl CharClass for RangeInclusive<char> {
+    #[inline]
+    fn contains(&self, c: char) -> bool {
+        RangeInclusive::contains(self, &c)
+    }
+}
+
+impl<const N: usize> CharClass for [char; N] {
+    #[inline]
+    fn contains(&self, c: char) -> bool {
+        self.as_slice().contains(&c)
+    }
+}
+
+impl CharClass for ByteSet {
+    #[inline]
+    fn contains(&self, c: char) -> bool {
+        self.contains_char(c)
+    }
+}
+
+impl CharClass for fn(char) -> bool {
+    #[inline]
+    fn contains(&self, c: char) -> bool {
+        self(c)
+    }
+}
+
+/// Table of scan functions keyed by the class of the first character,
+/// replacing chains of `if scanner.scan_a().is_ok() { .. } else if ..`.
+///
+/// [`scan()`] only tries the rules whose class contains the next
+/// character, instead of every rule in turn, and resolves to the
+/// longest match. If multiple rules match the same length, then the
+/// rule added first wins.
+///
+/// The candidates for ASCII characters are resolved using a lookup
+/// table, when the rule is added. Non-ASCII characters are tested
+/// against each class.
+///
+/// # Example
+///
+/// ```rust
+/// # use text_scanner::{Dispatcher, Scanner};
+/// #[derive(PartialEq, Eq, Debug)]
+/// enum Token {
+///     Number,
+///     Ident,
+///     Keyword,
+///     Punct,
+/// }
+///
+/// let dispatcher = Dispatcher::new()
+///     .on('0'..='9', |scanner| scanner.scan_digits().map(|(r, _s)| (r, Token::Number)))
+///     .on('-', |scanner| {
+///         scanner
+///             .scan_with(|scanner| {
+///                 scanner.accept_char('-')?;
+///                 scanner.scan_digits()?;
+///                 Ok(())
+///             })
+///             .map(|(r, _s)| (r, Token::Number))
+///     })
+///     .on(['-', '+'], |scanner| scanner.next().map(|(r, _c)| (r, Token::Punct)))
+///     .on('a'..='z', |scanner| {
+///         let (r, _s) = scanner.skip_while(|c| c.is_ascii_lowercase());
+///         Ok((r, Token::Ident))
+///     })
+///     .on(['f', 'l'], |scanner| {
+///         scanner
+///             .accept_str_any(&["fn", "let"])
+///             .map(|(r, _s)| (r, Token::Keyword))
+///     });
+///
+/// let mut scanner = Scanner::new("-12-let+fnord?");
+/// assert_eq!(dispatcher.scan(&mut scanner), Ok((0..3, Token::Number)));
+/// assert_eq!(dispatcher.scan(&mut scanner), Ok((3..4, Token::Punct)));
+/// // `Ident` and `Keyword` have the same length, and `Ident` was added first
+/// assert_eq!(dispatcher.scan(&mut scanner), Ok((4..7, Token::Ident)));
+/// assert_eq!(dispatcher.scan(&mut scanner), Ok((7..8, Token::Punct)));
+/// // `Ident` is longer than `Keyword`
+/// assert_eq!(dispatcher.scan(&mut scanner), Ok((8..13, Token::Ident)));
+/// assert_eq!(dispatcher.scan(&mut scanner), Err((13..13, "")));
+/// assert_eq!(scanner.remaining_text(), "?");
+/// ```
+///
+/// [`scan()`]: Self::scan
+pub struct Dispatcher<T> {
+    rules: Vec<ScanFn<T>>,
+    /// Indices into `rules` for each ASCII character.
+    ascii: [Vec<usize>; 128],
+    /// Classes and indices into `rules`, for non-ASCII characters.
+    non_ascii: Vec<(Box<dyn CharClass + Send + Sync>, usize)>,
+}
+
+impl<T> Dispatcher<T> {
+    /// Constructs a new [`Dispatcher`] without any rules.
+    #[inline]
+    pub fn new() -> Self {
+        Self {
+            rules: Vec::new(),
+            ascii: std::array::from_fn(|_| Vec::new()),
+            non_ascii: Vec::new(),
+        }
+    }
+
+    /// Adds a rule trying `f` when the next character is in `class`.
+    ///
+    /// The same class can be used by multiple rules, and
+    /// the classes of rules may overlap.
+    pub fn on<C>(mut self, class: C, f: ScanFn<T>) -> Self
+    where
+        C: CharClass + Send + Sync + 'static,
+    {
+        let rule = self.rules.len();
+        self.rules.push(f);
+
+        for (b, rules) in self.ascii.iter_mut().enumerate() {
+            if class.contains(char::from(b as u8)) {
+                rules.push(rule);
+            }
+        }
+        self.non_ascii.push((Box::new(class), rule));
+
+        self
+    }
+
+    /// Adds a rule trying `f` regardless of the next character,
+    /// e.g. for a rule matching any remaining character.
+    #[inline]
+    pub fn on_any(self, f: ScanFn<T>) -> Self {
+        self.on((|_| true) as fn(char) -> bool, f)
+    }
+
+    /// Returns the amount of rules.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.rules.len()
+    }
+
+    /// Returns `true` if there are no rules.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.rules.is_empty()
+    }
+
+    /// Tries all rules matching the next character, and advances the
+    /// cursor past the longest match. Rules that succeed without
+    /// advancing the cursor are ignored.
+    ///
+    /// If no rules match, then `Err` is returned and the cursor
+    /// remains unchanged.
+    pub fn scan<'text>(&self, scanner: &mut Scanner<'text>) -> ScannerResult<'text, T> {
+        let start = scanner.cursor_pos();
+        let Ok((_r, c)) = scanner.peek() else {
+            return Err((start..start, ""));
+        };
+
+        let mut best: Option<(usize, (Range<usize>, T))> = None;
+        let mut try_rule = |rule: usize| {
+            scanner.set_cursor_pos(start);
+            if let Ok(item) = self.rules[rule](scanner) {
+                let end = scanner.cursor_pos();
+                let is_longer = match &best {
+                    Some((best_end, _item)) => end > *best_end,
+                    None => end > start,
+                };
+                if is_longer {
+                    best = Some((end, item));
+                }
+            }
+        };
+
+        if c.is_ascii() {
+            for &rule in &self.ascii[c as usize] {
+                try_rule(rule);
+            }
+        } else {
+            for (_class, rule) in self.non_ascii.iter().filter(|(class, _)| class.contains(c)) {
+                try_rule(*rule);
+            }
+        }
+
+        match best {
+            Some((end, item)) => {
+                scanner.set_cursor_pos(end);
+                Ok(item)
+            }
+            None => {
+                scanner.set_cursor_pos(start);
+                Err((start..start, ""))
+            }
+        }
+    }
+}
+
+impl<T> Default for Dispatcher<T> {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> fmt::Debug for Dispatcher<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Dispatcher")
+            .field("rules", &self.rules.len())
+            .finish_non_exhaustive()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(PartialEq, Eq, Clone, Copy, Debug)]
+    enum Tok {
+        A,
+        B,
+        Empty,
+        Any,
+    }
+
+    #[test]
+    fn test_dispatcher_longest_match() {
+        let dispatcher = Dispatcher::new()
+            .on('a', |scanner| {
+                scanner.accept_str("a").map(|(r, _s)| (r, Tok::A))
+            })
+            .on('a', |scanner| {
+                scanner.accept_str("ab").map(|(r, _s)| (r, Tok::B))
+            })
+            .on('a', |scanner| {
+                scanner.accept_str("a").map(|(r, _s)| (r, Tok::Any))
+            });
+
+        let cases = [
+            ("a", Ok((0..1, Tok::A)), ""),
+            ("ab", Ok((0..2, Tok::B)), ""),
+            ("ac", Ok((0..1, Tok::A)), "c"),
+            ("b", Err((0..0, "")), "b"),
+            ("", Err((0..0, "")), ""),
+        ];
+        for (text, expected, remaining) in cases {
+            let mut scanner = Scanner::new(text);
+            assert_eq!(dispatcher.scan(&mut scanner), expected, "{text:?}");
+            assert_eq!(scanner.remaining_text(), remaining, "{text:?}");
+        }
+    }
+
+    #[test]
+    fn test_dispatcher_empty_match() {
+        let dispatcher = Dispatcher::new()
+            .on('a', |scanner| {
+                let (r, _s) = scanner.skip_while(|c| c == 'b');
+                Ok((r, Tok::Empty))
+            })
+            .on_any(|scanner| scanner.next().map(|(r, _c)| (r, Tok::Any)));
+
+        let mut scanner = Scanner::new("ab");
+        assert_eq!(dispatcher.scan(&mut scanner), Ok((0..1, Tok::Any)));
+        assert_eq!(dispatcher.scan(&mut scanner), Ok((1..2, Tok::Any)));
+        assert_eq!(dispatcher.scan(&mut scanner), Err((2..2, "")));
+    }
+
+    #[test]
+    fn test_dispatcher_classes() {
+        let dispatcher = Dispatcher::new()
+            .on(ByteSet::ASCII_DIGIT, |scanner| {
+                scanner.scan_digits().map(|(r, _s)| (r, Tok::A))
+            })
+            .on(char::is_alphabetic as fn(char) -> bool, |scanner| {
+                let (r, _s) = scanner.skip_while(char::is_alphabetic);
+                Ok((r, Tok::B))
+            })
+            .on('🦀', |scanner| {
+                scanner.next().map(|(r, _c)| (r, Tok::Any))
+            });
+        assert_eq!(dispatcher.len(), 3);
+
+        let mut scanner = Scanner::new("12æøå🦀x");
+        assert_eq!(dispatcher.scan(&mut scanner), Ok((0..2, Tok::A)));
+        assert_eq!(dispatcher.scan(&mut scanner), Ok((2..8, Tok::B)));
+        assert_eq!(dispatcher.scan(&mut scanner), Ok((8..12, Tok::Any)));
+        assert_eq!(dispatcher.scan(&mut scanner), Ok((12..13, Tok::B)));
+        assert_eq!(dispatcher.scan(&mut scanner), Err((13..13, "")));
+    }
+}
@@ -0,0 +1,297 @@
+use crate::{IdentifierCharset, ScanOutcome, Scanner, ScannerResult};
+
+/// [`Scanner`] extension for scanning Apache HTTP Server configuration
+/// tokens, e.g. `httpd.conf` and `.htaccess` files.
+///
+/// **Note:** Apache configuration is line-oriented, e.g. comments and
+/// directives are only recognized at the start of a line. The scanning
+/// methods do not check this, that is up to the caller.
+///
+/// _Based on [Configuration Files]_.
+///
+/// [Configuration Files]: https://httpd.apache.org/docs/current/configuring.html
+pub trait ApacheScannerExt<'text>: crate::private::Sealed {
+    /// Scans an Apache line comment, e.g. `# comment`.
+    fn scan_apache_line_comment(&mut self) -> ScannerResult<'text, &'text str>;
+
+    /// Scans an Apache directive name, e.g. `ServerName` and `Listen`.
+    fn scan_apache_identifier(&mut self) -> ScannerResult<'text, &'text str>;
+
+    /// Scans the start of an Apache section tag, i.e. `<` or `</` followed
+    /// by the section name, e.g. `<VirtualHost` and `</Directory`. The
+    /// arguments and closing `>` are not included.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use text_scanner::{ext::ApacheScannerExt, Scanner};
+    ///
+    /// let text = "<VirtualHost *:80>";
+    /// let mut scanner = Scanner::new(text);
+    ///
+    /// assert_eq!(scanner.scan_apache_section_open(), Ok((0..12, "<VirtualHost")));
+    /// assert_eq!(scanner.remaining_text(), " *:80>");
+    /// ```
+    fn scan_apache_section_open(&mut self) -> ScannerResult<'text, &'text str>;
+
+    /// Scans an Apache variable, i.e. a `Define` variable, e.g. `${SRVROOT}`,
+    /// a server or environment variable, e.g. `%{HTTP_HOST}` and
+    /// `%{ENV:PATH}`, or a backreference, e.g. `$1` and `%1`.
+    fn scan_apache_variable(&mut self) -> ScannerResult<'text, &'text str>;
+
+    /// Scans a single Apache string, i.e. `"..."` or `'...'`, including
+    /// any variables and escape sequences.
+    ///
+    /// Strings are unterminated at the end of the line.
+    fn scan_apache_string(&mut self) -> ScannerResult<'text, &'text str>;
+    /// Same as [`scan_apache_string()`], but additionally returns
+    /// whether the string is unterminated.
+    ///
+    /// [`scan_apache_string()`]: Self::scan_apache_string
+    fn scan_apache_string_outcome(&mut self) -> ScannerResult<'text, ScanOutcome>;
+
+    /// Scans an Apache line continuation, i.e. `\` at the end of a line.
+    /// The line break is not included.
+    fn scan_apache_continuation(&mut self) -> ScannerResult<'text, &'text str>;
+
+    /// Scans the text of an Apache unquoted argument, e.g. `/var/www`,
+    /// `*:80`, and `^/old/(.*)$`, until whitespace, `>`, a variable,
+    /// or a line continuation.
+    fn scan_apache_word(&mut self) -> ScannerResult<'text, &'text str>;
+}
+
+impl<'text> ApacheScannerExt<'text> for Scanner<'text> {
+    // Reference: https://httpd.apache.org/docs/current/configuring.html#syntax
+    fn scan_apache_line_comment(&mut self) -> ScannerResult<'text, &'text str> {
+        self.scan_with(|scanner| {
+            scanner.accept_char('#')?;
+            scanner.skip_until_char_any(&['\n', '\r']);
+            Ok(())
+        })
+    }
+
+    // Reference: https://httpd.apache.org/docs/current/configuring.html#syntax
+    fn scan_apache_identifier(&mut self) -> ScannerResult<'text, &'text str> {
+        self.scan_identifier_with_charset(IdentifierCharset::C)
+    }
+
+    // Reference: https://httpd.apache.org/docs/current/sections.html
+    fn scan_apache_section_open(&mut self) -> ScannerResult<'text, &'text str> {
+        self.scan_with(|scanner| {
+            scanner.accept_char('<')?;
+            _ = scanner.accept_char('/');
+            scanner.scan_apache_identifier()?;
+
+            match scanner.peek() {
+                Ok((r, c)) if !c.is_whitespace() && (c != '>') => Err(scanner.ranged_text(r)),
+                _ => Ok(()),
+            }
+        })
+    }
+
+    // Reference: https://httpd.apache.org/docs/current/configuring.html#syntax
+    // Reference: https://httpd.apache.org/docs/current/mod/mod_rewrite.html#rewritecond
+    fn scan_apache_variable(&mut self) -> ScannerResult<'text, &'text str> {
+        self.scan_with(|scanner| {
+            let (_r, c) = scanner.accept_char_any(&['$', '%'])?;
+
+            // Backreference, e.g. `$1` and `%1`
+            if scanner.accept_if(|c| c.is_ascii_digit()).is_ok() {
+                return Ok(());
+            }
+
+            scanner.accept_char('{')?;
+            scanner.scan_apache_identifier()?;
+            if c == '%' && scanner.accept_char(':').is_ok() {
+                // e.g. `%{ENV:PATH}` and `%{HTTP:Accept-Language}`
+                scanner.accept_if(|c| c.is_ascii_alphanumeric() || (c == '_'))?;
+                scanner.skip_while(|c| c.is_ascii_alphanumeric() || matches!(c, '_' | '-'));
+            }
+            scanner.accept_char('}')?;
+            Ok(())
+        })
+    }
+
+    // Reference: https://httpd.apache.org/docs/current/configuring.html#syntax
+    #[inline]
+    fn scan_apache_string(&mut self) -> ScannerResult<'text, &'text str> {
+        let (r, _outcome) = self.scan_apache_string_outcome()?;
+        Ok(self.ranged_text(r))
+    }
+
+    // Reference: https://httpd.apache.org/docs/current/configuring.html#syntax
+    fn scan_apache_string_outcome(&mut self) -> ScannerResult<'text, ScanOutcome> {
+        let mut outcome = ScanOutcome::Terminated;
+        let (r, _s) = self.scan_with(|scanner| {
+            let (open_range, quote) = scanner.accept_char_any(&['"', '\''])?;
+
+            loop {
+                scanner.skip_until_char_any(&[quote, '\\', '\n', '\r']);
+                match scanner.peek() {
+                    Ok((_r, '\\')) => {
+                        // Skip the next character as it is escaped
+                        _ = scanner.next();
+                        _ = scanner.accept_if(|c| !matches!(c, '\n' | '\r'));
+                    }
+                    Ok((_, '\n' | '\r')) | Err(_) => {
+                        outcome = ScanOutcome::Unterminated(open_range);
+                        break;
+                    }
+                    Ok(_) => {
+                        _ = scanner.next();
+                        break;
+                    }
+                }
+            }
+
+            Ok(())
+        })?;
+        Ok((r, outcome))
+    }
+
+    // Reference: https://httpd.apache.org/docs/current/configuring.html#syntax
+    fn scan_apache_continuation(&mut self) -> ScannerResult<'text, &'text str> {
+        self.scan_with(|scanner| {
+            let (r, _c) = scanner.accept_char('\\')?;
+            match scanner.peek() {
+                Ok((_, '\n' | '\r')) | Err(_) => Ok(()),
+                Ok(_) => Err(scanner.ranged_text(r)),
+            }
+        })
+    }
+
+    // Reference: https://httpd.apache.org/docs/current/configuring.html#syntax
+    fn scan_apache_word(&mut self) -> ScannerResult<'text, &'text str> {
+        self.scan_with(|scanner| {
+            let start = scanner.cursor_pos();
+            loop {
+                let stop = scanner.peeking(|scanner| {
+                    scanner.scan_apache_variable().is_ok()
+                        || scanner.scan_apache_continuation().is_ok()
+                });
+                if stop {
+                    break;
+                }
+                match scanner.peek() {
+                    Ok((_r, '\\')) => {
+                        _ = scanner.next();
+                        _ = scanner.next();
+                    }
+                    Ok((_r, c)) if !c.is_whitespace() && (c != '>') => {
+                        _ = scanner.next();
+                    }
+                    _ => break,
+                }
+            }
+
+            if scanner.cursor_pos() == start {
+                return Err((start..start, ""));
+            }
+            Ok(())
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{assert_invalid_cases, assert_valid_cases};
+
+    #[test]
+    fn test_apache_line_comments() {
+        let cases = ["#", "# Comment", "#<VirtualHost>"];
+
+        assert_valid_cases!(scan_apache_line_comment, cases);
+        assert_valid_cases!(scan_apache_line_comment, cases, "\nrest");
+    }
+
+    #[test]
+    fn test_apache_section_opens() {
+        let cases = ["<VirtualHost", "</VirtualHost", "<IfModule", "<Directory"];
+
+        assert_valid_cases!(scan_apache_section_open, cases);
+        assert_valid_cases!(scan_apache_section_open, cases, " *:80>");
+        assert_valid_cases!(scan_apache_section_open, cases, ">");
+    }
+
+    #[test]
+    fn test_apache_section_opens_invalid() {
+        let cases = ["<", "</", "< VirtualHost", "<1", "<a-b>", "VirtualHost"];
+
+        assert_invalid_cases!(scan_apache_section_open, cases);
+    }
+
+    #[test]
+    fn test_apache_variables() {
+        let cases = [
+            "${SRVROOT}",
+            "%{HTTP_HOST}",
+            "%{ENV:PATH}",
+            "%{HTTP:Accept-Language}",
+            "$1",
+            "%9",
+        ];
+
+        assert_valid_cases!(scan_apache_variable, cases);
+        assert_valid_cases!(scan_apache_variable, cases, "/rest");
+    }
+
+    #[test]
+    fn test_apache_variables_invalid() {
+        let cases = ["$", "%", "${}", "${SRVROOT", "$a", "%{ENV:}", "${ENV:PATH}"];
+
+        assert_invalid_cases!(scan_apache_variable, cases);
+    }
+
+    #[test]
+    fn test_apache_strings() {
+        let cases = [
+            r#""""#,
+            r#""/var/www/html""#,
+            r#""a \" b""#,
+            r#""${SRVROOT}/htdocs""#,
+            "'single'",
+        ];
+
+        assert_valid_cases!(scan_apache_string, cases);
+        assert_valid_cases!(scan_apache_string, cases, " rest");
+
+        let mut scanner = Scanner::new("\"unterminated\nrest");
+        let (r, outcome) = scanner.scan_apache_string_outcome().unwrap();
+        assert_eq!(r, 0..13);
+        assert_eq!(outcome, ScanOutcome::Unterminated(0..1));
+    }
+
+    #[test]
+    fn test_apache_continuations() {
+        let mut scanner = Scanner::new("\\\n");
+        assert_eq!(scanner.scan_apache_continuation(), Ok((0..1, "\\")));
+        assert_eq!(scanner.remaining_text(), "\n");
+
+        assert_invalid_cases!(scan_apache_continuation, ["\\a", "\\ \n", "a"]);
+    }
+
+    #[test]
+    fn test_apache_words() {
+        let cases = [
+            "/var/www/html",
+            "*:80",
+            "^/old/(.*)$",
+            "mod_ssl.c",
+            "-Indexes",
+            r"a\ b",
+        ];
+
+        assert_valid_cases!(scan_apache_word, cases);
+        assert_valid_cases!(scan_apache_word, cases, " rest");
+        assert_valid_cases!(scan_apache_word, cases, ">");
+        assert_valid_cases!(scan_apache_word, cases, "${SRVROOT}");
+    }
+
+    #[test]
+    fn test_apache_words_invalid() {
+        let cases = ["", " ", ">", "${SRVROOT}", "\\\n"];
+
+        assert_invalid_cases!(scan_apache_word, cases);
+    }
+}
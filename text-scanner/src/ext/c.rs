@@ -1,9 +1,15 @@
-use crate::{CharExt, Scanner, ScannerResult};
+use super::NumberInfo;
+use crate::{CharExt, IdentifierCharset, ScanOutcome, Scanner, ScannerResult};
 
 /// [`Scanner`] extension for scanning C tokens.
 pub trait CScannerExt<'text>: crate::private::Sealed {
     fn scan_c_line_comment(&mut self) -> ScannerResult<'text, &'text str>;
     fn scan_c_block_comment(&mut self) -> ScannerResult<'text, &'text str>;
+    /// Same as [`scan_c_block_comment()`], but additionally returns
+    /// whether the block comment is unterminated.
+    ///
+    /// [`scan_c_block_comment()`]: Self::scan_c_block_comment
+    fn scan_c_block_comment_outcome(&mut self) -> ScannerResult<'text, ScanOutcome>;
 
     fn scan_c_identifier(&mut self) -> ScannerResult<'text, &'text str>;
 
@@ -11,9 +17,53 @@ pub trait CScannerExt<'text>: crate::private::Sealed {
     fn scan_c_int_hex(&mut self) -> ScannerResult<'text, &'text str>;
     fn scan_c_int_oct(&mut self) -> ScannerResult<'text, &'text str>;
     fn scan_c_float(&mut self) -> ScannerResult<'text, &'text str>;
+    /// Scans a single integer or floating-point literal, including its
+    /// suffix, e.g. `UL` in `1UL` and `f` in `1.5f`, and returns its
+    /// [`NumberInfo`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use text_scanner::{ext::{CScannerExt, NumberInfo}, Scanner};
+    ///
+    /// let mut scanner = Scanner::new("0x1Full");
+    /// assert_eq!(
+    ///     scanner.scan_c_number_info(),
+    ///     Ok((0..7, NumberInfo {
+    ///         radix: 16,
+    ///         digits_range: 2..4,
+    ///         suffix_range: Some(4..7),
+    ///         is_float: false,
+    ///     })),
+    /// );
+    /// ```
+    fn scan_c_number_info(&mut self) -> ScannerResult<'text, NumberInfo>;
 
     fn scan_c_char(&mut self) -> ScannerResult<'text, &'text str>;
     fn scan_c_string(&mut self) -> ScannerResult<'text, &'text str>;
+    /// Same as [`scan_c_string()`], but additionally returns whether
+    /// the string is unterminated, i.e. ends at the end of the line.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use text_scanner::{ext::CScannerExt, ScanOutcome, Scanner};
+    ///
+    /// let mut scanner = Scanner::new("\"abc\n\"def\"");
+    /// assert_eq!(
+    ///     scanner.scan_c_string_outcome(),
+    ///     Ok((0..4, ScanOutcome::Unterminated(0..1))),
+    /// );
+    ///
+    /// scanner.skip_whitespace();
+    /// assert_eq!(
+    ///     scanner.scan_c_string_outcome(),
+    ///     Ok((5..10, ScanOutcome::Terminated)),
+    /// );
+    /// ```
+    ///
+    /// [`scan_c_string()`]: Self::scan_c_string
+    fn scan_c_string_outcome(&mut self) -> ScannerResult<'text, ScanOutcome>;
 }
 
 impl<'text> CScannerExt<'text> for Scanner<'text> {
@@ -28,34 +78,21 @@ impl<'text> CScannerExt<'text> for Scanner<'text> {
     }
 
     // Reference: https://learn.microsoft.com/en-us/cpp/c-language/c-comments?view=msvc-170
+    #[inline]
     fn scan_c_block_comment(&mut self) -> ScannerResult<'text, &'text str> {
-        self.scan_with(|scanner| {
-            scanner.accept_str("/*")?;
-
-            loop {
-                scanner.skip_until_char('*');
+        let (r, _outcome) = self.scan_c_block_comment_outcome()?;
+        Ok(self.ranged_text(r))
+    }
 
-                match scanner.next() {
-                    Ok((_r, '*')) => {
-                        if let Ok((_r, '/')) = scanner.next() {
-                            break;
-                        }
-                    }
-                    Ok((_r, _c)) => {}
-                    Err(_) => break,
-                }
-            }
-            Ok(())
-        })
+    // Reference: https://learn.microsoft.com/en-us/cpp/c-language/c-comments?view=msvc-170
+    #[inline]
+    fn scan_c_block_comment_outcome(&mut self) -> ScannerResult<'text, ScanOutcome> {
+        self.scan_block_comment_outcome("/*", "*/", false)
     }
 
     // Reference: https://learn.microsoft.com/en-us/cpp/c-language/c-identifiers?view=msvc-170#syntax
     fn scan_c_identifier(&mut self) -> ScannerResult<'text, &'text str> {
-        self.scan_with(|scanner| {
-            scanner.accept_if(|c| c.is_alphabetic() || (c == '_'))?;
-            scanner.skip_while(|c| c.is_alphanumeric() || (c == '_'));
-            Ok(())
-        })
+        self.scan_identifier_with_charset(IdentifierCharset::XID)
     }
 
     fn scan_c_int_dec(&mut self) -> ScannerResult<'text, &'text str> {
@@ -110,6 +147,37 @@ impl<'text> CScannerExt<'text> for Scanner<'text> {
         })
     }
 
+    fn scan_c_number_info(&mut self) -> ScannerResult<'text, NumberInfo> {
+        let mut is_float = false;
+        let (r, literal) = match self.scan_c_int_hex() {
+            Ok(item) => item,
+            Err(_) => match self.scan_c_float() {
+                Ok(item) => {
+                    is_float = true;
+                    item
+                }
+                Err(_) => self.scan_c_int_oct().or_else(|_| self.scan_c_int_dec())?,
+            },
+        };
+
+        let suffix_range = if is_float {
+            self.accept_char_any(&['f', 'F', 'l', 'L'])
+                .map_or(r.end..r.end, |(r, _c)| r)
+        } else {
+            self.skip_while(|c| matches!(c, 'u' | 'U' | 'l' | 'L')).0
+        };
+
+        // Octal literals are only prefixed by `0`, e.g. `0777`
+        let prefixes: &[(&str, u32)] =
+            if !is_float && (literal.len() > 1) && literal.starts_with('0') {
+                &[("0x", 16), ("0", 8)]
+            } else {
+                &[("0x", 16)]
+            };
+        let info = NumberInfo::new(r.start, literal, suffix_range.clone(), prefixes, is_float);
+        Ok((r.start..suffix_range.end, info))
+    }
+
     // Reference: https://learn.microsoft.com/en-us/cpp/c-language/c-character-constants?view=msvc-170#syntax
     fn scan_c_char(&mut self) -> ScannerResult<'text, &'text str> {
         self.scan_with(|scanner| {
@@ -136,9 +204,17 @@ impl<'text> CScannerExt<'text> for Scanner<'text> {
     }
 
     // Reference: https://learn.microsoft.com/en-us/cpp/c-language/c-string-literals?view=msvc-170#syntax
+    #[inline]
     fn scan_c_string(&mut self) -> ScannerResult<'text, &'text str> {
-        self.scan_with(|scanner| {
-            scanner.accept_char('"')?;
+        let (r, _outcome) = self.scan_c_string_outcome()?;
+        Ok(self.ranged_text(r))
+    }
+
+    // Reference: https://learn.microsoft.com/en-us/cpp/c-language/c-string-literals?view=msvc-170#syntax
+    fn scan_c_string_outcome(&mut self) -> ScannerResult<'text, ScanOutcome> {
+        let mut outcome = ScanOutcome::Terminated;
+        let (r, _s) = self.scan_with(|scanner| {
+            let (open_range, _c) = scanner.accept_char('"')?;
 
             loop {
                 scanner.skip_until_char_any(&['"', '\\', '\n']);
@@ -153,14 +229,17 @@ impl<'text> CScannerExt<'text> for Scanner<'text> {
                         // Note: Technically any character is not valid
                         _ = scanner.next();
                     }
-                    Ok((_r, '\n')) => break,
+                    Ok((_, '\n')) | Err(_) => {
+                        outcome = ScanOutcome::Unterminated(open_range);
+                        break;
+                    }
                     Ok(_) => unreachable!(),
-                    Err(_) => break,
                 }
             }
 
             Ok(())
-        })
+        })?;
+        Ok((r, outcome))
     }
 }
 
@@ -216,6 +295,9 @@ mod tests {
             ("/*", Ok((0..2, "/*")), ""),
             ("/* ", Ok((0..3, "/* ")), ""),
             ("/* * /", Ok((0..6, "/* * /")), ""),
+            ("/* **/ x", Ok((0..6, "/* **/")), " x"),
+            ("/***/ x", Ok((0..5, "/***/")), " x"),
+            ("/*/**/*/", Ok((0..6, "/*/**/")), "*/"),
             ("/* * /\n", Ok((0..7, "/* * /\n")), ""),
             ("/* Unterminated Block Comment", Ok((0..29, "/* Unterminated Block Comment")), ""),
             ("/*\nUnterminated\nBlock\nComment\n", Ok((0..30, "/*\nUnterminated\nBlock\nComment\n")), ""),
@@ -0,0 +1,371 @@
+use crate::{IdentifierCharset, ScanOutcome, Scanner, ScannerResult};
+
+/// [`Scanner`] extension for scanning CMake tokens.
+///
+/// _Based on [cmake-language(7)]_.
+///
+/// [cmake-language(7)]: https://cmake.org/cmake/help/latest/manual/cmake-language.7.html
+pub trait CMakeScannerExt<'text>: crate::private::Sealed {
+    /// Scans a CMake line comment, e.g. `# comment`, which is not
+    /// a bracket comment.
+    fn scan_cmake_line_comment(&mut self) -> ScannerResult<'text, &'text str>;
+    /// Scans a CMake bracket comment, e.g. `#[[ comment ]]` and
+    /// `#[==[ comment ]==]`.
+    ///
+    /// If the bracket comment is unterminated, then it ends
+    /// at the end of the text.
+    fn scan_cmake_bracket_comment(&mut self) -> ScannerResult<'text, &'text str>;
+    /// Same as [`scan_cmake_bracket_comment()`], but additionally returns
+    /// whether the bracket comment is unterminated.
+    ///
+    /// [`scan_cmake_bracket_comment()`]: Self::scan_cmake_bracket_comment
+    fn scan_cmake_bracket_comment_outcome(&mut self) -> ScannerResult<'text, ScanOutcome>;
+
+    fn scan_cmake_identifier(&mut self) -> ScannerResult<'text, &'text str>;
+
+    /// Scans a CMake bracket argument, e.g. `[[text]]` and `[=[text]=]`,
+    /// where the amount of `=` in the opening and closing brackets
+    /// must be the same.
+    ///
+    /// If the bracket argument is unterminated, then it ends
+    /// at the end of the text.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use text_scanner::{ext::CMakeScannerExt, Scanner};
+    ///
+    /// let text = "[=[a ]] b]=] rest";
+    /// let mut scanner = Scanner::new(text);
+    ///
+    /// assert_eq!(scanner.scan_cmake_bracket_argument(), Ok((0..12, "[=[a ]] b]=]")));
+    /// assert_eq!(scanner.remaining_text(), " rest");
+    /// ```
+    fn scan_cmake_bracket_argument(&mut self) -> ScannerResult<'text, &'text str>;
+    /// Same as [`scan_cmake_bracket_argument()`], but additionally returns
+    /// whether the bracket argument is unterminated.
+    ///
+    /// [`scan_cmake_bracket_argument()`]: Self::scan_cmake_bracket_argument
+    fn scan_cmake_bracket_argument_outcome(&mut self) -> ScannerResult<'text, ScanOutcome>;
+
+    /// Scans a CMake quoted argument, e.g. `"Hello ${NAME}\n"`, including
+    /// any variable references, escape sequences, and line continuations.
+    ///
+    /// If the quoted argument is unterminated, then it ends
+    /// at the end of the text.
+    fn scan_cmake_quoted_argument(&mut self) -> ScannerResult<'text, &'text str>;
+    /// Same as [`scan_cmake_quoted_argument()`], but additionally returns
+    /// whether the quoted argument is unterminated.
+    ///
+    /// [`scan_cmake_quoted_argument()`]: Self::scan_cmake_quoted_argument
+    fn scan_cmake_quoted_argument_outcome(&mut self) -> ScannerResult<'text, ScanOutcome>;
+
+    /// Scans the text of a CMake unquoted argument, e.g. `src/main.c`,
+    /// `-DFOO=1`, and `a;b`, until whitespace, `(`, `)`, `#`, `"`, or
+    /// a variable reference.
+    fn scan_cmake_unquoted_element(&mut self) -> ScannerResult<'text, &'text str>;
+
+    /// Scans a CMake variable reference, e.g. `${NAME}`, `$ENV{PATH}`,
+    /// and `$CACHE{NAME}`, including nested variable references,
+    /// e.g. `${a_${b}}`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use text_scanner::{ext::CMakeScannerExt, Scanner};
+    ///
+    /// let text = "${a_${b}}/c";
+    /// let mut scanner = Scanner::new(text);
+    ///
+    /// assert_eq!(scanner.scan_cmake_variable_reference(), Ok((0..9, "${a_${b}}")));
+    /// assert_eq!(scanner.remaining_text(), "/c");
+    /// ```
+    fn scan_cmake_variable_reference(&mut self) -> ScannerResult<'text, &'text str>;
+}
+
+impl<'text> CMakeScannerExt<'text> for Scanner<'text> {
+    // Reference: https://cmake.org/cmake/help/latest/manual/cmake-language.7.html#line-comment
+    fn scan_cmake_line_comment(&mut self) -> ScannerResult<'text, &'text str> {
+        self.scan_with(|scanner| {
+            let (r, _c) = scanner.accept_char('#')?;
+            if scan_cmake_bracket_open(scanner).is_some() {
+                return Err(scanner.ranged_text(r));
+            }
+            scanner.skip_until_char_any(&['\n', '\r']);
+            Ok(())
+        })
+    }
+
+    // Reference: https://cmake.org/cmake/help/latest/manual/cmake-language.7.html#bracket-comment
+    #[inline]
+    fn scan_cmake_bracket_comment(&mut self) -> ScannerResult<'text, &'text str> {
+        let (r, _outcome) = self.scan_cmake_bracket_comment_outcome()?;
+        Ok(self.ranged_text(r))
+    }
+
+    // Reference: https://cmake.org/cmake/help/latest/manual/cmake-language.7.html#bracket-comment
+    fn scan_cmake_bracket_comment_outcome(&mut self) -> ScannerResult<'text, ScanOutcome> {
+        let mut outcome = ScanOutcome::Terminated;
+        let (r, _s) = self.scan_with(|scanner| {
+            scanner.accept_char('#')?;
+            let (_r, o) = scanner.scan_cmake_bracket_argument_outcome()?;
+            outcome = o;
+            Ok(())
+        })?;
+        Ok((r, outcome))
+    }
+
+    // Reference: https://cmake.org/cmake/help/latest/manual/cmake-language.7.html#command-invocations
+    fn scan_cmake_identifier(&mut self) -> ScannerResult<'text, &'text str> {
+        self.scan_identifier_with_charset(IdentifierCharset::C)
+    }
+
+    // Reference: https://cmake.org/cmake/help/latest/manual/cmake-language.7.html#bracket-argument
+    #[inline]
+    fn scan_cmake_bracket_argument(&mut self) -> ScannerResult<'text, &'text str> {
+        let (r, _outcome) = self.scan_cmake_bracket_argument_outcome()?;
+        Ok(self.ranged_text(r))
+    }
+
+    // Reference: https://cmake.org/cmake/help/latest/manual/cmake-language.7.html#bracket-argument
+    fn scan_cmake_bracket_argument_outcome(&mut self) -> ScannerResult<'text, ScanOutcome> {
+        let mut outcome = ScanOutcome::Terminated;
+        let (r, _s) = self.scan_with(|scanner| {
+            let start = scanner.cursor_pos();
+            let Some(equals) = scan_cmake_bracket_open(scanner) else {
+                let (r, _c) = scanner.accept_char('[')?;
+                return Err(scanner.ranged_text(r));
+            };
+            let open_range = start..scanner.cursor_pos();
+
+            loop {
+                scanner.skip_until_char(']');
+                if scanner.accept_char(']').is_err() {
+                    outcome = ScanOutcome::Unterminated(open_range);
+                    break;
+                }
+                let (_r, s) = scanner.skip_while_char('=');
+                if (s.len() == equals) && scanner.accept_char(']').is_ok() {
+                    break;
+                }
+            }
+
+            Ok(())
+        })?;
+        Ok((r, outcome))
+    }
+
+    // Reference: https://cmake.org/cmake/help/latest/manual/cmake-language.7.html#quoted-argument
+    #[inline]
+    fn scan_cmake_quoted_argument(&mut self) -> ScannerResult<'text, &'text str> {
+        let (r, _outcome) = self.scan_cmake_quoted_argument_outcome()?;
+        Ok(self.ranged_text(r))
+    }
+
+    // Reference: https://cmake.org/cmake/help/latest/manual/cmake-language.7.html#quoted-argument
+    fn scan_cmake_quoted_argument_outcome(&mut self) -> ScannerResult<'text, ScanOutcome> {
+        let mut outcome = ScanOutcome::Terminated;
+        let (r, _s) = self.scan_with(|scanner| {
+            let (open_range, _c) = scanner.accept_char('"')?;
+
+            loop {
+                scanner.skip_until_char_any(&['"', '\\']);
+                match scanner.next() {
+                    Ok((_r, '"')) => break,
+                    Ok((_r, '\\')) => {
+                        // Skip the next character as it is escaped,
+                        // or a line continuation
+                        _ = scanner.accept_char('\r');
+                        _ = scanner.next();
+                    }
+                    Ok(_) => unreachable!(),
+                    Err(_) => {
+                        outcome = ScanOutcome::Unterminated(open_range);
+                        break;
+                    }
+                }
+            }
+
+            Ok(())
+        })?;
+        Ok((r, outcome))
+    }
+
+    // Reference: https://cmake.org/cmake/help/latest/manual/cmake-language.7.html#unquoted-argument
+    fn scan_cmake_unquoted_element(&mut self) -> ScannerResult<'text, &'text str> {
+        self.scan_with(|scanner| {
+            let start = scanner.cursor_pos();
+            loop {
+                if scanner.peeking(|scanner| scanner.scan_cmake_variable_reference().is_ok()) {
+                    break;
+                }
+                match scanner.peek() {
+                    Ok((_r, '\\')) => {
+                        _ = scanner.next();
+                        _ = scanner.next();
+                    }
+                    Ok((_r, c)) if !c.is_whitespace() && !"()#\"".contains(c) => {
+                        _ = scanner.next();
+                    }
+                    _ => break,
+                }
+            }
+
+            if scanner.cursor_pos() == start {
+                return Err((start..start, ""));
+            }
+            Ok(())
+        })
+    }
+
+    // Reference: https://cmake.org/cmake/help/latest/manual/cmake-language.7.html#variable-references
+    fn scan_cmake_variable_reference(&mut self) -> ScannerResult<'text, &'text str> {
+        self.scan_with(|scanner| {
+            scanner.accept_char('$')?;
+            _ = scanner.accept_str_any(&["ENV", "CACHE"]);
+            scanner.accept_char('{')?;
+
+            loop {
+                if scanner.scan_cmake_variable_reference().is_ok() {
+                    continue;
+                }
+                match scanner.next()? {
+                    (_r, '}') => break,
+                    (_r, '\\') => {
+                        _ = scanner.next();
+                    }
+                    (r, '\n' | '\r') => return Err(scanner.ranged_text(r)),
+                    _ => {}
+                }
+            }
+
+            Ok(())
+        })
+    }
+}
+
+/// Scans the opening brackets of a bracket argument or a bracket comment,
+/// i.e. `[`, any amount of `=`, and `[`, and returns the amount of `=`.
+fn scan_cmake_bracket_open(scanner: &mut Scanner<'_>) -> Option<usize> {
+    let start = scanner.cursor_pos();
+    let equals = scanner
+        .accept_char('[')
+        .ok()
+        .map(|_| scanner.skip_while_char('=').1.len())
+        .filter(|_| scanner.accept_char('[').is_ok());
+    if equals.is_none() {
+        scanner.set_cursor_pos(start);
+    }
+    equals
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{assert_invalid_cases, assert_valid_cases};
+
+    #[test]
+    fn test_cmake_line_comments() {
+        let cases = ["#", "# Comment", "#[ not a bracket comment", "#[=x"];
+
+        assert_valid_cases!(scan_cmake_line_comment, cases);
+        assert_valid_cases!(scan_cmake_line_comment, cases, "\nrest");
+    }
+
+    #[test]
+    fn test_cmake_line_comments_invalid() {
+        let cases = ["#[[ bracket ]]", "#[=[ bracket ]=]", "// comment"];
+
+        assert_invalid_cases!(scan_cmake_line_comment, cases);
+    }
+
+    #[test]
+    fn test_cmake_bracket_comments() {
+        let cases = [
+            "#[[]]",
+            "#[[ comment ]]",
+            "#[[\nmultiline\n]]",
+            "#[=[ ]] ]=]",
+            "#[==[ ]=] ]==]",
+        ];
+
+        assert_valid_cases!(scan_cmake_bracket_comment, cases);
+        assert_valid_cases!(scan_cmake_bracket_comment, cases, "rest");
+    }
+
+    #[test]
+    fn test_cmake_bracket_arguments() {
+        let cases = ["[[]]", "[[text]]", "[=[ ]] ]=]", "[==[\n]=]\n]==]"];
+
+        assert_valid_cases!(scan_cmake_bracket_argument, cases);
+        assert_valid_cases!(scan_cmake_bracket_argument, cases, "rest");
+
+        let mut scanner = Scanner::new("[=[ unterminated ]]");
+        let (r, outcome) = scanner.scan_cmake_bracket_argument_outcome().unwrap();
+        assert_eq!(r, 0..19);
+        assert_eq!(outcome, ScanOutcome::Unterminated(0..3));
+    }
+
+    #[test]
+    fn test_cmake_bracket_arguments_invalid() {
+        let cases = ["[", "[=", "[=x[]]", "x"];
+
+        assert_invalid_cases!(scan_cmake_bracket_argument, cases);
+    }
+
+    #[test]
+    fn test_cmake_quoted_arguments() {
+        let cases = [
+            r#""""#,
+            r#""Hello""#,
+            r#""Hello ${NAME}\n""#,
+            r#""\"""#,
+            "\"line \\\ncontinuation\"",
+            "\"multi\nline\"",
+        ];
+
+        assert_valid_cases!(scan_cmake_quoted_argument, cases);
+        assert_valid_cases!(scan_cmake_quoted_argument, cases, "rest");
+    }
+
+    #[test]
+    fn test_cmake_unquoted_elements() {
+        let cases = [
+            "a",
+            "src/main.c",
+            "-DFOO=1",
+            r"a\ b",
+            "a;b",
+            "$",
+            "$x",
+            "a$ENV",
+        ];
+
+        assert_valid_cases!(scan_cmake_unquoted_element, cases);
+        assert_valid_cases!(scan_cmake_unquoted_element, cases, " rest");
+        assert_valid_cases!(scan_cmake_unquoted_element, cases, "${rest}");
+        assert_valid_cases!(scan_cmake_unquoted_element, cases, ")");
+    }
+
+    #[test]
+    fn test_cmake_variable_references() {
+        let cases = [
+            "${x}",
+            "${CMAKE_SOURCE_DIR}",
+            "$ENV{PATH}",
+            "$CACHE{x}",
+            "${a_${b}_c}",
+            "${a${b${c}}}",
+        ];
+
+        assert_valid_cases!(scan_cmake_variable_reference, cases);
+        assert_valid_cases!(scan_cmake_variable_reference, cases, "}rest");
+    }
+
+    #[test]
+    fn test_cmake_variable_references_invalid() {
+        let cases = ["$", "${", "${x", "${x\n}", "$FOO{x}", "{x}"];
+
+        assert_invalid_cases!(scan_cmake_variable_reference, cases);
+    }
+}
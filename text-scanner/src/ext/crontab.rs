@@ -0,0 +1,218 @@
+use crate::{Scanner, ScannerResult};
+
+/// Schedule nicknames, which replace the five time and date fields.
+const NICKNAMES: &[&str] = &[
+    "@reboot",
+    "@yearly",
+    "@annually",
+    "@monthly",
+    "@weekly",
+    "@daily",
+    "@midnight",
+    "@hourly",
+];
+
+/// [`Scanner`] extension for scanning crontab tokens.
+///
+/// **Note:** Crontabs are line-oriented, e.g. comments, environment
+/// settings, and the schedule are only recognized at the start of a line,
+/// and the command is the remainder of the line. The scanning methods do
+/// not check this, that is up to the caller.
+///
+/// _Based on [crontab(5)]_.
+///
+/// [crontab(5)]: https://man7.org/linux/man-pages/man5/crontab.5.html
+pub trait CrontabScannerExt<'text>: crate::private::Sealed {
+    /// Scans a crontab line comment, e.g. `# comment`.
+    fn scan_crontab_line_comment(&mut self) -> ScannerResult<'text, &'text str>;
+
+    /// Scans a single crontab time and date field, e.g. `*`, `*/15`,
+    /// `1-5`, `0,30`, and `mon-fri`.
+    ///
+    /// The field must be followed by whitespace or the end of the text.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use text_scanner::{ext::CrontabScannerExt, Scanner};
+    ///
+    /// let text = "*/15 9-17 * * mon-fri";
+    /// let mut scanner = Scanner::new(text);
+    ///
+    /// assert_eq!(scanner.scan_crontab_field(), Ok((0..4, "*/15")));
+    /// assert_eq!(scanner.remaining_text(), " 9-17 * * mon-fri");
+    /// ```
+    fn scan_crontab_field(&mut self) -> ScannerResult<'text, &'text str>;
+
+    /// Scans a crontab schedule nickname, e.g. `@reboot` and `@daily`.
+    fn scan_crontab_nickname(&mut self) -> ScannerResult<'text, &'text str>;
+
+    /// Scans the name of a crontab environment setting, e.g. `MAILTO`
+    /// in `MAILTO=root`. The name must be followed by `=`, optionally
+    /// preceded by spaces or tabs, which is not included.
+    fn scan_crontab_env_name(&mut self) -> ScannerResult<'text, &'text str>;
+}
+
+impl<'text> CrontabScannerExt<'text> for Scanner<'text> {
+    // Reference: https://man7.org/linux/man-pages/man5/crontab.5.html
+    fn scan_crontab_line_comment(&mut self) -> ScannerResult<'text, &'text str> {
+        self.scan_with(|scanner| {
+            scanner.accept_char('#')?;
+            scanner.skip_until_char_any(&['\n', '\r']);
+            Ok(())
+        })
+    }
+
+    // Reference: https://man7.org/linux/man-pages/man5/crontab.5.html
+    fn scan_crontab_field(&mut self) -> ScannerResult<'text, &'text str> {
+        self.scan_with(|scanner| {
+            loop {
+                // Range, e.g. `*`, `1`, `1-5`, and `mon-fri`
+                if scanner.accept_char('*').is_err() {
+                    scan_crontab_value(scanner)?;
+                    if scanner.accept_char('-').is_ok() {
+                        scan_crontab_value(scanner)?;
+                    }
+                }
+
+                // Step, e.g. `*/15` and `0-30/5`
+                if scanner.accept_char('/').is_ok() {
+                    scanner.accept_if(|c| c.is_ascii_digit())?;
+                    scanner.skip_while(|c| c.is_ascii_digit());
+                }
+
+                // List, e.g. `0,30`
+                if scanner.accept_char(',').is_err() {
+                    break;
+                }
+            }
+
+            match scanner.peek() {
+                Ok((r, c)) if !c.is_whitespace() => Err(scanner.ranged_text(r)),
+                _ => Ok(()),
+            }
+        })
+    }
+
+    // Reference: https://man7.org/linux/man-pages/man5/crontab.5.html
+    fn scan_crontab_nickname(&mut self) -> ScannerResult<'text, &'text str> {
+        self.scan_with(|scanner| {
+            let (first, _c) = scanner.accept_char('@')?;
+            scanner.skip_while(|c| c.is_ascii_alphabetic());
+
+            let r = first.start..scanner.cursor_pos();
+            let nickname = scanner.ranged_text(r.clone()).1;
+            if !NICKNAMES.contains(&nickname) {
+                return Err(scanner.ranged_text(r));
+            }
+            match scanner.peek() {
+                Ok((r, c)) if !c.is_whitespace() => Err(scanner.ranged_text(r)),
+                _ => Ok(()),
+            }
+        })
+    }
+
+    // Reference: https://man7.org/linux/man-pages/man5/crontab.5.html
+    fn scan_crontab_env_name(&mut self) -> ScannerResult<'text, &'text str> {
+        self.scan_with(|scanner| {
+            scanner.accept_if(|c| c.is_ascii_alphabetic() || (c == '_'))?;
+            scanner.skip_while(|c| c.is_ascii_alphanumeric() || (c == '_'));
+
+            let is_assignment = scanner.peeking(|scanner| {
+                scanner.skip_while_char_any(&[' ', '\t']);
+                scanner.accept_char('=').is_ok()
+            });
+            if !is_assignment {
+                let pos = scanner.cursor_pos();
+                return Err(scanner.ranged_text(pos..pos));
+            }
+            Ok(())
+        })
+    }
+}
+
+/// Scans a single value of a crontab field, i.e. a number, e.g. `15`,
+/// or the name of a month or day of the week, e.g. `jan` and `mon`.
+fn scan_crontab_value<'text>(scanner: &mut Scanner<'text>) -> ScannerResult<'text, &'text str> {
+    scanner.scan_with(|scanner| {
+        let (_r, c) = scanner.accept_if(|c| c.is_ascii_alphanumeric())?;
+        if c.is_ascii_digit() {
+            scanner.skip_while(|c| c.is_ascii_digit());
+        } else {
+            scanner.accept_if(|c| c.is_ascii_alphabetic())?;
+            scanner.accept_if(|c| c.is_ascii_alphabetic())?;
+        }
+        Ok(())
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{assert_invalid_cases, assert_valid_cases};
+
+    #[test]
+    fn test_crontab_line_comments() {
+        let cases = ["#", "# m h dom mon dow command", "#0 * * * * cmd"];
+
+        assert_valid_cases!(scan_crontab_line_comment, cases);
+        assert_valid_cases!(scan_crontab_line_comment, cases, "\nrest");
+    }
+
+    #[test]
+    fn test_crontab_fields() {
+        let cases = [
+            "*",
+            "*/15",
+            "0",
+            "59",
+            "1-5",
+            "0-30/5",
+            "0,30",
+            "1,2-4,*/10",
+            "mon",
+            "mon-fri",
+            "JAN",
+            "jan,jul",
+        ];
+
+        assert_valid_cases!(scan_crontab_field, cases);
+        assert_valid_cases!(scan_crontab_field, cases, " rest");
+        assert_valid_cases!(scan_crontab_field, cases, "\t* * cmd");
+    }
+
+    #[test]
+    fn test_crontab_fields_invalid() {
+        let cases = [
+            "", "-", "*/", "1-", "1,", "mo", "monday", "5min", "*/x", "/usr/bin", "@daily",
+        ];
+
+        assert_invalid_cases!(scan_crontab_field, cases);
+    }
+
+    #[test]
+    fn test_crontab_nicknames() {
+        assert_valid_cases!(scan_crontab_nickname, NICKNAMES.iter().copied());
+        assert_valid_cases!(scan_crontab_nickname, NICKNAMES.iter().copied(), " cmd");
+        assert_invalid_cases!(scan_crontab_nickname, ["@", "@daily2", "@never", "daily"]);
+    }
+
+    #[test]
+    fn test_crontab_env_names() {
+        let cases = ["MAILTO", "SHELL", "CRON_TZ", "_x"];
+
+        for suffix in ["=root", " = root", "\t=/bin/sh"] {
+            for case in cases {
+                let text = format!("{case}{suffix}");
+                let mut scanner = Scanner::new(&text);
+                assert_eq!(scanner.scan_crontab_env_name(), Ok((0..case.len(), case)));
+                assert_eq!(scanner.remaining_text(), suffix);
+            }
+        }
+
+        assert_invalid_cases!(
+            scan_crontab_env_name,
+            ["MAILTO", "MAILTO root", "1X=1", "=1"]
+        );
+    }
+}
@@ -0,0 +1,671 @@
+use crate::{
+    closers::Closers, ext::CScannerExt, Scanner, ScannerItem, ScannerResult, SeparatorPolicy,
+};
+
+// Reference: https://learn.microsoft.com/en-us/dotnet/csharp/language-reference/language-specification/lexical-structure#6453-integer-literals
+const CSHARP_DIGIT_SEPARATORS: SeparatorPolicy = SeparatorPolicy {
+    consecutive: true,
+    ..SeparatorPolicy::STRICT
+};
+
+// Underscores are allowed between the `0x` and `0b` prefixes and
+// the digits, e.g. `0x_FF`
+const CSHARP_PREFIXED_DIGIT_SEPARATORS: SeparatorPolicy = SeparatorPolicy {
+    leading: true,
+    ..CSHARP_DIGIT_SEPARATORS
+};
+
+/// Reference: <https://learn.microsoft.com/en-us/dotnet/csharp/language-reference/keywords/>
+#[rustfmt::skip]
+pub const CSHARP_KEYWORDS: &[&str] = &[
+    "abstract", "as", "base", "bool", "break", "byte", "case", "catch", "char", "checked",
+    "class", "const", "continue", "decimal", "default", "delegate", "do", "double", "else",
+    "enum", "event", "explicit", "extern", "false", "finally", "fixed", "float", "for",
+    "foreach", "goto", "if", "implicit", "in", "int", "interface", "internal", "is", "lock",
+    "long", "namespace", "new", "null", "object", "operator", "out", "override", "params",
+    "private", "protected", "public", "readonly", "ref", "return", "sbyte", "sealed", "short",
+    "sizeof", "stackalloc", "static", "string", "struct", "switch", "this", "throw", "true",
+    "try", "typeof", "uint", "ulong", "unchecked", "unsafe", "ushort", "using", "virtual",
+    "void", "volatile", "while",
+];
+
+/// Reference: <https://learn.microsoft.com/en-us/dotnet/csharp/language-reference/keywords/#contextual-keywords>
+#[rustfmt::skip]
+pub const CSHARP_CONTEXTUAL_KEYWORDS: &[&str] = &[
+    "add", "and", "alias", "ascending", "args", "async", "await", "by", "descending",
+    "dynamic", "equals", "file", "from", "get", "global", "group", "init", "into", "join",
+    "let", "managed", "nameof", "nint", "not", "notnull", "nuint", "on", "or", "orderby",
+    "partial", "record", "remove", "required", "scoped", "select", "set", "unmanaged",
+    "value", "var", "when", "where", "with", "yield",
+];
+
+/// [`Scanner`] extension for scanning C# tokens.
+///
+/// See also [`CSharpStrExt`].
+///
+/// _Based on [C# 11]_.
+///
+/// [C# 11]: https://learn.microsoft.com/en-us/dotnet/csharp/whats-new/csharp-11
+pub trait CSharpScannerExt<'text>: crate::private::Sealed {
+    fn scan_csharp_line_comment(&mut self) -> ScannerResult<'text, &'text str>;
+    fn scan_csharp_block_comment(&mut self) -> ScannerResult<'text, &'text str>;
+
+    /// Scans a C# identifier, including verbatim identifiers, e.g. `@class`.
+    fn scan_csharp_identifier(&mut self) -> ScannerResult<'text, &'text str>;
+
+    fn scan_csharp_int_literal(&mut self) -> ScannerResult<'text, &'text str>;
+    /// Scans a C# real literal, i.e. a number literal containing a `.`,
+    /// an exponent, or a real suffix, e.g. `1.5`, `1e3`, and `1m`.
+    fn scan_csharp_real_literal(&mut self) -> ScannerResult<'text, &'text str>;
+
+    fn scan_csharp_char_literal(&mut self) -> ScannerResult<'text, &'text str>;
+
+    /// Scans a regular C# string literal, e.g. `"Hello\n"`, including
+    /// UTF-8 string literals, e.g. `"Hello"u8`.
+    fn scan_csharp_string_literal(&mut self) -> ScannerResult<'text, &'text str>;
+    /// Scans a verbatim C# string literal, e.g. `@"C:\Users"`, in which
+    /// `""` is an escaped `"`.
+    fn scan_csharp_verbatim_string_literal(&mut self) -> ScannerResult<'text, &'text str>;
+    /// Scans a C# 11 raw string literal, i.e. a string literal starting with
+    /// three or more `"`, and ending with the same amount of `"`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use text_scanner::{ext::CSharpScannerExt, Scanner};
+    ///
+    /// let text = r#"""""He said """Hello""" """" rest"#;
+    /// let mut scanner = Scanner::new(text);
+    ///
+    /// assert_eq!(
+    ///     scanner.scan_csharp_raw_string_literal(),
+    ///     Ok((0..28, r#"""""He said """Hello""" """""#))
+    /// );
+    /// assert_eq!(scanner.remaining_text(), " rest");
+    /// ```
+    fn scan_csharp_raw_string_literal(&mut self) -> ScannerResult<'text, &'text str>;
+    /// Scans an interpolated C# string literal, including interpolated
+    /// verbatim and raw string literals, e.g. `$"{x}"`, `$@"{x}"`,
+    /// and `$$"""{{x}}"""`.
+    ///
+    /// Interpolations can contain nested string literals, and are ended
+    /// by the first unnested `}`. Within an interpolation, an unnested `:`
+    /// starts the format specifier, which can contain any character
+    /// except `}`, e.g. `{DateTime.Now:HH:mm}`.
+    ///
+    /// For interpolated raw string literals, the amount of `$` is the
+    /// amount of `{` and `}` delimiting an interpolation, while fewer
+    /// braces are part of the string.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use text_scanner::{ext::CSharpScannerExt, Scanner};
+    ///
+    /// let text = r#"$"{x,10:N2} {{ {(b ? "}" : $"{y}")}" rest"#;
+    /// let mut scanner = Scanner::new(text);
+    ///
+    /// assert_eq!(
+    ///     scanner.scan_csharp_interpolated_string_literal(),
+    ///     Ok((0..36, r#"$"{x,10:N2} {{ {(b ? "}" : $"{y}")}""#))
+    /// );
+    /// assert_eq!(scanner.remaining_text(), " rest");
+    ///
+    /// let text = r#"$$"""{ "x": {{x}} }""" rest"#;
+    /// let mut scanner = Scanner::new(text);
+    ///
+    /// assert_eq!(
+    ///     scanner.scan_csharp_interpolated_string_literal(),
+    ///     Ok((0..22, r#"$$"""{ "x": {{x}} }""""#))
+    /// );
+    /// assert_eq!(scanner.remaining_text(), " rest");
+    /// ```
+    fn scan_csharp_interpolated_string_literal(&mut self) -> ScannerResult<'text, &'text str>;
+}
+
+impl<'text> CSharpScannerExt<'text> for Scanner<'text> {
+    // Reference: https://learn.microsoft.com/en-us/dotnet/csharp/language-reference/language-specification/lexical-structure#634-comments
+    #[inline]
+    fn scan_csharp_line_comment(&mut self) -> ScannerResult<'text, &'text str> {
+        self.scan_c_line_comment()
+    }
+
+    // Reference: https://learn.microsoft.com/en-us/dotnet/csharp/language-reference/language-specification/lexical-structure#634-comments
+    #[inline]
+    fn scan_csharp_block_comment(&mut self) -> ScannerResult<'text, &'text str> {
+        self.scan_c_block_comment()
+    }
+
+    // Reference: https://learn.microsoft.com/en-us/dotnet/csharp/language-reference/language-specification/lexical-structure#643-identifiers
+    #[inline]
+    fn scan_csharp_identifier(&mut self) -> ScannerResult<'text, &'text str> {
+        self.scan_with(|scanner| {
+            _ = scanner.accept_char('@');
+            scanner.scan_c_identifier()?;
+            Ok(())
+        })
+    }
+
+    // Reference: https://learn.microsoft.com/en-us/dotnet/csharp/language-reference/language-specification/lexical-structure#6453-integer-literals
+    fn scan_csharp_int_literal(&mut self) -> ScannerResult<'text, &'text str> {
+        self.scan_with(|scanner| {
+            let hex_or_bin = scanner.scan_with(|scanner| {
+                scanner.accept_char('0')?;
+                let radix = match scanner.accept_char_any(&['x', 'X', 'b', 'B'])? {
+                    (_r, 'x' | 'X') => 16,
+                    _ => 2,
+                };
+                scanner.scan_digits_sep(radix, '_', CSHARP_PREFIXED_DIGIT_SEPARATORS)?;
+                Ok(())
+            });
+            if hex_or_bin.is_err() {
+                scanner.scan_digits_sep(10, '_', CSHARP_DIGIT_SEPARATORS)?;
+            }
+
+            // Suffixes: `U`, `L`, `UL`, and `LU` in any case
+            if scanner.accept_char_any(&['u', 'U']).is_ok() {
+                _ = scanner.accept_char_any(&['l', 'L']);
+            } else if scanner.accept_char_any(&['l', 'L']).is_ok() {
+                _ = scanner.accept_char_any(&['u', 'U']);
+            }
+
+            Ok(())
+        })
+    }
+
+    // Reference: https://learn.microsoft.com/en-us/dotnet/csharp/language-reference/language-specification/lexical-structure#6454-real-literals
+    fn scan_csharp_real_literal(&mut self) -> ScannerResult<'text, &'text str> {
+        self.scan_with(|scanner| {
+            let start = scanner.cursor;
+            let mut is_real = false;
+
+            if scanner.accept_char('.').is_ok() {
+                scanner.scan_digits_sep(10, '_', CSHARP_DIGIT_SEPARATORS)?;
+                is_real = true;
+            } else {
+                scanner.scan_digits_sep(10, '_', CSHARP_DIGIT_SEPARATORS)?;
+
+                // The fraction requires digits, such that e.g.
+                // `1..2` and `1.ToString()` are not real literals
+                _ = scanner.scan_with(|scanner| {
+                    scanner.accept_char('.')?;
+                    scanner.scan_digits_sep(10, '_', CSHARP_DIGIT_SEPARATORS)?;
+                    is_real = true;
+                    Ok(())
+                });
+            }
+
+            _ = scanner.scan_with(|scanner| {
+                scanner.accept_char_any(&['e', 'E'])?;
+                _ = scanner.accept_char_any(&['+', '-']);
+                scanner.scan_digits_sep(10, '_', CSHARP_DIGIT_SEPARATORS)?;
+                is_real = true;
+                Ok(())
+            });
+
+            if scanner
+                .accept_char_any(&['f', 'F', 'd', 'D', 'm', 'M'])
+                .is_ok()
+            {
+                is_real = true;
+            }
+
+            if is_real {
+                Ok(())
+            } else {
+                Err(scanner.ranged_text(start..scanner.cursor))
+            }
+        })
+    }
+
+    // Reference: https://learn.microsoft.com/en-us/dotnet/csharp/language-reference/language-specification/lexical-structure#6455-character-literals
+    fn scan_csharp_char_literal(&mut self) -> ScannerResult<'text, &'text str> {
+        self.scan_with(|scanner| {
+            scanner.accept_char('\'')?;
+
+            let (_r, c) = scanner.next()?;
+            if c == '\\' {
+                // Skip the next character as it is escaped
+                // Note: Technically any character is not valid
+                let (_r, c) = scanner.next()?;
+
+                // `\xH..HHHH`, `\uHHHH`, and `\UHHHHHHHH`
+                if matches!(c, 'x' | 'u' | 'U') {
+                    scanner.skip_while_ext(char::is_ascii_hexdigit);
+                }
+            }
+
+            scanner.accept_char('\'')?;
+            Ok(())
+        })
+    }
+
+    // Reference: https://learn.microsoft.com/en-us/dotnet/csharp/language-reference/language-specification/lexical-structure#6456-string-literals
+    fn scan_csharp_string_literal(&mut self) -> ScannerResult<'text, &'text str> {
+        self.scan_with(|scanner| {
+            scanner.accept_char('"')?;
+
+            loop {
+                scanner.skip_until_char_any(&['"', '\\', '\n']);
+                match scanner.peek() {
+                    Ok((_r, '"')) => {
+                        _ = scanner.next();
+                        break;
+                    }
+                    Ok((_r, '\\')) => {
+                        _ = scanner.next();
+                        // Skip the next character as it is escaped
+                        // Note: Technically any character is not valid
+                        _ = scanner.next();
+                    }
+                    Ok((_, '\n')) | Err(_) => return Ok(()),
+                    Ok(_) => unreachable!(),
+                }
+            }
+
+            // Reference: https://learn.microsoft.com/en-us/dotnet/csharp/language-reference/proposals/csharp-11.0/utf8-string-literals
+            _ = scanner.accept_str_any(&["u8", "U8"]);
+
+            Ok(())
+        })
+    }
+
+    // Reference: https://learn.microsoft.com/en-us/dotnet/csharp/language-reference/language-specification/lexical-structure#6456-string-literals
+    fn scan_csharp_verbatim_string_literal(&mut self) -> ScannerResult<'text, &'text str> {
+        self.scan_with(|scanner| {
+            scanner.accept_char('@')?;
+            scanner.accept_char('"')?;
+
+            loop {
+                scanner.skip_until_char('"');
+                if scanner.next().is_err() {
+                    return Ok(());
+                }
+                // `""` is an escaped `"`
+                if scanner.accept_char('"').is_err() {
+                    break;
+                }
+            }
+
+            _ = scanner.accept_str_any(&["u8", "U8"]);
+
+            Ok(())
+        })
+    }
+
+    // Reference: https://learn.microsoft.com/en-us/dotnet/csharp/language-reference/tokens/raw-string
+    fn scan_csharp_raw_string_literal(&mut self) -> ScannerResult<'text, &'text str> {
+        self.scan_with(|scanner| {
+            let quotes = scan_csharp_raw_string_open(scanner)?;
+
+            loop {
+                scanner.skip_until_char('"');
+                let (_r, s) = scanner.skip_while_char('"');
+                if s.is_empty() {
+                    // Unterminated raw string literal
+                    return Ok(());
+                } else if s.len() >= quotes {
+                    break;
+                }
+            }
+
+            _ = scanner.accept_str_any(&["u8", "U8"]);
+
+            Ok(())
+        })
+    }
+
+    // Reference: https://learn.microsoft.com/en-us/dotnet/csharp/language-reference/tokens/interpolated
+    fn scan_csharp_interpolated_string_literal(&mut self) -> ScannerResult<'text, &'text str> {
+        self.scan_with(|scanner| {
+            let verbatim = scanner.accept_char('@').is_ok();
+            let (r, dollars) = scanner.skip_while_char('$');
+            let dollars = dollars.len();
+            if dollars == 0 {
+                return Err(scanner.ranged_text(r));
+            }
+            let verbatim = verbatim || ((dollars == 1) && scanner.accept_char('@').is_ok());
+
+            if !verbatim {
+                if let Ok(quotes) = scan_csharp_raw_string_open(scanner) {
+                    scan_csharp_interpolated_raw_string_body(scanner, quotes, dollars);
+                    return Ok(());
+                }
+            }
+
+            // Only interpolated raw string literals can have multiple `$`
+            if dollars > 1 {
+                return Err(scanner.ranged_text(r));
+            }
+
+            scanner.accept_char('"')?;
+
+            let delims: &[char] = if verbatim {
+                &['"', '{']
+            } else {
+                &['"', '{', '\\', '\n']
+            };
+            loop {
+                scanner.skip_until_char_any(delims);
+                match scanner.peek() {
+                    Ok((_r, '"')) => {
+                        _ = scanner.next();
+                        // `""` is an escaped `"` in verbatim string literals
+                        if !verbatim || scanner.accept_char('"').is_err() {
+                            break;
+                        }
+                    }
+                    Ok((_r, '{')) => {
+                        _ = scanner.next();
+                        // `{{` is an escaped `{`
+                        if scanner.accept_char('{').is_err()
+                            && !scan_csharp_interpolation(scanner, 1)
+                        {
+                            return Ok(());
+                        }
+                    }
+                    Ok((_r, '\\')) => {
+                        _ = scanner.next();
+                        // Skip the next character as it is escaped
+                        // Note: Technically any character is not valid
+                        _ = scanner.next();
+                    }
+                    Ok((_, '\n')) | Err(_) => return Ok(()),
+                    Ok(_) => unreachable!(),
+                }
+            }
+
+            Ok(())
+        })
+    }
+}
+
+/// Scans the opening `"""` of a raw string literal, and returns the
+/// amount of `"`.
+fn scan_csharp_raw_string_open<'text>(
+    scanner: &mut Scanner<'text>,
+) -> Result<usize, ScannerItem<&'text str>> {
+    let (_r, s) = scanner.scan_with(|scanner| {
+        let (r, s) = scanner.skip_while_char('"');
+        if s.len() < 3 {
+            return Err((r, s));
+        }
+        Ok(())
+    })?;
+    Ok(s.len())
+}
+
+/// Scans the remaining interpolated raw string literal after the opening
+/// `"`s, where `dollars` is the amount of `{` and `}` delimiting an
+/// interpolation.
+///
+/// If the string literal is unterminated, then it ends at the end of the text.
+fn scan_csharp_interpolated_raw_string_body(
+    scanner: &mut Scanner<'_>,
+    quotes: usize,
+    dollars: usize,
+) {
+    loop {
+        scanner.skip_until_char_any(&['"', '{']);
+        match scanner.peek() {
+            Ok((_r, '"')) => {
+                let (_r, s) = scanner.skip_while_char('"');
+                if s.len() >= quotes {
+                    return;
+                }
+            }
+            Ok((_r, '{')) => {
+                // The last `dollars` braces of a longer run open the
+                // interpolation, while any preceding braces are content
+                let (_r, s) = scanner.skip_while_char('{');
+                if (s.len() >= dollars) && !scan_csharp_interpolation(scanner, dollars) {
+                    return;
+                }
+            }
+            Err(_) => return,
+            Ok(_) => unreachable!(),
+        }
+    }
+}
+
+/// Scans the remaining interpolation after the opening `{`s,
+/// i.e. the expression, alignment, and format specifier, as well
+/// as the closing `braces` amount of `}`.
+///
+/// Returns `false` if the interpolation is unterminated, in which
+/// case the cursor is at the end of the text.
+fn scan_csharp_interpolation(scanner: &mut Scanner<'_>, braces: usize) -> bool {
+    let mut closers = Closers::new();
+    loop {
+        if closers.is_empty() {
+            let rest = scanner.remaining_text();
+            if rest.bytes().take_while(|&b| b == b'}').count() >= braces {
+                _ = scanner.next_str(braces);
+                return true;
+            }
+        }
+
+        if scanner.scan_csharp_char_literal().is_ok()
+            || scanner.scan_csharp_interpolated_string_literal().is_ok()
+            || scanner.scan_csharp_raw_string_literal().is_ok()
+            || scanner.scan_csharp_verbatim_string_literal().is_ok()
+            || scanner.scan_csharp_string_literal().is_ok()
+        {
+            continue;
+        }
+
+        match scanner.next() {
+            Ok((_r, ':')) if closers.is_empty() => {
+                // `::` is the namespace alias qualifier, e.g. `global::System`,
+                // otherwise the format specifier, e.g. `{x:N2}`
+                if scanner.accept_char(':').is_err() {
+                    scanner.skip_until_char('}');
+                }
+            }
+            Ok((_r, c)) if closers.pop_if(c) => {}
+            Ok((_r, '(')) => closers.push(')'),
+            Ok((_r, '[')) => closers.push(']'),
+            Ok((_r, '{')) => closers.push('}'),
+            Ok(_) => {}
+            Err(_) => return false,
+        }
+    }
+}
+
+/// [`str`] extension for checking if a `&str` is e.g. a C# keyword.
+pub trait CSharpStrExt {
+    fn is_csharp_keyword(&self) -> bool;
+    fn is_csharp_contextual_keyword(&self) -> bool;
+}
+
+impl CSharpStrExt for str {
+    #[inline]
+    fn is_csharp_keyword(&self) -> bool {
+        CSHARP_KEYWORDS.contains(&self)
+    }
+
+    #[inline]
+    fn is_csharp_contextual_keyword(&self) -> bool {
+        CSHARP_CONTEXTUAL_KEYWORDS.contains(&self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{assert_invalid_cases, assert_valid_cases};
+
+    #[test]
+    fn test_csharp_identifiers() {
+        let cases = ["x", "_x1", "@class", "Überschrift"];
+
+        assert_valid_cases!(scan_csharp_identifier, cases);
+        assert_valid_cases!(scan_csharp_identifier, cases, " remaining");
+    }
+
+    #[test]
+    fn test_csharp_int_literals() {
+        let cases = [
+            "0", "123", "1_000", "1__0", "0x_FF", "0XFF", "0b1010", "0B_1_0", "1u", "1UL", "1lu",
+            "1L",
+        ];
+
+        assert_valid_cases!(scan_csharp_int_literal, cases);
+        assert_valid_cases!(scan_csharp_int_literal, cases, " remaining");
+    }
+
+    #[test]
+    fn test_csharp_real_literals() {
+        let cases = [
+            "1.5",
+            ".5",
+            "1e3",
+            "1E-3",
+            "1.5e+3",
+            "1f",
+            "1.5F",
+            "2d",
+            "1.5m",
+            "1_000.0_1M",
+        ];
+
+        assert_valid_cases!(scan_csharp_real_literal, cases);
+        assert_valid_cases!(scan_csharp_real_literal, cases, " remaining");
+    }
+
+    #[test]
+    fn test_csharp_real_literals_invalid() {
+        let cases = ["1", "1.", "1..2", "1.ToString()", "1e", "."];
+
+        assert_invalid_cases!(scan_csharp_real_literal, cases);
+    }
+
+    #[test]
+    fn test_csharp_char_literals() {
+        let cases = [
+            "'a'",
+            "'\\''",
+            "'\\\\'",
+            "'\\n'",
+            "'\\x41'",
+            "'\\u0041'",
+            "'\\U00000041'",
+            "'™'",
+        ];
+
+        assert_valid_cases!(scan_csharp_char_literal, cases);
+        assert_valid_cases!(scan_csharp_char_literal, cases, " remaining");
+    }
+
+    #[test]
+    fn test_csharp_string_literals() {
+        let cases = ["\"\"", "\"Hello\"", "\"\\\"\\n\"", "\"Hello\"u8"];
+
+        assert_valid_cases!(scan_csharp_string_literal, cases);
+        assert_valid_cases!(scan_csharp_string_literal, cases, " remaining");
+    }
+
+    #[test]
+    fn test_csharp_verbatim_string_literals() {
+        let cases = [
+            "@\"\"",
+            "@\"C:\\Users\\\"",
+            "@\"Say \"\"Hello\"\"\"",
+            "@\"Line 1\nLine 2\"",
+        ];
+
+        assert_valid_cases!(scan_csharp_verbatim_string_literal, cases);
+        assert_valid_cases!(scan_csharp_verbatim_string_literal, cases, " remaining");
+    }
+
+    #[test]
+    fn test_csharp_raw_string_literals() {
+        let cases = [
+            r#""""Hello""""#,
+            r#""""He said "Hi" and ""Bye"""""#,
+            "\"\"\"\n    {\n        \"x\": 1\n    }\n    \"\"\"",
+            r#"""""Contains """ quotes""""""#,
+            r#""""\n is not an escape""""#,
+            r#""""Hello"""u8"#,
+        ];
+
+        assert_valid_cases!(scan_csharp_raw_string_literal, cases);
+        assert_valid_cases!(scan_csharp_raw_string_literal, cases, " remaining");
+    }
+
+    #[test]
+    fn test_csharp_raw_string_literals_invalid() {
+        let cases = ["\"\"", "\"Hello\"", "@\"Hello\""];
+
+        assert_invalid_cases!(scan_csharp_raw_string_literal, cases);
+    }
+
+    #[test]
+    fn test_csharp_interpolated_string_literals() {
+        let cases = [
+            "$\"\"",
+            "$\"Hello {name}!\"",
+            "$\"{{escaped}} {x}\"",
+            "$\"{x,10:N2}\"",
+            "$\"{DateTime.Now:HH:mm:ss}\"",
+            "$\"{global::System.Math.PI}\"",
+            "$\"{(b ? \"yes\" : \"no\")}\"",
+            "$\"{dict[\"}\"]}\"",
+            "$\"{new { A = 1 }.A}\"",
+            "$\"{'}'}\"",
+            "$\"outer {$\"inner {x}\"} outer\"",
+            "$\"{x}\\\"{y}\"",
+            "$@\"C:\\{dir}\\\"\"{file}\"\"\"",
+            "@$\"C:\\{dir}\"",
+            "$\"\"\"Hello {name}\"\"\"",
+            "$\"\"\"\"Contains \"\"\" and {x}\"\"\"\"",
+            "$$\"\"\"{ \"x\": {{x}} }\"\"\"",
+            "$$\"\"\"{{{x}}}\"\"\"",
+            "$$\"\"\"{{x:N2}}\"\"\"",
+            "$$\"\"\"{{new { A = 1 }.A}}\"\"\"",
+            "$\"{x\n+ y}\"",
+        ];
+
+        assert_valid_cases!(scan_csharp_interpolated_string_literal, cases);
+        assert_valid_cases!(scan_csharp_interpolated_string_literal, cases, " remaining");
+    }
+
+    #[test]
+    fn test_csharp_interpolated_string_literals_invalid() {
+        let cases = ["\"Hello\"", "$", "$x", "$$\"Hello\"", "@\"Hello\""];
+
+        assert_invalid_cases!(scan_csharp_interpolated_string_literal, cases);
+    }
+
+    #[test]
+    fn test_csharp_unterminated_strings() {
+        let mut scanner = Scanner::new("\"Hello\nx");
+        assert_eq!(scanner.scan_csharp_string_literal(), Ok((0..6, "\"Hello")));
+        assert_eq!(scanner.remaining_text(), "\nx");
+
+        let text = "@\"Hello\nx";
+        let mut scanner = Scanner::new(text);
+        assert_eq!(
+            scanner.scan_csharp_verbatim_string_literal(),
+            Ok((0..9, text))
+        );
+
+        let text = "\"\"\"Hello\"\"";
+        let mut scanner = Scanner::new(text);
+        assert_eq!(scanner.scan_csharp_raw_string_literal(), Ok((0..10, text)));
+
+        let text = "$\"{x\"";
+        let mut scanner = Scanner::new(text);
+        assert_eq!(
+            scanner.scan_csharp_interpolated_string_literal(),
+            Ok((0..5, text))
+        );
+
+        let text = "$$\"\"\"{{x}\"\"\"";
+        let mut scanner = Scanner::new(text);
+        assert_eq!(
+            scanner.scan_csharp_interpolated_string_literal(),
+            Ok((0..12, text))
+        );
+    }
+}
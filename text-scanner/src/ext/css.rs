@@ -1,4 +1,6 @@
-use crate::{Scanner, ScannerResult};
+use crate::{ByteSet, ScanOutcome, Scanner, ScannerResult};
+
+const CSS_IDENT_CONTINUE: ByteSet = ByteSet::IDENT_CONTINUE.union(ByteSet::from_bytes(b"-"));
 
 /// [`Scanner`] extension for scanning CSS tokens.
 ///
@@ -47,6 +49,11 @@ pub trait CssScannerExt<'text>: crate::private::Sealed {
     ///
     /// [CSS block comment]: https://www.w3.org/TR/css-syntax-3/#comment-diagram
     fn scan_css_block_comment(&mut self) -> ScannerResult<'text, &'text str>;
+    /// Same as [`scan_css_block_comment()`], but additionally returns whether
+    /// the block comment is unterminated.
+    ///
+    /// [`scan_css_block_comment()`]: Self::scan_css_block_comment
+    fn scan_css_block_comment_outcome(&mut self) -> ScannerResult<'text, ScanOutcome>;
 
     /// Scans a single [CSS identifier].
     ///
@@ -129,6 +136,11 @@ pub trait CssScannerExt<'text>: crate::private::Sealed {
     ///
     /// [CSS string]: https://www.w3.org/TR/css-syntax-3/#string-token-diagram
     fn scan_css_string(&mut self) -> ScannerResult<'text, &'text str>;
+    /// Same as [`scan_css_string()`], but additionally returns whether
+    /// the string is unterminated.
+    ///
+    /// [`scan_css_string()`]: Self::scan_css_string
+    fn scan_css_string_outcome(&mut self) -> ScannerResult<'text, ScanOutcome>;
 
     /// Scans a single [CSS number].
     ///
@@ -172,30 +184,93 @@ pub trait CssScannerExt<'text>: crate::private::Sealed {
     ///
     /// [CSS number]: https://www.w3.org/TR/css-syntax-3/#number-token-diagram
     fn scan_css_number(&mut self) -> ScannerResult<'text, &'text str>;
+
+    /// Scans a single [CSS attribute selector], e.g. `[disabled]`,
+    /// `[data-x="y"]`, `[href^='https' i]`, and `[xlink|href]`.
+    ///
+    /// **Note:** This has the same lifetime as the original `text`,
+    /// so the scanner can continue to be used while this exists.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use text_scanner::{ext::CssScannerExt, Scanner};
+    ///
+    /// let text = r#"a[data-x="y"][lang|=en i]"#;
+    /// let mut scanner = Scanner::new(text);
+    ///
+    /// assert_eq!(scanner.scan_css_identifier(), Ok((0..1, "a")));
+    /// assert_eq!(scanner.scan_css_attribute_selector(), Ok((1..13, r#"[data-x="y"]"#)));
+    /// assert_eq!(scanner.scan_css_attribute_selector(), Ok((13..25, "[lang|=en i]")));
+    /// assert_eq!(scanner.remaining_text(), "");
+    /// ```
+    ///
+    /// [CSS attribute selector]: https://www.w3.org/TR/selectors-4/#attribute-selectors
+    fn scan_css_attribute_selector(&mut self) -> ScannerResult<'text, &'text str>;
+
+    /// Scans a single [CSS pseudo-class], i.e. `:` followed by an
+    /// identifier, e.g. `:hover` and `:nth-child`.
+    ///
+    /// Any arguments, e.g. `(2n + 1)` in `:nth-child(2n + 1)`, are not
+    /// included.
+    ///
+    /// **Note:** Legacy pseudo-elements using a single `:`, e.g. `:before`,
+    /// are scanned as pseudo-classes, as they cannot be distinguished
+    /// syntactically.
+    ///
+    /// **Note:** This has the same lifetime as the original `text`,
+    /// so the scanner can continue to be used while this exists.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use text_scanner::{ext::CssScannerExt, Scanner};
+    ///
+    /// let text = "a:hover::before";
+    /// let mut scanner = Scanner::new(text);
+    ///
+    /// assert_eq!(scanner.scan_css_identifier(), Ok((0..1, "a")));
+    /// assert_eq!(scanner.scan_css_pseudo_class(), Ok((1..7, ":hover")));
+    /// assert!(scanner.scan_css_pseudo_class().is_err());
+    /// assert_eq!(scanner.scan_css_pseudo_element(), Ok((7..15, "::before")));
+    /// ```
+    ///
+    /// [CSS pseudo-class]: https://www.w3.org/TR/selectors-4/#pseudo-classes
+    fn scan_css_pseudo_class(&mut self) -> ScannerResult<'text, &'text str>;
+
+    /// Scans a single [CSS pseudo-element], i.e. `::` followed by an
+    /// identifier, e.g. `::before` and `::part`.
+    ///
+    /// See [`scan_css_pseudo_class()`] for an example.
+    ///
+    /// [CSS pseudo-element]: https://www.w3.org/TR/selectors-4/#pseudo-elements
+    /// [`scan_css_pseudo_class()`]: Self::scan_css_pseudo_class
+    fn scan_css_pseudo_element(&mut self) -> ScannerResult<'text, &'text str>;
+
+    /// Scans a single [CSS combinator], i.e. `>`, `+`, `~`, or `||`.
+    ///
+    /// **Note:** The descendant combinator is whitespace, and is
+    /// not scanned by this method.
+    ///
+    /// **Note:** This has the same lifetime as the original `text`,
+    /// so the scanner can continue to be used while this exists.
+    ///
+    /// [CSS combinator]: https://www.w3.org/TR/selectors-4/#combinators
+    fn scan_css_combinator(&mut self) -> ScannerResult<'text, &'text str>;
 }
 
 impl<'text> CssScannerExt<'text> for Scanner<'text> {
     // Reference: https://www.w3.org/TR/css-syntax-3/#comment-diagram
+    #[inline]
     fn scan_css_block_comment(&mut self) -> ScannerResult<'text, &'text str> {
-        self.scan_with(|scanner| {
-            scanner.accept_str("/*")?;
-
-            loop {
-                let (r, _) = scanner.skip_until_char('*');
-                if r.is_empty() {
-                    break;
-                }
-
-                // Safe to ignore as it is guaranteed to be `Ok`
-                _ = scanner.accept_char('*');
-
-                if scanner.accept_char('/').is_ok() {
-                    break;
-                }
-            }
+        let (r, _outcome) = self.scan_css_block_comment_outcome()?;
+        Ok(self.ranged_text(r))
+    }
 
-            Ok(())
-        })
+    // Reference: https://www.w3.org/TR/css-syntax-3/#comment-diagram
+    #[inline]
+    fn scan_css_block_comment_outcome(&mut self) -> ScannerResult<'text, ScanOutcome> {
+        self.scan_block_comment_outcome("/*", "*/", false)
     }
 
     // Reference: https://www.w3.org/TR/css-syntax-3/#ident-token-diagram
@@ -207,10 +282,10 @@ impl<'text> CssScannerExt<'text> for Scanner<'text> {
                     scanner.accept_if(|c| c.is_alphabetic() || (c == '_'))?;
                 }
 
-                scanner.skip_while(|c| c.is_alphanumeric() || matches!(c, '_' | '-'));
+                scanner.skip_while_in(&CSS_IDENT_CONTINUE);
             } else {
                 scanner.accept_if(|c| c.is_alphabetic() || (c == '_'))?;
-                scanner.skip_while(|c| c.is_alphanumeric() || matches!(c, '_' | '-'));
+                scanner.skip_while_in(&CSS_IDENT_CONTINUE);
             }
 
             Ok(())
@@ -231,15 +306,23 @@ impl<'text> CssScannerExt<'text> for Scanner<'text> {
         self.scan_with(|scanner| {
             scanner.accept_char('#')?;
             scanner.accept_if(|c| c.is_alphanumeric() || matches!(c, '_' | '-'))?;
-            scanner.skip_while(|c| c.is_alphanumeric() || matches!(c, '_' | '-'));
+            scanner.skip_while_in(&CSS_IDENT_CONTINUE);
             Ok(())
         })
     }
 
     // Reference: https://www.w3.org/TR/css-syntax-3/#string-token-diagram
+    #[inline]
     fn scan_css_string(&mut self) -> ScannerResult<'text, &'text str> {
-        self.scan_with(|scanner| {
-            let (_r, quote) = scanner.accept_char_any(&['"', '\''])?;
+        let (r, _outcome) = self.scan_css_string_outcome()?;
+        Ok(self.ranged_text(r))
+    }
+
+    // Reference: https://www.w3.org/TR/css-syntax-3/#string-token-diagram
+    fn scan_css_string_outcome(&mut self) -> ScannerResult<'text, ScanOutcome> {
+        let mut outcome = ScanOutcome::Terminated;
+        let (r, _s) = self.scan_with(|scanner| {
+            let (open_range, quote) = scanner.accept_char_any(&['"', '\''])?;
 
             loop {
                 scanner.skip_until(|c| (c == quote) || (c == '\\'));
@@ -250,12 +333,16 @@ impl<'text> CssScannerExt<'text> for Scanner<'text> {
                         _ = scanner.next();
                     }
                     Ok(_) => unreachable!(),
-                    Err(_) => break,
+                    Err(_) => {
+                        outcome = ScanOutcome::Unterminated(open_range);
+                        break;
+                    }
                 }
             }
 
             Ok(())
-        })
+        })?;
+        Ok((r, outcome))
     }
 
     // Reference: https://www.w3.org/TR/css-syntax-3/#number-token-diagram
@@ -276,12 +363,87 @@ impl<'text> CssScannerExt<'text> for Scanner<'text> {
                 }
             }
 
-            if scanner.accept_char_any(&['E', 'e']).is_ok() {
+            // The exponent is only included if followed by digits,
+            // e.g. `1em` is the number `1` followed by the unit `em`
+            _ = scanner.scan_with(|scanner| {
+                scanner.accept_char_any(&['E', 'e'])?;
                 _ = scanner.accept_char_any(&['+', '-']);
                 scanner.accept_if_ext(char::is_ascii_digit)?;
                 scanner.skip_while_ext(char::is_ascii_digit);
+                Ok(())
+            });
+
+            Ok(())
+        })
+    }
+
+    // Reference: https://www.w3.org/TR/selectors-4/#attribute-selectors
+    fn scan_css_attribute_selector(&mut self) -> ScannerResult<'text, &'text str> {
+        self.scan_with(|scanner| {
+            scanner.accept_char('[')?;
+            scanner.skip_whitespace();
+
+            // Optional namespace prefix, e.g. `[xlink|href]` and `[*|href]`
+            let res = scanner.scan_with(|scanner| {
+                if scanner.accept_char('*').is_err() {
+                    _ = scanner.scan_css_identifier();
+                }
+                scanner.accept_char('|')?;
+                scanner.scan_css_identifier()?;
+                Ok(())
+            });
+            if res.is_err() {
+                scanner.scan_css_identifier()?;
             }
+            scanner.skip_whitespace();
+
+            let matcher = scanner.scan_with(|scanner| {
+                _ = scanner.accept_char_any(&['~', '|', '^', '$', '*']);
+                scanner.accept_char('=')?;
+                Ok(())
+            });
+            if matcher.is_ok() {
+                scanner.skip_whitespace();
+                if scanner.scan_css_string().is_err() {
+                    scanner.scan_css_identifier()?;
+                }
+                scanner.skip_whitespace();
 
+                // Optional modifier, e.g. `i` in `[href$=".pdf" i]`
+                if scanner.accept_char_any(&['i', 'I', 's', 'S']).is_ok() {
+                    scanner.skip_whitespace();
+                }
+            }
+
+            scanner.accept_char(']')?;
+            Ok(())
+        })
+    }
+
+    // Reference: https://www.w3.org/TR/selectors-4/#pseudo-classes
+    fn scan_css_pseudo_class(&mut self) -> ScannerResult<'text, &'text str> {
+        self.scan_with(|scanner| {
+            scanner.accept_char(':')?;
+            scanner.scan_css_identifier()?;
+            Ok(())
+        })
+    }
+
+    // Reference: https://www.w3.org/TR/selectors-4/#pseudo-elements
+    fn scan_css_pseudo_element(&mut self) -> ScannerResult<'text, &'text str> {
+        self.scan_with(|scanner| {
+            scanner.accept_str("::")?;
+            scanner.scan_css_identifier()?;
+            Ok(())
+        })
+    }
+
+    // Reference: https://www.w3.org/TR/selectors-4/#combinators
+    fn scan_css_combinator(&mut self) -> ScannerResult<'text, &'text str> {
+        self.scan_with(|scanner| {
+            if scanner.accept_str("||").is_err() {
+                scanner.accept_char_any(&['>', '+', '~'])?;
+            }
             Ok(())
         })
     }
@@ -291,6 +453,25 @@ impl<'text> CssScannerExt<'text> for Scanner<'text> {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_css_block_comment() {
+        let cases = [
+            ("/**/", Ok((0..4, "/**/")), ""),
+            ("/* a */b", Ok((0..7, "/* a */")), "b"),
+            ("/** a **/b", Ok((0..9, "/** a **/")), "b"),
+            ("/* * / */", Ok((0..9, "/* * / */")), ""),
+            ("/* a", Ok((0..4, "/* a")), ""),
+            ("/*", Ok((0..2, "/*")), ""),
+            ("/", Err((0..1, "/")), "/"),
+        ];
+
+        for (text, expected, remaining) in cases {
+            let mut scanner = Scanner::new(text);
+            assert_eq!(scanner.scan_css_block_comment(), expected, "{text:?}");
+            assert_eq!(scanner.remaining_text(), remaining);
+        }
+    }
+
     #[test]
     fn test_css_ident() {
         let cases = [
@@ -391,12 +572,6 @@ mod tests {
             ("--1", Err((0..1, "-")), "--1"),
             ("+-1", Err((0..1, "+")), "+-1"),
             ("-+1", Err((0..1, "-")), "-+1"),
-            //
-            ("1E", Err((0..2, "1E")), "1E"),
-            ("1EE", Err((0..2, "1E")), "1EE"),
-            ("1E*", Err((0..2, "1E")), "1E*"),
-            ("1E+", Err((0..3, "1E+")), "1E+"),
-            ("1E+X", Err((0..3, "1E+")), "1E+X"),
         ];
 
         for (text, expected, remaining) in cases.iter().cloned() {
@@ -429,15 +604,20 @@ mod tests {
     }
 
     #[test]
-    fn test_css_num_invalid() {
+    fn test_css_num_without_exponent() {
+        // Reference: https://www.w3.org/TR/css-syntax-3/#consume-number
         let cases = [
-            ("1E", Err((0..2, "1E")), "1E"),
-            ("1E ", Err((0..2, "1E")), "1E "),
-            ("1EE", Err((0..2, "1E")), "1EE"),
-            ("1E*", Err((0..2, "1E")), "1E*"),
-            ("1E+", Err((0..3, "1E+")), "1E+"),
-            ("1E+ ", Err((0..3, "1E+")), "1E+ "),
-            ("1E+X", Err((0..3, "1E+")), "1E+X"),
+            ("1E", Ok((0..1, "1")), "E"),
+            ("1E ", Ok((0..1, "1")), "E "),
+            ("1EE", Ok((0..1, "1")), "EE"),
+            ("1E*", Ok((0..1, "1")), "E*"),
+            ("1E+", Ok((0..1, "1")), "E+"),
+            ("1E+ ", Ok((0..1, "1")), "E+ "),
+            ("1E+X", Ok((0..1, "1")), "E+X"),
+            ("1em", Ok((0..1, "1")), "em"),
+            ("40em)", Ok((0..2, "40")), "em)"),
+            (".25em", Ok((0..3, ".25")), "em"),
+            ("3ex", Ok((0..1, "3")), "ex"),
         ];
 
         for (text, expected, remaining) in cases {
@@ -446,4 +626,103 @@ mod tests {
             assert_eq!(scanner.remaining_text(), remaining);
         }
     }
+
+    #[test]
+    fn test_css_attribute_selector() {
+        let cases = [
+            ("[x]", Ok((0..3, "[x]")), ""),
+            ("[ x ]", Ok((0..5, "[ x ]")), ""),
+            ("[data-x=\"y\"]", Ok((0..12, "[data-x=\"y\"]")), ""),
+            ("[x='y']z", Ok((0..7, "[x='y']")), "z"),
+            ("[x=y]", Ok((0..5, "[x=y]")), ""),
+            ("[x~=y]", Ok((0..6, "[x~=y]")), ""),
+            ("[x|=y]", Ok((0..6, "[x|=y]")), ""),
+            ("[x^=y]", Ok((0..6, "[x^=y]")), ""),
+            ("[x$=y]", Ok((0..6, "[x$=y]")), ""),
+            ("[x*=y]", Ok((0..6, "[x*=y]")), ""),
+            ("[ x = \"y\" i ]", Ok((0..13, "[ x = \"y\" i ]")), ""),
+            ("[x=y s]", Ok((0..7, "[x=y s]")), ""),
+            ("[ns|x]", Ok((0..6, "[ns|x]")), ""),
+            ("[*|x=y]", Ok((0..7, "[*|x=y]")), ""),
+            ("[|x]", Ok((0..4, "[|x]")), ""),
+            //
+            ("", Err((0..0, "")), ""),
+            ("[", Err((0..1, "[")), "["),
+            ("[]", Err((0..1, "[")), "[]"),
+            ("[x", Err((0..2, "[x")), "[x"),
+            ("[x=]", Err((0..3, "[x=")), "[x=]"),
+            ("[x==y]", Err((0..3, "[x=")), "[x==y]"),
+            ("[x=y z]", Err((0..5, "[x=y ")), "[x=y z]"),
+            ("[1]", Err((0..1, "[")), "[1]"),
+            ("[full-start] 1fr", Ok((0..12, "[full-start]")), " 1fr"),
+        ];
+
+        for (text, expected, remaining) in cases {
+            let mut scanner = Scanner::new(text);
+            assert_eq!(scanner.scan_css_attribute_selector(), expected, "{text:?}");
+            assert_eq!(scanner.remaining_text(), remaining, "{text:?}");
+        }
+    }
+
+    #[test]
+    fn test_css_pseudo() {
+        let cases = [
+            (":hover", Ok((0..6, ":hover")), ""),
+            (":nth-child(2n)", Ok((0..10, ":nth-child")), "(2n)"),
+            (":-moz-focusring", Ok((0..15, ":-moz-focusring")), ""),
+            (":before{", Ok((0..7, ":before")), "{"),
+            //
+            (":", Err((0..1, ":")), ":"),
+            (": x", Err((0..1, ":")), ": x"),
+            (":1", Err((0..1, ":")), ":1"),
+            ("::before", Err((0..1, ":")), "::before"),
+        ];
+
+        for (text, expected, remaining) in cases {
+            let mut scanner = Scanner::new(text);
+            assert_eq!(scanner.scan_css_pseudo_class(), expected, "{text:?}");
+            assert_eq!(scanner.remaining_text(), remaining, "{text:?}");
+        }
+
+        let cases = [
+            ("::before", Ok((0..8, "::before")), ""),
+            ("::part(x)", Ok((0..6, "::part")), "(x)"),
+            (
+                "::-webkit-scrollbar",
+                Ok((0..19, "::-webkit-scrollbar")),
+                "",
+            ),
+            //
+            (":before", Err((0..1, ":")), ":before"),
+            ("::", Err((0..2, "::")), "::"),
+            (":: x", Err((0..2, "::")), ":: x"),
+        ];
+
+        for (text, expected, remaining) in cases {
+            let mut scanner = Scanner::new(text);
+            assert_eq!(scanner.scan_css_pseudo_element(), expected, "{text:?}");
+            assert_eq!(scanner.remaining_text(), remaining, "{text:?}");
+        }
+    }
+
+    #[test]
+    fn test_css_combinator() {
+        let cases = [
+            (">", Ok((0..1, ">")), ""),
+            ("+ a", Ok((0..1, "+")), " a"),
+            ("~a", Ok((0..1, "~")), "a"),
+            ("||", Ok((0..2, "||")), ""),
+            (">>", Ok((0..1, ">")), ">"),
+            //
+            ("", Err((0..0, "")), ""),
+            ("|", Err((0..0, "")), "|"),
+            ("a", Err((0..0, "")), "a"),
+        ];
+
+        for (text, expected, remaining) in cases {
+            let mut scanner = Scanner::new(text);
+            assert_eq!(scanner.scan_css_combinator(), expected, "{text:?}");
+            assert_eq!(scanner.remaining_text(), remaining, "{text:?}");
+        }
+    }
 }
@@ -0,0 +1,571 @@
+use crate::{ByteSet, ScanOutcome, Scanner, ScannerResult};
+
+// Keywords are not reserved in Fortran, so these are common statement
+// keywords, e.g. `subroutine`, and specifiers, e.g. `intent` and `in`
+pub const FORTRAN_KEYWORDS: &[&str] = &[
+    "abstract",
+    "allocatable",
+    "allocate",
+    "associate",
+    "bind",
+    "block",
+    "call",
+    "case",
+    "character",
+    "class",
+    "close",
+    "common",
+    "complex",
+    "contains",
+    "continue",
+    "cycle",
+    "data",
+    "deallocate",
+    "default",
+    "dimension",
+    "do",
+    "double",
+    "else",
+    "elseif",
+    "elemental",
+    "end",
+    "enddo",
+    "endif",
+    "entry",
+    "equivalence",
+    "exit",
+    "extends",
+    "external",
+    "format",
+    "function",
+    "go",
+    "goto",
+    "if",
+    "implicit",
+    "import",
+    "in",
+    "include",
+    "inout",
+    "integer",
+    "intent",
+    "interface",
+    "intrinsic",
+    "logical",
+    "module",
+    "none",
+    "nullify",
+    "only",
+    "open",
+    "optional",
+    "out",
+    "parameter",
+    "pointer",
+    "precision",
+    "print",
+    "private",
+    "procedure",
+    "program",
+    "protected",
+    "public",
+    "pure",
+    "read",
+    "real",
+    "recursive",
+    "result",
+    "return",
+    "save",
+    "select",
+    "stop",
+    "submodule",
+    "subroutine",
+    "target",
+    "then",
+    "to",
+    "type",
+    "use",
+    "value",
+    "volatile",
+    "where",
+    "while",
+    "write",
+];
+
+/// [`Scanner`] extension for scanning Fortran tokens.
+///
+/// _Based on [Fortran 2018]._
+///
+/// Keywords are case-insensitive, e.g. `END` and `end`, however,
+/// [`FORTRAN_KEYWORDS`] only contains lowercase keywords.
+///
+/// See also [`FortranStrExt`].
+///
+/// [Fortran 2018]: https://j3-fortran.org/doc/year/18/18-007r1.pdf
+pub trait FortranScannerExt<'text>: crate::private::Sealed {
+    /// Scans a Fortran line comment, i.e. `!` until the end of the line.
+    fn scan_fortran_line_comment(&mut self) -> ScannerResult<'text, &'text str>;
+
+    /// Scans a fixed-form Fortran comment line, i.e. a line starting with
+    /// `C`, `c`, `*`, or `!` in column 1.
+    ///
+    /// This only succeeds if the cursor is at the start of a line.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use text_scanner::{ext::FortranScannerExt, Scanner};
+    ///
+    /// let text = "C comment\n      CALL F";
+    /// let mut scanner = Scanner::new(text);
+    ///
+    /// assert_eq!(scanner.scan_fortran_fixed_comment_line(), Ok((0..9, "C comment")));
+    ///
+    /// scanner.skip_whitespace();
+    /// assert!(scanner.scan_fortran_fixed_comment_line().is_err());
+    /// ```
+    fn scan_fortran_fixed_comment_line(&mut self) -> ScannerResult<'text, &'text str>;
+
+    /// Scans a fixed-form Fortran continuation mark, i.e. any character
+    /// other than a blank or `0` in column 6, where columns 1 to 5 are
+    /// blanks. Alternatively, a line starting with a tab followed by a
+    /// digit from `1` to `9`, is also a continuation line.
+    ///
+    /// This only succeeds if the cursor is at column 6, i.e. after the
+    /// 5 leading blanks, or the leading tab. Only the continuation mark
+    /// is scanned.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use text_scanner::{ext::FortranScannerExt, Scanner};
+    ///
+    /// let text = "     &  X";
+    /// let mut scanner = Scanner::new(text);
+    ///
+    /// scanner.skip_whitespace();
+    /// assert_eq!(scanner.scan_fortran_fixed_continuation(), Ok((5..6, "&")));
+    ///
+    /// scanner.skip_whitespace();
+    /// assert!(scanner.scan_fortran_fixed_continuation().is_err());
+    /// ```
+    fn scan_fortran_fixed_continuation(&mut self) -> ScannerResult<'text, &'text str>;
+
+    /// Scans a free-form Fortran continuation mark, i.e. `&`.
+    fn scan_fortran_free_continuation(&mut self) -> ScannerResult<'text, &'text str>;
+
+    fn scan_fortran_identifier(&mut self) -> ScannerResult<'text, &'text str>;
+    fn scan_fortran_keyword(&mut self) -> ScannerResult<'text, &'text str>;
+
+    /// Scans a Fortran dot operator or logical literal, e.g. `.and.`,
+    /// `.EQ.`, `.true.`, and user-defined operators such as `.cross.`.
+    fn scan_fortran_dot_operator(&mut self) -> ScannerResult<'text, &'text str>;
+    fn scan_fortran_operator(&mut self) -> ScannerResult<'text, &'text str>;
+    fn scan_fortran_delimiter(&mut self) -> ScannerResult<'text, &'text str>;
+
+    /// Scans a Fortran integer literal, e.g. `123`, including any
+    /// kind parameter, e.g. `123_8` and `123_int64`.
+    fn scan_fortran_int(&mut self) -> ScannerResult<'text, &'text str>;
+    /// Scans a Fortran real literal, e.g. `1.5`, `1.`, `.5`, `1e3`, and
+    /// `1.5d0`, including any kind parameter, e.g. `1.5_dp`.
+    ///
+    /// A trailing `.` is not included if it starts a dot operator,
+    /// e.g. `1` in `1.eq.x`.
+    fn scan_fortran_real(&mut self) -> ScannerResult<'text, &'text str>;
+    /// Scans a Fortran binary, octal, or hexadecimal literal, e.g.
+    /// `B'1010'`, `O'777'`, and `Z"FF"`.
+    fn scan_fortran_boz(&mut self) -> ScannerResult<'text, &'text str>;
+
+    /// Scans a Fortran character literal, e.g. `'Hello'` and `"Hello"`,
+    /// where the quote is escaped by doubling it, e.g. `'Don''t'`.
+    ///
+    /// If the character literal is unterminated, then it ends at the
+    /// end of the line.
+    fn scan_fortran_string(&mut self) -> ScannerResult<'text, &'text str>;
+    /// Same as [`scan_fortran_string()`], but additionally returns
+    /// whether the character literal is unterminated.
+    ///
+    /// [`scan_fortran_string()`]: Self::scan_fortran_string
+    fn scan_fortran_string_outcome(&mut self) -> ScannerResult<'text, ScanOutcome>;
+}
+
+impl<'text> FortranScannerExt<'text> for Scanner<'text> {
+    // Reference: Fortran 2018, section 6.3.2.3
+    fn scan_fortran_line_comment(&mut self) -> ScannerResult<'text, &'text str> {
+        self.scan_with(|scanner| {
+            scanner.accept_char('!')?;
+            scanner.skip_until_char_any(&['\n', '\r']);
+            Ok(())
+        })
+    }
+
+    // Reference: Fortran 2018, section 6.3.3.2
+    fn scan_fortran_fixed_comment_line(&mut self) -> ScannerResult<'text, &'text str> {
+        self.scan_with(|scanner| {
+            let (r, _c) = scanner.accept_char_any(&['C', 'c', '*', '!'])?;
+            if !line_prefix(scanner, r.start).is_empty() {
+                return Err(scanner.ranged_text(r));
+            }
+            scanner.skip_until_char_any(&['\n', '\r']);
+            Ok(())
+        })
+    }
+
+    // Reference: Fortran 2018, section 6.3.3.3
+    fn scan_fortran_fixed_continuation(&mut self) -> ScannerResult<'text, &'text str> {
+        self.scan_with(|scanner| {
+            let (r, c) = scanner.next()?;
+            let is_continuation = match line_prefix(scanner, r.start) {
+                "     " => !matches!(c, ' ' | '0' | '\t' | '\n' | '\r'),
+                "\t" => matches!(c, '1'..='9'),
+                _ => false,
+            };
+            if !is_continuation {
+                return Err(scanner.ranged_text(r));
+            }
+            Ok(())
+        })
+    }
+
+    // Reference: Fortran 2018, section 6.3.2.4
+    fn scan_fortran_free_continuation(&mut self) -> ScannerResult<'text, &'text str> {
+        let (r, _c) = self.accept_char('&')?;
+        Ok(self.ranged_text(r))
+    }
+
+    // Reference: Fortran 2018, section 6.2.2
+    fn scan_fortran_identifier(&mut self) -> ScannerResult<'text, &'text str> {
+        self.scan_with(|scanner| {
+            scanner.accept_if(|c| c.is_ascii_alphabetic())?;
+            scanner.skip_while_in(&ByteSet::IDENT_CONTINUE);
+            Ok(())
+        })
+    }
+
+    fn scan_fortran_keyword(&mut self) -> ScannerResult<'text, &'text str> {
+        self.scan_with(|scanner| {
+            let (r, s) = scanner.scan_fortran_identifier()?;
+            if s.is_fortran_keyword() {
+                Ok(())
+            } else {
+                Err((r, s))
+            }
+        })
+    }
+
+    // Reference: Fortran 2018, section 6.2.4
+    fn scan_fortran_dot_operator(&mut self) -> ScannerResult<'text, &'text str> {
+        self.scan_with(|scanner| {
+            scanner.accept_char('.')?;
+            scanner.accept_if(|c| c.is_ascii_alphabetic())?;
+            scanner.skip_while(|c| c.is_ascii_alphabetic());
+            scanner.accept_char('.')?;
+            Ok(())
+        })
+    }
+
+    // Reference: Fortran 2018, section 6.2.4
+    fn scan_fortran_operator(&mut self) -> ScannerResult<'text, &'text str> {
+        self.scan_with(|scanner| {
+            let (r, c) = scanner.next()?;
+            match c {
+                '*' => {
+                    _ = scanner.accept_char('*');
+                }
+                '/' => {
+                    _ = scanner.accept_char_any(&['/', '=']);
+                }
+                '=' => {
+                    _ = scanner.accept_char_any(&['=', '>']);
+                }
+                '<' | '>' => {
+                    _ = scanner.accept_char('=');
+                }
+                ':' => {
+                    _ = scanner.accept_char(':');
+                }
+                '+' | '-' | '%' | ',' | ';' => {}
+                _ => return Err(scanner.ranged_text(r)),
+            }
+            Ok(())
+        })
+    }
+
+    fn scan_fortran_delimiter(&mut self) -> ScannerResult<'text, &'text str> {
+        let (r, _c) = self.accept_char_any(&['(', ')', '[', ']'])?;
+        Ok(self.ranged_text(r))
+    }
+
+    // Reference: Fortran 2018, section 7.4.3.1
+    fn scan_fortran_int(&mut self) -> ScannerResult<'text, &'text str> {
+        self.scan_with(|scanner| {
+            scanner.scan_digits()?;
+            scan_fortran_kind_param(scanner);
+            Ok(())
+        })
+    }
+
+    // Reference: Fortran 2018, section 7.4.3.2
+    fn scan_fortran_real(&mut self) -> ScannerResult<'text, &'text str> {
+        self.scan_with(|scanner| {
+            let mut int_range = None;
+
+            if scanner.accept_char('.').is_ok() {
+                scanner.scan_digits()?;
+            } else {
+                int_range = Some(scanner.scan_digits()?.0);
+
+                // Exclude the `.` of e.g. `1.eq.x`
+                let is_dot_operator =
+                    scanner.peeking(|scanner| scanner.scan_fortran_dot_operator().is_ok());
+                if !is_dot_operator && scanner.accept_char('.').is_ok() {
+                    int_range = None;
+                    _ = scanner.scan_digits();
+                }
+            }
+
+            let exponent = scanner.scan_with(|scanner| {
+                scanner.accept_char_any(&['e', 'E', 'd', 'D', 'q', 'Q'])?;
+                _ = scanner.accept_char_any(&['+', '-']);
+                scanner.scan_digits()?;
+                Ok(())
+            });
+            if exponent.is_err() {
+                if let Some(r) = int_range {
+                    return Err(scanner.ranged_text(r));
+                }
+            }
+
+            scan_fortran_kind_param(scanner);
+            Ok(())
+        })
+    }
+
+    // Reference: Fortran 2018, section 7.7
+    fn scan_fortran_boz(&mut self) -> ScannerResult<'text, &'text str> {
+        self.scan_with(|scanner| {
+            let (_r, prefix) = scanner.accept_char_any(&['B', 'b', 'O', 'o', 'Z', 'z'])?;
+            let radix = match prefix {
+                'B' | 'b' => 2,
+                'O' | 'o' => 8,
+                _ => 16,
+            };
+            let (_r, quote) = scanner.accept_char_any(&['\'', '"'])?;
+            scanner.accept_if(|c| c.is_digit(radix))?;
+            scanner.skip_while(|c| c.is_digit(radix));
+            scanner.accept_char(quote)?;
+            Ok(())
+        })
+    }
+
+    // Reference: Fortran 2018, section 7.4.4.3
+    #[inline]
+    fn scan_fortran_string(&mut self) -> ScannerResult<'text, &'text str> {
+        let (r, _outcome) = self.scan_fortran_string_outcome()?;
+        Ok(self.ranged_text(r))
+    }
+
+    // Reference: Fortran 2018, section 7.4.4.3
+    fn scan_fortran_string_outcome(&mut self) -> ScannerResult<'text, ScanOutcome> {
+        let mut outcome = ScanOutcome::Terminated;
+        let (r, _s) = self.scan_with(|scanner| {
+            let (open_range, quote) = scanner.accept_char_any(&['\'', '"'])?;
+
+            loop {
+                scanner.skip_until_char_any(&[quote, '\n', '\r']);
+                if scanner.accept_char(quote).is_err() {
+                    outcome = ScanOutcome::Unterminated(open_range);
+                    break;
+                }
+                // A doubled quote is an escaped quote
+                if scanner.accept_char(quote).is_err() {
+                    break;
+                }
+            }
+
+            Ok(())
+        })?;
+        Ok((r, outcome))
+    }
+}
+
+/// Returns the text from the start of the line containing `pos` until `pos`.
+fn line_prefix<'text>(scanner: &Scanner<'text>, pos: usize) -> &'text str {
+    let before = &scanner.text()[..pos];
+    let line_start = before.rfind(['\n', '\r']).map_or(0, |i| i + 1);
+    &before[line_start..]
+}
+
+/// Scans an optional kind parameter, e.g. `_8` and `_dp`.
+fn scan_fortran_kind_param(scanner: &mut Scanner<'_>) {
+    _ = scanner.scan_with(|scanner| {
+        scanner.accept_char('_')?;
+        scanner.accept_if(|c| c.is_ascii_alphanumeric())?;
+        scanner.skip_while_in(&ByteSet::IDENT_CONTINUE);
+        Ok(())
+    });
+}
+
+/// [`str`] extension for checking if a `&str` is e.g. a Fortran keyword.
+pub trait FortranStrExt {
+    /// Returns `true` if `self` is a Fortran keyword, ignoring case.
+    fn is_fortran_keyword(&self) -> bool;
+}
+
+impl FortranStrExt for str {
+    #[inline]
+    fn is_fortran_keyword(&self) -> bool {
+        FORTRAN_KEYWORDS
+            .iter()
+            .any(|kw| kw.eq_ignore_ascii_case(self))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{assert_invalid_cases, assert_valid_cases};
+
+    #[test]
+    fn test_fortran_keywords() {
+        let cases = ["end", "END", "Subroutine", "implicit", "enddo"];
+
+        assert_valid_cases!(scan_fortran_keyword, cases);
+        assert_valid_cases!(scan_fortran_keyword, cases, " rest");
+
+        let cases = ["x", "ends", "_end", "1end"];
+        assert_invalid_cases!(scan_fortran_keyword, cases);
+    }
+
+    #[test]
+    fn test_fortran_fixed_comment_lines() {
+        let cases = ["C", "C comment", "c comment", "* comment", "! comment"];
+
+        assert_valid_cases!(scan_fortran_fixed_comment_line, cases);
+        assert_valid_cases!(scan_fortran_fixed_comment_line, cases, "\n      X = 1");
+
+        // Only at the start of a line
+        let mut scanner = Scanner::new("X\nC a\n C b");
+        scanner.set_cursor_pos(2);
+        assert_eq!(scanner.scan_fortran_fixed_comment_line(), Ok((2..5, "C a")));
+        scanner.set_cursor_pos(7);
+        assert!(scanner.scan_fortran_fixed_comment_line().is_err());
+    }
+
+    #[test]
+    fn test_fortran_fixed_continuations() {
+        let cases = [
+            ("     &", 5, Some("&")),
+            ("     1", 5, Some("1")),
+            ("X\n     +", 7, Some("+")),
+            ("\t1", 1, Some("1")),
+            ("     0", 5, None),
+            ("     ", 5, None),
+            ("      X", 6, None),
+            ("    &", 4, None),
+            ("\t&", 1, None),
+            ("\t0", 1, None),
+            ("X    &", 5, None),
+        ];
+        for (text, pos, expected) in cases {
+            let mut scanner = Scanner::new(text);
+            scanner.set_cursor_pos(pos);
+            let actual = scanner.scan_fortran_fixed_continuation().ok();
+            let expected = expected.map(|s| (pos..(pos + s.len()), s));
+            assert_eq!(actual, expected, "{text:?}");
+        }
+    }
+
+    #[test]
+    fn test_fortran_dot_operators() {
+        let cases = [".and.", ".EQ.", ".true.", ".False.", ".cross."];
+
+        assert_valid_cases!(scan_fortran_dot_operator, cases);
+        assert_valid_cases!(scan_fortran_dot_operator, cases, "x");
+
+        let cases = [".", "..", ".5", ".and", ".a1."];
+        assert_invalid_cases!(scan_fortran_dot_operator, cases);
+    }
+
+    #[test]
+    fn test_fortran_operators() {
+        let cases = [
+            "**", "*", "//", "/=", "/", "==", "=>", "=", "<=", "<", ">=", ">", "::", ":", "+", "-",
+            "%", ",", ";",
+        ];
+
+        assert_valid_cases!(scan_fortran_operator, cases);
+        assert_valid_cases!(scan_fortran_operator, cases, " rest");
+    }
+
+    #[test]
+    fn test_fortran_ints() {
+        let cases = ["0", "123", "123_8", "1_int64"];
+
+        assert_valid_cases!(scan_fortran_int, cases);
+        assert_valid_cases!(scan_fortran_int, cases, " rest");
+    }
+
+    #[test]
+    fn test_fortran_reals() {
+        let cases = [
+            "1.5",
+            "1.",
+            ".5",
+            "1e3",
+            "1E-3",
+            "1.5d0",
+            "1.5D+10",
+            "1.5q0",
+            "1.5_dp",
+            "1._8",
+            "1e3_real64",
+        ];
+
+        assert_valid_cases!(scan_fortran_real, cases);
+        assert_valid_cases!(scan_fortran_real, cases, " rest");
+
+        let mut scanner = Scanner::new("1.eq.x");
+        assert!(scanner.scan_fortran_real().is_err());
+        assert_eq!(scanner.scan_fortran_int(), Ok((0..1, "1")));
+        assert_eq!(scanner.scan_fortran_dot_operator(), Ok((1..5, ".eq.")));
+    }
+
+    #[test]
+    fn test_fortran_reals_invalid() {
+        let cases = ["1", "123_8", ".", ".e3", "e3"];
+
+        assert_invalid_cases!(scan_fortran_real, cases);
+    }
+
+    #[test]
+    fn test_fortran_boz() {
+        let cases = ["B'1010'", "b\"1\"", "O'777'", "Z'FF'", "z\"0aF\""];
+
+        assert_valid_cases!(scan_fortran_boz, cases);
+        assert_valid_cases!(scan_fortran_boz, cases, " rest");
+
+        let cases = ["B'2'", "Z''", "X'1'", "Z'FF\"", "B1"];
+        assert_invalid_cases!(scan_fortran_boz, cases);
+    }
+
+    #[test]
+    fn test_fortran_strings() {
+        let cases = [
+            "''",
+            "'Hello'",
+            "\"Hello\"",
+            "'Don''t'",
+            "\"Say \"\"Hi\"\"\"",
+            "'\"'",
+            "''''",
+        ];
+
+        assert_valid_cases!(scan_fortran_string, cases);
+        assert_valid_cases!(scan_fortran_string, cases, " rest");
+
+        let mut scanner = Scanner::new("'unterminated\n'");
+        assert_eq!(
+            scanner.scan_fortran_string_outcome(),
+            Ok((0..13, ScanOutcome::Unterminated(0..1))),
+        );
+    }
+}
@@ -0,0 +1,669 @@
+use crate::{ext::RustScannerExt, Scanner, ScannerResult, SeparatorPolicy};
+
+// Reference: https://go.dev/ref/spec#Integer_literals
+const GO_DIGIT_SEPARATORS: SeparatorPolicy = SeparatorPolicy::STRICT;
+
+// Reference: https://go.dev/ref/spec#Integer_literals
+const GO_PREFIXED_DIGIT_SEPARATORS: SeparatorPolicy = SeparatorPolicy {
+    leading: true,
+    ..SeparatorPolicy::STRICT
+};
+
+/// Reference: <https://go.dev/ref/spec#Keywords>
+#[rustfmt::skip]
+pub const GO_KEYWORDS: &[&str] = &[
+    "break", "case", "chan", "const", "continue", "default", "defer", "else",
+    "fallthrough", "for", "func", "go", "goto", "if", "import", "interface",
+    "map", "package", "range", "return", "select", "struct", "switch", "type",
+    "var",
+];
+
+/// Reference: <https://go.dev/ref/spec#Predeclared_identifiers>
+#[rustfmt::skip]
+pub const GO_PREDECLARED_IDENTIFIERS: &[&str] = &[
+    // Types
+    "any", "bool", "byte", "comparable", "complex64", "complex128", "error",
+    "float32", "float64", "int", "int8", "int16", "int32", "int64", "rune",
+    "string", "uint", "uint8", "uint16", "uint32", "uint64", "uintptr",
+    // Constants
+    "true", "false", "iota",
+    // Zero value
+    "nil",
+    // Functions
+    "append", "cap", "clear", "close", "complex", "copy", "delete", "imag",
+    "len", "make", "max", "min", "new", "panic", "print", "println", "real",
+    "recover",
+];
+
+/// Reference: <https://go.dev/ref/spec#Operators_and_punctuation>
+#[rustfmt::skip]
+pub const GO_OPERATORS: &[&str] = &[
+    "+", "-", "*", "/", "%",
+    "&", "|", "^", "<<", ">>", "&^",
+    "+=", "-=", "*=", "/=", "%=",
+    "&=", "|=", "^=", "<<=", ">>=", "&^=",
+    "&&", "||", "<-", "++", "--",
+    "==", "<", ">", "=", "!", "~",
+    "!=", "<=", ">=", ":=", "...",
+    ",", ";", ".", ":",
+];
+
+pub const GO_DELIMITERS: &[&str] = &["(", ")", "[", "]", "{", "}"];
+
+/// [`Scanner`] extension for scanning Go tokens.
+///
+/// See also [`GoStrExt`].
+///
+/// _Based on [Go 1.21]_.
+///
+/// [Go 1.21]: https://go.dev/ref/spec
+pub trait GoScannerExt<'text>: crate::private::Sealed {
+    fn scan_go_line_comment(&mut self) -> ScannerResult<'text, &'text str>;
+    fn scan_go_block_comment(&mut self) -> ScannerResult<'text, &'text str>;
+
+    fn scan_go_identifier(&mut self) -> ScannerResult<'text, &'text str>;
+    fn scan_go_keyword(&mut self) -> ScannerResult<'text, &'text str>;
+
+    fn scan_go_operator(&mut self) -> ScannerResult<'text, &'text str>;
+    fn scan_go_delimiter(&mut self) -> ScannerResult<'text, &'text str>;
+
+    fn scan_go_int_literal(&mut self) -> ScannerResult<'text, &'text str>;
+    fn scan_go_int_dec_literal(&mut self) -> ScannerResult<'text, &'text str>;
+    fn scan_go_int_hex_literal(&mut self) -> ScannerResult<'text, &'text str>;
+    /// Scans an octal integer literal, including legacy octal
+    /// integer literals without the `o`, e.g. `0o600` and `0600`.
+    fn scan_go_int_oct_literal(&mut self) -> ScannerResult<'text, &'text str>;
+    fn scan_go_int_bin_literal(&mut self) -> ScannerResult<'text, &'text str>;
+
+    /// Scans a floating-point literal, which contains a `.` and/or an
+    /// exponent, i.e. integer literals are not floating-point literals.
+    fn scan_go_float_literal(&mut self) -> ScannerResult<'text, &'text str>;
+    fn scan_go_float_dec_literal(&mut self) -> ScannerResult<'text, &'text str>;
+    fn scan_go_float_hex_literal(&mut self) -> ScannerResult<'text, &'text str>;
+
+    /// Scans an imaginary literal, i.e. an integer or floating-point
+    /// literal followed by `i`, e.g. `1i`, `0x1p-2i`, and `1.5e3i`.
+    fn scan_go_imaginary_literal(&mut self) -> ScannerResult<'text, &'text str>;
+
+    fn scan_go_rune_literal(&mut self) -> ScannerResult<'text, &'text str>;
+
+    /// Scans an interpreted string literal, e.g. `"foo\n"`,
+    /// or a raw string literal, e.g. `` `foo\n` ``.
+    fn scan_go_string(&mut self) -> ScannerResult<'text, &'text str>;
+    /// Scans an interpreted string literal, e.g. `"foo\n"`.
+    ///
+    /// Interpreted string literals cannot contain line breaks, so an
+    /// unterminated string literal ends before the line break.
+    fn scan_go_interpreted_string(&mut self) -> ScannerResult<'text, &'text str>;
+    /// Scans a raw string literal, e.g. `` `foo\n` ``,
+    /// which can span multiple lines.
+    fn scan_go_raw_string(&mut self) -> ScannerResult<'text, &'text str>;
+}
+
+impl<'text> GoScannerExt<'text> for Scanner<'text> {
+    // Reference: https://go.dev/ref/spec#Comments
+    #[inline]
+    fn scan_go_line_comment(&mut self) -> ScannerResult<'text, &'text str> {
+        self.scan_rust_line_comment()
+    }
+
+    // Reference: https://go.dev/ref/spec#Comments
+    #[inline]
+    fn scan_go_block_comment(&mut self) -> ScannerResult<'text, &'text str> {
+        self.scan_block_comment("/*", "*/", false)
+    }
+
+    // Reference: https://go.dev/ref/spec#Identifiers
+    #[inline]
+    fn scan_go_identifier(&mut self) -> ScannerResult<'text, &'text str> {
+        self.scan_with(|scanner| {
+            scan_go_identifier(scanner)?;
+            Ok(())
+        })
+    }
+
+    // Reference: https://go.dev/ref/spec#Keywords
+    fn scan_go_keyword(&mut self) -> ScannerResult<'text, &'text str> {
+        self.scan_with(|scanner| {
+            let (r, ident) = scan_go_identifier(scanner)?;
+            if ident.is_go_keyword() {
+                Ok(())
+            } else {
+                Err((r, ident))
+            }
+        })
+    }
+
+    // Reference: https://go.dev/ref/spec#Operators_and_punctuation
+    fn scan_go_operator(&mut self) -> ScannerResult<'text, &'text str> {
+        self.scan_with(|scanner| {
+            let (first, c) = scanner.next()?;
+            match c {
+                // + += ++
+                // - -= --
+                '+' | '-' => {
+                    _ = scanner.accept_char_any(&[c, '=']);
+                }
+                // | |= ||
+                '|' => {
+                    _ = scanner.accept_char_any(&['|', '=']);
+                }
+                // * *= / /= % %= ^ ^=
+                // = == ! != : :=
+                '*' | '/' | '%' | '^' | '=' | '!' | ':' => {
+                    _ = scanner.accept_char('=');
+                }
+                // & &= && &^ &^=
+                '&' => {
+                    if let Ok((_r, c)) = scanner.accept_char_any(&['&', '=', '^']) {
+                        if c == '^' {
+                            _ = scanner.accept_char('=');
+                        }
+                    }
+                }
+                // < <= << <<= <-
+                '<' => {
+                    if scanner.accept_char('<').is_ok() {
+                        _ = scanner.accept_char('=');
+                    } else {
+                        _ = scanner.accept_char_any(&['=', '-']);
+                    }
+                }
+                // > >= >> >>=
+                '>' => {
+                    _ = scanner.accept_char('>');
+                    _ = scanner.accept_char('=');
+                }
+                // . ...
+                '.' => {
+                    _ = scanner.accept_str("..");
+                }
+                // , ; ~
+                ',' | ';' | '~' => {}
+                _ => return Err(scanner.ranged_text(first)),
+            }
+            Ok(())
+        })
+    }
+
+    // Reference: https://go.dev/ref/spec#Operators_and_punctuation
+    #[inline]
+    fn scan_go_delimiter(&mut self) -> ScannerResult<'text, &'text str> {
+        let (r, _c) = self.accept_char_any(&['(', ')', '[', ']', '{', '}'])?;
+        Ok(self.ranged_text(r))
+    }
+
+    // Reference: https://go.dev/ref/spec#Integer_literals
+    #[inline]
+    fn scan_go_int_literal(&mut self) -> ScannerResult<'text, &'text str> {
+        self.scan_go_int_hex_literal()
+            .or_else(|_| self.scan_go_int_bin_literal())
+            .or_else(|_| self.scan_go_int_oct_literal())
+            .or_else(|_| self.scan_go_int_dec_literal())
+    }
+
+    // Reference: https://go.dev/ref/spec#Integer_literals
+    #[inline]
+    fn scan_go_int_dec_literal(&mut self) -> ScannerResult<'text, &'text str> {
+        self.scan_digits_sep(10, '_', GO_DIGIT_SEPARATORS)
+    }
+
+    // Reference: https://go.dev/ref/spec#Integer_literals
+    #[inline]
+    fn scan_go_int_hex_literal(&mut self) -> ScannerResult<'text, &'text str> {
+        self.scan_with(|scanner| {
+            scanner.accept_char('0')?;
+            scanner.accept_char_any(&['x', 'X'])?;
+            scanner.scan_digits_sep(16, '_', GO_PREFIXED_DIGIT_SEPARATORS)?;
+            Ok(())
+        })
+    }
+
+    // Reference: https://go.dev/ref/spec#Integer_literals
+    #[inline]
+    fn scan_go_int_oct_literal(&mut self) -> ScannerResult<'text, &'text str> {
+        self.scan_with(|scanner| {
+            scanner.accept_char('0')?;
+            _ = scanner.accept_char_any(&['o', 'O']);
+            scanner.scan_digits_sep(8, '_', GO_PREFIXED_DIGIT_SEPARATORS)?;
+            Ok(())
+        })
+    }
+
+    // Reference: https://go.dev/ref/spec#Integer_literals
+    #[inline]
+    fn scan_go_int_bin_literal(&mut self) -> ScannerResult<'text, &'text str> {
+        self.scan_with(|scanner| {
+            scanner.accept_char('0')?;
+            scanner.accept_char_any(&['b', 'B'])?;
+            scanner.scan_digits_sep(2, '_', GO_PREFIXED_DIGIT_SEPARATORS)?;
+            Ok(())
+        })
+    }
+
+    // Reference: https://go.dev/ref/spec#Floating-point_literals
+    #[inline]
+    fn scan_go_float_literal(&mut self) -> ScannerResult<'text, &'text str> {
+        self.scan_go_float_hex_literal()
+            .or_else(|_| self.scan_go_float_dec_literal())
+    }
+
+    // Reference: https://go.dev/ref/spec#Floating-point_literals
+    fn scan_go_float_dec_literal(&mut self) -> ScannerResult<'text, &'text str> {
+        self.scan_with(|scanner| {
+            let start = scanner.cursor_pos();
+
+            let has_fraction = if scanner.accept_char('.').is_ok() {
+                scanner.scan_digits_sep(10, '_', GO_DIGIT_SEPARATORS)?;
+                true
+            } else {
+                scanner.scan_digits_sep(10, '_', GO_DIGIT_SEPARATORS)?;
+                if scanner.accept_char('.').is_ok() {
+                    _ = scanner.scan_digits_sep(10, '_', GO_DIGIT_SEPARATORS);
+                    true
+                } else {
+                    false
+                }
+            };
+
+            let has_exponent = scan_go_exponent(scanner, &['e', 'E']).is_ok();
+
+            if !has_fraction && !has_exponent {
+                return Err(scanner.ranged_text(start..scanner.cursor_pos()));
+            }
+
+            Ok(())
+        })
+    }
+
+    // Reference: https://go.dev/ref/spec#Floating-point_literals
+    fn scan_go_float_hex_literal(&mut self) -> ScannerResult<'text, &'text str> {
+        self.scan_with(|scanner| {
+            scanner.accept_char('0')?;
+            scanner.accept_char_any(&['x', 'X'])?;
+
+            if scanner.accept_char('.').is_ok() {
+                scanner.scan_digits_sep(16, '_', GO_DIGIT_SEPARATORS)?;
+            } else {
+                scanner.scan_digits_sep(16, '_', GO_PREFIXED_DIGIT_SEPARATORS)?;
+                if scanner.accept_char('.').is_ok() {
+                    _ = scanner.scan_digits_sep(16, '_', GO_DIGIT_SEPARATORS);
+                }
+            }
+
+            // The exponent is required for hexadecimal floating-point literals
+            scan_go_exponent(scanner, &['p', 'P'])?;
+
+            Ok(())
+        })
+    }
+
+    // Reference: https://go.dev/ref/spec#Imaginary_literals
+    fn scan_go_imaginary_literal(&mut self) -> ScannerResult<'text, &'text str> {
+        self.scan_with(|scanner| {
+            // Note: For backward compatibility, the integer part of e.g. `0123i`
+            // is decimal and not octal, which is also scanned as an octal literal
+            scanner
+                .scan_go_float_literal()
+                .or_else(|_| scanner.scan_go_int_literal())?;
+            scanner.accept_char('i')?;
+            Ok(())
+        })
+    }
+
+    // Reference: https://go.dev/ref/spec#Rune_literals
+    fn scan_go_rune_literal(&mut self) -> ScannerResult<'text, &'text str> {
+        self.scan_with(|scanner| {
+            scanner.accept_char('\'')?;
+
+            let (_r, c) = scanner.next()?;
+            if c == '\\' {
+                // Skip the digits of `\x7F`, `\u12e4`, `\U00101234`, and `\377`
+                // Note: Technically any character is not valid
+                let (_r, c) = scanner.next()?;
+                let (digits, is_digit): (usize, fn(&char) -> bool) = match c {
+                    'x' => (2, char::is_ascii_hexdigit),
+                    'u' => (4, char::is_ascii_hexdigit),
+                    'U' => (8, char::is_ascii_hexdigit),
+                    '0'..='7' => (2, |c| matches!(c, '0'..='7')),
+                    _ => (0, char::is_ascii_hexdigit),
+                };
+                for _ in 0..digits {
+                    if scanner.accept_if_ext(is_digit).is_err() {
+                        break;
+                    }
+                }
+            }
+
+            scanner.accept_char('\'')?;
+            Ok(())
+        })
+    }
+
+    // Reference: https://go.dev/ref/spec#String_literals
+    #[inline]
+    fn scan_go_string(&mut self) -> ScannerResult<'text, &'text str> {
+        self.scan_go_interpreted_string()
+            .or_else(|_| self.scan_go_raw_string())
+    }
+
+    // Reference: https://go.dev/ref/spec#String_literals
+    fn scan_go_interpreted_string(&mut self) -> ScannerResult<'text, &'text str> {
+        self.scan_with(|scanner| {
+            scanner.accept_char('"')?;
+
+            loop {
+                scanner.skip_until_char_any(&['"', '\\', '\n']);
+                match scanner.next() {
+                    Ok((_r, '"')) => break,
+                    Ok((r, '\n')) => {
+                        // Unterminated string literal
+                        scanner.cursor = r.start;
+                        break;
+                    }
+                    Ok((_r, '\\')) => {
+                        // Skip the next character as it is escaped
+                        // Note: Technically any character is not valid
+                        _ = scanner.accept_if(|c| c != '\n');
+                    }
+                    Ok(_) => unreachable!(),
+                    Err(_) => break,
+                }
+            }
+
+            Ok(())
+        })
+    }
+
+    // Reference: https://go.dev/ref/spec#String_literals
+    fn scan_go_raw_string(&mut self) -> ScannerResult<'text, &'text str> {
+        self.scan_with(|scanner| {
+            scanner.accept_char('`')?;
+            scanner.skip_until_char('`');
+            _ = scanner.accept_char('`');
+            Ok(())
+        })
+    }
+}
+
+#[inline]
+fn scan_go_identifier<'text>(scanner: &mut Scanner<'text>) -> ScannerResult<'text, &'text str> {
+    let (first, _) = scanner.accept_if(|c| c.is_alphabetic() || (c == '_'))?;
+    let (last, _) = scanner.skip_while(|c| c.is_alphanumeric() || (c == '_'));
+    Ok(scanner.ranged_text(first.start..last.end))
+}
+
+// Reference: https://go.dev/ref/spec#Floating-point_literals
+#[inline]
+fn scan_go_exponent<'text>(
+    scanner: &mut Scanner<'text>,
+    exponents: &[char],
+) -> ScannerResult<'text, &'text str> {
+    scanner.scan_with(|scanner| {
+        scanner.accept_char_any(exponents)?;
+        _ = scanner.accept_char_any(&['+', '-']);
+        scanner.scan_digits_sep(10, '_', GO_DIGIT_SEPARATORS)?;
+        Ok(())
+    })
+}
+
+/// [`str`] extension for checking if a `&str` is e.g. a Go keyword.
+pub trait GoStrExt {
+    fn is_go_keyword(&self) -> bool;
+    fn is_go_predeclared_identifier(&self) -> bool;
+
+    fn is_go_operator(&self) -> bool;
+    fn is_go_delimiter(&self) -> bool;
+}
+
+impl GoStrExt for str {
+    #[inline]
+    fn is_go_keyword(&self) -> bool {
+        GO_KEYWORDS.contains(&self)
+    }
+
+    #[inline]
+    fn is_go_predeclared_identifier(&self) -> bool {
+        GO_PREDECLARED_IDENTIFIERS.contains(&self)
+    }
+
+    #[inline]
+    fn is_go_operator(&self) -> bool {
+        GO_OPERATORS.contains(&self)
+    }
+
+    #[inline]
+    fn is_go_delimiter(&self) -> bool {
+        GO_DELIMITERS.contains(&self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{assert_invalid_cases, assert_valid_cases};
+
+    #[test]
+    fn test_go_comments() {
+        let cases = ["//", "// foo", "/**/", "/* foo\nbar */"];
+        for case in cases {
+            let mut scanner = Scanner::new(case);
+            let actual = scanner
+                .scan_go_line_comment()
+                .or_else(|_| scanner.scan_go_block_comment());
+            assert_eq!(actual, Ok((0..case.len(), case)));
+        }
+
+        // Block comments do not nest
+        let mut scanner = Scanner::new("/* a /* b */ c */");
+        assert_eq!(scanner.scan_go_block_comment(), Ok((0..12, "/* a /* b */")));
+    }
+
+    #[test]
+    fn test_go_identifiers() {
+        let cases = ["a", "_x9", "ThisVariableIsExported", "αβ", "_"];
+
+        assert_valid_cases!(scan_go_identifier, cases);
+        assert_valid_cases!(scan_go_identifier, cases, " remaining");
+    }
+
+    #[test]
+    fn test_go_identifiers_invalid() {
+        let cases = ["", "0", "0foo", "$foo"];
+
+        assert_invalid_cases!(scan_go_identifier, cases);
+    }
+
+    #[test]
+    fn test_go_keywords() {
+        for &expected in GO_KEYWORDS {
+            let mut scanner = Scanner::new(expected);
+
+            let actual = scanner.scan_go_keyword().map(|(_r, kw)| kw);
+            assert_eq!(actual, Ok(expected));
+
+            assert!(expected.is_go_keyword());
+            assert!(!expected.is_go_predeclared_identifier());
+        }
+
+        assert_invalid_cases!(scan_go_keyword, ["nil", "int", "breaks", "Func"]);
+    }
+
+    #[test]
+    fn test_go_operators() {
+        for &expected in GO_OPERATORS {
+            for remaining in ["", "remaining"] {
+                let text = format!("{expected}{remaining}");
+                let mut scanner = Scanner::new(&text);
+
+                let actual = scanner.scan_go_operator().map(|(_r, punct)| punct);
+                assert_eq!(actual, Ok(expected));
+                assert_eq!(scanner.remaining_text(), remaining);
+
+                let actual = actual.unwrap();
+                assert!(actual.is_go_operator());
+                assert!(!actual.is_go_delimiter());
+            }
+        }
+
+        let mut scanner = Scanner::new("..");
+        assert_eq!(scanner.scan_go_operator(), Ok((0..1, ".")));
+    }
+
+    #[test]
+    fn test_go_delimiters() {
+        for &expected in GO_DELIMITERS {
+            for remaining in ["", "remaining"] {
+                let text = format!("{expected}{remaining}");
+                let mut scanner = Scanner::new(&text);
+
+                let actual = scanner.scan_go_delimiter().map(|(_r, punct)| punct);
+                assert_eq!(actual, Ok(expected));
+                assert_eq!(scanner.remaining_text(), remaining);
+
+                let actual = actual.unwrap();
+                assert!(actual.is_go_delimiter());
+                assert!(!actual.is_go_operator());
+            }
+        }
+    }
+
+    #[test]
+    fn test_go_int_literals() {
+        let cases = [
+            "42",
+            "4_2",
+            "0600",
+            "0_600",
+            "0o600",
+            "0O600",
+            "0xBadFace",
+            "0xBad_Face",
+            "0x_67_7a_2f_cc_40_c6",
+            "0b0101",
+            "0B_1",
+            "170141183460469231731687303715884105727",
+            "170_141183_460469_231731_687303_715884_105727",
+        ];
+
+        assert_valid_cases!(scan_go_int_literal, cases);
+        assert_valid_cases!(scan_go_int_literal, cases, "remaining");
+
+        assert_valid_cases!(scan_go_int_oct_literal, ["0600", "0_600", "0o600"]);
+        assert_valid_cases!(scan_go_int_hex_literal, ["0X_1F", "0xff"]);
+        assert_valid_cases!(scan_go_int_bin_literal, ["0b1", "0b_1_0"]);
+    }
+
+    #[test]
+    fn test_go_int_literals_invalid() {
+        let cases = ["_42", "0x", "0b", "0_x1"];
+
+        assert_invalid_cases!(scan_go_int_hex_literal, cases);
+        assert_invalid_cases!(scan_go_int_bin_literal, cases);
+        assert_invalid_cases!(scan_go_int_dec_literal, ["_42", "x"]);
+        assert_invalid_cases!(scan_go_int_hex_literal, ["0x__1", "0xg"]);
+    }
+
+    #[test]
+    fn test_go_float_literals() {
+        let cases = [
+            "0.",
+            "72.40",
+            "072.40",
+            "2.71828",
+            "1.e+0",
+            "6.67428e-11",
+            "1E6",
+            ".25",
+            ".12345E+5",
+            "1_5.",
+            "0.15e+0_2",
+            //
+            "0x1p-2",
+            "0x2.p10",
+            "0x1.Fp+0",
+            "0X.8p-0",
+            "0X_1FFFP-16",
+        ];
+
+        assert_valid_cases!(scan_go_float_literal, cases);
+        assert_valid_cases!(scan_go_float_literal, cases, "remaining");
+    }
+
+    #[test]
+    fn test_go_float_literals_invalid() {
+        let cases = ["1", "0x1", "0x15e", "1_.5", "1e", "0x.p1", "."];
+
+        assert_invalid_cases!(scan_go_float_literal, cases);
+    }
+
+    #[test]
+    fn test_go_imaginary_literals() {
+        let cases = [
+            "0i",
+            "0123i",
+            "0o123i",
+            "0xabci",
+            "0.i",
+            "2.71828i",
+            "1.e+0i",
+            "6.67428e-11i",
+            "1E6i",
+            ".25i",
+            ".12345E+5i",
+            "0x1p-2i",
+        ];
+
+        assert_valid_cases!(scan_go_imaginary_literal, cases);
+        assert_valid_cases!(scan_go_imaginary_literal, cases, "remaining");
+
+        assert_invalid_cases!(scan_go_imaginary_literal, ["1", "i", "0x1"]);
+    }
+
+    #[test]
+    fn test_go_rune_literals() {
+        let cases = [
+            "'a'",
+            "'ä'",
+            "'本'",
+            r"'\t'",
+            r"'\000'",
+            r"'\007'",
+            r"'\377'",
+            r"'\x07'",
+            r"'\xff'",
+            r"'\u12e4'",
+            r"'\U00101234'",
+            r"'\''",
+        ];
+
+        assert_valid_cases!(scan_go_rune_literal, cases);
+        assert_valid_cases!(scan_go_rune_literal, cases, "remaining");
+
+        assert_invalid_cases!(scan_go_rune_literal, ["''", "'aa'", r"'\xffff'", "'a"]);
+    }
+
+    #[test]
+    fn test_go_string_literals() {
+        let cases = [
+            r#""""#,
+            r#""abc""#,
+            r#""\n""#,
+            r#""\"""#,
+            r#""Hello, world!\n""#,
+            r#""日本語""#,
+            r#""日本\U00008a9e""#,
+            "``",
+            "`abc`",
+            "`\\n\n\\n`",
+        ];
+
+        assert_valid_cases!(scan_go_string, cases);
+        assert_valid_cases!(scan_go_string, cases, "remaining");
+
+        // Unterminated interpreted string literals end before the line break
+        let mut scanner = Scanner::new("\"foo\nbar\"");
+        assert_eq!(scanner.scan_go_interpreted_string(), Ok((0..4, "\"foo")));
+        assert_eq!(scanner.scan_go_raw_string(), Err((4..4, "")));
+    }
+}
@@ -0,0 +1,432 @@
+use crate::{Scanner, ScannerResult};
+
+/// [`Scanner`] extension for scanning [HTML] tokens.
+///
+/// [HTML]: https://html.spec.whatwg.org/multipage/syntax.html
+pub trait HtmlScannerExt<'text>: crate::private::Sealed {
+    /// Scans a single [HTML comment], i.e. `<!-- ... -->`.
+    ///
+    /// **Note:** This has the same lifetime as the original `text`,
+    /// so the scanner can continue to be used while this exists.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use text_scanner::{ext::HtmlScannerExt, Scanner};
+    ///
+    /// let text = r#"
+    ///   <!-- Comment -->
+    ///   <!-- Multi
+    ///   Line -->
+    ///   <!-- Unterminated Comment
+    /// "#;
+    ///
+    /// let comments = [
+    ///     (3..19,  "<!-- Comment -->"),
+    ///     (22..43, "<!-- Multi\n  Line -->"),
+    ///     (46..72, "<!-- Unterminated Comment\n"),
+    /// ];
+    ///
+    /// let mut scanner = Scanner::new(text);
+    /// for comment in comments {
+    ///     scanner.skip_whitespace();
+    ///     assert_eq!(scanner.scan_html_comment(), Ok(comment));
+    /// }
+    ///
+    /// # scanner.skip_whitespace();
+    /// # assert_eq!(scanner.remaining_text(), "");
+    /// ```
+    ///
+    /// [HTML comment]: https://html.spec.whatwg.org/multipage/syntax.html#comments
+    fn scan_html_comment(&mut self) -> ScannerResult<'text, &'text str>;
+
+    /// Scans a single [HTML DOCTYPE], or any other markup declaration
+    /// starting with `<!`, e.g. `<!DOCTYPE html>` and `<![CDATA[ ... ]]>`.
+    ///
+    /// **Note:** This does not scan comments, see [`scan_html_comment()`].
+    /// Likewise, CDATA sections end at the first `>`, even if it is inside
+    /// the section, see [`scan_html_cdata()`].
+    ///
+    /// **Note:** This has the same lifetime as the original `text`,
+    /// so the scanner can continue to be used while this exists.
+    ///
+    /// [HTML DOCTYPE]: https://html.spec.whatwg.org/multipage/syntax.html#the-doctype
+    /// [`scan_html_comment()`]: Self::scan_html_comment
+    /// [`scan_html_cdata()`]: Self::scan_html_cdata
+    fn scan_html_doctype(&mut self) -> ScannerResult<'text, &'text str>;
+
+    /// Scans a single [CDATA section], i.e. `<![CDATA[ ... ]]>`, which
+    /// in HTML only occurs in foreign content, e.g. inline `<svg>`.
+    ///
+    /// **Note:** This has the same lifetime as the original `text`,
+    /// so the scanner can continue to be used while this exists.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use text_scanner::{ext::HtmlScannerExt, Scanner};
+    ///
+    /// let text = "<![CDATA[ x > y ]]> <![CDATA[ Unterminated";
+    ///
+    /// let mut scanner = Scanner::new(text);
+    /// assert_eq!(scanner.scan_html_cdata(), Ok((0..19, "<![CDATA[ x > y ]]>")));
+    /// scanner.skip_whitespace();
+    /// assert_eq!(scanner.scan_html_cdata(), Ok((20..42, "<![CDATA[ Unterminated")));
+    /// ```
+    ///
+    /// [CDATA section]: https://html.spec.whatwg.org/multipage/syntax.html#cdata-sections
+    fn scan_html_cdata(&mut self) -> ScannerResult<'text, &'text str>;
+
+    /// Scans a single [HTML tag name], e.g. `div` and `my-component`.
+    ///
+    /// **Note:** This has the same lifetime as the original `text`,
+    /// so the scanner can continue to be used while this exists.
+    ///
+    /// [HTML tag name]: https://html.spec.whatwg.org/multipage/syntax.html#syntax-tag-name
+    fn scan_html_tag_name(&mut self) -> ScannerResult<'text, &'text str>;
+
+    /// Scans a single [HTML attribute name].
+    ///
+    /// Attribute names are lenient, such that framework specific
+    /// attributes are also accepted, e.g. `:value`, `@click`, `v-if`,
+    /// `#default`, and `on:click`.
+    ///
+    /// **Note:** This has the same lifetime as the original `text`,
+    /// so the scanner can continue to be used while this exists.
+    ///
+    /// [HTML attribute name]: https://html.spec.whatwg.org/multipage/syntax.html#attributes-2
+    fn scan_html_attr_name(&mut self) -> ScannerResult<'text, &'text str>;
+
+    /// Scans a single quoted or unquoted [HTML attribute value].
+    ///
+    /// Unquoted values starting with `{` are scanned until the matching `}`,
+    /// such that framework expressions are accepted, e.g. Svelte's
+    /// `on:click={() => count++}`.
+    ///
+    /// **Note:** This has the same lifetime as the original `text`,
+    /// so the scanner can continue to be used while this exists.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use text_scanner::{ext::HtmlScannerExt, Scanner};
+    ///
+    /// let text = r#"
+    ///   "foo > bar"
+    ///   'foo'
+    ///   foo
+    ///   {() => ({ a: 1 })}
+    ///   "Unterminated
+    /// "#;
+    ///
+    /// let values = [
+    ///     (3..14,  r#""foo > bar""#),
+    ///     (17..22, "'foo'"),
+    ///     (25..28, "foo"),
+    ///     (31..49, "{() => ({ a: 1 })}"),
+    ///     (52..66, "\"Unterminated\n"),
+    /// ];
+    ///
+    /// let mut scanner = Scanner::new(text);
+    /// for value in values {
+    ///     scanner.skip_whitespace();
+    ///     assert_eq!(scanner.scan_html_attr_value(), Ok(value));
+    /// }
+    ///
+    /// # scanner.skip_whitespace();
+    /// # assert_eq!(scanner.remaining_text(), "");
+    /// ```
+    ///
+    /// [HTML attribute value]: https://html.spec.whatwg.org/multipage/syntax.html#attributes-2
+    fn scan_html_attr_value(&mut self) -> ScannerResult<'text, &'text str>;
+
+    /// Scans a single [HTML character reference], i.e. a named, decimal,
+    /// or hexadecimal reference, e.g. `&amp;`, `&#39;`, and `&#x27;`.
+    ///
+    /// The terminating `;` is required, such that ambiguous ampersands,
+    /// e.g. `&` in `a & b` and `&copy` without `;`, are not scanned. The
+    /// names of named references are not validated.
+    ///
+    /// **Note:** This has the same lifetime as the original `text`,
+    /// so the scanner can continue to be used while this exists.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use text_scanner::{ext::HtmlScannerExt, Scanner};
+    ///
+    /// let text = "&lt;&#60;&#x3C; & &lt";
+    ///
+    /// let mut scanner = Scanner::new(text);
+    /// assert_eq!(scanner.scan_html_entity(), Ok((0..4, "&lt;")));
+    /// assert_eq!(scanner.scan_html_entity(), Ok((4..9, "&#60;")));
+    /// assert_eq!(scanner.scan_html_entity(), Ok((9..15, "&#x3C;")));
+    ///
+    /// scanner.skip_whitespace();
+    /// assert!(scanner.scan_html_entity().is_err());
+    /// assert_eq!(scanner.remaining_text(), "& &lt");
+    /// ```
+    ///
+    /// [HTML character reference]: https://html.spec.whatwg.org/multipage/syntax.html#character-references
+    fn scan_html_entity(&mut self) -> ScannerResult<'text, &'text str>;
+
+    /// Scans the [raw text] contents of an element, e.g. the contents of
+    /// `<script>` and `<style>`, i.e. until the end tag `</tag_name`
+    /// (matched case-insensitively) or the end of the text.
+    ///
+    /// Returns `Err` if the contents are empty.
+    ///
+    /// **Note:** This has the same lifetime as the original `text`,
+    /// so the scanner can continue to be used while this exists.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use text_scanner::{ext::HtmlScannerExt, Scanner};
+    ///
+    /// let text = "if (a </b) {}</SCRIPT>";
+    ///
+    /// let mut scanner = Scanner::new(text);
+    /// assert_eq!(scanner.scan_html_raw_text("script"), Ok((0..13, "if (a </b) {}")));
+    /// assert_eq!(scanner.remaining_text(), "</SCRIPT>");
+    /// ```
+    ///
+    /// [raw text]: https://html.spec.whatwg.org/multipage/syntax.html#raw-text-elements
+    fn scan_html_raw_text(&mut self, tag_name: &str) -> ScannerResult<'text, &'text str>;
+}
+
+impl<'text> HtmlScannerExt<'text> for Scanner<'text> {
+    // Reference: https://html.spec.whatwg.org/multipage/syntax.html#comments
+    fn scan_html_comment(&mut self) -> ScannerResult<'text, &'text str> {
+        self.scan_with(|scanner| {
+            scanner.accept_str("<!--")?;
+
+            let (_r, _s) = scanner.skip_until_str("-->");
+            _ = scanner.accept_str("-->");
+
+            Ok(())
+        })
+    }
+
+    // Reference: https://html.spec.whatwg.org/multipage/syntax.html#the-doctype
+    fn scan_html_doctype(&mut self) -> ScannerResult<'text, &'text str> {
+        self.scan_with(|scanner| {
+            scanner.accept_str("<!")?;
+            if scanner.remaining_text().starts_with("--") {
+                return Err(scanner.ranged_text(scanner.cursor_pos()..scanner.cursor_pos()));
+            }
+
+            scanner.skip_until_char('>');
+            _ = scanner.accept_char('>');
+
+            Ok(())
+        })
+    }
+
+    // Reference: https://html.spec.whatwg.org/multipage/syntax.html#cdata-sections
+    fn scan_html_cdata(&mut self) -> ScannerResult<'text, &'text str> {
+        self.scan_with(|scanner| {
+            scanner.accept_str("<![CDATA[")?;
+
+            let (_r, _s) = scanner.skip_until_str("]]>");
+            _ = scanner.accept_str("]]>");
+
+            Ok(())
+        })
+    }
+
+    // Reference: https://html.spec.whatwg.org/multipage/syntax.html#syntax-tag-name
+    fn scan_html_tag_name(&mut self) -> ScannerResult<'text, &'text str> {
+        self.scan_with(|scanner| {
+            scanner.accept_if(|c| c.is_alphabetic())?;
+            scanner.skip_while(|c| c.is_alphanumeric() || matches!(c, '-' | '_' | ':' | '.'));
+            Ok(())
+        })
+    }
+
+    // Reference: https://html.spec.whatwg.org/multipage/syntax.html#attributes-2
+    fn scan_html_attr_name(&mut self) -> ScannerResult<'text, &'text str> {
+        self.scan_with(|scanner| {
+            let is_attr_name_char = |c: char| {
+                !c.is_whitespace() && !c.is_control() && !matches!(c, '"' | '\'' | '>' | '/' | '=')
+            };
+
+            scanner.accept_if(is_attr_name_char)?;
+            scanner.skip_while(is_attr_name_char);
+            Ok(())
+        })
+    }
+
+    // Reference: https://html.spec.whatwg.org/multipage/syntax.html#attributes-2
+    fn scan_html_attr_value(&mut self) -> ScannerResult<'text, &'text str> {
+        self.scan_with(|scanner| {
+            if let Ok((_r, quote)) = scanner.accept_char_any(&['"', '\'']) {
+                scanner.skip_until_char(quote);
+                _ = scanner.accept_char(quote);
+            } else if scanner.accept_char('{').is_ok() {
+                let mut depth = 0usize;
+                loop {
+                    scanner.skip_until_char_any(&['{', '}']);
+                    match scanner.next() {
+                        Ok((_r, '{')) => depth += 1,
+                        Ok((_r, '}')) if depth == 0 => break,
+                        Ok((_r, '}')) => depth -= 1,
+                        Ok(_) => unreachable!(),
+                        Err(_) => break,
+                    }
+                }
+            } else {
+                let is_unquoted_char = |c: char| {
+                    !c.is_whitespace() && !matches!(c, '"' | '\'' | '=' | '<' | '>' | '`')
+                };
+
+                scanner.accept_if(is_unquoted_char)?;
+                scanner.skip_while(is_unquoted_char);
+            }
+
+            Ok(())
+        })
+    }
+
+    // Reference: https://html.spec.whatwg.org/multipage/syntax.html#character-references
+    fn scan_html_entity(&mut self) -> ScannerResult<'text, &'text str> {
+        self.scan_with(|scanner| {
+            scanner.accept_char('&')?;
+
+            if scanner.accept_char('#').is_ok() {
+                if scanner.accept_char_any(&['x', 'X']).is_ok() {
+                    scanner.accept_if(|c| c.is_ascii_hexdigit())?;
+                    scanner.skip_while(|c| c.is_ascii_hexdigit());
+                } else {
+                    scanner.accept_if(|c| c.is_ascii_digit())?;
+                    scanner.skip_while(|c| c.is_ascii_digit());
+                }
+            } else {
+                scanner.accept_if(|c| c.is_ascii_alphanumeric())?;
+                scanner.skip_while(|c| c.is_ascii_alphanumeric());
+            }
+
+            scanner.accept_char(';')?;
+            Ok(())
+        })
+    }
+
+    // Reference: https://html.spec.whatwg.org/multipage/syntax.html#cdata-rcdata-restrictions
+    fn scan_html_raw_text(&mut self, tag_name: &str) -> ScannerResult<'text, &'text str> {
+        let start = self.cursor_pos();
+        let text = self.remaining_text();
+
+        let end = text
+            .match_indices("</")
+            .map(|(i, _)| i)
+            .find(|&i| {
+                let name = text[i + 2..].get(..tag_name.len());
+                name.is_some_and(|name| name.eq_ignore_ascii_case(tag_name))
+            })
+            .unwrap_or(text.len());
+
+        if end == 0 {
+            return Err(self.ranged_text(start..start));
+        }
+
+        self.set_cursor_pos(start + end);
+        Ok(self.ranged_text(start..(start + end)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_html_doctype() {
+        let cases = [
+            ("<!DOCTYPE html>", Ok((0..15, "<!DOCTYPE html>")), ""),
+            ("<!doctype html><p>", Ok((0..15, "<!doctype html>")), "<p>"),
+            ("<![CDATA[x]]>", Ok((0..13, "<![CDATA[x]]>")), ""),
+            ("<!DOCTYPE", Ok((0..9, "<!DOCTYPE")), ""),
+            //
+            ("", Err((0..0, "")), ""),
+            ("<", Err((0..1, "<")), "<"),
+            ("<p>", Err((0..1, "<")), "<p>"),
+            ("<!-- -->", Err((0..2, "<!")), "<!-- -->"),
+        ];
+
+        for (text, expected, remaining) in cases {
+            let mut scanner = Scanner::new(text);
+            assert_eq!(scanner.scan_html_doctype(), expected, "{text:?}");
+            assert_eq!(scanner.remaining_text(), remaining, "{text:?}");
+        }
+    }
+
+    #[test]
+    fn test_html_attr_name() {
+        let cases = [
+            ("class", Ok((0..5, "class")), ""),
+            ("class=\"a\"", Ok((0..5, "class")), "=\"a\""),
+            ("v-if=", Ok((0..4, "v-if")), "="),
+            (":value ", Ok((0..6, ":value")), " "),
+            ("@click.stop>", Ok((0..11, "@click.stop")), ">"),
+            ("disabled/>", Ok((0..8, "disabled")), "/>"),
+            //
+            ("", Err((0..0, "")), ""),
+            ("=", Err((0..0, "")), "="),
+            (">", Err((0..0, "")), ">"),
+            ("\"a\"", Err((0..0, "")), "\"a\""),
+        ];
+
+        for (text, expected, remaining) in cases {
+            let mut scanner = Scanner::new(text);
+            assert_eq!(scanner.scan_html_attr_name(), expected, "{text:?}");
+            assert_eq!(scanner.remaining_text(), remaining, "{text:?}");
+        }
+    }
+
+    #[test]
+    fn test_html_entity() {
+        let cases = [
+            ("&amp;", Ok((0..5, "&amp;")), ""),
+            ("&Aacute;a", Ok((0..8, "&Aacute;")), "a"),
+            ("&frac12;", Ok((0..8, "&frac12;")), ""),
+            ("&#0;", Ok((0..4, "&#0;")), ""),
+            ("&#X1f600;", Ok((0..9, "&#X1f600;")), ""),
+            ("&amp;&amp;", Ok((0..5, "&amp;")), "&amp;"),
+            //
+            ("", Err((0..0, "")), ""),
+            ("&", Err((0..1, "&")), "&"),
+            ("& ", Err((0..1, "&")), "& "),
+            ("&;", Err((0..1, "&")), "&;"),
+            ("&amp", Err((0..4, "&amp")), "&amp"),
+            ("&a-b;", Err((0..2, "&a")), "&a-b;"),
+            ("&#;", Err((0..2, "&#")), "&#;"),
+            ("&#x;", Err((0..3, "&#x")), "&#x;"),
+            ("&#xG;", Err((0..3, "&#x")), "&#xG;"),
+        ];
+
+        for (text, expected, remaining) in cases {
+            let mut scanner = Scanner::new(text);
+            assert_eq!(scanner.scan_html_entity(), expected, "{text:?}");
+            assert_eq!(scanner.remaining_text(), remaining, "{text:?}");
+        }
+    }
+
+    #[test]
+    fn test_html_raw_text() {
+        let cases = [
+            ("a</style>", Ok((0..1, "a")), "</style>"),
+            ("a</STYLE >", Ok((0..1, "a")), "</STYLE >"),
+            ("a</b></style>", Ok((0..5, "a</b>")), "</style>"),
+            ("a</styl", Ok((0..7, "a</styl")), ""),
+            ("a", Ok((0..1, "a")), ""),
+            //
+            ("", Err((0..0, "")), ""),
+            ("</style>", Err((0..0, "")), "</style>"),
+        ];
+
+        for (text, expected, remaining) in cases {
+            let mut scanner = Scanner::new(text);
+            assert_eq!(scanner.scan_html_raw_text("style"), expected, "{text:?}");
+            assert_eq!(scanner.remaining_text(), remaining, "{text:?}");
+        }
+    }
+}
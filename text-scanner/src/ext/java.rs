@@ -1,4 +1,17 @@
-use crate::{ext::CScannerExt, CharExt, Scanner, ScannerResult};
+use crate::{ext::CScannerExt, ByteSet, CharExt, Scanner, ScannerResult, SeparatorPolicy};
+
+// Reference: https://docs.oracle.com/javase/specs/jls/se20/html/jls-3.html#jls-3.10.1
+const JAVA_DIGIT_SEPARATORS: SeparatorPolicy = SeparatorPolicy {
+    consecutive: true,
+    ..SeparatorPolicy::STRICT
+};
+
+// Underscores are allowed between the `0` prefix and the digits
+// of octal literals, e.g. `0_7`
+const JAVA_PREFIXED_DIGIT_SEPARATORS: SeparatorPolicy = SeparatorPolicy {
+    leading: true,
+    ..JAVA_DIGIT_SEPARATORS
+};
 
 /// Reference: <https://docs.oracle.com/javase/specs/jls/se20/html/jls-3.html#jls-3.9>
 #[rustfmt::skip]
@@ -70,6 +83,32 @@ pub trait JavaScannerExt<'text>: crate::private::Sealed {
 
     fn scan_java_char_literal(&mut self) -> ScannerResult<'text, &'text str>;
     fn scan_java_string_literal(&mut self) -> ScannerResult<'text, &'text str>;
+    /// Scans a Java 15+ text block, i.e. a multiline string literal
+    /// delimited by `"""`.
+    ///
+    /// The opening `"""` must be followed by a line terminator, optionally
+    /// preceded by whitespace, otherwise it is not a text block. The text
+    /// block ends at the first unescaped `"""`, as such a `"` directly before
+    /// the closing delimiter must be escaped, e.g. `\""""`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use text_scanner::{ext::JavaScannerExt, Scanner};
+    ///
+    /// let text = "\"\"\"\n    Hello \"World\"\n    \"\"\" + \"\"\"\"";
+    /// let mut scanner = Scanner::new(text);
+    ///
+    /// assert_eq!(
+    ///     scanner.scan_java_text_block(),
+    ///     Ok((0..29, "\"\"\"\n    Hello \"World\"\n    \"\"\""))
+    /// );
+    ///
+    /// // Not a text block, as `"""` is not followed by a line terminator
+    /// scanner.skip_until_char('"');
+    /// assert!(scanner.scan_java_text_block().is_err());
+    /// ```
+    fn scan_java_text_block(&mut self) -> ScannerResult<'text, &'text str>;
 }
 
 impl<'text> JavaScannerExt<'text> for Scanner<'text> {
@@ -255,7 +294,7 @@ impl<'text> JavaScannerExt<'text> for Scanner<'text> {
         self.scan_with(|scanner| {
             match scanner.accept_if_ext(char::is_ascii_digit)? {
                 (r, '0') => {
-                    if scanner.peek().map_or(false, |(_r, c)| match c {
+                    if scanner.peek().is_ok_and(|(_r, c)| match c {
                         c if c.is_ascii_digit() => true,
                         'x' | 'X' | 'b' | 'B' | 'f' | 'F' | 'd' | 'D' => true,
                         _ => false,
@@ -264,7 +303,7 @@ impl<'text> JavaScannerExt<'text> for Scanner<'text> {
                     }
                 }
                 _ => {
-                    scanner.skip_while(|c| c.is_ascii_non_zero_digit() || (c == '_'));
+                    _ = scanner.scan_digits_sep(10, '_', JAVA_PREFIXED_DIGIT_SEPARATORS);
                 }
             }
 
@@ -281,10 +320,7 @@ impl<'text> JavaScannerExt<'text> for Scanner<'text> {
             scanner.accept_char('0')?;
             scanner.accept_char_any(&['x', 'X'])?;
 
-            scanner.skip_while_char('_');
-            scanner.accept_if_ext(char::is_ascii_hexdigit)?;
-
-            scanner.skip_while(|c| c.is_ascii_hexdigit() || (c == '_'));
+            scanner.scan_digits_sep(16, '_', JAVA_DIGIT_SEPARATORS)?;
 
             _ = scanner.accept_char_any(&['l', 'L']);
 
@@ -298,10 +334,7 @@ impl<'text> JavaScannerExt<'text> for Scanner<'text> {
         self.scan_with(|scanner| {
             scanner.accept_char('0')?;
 
-            scanner.skip_while_char('_');
-            scanner.accept_if(CharExt::is_ascii_octdigit)?;
-
-            scanner.skip_while(|c| CharExt::is_ascii_octdigit(c) || (c == '_'));
+            scanner.scan_digits_sep(8, '_', JAVA_PREFIXED_DIGIT_SEPARATORS)?;
 
             _ = scanner.accept_char_any(&['l', 'L']);
 
@@ -316,10 +349,7 @@ impl<'text> JavaScannerExt<'text> for Scanner<'text> {
             scanner.accept_char('0')?;
             scanner.accept_char_any(&['b', 'B'])?;
 
-            scanner.skip_while_char('_');
-            scanner.accept_if(CharExt::is_ascii_bindigit)?;
-
-            scanner.skip_while(|c| c.is_ascii_bindigit() || (c == '_'));
+            scanner.scan_digits_sep(2, '_', JAVA_DIGIT_SEPARATORS)?;
 
             _ = scanner.accept_char_any(&['l', 'L']);
 
@@ -367,13 +397,13 @@ impl<'text> JavaScannerExt<'text> for Scanner<'text> {
 
             scanner.skip_while_char('_');
             scanner.accept_if_ext(char::is_ascii_hexdigit)?;
-            scanner.skip_while(|c| c.is_ascii_hexdigit() || (c == '_'));
+            scanner.skip_while_in(&ByteSet::ASCII_HEXDIGIT_OR_UNDERSCORE);
 
             scanner.accept_char('.')?;
 
             scanner.skip_while_char('_');
             if scanner.accept_if_ext(char::is_ascii_hexdigit).is_ok() {
-                scanner.skip_while(|c| c.is_ascii_hexdigit() || (c == '_'));
+                scanner.skip_while_in(&ByteSet::ASCII_HEXDIGIT_OR_UNDERSCORE);
             }
 
             scanner.accept_char_any(&['p', 'P'])?;
@@ -421,6 +451,36 @@ impl<'text> JavaScannerExt<'text> for Scanner<'text> {
         // TODO: It can scan `\uFFFF` but it is not handled correctly
         self.scan_c_string()
     }
+
+    // Reference: https://docs.oracle.com/javase/specs/jls/se20/html/jls-3.html#jls-3.10.6
+    fn scan_java_text_block(&mut self) -> ScannerResult<'text, &'text str> {
+        self.scan_with(|scanner| {
+            scanner.accept_str(r#"""""#)?;
+            scanner.skip_while_char_any(&[' ', '\t', '\x0C']);
+            scanner.accept_char_any(&['\n', '\r'])?;
+
+            loop {
+                scanner.skip_until_char_any(&['"', '\\']);
+                match scanner.next() {
+                    Ok((_r, '"')) => {
+                        if scanner.accept_str(r#""""#).is_ok() {
+                            break;
+                        }
+                    }
+                    Ok((_r, '\\')) => {
+                        // Skip the next character as it is escaped,
+                        // including line terminators, i.e. `\<line-terminator>`
+                        // Note: Technically any character is not valid
+                        _ = scanner.next();
+                    }
+                    Ok(_) => unreachable!(),
+                    Err(_) => break,
+                }
+            }
+
+            Ok(())
+        })
+    }
 }
 
 /// [`str`] extension for checking if a `&str` is e.g. a Java keyword.
@@ -551,33 +611,44 @@ mod tests {
 
     #[test]
     fn test_java_null_literals() {
-        assert_eq!("null".is_java_null_literal(), true);
-        assert_eq!("null".is_java_boolean_literal(), false);
-        assert_eq!("null".is_java_keyword(), false);
+        assert!("null".is_java_null_literal());
+        assert!(!"null".is_java_boolean_literal());
+        assert!(!"null".is_java_keyword());
 
-        assert_eq!("NULL".is_java_null_literal(), false);
-        assert_eq!("NULL".is_java_boolean_literal(), false);
-        assert_eq!("NULL".is_java_keyword(), false);
+        assert!(!"NULL".is_java_null_literal());
+        assert!(!"NULL".is_java_boolean_literal());
+        assert!(!"NULL".is_java_keyword());
 
-        assert_eq!("Null".is_java_null_literal(), false);
-        assert_eq!("Null".is_java_boolean_literal(), false);
-        assert_eq!("Null".is_java_keyword(), false);
+        assert!(!"Null".is_java_null_literal());
+        assert!(!"Null".is_java_boolean_literal());
+        assert!(!"Null".is_java_keyword());
     }
 
     #[test]
     fn test_java_boolean_literals() {
-        assert_eq!("true".is_java_boolean_literal(), true);
-        assert_eq!("true".is_java_null_literal(), false);
-        assert_eq!("true".is_java_keyword(), false);
+        assert!("true".is_java_boolean_literal());
+        assert!(!"true".is_java_null_literal());
+        assert!(!"true".is_java_keyword());
 
-        assert_eq!("false".is_java_boolean_literal(), true);
-        assert_eq!("false".is_java_null_literal(), false);
-        assert_eq!("false".is_java_keyword(), false);
+        assert!("false".is_java_boolean_literal());
+        assert!(!"false".is_java_null_literal());
+        assert!(!"false".is_java_keyword());
     }
 
     #[test]
     fn test_java_int_dec_literals() {
-        let cases = ["0", "2", "0l", "0L", "1996", "2_147_483_648L", "2147483648"];
+        let cases = [
+            "0",
+            "2",
+            "0l",
+            "0L",
+            "10",
+            "1_0",
+            "1__0",
+            "1996",
+            "2_147_483_648L",
+            "2147483648",
+        ];
 
         assert_valid_cases!(scan_java_int_dec_literal, cases);
         assert_valid_cases!(scan_java_int_dec_literal, cases, "remaining");
@@ -729,4 +800,39 @@ mod tests {
         assert_valid_cases!(scan_java_string_literal, cases);
         assert_valid_cases!(scan_java_string_literal, cases, "remaining");
     }
+
+    #[test]
+    fn test_java_text_blocks() {
+        let cases = [
+            "\"\"\"\n\"\"\"",
+            "\"\"\"\r\n\"\"\"",
+            "\"\"\" \t\n    Hello World\n    \"\"\"",
+            "\"\"\"\n    \"Hello\" \"\"World\"\"\n    \"\"\"",
+            "\"\"\"\n    \\\"\"\"\n    \"\"\"",
+            "\"\"\"\n    Hello \\\n    World\\s\n    \"\"\"",
+            "\"\"\"\n    Hello \\\"\"\"\"",
+            "\"\"\"\n    Hello \"\"\"",
+        ];
+
+        assert_valid_cases!(scan_java_text_block, cases);
+        assert_valid_cases!(scan_java_text_block, cases, "remaining");
+    }
+
+    #[test]
+    fn test_java_text_blocks_trailing_quote() {
+        // The text block ends at the first unescaped `"""`
+        let mut scanner = Scanner::new("\"\"\"\n    Hello\"\"\"\"");
+        assert_eq!(
+            scanner.scan_java_text_block(),
+            Ok((0..16, "\"\"\"\n    Hello\"\"\""))
+        );
+        assert_eq!(scanner.remaining_text(), "\"");
+    }
+
+    #[test]
+    fn test_java_text_blocks_invalid() {
+        let cases = ["\"\"", "\"\"\"\"\"\"", "\"\"\"Hello\"\"\"", "\"Hello\""];
+
+        assert_invalid_cases!(scan_java_text_block, cases);
+    }
 }
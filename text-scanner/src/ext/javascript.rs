@@ -0,0 +1,924 @@
+use crate::{
+    ext::{CScannerExt, NumberInfo},
+    ByteSet, ScanOutcome, Scanner, ScannerResult, SeparatorPolicy,
+};
+
+// Reference: https://tc39.es/ecma262/#sec-literals-numeric-literals
+const JAVASCRIPT_DIGIT_SEPARATORS: SeparatorPolicy = SeparatorPolicy::STRICT;
+
+const JAVASCRIPT_IDENT_CONTINUE: ByteSet = ByteSet::IDENT_CONTINUE.union(ByteSet::from_bytes(b"$"));
+
+// Reference: https://tc39.es/ecma262/#sec-keywords-and-reserved-words
+pub const JAVASCRIPT_KEYWORDS: &[&str] = &[
+    "await",
+    "break",
+    "case",
+    "catch",
+    "class",
+    "const",
+    "continue",
+    "debugger",
+    "default",
+    "delete",
+    "do",
+    "else",
+    "enum",
+    "export",
+    "extends",
+    "false",
+    "finally",
+    "for",
+    "function",
+    "if",
+    "import",
+    "in",
+    "instanceof",
+    "new",
+    "null",
+    "return",
+    "super",
+    "switch",
+    "this",
+    "throw",
+    "true",
+    "try",
+    "typeof",
+    "var",
+    "void",
+    "while",
+    "with",
+    "yield",
+];
+
+// Identifiers which are only keywords in some contexts, e.g. `of` in `for (x of xs)`.
+//
+// Reference: https://tc39.es/ecma262/#sec-keywords-and-reserved-words
+pub const JAVASCRIPT_CONTEXTUAL_KEYWORDS: &[&str] =
+    &["as", "async", "from", "get", "let", "of", "set", "static"];
+
+// Keywords after which an expression is expected, i.e. where `/` starts
+// a regular expression, see `JavaScriptScannerExt::scan_javascript_regex()`.
+//
+// Reference: https://tc39.es/ecma262/#sec-ecmascript-language-lexical-grammar
+pub const JAVASCRIPT_KEYWORDS_BEFORE_EXPR: &[&str] = &[
+    "await",
+    "case",
+    "default",
+    "delete",
+    "do",
+    "else",
+    "extends",
+    "in",
+    "instanceof",
+    "new",
+    "of",
+    "return",
+    "throw",
+    "typeof",
+    "void",
+    "yield",
+];
+
+// Keywords in addition to JavaScript keywords.
+//
+// Reference: https://github.com/microsoft/TypeScript/blob/main/src/compiler/scanner.ts
+pub const TYPESCRIPT_KEYWORDS: &[&str] = &[
+    "abstract",
+    "accessor",
+    "any",
+    "asserts",
+    "bigint",
+    "boolean",
+    "declare",
+    "implements",
+    "infer",
+    "interface",
+    "is",
+    "keyof",
+    "module",
+    "namespace",
+    "never",
+    "number",
+    "object",
+    "override",
+    "private",
+    "protected",
+    "public",
+    "readonly",
+    "satisfies",
+    "string",
+    "symbol",
+    "type",
+    "unique",
+    "unknown",
+];
+
+// Ordered such that longer operators are matched first.
+//
+// Reference: https://tc39.es/ecma262/#sec-punctuators
+pub const JAVASCRIPT_OPERATORS: &[&str] = &[
+    ">>>=", "...", "===", "!==", "**=", "<<=", ">>=", ">>>", "&&=", "||=", "??=", "=>", "==", "!=",
+    "<=", ">=", "&&", "||", "??", "?.", "++", "--", "+=", "-=", "*=", "/=", "%=", "&=", "|=", "^=",
+    "**", "<<", ">>", "+", "-", "*", "/", "%", "&", "|", "^", "!", "~", "?", ":", "=", "<", ">",
+    ".", ",", ";", "@", "#",
+];
+
+pub const JAVASCRIPT_DELIMITERS: &[&str] = &["(", ")", "[", "]", "{", "}"];
+
+/// [`Scanner`] extension for scanning JavaScript and TypeScript tokens,
+/// including [JSX].
+///
+/// [JSX]: https://facebook.github.io/jsx/
+pub trait JavaScriptScannerExt<'text>: crate::private::Sealed {
+    fn scan_javascript_line_comment(&mut self) -> ScannerResult<'text, &'text str>;
+    fn scan_javascript_block_comment(&mut self) -> ScannerResult<'text, &'text str>;
+    /// Same as [`scan_javascript_block_comment()`], but additionally
+    /// returns whether the block comment is unterminated.
+    ///
+    /// [`scan_javascript_block_comment()`]: Self::scan_javascript_block_comment
+    fn scan_javascript_block_comment_outcome(&mut self) -> ScannerResult<'text, ScanOutcome>;
+
+    /// Scans a single [JavaScript identifier], including identifiers
+    /// containing Unicode escape sequences, e.g. `\u0061bc` and `a\u{62}c`.
+    ///
+    /// **Note:** The escaped code points are not validated,
+    /// e.g. `\u0020` is accepted.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use text_scanner::{ext::JavaScriptScannerExt, Scanner};
+    ///
+    /// let mut scanner = Scanner::new(r"$foo \u{1d4d0}bc a\u0062c-d");
+    ///
+    /// assert_eq!(scanner.scan_javascript_identifier(), Ok((0..4, "$foo")));
+    /// scanner.skip_whitespace();
+    /// assert_eq!(scanner.scan_javascript_identifier(), Ok((5..16, r"\u{1d4d0}bc")));
+    /// scanner.skip_whitespace();
+    /// assert_eq!(scanner.scan_javascript_identifier(), Ok((17..25, r"a\u0062c")));
+    /// assert_eq!(scanner.remaining_text(), "-d");
+    /// ```
+    ///
+    /// [JavaScript identifier]: https://tc39.es/ecma262/#prod-IdentifierName
+    fn scan_javascript_identifier(&mut self) -> ScannerResult<'text, &'text str>;
+
+    /// Scans a single [decorator] name, i.e. `@` followed by an identifier
+    /// and any property accesses, e.g. `@Component` and `@foo.bar`.
+    ///
+    /// Any arguments, e.g. `(x)` in `@foo(x)`, are not included.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use text_scanner::{ext::JavaScriptScannerExt, Scanner};
+    ///
+    /// let mut scanner = Scanner::new("@Component @foo.bar(x) @ x");
+    ///
+    /// assert_eq!(scanner.scan_javascript_decorator(), Ok((0..10, "@Component")));
+    /// scanner.skip_whitespace();
+    /// assert_eq!(scanner.scan_javascript_decorator(), Ok((11..19, "@foo.bar")));
+    /// assert_eq!(scanner.remaining_text(), "(x) @ x");
+    ///
+    /// scanner.skip_until_char('@');
+    /// assert_eq!(scanner.scan_javascript_decorator(), Err((23..24, "@")));
+    /// ```
+    ///
+    /// [decorator]: https://www.typescriptlang.org/docs/handbook/decorators.html
+    fn scan_javascript_decorator(&mut self) -> ScannerResult<'text, &'text str>;
+
+    fn scan_javascript_operator(&mut self) -> ScannerResult<'text, &'text str>;
+    fn scan_javascript_delimiter(&mut self) -> ScannerResult<'text, &'text str>;
+
+    /// Scans a single [JavaScript numeric literal], e.g. `123`, `1_000`,
+    /// `.5`, `1e-3`, `0xFF`, `0o17`, `0b1010`, and `123n`.
+    ///
+    /// **Note:** This has the same lifetime as the original `text`,
+    /// so the scanner can continue to be used while this exists.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use text_scanner::{ext::JavaScriptScannerExt, Scanner};
+    ///
+    /// let text = r#"
+    ///   123
+    ///   1_000.5
+    ///   .5e-3
+    ///   0xFF
+    ///   123n
+    /// "#;
+    ///
+    /// let numbers = [
+    ///     (3..6,   "123"),
+    ///     (9..16,  "1_000.5"),
+    ///     (19..24, ".5e-3"),
+    ///     (27..31, "0xFF"),
+    ///     (34..38, "123n"),
+    /// ];
+    ///
+    /// let mut scanner = Scanner::new(text);
+    /// for num in numbers {
+    ///     scanner.skip_whitespace();
+    ///     assert_eq!(scanner.scan_javascript_number(), Ok(num));
+    /// }
+    ///
+    /// # scanner.skip_whitespace();
+    /// # assert_eq!(scanner.remaining_text(), "");
+    /// ```
+    ///
+    /// [JavaScript numeric literal]: https://tc39.es/ecma262/#sec-literals-numeric-literals
+    fn scan_javascript_number(&mut self) -> ScannerResult<'text, &'text str>;
+
+    /// Scans a single [JavaScript numeric literal], and returns its
+    /// [`NumberInfo`], where the suffix is the `n` of BigInt literals.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use text_scanner::{ext::JavaScriptScannerExt, Scanner};
+    ///
+    /// let text = "0xFFn 1_000n 1.5e3 .5 0b1";
+    ///
+    /// let numbers = [
+    ///     // (range, radix, digits_range, suffix_range, is_float)
+    ///     (0..5,   16, 2..4,   Some(4..5),   false),
+    ///     (6..12,  10, 6..11,  Some(11..12), false),
+    ///     (13..18, 10, 13..18, None,         true),
+    ///     (19..21, 10, 19..21, None,         true),
+    ///     (22..25, 2,  24..25, None,         false),
+    /// ];
+    ///
+    /// let mut scanner = Scanner::new(text);
+    /// for (range, radix, digits_range, suffix_range, is_float) in numbers {
+    ///     scanner.skip_whitespace();
+    ///     let (r, info) = scanner.scan_javascript_number_info().unwrap();
+    ///     assert_eq!(r, range);
+    ///     assert_eq!(info.radix, radix);
+    ///     assert_eq!(info.digits_range, digits_range);
+    ///     assert_eq!(info.suffix_range, suffix_range);
+    ///     assert_eq!(info.is_float, is_float);
+    /// }
+    ///
+    /// # assert_eq!(scanner.remaining_text(), "");
+    /// ```
+    ///
+    /// [JavaScript numeric literal]: https://tc39.es/ecma262/#sec-literals-numeric-literals
+    fn scan_javascript_number_info(&mut self) -> ScannerResult<'text, NumberInfo>;
+
+    /// Scans a single single-quoted or double-quoted [JavaScript string].
+    ///
+    /// **Note:** Unterminated strings end at the end of the line.
+    fn scan_javascript_string(&mut self) -> ScannerResult<'text, &'text str>;
+    /// Same as [`scan_javascript_string()`], but additionally returns
+    /// whether the string is unterminated.
+    ///
+    /// [`scan_javascript_string()`]: Self::scan_javascript_string
+    fn scan_javascript_string_outcome(&mut self) -> ScannerResult<'text, ScanOutcome>;
+
+    /// Scans a single [JavaScript template literal], i.e. `` `...` ``,
+    /// including any nested substitutions, i.e. `${...}`.
+    ///
+    /// This also scans TypeScript [template literal types], e.g.
+    /// `` `on${Capitalize<K>}` ``, which are lexically the same.
+    ///
+    /// **Note:** This has the same lifetime as the original `text`,
+    /// so the scanner can continue to be used while this exists.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use text_scanner::{ext::JavaScriptScannerExt, Scanner};
+    ///
+    /// let text = r#"
+    ///   `Hello ${name}`
+    ///   `${a ? `${b}` : { c }}`
+    ///   `Multi
+    ///   Line`
+    /// "#;
+    ///
+    /// let templates = [
+    ///     (3..18,  "`Hello ${name}`"),
+    ///     (21..44, "`${a ? `${b}` : { c }}`"),
+    ///     (47..61, "`Multi\n  Line`"),
+    /// ];
+    ///
+    /// let mut scanner = Scanner::new(text);
+    /// for template in templates {
+    ///     scanner.skip_whitespace();
+    ///     assert_eq!(scanner.scan_javascript_template_string(), Ok(template));
+    /// }
+    ///
+    /// # scanner.skip_whitespace();
+    /// # assert_eq!(scanner.remaining_text(), "");
+    /// ```
+    ///
+    /// [JavaScript template literal]: https://tc39.es/ecma262/#sec-template-literal-lexical-components
+    /// [template literal types]: https://www.typescriptlang.org/docs/handbook/2/template-literal-types.html
+    fn scan_javascript_template_string(&mut self) -> ScannerResult<'text, &'text str>;
+    /// Same as [`scan_javascript_template_string()`], but additionally
+    /// returns whether the template literal is unterminated.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use text_scanner::{ext::JavaScriptScannerExt, ScanOutcome, Scanner};
+    ///
+    /// let mut scanner = Scanner::new("`${a}` `${`b`");
+    /// assert_eq!(
+    ///     scanner.scan_javascript_template_string_outcome(),
+    ///     Ok((0..6, ScanOutcome::Terminated)),
+    /// );
+    ///
+    /// scanner.skip_whitespace();
+    /// assert_eq!(
+    ///     scanner.scan_javascript_template_string_outcome(),
+    ///     Ok((7..13, ScanOutcome::Unterminated(7..8))),
+    /// );
+    /// ```
+    ///
+    /// [`scan_javascript_template_string()`]: Self::scan_javascript_template_string
+    fn scan_javascript_template_string_outcome(&mut self) -> ScannerResult<'text, ScanOutcome>;
+
+    /// Scans a single [JavaScript regular expression literal], e.g. `/[a-z]+/gi`.
+    ///
+    /// **Note:** Whether `/` starts a regular expression or is a division,
+    /// depends on the previous token. So this should only be called where
+    /// an expression is expected. Given the previous token, excluding
+    /// whitespace and comments, a `/` starts a regular expression:
+    ///
+    /// - At the start of the text
+    /// - After a punctuator, e.g. `(`, `,`, `=`, and `=>`,
+    ///   except `)`, `]`, `}`, `++`, and `--`
+    /// - After a keyword expecting an expression,
+    ///   see [`JAVASCRIPT_KEYWORDS_BEFORE_EXPR`]
+    ///
+    /// Otherwise, e.g. after identifiers, literals, and keywords like `this`,
+    /// a `/` is a division. See [`JavaScriptStrExt::is_javascript_regex_allowed_after()`].
+    ///
+    /// The rules are an approximation of the grammar, which for example
+    /// considers `/` a division after `)` in `if (x) /a/.test(y)`, and
+    /// after the `}` of a block statement. Additionally, keywords used as
+    /// property names, e.g. `x.return / 2`, are not keywords.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use text_scanner::{ext::{JavaScriptScannerExt, JavaScriptStrExt}, Scanner};
+    ///
+    /// let mut scanner = Scanner::new("x = a / b / /[/]+/gi.test(c)");
+    ///
+    /// let mut regexes = Vec::new();
+    /// let mut prev = None;
+    /// while scanner.has_remaining_text() {
+    ///     scanner.skip_whitespace();
+    ///
+    ///     let regex_allowed = prev.map_or(true, |prev: &str| prev.is_javascript_regex_allowed_after());
+    ///     if regex_allowed {
+    ///         if let Ok((_r, regex)) = scanner.scan_javascript_regex() {
+    ///             regexes.push(regex);
+    ///             prev = Some(regex);
+    ///             continue;
+    ///         }
+    ///     }
+    ///
+    ///     let (_r, tok) = scanner
+    ///         .scan_javascript_identifier()
+    ///         .or_else(|_| scanner.scan_javascript_operator())
+    ///         .or_else(|_| scanner.scan_javascript_delimiter())
+    ///         .unwrap();
+    ///     prev = Some(tok);
+    /// }
+    ///
+    /// assert_eq!(regexes, ["/[/]+/gi"]);
+    /// ```
+    ///
+    /// [JavaScript regular expression literal]: https://tc39.es/ecma262/#sec-literals-regular-expression-literals
+    fn scan_javascript_regex(&mut self) -> ScannerResult<'text, &'text str>;
+
+    /// Scans a single [JSX] element name or attribute name,
+    /// e.g. `div`, `Foo.Bar`, `svg:rect`, and `aria-label`.
+    ///
+    /// [JSX]: https://facebook.github.io/jsx/#prod-JSXElementName
+    fn scan_jsx_identifier(&mut self) -> ScannerResult<'text, &'text str>;
+
+    /// Scans [JSX] text, i.e. until the next `{` or `<`.
+    ///
+    /// [JSX]: https://facebook.github.io/jsx/#prod-JSXText
+    fn scan_jsx_text(&mut self) -> ScannerResult<'text, &'text str>;
+
+    /// Scans a single [JSX] attribute string, which as opposed to JavaScript
+    /// strings, can span multiple lines and does not have escape sequences.
+    ///
+    /// [JSX]: https://facebook.github.io/jsx/#prod-JSXDoubleStringCharacters
+    fn scan_jsx_string(&mut self) -> ScannerResult<'text, &'text str>;
+}
+
+impl<'text> JavaScriptScannerExt<'text> for Scanner<'text> {
+    // Reference: https://tc39.es/ecma262/#sec-comments
+    #[inline]
+    fn scan_javascript_line_comment(&mut self) -> ScannerResult<'text, &'text str> {
+        self.scan_c_line_comment()
+    }
+
+    // Reference: https://tc39.es/ecma262/#sec-comments
+    #[inline]
+    fn scan_javascript_block_comment(&mut self) -> ScannerResult<'text, &'text str> {
+        self.scan_c_block_comment()
+    }
+
+    #[inline]
+    fn scan_javascript_block_comment_outcome(&mut self) -> ScannerResult<'text, ScanOutcome> {
+        self.scan_c_block_comment_outcome()
+    }
+
+    // Reference: https://tc39.es/ecma262/#sec-names-and-keywords
+    fn scan_javascript_identifier(&mut self) -> ScannerResult<'text, &'text str> {
+        self.scan_with(|scanner| {
+            if scanner
+                .accept_if(|c| c.is_alphabetic() || matches!(c, '_' | '$'))
+                .is_err()
+            {
+                scan_unicode_escape(scanner)?;
+            }
+
+            loop {
+                scanner.skip_while_in(&JAVASCRIPT_IDENT_CONTINUE);
+                if scan_unicode_escape(scanner).is_err() {
+                    break;
+                }
+            }
+
+            Ok(())
+        })
+    }
+
+    // Reference: https://github.com/tc39/proposal-decorators#syntax
+    fn scan_javascript_decorator(&mut self) -> ScannerResult<'text, &'text str> {
+        self.scan_with(|scanner| {
+            scanner.accept_char('@')?;
+            scanner.scan_javascript_identifier()?;
+
+            while scanner
+                .scan_with(|scanner| {
+                    scanner.accept_char('.')?;
+                    scanner.scan_javascript_identifier()?;
+                    Ok(())
+                })
+                .is_ok()
+            {}
+
+            Ok(())
+        })
+    }
+
+    // Reference: https://tc39.es/ecma262/#sec-punctuators
+    #[inline]
+    fn scan_javascript_operator(&mut self) -> ScannerResult<'text, &'text str> {
+        self.accept_str_any(JAVASCRIPT_OPERATORS)
+    }
+
+    // Reference: https://tc39.es/ecma262/#sec-punctuators
+    #[inline]
+    fn scan_javascript_delimiter(&mut self) -> ScannerResult<'text, &'text str> {
+        self.accept_str_any(JAVASCRIPT_DELIMITERS)
+    }
+
+    // Reference: https://tc39.es/ecma262/#sec-literals-numeric-literals
+    fn scan_javascript_number(&mut self) -> ScannerResult<'text, &'text str> {
+        self.scan_with(|scanner| {
+            let scan_digits = |scanner: &mut Scanner<'text>, radix| {
+                scanner.scan_digits_sep(radix, '_', JAVASCRIPT_DIGIT_SEPARATORS)
+            };
+
+            if scanner.accept_str_any(&["0x", "0X"]).is_ok() {
+                scan_digits(scanner, 16)?;
+            } else if scanner.accept_str_any(&["0o", "0O"]).is_ok() {
+                scan_digits(scanner, 8)?;
+            } else if scanner.accept_str_any(&["0b", "0B"]).is_ok() {
+                scan_digits(scanner, 2)?;
+            } else {
+                if scanner.accept_char('.').is_ok() {
+                    scan_digits(scanner, 10)?;
+                } else {
+                    scan_digits(scanner, 10)?;
+
+                    // Only a fraction if followed by a digit, such that
+                    // e.g. `1.toString()` is not a number
+                    let is_fraction = scanner
+                        .peeking(|scanner| {
+                            scanner.accept_char('.')?;
+                            scanner.accept_if(|c| c.is_ascii_digit())
+                        })
+                        .is_ok();
+                    if is_fraction {
+                        _ = scanner.accept_char('.');
+                        scan_digits(scanner, 10)?;
+                    } else if scanner.accept_char('n').is_ok() {
+                        return Ok(());
+                    }
+                }
+
+                let is_exponent = scanner
+                    .peeking(|scanner| {
+                        scanner.accept_char_any(&['e', 'E'])?;
+                        _ = scanner.accept_char_any(&['+', '-']);
+                        scanner.accept_if(|c| c.is_ascii_digit())
+                    })
+                    .is_ok();
+                if is_exponent {
+                    _ = scanner.accept_char_any(&['e', 'E']);
+                    _ = scanner.accept_char_any(&['+', '-']);
+                    scan_digits(scanner, 10)?;
+                }
+
+                return Ok(());
+            }
+
+            _ = scanner.accept_char('n');
+            Ok(())
+        })
+    }
+
+    // Reference: https://tc39.es/ecma262/#sec-literals-numeric-literals
+    fn scan_javascript_number_info(&mut self) -> ScannerResult<'text, NumberInfo> {
+        let (r, number) = self.scan_javascript_number()?;
+
+        let (literal, suffix_range) = match number.strip_suffix('n') {
+            Some(literal) => (literal, (r.end - 1)..r.end),
+            None => (number, r.end..r.end),
+        };
+
+        const PREFIXES: &[(&str, u32)] = &[("0x", 16), ("0o", 8), ("0b", 2)];
+        let mut info = NumberInfo::new(r.start, literal, suffix_range, PREFIXES, false);
+        info.is_float = (info.radix == 10) && literal.contains(['.', 'e', 'E']);
+
+        Ok((r, info))
+    }
+
+    // Reference: https://tc39.es/ecma262/#sec-literals-string-literals
+    #[inline]
+    fn scan_javascript_string(&mut self) -> ScannerResult<'text, &'text str> {
+        let (r, _outcome) = self.scan_javascript_string_outcome()?;
+        Ok(self.ranged_text(r))
+    }
+
+    // Reference: https://tc39.es/ecma262/#sec-literals-string-literals
+    fn scan_javascript_string_outcome(&mut self) -> ScannerResult<'text, ScanOutcome> {
+        let mut outcome = ScanOutcome::Terminated;
+        let (r, _s) = self.scan_with(|scanner| {
+            let (open_range, quote) = scanner.accept_char_any(&['"', '\''])?;
+
+            loop {
+                scanner.skip_until(|c| (c == quote) || (c == '\\') || (c == '\n'));
+                match scanner.peek() {
+                    Ok((_r, '\\')) => {
+                        _ = scanner.next();
+                        // Skip the next character as it is escaped
+                        _ = scanner.next();
+                    }
+                    Ok((_r, c)) if c == quote => {
+                        _ = scanner.next();
+                        break;
+                    }
+                    _ => {
+                        outcome = ScanOutcome::Unterminated(open_range);
+                        break;
+                    }
+                }
+            }
+
+            Ok(())
+        })?;
+        Ok((r, outcome))
+    }
+
+    // Reference: https://tc39.es/ecma262/#sec-template-literal-lexical-components
+    #[inline]
+    fn scan_javascript_template_string(&mut self) -> ScannerResult<'text, &'text str> {
+        let (r, _outcome) = self.scan_javascript_template_string_outcome()?;
+        Ok(self.ranged_text(r))
+    }
+
+    // Reference: https://tc39.es/ecma262/#sec-template-literal-lexical-components
+    fn scan_javascript_template_string_outcome(&mut self) -> ScannerResult<'text, ScanOutcome> {
+        let mut outcome = ScanOutcome::Terminated;
+        let (r, _s) = self.scan_with(|scanner| {
+            let (open_range, _c) = scanner.accept_char('`')?;
+
+            loop {
+                scanner.skip_until(|c| matches!(c, '`' | '\\' | '$'));
+                match scanner.next() {
+                    Ok((_r, '`')) => break,
+                    Ok((_r, '\\')) => {
+                        // Skip the next character as it is escaped
+                        _ = scanner.next();
+                    }
+                    Ok((_r, '$')) => {
+                        if scanner.accept_char('{').is_ok() {
+                            skip_substitution(scanner);
+                        }
+                    }
+                    Ok(_) => unreachable!(),
+                    Err(_) => {
+                        outcome = ScanOutcome::Unterminated(open_range);
+                        break;
+                    }
+                }
+            }
+
+            Ok(())
+        })?;
+        Ok((r, outcome))
+    }
+
+    // Reference: https://tc39.es/ecma262/#sec-literals-regular-expression-literals
+    fn scan_javascript_regex(&mut self) -> ScannerResult<'text, &'text str> {
+        self.scan_with(|scanner| {
+            scanner.accept_char('/')?;
+
+            // `//` and `/*` are comments
+            if scanner.peek().is_ok_and(|(_r, c)| matches!(c, '/' | '*')) {
+                return Err(scanner.ranged_text(scanner.cursor_pos()..scanner.cursor_pos()));
+            }
+
+            let mut in_class = false;
+            loop {
+                let (r, c) = scanner.next()?;
+                match c {
+                    '\\' => {
+                        _ = scanner.next();
+                    }
+                    '[' => in_class = true,
+                    ']' => in_class = false,
+                    '/' if !in_class => break,
+                    '\n' | '\r' => return Err(scanner.ranged_text(r)),
+                    _ => {}
+                }
+            }
+
+            scanner.skip_while(|c| c.is_ascii_alphabetic());
+            Ok(())
+        })
+    }
+
+    // Reference: https://facebook.github.io/jsx/#prod-JSXElementName
+    fn scan_jsx_identifier(&mut self) -> ScannerResult<'text, &'text str> {
+        self.scan_with(|scanner| {
+            scanner.accept_if(|c| c.is_alphabetic() || matches!(c, '_' | '$'))?;
+            scanner.skip_while(|c| c.is_alphanumeric() || matches!(c, '_' | '$' | '-' | '.' | ':'));
+            Ok(())
+        })
+    }
+
+    // Reference: https://facebook.github.io/jsx/#prod-JSXText
+    fn scan_jsx_text(&mut self) -> ScannerResult<'text, &'text str> {
+        self.scan_with(|scanner| {
+            scanner.accept_if(|c| !matches!(c, '{' | '<'))?;
+            scanner.skip_until_char_any(&['{', '<']);
+            Ok(())
+        })
+    }
+
+    // Reference: https://facebook.github.io/jsx/#prod-JSXDoubleStringCharacters
+    fn scan_jsx_string(&mut self) -> ScannerResult<'text, &'text str> {
+        self.scan_with(|scanner| {
+            let (_r, quote) = scanner.accept_char_any(&['"', '\''])?;
+            scanner.skip_until_char(quote);
+            _ = scanner.accept_char(quote);
+            Ok(())
+        })
+    }
+}
+
+/// Scans a Unicode escape sequence in an identifier, i.e. `\u0061` or `\u{61}`.
+// Reference: https://tc39.es/ecma262/#prod-UnicodeEscapeSequence
+fn scan_unicode_escape<'text>(scanner: &mut Scanner<'text>) -> ScannerResult<'text, &'text str> {
+    scanner.scan_with(|scanner| {
+        scanner.accept_str("\\u")?;
+
+        if scanner.accept_char('{').is_ok() {
+            scanner.accept_if(|c| c.is_ascii_hexdigit())?;
+            scanner.skip_while(|c| c.is_ascii_hexdigit());
+            scanner.accept_char('}')?;
+        } else {
+            for _ in 0..4 {
+                scanner.accept_if(|c| c.is_ascii_hexdigit())?;
+            }
+        }
+
+        Ok(())
+    })
+}
+
+/// Skips the remaining substitution of a template literal,
+/// i.e. after `${`, including the closing `}`.
+fn skip_substitution(scanner: &mut Scanner<'_>) {
+    let mut depth = 0usize;
+    loop {
+        scanner.skip_until(|c| matches!(c, '{' | '}' | '`' | '"' | '\''));
+
+        if scanner.scan_javascript_template_string().is_ok()
+            || scanner.scan_javascript_string().is_ok()
+        {
+            continue;
+        }
+
+        match scanner.next() {
+            Ok((_r, '{')) => depth += 1,
+            Ok((_r, '}')) if depth == 0 => break,
+            Ok((_r, '}')) => depth -= 1,
+            Ok(_) => unreachable!(),
+            Err(_) => break,
+        }
+    }
+}
+
+pub trait JavaScriptStrExt {
+    fn is_javascript_keyword(&self) -> bool;
+    fn is_javascript_contextual_keyword(&self) -> bool;
+    fn is_typescript_keyword(&self) -> bool;
+
+    /// Returns `true` if `self` is a keyword after which an expression
+    /// is expected, see [`JAVASCRIPT_KEYWORDS_BEFORE_EXPR`].
+    fn is_javascript_keyword_before_expr(&self) -> bool;
+
+    /// Returns `true` if a `/` after the token `self` starts a regular
+    /// expression, as opposed to being a division, see the rules in
+    /// [`JavaScriptScannerExt::scan_javascript_regex()`].
+    ///
+    /// `self` must be the previous token excluding whitespace and comments.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use text_scanner::ext::JavaScriptStrExt;
+    ///
+    /// for prev in ["(", ",", "=", "=>", "!", "&&", "return", "typeof"] {
+    ///     assert!(prev.is_javascript_regex_allowed_after(), "{prev}");
+    /// }
+    ///
+    /// for prev in [")", "]", "}", "++", "x", "1", "'a'", "this"] {
+    ///     assert!(!prev.is_javascript_regex_allowed_after(), "{prev}");
+    /// }
+    /// ```
+    fn is_javascript_regex_allowed_after(&self) -> bool;
+}
+
+impl JavaScriptStrExt for str {
+    #[inline]
+    fn is_javascript_keyword(&self) -> bool {
+        JAVASCRIPT_KEYWORDS.contains(&self)
+    }
+
+    #[inline]
+    fn is_javascript_contextual_keyword(&self) -> bool {
+        JAVASCRIPT_CONTEXTUAL_KEYWORDS.contains(&self)
+    }
+
+    #[inline]
+    fn is_typescript_keyword(&self) -> bool {
+        TYPESCRIPT_KEYWORDS.contains(&self)
+    }
+
+    #[inline]
+    fn is_javascript_keyword_before_expr(&self) -> bool {
+        JAVASCRIPT_KEYWORDS_BEFORE_EXPR.contains(&self)
+    }
+
+    fn is_javascript_regex_allowed_after(&self) -> bool {
+        match self {
+            ")" | "]" | "}" | "++" | "--" => false,
+            _ => {
+                self.is_javascript_keyword_before_expr()
+                    || JAVASCRIPT_OPERATORS.contains(&self)
+                    || JAVASCRIPT_DELIMITERS.contains(&self)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_javascript_number() {
+        let cases = [
+            ("0", Ok((0..1, "0")), ""),
+            ("1_000", Ok((0..5, "1_000")), ""),
+            ("1.5", Ok((0..3, "1.5")), ""),
+            ("1.toString()", Ok((0..1, "1")), ".toString()"),
+            ("1.5em", Ok((0..3, "1.5")), "em"),
+            ("1e10", Ok((0..4, "1e10")), ""),
+            ("1E+10", Ok((0..5, "1E+10")), ""),
+            ("1e", Ok((0..1, "1")), "e"),
+            (".5", Ok((0..2, ".5")), ""),
+            ("0xFF_FF", Ok((0..7, "0xFF_FF")), ""),
+            ("1_000_", Ok((0..5, "1_000")), "_"),
+            ("1__000", Err((0..2, "1_")), "1__000"),
+            ("0x_FF", Err((0..2, "0x")), "0x_FF"),
+            ("1.5_5e1_0", Ok((0..9, "1.5_5e1_0")), ""),
+            ("0o17", Ok((0..4, "0o17")), ""),
+            ("0b1010n", Ok((0..7, "0b1010n")), ""),
+            ("123n", Ok((0..4, "123n")), ""),
+            //
+            ("", Err((0..0, "")), ""),
+            (".", Err((0..1, ".")), "."),
+            ("0x", Err((0..2, "0x")), "0x"),
+            ("x", Err((0..0, "")), "x"),
+        ];
+
+        for (text, expected, remaining) in cases {
+            let mut scanner = Scanner::new(text);
+            assert_eq!(scanner.scan_javascript_number(), expected, "{text:?}");
+            assert_eq!(scanner.remaining_text(), remaining, "{text:?}");
+        }
+    }
+
+    #[test]
+    fn test_javascript_identifier() {
+        let cases = [
+            ("foo", Ok((0..3, "foo")), ""),
+            ("_$a1", Ok((0..4, "_$a1")), ""),
+            ("æøå.x", Ok((0..6, "æøå")), ".x"),
+            (r"\u0061", Ok((0..6, r"\u0061")), ""),
+            (r"\u{61}b\u{1F600}", Ok((0..16, r"\u{61}b\u{1F600}")), ""),
+            (r"a\u00620", Ok((0..8, r"a\u00620")), ""),
+            (r"a\u006", Ok((0..1, "a")), r"\u006"),
+            (r"a\u{}", Ok((0..1, "a")), r"\u{}"),
+            (r"a\x61", Ok((0..1, "a")), r"\x61"),
+            //
+            ("", Err((0..0, "")), ""),
+            ("1a", Err((0..0, "")), "1a"),
+            (r"\u{61", Err((0..5, r"\u{61")), r"\u{61"),
+            (r"\x61", Err((0..1, r"\")), r"\x61"),
+        ];
+
+        for (text, expected, remaining) in cases {
+            let mut scanner = Scanner::new(text);
+            assert_eq!(scanner.scan_javascript_identifier(), expected, "{text:?}");
+            assert_eq!(scanner.remaining_text(), remaining, "{text:?}");
+        }
+    }
+
+    #[test]
+    fn test_javascript_decorator() {
+        let cases = [
+            ("@a", Ok((0..2, "@a")), ""),
+            ("@a.b.c()", Ok((0..6, "@a.b.c")), "()"),
+            ("@a.", Ok((0..2, "@a")), "."),
+            ("@a .b", Ok((0..2, "@a")), " .b"),
+            ("@\\u0061", Ok((0..7, "@\\u0061")), ""),
+            //
+            ("@", Err((0..1, "@")), "@"),
+            ("@1", Err((0..1, "@")), "@1"),
+            ("a", Err((0..0, "")), "a"),
+        ];
+
+        for (text, expected, remaining) in cases {
+            let mut scanner = Scanner::new(text);
+            assert_eq!(scanner.scan_javascript_decorator(), expected, "{text:?}");
+            assert_eq!(scanner.remaining_text(), remaining, "{text:?}");
+        }
+    }
+
+    #[test]
+    fn test_javascript_string() {
+        let cases = [
+            ("''", Ok((0..2, "''")), ""),
+            ("'a\\'b'", Ok((0..6, "'a\\'b'")), ""),
+            ("\"a\" + b", Ok((0..3, "\"a\"")), " + b"),
+            ("'a\nb'", Ok((0..2, "'a")), "\nb'"),
+            //
+            ("", Err((0..0, "")), ""),
+            ("`a`", Err((0..0, "")), "`a`"),
+        ];
+
+        for (text, expected, remaining) in cases {
+            let mut scanner = Scanner::new(text);
+            assert_eq!(scanner.scan_javascript_string(), expected, "{text:?}");
+            assert_eq!(scanner.remaining_text(), remaining, "{text:?}");
+        }
+    }
+
+    #[test]
+    fn test_javascript_regex() {
+        let cases = [
+            ("/a/", Ok((0..3, "/a/")), ""),
+            ("/[/]+/gi.test(x)", Ok((0..8, "/[/]+/gi")), ".test(x)"),
+            ("/a\\/b/", Ok((0..6, "/a\\/b/")), ""),
+            //
+            ("", Err((0..0, "")), ""),
+            ("// a", Err((0..1, "/")), "// a"),
+            ("/* a */", Err((0..1, "/")), "/* a */"),
+            ("/a\n/", Err((0..3, "/a\n")), "/a\n/"),
+            ("/a", Err((0..2, "/a")), "/a"),
+        ];
+
+        for (text, expected, remaining) in cases {
+            let mut scanner = Scanner::new(text);
+            assert_eq!(scanner.scan_javascript_regex(), expected, "{text:?}");
+            assert_eq!(scanner.remaining_text(), remaining, "{text:?}");
+        }
+    }
+}
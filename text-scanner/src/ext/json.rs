@@ -1,16 +1,29 @@
-use crate::{Scanner, ScannerResult};
+use crate::{ByteSet, ScanOutcome, Scanner, ScannerResult};
 
 /// [`Scanner`] extension for scanning JSON tokens.
 pub trait JsonScannerExt<'text>: crate::private::Sealed {
     fn scan_json_string(&mut self) -> ScannerResult<'text, &'text str>;
+    /// Same as [`scan_json_string()`], but additionally returns whether
+    /// the string is unterminated.
+    ///
+    /// [`scan_json_string()`]: Self::scan_json_string
+    fn scan_json_string_outcome(&mut self) -> ScannerResult<'text, ScanOutcome>;
     fn scan_json_number(&mut self) -> ScannerResult<'text, &'text str>;
 }
 
 impl<'text> JsonScannerExt<'text> for Scanner<'text> {
     // Reference: https://www.json.org/json-en.html
+    #[inline]
     fn scan_json_string(&mut self) -> ScannerResult<'text, &'text str> {
-        self.scan_with(|scanner| {
-            let (_r, _c) = scanner.accept_char('"')?;
+        let (r, _outcome) = self.scan_json_string_outcome()?;
+        Ok(self.ranged_text(r))
+    }
+
+    // Reference: https://www.json.org/json-en.html
+    fn scan_json_string_outcome(&mut self) -> ScannerResult<'text, ScanOutcome> {
+        let mut outcome = ScanOutcome::Terminated;
+        let (r, _s) = self.scan_with(|scanner| {
+            let (open_range, _c) = scanner.accept_char('"')?;
 
             loop {
                 scanner.skip_until_char_any(&['"', '\\']);
@@ -22,12 +35,16 @@ impl<'text> JsonScannerExt<'text> for Scanner<'text> {
                         _ = scanner.next();
                     }
                     Ok(_) => unreachable!(),
-                    Err(_) => break,
+                    Err(_) => {
+                        outcome = ScanOutcome::Unterminated(open_range);
+                        break;
+                    }
                 }
             }
 
             Ok(())
-        })
+        })?;
+        Ok((r, outcome))
     }
 
     // Reference: https://www.json.org/json-en.html
@@ -37,15 +54,15 @@ impl<'text> JsonScannerExt<'text> for Scanner<'text> {
             if c == '-' {
                 scanner.accept_if(|c| c.is_ascii_digit())?;
             }
-            scanner.skip_while(|c| c.is_ascii_digit());
+            scanner.skip_while_in(&ByteSet::ASCII_DIGIT);
 
             if scanner.accept_char('.').is_ok() {
-                scanner.skip_while(|c| c.is_ascii_digit());
+                scanner.skip_while_in(&ByteSet::ASCII_DIGIT);
             }
 
             if scanner.accept_char_any(&['E', 'e']).is_ok() {
                 _ = scanner.accept_char_any(&['+', '-']);
-                scanner.skip_while(|c| c.is_ascii_digit());
+                scanner.skip_while_in(&ByteSet::ASCII_DIGIT);
             }
 
             Ok(())
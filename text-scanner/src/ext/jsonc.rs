@@ -1,4 +1,4 @@
-use crate::{ext::CScannerExt, Scanner, ScannerResult};
+use crate::{ext::CScannerExt, ScanOutcome, Scanner, ScannerResult};
 
 /// [`Scanner`] extension for scanning [JSON with Comments] tokens.
 ///
@@ -6,6 +6,11 @@ use crate::{ext::CScannerExt, Scanner, ScannerResult};
 pub trait JsonCScannerExt<'text>: crate::private::Sealed {
     fn scan_jsonc_line_comment(&mut self) -> ScannerResult<'text, &'text str>;
     fn scan_jsonc_block_comment(&mut self) -> ScannerResult<'text, &'text str>;
+    /// Same as [`scan_jsonc_block_comment()`], but additionally returns
+    /// whether the block comment is unterminated.
+    ///
+    /// [`scan_jsonc_block_comment()`]: Self::scan_jsonc_block_comment
+    fn scan_jsonc_block_comment_outcome(&mut self) -> ScannerResult<'text, ScanOutcome>;
 }
 
 impl<'text> JsonCScannerExt<'text> for Scanner<'text> {
@@ -20,4 +25,10 @@ impl<'text> JsonCScannerExt<'text> for Scanner<'text> {
     fn scan_jsonc_block_comment(&mut self) -> ScannerResult<'text, &'text str> {
         self.scan_c_block_comment()
     }
+
+    // Reference: https://code.visualstudio.com/docs/languages/json#_json-with-comments
+    #[inline]
+    fn scan_jsonc_block_comment_outcome(&mut self) -> ScannerResult<'text, ScanOutcome> {
+        self.scan_c_block_comment_outcome()
+    }
 }
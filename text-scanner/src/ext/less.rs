@@ -0,0 +1,166 @@
+use crate::{
+    ext::{CScannerExt, CssScannerExt},
+    Scanner, ScannerResult,
+};
+
+/// [`Scanner`] extension for scanning [Less] tokens.
+///
+/// See also [`CssScannerExt`].
+///
+/// [Less]: https://lesscss.org
+/// [`CssScannerExt`]: super::CssScannerExt
+pub trait LessScannerExt<'text>: crate::private::Sealed {
+    fn scan_less_line_comment(&mut self) -> ScannerResult<'text, &'text str>;
+
+    /// Scans a single [Less variable], i.e. `@name`, `@@name`
+    /// (variable variables), or `@{name}` (interpolation).
+    ///
+    /// **Note:** Plain CSS at-rules, e.g. `@media`, are also
+    /// accepted, as they are syntactically identical.
+    ///
+    /// **Note:** This has the same lifetime as the original `text`,
+    /// so the scanner can continue to be used while this exists.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use text_scanner::{ext::LessScannerExt, Scanner};
+    ///
+    /// let text = r#"
+    ///   @color
+    ///   @@name
+    ///   @{prefix}
+    /// "#;
+    ///
+    /// let variables = [
+    ///     (3..9,   "@color"),
+    ///     (12..18, "@@name"),
+    ///     (21..30, "@{prefix}"),
+    /// ];
+    ///
+    /// let mut scanner = Scanner::new(text);
+    /// for variable in variables {
+    ///     scanner.skip_whitespace();
+    ///     assert_eq!(scanner.scan_less_variable(), Ok(variable));
+    /// }
+    ///
+    /// # scanner.skip_whitespace();
+    /// # assert_eq!(scanner.remaining_text(), "");
+    /// ```
+    ///
+    /// [Less variable]: https://lesscss.org/features/#variables-feature
+    fn scan_less_variable(&mut self) -> ScannerResult<'text, &'text str>;
+
+    /// Scans a single [Less escape], i.e. `~"..."` or `~'...'`.
+    ///
+    /// **Note:** This has the same lifetime as the original `text`,
+    /// so the scanner can continue to be used while this exists.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use text_scanner::{ext::LessScannerExt, Scanner};
+    ///
+    /// let text = r#"
+    ///   ~"calc(100% - 10px)"
+    ///   ~'@{var}'
+    /// "#;
+    ///
+    /// let escapes = [
+    ///     (3..23,  r#"~"calc(100% - 10px)""#),
+    ///     (26..35, "~'@{var}'"),
+    /// ];
+    ///
+    /// let mut scanner = Scanner::new(text);
+    /// for escape in escapes {
+    ///     scanner.skip_whitespace();
+    ///     assert_eq!(scanner.scan_less_escape(), Ok(escape));
+    /// }
+    ///
+    /// # scanner.skip_whitespace();
+    /// # assert_eq!(scanner.remaining_text(), "");
+    /// ```
+    ///
+    /// [Less escape]: https://lesscss.org/features/#escaping-feature
+    fn scan_less_escape(&mut self) -> ScannerResult<'text, &'text str>;
+}
+
+impl<'text> LessScannerExt<'text> for Scanner<'text> {
+    #[inline]
+    fn scan_less_line_comment(&mut self) -> ScannerResult<'text, &'text str> {
+        self.scan_c_line_comment()
+    }
+
+    // Reference: https://lesscss.org/features/#variables-feature
+    fn scan_less_variable(&mut self) -> ScannerResult<'text, &'text str> {
+        self.scan_with(|scanner| {
+            scanner.accept_char('@')?;
+
+            if scanner.accept_char('{').is_ok() {
+                scanner.scan_css_identifier()?;
+                scanner.accept_char('}')?;
+            } else {
+                _ = scanner.accept_char('@');
+                scanner.scan_css_identifier()?;
+            }
+
+            Ok(())
+        })
+    }
+
+    // Reference: https://lesscss.org/features/#escaping-feature
+    fn scan_less_escape(&mut self) -> ScannerResult<'text, &'text str> {
+        self.scan_with(|scanner| {
+            scanner.accept_char('~')?;
+            scanner.scan_css_string()?;
+            Ok(())
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_less_variable() {
+        let cases = [
+            ("@a", Ok((0..2, "@a")), ""),
+            ("@a-b: 1", Ok((0..4, "@a-b")), ": 1"),
+            ("@@a", Ok((0..3, "@@a")), ""),
+            ("@{a}", Ok((0..4, "@{a}")), ""),
+            ("@{a}-b", Ok((0..4, "@{a}")), "-b"),
+            //
+            ("", Err((0..0, "")), ""),
+            ("@", Err((0..1, "@")), "@"),
+            ("@@", Err((0..2, "@@")), "@@"),
+            ("@{a", Err((0..3, "@{a")), "@{a"),
+            ("@{}", Err((0..2, "@{")), "@{}"),
+        ];
+
+        for (text, expected, remaining) in cases {
+            let mut scanner = Scanner::new(text);
+            assert_eq!(scanner.scan_less_variable(), expected, "{text:?}");
+            assert_eq!(scanner.remaining_text(), remaining, "{text:?}");
+        }
+    }
+
+    #[test]
+    fn test_less_escape() {
+        let cases = [
+            ("~\"\"", Ok((0..3, "~\"\"")), ""),
+            ("~'a' b", Ok((0..4, "~'a'")), " b"),
+            //
+            ("", Err((0..0, "")), ""),
+            ("~", Err((0..1, "~")), "~"),
+            ("~a", Err((0..1, "~")), "~a"),
+            ("\"a\"", Err((0..0, "")), "\"a\""),
+        ];
+
+        for (text, expected, remaining) in cases {
+            let mut scanner = Scanner::new(text);
+            assert_eq!(scanner.scan_less_escape(), expected, "{text:?}");
+            assert_eq!(scanner.remaining_text(), remaining, "{text:?}");
+        }
+    }
+}
@@ -0,0 +1,638 @@
+use crate::{ByteSet, Scanner, ScannerResult};
+
+/// [`Scanner`] extension for scanning [CommonMark] Markdown tokens.
+///
+/// **Note:** Markdown is line-oriented, e.g. headings and list markers are
+/// only recognized at the start of a line. The scanning methods do not check
+/// this, that is up to the caller.
+///
+/// [CommonMark]: https://spec.commonmark.org/
+pub trait MarkdownScannerExt<'text>: crate::private::Sealed {
+    /// Scans a single [ATX heading], i.e. 1-6 `#` followed by a space,
+    /// until the end of the line.
+    ///
+    /// **Note:** This has the same lifetime as the original `text`,
+    /// so the scanner can continue to be used while this exists.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use text_scanner::{ext::MarkdownScannerExt, Scanner};
+    ///
+    /// let text = "# Heading\n###### Heading 6\n####### Text";
+    ///
+    /// let mut scanner = Scanner::new(text);
+    /// assert_eq!(scanner.scan_markdown_heading(), Ok((0..9, "# Heading")));
+    /// scanner.skip_whitespace();
+    /// assert_eq!(scanner.scan_markdown_heading(), Ok((10..26, "###### Heading 6")));
+    /// scanner.skip_whitespace();
+    /// assert_eq!(scanner.scan_markdown_heading(), Err((27..34, "#######")));
+    /// ```
+    ///
+    /// [ATX heading]: https://spec.commonmark.org/0.30/#atx-headings
+    fn scan_markdown_heading(&mut self) -> ScannerResult<'text, &'text str>;
+
+    /// Scans a single [fenced code block], i.e. from the opening fence
+    /// including the info string, until and including the closing fence.
+    ///
+    /// If the code block is unterminated, then it continues until
+    /// the end of the text.
+    ///
+    /// **Note:** This has the same lifetime as the original `text`,
+    /// so the scanner can continue to be used while this exists.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use text_scanner::{ext::MarkdownScannerExt, Scanner};
+    ///
+    /// let text = "```rust\nfn main() {}\n```\nText";
+    ///
+    /// let mut scanner = Scanner::new(text);
+    /// assert_eq!(
+    ///     scanner.scan_markdown_code_fence(),
+    ///     Ok((0..24, "```rust\nfn main() {}\n```"))
+    /// );
+    /// assert_eq!(scanner.remaining_text(), "\nText");
+    /// ```
+    ///
+    /// [fenced code block]: https://spec.commonmark.org/0.30/#fenced-code-blocks
+    fn scan_markdown_code_fence(&mut self) -> ScannerResult<'text, &'text str>;
+
+    /// Scans a single [code span], e.g. `` `code` `` or ``` `` a ` b `` ```.
+    ///
+    /// [code span]: https://spec.commonmark.org/0.30/#code-spans
+    fn scan_markdown_inline_code(&mut self) -> ScannerResult<'text, &'text str>;
+
+    /// Scans a single [emphasis or strong emphasis], e.g. `*a*`, `_a_`,
+    /// `**a**`, and `__a__`, which does not span multiple paragraphs.
+    ///
+    /// [emphasis or strong emphasis]: https://spec.commonmark.org/0.30/#emphasis-and-strong-emphasis
+    fn scan_markdown_emphasis(&mut self) -> ScannerResult<'text, &'text str>;
+
+    /// Scans a single inline [link] or [image], e.g. `[text](url)`,
+    /// `![alt](src)`, and `[text][ref]`, or an [autolink], e.g.
+    /// `<https://example.com>`.
+    ///
+    /// [link]: https://spec.commonmark.org/0.30/#links
+    /// [image]: https://spec.commonmark.org/0.30/#images
+    /// [autolink]: https://spec.commonmark.org/0.30/#autolinks
+    fn scan_markdown_link(&mut self) -> ScannerResult<'text, &'text str>;
+
+    /// Scans a single [list marker], i.e. `-`, `*`, `+`, or a number followed
+    /// by `.` or `)`, which must be followed by whitespace. The whitespace is
+    /// not included.
+    ///
+    /// [list marker]: https://spec.commonmark.org/0.30/#list-items
+    fn scan_markdown_list_marker(&mut self) -> ScannerResult<'text, &'text str>;
+
+    /// Scans a single [block quote marker], i.e. `>`.
+    ///
+    /// [block quote marker]: https://spec.commonmark.org/0.30/#block-quotes
+    fn scan_markdown_block_quote(&mut self) -> ScannerResult<'text, &'text str>;
+
+    /// Scans a single [thematic break], i.e. a line of 3 or more `-`, `*`,
+    /// or `_`, optionally separated by spaces.
+    ///
+    /// [thematic break]: https://spec.commonmark.org/0.30/#thematic-breaks
+    fn scan_markdown_thematic_break(&mut self) -> ScannerResult<'text, &'text str>;
+
+    /// Scans a single GFM [table] delimiter row, i.e. the line of cells
+    /// containing `-`s and optionally `:`s for alignment, separated by `|`,
+    /// e.g. `| :-- | :-: | --: |`. Trailing whitespace is not included.
+    ///
+    /// At least one `|` is required, such that e.g. `---` is a thematic
+    /// break instead.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use text_scanner::{ext::MarkdownScannerExt, Scanner};
+    ///
+    /// let text = "| :-- | --: |\n--- | ---\n---";
+    ///
+    /// let mut scanner = Scanner::new(text);
+    /// assert_eq!(scanner.scan_markdown_table_delimiter_row(), Ok((0..13, "| :-- | --: |")));
+    /// scanner.skip_whitespace();
+    /// assert_eq!(scanner.scan_markdown_table_delimiter_row(), Ok((14..23, "--- | ---")));
+    /// scanner.skip_whitespace();
+    /// assert_eq!(scanner.scan_markdown_table_delimiter_row(), Err((24..27, "---")));
+    /// ```
+    ///
+    /// [table]: https://github.github.com/gfm/#tables-extension-
+    fn scan_markdown_table_delimiter_row(&mut self) -> ScannerResult<'text, &'text str>;
+
+    /// Scans a single GFM [task list item marker], i.e. `[ ]`, `[x]`,
+    /// or `[X]`, which must be followed by whitespace. The whitespace
+    /// is not included.
+    ///
+    /// **Note:** Task list item markers are only valid directly after
+    /// a list marker, e.g. `- [x] Done`, which is up to the caller.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use text_scanner::{ext::MarkdownScannerExt, Scanner};
+    ///
+    /// let text = "- [x] Done";
+    ///
+    /// let mut scanner = Scanner::new(text);
+    /// scanner.skip_until_char('[');
+    /// assert_eq!(scanner.scan_markdown_task_marker(), Ok((2..5, "[x]")));
+    /// assert_eq!(scanner.remaining_text(), " Done");
+    /// ```
+    ///
+    /// [task list item marker]: https://github.github.com/gfm/#task-list-items-extension-
+    fn scan_markdown_task_marker(&mut self) -> ScannerResult<'text, &'text str>;
+}
+
+impl<'text> MarkdownScannerExt<'text> for Scanner<'text> {
+    // Reference: https://spec.commonmark.org/0.30/#atx-headings
+    fn scan_markdown_heading(&mut self) -> ScannerResult<'text, &'text str> {
+        self.scan_with(|scanner| {
+            let (r, _s) = scanner.skip_while_char('#');
+            if r.is_empty() || (r.len() > 6) {
+                return Err(scanner.ranged_text(r));
+            }
+
+            match scanner.peek() {
+                Ok((_, ' ' | '\t' | '\n' | '\r')) | Err(_) => {}
+                Ok(_) => return Err(scanner.ranged_text(r)),
+            }
+
+            scanner.skip_until_char_any(&['\n', '\r']);
+            Ok(())
+        })
+    }
+
+    // Reference: https://spec.commonmark.org/0.30/#fenced-code-blocks
+    fn scan_markdown_code_fence(&mut self) -> ScannerResult<'text, &'text str> {
+        self.scan_with(|scanner| {
+            let (_r, fence) = scanner.accept_char_any(&['`', '~'])?;
+            let (r, _s) = scanner.skip_while_char(fence);
+            let fence_len = r.len() + 1;
+            if fence_len < 3 {
+                return Err(scanner.ranged_text(r));
+            }
+
+            // Info string, which for backtick fences must not contain backticks
+            let (r, info) = scanner.skip_until_char_any(&['\n', '\r']);
+            if (fence == '`') && info.contains('`') {
+                return Err(scanner.ranged_text(r));
+            }
+
+            while scanner.next_line_terminator().is_ok() {
+                let line = scanner.remaining_text();
+                let line = line.trim_start_matches([' ', '\t']);
+                let closing_len = line.len() - line.trim_start_matches(fence).len();
+
+                if closing_len >= fence_len {
+                    let after = &line[closing_len..];
+                    let after = after.trim_start_matches([' ', '\t']);
+                    if after.is_empty() || after.starts_with(['\n', '\r']) {
+                        scanner.skip_until_char_any(&['\n', '\r']);
+                        break;
+                    }
+                }
+
+                scanner.skip_until_char_any(&['\n', '\r']);
+            }
+
+            Ok(())
+        })
+    }
+
+    // Reference: https://spec.commonmark.org/0.30/#code-spans
+    fn scan_markdown_inline_code(&mut self) -> ScannerResult<'text, &'text str> {
+        self.scan_with(|scanner| {
+            let (r, ticks) = scanner.skip_while_char('`');
+            if r.is_empty() {
+                return Err(scanner.ranged_text(r));
+            }
+
+            loop {
+                scanner.skip_until_char('`');
+                let (r2, closing) = scanner.skip_while_char('`');
+                if r2.is_empty() {
+                    // Unterminated
+                    return Err(scanner.ranged_text(r));
+                } else if closing == ticks {
+                    break;
+                }
+            }
+
+            Ok(())
+        })
+    }
+
+    // Reference: https://spec.commonmark.org/0.30/#emphasis-and-strong-emphasis
+    fn scan_markdown_emphasis(&mut self) -> ScannerResult<'text, &'text str> {
+        self.scan_with(|scanner| {
+            let (r, delim) = scanner.accept_char_any(&['*', '_'])?;
+            let strong = scanner.accept_char(delim).is_ok();
+            let closing = match (delim, strong) {
+                ('*', true) => "**",
+                ('*', false) => "*",
+                (_, true) => "__",
+                (_, false) => "_",
+            };
+
+            let rest = scanner.remaining_text();
+            let paragraph = rest.find("\n\n").map_or(rest, |i| &rest[..i]);
+
+            // Opening delimiter must not be followed by whitespace
+            let first = paragraph
+                .chars()
+                .next()
+                .filter(|&c| !c.is_whitespace() && (c != delim))
+                .ok_or_else(|| scanner.ranged_text(r.clone()))?;
+
+            let mut offset = first.len_utf8();
+            loop {
+                let Some(i) = paragraph[offset..].find(closing) else {
+                    return Err(scanner.ranged_text(r));
+                };
+                let i = offset + i;
+                // Closing delimiter must not be preceded by whitespace
+                let before = paragraph[..i].chars().next_back();
+                let after = paragraph[(i + closing.len())..].chars().next();
+                if before.is_some_and(|c| !c.is_whitespace()) && (after != Some(delim)) {
+                    offset = i;
+                    break;
+                }
+                offset = i + closing.len();
+            }
+
+            _ = scanner.next_str(paragraph[..offset].chars().count());
+            _ = scanner.accept_str(closing);
+            Ok(())
+        })
+    }
+
+    // Reference: https://spec.commonmark.org/0.30/#links
+    // Reference: https://spec.commonmark.org/0.30/#images
+    // Reference: https://spec.commonmark.org/0.30/#autolinks
+    fn scan_markdown_link(&mut self) -> ScannerResult<'text, &'text str> {
+        self.scan_with(|scanner| {
+            if scanner.accept_char('<').is_ok() {
+                let (r, _s) =
+                    scanner.skip_while(|c| c.is_ascii_alphanumeric() || "+.-".contains(c));
+                scanner.accept_char(':')?;
+                if r.is_empty() {
+                    return Err(scanner.ranged_text(r));
+                }
+                scanner.skip_until(|c| c.is_whitespace() || matches!(c, '<' | '>'));
+                scanner.accept_char('>')?;
+                return Ok(());
+            }
+
+            _ = scanner.accept_char('!');
+            scanner.accept_char('[')?;
+            skip_balanced(scanner, '[', ']')?;
+
+            if scanner.accept_char('(').is_ok() {
+                skip_balanced(scanner, '(', ')')?;
+            } else if scanner.accept_char('[').is_ok() {
+                skip_balanced(scanner, '[', ']')?;
+            }
+
+            Ok(())
+        })
+    }
+
+    // Reference: https://spec.commonmark.org/0.30/#list-items
+    fn scan_markdown_list_marker(&mut self) -> ScannerResult<'text, &'text str> {
+        self.scan_with(|scanner| {
+            if scanner.accept_char_any(&['-', '*', '+']).is_err() {
+                let (r, _s) = scanner.skip_while_in(&ByteSet::ASCII_DIGIT);
+                if r.is_empty() || (r.len() > 9) {
+                    return Err(scanner.ranged_text(r));
+                }
+                scanner.accept_char_any(&['.', ')'])?;
+            }
+
+            match scanner.peek() {
+                Ok((_, ' ' | '\t' | '\n' | '\r')) | Err(_) => Ok(()),
+                Ok((r, _c)) => Err(scanner.ranged_text(r)),
+            }
+        })
+    }
+
+    // Reference: https://spec.commonmark.org/0.30/#block-quotes
+    #[inline]
+    fn scan_markdown_block_quote(&mut self) -> ScannerResult<'text, &'text str> {
+        let (r, _c) = self.accept_char('>')?;
+        Ok(self.ranged_text(r))
+    }
+
+    // Reference: https://spec.commonmark.org/0.30/#thematic-breaks
+    fn scan_markdown_thematic_break(&mut self) -> ScannerResult<'text, &'text str> {
+        self.scan_with(|scanner| {
+            let (r, c) = scanner.accept_char_any(&['-', '*', '_'])?;
+
+            let mut count = 1;
+            loop {
+                scanner.skip_while_char_any(&[' ', '\t']);
+                if scanner.accept_char(c).is_err() {
+                    break;
+                }
+                count += 1;
+            }
+
+            let at_line_end = scanner
+                .peek()
+                .map_or(true, |(_r, c)| matches!(c, '\n' | '\r'));
+            if (count < 3) || !at_line_end {
+                return Err(scanner.ranged_text(r));
+            }
+
+            // Exclude trailing whitespace
+            let s = scanner.text()[r.start..scanner.cursor_pos()].trim_end();
+            scanner.set_cursor_pos(r.start + s.len());
+            Ok(())
+        })
+    }
+
+    // Reference: https://github.github.com/gfm/#delimiter-row
+    fn scan_markdown_table_delimiter_row(&mut self) -> ScannerResult<'text, &'text str> {
+        self.scan_with(|scanner| {
+            let start = scanner.cursor_pos();
+
+            let mut pipes = 0;
+            let mut cells = 0;
+            loop {
+                scanner.skip_while_char_any(&[' ', '\t']);
+                if scanner.accept_char('|').is_ok() {
+                    pipes += 1;
+                    continue;
+                }
+
+                match scanner.peek() {
+                    Ok((_, '\n' | '\r')) | Err(_) => break,
+                    Ok(_) => {}
+                }
+
+                // Cell, e.g. `:--`, `:-:`, and `--:`
+                _ = scanner.accept_char(':');
+                let (r, _s) = scanner.skip_while_char('-');
+                if r.is_empty() {
+                    return Err(scanner.ranged_text(start..scanner.cursor_pos()));
+                }
+                _ = scanner.accept_char(':');
+                cells += 1;
+
+                scanner.skip_while_char_any(&[' ', '\t']);
+                match scanner.peek() {
+                    Ok((_, '|' | '\n' | '\r')) | Err(_) => {}
+                    Ok((r, _c)) => return Err(scanner.ranged_text(start..r.end)),
+                }
+            }
+
+            if (pipes == 0) || (cells == 0) {
+                return Err(scanner.ranged_text(start..scanner.cursor_pos()));
+            }
+
+            // Exclude trailing whitespace
+            let s = scanner.text()[start..scanner.cursor_pos()].trim_end();
+            scanner.set_cursor_pos(start + s.len());
+            Ok(())
+        })
+    }
+
+    // Reference: https://github.github.com/gfm/#task-list-items-extension-
+    fn scan_markdown_task_marker(&mut self) -> ScannerResult<'text, &'text str> {
+        self.scan_with(|scanner| {
+            scanner.accept_char('[')?;
+            scanner.accept_char_any(&[' ', 'x', 'X'])?;
+            scanner.accept_char(']')?;
+
+            match scanner.peek() {
+                Ok((_, ' ' | '\t' | '\n' | '\r')) | Err(_) => Ok(()),
+                Ok((r, _c)) => Err(scanner.ranged_text(r)),
+            }
+        })
+    }
+}
+
+/// Skips until and including the `close` matching an already accepted
+/// `open`, or returns `Err` if there is none.
+fn skip_balanced<'text>(
+    scanner: &mut Scanner<'text>,
+    open: char,
+    close: char,
+) -> Result<(), crate::ScannerItem<&'text str>> {
+    let mut depth = 0usize;
+    loop {
+        let (r, c) = scanner.next()?;
+        match c {
+            '\\' => {
+                _ = scanner.next();
+            }
+            c if c == open => depth += 1,
+            c if (c == close) && (depth == 0) => return Ok(()),
+            c if c == close => depth -= 1,
+            // Links do not span paragraphs
+            '\n' if scanner.remaining_text().starts_with('\n') => {
+                return Err(scanner.ranged_text(r));
+            }
+            _ => {}
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_markdown_heading() {
+        let cases = [
+            ("#", Ok((0..1, "#")), ""),
+            ("# a\nb", Ok((0..3, "# a")), "\nb"),
+            ("### a ###", Ok((0..9, "### a ###")), ""),
+            //
+            ("", Err((0..0, "")), ""),
+            ("#a", Err((0..1, "#")), "#a"),
+            ("####### a", Err((0..7, "#######")), "####### a"),
+            (" # a", Err((0..0, "")), " # a"),
+        ];
+
+        for (text, expected, remaining) in cases {
+            let mut scanner = Scanner::new(text);
+            assert_eq!(scanner.scan_markdown_heading(), expected, "{text:?}");
+            assert_eq!(scanner.remaining_text(), remaining, "{text:?}");
+        }
+    }
+
+    #[test]
+    fn test_markdown_code_fence() {
+        let cases = [
+            ("```\n```", Ok((0..7, "```\n```")), ""),
+            ("~~~py\nx\n~~~~\ny", Ok((0..12, "~~~py\nx\n~~~~")), "\ny"),
+            ("````\n```\n````", Ok((0..13, "````\n```\n````")), ""),
+            ("```\nx", Ok((0..5, "```\nx")), ""),
+            ("```\n``` x\n```", Ok((0..13, "```\n``` x\n```")), ""),
+            //
+            ("``", Err((0..2, "``")), "``"),
+            ("``` a`b", Err((0..7, "``` a`b")), "``` a`b"),
+        ];
+
+        for (text, expected, remaining) in cases {
+            let mut scanner = Scanner::new(text);
+            assert_eq!(scanner.scan_markdown_code_fence(), expected, "{text:?}");
+            assert_eq!(scanner.remaining_text(), remaining, "{text:?}");
+        }
+    }
+
+    #[test]
+    fn test_markdown_inline_code() {
+        let cases = [
+            ("`a`", Ok((0..3, "`a`")), ""),
+            ("`` a ` b `` c", Ok((0..11, "`` a ` b ``")), " c"),
+            //
+            ("`a", Err((0..1, "`")), "`a"),
+            ("``a`", Err((0..2, "``")), "``a`"),
+        ];
+
+        for (text, expected, remaining) in cases {
+            let mut scanner = Scanner::new(text);
+            assert_eq!(scanner.scan_markdown_inline_code(), expected, "{text:?}");
+            assert_eq!(scanner.remaining_text(), remaining, "{text:?}");
+        }
+    }
+
+    #[test]
+    fn test_markdown_emphasis() {
+        let cases = [
+            ("*a*", Ok((0..3, "*a*")), ""),
+            ("_a b_ c", Ok((0..5, "_a b_")), " c"),
+            ("**a**", Ok((0..5, "**a**")), ""),
+            ("**a *b* c**", Ok((0..11, "**a *b* c**")), ""),
+            ("*a\nb*", Ok((0..5, "*a\nb*")), ""),
+            //
+            ("* a*", Err((0..1, "*")), "* a*"),
+            ("*a *", Err((0..1, "*")), "*a *"),
+            ("*a\n\nb*", Err((0..1, "*")), "*a\n\nb*"),
+        ];
+
+        for (text, expected, remaining) in cases {
+            let mut scanner = Scanner::new(text);
+            assert_eq!(scanner.scan_markdown_emphasis(), expected, "{text:?}");
+            assert_eq!(scanner.remaining_text(), remaining, "{text:?}");
+        }
+    }
+
+    #[test]
+    fn test_markdown_link() {
+        let cases = [
+            ("[a](b)", Ok((0..6, "[a](b)")), ""),
+            ("![a [b]](c (d)) e", Ok((0..15, "![a [b]](c (d))")), " e"),
+            ("[a][b]", Ok((0..6, "[a][b]")), ""),
+            ("[a] b", Ok((0..3, "[a]")), " b"),
+            (
+                "<https://example.com>",
+                Ok((0..21, "<https://example.com>")),
+                "",
+            ),
+            //
+            ("[a", Err((0..2, "[a")), "[a"),
+            ("<a b>", Err((0..2, "<a")), "<a b>"),
+        ];
+
+        for (text, expected, remaining) in cases {
+            let mut scanner = Scanner::new(text);
+            assert_eq!(scanner.scan_markdown_link(), expected, "{text:?}");
+            assert_eq!(scanner.remaining_text(), remaining, "{text:?}");
+        }
+    }
+
+    #[test]
+    fn test_markdown_list_marker() {
+        let cases = [
+            ("- a", Ok((0..1, "-")), " a"),
+            ("+", Ok((0..1, "+")), ""),
+            ("12. a", Ok((0..3, "12.")), " a"),
+            ("1) a", Ok((0..2, "1)")), " a"),
+            //
+            ("-a", Err((0..2, "-a")), "-a"),
+            ("1 a", Err((0..1, "1")), "1 a"),
+        ];
+
+        for (text, expected, remaining) in cases {
+            let mut scanner = Scanner::new(text);
+            assert_eq!(scanner.scan_markdown_list_marker(), expected, "{text:?}");
+            assert_eq!(scanner.remaining_text(), remaining, "{text:?}");
+        }
+    }
+
+    #[test]
+    fn test_markdown_thematic_break() {
+        let cases = [
+            ("---", Ok((0..3, "---")), ""),
+            ("* * *  \na", Ok((0..5, "* * *")), "  \na"),
+            ("___\n", Ok((0..3, "___")), "\n"),
+            //
+            ("--", Err((0..1, "-")), "--"),
+            ("--- a", Err((0..1, "-")), "--- a"),
+            ("-*-", Err((0..1, "-")), "-*-"),
+        ];
+
+        for (text, expected, remaining) in cases {
+            let mut scanner = Scanner::new(text);
+            assert_eq!(scanner.scan_markdown_thematic_break(), expected, "{text:?}");
+            assert_eq!(scanner.remaining_text(), remaining, "{text:?}");
+        }
+    }
+
+    #[test]
+    fn test_markdown_table_delimiter_row() {
+        let cases = [
+            ("|---|", Ok((0..5, "|---|")), ""),
+            (
+                "| :-- | :-: | --: |  \na",
+                Ok((0..19, "| :-- | :-: | --: |")),
+                "  \na",
+            ),
+            ("--- | ---", Ok((0..9, "--- | ---")), ""),
+            ("-|-\n", Ok((0..3, "-|-")), "\n"),
+            ("|:-\t|", Ok((0..5, "|:-\t|")), ""),
+            //
+            ("", Err((0..0, "")), ""),
+            ("---", Err((0..3, "---")), "---"),
+            ("| a |", Err((0..2, "| ")), "| a |"),
+            ("| -- a |", Err((0..6, "| -- a")), "| -- a |"),
+            ("| :: |", Err((0..3, "| :")), "| :: |"),
+            ("| |", Err((0..3, "| |")), "| |"),
+        ];
+
+        for (text, expected, remaining) in cases {
+            let mut scanner = Scanner::new(text);
+            assert_eq!(
+                scanner.scan_markdown_table_delimiter_row(),
+                expected,
+                "{text:?}"
+            );
+            assert_eq!(scanner.remaining_text(), remaining, "{text:?}");
+        }
+    }
+
+    #[test]
+    fn test_markdown_task_marker() {
+        let cases = [
+            ("[ ]", Ok((0..3, "[ ]")), ""),
+            ("[x] a", Ok((0..3, "[x]")), " a"),
+            ("[X]\n", Ok((0..3, "[X]")), "\n"),
+            //
+            ("[]", Err((0..1, "[")), "[]"),
+            ("[y] a", Err((0..1, "[")), "[y] a"),
+            ("[x]a", Err((0..4, "[x]a")), "[x]a"),
+            ("[x](url)", Err((0..4, "[x](")), "[x](url)"),
+        ];
+
+        for (text, expected, remaining) in cases {
+            let mut scanner = Scanner::new(text);
+            assert_eq!(scanner.scan_markdown_task_marker(), expected, "{text:?}");
+            assert_eq!(scanner.remaining_text(), remaining, "{text:?}");
+        }
+    }
+}
@@ -0,0 +1,319 @@
+use crate::{IdentifierCharset, ScanOutcome, Scanner, ScannerResult};
+
+// Reference: https://mesonbuild.com/Syntax.html#grammar
+pub const MESON_KEYWORDS: &[&str] = &[
+    "and",
+    "break",
+    "continue",
+    "elif",
+    "else",
+    "endforeach",
+    "endif",
+    "endtestcase",
+    "false",
+    "foreach",
+    "if",
+    "in",
+    "not",
+    "or",
+    "testcase",
+    "true",
+];
+
+// Reference: https://mesonbuild.com/Syntax.html#grammar
+pub const MESON_OPERATORS: &[&str] = &[
+    "+", "-", "*", "/", "%", "=", "+=", "==", "!=", "<", "<=", ">", ">=", "?", ":", ",", ".",
+];
+
+// Reference: https://mesonbuild.com/Syntax.html#grammar
+pub const MESON_DELIMITERS: &[&str] = &["(", ")", "[", "]", "{", "}"];
+
+/// [`Scanner`] extension for scanning Meson tokens.
+///
+/// _Based on the [Meson syntax]._
+///
+/// See also [`MesonStrExt`].
+///
+/// [Meson syntax]: https://mesonbuild.com/Syntax.html
+pub trait MesonScannerExt<'text>: crate::private::Sealed {
+    fn scan_meson_line_comment(&mut self) -> ScannerResult<'text, &'text str>;
+
+    fn scan_meson_identifier(&mut self) -> ScannerResult<'text, &'text str>;
+    fn scan_meson_keyword(&mut self) -> ScannerResult<'text, &'text str>;
+
+    fn scan_meson_operator(&mut self) -> ScannerResult<'text, &'text str>;
+    fn scan_meson_delimiter(&mut self) -> ScannerResult<'text, &'text str>;
+
+    /// Scans a single Meson integer, i.e. a decimal integer, e.g. `123`,
+    /// or a hexadecimal, octal, or binary integer, e.g. `0xFF`, `0o77`,
+    /// and `0b11`.
+    fn scan_meson_int(&mut self) -> ScannerResult<'text, &'text str>;
+
+    /// Scans a single Meson string, i.e. either a single-line string,
+    /// e.g. `'Hello'`, or a multiline string, e.g. `'''Hello'''`,
+    /// optionally prefixed by `f` for format strings, e.g. `f'@name@'`.
+    ///
+    /// Single-line strings are unterminated at the end of the line.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use text_scanner::{ext::MesonScannerExt, Scanner};
+    ///
+    /// let text = "f'''multi\nline''' 'single'";
+    ///
+    /// let mut scanner = Scanner::new(text);
+    /// assert_eq!(scanner.scan_meson_string(), Ok((0..17, "f'''multi\nline'''")));
+    /// scanner.skip_whitespace();
+    /// assert_eq!(scanner.scan_meson_string(), Ok((18..26, "'single'")));
+    /// ```
+    fn scan_meson_string(&mut self) -> ScannerResult<'text, &'text str>;
+    /// Same as [`scan_meson_string()`], but additionally returns
+    /// whether the string is unterminated.
+    ///
+    /// [`scan_meson_string()`]: Self::scan_meson_string
+    fn scan_meson_string_outcome(&mut self) -> ScannerResult<'text, ScanOutcome>;
+}
+
+impl<'text> MesonScannerExt<'text> for Scanner<'text> {
+    // Reference: https://mesonbuild.com/Syntax.html#comments
+    fn scan_meson_line_comment(&mut self) -> ScannerResult<'text, &'text str> {
+        self.scan_with(|scanner| {
+            scanner.accept_char('#')?;
+            scanner.skip_until_char_any(&['\n', '\r']);
+            Ok(())
+        })
+    }
+
+    // Reference: https://mesonbuild.com/Syntax.html#grammar
+    fn scan_meson_identifier(&mut self) -> ScannerResult<'text, &'text str> {
+        self.scan_identifier_with_charset(IdentifierCharset::C)
+    }
+
+    // Reference: https://mesonbuild.com/Syntax.html#grammar
+    fn scan_meson_keyword(&mut self) -> ScannerResult<'text, &'text str> {
+        self.scan_with(|scanner| {
+            let (r, s) = scanner.scan_meson_identifier()?;
+            if s.is_meson_keyword() {
+                Ok(())
+            } else {
+                Err((r, s))
+            }
+        })
+    }
+
+    // Reference: https://mesonbuild.com/Syntax.html#grammar
+    fn scan_meson_operator(&mut self) -> ScannerResult<'text, &'text str> {
+        self.scan_with(|scanner| {
+            let (r, c) = scanner.next()?;
+            match c {
+                '+' | '=' | '<' | '>' => {
+                    _ = scanner.accept_char('=');
+                }
+                '!' => {
+                    scanner.accept_char('=')?;
+                }
+                '-' | '*' | '/' | '%' | '?' | ':' | ',' | '.' => {}
+                _ => return Err(scanner.ranged_text(r)),
+            }
+            Ok(())
+        })
+    }
+
+    // Reference: https://mesonbuild.com/Syntax.html#grammar
+    fn scan_meson_delimiter(&mut self) -> ScannerResult<'text, &'text str> {
+        let (r, _c) = self.accept_char_any(&['(', ')', '[', ']', '{', '}'])?;
+        Ok(self.ranged_text(r))
+    }
+
+    // Reference: https://mesonbuild.com/Syntax.html#numbers
+    fn scan_meson_int(&mut self) -> ScannerResult<'text, &'text str> {
+        self.scan_with(|scanner| {
+            let (r, c) = scanner.accept_if(|c| c.is_ascii_digit())?;
+
+            if c == '0' {
+                let radix = match scanner.peek() {
+                    Ok((_r, 'x' | 'X')) => Some(16),
+                    Ok((_r, 'o' | 'O')) => Some(8),
+                    Ok((_r, 'b' | 'B')) => Some(2),
+                    _ => None,
+                };
+                if let Some(radix) = radix {
+                    _ = scanner.next();
+                    scanner
+                        .accept_if(|c| c.is_digit(radix))
+                        .map_err(|_| scanner.ranged_text(r.start..scanner.cursor_pos()))?;
+                    scanner.skip_while(|c| c.is_digit(radix));
+                    return Ok(());
+                }
+            }
+
+            scanner.skip_while(|c| c.is_ascii_digit());
+            Ok(())
+        })
+    }
+
+    // Reference: https://mesonbuild.com/Syntax.html#strings
+    #[inline]
+    fn scan_meson_string(&mut self) -> ScannerResult<'text, &'text str> {
+        let (r, _outcome) = self.scan_meson_string_outcome()?;
+        Ok(self.ranged_text(r))
+    }
+
+    // Reference: https://mesonbuild.com/Syntax.html#strings
+    fn scan_meson_string_outcome(&mut self) -> ScannerResult<'text, ScanOutcome> {
+        let mut outcome = ScanOutcome::Terminated;
+        let (r, _s) = self.scan_with(|scanner| {
+            let start = scanner.cursor_pos();
+            _ = scanner.accept_char('f');
+            let o = if scanner.accept_str("'''").is_ok() {
+                scan_meson_multiline_string(scanner)
+            } else {
+                scanner.accept_char('\'')?;
+                scan_meson_single_line_string(scanner)
+            };
+            // Include the prefix in the range of the opening delimiter
+            if let ScanOutcome::Unterminated(r) = o {
+                outcome = ScanOutcome::Unterminated(start..r.end);
+            }
+            Ok(())
+        })?;
+        Ok((r, outcome))
+    }
+}
+
+/// Scans the remaining single-line string, after the opening `'`.
+fn scan_meson_single_line_string(scanner: &mut Scanner<'_>) -> ScanOutcome {
+    let open_end = scanner.cursor_pos();
+
+    loop {
+        scanner.skip_until_char_any(&['\'', '\\', '\n', '\r']);
+        match scanner.peek() {
+            Ok((_r, '\'')) => {
+                _ = scanner.next();
+                return ScanOutcome::Terminated;
+            }
+            Ok((_r, '\\')) => {
+                _ = scanner.next();
+                // Skip the next character as it is escaped
+                _ = scanner.next();
+            }
+            _ => return ScanOutcome::Unterminated(open_end..open_end),
+        }
+    }
+}
+
+/// Scans the remaining multiline string, after the opening `'''`.
+/// Escape sequences are not processed in multiline strings.
+fn scan_meson_multiline_string(scanner: &mut Scanner<'_>) -> ScanOutcome {
+    let open_end = scanner.cursor_pos();
+
+    loop {
+        scanner.skip_until_char('\'');
+        if !scanner.has_remaining_text() {
+            return ScanOutcome::Unterminated(open_end..open_end);
+        }
+        if scanner.accept_str("'''").is_ok() {
+            return ScanOutcome::Terminated;
+        }
+        _ = scanner.next();
+    }
+}
+
+/// [`str`] extension for checking if a `&str` is e.g. a Meson keyword.
+pub trait MesonStrExt {
+    fn is_meson_keyword(&self) -> bool;
+}
+
+impl MesonStrExt for str {
+    #[inline]
+    fn is_meson_keyword(&self) -> bool {
+        MESON_KEYWORDS.contains(&self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{assert_invalid_cases, assert_valid_cases};
+
+    #[test]
+    fn test_meson_keywords() {
+        assert_valid_cases!(scan_meson_keyword, MESON_KEYWORDS.iter().copied());
+        assert_valid_cases!(scan_meson_keyword, MESON_KEYWORDS.iter().copied(), " rest");
+
+        let cases = ["iff", "foreach_", "True", "project"];
+        assert_invalid_cases!(scan_meson_keyword, cases);
+    }
+
+    #[test]
+    fn test_meson_operators() {
+        assert_valid_cases!(scan_meson_operator, MESON_OPERATORS.iter().copied());
+        assert_valid_cases!(
+            scan_meson_operator,
+            MESON_OPERATORS.iter().copied(),
+            " rest"
+        );
+    }
+
+    #[test]
+    fn test_meson_delimiters() {
+        assert_valid_cases!(scan_meson_delimiter, MESON_DELIMITERS.iter().copied());
+        assert_valid_cases!(
+            scan_meson_delimiter,
+            MESON_DELIMITERS.iter().copied(),
+            " rest"
+        );
+    }
+
+    #[test]
+    fn test_meson_ints() {
+        let cases = ["0", "123", "0xFF", "0o777", "0b1010"];
+
+        assert_valid_cases!(scan_meson_int, cases);
+        assert_valid_cases!(scan_meson_int, cases, " rest");
+    }
+
+    #[test]
+    fn test_meson_ints_invalid() {
+        let cases = ["0x", "0b2", "x1"];
+
+        assert_invalid_cases!(scan_meson_int, cases);
+    }
+
+    #[test]
+    fn test_meson_strings() {
+        let cases = [
+            "''",
+            "'Hello World'",
+            r"'\'escaped\''",
+            "f'@name@'",
+            "''''''",
+            "'''multi\n'line'\n'''",
+            "f'''@multi@\n'''",
+        ];
+
+        assert_valid_cases!(scan_meson_string, cases);
+        assert_valid_cases!(scan_meson_string, cases, " rest");
+    }
+
+    #[test]
+    fn test_meson_strings_unterminated() {
+        let mut scanner = Scanner::new("f'unterminated\n'");
+        let (r, outcome) = scanner.scan_meson_string_outcome().unwrap();
+        assert_eq!(r, 0..14);
+        assert_eq!(outcome, ScanOutcome::Unterminated(0..2));
+
+        let mut scanner = Scanner::new("'''unterminated'' ");
+        let (r, outcome) = scanner.scan_meson_string_outcome().unwrap();
+        assert_eq!(r, 0..18);
+        assert_eq!(outcome, ScanOutcome::Unterminated(0..3));
+    }
+
+    #[test]
+    fn test_meson_strings_invalid() {
+        let cases = ["\"double\"", "f", "f\"\""];
+
+        assert_invalid_cases!(scan_meson_string, cases);
+    }
+}
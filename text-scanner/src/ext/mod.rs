@@ -11,24 +11,127 @@
 //! [`any-lexer` crate]: https://docs.rs/any-lexer/*/any_lexer/
 //! [`RustLexer`]: https://docs.rs/any-lexer/*/any_lexer/struct.RustLexer.html
 
+mod apache;
 mod c;
+mod cmake;
+mod crontab;
+mod csharp;
 mod css;
+mod fortran;
+mod go;
+mod html;
 mod java;
+mod javascript;
 mod json;
 mod jsonc;
+mod less;
 mod lisp;
+mod markdown;
+mod meson;
+mod nginx;
 mod python;
 mod rust;
 mod scss;
+mod stylus;
 mod swift;
+mod systemd;
+mod toml;
+mod xml;
 
+pub use self::apache::*;
 pub use self::c::*;
+pub use self::cmake::*;
+pub use self::crontab::*;
+pub use self::csharp::*;
 pub use self::css::*;
+pub use self::fortran::*;
+pub use self::go::*;
+pub use self::html::*;
 pub use self::java::*;
+pub use self::javascript::*;
 pub use self::json::*;
 pub use self::jsonc::*;
+pub use self::less::*;
 pub use self::lisp::*;
+pub use self::markdown::*;
+pub use self::meson::*;
+pub use self::nginx::*;
 pub use self::python::*;
 pub use self::rust::*;
 pub use self::scss::*;
+pub use self::stylus::*;
 pub use self::swift::*;
+pub use self::systemd::*;
+pub use self::toml::*;
+pub use self::xml::*;
+
+use std::ops::Range;
+
+/// Metadata of a scanned number literal, such that the literal does not
+/// need to be parsed again, e.g. by formatters and linters.
+///
+/// Returned by e.g. [`RustScannerExt::scan_rust_number_info()`],
+/// [`CScannerExt::scan_c_number_info()`],
+/// [`PythonScannerExt::scan_python_number_info()`], and
+/// [`JavaScriptScannerExt::scan_javascript_number_info()`].
+///
+/// # Example
+///
+/// ```rust
+/// use text_scanner::{ext::{NumberInfo, RustScannerExt}, Scanner};
+///
+/// let mut scanner = Scanner::new("0xFF_u8");
+/// assert_eq!(
+///     scanner.scan_rust_number_info(),
+///     Ok((0..7, NumberInfo {
+///         radix: 16,
+///         digits_range: 2..5,
+///         suffix_range: Some(5..7),
+///         is_float: false,
+///     })),
+/// );
+/// ```
+#[derive(PartialEq, Eq, Clone, Debug)]
+pub struct NumberInfo {
+    /// The radix of the digits, i.e. `2`, `8`, `10`, or `16`.
+    pub radix: u32,
+    /// The range of the digits, excluding the radix prefix and the suffix,
+    /// e.g. `FF_` in `0xFF_u8`. For floats this includes the fraction and
+    /// the exponent, e.g. `1.5e3` in `1.5e3f32`.
+    pub digits_range: Range<usize>,
+    /// The range of the type suffix, e.g. `u8` in `0xFF_u8`,
+    /// `UL` in `1UL`, `j` in `1j`, and `n` in `1n`.
+    pub suffix_range: Option<Range<usize>>,
+    /// Whether the literal is a floating-point literal, including
+    /// integer literals with a float suffix, e.g. `1f32`.
+    pub is_float: bool,
+}
+
+impl NumberInfo {
+    /// Returns the [`NumberInfo`] of the number literal starting at `start`,
+    /// where `literal` is the text of the number excluding the suffix, and
+    /// `prefixes` are the lowercase radix prefixes of the language,
+    /// e.g. `("0x", 16)`.
+    fn new(
+        start: usize,
+        literal: &str,
+        suffix_range: Range<usize>,
+        prefixes: &[(&str, u32)],
+        is_float: bool,
+    ) -> Self {
+        let (prefix_len, radix) = prefixes
+            .iter()
+            .find(|(prefix, _radix)| {
+                literal
+                    .get(..prefix.len())
+                    .is_some_and(|p| p.eq_ignore_ascii_case(prefix))
+            })
+            .map_or((0, 10), |(prefix, radix)| (prefix.len(), *radix));
+        Self {
+            radix,
+            digits_range: (start + prefix_len)..suffix_range.start,
+            suffix_range: (!suffix_range.is_empty()).then_some(suffix_range),
+            is_float,
+        }
+    }
+}
@@ -0,0 +1,230 @@
+use crate::{ScanOutcome, Scanner, ScannerResult};
+
+/// [`Scanner`] extension for scanning nginx configuration tokens.
+///
+/// _Based on the [nginx configuration] syntax_.
+///
+/// [nginx configuration]: https://nginx.org/en/docs/beginners_guide.html#conf_structure
+pub trait NginxScannerExt<'text>: crate::private::Sealed {
+    /// Scans an nginx line comment, e.g. `# comment`.
+    ///
+    /// **Note:** `#` only starts a comment at the start of a token,
+    /// e.g. `https://example.com/#top` is a single word. Thereby, line
+    /// comments must be scanned before words.
+    fn scan_nginx_line_comment(&mut self) -> ScannerResult<'text, &'text str>;
+
+    /// Scans an nginx variable, e.g. `$host`, `${host}`, and the regex
+    /// capture `$1`.
+    fn scan_nginx_variable(&mut self) -> ScannerResult<'text, &'text str>;
+
+    /// Scans a single nginx string, i.e. `"..."` or `'...'`, including
+    /// any variables and escape sequences. Strings can span multiple lines.
+    ///
+    /// If the string is unterminated, then it ends at the end of the text.
+    fn scan_nginx_string(&mut self) -> ScannerResult<'text, &'text str>;
+    /// Same as [`scan_nginx_string()`], but additionally returns
+    /// whether the string is unterminated.
+    ///
+    /// [`scan_nginx_string()`]: Self::scan_nginx_string
+    fn scan_nginx_string_outcome(&mut self) -> ScannerResult<'text, ScanOutcome>;
+
+    /// Scans the text of an nginx word, i.e. a directive name or an
+    /// unquoted argument, e.g. `server_name`, `/var/www/`, and `~*`,
+    /// until whitespace, `;`, `{`, `}`, or a variable.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use text_scanner::{ext::NginxScannerExt, Scanner};
+    ///
+    /// let text = "/var/www/$host/;";
+    /// let mut scanner = Scanner::new(text);
+    ///
+    /// assert_eq!(scanner.scan_nginx_word(), Ok((0..9, "/var/www/")));
+    /// assert_eq!(scanner.scan_nginx_variable(), Ok((9..14, "$host")));
+    /// assert_eq!(scanner.scan_nginx_word(), Ok((14..15, "/")));
+    /// assert_eq!(scanner.remaining_text(), ";");
+    /// ```
+    fn scan_nginx_word(&mut self) -> ScannerResult<'text, &'text str>;
+
+    /// Scans a single nginx delimiter, i.e. `{`, `}`, or `;`.
+    fn scan_nginx_delimiter(&mut self) -> ScannerResult<'text, &'text str>;
+}
+
+impl<'text> NginxScannerExt<'text> for Scanner<'text> {
+    // Reference: https://nginx.org/en/docs/beginners_guide.html#conf_structure
+    fn scan_nginx_line_comment(&mut self) -> ScannerResult<'text, &'text str> {
+        self.scan_with(|scanner| {
+            scanner.accept_char('#')?;
+            scanner.skip_until_char_any(&['\n', '\r']);
+            Ok(())
+        })
+    }
+
+    // Reference: https://nginx.org/en/docs/varindex.html
+    fn scan_nginx_variable(&mut self) -> ScannerResult<'text, &'text str> {
+        self.scan_with(|scanner| {
+            scanner.accept_char('$')?;
+
+            let braced = scanner.accept_char('{').is_ok();
+            let (_r, c) = scanner.accept_if(|c| c.is_ascii_alphanumeric() || (c == '_'))?;
+            if c.is_ascii_digit() && !braced {
+                // Regex capture, e.g. `$1`
+                return Ok(());
+            }
+            scanner.skip_while(|c| c.is_ascii_alphanumeric() || (c == '_'));
+
+            if braced {
+                scanner.accept_char('}')?;
+            }
+            Ok(())
+        })
+    }
+
+    // Reference: https://nginx.org/en/docs/beginners_guide.html#conf_structure
+    #[inline]
+    fn scan_nginx_string(&mut self) -> ScannerResult<'text, &'text str> {
+        let (r, _outcome) = self.scan_nginx_string_outcome()?;
+        Ok(self.ranged_text(r))
+    }
+
+    // Reference: https://nginx.org/en/docs/beginners_guide.html#conf_structure
+    fn scan_nginx_string_outcome(&mut self) -> ScannerResult<'text, ScanOutcome> {
+        let mut outcome = ScanOutcome::Terminated;
+        let (r, _s) = self.scan_with(|scanner| {
+            let (open_range, quote) = scanner.accept_char_any(&['"', '\''])?;
+
+            loop {
+                scanner.skip_until_char_any(&[quote, '\\']);
+                match scanner.next() {
+                    Ok((_r, '\\')) => {
+                        // Skip the next character as it is escaped
+                        _ = scanner.next();
+                    }
+                    Ok(_) => break,
+                    Err(_) => {
+                        outcome = ScanOutcome::Unterminated(open_range);
+                        break;
+                    }
+                }
+            }
+
+            Ok(())
+        })?;
+        Ok((r, outcome))
+    }
+
+    // Reference: https://nginx.org/en/docs/beginners_guide.html#conf_structure
+    fn scan_nginx_word(&mut self) -> ScannerResult<'text, &'text str> {
+        self.scan_with(|scanner| {
+            let start = scanner.cursor_pos();
+            loop {
+                if scanner.peeking(|scanner| scanner.scan_nginx_variable().is_ok()) {
+                    break;
+                }
+                match scanner.peek() {
+                    Ok((_r, '\\')) => {
+                        _ = scanner.next();
+                        _ = scanner.next();
+                    }
+                    Ok((_r, c)) if !c.is_whitespace() && !matches!(c, ';' | '{' | '}') => {
+                        _ = scanner.next();
+                    }
+                    _ => break,
+                }
+            }
+
+            if scanner.cursor_pos() == start {
+                return Err((start..start, ""));
+            }
+            Ok(())
+        })
+    }
+
+    // Reference: https://nginx.org/en/docs/beginners_guide.html#conf_structure
+    #[inline]
+    fn scan_nginx_delimiter(&mut self) -> ScannerResult<'text, &'text str> {
+        let (r, _c) = self.accept_char_any(&['{', '}', ';'])?;
+        Ok(self.ranged_text(r))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{assert_invalid_cases, assert_valid_cases};
+
+    #[test]
+    fn test_nginx_line_comments() {
+        let cases = ["#", "# Comment", "#server {"];
+
+        assert_valid_cases!(scan_nginx_line_comment, cases);
+        assert_valid_cases!(scan_nginx_line_comment, cases, "\nrest");
+    }
+
+    #[test]
+    fn test_nginx_variables() {
+        let cases = ["$host", "$http_user_agent", "${host}", "$1", "$_"];
+
+        assert_valid_cases!(scan_nginx_variable, cases);
+        assert_valid_cases!(scan_nginx_variable, cases, "/rest");
+
+        let mut scanner = Scanner::new("$12");
+        assert_eq!(scanner.scan_nginx_variable(), Ok((0..2, "$1")));
+    }
+
+    #[test]
+    fn test_nginx_variables_invalid() {
+        let cases = ["$", "$ host", "${host", "${}", "host"];
+
+        assert_invalid_cases!(scan_nginx_variable, cases);
+    }
+
+    #[test]
+    fn test_nginx_strings() {
+        let cases = [
+            r#""""#,
+            r#""Hello World""#,
+            r#""$host:$server_port""#,
+            r#""a \" b""#,
+            "'single'",
+            "'it\\'s'",
+            "\"multi\nline\"",
+        ];
+
+        assert_valid_cases!(scan_nginx_string, cases);
+        assert_valid_cases!(scan_nginx_string, cases, ";");
+
+        let mut scanner = Scanner::new("\"unterminated");
+        let (r, outcome) = scanner.scan_nginx_string_outcome().unwrap();
+        assert_eq!(r, 0..13);
+        assert_eq!(outcome, ScanOutcome::Unterminated(0..1));
+    }
+
+    #[test]
+    fn test_nginx_words() {
+        let cases = [
+            "server_name",
+            "example.com",
+            "/var/www/html",
+            "~*",
+            r"\.(gif|jpg)$",
+            "127.0.0.1:8080",
+            "https://example.com/#top",
+            r"a\;b",
+            "10m",
+        ];
+
+        assert_valid_cases!(scan_nginx_word, cases);
+        assert_valid_cases!(scan_nginx_word, cases, ";");
+        assert_valid_cases!(scan_nginx_word, cases, " {");
+        assert_valid_cases!(scan_nginx_word, cases, "$host");
+    }
+
+    #[test]
+    fn test_nginx_words_invalid() {
+        let cases = ["", " ", ";", "{", "}", "$host"];
+
+        assert_invalid_cases!(scan_nginx_word, cases);
+    }
+}
@@ -1,4 +1,8 @@
-use crate::{CharExt, ScanResult, Scanner, ScannerResult};
+use super::NumberInfo;
+use crate::{
+    CharExt, IdentifierCharset, ScanOutcome, ScanResult, Scanner, ScannerItem, ScannerResult,
+    SeparatorPolicy,
+};
 
 // Reference: https://docs.python.org/3/reference/lexical_analysis.html#keywords
 pub const PYTHON_KEYWORDS: &[&str] = &[
@@ -8,6 +12,22 @@ pub const PYTHON_KEYWORDS: &[&str] = &[
     "or", "yield",
 ];
 
+// Reference: https://docs.python.org/3/reference/lexical_analysis.html#integer-literals
+const PYTHON_DIGIT_SEPARATORS: SeparatorPolicy = SeparatorPolicy::STRICT;
+
+// Underscores are allowed directly after the base prefix, e.g. `0x_FF`
+const PYTHON_PREFIXED_DIGIT_SEPARATORS: SeparatorPolicy = SeparatorPolicy {
+    leading: true,
+    ..PYTHON_DIGIT_SEPARATORS
+};
+
+// Reference: https://docs.python.org/2.7/reference/lexical_analysis.html#keywords
+pub const PYTHON2_KEYWORDS: &[&str] = &[
+    "and", "del", "from", "not", "while", "as", "elif", "global", "or", "with", "assert", "else",
+    "if", "pass", "yield", "break", "except", "import", "print", "class", "exec", "in", "raise",
+    "continue", "finally", "is", "return", "def", "for", "lambda", "try",
+];
+
 // Reference: https://docs.python.org/3/reference/lexical_analysis.html#soft-keywords
 pub const PYTHON_SOFT_KEYWORDS: &[&str] = &["match", "case", "_"];
 
@@ -44,14 +64,99 @@ pub trait PythonScannerExt<'text>: crate::private::Sealed {
     fn scan_python_int_oct(&mut self) -> ScannerResult<'text, &'text str>;
     fn scan_python_int_bin(&mut self) -> ScannerResult<'text, &'text str>;
     fn scan_python_float(&mut self) -> ScannerResult<'text, &'text str>;
+    fn scan_python_imaginary(&mut self) -> ScannerResult<'text, &'text str>;
+    /// Scans a single integer, floating-point, or imaginary literal, and
+    /// returns its [`NumberInfo`], where the suffix is the `j` of
+    /// imaginary literals.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use text_scanner::{ext::{NumberInfo, PythonScannerExt}, Scanner};
+    ///
+    /// let mut scanner = Scanner::new("1.5e3j");
+    /// assert_eq!(
+    ///     scanner.scan_python_number_info(),
+    ///     Ok((0..6, NumberInfo {
+    ///         radix: 10,
+    ///         digits_range: 0..5,
+    ///         suffix_range: Some(5..6),
+    ///         is_float: true,
+    ///     })),
+    /// );
+    /// ```
+    fn scan_python_number_info(&mut self) -> ScannerResult<'text, NumberInfo>;
+
+    fn scan_python_ellipsis(&mut self) -> ScannerResult<'text, &'text str>;
 
     fn scan_python_string(&mut self) -> ScannerResult<'text, &'text str>;
     fn scan_python_short_string(&mut self) -> ScannerResult<'text, &'text str>;
     fn scan_python_long_string(&mut self) -> ScannerResult<'text, &'text str>;
+    /// Same as [`scan_python_short_string()`], but additionally returns whether
+    /// the string is unterminated, i.e. ends at the end of the line.
+    ///
+    /// [`scan_python_short_string()`]: Self::scan_python_short_string
+    fn scan_python_short_string_outcome(&mut self) -> ScannerResult<'text, ScanOutcome>;
+    /// Same as [`scan_python_long_string()`], but additionally returns whether
+    /// the string is unterminated.
+    ///
+    /// [`scan_python_long_string()`]: Self::scan_python_long_string
+    fn scan_python_long_string_outcome(&mut self) -> ScannerResult<'text, ScanOutcome>;
 
     fn scan_python_bytes(&mut self) -> ScannerResult<'text, &'text str>;
     fn scan_python_short_bytes(&mut self) -> ScannerResult<'text, &'text str>;
     fn scan_python_long_bytes(&mut self) -> ScannerResult<'text, &'text str>;
+
+    /// Scans a single [Python 2 keyword], e.g. `print` and `exec`.
+    ///
+    /// [Python 2 keyword]: https://docs.python.org/2.7/reference/lexical_analysis.html#keywords
+    fn scan_python2_keyword(&mut self) -> ScannerResult<'text, &'text str>;
+
+    /// Scans a single Python 2 operator, i.e. any Python operator
+    /// or the [`<>` operator], which is the same as `!=`.
+    ///
+    /// [`<>` operator]: https://docs.python.org/2.7/reference/lexical_analysis.html#operators
+    fn scan_python2_operator(&mut self) -> ScannerResult<'text, &'text str>;
+
+    /// Scans a single Python 2 octal integer, i.e. either `0o777`
+    /// or the [legacy octal] `0777`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use text_scanner::{ext::PythonScannerExt, Scanner};
+    ///
+    /// let text = "0777 0o777 0";
+    ///
+    /// let mut scanner = Scanner::new(text);
+    /// assert_eq!(scanner.scan_python2_int_oct(), Ok((0..4, "0777")));
+    /// scanner.skip_whitespace();
+    /// assert_eq!(scanner.scan_python2_int_oct(), Ok((5..10, "0o777")));
+    /// scanner.skip_whitespace();
+    /// assert_eq!(scanner.scan_python2_int_oct(), Err((11..12, "0")));
+    /// ```
+    ///
+    /// [legacy octal]: https://docs.python.org/2.7/reference/lexical_analysis.html#integer-and-long-integer-literals
+    fn scan_python2_int_oct(&mut self) -> ScannerResult<'text, &'text str>;
+
+    /// Scans a single Python 2 string, where the [prefix] is any of `u`, `r`,
+    /// and `ur` (case-insensitively). As opposed to Python 3, `ur''` is valid
+    /// while `f''` is not.
+    ///
+    /// [prefix]: https://docs.python.org/2.7/reference/lexical_analysis.html#string-literals
+    fn scan_python2_string(&mut self) -> ScannerResult<'text, &'text str>;
+    fn scan_python2_short_string(&mut self) -> ScannerResult<'text, &'text str>;
+    fn scan_python2_long_string(&mut self) -> ScannerResult<'text, &'text str>;
+    /// Same as [`scan_python2_short_string()`], but additionally returns whether
+    /// the string is unterminated, i.e. ends at the end of the line.
+    ///
+    /// [`scan_python2_short_string()`]: Self::scan_python2_short_string
+    fn scan_python2_short_string_outcome(&mut self) -> ScannerResult<'text, ScanOutcome>;
+    /// Same as [`scan_python2_long_string()`], but additionally returns whether
+    /// the string is unterminated.
+    ///
+    /// [`scan_python2_long_string()`]: Self::scan_python2_long_string
+    fn scan_python2_long_string_outcome(&mut self) -> ScannerResult<'text, ScanOutcome>;
 }
 
 impl<'text> PythonScannerExt<'text> for Scanner<'text> {
@@ -84,11 +189,7 @@ impl<'text> PythonScannerExt<'text> for Scanner<'text> {
 
     // Reference: https://docs.python.org/3/reference/lexical_analysis.html#identifiers
     fn scan_python_identifier(&mut self) -> ScannerResult<'text, &'text str> {
-        self.scan_with(|scanner| {
-            scanner.accept_if(|c| c.is_alphabetic() || (c == '_'))?;
-            scanner.skip_while(|c| c.is_alphanumeric() || (c == '_'));
-            Ok(())
-        })
+        self.scan_identifier_with_charset(IdentifierCharset::XID)
     }
 
     // Reference: https://docs.python.org/3/reference/lexical_analysis.html#keywords
@@ -170,11 +271,7 @@ impl<'text> PythonScannerExt<'text> for Scanner<'text> {
 
     // Reference: https://docs.python.org/3/reference/lexical_analysis.html#integer-literals
     fn scan_python_int_dec(&mut self) -> ScannerResult<'text, &'text str> {
-        self.scan_with(|scanner| {
-            scanner.accept_if_ext(char::is_ascii_digit)?;
-            scanner.skip_while(|c| c.is_ascii_digit() || (c == '_'));
-            Ok(())
-        })
+        self.scan_digits_sep(10, '_', PYTHON_DIGIT_SEPARATORS)
     }
 
     // Reference: https://docs.python.org/3/reference/lexical_analysis.html#integer-literals
@@ -183,10 +280,7 @@ impl<'text> PythonScannerExt<'text> for Scanner<'text> {
             scanner.accept_char('0')?;
             scanner.accept_char_any(&['x', 'X'])?;
 
-            scanner.skip_while_char('_');
-            scanner.accept_if_ext(char::is_ascii_hexdigit)?;
-
-            scanner.skip_while(|c| c.is_ascii_hexdigit() || (c == '_'));
+            scanner.scan_digits_sep(16, '_', PYTHON_PREFIXED_DIGIT_SEPARATORS)?;
 
             Ok(())
         })
@@ -198,10 +292,7 @@ impl<'text> PythonScannerExt<'text> for Scanner<'text> {
             scanner.accept_char('0')?;
             scanner.accept_char_any(&['o', 'O'])?;
 
-            scanner.skip_while_char('_');
-            scanner.accept_if(CharExt::is_ascii_octdigit)?;
-
-            scanner.skip_while(|c| CharExt::is_ascii_octdigit(c) || (c == '_'));
+            scanner.scan_digits_sep(8, '_', PYTHON_PREFIXED_DIGIT_SEPARATORS)?;
 
             Ok(())
         })
@@ -213,10 +304,7 @@ impl<'text> PythonScannerExt<'text> for Scanner<'text> {
             scanner.accept_char('0')?;
             scanner.accept_char_any(&['b', 'B'])?;
 
-            scanner.skip_while_char('_');
-            scanner.accept_if(CharExt::is_ascii_bindigit)?;
-
-            scanner.skip_while(|c| c.is_ascii_bindigit() || (c == '_'));
+            scanner.scan_digits_sep(2, '_', PYTHON_PREFIXED_DIGIT_SEPARATORS)?;
 
             Ok(())
         })
@@ -241,9 +329,7 @@ impl<'text> PythonScannerExt<'text> for Scanner<'text> {
             if scanner.accept_char_any(&['e', 'E']).is_ok() {
                 _ = scanner.accept_char_any(&['+', '-']);
 
-                scanner.skip_while_char('_');
-                scanner.accept_if_ext(char::is_ascii_digit)?;
-                scanner.skip_while(|c| c.is_ascii_digit() || (c == '_'));
+                scanner.scan_python_int_dec()?;
             } else if let Some(r) = int_range {
                 return Err(scanner.ranged_text(r));
             }
@@ -252,6 +338,55 @@ impl<'text> PythonScannerExt<'text> for Scanner<'text> {
         })
     }
 
+    // Reference: https://docs.python.org/3/reference/lexical_analysis.html#imaginary-literals
+    fn scan_python_imaginary(&mut self) -> ScannerResult<'text, &'text str> {
+        self.scan_with(|scanner| {
+            scanner
+                .scan_python_float()
+                .or_else(|_| scanner.scan_python_int_dec())?;
+            scanner.accept_char_any(&['j', 'J'])?;
+            Ok(())
+        })
+    }
+
+    // Reference: https://docs.python.org/3/reference/lexical_analysis.html#numeric-literals
+    fn scan_python_number_info(&mut self) -> ScannerResult<'text, NumberInfo> {
+        let mut is_float = false;
+        let (r, literal) = match self
+            .scan_python_int_hex()
+            .or_else(|_| self.scan_python_int_oct())
+            .or_else(|_| self.scan_python_int_bin())
+        {
+            Ok(item) => item,
+            Err(_) => match self.scan_python_float() {
+                Ok(item) => {
+                    is_float = true;
+                    item
+                }
+                Err(_) => self.scan_python_int_dec()?,
+            },
+        };
+
+        const PREFIXES: &[(&str, u32)] = &[("0x", 16), ("0o", 8), ("0b", 2)];
+        let mut info = NumberInfo::new(r.start, literal, r.end..r.end, PREFIXES, is_float);
+
+        // Only decimal literals can be imaginary, e.g. `0x1j` is not
+        if info.radix == 10 {
+            if let Ok((suffix_range, _c)) = self.accept_char_any(&['j', 'J']) {
+                info.suffix_range = Some(suffix_range);
+            }
+        }
+
+        let end = info.suffix_range.as_ref().map_or(r.end, |r| r.end);
+        Ok((r.start..end, info))
+    }
+
+    // Reference: https://docs.python.org/3/library/constants.html#Ellipsis
+    #[inline]
+    fn scan_python_ellipsis(&mut self) -> ScannerResult<'text, &'text str> {
+        self.accept_str("...")
+    }
+
     // Reference: https://docs.python.org/3/reference/lexical_analysis.html#string-and-bytes-literals
     #[inline]
     fn scan_python_string(&mut self) -> ScannerResult<'text, &'text str> {
@@ -260,21 +395,47 @@ impl<'text> PythonScannerExt<'text> for Scanner<'text> {
     }
 
     // Reference: https://docs.python.org/3/reference/lexical_analysis.html#string-and-bytes-literals
+    #[inline]
     fn scan_python_short_string(&mut self) -> ScannerResult<'text, &'text str> {
-        self.scan_with(|scanner| {
+        let (r, _outcome) = self.scan_python_short_string_outcome()?;
+        Ok(self.ranged_text(r))
+    }
+
+    // Reference: https://docs.python.org/3/reference/lexical_analysis.html#string-and-bytes-literals
+    fn scan_python_short_string_outcome(&mut self) -> ScannerResult<'text, ScanOutcome> {
+        let mut outcome = ScanOutcome::Terminated;
+        let (r, _s) = self.scan_with(|scanner| {
+            let start = scanner.cursor_pos();
             scan_python_string_prefix(scanner)?;
-            scan_python_short_string(scanner)?;
+            // Include the prefix in the range of the opening delimiter
+            if let ScanOutcome::Unterminated(r) = scan_python_short_string(scanner)? {
+                outcome = ScanOutcome::Unterminated(start..r.end);
+            }
             Ok(())
-        })
+        })?;
+        Ok((r, outcome))
     }
 
     // Reference: https://docs.python.org/3/reference/lexical_analysis.html#string-and-bytes-literals
+    #[inline]
     fn scan_python_long_string(&mut self) -> ScannerResult<'text, &'text str> {
-        self.scan_with(|scanner| {
+        let (r, _outcome) = self.scan_python_long_string_outcome()?;
+        Ok(self.ranged_text(r))
+    }
+
+    // Reference: https://docs.python.org/3/reference/lexical_analysis.html#string-and-bytes-literals
+    fn scan_python_long_string_outcome(&mut self) -> ScannerResult<'text, ScanOutcome> {
+        let mut outcome = ScanOutcome::Terminated;
+        let (r, _s) = self.scan_with(|scanner| {
+            let start = scanner.cursor_pos();
             scan_python_string_prefix(scanner)?;
-            scan_python_long_string(scanner)?;
+            // Include the prefix in the range of the opening delimiter
+            if let ScanOutcome::Unterminated(r) = scan_python_long_string(scanner)? {
+                outcome = ScanOutcome::Unterminated(start..r.end);
+            }
             Ok(())
-        })
+        })?;
+        Ok((r, outcome))
     }
 
     // Reference: https://docs.python.org/3/reference/lexical_analysis.html#string-and-bytes-literals
@@ -303,6 +464,87 @@ impl<'text> PythonScannerExt<'text> for Scanner<'text> {
             Ok(())
         })
     }
+
+    // Reference: https://docs.python.org/2.7/reference/lexical_analysis.html#keywords
+    fn scan_python2_keyword(&mut self) -> ScannerResult<'text, &'text str> {
+        self.scan_with(|scanner| {
+            let (r, s) = scanner.scan_python_identifier()?;
+            if s.is_python2_keyword() {
+                Ok(())
+            } else {
+                Err(scanner.ranged_text(r))
+            }
+        })
+    }
+
+    // Reference: https://docs.python.org/2.7/reference/lexical_analysis.html#operators
+    fn scan_python2_operator(&mut self) -> ScannerResult<'text, &'text str> {
+        self.accept_str("<>")
+            .or_else(|_| self.scan_python_operator())
+    }
+
+    // Reference: https://docs.python.org/2.7/reference/lexical_analysis.html#integer-and-long-integer-literals
+    fn scan_python2_int_oct(&mut self) -> ScannerResult<'text, &'text str> {
+        self.scan_python_int_oct().or_else(|_| {
+            self.scan_with(|scanner| {
+                scanner.accept_char('0')?;
+                scanner.accept_if(CharExt::is_ascii_octdigit)?;
+                scanner.skip_while(CharExt::is_ascii_octdigit);
+                Ok(())
+            })
+        })
+    }
+
+    // Reference: https://docs.python.org/2.7/reference/lexical_analysis.html#string-literals
+    #[inline]
+    fn scan_python2_string(&mut self) -> ScannerResult<'text, &'text str> {
+        self.scan_python2_long_string()
+            .or_else(|_| self.scan_python2_short_string())
+    }
+
+    // Reference: https://docs.python.org/2.7/reference/lexical_analysis.html#string-literals
+    #[inline]
+    fn scan_python2_short_string(&mut self) -> ScannerResult<'text, &'text str> {
+        let (r, _outcome) = self.scan_python2_short_string_outcome()?;
+        Ok(self.ranged_text(r))
+    }
+
+    // Reference: https://docs.python.org/2.7/reference/lexical_analysis.html#string-literals
+    fn scan_python2_short_string_outcome(&mut self) -> ScannerResult<'text, ScanOutcome> {
+        let mut outcome = ScanOutcome::Terminated;
+        let (r, _s) = self.scan_with(|scanner| {
+            let start = scanner.cursor_pos();
+            scan_python2_string_prefix(scanner);
+            // Include the prefix in the range of the opening delimiter
+            if let ScanOutcome::Unterminated(r) = scan_python_short_string(scanner)? {
+                outcome = ScanOutcome::Unterminated(start..r.end);
+            }
+            Ok(())
+        })?;
+        Ok((r, outcome))
+    }
+
+    // Reference: https://docs.python.org/2.7/reference/lexical_analysis.html#string-literals
+    #[inline]
+    fn scan_python2_long_string(&mut self) -> ScannerResult<'text, &'text str> {
+        let (r, _outcome) = self.scan_python2_long_string_outcome()?;
+        Ok(self.ranged_text(r))
+    }
+
+    // Reference: https://docs.python.org/2.7/reference/lexical_analysis.html#string-literals
+    fn scan_python2_long_string_outcome(&mut self) -> ScannerResult<'text, ScanOutcome> {
+        let mut outcome = ScanOutcome::Terminated;
+        let (r, _s) = self.scan_with(|scanner| {
+            let start = scanner.cursor_pos();
+            scan_python2_string_prefix(scanner);
+            // Include the prefix in the range of the opening delimiter
+            if let ScanOutcome::Unterminated(r) = scan_python_long_string(scanner)? {
+                outcome = ScanOutcome::Unterminated(start..r.end);
+            }
+            Ok(())
+        })?;
+        Ok((r, outcome))
+    }
 }
 
 // Reference: https://docs.python.org/3/reference/lexical_analysis.html#string-and-bytes-literals
@@ -327,6 +569,13 @@ fn scan_python_string_prefix<'text>(scanner: &mut Scanner<'text>) -> ScanResult<
     Ok(())
 }
 
+// Reference: https://docs.python.org/2.7/reference/lexical_analysis.html#string-literals
+#[inline]
+fn scan_python2_string_prefix(scanner: &mut Scanner<'_>) {
+    _ = scanner.accept_char_any(&['u', 'U']);
+    _ = scanner.accept_char_any(&['r', 'R']);
+}
+
 // Reference: https://docs.python.org/3/reference/lexical_analysis.html#string-and-bytes-literals
 #[inline]
 fn scan_python_bytes_prefix<'text>(scanner: &mut Scanner<'text>) -> ScanResult<'text> {
@@ -349,8 +598,10 @@ fn scan_python_bytes_prefix<'text>(scanner: &mut Scanner<'text>) -> ScanResult<'
 
 // Reference: https://docs.python.org/3/reference/lexical_analysis.html#string-and-bytes-literals
 #[inline]
-fn scan_python_short_string<'text>(scanner: &mut Scanner<'text>) -> ScanResult<'text> {
-    let (_r, quote) = scanner.accept_char_any(&['"', '\''])?;
+fn scan_python_short_string<'text>(
+    scanner: &mut Scanner<'text>,
+) -> Result<ScanOutcome, ScannerItem<&'text str>> {
+    let (open_range, quote) = scanner.accept_char_any(&['"', '\''])?;
 
     loop {
         scanner.skip_until_char_any(&[quote, '\\', '\n']);
@@ -365,21 +616,23 @@ fn scan_python_short_string<'text>(scanner: &mut Scanner<'text>) -> ScanResult<'
                 // Note: Technically any character is not valid
                 _ = scanner.next();
             }
-            Ok((_r, '\n')) => break,
+            Ok((_, '\n')) | Err(_) => return Ok(ScanOutcome::Unterminated(open_range)),
             Ok(_) => unreachable!(),
-            Err(_) => break,
         }
     }
 
-    Ok(())
+    Ok(ScanOutcome::Terminated)
 }
 
 // Reference: https://docs.python.org/3/reference/lexical_analysis.html#string-and-bytes-literals
 #[inline]
-fn scan_python_long_string<'text>(scanner: &mut Scanner<'text>) -> ScanResult<'text> {
-    let (_r, quote) = scanner.accept_char_any(&['"', '\''])?;
-    scanner.accept_char(quote)?;
+fn scan_python_long_string<'text>(
+    scanner: &mut Scanner<'text>,
+) -> Result<ScanOutcome, ScannerItem<&'text str>> {
+    let (open_range, quote) = scanner.accept_char_any(&['"', '\''])?;
     scanner.accept_char(quote)?;
+    let (r, _c) = scanner.accept_char(quote)?;
+    let open_range = open_range.start..r.end;
 
     'scan: loop {
         scanner.skip_until_char_any(&[quote, '\\']);
@@ -402,16 +655,17 @@ fn scan_python_long_string<'text>(scanner: &mut Scanner<'text>) -> ScanResult<'t
                 _ = scanner.next();
             }
             Ok(_) => unreachable!(),
-            Err(_) => break,
+            Err(_) => return Ok(ScanOutcome::Unterminated(open_range)),
         }
     }
 
-    Ok(())
+    Ok(ScanOutcome::Terminated)
 }
 
 /// [`str`] extension for checking if a `&str` is e.g. a Python keyword.
 pub trait PythonStrExt {
     fn is_python_keyword(&self) -> bool;
+    fn is_python2_keyword(&self) -> bool;
     fn is_python_soft_keyword(&self) -> bool;
     fn is_python_operator(&self) -> bool;
     fn is_python_delimiter(&self) -> bool;
@@ -423,6 +677,11 @@ impl PythonStrExt for str {
         PYTHON_KEYWORDS.contains(&self)
     }
 
+    #[inline]
+    fn is_python2_keyword(&self) -> bool {
+        PYTHON2_KEYWORDS.contains(&self)
+    }
+
     #[inline]
     fn is_python_soft_keyword(&self) -> bool {
         PYTHON_SOFT_KEYWORDS.contains(&self)
@@ -604,11 +863,11 @@ mod tests {
             ("0+", Ok((0..1, "0")), "+"),
             //
             ("1_2", Ok((0..3, "1_2")), ""),
-            // FIXME: ("1_2_", Ok((0..3, "1_2")), ""),
+            ("1_2_", Ok((0..3, "1_2")), "_"),
             ("_1_2", Err((0..0, "")), "_1_2"),
             //
             // FIXME: ("0123", Err((0..1, "0")), "0123"),
-            // FIXME: ("12__34", Err((0..3, "12_")), "12__34"),
+            ("12__34", Err((0..3, "12_")), "12__34"),
             //
             ("-0", Err((0..0, "")), "-0"),
             ("-123", Err((0..0, "")), "-123"),
@@ -650,7 +909,9 @@ mod tests {
             ("0x_FF", Ok((0..5, "0x_FF")), ""),
             ("0x_F_F", Ok((0..6, "0x_F_F")), ""),
             ("0x_", Err((0..3, "0x_")), "0x_"),
-            // FIXME: ("0xF__F", Err((0..4, "0xF_")), "0xF__F"),
+            ("0xF__F", Err((0..4, "0xF_")), "0xF__F"),
+            ("0x__F", Err((0..3, "0x_")), "0x__F"),
+            ("0xF_", Ok((0..3, "0xF")), "_"),
             //
             ("0", Err((0..1, "0")), "0"),
             ("0x", Err((0..2, "0x")), "0x"),
@@ -694,7 +955,9 @@ mod tests {
             ("0o_77", Ok((0..5, "0o_77")), ""),
             ("0o_7_7", Ok((0..6, "0o_7_7")), ""),
             ("0o_", Err((0..3, "0o_")), "0o_"),
-            // FIXME: ("0o7__7", Err((0..4, "0o7_")), "0o7__7"),
+            ("0o7__7", Err((0..4, "0o7_")), "0o7__7"),
+            ("0o__7", Err((0..3, "0o_")), "0o__7"),
+            ("0o7_", Ok((0..3, "0o7")), "_"),
             //
             ("0", Err((0..1, "0")), "0"),
             ("0o", Err((0..2, "0o")), "0o"),
@@ -717,6 +980,56 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_python2_int_oct() {
+        let cases = [
+            // text, expected, remaining text
+            ("00", Ok((0..2, "00")), ""),
+            ("0777", Ok((0..4, "0777")), ""),
+            ("0777L", Ok((0..4, "0777")), "L"),
+            ("0o777", Ok((0..5, "0o777")), ""),
+            ("0o7_7", Ok((0..5, "0o7_7")), ""),
+            ("0778", Ok((0..3, "077")), "8"),
+            //
+            ("0", Err((0..1, "0")), "0"),
+            ("08", Err((0..1, "0")), "08"),
+            ("0_7", Err((0..1, "0")), "0_7"),
+            ("777", Err((0..0, "")), "777"),
+        ];
+
+        for (text, expected, remaining) in cases {
+            let mut scanner = Scanner::new(text);
+
+            let actual = scanner.scan_python2_int_oct();
+            assert_eq!(actual, expected);
+
+            assert_eq!(scanner.remaining_text(), remaining);
+        }
+    }
+
+    #[test]
+    fn test_python2_string() {
+        let cases = [
+            // text, expected, remaining text
+            ("u'x'", Ok((0..4, "u'x'")), ""),
+            (r"ur'\d'", Ok((0..6, r"ur'\d'")), ""),
+            (r#"UR"x""#, Ok((0..5, r#"UR"x""#)), ""),
+            ("r'''x'''", Ok((0..8, "r'''x'''")), ""),
+            //
+            ("f'x'", Err((0..0, "")), "f'x'"),
+            ("ru'x'", Err((0..1, "r")), "ru'x'"),
+        ];
+
+        for (text, expected, remaining) in cases {
+            let mut scanner = Scanner::new(text);
+
+            let actual = scanner.scan_python2_string();
+            assert_eq!(actual, expected);
+
+            assert_eq!(scanner.remaining_text(), remaining);
+        }
+    }
+
     #[test]
     fn test_python_int_bin() {
         let cases = [
@@ -750,7 +1063,9 @@ mod tests {
             ("0b_11", Ok((0..5, "0b_11")), ""),
             ("0b_1_1", Ok((0..6, "0b_1_1")), ""),
             ("0b_", Err((0..3, "0b_")), "0b_"),
-            // FIXME: ("0b1__1", Err((0..4, "0b1_")), "0b1__1"),
+            ("0b1__1", Err((0..4, "0b1_")), "0b1__1"),
+            ("0b__1", Err((0..3, "0b_")), "0b__1"),
+            ("0b1_", Ok((0..3, "0b1")), "_"),
         ];
 
         for (text, expected, remaining) in cases {
@@ -820,6 +1135,62 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_python_imaginary() {
+        let cases = [
+            // text, expected, remaining text
+            ("3j", Ok((0..2, "3j")), ""),
+            ("3J", Ok((0..2, "3J")), ""),
+            ("2.5J", Ok((0..4, "2.5J")), ""),
+            ("1.j", Ok((0..3, "1.j")), ""),
+            (".5j", Ok((0..3, ".5j")), ""),
+            ("1e3j", Ok((0..4, "1e3j")), ""),
+            ("1_000j", Ok((0..6, "1_000j")), ""),
+            ("0123j", Ok((0..5, "0123j")), ""),
+            //
+            ("3j ", Ok((0..2, "3j")), " "),
+            ("3j+1", Ok((0..2, "3j")), "+1"),
+            //
+            ("3", Err((0..1, "3")), "3"),
+            ("2.5", Err((0..3, "2.5")), "2.5"),
+            ("3 j", Err((0..1, "3")), "3 j"),
+            ("j", Err((0..0, "")), "j"),
+            ("-3j", Err((0..0, "")), "-3j"),
+        ];
+
+        for (text, expected, remaining) in cases {
+            let mut scanner = Scanner::new(text);
+
+            let actual = scanner.scan_python_imaginary();
+            assert_eq!(actual, expected);
+
+            assert_eq!(scanner.remaining_text(), remaining);
+        }
+    }
+
+    #[test]
+    fn test_python_ellipsis() {
+        let cases = [
+            // text, expected, remaining text
+            ("...", Ok((0..3, "...")), ""),
+            ("....", Ok((0..3, "...")), "."),
+            ("...)", Ok((0..3, "...")), ")"),
+            //
+            ("..", Err((0..2, "..")), ".."),
+            (". ..", Err((0..1, ".")), ". .."),
+            ("", Err((0..0, "")), ""),
+        ];
+
+        for (text, expected, remaining) in cases {
+            let mut scanner = Scanner::new(text);
+
+            let actual = scanner.scan_python_ellipsis();
+            assert_eq!(actual, expected);
+
+            assert_eq!(scanner.remaining_text(), remaining);
+        }
+    }
+
     #[test]
     fn test_python_short_string_double_quote() {
         let cases = [
@@ -1,7 +1,77 @@
-use crate::{CharExt, Scanner, ScannerResult};
+use super::NumberInfo;
+use crate::{IdentifierCharset, ScanOutcome, ScanResult, Scanner, ScannerResult, SeparatorPolicy};
+
+// Reference: https://doc.rust-lang.org/reference/tokens.html#integer-literals
+const RUST_DIGIT_SEPARATORS: SeparatorPolicy = SeparatorPolicy {
+    leading: false,
+    ..SeparatorPolicy::LENIENT
+};
+
+// Underscores are allowed directly after the base prefix, e.g. `0x_FF`
+const RUST_PREFIXED_DIGIT_SEPARATORS: SeparatorPolicy = SeparatorPolicy::LENIENT;
+
+/// Strict keywords, which can only be used where they are allowed,
+/// i.e. not as identifiers, in all editions.
+///
+/// Reference: <https://doc.rust-lang.org/reference/keywords.html#strict-keywords>
+#[rustfmt::skip]
+pub const RUST_KEYWORDS_STRICT: &[&str] = &[
+    "as", "break", "const", "continue", "crate", "else", "enum", "extern",
+    "false", "fn", "for", "if", "impl", "in", "let", "loop", "match", "mod",
+    "move", "mut", "pub", "ref", "return", "self", "Self", "static", "struct",
+    "super", "trait", "true", "type", "unsafe", "use", "where", "while",
+];
+
+/// Strict keywords added in the 2018 edition.
+///
+/// **Note:** In the 2015 edition, `dyn` is a weak keyword instead.
+///
+/// Reference: <https://doc.rust-lang.org/reference/keywords.html#strict-keywords>
+pub const RUST_KEYWORDS_STRICT_2018: &[&str] = &["async", "await", "dyn"];
+
+/// Keywords reserved for future use in all editions.
+///
+/// Reference: <https://doc.rust-lang.org/reference/keywords.html#reserved-keywords>
+#[rustfmt::skip]
+pub const RUST_KEYWORDS_RESERVED: &[&str] = &[
+    "abstract", "become", "box", "do", "final", "macro", "override", "priv",
+    "typeof", "unsized", "virtual", "yield",
+];
+
+/// Keywords reserved for future use in the 2018 edition and later.
+///
+/// Reference: <https://doc.rust-lang.org/reference/keywords.html#reserved-keywords>
+pub const RUST_KEYWORDS_RESERVED_2018: &[&str] = &["try"];
+
+/// Keywords reserved for future use in the 2024 edition and later.
+///
+/// Reference: <https://doc.rust-lang.org/reference/keywords.html#reserved-keywords>
+pub const RUST_KEYWORDS_RESERVED_2024: &[&str] = &["gen"];
+
+/// Weak keywords, which only have special meaning in certain contexts,
+/// e.g. `union` when declaring a union, and otherwise are identifiers.
+///
+/// **Note:** `'static` is a lifetime, and is not scanned as an identifier.
+///
+/// Reference: <https://doc.rust-lang.org/reference/keywords.html#weak-keywords>
+pub const RUST_KEYWORDS_WEAK: &[&str] = &["'static", "macro_rules", "raw", "safe", "union"];
+
+/// [Rust edition], which determines the keywords, see [`RustStrExt`].
+///
+/// [Rust edition]: https://doc.rust-lang.org/edition-guide/editions/
+#[derive(PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Copy, Default, Debug)]
+pub enum RustEdition {
+    Rust2015,
+    Rust2018,
+    #[default]
+    Rust2021,
+    Rust2024,
+}
 
 /// [`Scanner`] extension for scanning Rust tokens.
 ///
+/// See also [`RustStrExt`].
+///
 /// **Note:** When using the `scan_rust_*()` methods, the order they are
 /// called matters.
 pub trait RustScannerExt<'text>: crate::private::Sealed {
@@ -89,6 +159,31 @@ pub trait RustScannerExt<'text>: crate::private::Sealed {
     /// [Rust block comment]: https://doc.rust-lang.org/reference/comments.html
     fn scan_rust_block_comment(&mut self) -> ScannerResult<'text, &'text str>;
 
+    /// Same as [`scan_rust_block_comment()`], but additionally returns
+    /// whether the block comment is unterminated, including if a nested
+    /// block comment is unterminated.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use text_scanner::{ext::RustScannerExt, ScanOutcome, Scanner};
+    ///
+    /// let mut scanner = Scanner::new("/* /* */ */ /* /* */");
+    /// assert_eq!(
+    ///     scanner.scan_rust_block_comment_outcome(),
+    ///     Ok((0..11, ScanOutcome::Terminated)),
+    /// );
+    ///
+    /// scanner.skip_whitespace();
+    /// assert_eq!(
+    ///     scanner.scan_rust_block_comment_outcome(),
+    ///     Ok((12..20, ScanOutcome::Unterminated(12..14))),
+    /// );
+    /// ```
+    ///
+    /// [`scan_rust_block_comment()`]: Self::scan_rust_block_comment
+    fn scan_rust_block_comment_outcome(&mut self) -> ScannerResult<'text, ScanOutcome>;
+
     /// Scans a single [Rust identifier].
     ///
     /// **Note:** This **does not** differentiate between [Rust identifier]s
@@ -274,6 +369,30 @@ pub trait RustScannerExt<'text>: crate::private::Sealed {
     /// [Rust string]: https://doc.rust-lang.org/reference/tokens.html#string-literals
     fn scan_rust_string(&mut self) -> ScannerResult<'text, &'text str>;
 
+    /// Same as [`scan_rust_string()`], but additionally returns
+    /// whether the string is unterminated.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use text_scanner::{ext::RustScannerExt, ScanOutcome, Scanner};
+    ///
+    /// let mut scanner = Scanner::new(r#""terminated" "unterminated\""#);
+    /// assert_eq!(
+    ///     scanner.scan_rust_string_outcome(),
+    ///     Ok((0..12, ScanOutcome::Terminated)),
+    /// );
+    ///
+    /// scanner.skip_whitespace();
+    /// assert_eq!(
+    ///     scanner.scan_rust_string_outcome(),
+    ///     Ok((13..28, ScanOutcome::Unterminated(13..14))),
+    /// );
+    /// ```
+    ///
+    /// [`scan_rust_string()`]: Self::scan_rust_string
+    fn scan_rust_string_outcome(&mut self) -> ScannerResult<'text, ScanOutcome>;
+
     /// Scans a single [raw Rust string].
     ///
     /// **Note:** This has the same lifetime as the original `text`,
@@ -316,6 +435,31 @@ pub trait RustScannerExt<'text>: crate::private::Sealed {
     /// [raw Rust string]: https://doc.rust-lang.org/reference/tokens.html#string-literals
     fn scan_rust_raw_string(&mut self) -> ScannerResult<'text, &'text str>;
 
+    /// Same as [`scan_rust_raw_string()`], but additionally returns
+    /// whether the raw string is unterminated, where the range of the
+    /// opening delimiter includes the `r` and `#`s.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use text_scanner::{ext::RustScannerExt, ScanOutcome, Scanner};
+    ///
+    /// let mut scanner = Scanner::new(r###"r#"terminated"# r##"unterminated"#"###);
+    /// assert_eq!(
+    ///     scanner.scan_rust_raw_string_outcome(),
+    ///     Ok((0..15, ScanOutcome::Terminated)),
+    /// );
+    ///
+    /// scanner.skip_whitespace();
+    /// assert_eq!(
+    ///     scanner.scan_rust_raw_string_outcome(),
+    ///     Ok((16..34, ScanOutcome::Unterminated(16..20))),
+    /// );
+    /// ```
+    ///
+    /// [`scan_rust_raw_string()`]: Self::scan_rust_raw_string
+    fn scan_rust_raw_string_outcome(&mut self) -> ScannerResult<'text, ScanOutcome>;
+
     /// Scans a single [Rust integer decimal literal].
     ///
     /// **Note:** Rust integer literals do not allow a sign in front
@@ -531,6 +675,48 @@ pub trait RustScannerExt<'text>: crate::private::Sealed {
     ///
     /// [Rust floating-point literal]: https://doc.rust-lang.org/reference/tokens.html#floating-point-literals
     fn scan_rust_float(&mut self) -> ScannerResult<'text, &'text str>;
+
+    /// Scans a single [Rust integer literal] or [Rust floating-point literal],
+    /// including its suffix, e.g. `u8` in `0xFF_u8`, and returns its
+    /// [`NumberInfo`].
+    ///
+    /// Any identifier directly following the literal is considered its
+    /// suffix, same as `rustc`'s lexer.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use text_scanner::{ext::RustScannerExt, Scanner};
+    ///
+    /// let text = "0b1010 1_000i64 0o7_u8 1.5e3f32 2f64 1.0";
+    ///
+    /// let numbers = [
+    ///     // (range, radix, digits_range, suffix_range, is_float)
+    ///     (0..6,   2,  2..6,   None,         false),
+    ///     (7..15,  10, 7..12,  Some(12..15), false),
+    ///     (16..22, 8,  18..20, Some(20..22), false),
+    ///     (23..31, 10, 23..28, Some(28..31), true),
+    ///     (32..36, 10, 32..33, Some(33..36), true),
+    ///     (37..40, 10, 37..40, None,         true),
+    /// ];
+    ///
+    /// let mut scanner = Scanner::new(text);
+    /// for (range, radix, digits_range, suffix_range, is_float) in numbers {
+    ///     scanner.skip_whitespace();
+    ///     let (r, info) = scanner.scan_rust_number_info().unwrap();
+    ///     assert_eq!(r, range);
+    ///     assert_eq!(info.radix, radix);
+    ///     assert_eq!(info.digits_range, digits_range);
+    ///     assert_eq!(info.suffix_range, suffix_range);
+    ///     assert_eq!(info.is_float, is_float);
+    /// }
+    ///
+    /// # assert_eq!(scanner.remaining_text(), "");
+    /// ```
+    ///
+    /// [Rust integer literal]: https://doc.rust-lang.org/reference/tokens.html#integer-literals
+    /// [Rust floating-point literal]: https://doc.rust-lang.org/reference/tokens.html#floating-point-literals
+    fn scan_rust_number_info(&mut self) -> ScannerResult<'text, NumberInfo>;
 }
 
 impl<'text> RustScannerExt<'text> for Scanner<'text> {
@@ -545,42 +731,21 @@ impl<'text> RustScannerExt<'text> for Scanner<'text> {
     }
 
     // Reference: https://doc.rust-lang.org/reference/comments.html
+    #[inline]
     fn scan_rust_block_comment(&mut self) -> ScannerResult<'text, &'text str> {
-        self.scan_with(|scanner| {
-            scanner.accept_str("/*")?;
-            let mut open = 1;
-            loop {
-                scanner.skip_until_char_any(&['*', '/']);
+        let (r, _outcome) = self.scan_rust_block_comment_outcome()?;
+        Ok(self.ranged_text(r))
+    }
 
-                match scanner.next() {
-                    Ok((_r, '*')) => {
-                        if let Ok((_r, '/')) = scanner.next() {
-                            if open == 1 {
-                                break;
-                            }
-                            open -= 1;
-                        }
-                    }
-                    Ok((_r, '/')) => {
-                        if let Ok((_r, '*')) = scanner.next() {
-                            open += 1;
-                        }
-                    }
-                    Ok((_r, _c)) => {}
-                    Err(_) => break,
-                }
-            }
-            Ok(())
-        })
+    // Reference: https://doc.rust-lang.org/reference/comments.html
+    #[inline]
+    fn scan_rust_block_comment_outcome(&mut self) -> ScannerResult<'text, ScanOutcome> {
+        self.scan_block_comment_outcome("/*", "*/", true)
     }
 
     // Reference: https://doc.rust-lang.org/reference/identifiers.html
     fn scan_rust_identifier(&mut self) -> ScannerResult<'text, &'text str> {
-        self.scan_with(|scanner| {
-            scanner.accept_if(|c| c.is_alphabetic() || (c == '_'))?;
-            scanner.skip_while(|c| c.is_alphanumeric() || (c == '_'));
-            Ok(())
-        })
+        self.scan_identifier_with_charset(IdentifierCharset::XID)
     }
 
     // Reference: https://doc.rust-lang.org/reference/identifiers.html
@@ -601,7 +766,19 @@ impl<'text> RustScannerExt<'text> for Scanner<'text> {
             if c == '\\' {
                 // Skip the next character as it is escaped
                 // Note: Technically any character is not valid
-                _ = scanner.next();
+                match scanner.next() {
+                    // Skip the digits of `\x7F`
+                    Ok((_r, 'x')) => {
+                        _ = scanner.accept_if(|c| c.is_ascii_hexdigit());
+                        _ = scanner.accept_if(|c| c.is_ascii_hexdigit());
+                    }
+                    // Skip the digits of `\u{1F980}`
+                    Ok((_r, 'u')) if scanner.accept_char('{').is_ok() => {
+                        scanner.skip_until_char_any(&['}', '\'', '\n']);
+                        _ = scanner.accept_char('}');
+                    }
+                    _ => {}
+                }
             }
 
             scanner.accept_char('\'')?;
@@ -610,9 +787,17 @@ impl<'text> RustScannerExt<'text> for Scanner<'text> {
     }
 
     // Reference: https://doc.rust-lang.org/reference/tokens.html#string-literals
+    #[inline]
     fn scan_rust_string(&mut self) -> ScannerResult<'text, &'text str> {
-        self.scan_with(|scanner| {
-            scanner.accept_char('"')?;
+        let (r, _outcome) = self.scan_rust_string_outcome()?;
+        Ok(self.ranged_text(r))
+    }
+
+    // Reference: https://doc.rust-lang.org/reference/tokens.html#string-literals
+    fn scan_rust_string_outcome(&mut self) -> ScannerResult<'text, ScanOutcome> {
+        let mut outcome = ScanOutcome::Terminated;
+        let (r, _s) = self.scan_with(|scanner| {
+            let (open_range, _c) = scanner.accept_char('"')?;
 
             loop {
                 scanner.skip_until_char_any(&['"', '\\']);
@@ -624,25 +809,38 @@ impl<'text> RustScannerExt<'text> for Scanner<'text> {
                         _ = scanner.next();
                     }
                     Ok(_) => unreachable!(),
-                    Err(_) => break,
+                    Err(_) => {
+                        outcome = ScanOutcome::Unterminated(open_range);
+                        break;
+                    }
                 }
             }
 
             Ok(())
-        })
+        })?;
+        Ok((r, outcome))
     }
 
     // Reference: https://doc.rust-lang.org/reference/tokens.html#raw-string-literals
+    #[inline]
     fn scan_rust_raw_string(&mut self) -> ScannerResult<'text, &'text str> {
-        self.scan_with(|scanner| {
-            scanner.accept_char('r')?;
+        let (r, _outcome) = self.scan_rust_raw_string_outcome()?;
+        Ok(self.ranged_text(r))
+    }
+
+    // Reference: https://doc.rust-lang.org/reference/tokens.html#raw-string-literals
+    fn scan_rust_raw_string_outcome(&mut self) -> ScannerResult<'text, ScanOutcome> {
+        let mut outcome = ScanOutcome::Terminated;
+        let (r, _s) = self.scan_with(|scanner| {
+            let (r_range, _c) = scanner.accept_char('r')?;
             let hashes = scanner.skip_while_char('#').0.len();
-            scanner.accept_char('"')?;
+            let (quote_range, _c) = scanner.accept_char('"')?;
 
             'scan: loop {
                 scanner.skip_until_char('"');
 
                 if scanner.next().is_err() {
+                    outcome = ScanOutcome::Unterminated(r_range.start..quote_range.end);
                     break;
                 }
 
@@ -660,16 +858,13 @@ impl<'text> RustScannerExt<'text> for Scanner<'text> {
             }
 
             Ok(())
-        })
+        })?;
+        Ok((r, outcome))
     }
 
     // Reference: https://doc.rust-lang.org/reference/tokens.html#integer-literals
     fn scan_rust_int_dec(&mut self) -> ScannerResult<'text, &'text str> {
-        self.scan_with(|scanner| {
-            scanner.accept_if_ext(char::is_ascii_digit)?;
-            scanner.skip_while(|c| c.is_ascii_digit() || (c == '_'));
-            Ok(())
-        })
+        self.scan_digits_sep(10, '_', RUST_DIGIT_SEPARATORS)
     }
 
     // Reference: https://doc.rust-lang.org/reference/tokens.html#integer-literals
@@ -677,10 +872,7 @@ impl<'text> RustScannerExt<'text> for Scanner<'text> {
         self.scan_with(|scanner| {
             scanner.accept_str("0x")?;
 
-            scanner.skip_while_char('_');
-            scanner.accept_if_ext(char::is_ascii_hexdigit)?;
-
-            scanner.skip_while(|c| c.is_ascii_hexdigit() || (c == '_'));
+            scanner.scan_digits_sep(16, '_', RUST_PREFIXED_DIGIT_SEPARATORS)?;
 
             Ok(())
         })
@@ -691,10 +883,7 @@ impl<'text> RustScannerExt<'text> for Scanner<'text> {
         self.scan_with(|scanner| {
             scanner.accept_str("0o")?;
 
-            scanner.skip_while_char('_');
-            scanner.accept_if(CharExt::is_ascii_octdigit)?;
-
-            scanner.skip_while(|c| CharExt::is_ascii_octdigit(c) || (c == '_'));
+            scanner.scan_digits_sep(8, '_', RUST_PREFIXED_DIGIT_SEPARATORS)?;
 
             Ok(())
         })
@@ -705,10 +894,7 @@ impl<'text> RustScannerExt<'text> for Scanner<'text> {
         self.scan_with(|scanner| {
             scanner.accept_str("0b")?;
 
-            scanner.skip_while_char('_');
-            scanner.accept_if(CharExt::is_ascii_bindigit)?;
-
-            scanner.skip_while(|c| c.is_ascii_bindigit() || (c == '_'));
+            scanner.scan_digits_sep(2, '_', RUST_PREFIXED_DIGIT_SEPARATORS)?;
 
             Ok(())
         })
@@ -718,19 +904,136 @@ impl<'text> RustScannerExt<'text> for Scanner<'text> {
     fn scan_rust_float(&mut self) -> ScannerResult<'text, &'text str> {
         self.scan_with(|scanner| {
             scanner.scan_rust_int_dec()?;
-            scanner.accept_char('.')?;
 
-            if scanner.scan_rust_int_dec().is_ok() && scanner.accept_char_any(&['e', 'E']).is_ok() {
-                _ = scanner.accept_char_any(&['+', '-']);
+            if scanner.accept_char('.').is_err() {
+                // Without a fraction, then the exponent is required, e.g. `1e10`
+                return scan_rust_float_exponent(scanner);
+            }
+
+            // A `.` followed by `.` or an identifier is a range or
+            // a field access, e.g. `0..1` and `1.max(2)`
+            if let Ok((r, c)) = scanner.peek() {
+                if (c == '.') || (c == '_') || c.is_alphabetic() {
+                    return Err(scanner.ranged_text(r));
+                }
+            }
 
-                scanner.skip_while_char('_');
-                scanner.accept_if_ext(char::is_ascii_digit)?;
-                scanner.skip_while(|c| c.is_ascii_digit() || (c == '_'));
+            if scanner.scan_rust_int_dec().is_ok() {
+                _ = scan_rust_float_exponent(scanner);
             }
 
             Ok(())
         })
     }
+
+    // Reference: https://doc.rust-lang.org/reference/tokens.html#number-literals
+    fn scan_rust_number_info(&mut self) -> ScannerResult<'text, NumberInfo> {
+        let mut is_float = false;
+        let (r, literal) = match self
+            .scan_rust_int_hex()
+            .or_else(|_| self.scan_rust_int_oct())
+            .or_else(|_| self.scan_rust_int_bin())
+        {
+            Ok(item) => item,
+            Err(_) => match self.scan_rust_float() {
+                Ok(item) => {
+                    is_float = true;
+                    item
+                }
+                Err(_) => self.scan_rust_int_dec()?,
+            },
+        };
+
+        let (suffix_range, suffix) = self.scan_rust_identifier().unwrap_or((r.end..r.end, ""));
+
+        const PREFIXES: &[(&str, u32)] = &[("0x", 16), ("0o", 8), ("0b", 2)];
+        let mut info = NumberInfo::new(r.start, literal, suffix_range.clone(), PREFIXES, is_float);
+        // e.g. `1f32`, while `0x1f32` is an integer
+        info.is_float |= (info.radix == 10) && matches!(suffix, "f32" | "f64");
+
+        Ok((r.start..suffix_range.end, info))
+    }
+}
+
+fn scan_rust_float_exponent<'text>(scanner: &mut Scanner<'text>) -> ScanResult<'text> {
+    scanner.scan_with(|scanner| {
+        scanner.accept_char_any(&['e', 'E'])?;
+        _ = scanner.accept_char_any(&['+', '-']);
+
+        // Underscores are also allowed directly after the exponent, e.g. `1e_5`
+        scanner.scan_digits_sep(10, '_', RUST_PREFIXED_DIGIT_SEPARATORS)?;
+
+        Ok(())
+    })?;
+    Ok(())
+}
+
+/// [`str`] extension for checking if a `&str` is a Rust keyword
+/// in a given [`RustEdition`].
+///
+/// # Example
+///
+/// ```rust
+/// use text_scanner::ext::{RustEdition, RustStrExt};
+///
+/// assert!("fn".is_rust_keyword(RustEdition::Rust2015));
+///
+/// assert!(!"dyn".is_rust_keyword(RustEdition::Rust2015));
+/// assert!("dyn".is_rust_weak_keyword(RustEdition::Rust2015));
+/// assert!("dyn".is_rust_strict_keyword(RustEdition::Rust2018));
+///
+/// assert!(!"gen".is_rust_keyword(RustEdition::Rust2021));
+/// assert!("gen".is_rust_reserved_keyword(RustEdition::Rust2024));
+///
+/// // Weak keywords are valid identifiers
+/// assert!(!"union".is_rust_keyword(RustEdition::Rust2021));
+/// assert!("union".is_rust_weak_keyword(RustEdition::Rust2021));
+/// ```
+pub trait RustStrExt {
+    /// Returns `true` if `self` is a strict or reserved keyword in `edition`,
+    /// i.e. a keyword which cannot be used as an identifier, except as a raw
+    /// identifier, e.g. `r#fn`.
+    ///
+    /// Weak keywords are not included, see [`is_rust_weak_keyword()`].
+    ///
+    /// [`is_rust_weak_keyword()`]: Self::is_rust_weak_keyword
+    fn is_rust_keyword(&self, edition: RustEdition) -> bool;
+
+    /// Returns `true` if `self` is a strict keyword in `edition`, see
+    /// [`RUST_KEYWORDS_STRICT`] and [`RUST_KEYWORDS_STRICT_2018`].
+    fn is_rust_strict_keyword(&self, edition: RustEdition) -> bool;
+
+    /// Returns `true` if `self` is a reserved keyword in `edition`, see
+    /// [`RUST_KEYWORDS_RESERVED`], [`RUST_KEYWORDS_RESERVED_2018`],
+    /// and [`RUST_KEYWORDS_RESERVED_2024`].
+    fn is_rust_reserved_keyword(&self, edition: RustEdition) -> bool;
+
+    /// Returns `true` if `self` is a weak keyword in `edition`, see
+    /// [`RUST_KEYWORDS_WEAK`], which includes `dyn` in the 2015 edition.
+    fn is_rust_weak_keyword(&self, edition: RustEdition) -> bool;
+}
+
+impl RustStrExt for str {
+    #[inline]
+    fn is_rust_keyword(&self, edition: RustEdition) -> bool {
+        self.is_rust_strict_keyword(edition) || self.is_rust_reserved_keyword(edition)
+    }
+
+    fn is_rust_strict_keyword(&self, edition: RustEdition) -> bool {
+        RUST_KEYWORDS_STRICT.contains(&self)
+            || ((edition >= RustEdition::Rust2018) && RUST_KEYWORDS_STRICT_2018.contains(&self))
+    }
+
+    fn is_rust_reserved_keyword(&self, edition: RustEdition) -> bool {
+        RUST_KEYWORDS_RESERVED.contains(&self)
+            || ((edition >= RustEdition::Rust2018) && RUST_KEYWORDS_RESERVED_2018.contains(&self))
+            || ((edition >= RustEdition::Rust2024) && RUST_KEYWORDS_RESERVED_2024.contains(&self))
+    }
+
+    fn is_rust_weak_keyword(&self, edition: RustEdition) -> bool {
+        RUST_KEYWORDS_WEAK.contains(&self)
+            || ((edition == RustEdition::Rust2015) && (self == "dyn"))
+    }
 }
 
 #[cfg(test)]
@@ -875,6 +1178,21 @@ mod tests {
 
         assert_eq!(scanner.skip_whitespace().0, 429..429);
         assert_eq!(scanner.remaining_text(), "");
+
+        #[rustfmt::skip]
+        let cases = [
+            // text, expected, remaining text
+            ("/* **/ x",         Ok((0..6, "/* **/")),          " x"),
+            ("/***/ x",          Ok((0..5, "/***/")),           " x"),
+            ("/*/**/*/ x",       Ok((0..8, "/*/**/*/")),        " x"),
+            ("/* /* **/ */ x",   Ok((0..12, "/* /* **/ */")),   " x"),
+            ("/* //* */ */ x",   Ok((0..12, "/* //* */ */")),   " x"),
+        ];
+        for (text, expected, remaining) in cases {
+            let mut scanner = Scanner::new(text);
+            assert_eq!(scanner.scan_rust_block_comment(), expected, "{text:?}");
+            assert_eq!(scanner.remaining_text(), remaining, "{text:?}");
+        }
     }
 
     #[test]
@@ -990,4 +1308,148 @@ mod tests {
             assert_eq!(scanner.remaining_text(), remaining);
         }
     }
+
+    #[test]
+    fn test_chars() {
+        let cases = [
+            // text, expected, remaining text
+            ("'a'", Some("'a'"), ""),
+            ("'\\''", Some("'\\''"), ""),
+            ("'\\x7F'", Some("'\\x7F'"), ""),
+            ("'\\u{85}'", Some("'\\u{85}'"), ""),
+            ("'\\u{1F980}'.", Some("'\\u{1F980}'"), "."),
+            ("'🦀'", Some("'🦀'"), ""),
+            ("'a", None, "'a"),
+            ("'ab'", None, "'ab'"),
+            ("'a: loop", None, "'a: loop"),
+        ];
+
+        for (text, expected, remaining) in cases {
+            let mut scanner = Scanner::new(text);
+
+            let actual = scanner.scan_rust_char().map(|(_, s)| s).ok();
+            assert_eq!(actual, expected, "{text:?}");
+
+            assert_eq!(scanner.remaining_text(), remaining, "{text:?}");
+        }
+    }
+
+    #[test]
+    fn test_floats() {
+        let cases = [
+            // text, expected, remaining text
+            ("1.", Some("1."), ""),
+            ("1.0", Some("1.0"), ""),
+            ("1e10", Some("1e10"), ""),
+            ("1E+1_0", Some("1E+1_0"), ""),
+            ("1.5e-3", Some("1.5e-3"), ""),
+            ("1.5e", Some("1.5"), "e"),
+            ("1.)", Some("1."), ")"),
+            ("1", None, "1"),
+            ("1e", None, "1e"),
+            ("0..1", None, "0..1"),
+            ("1.max(2)", None, "1.max(2)"),
+            ("1._0", None, "1._0"),
+        ];
+
+        for (text, expected, remaining) in cases {
+            let mut scanner = Scanner::new(text);
+
+            let actual = scanner.scan_rust_float().map(|(_, s)| s).ok();
+            assert_eq!(actual, expected, "{text:?}");
+
+            assert_eq!(scanner.remaining_text(), remaining, "{text:?}");
+        }
+    }
+
+    #[test]
+    fn test_number_info() {
+        let info = |radix, digits_range, suffix_range, is_float| NumberInfo {
+            radix,
+            digits_range,
+            suffix_range,
+            is_float,
+        };
+
+        #[rustfmt::skip]
+        let cases = [
+            ("0",       Ok((0..1, info(10, 0..1, None,       false))), ""),
+            ("0x1f32",  Ok((0..6, info(16, 2..6, None,       false))), ""),
+            ("1f32",    Ok((0..4, info(10, 0..1, Some(1..4), true))),  ""),
+            ("1_usize", Ok((0..7, info(10, 0..2, Some(2..7), false))), ""),
+            ("1.max",   Ok((0..1, info(10, 0..1, None,       false))), ".max"),
+            ("1..2",    Ok((0..1, info(10, 0..1, None,       false))), "..2"),
+            ("0b102",   Ok((0..4, info(2,  2..4, None,       false))), "2"),
+            ("x",       Err((0..0, "")),                               "x"),
+        ];
+        for (text, expected, remaining) in cases {
+            let mut scanner = Scanner::new(text);
+            assert_eq!(scanner.scan_rust_number_info(), expected, "{text:?}");
+            assert_eq!(scanner.remaining_text(), remaining, "{text:?}");
+        }
+    }
+
+    #[test]
+    fn test_outcome() {
+        use ScanOutcome::{Terminated, Unterminated};
+
+        #[rustfmt::skip]
+        let cases: [(&str, crate::ScanFn<ScanOutcome>, _); 10] = [
+            ("\"\"",         |s| s.scan_rust_string_outcome(),        Ok((0..2, Terminated))),
+            ("\"",           |s| s.scan_rust_string_outcome(),        Ok((0..1, Unterminated(0..1)))),
+            ("\"\\\"",       |s| s.scan_rust_string_outcome(),        Ok((0..3, Unterminated(0..1)))),
+            ("r#\"\"#",      |s| s.scan_rust_raw_string_outcome(),    Ok((0..5, Terminated))),
+            ("r#\"\"",       |s| s.scan_rust_raw_string_outcome(),    Ok((0..4, Unterminated(0..3)))),
+            ("r\"",          |s| s.scan_rust_raw_string_outcome(),    Ok((0..2, Unterminated(0..2)))),
+            ("/**/",        |s| s.scan_rust_block_comment_outcome(), Ok((0..4, Terminated))),
+            ("/*",          |s| s.scan_rust_block_comment_outcome(), Ok((0..2, Unterminated(0..2)))),
+            ("/* /* */",    |s| s.scan_rust_block_comment_outcome(), Ok((0..8, Unterminated(0..2)))),
+            ("x",           |s| s.scan_rust_string_outcome(),        Err((0..0, ""))),
+        ];
+        for (text, f, expected) in cases {
+            let mut scanner = Scanner::new(text);
+            assert_eq!(f(&mut scanner), expected, "{text:?}");
+        }
+    }
+
+    #[test]
+    fn test_keywords() {
+        use RustEdition::*;
+
+        // (ident, [2015, 2018, 2021, 2024])
+        #[rustfmt::skip]
+        let cases = [
+            ("fn",          [true,  true,  true,  true]),
+            ("Self",        [true,  true,  true,  true]),
+            ("async",       [false, true,  true,  true]),
+            ("dyn",         [false, true,  true,  true]),
+            ("try",         [false, true,  true,  true]),
+            ("gen",         [false, false, false, true]),
+            ("yield",       [true,  true,  true,  true]),
+            ("union",       [false, false, false, false]),
+            ("macro_rules", [false, false, false, false]),
+            ("foo",         [false, false, false, false]),
+            ("r#fn",        [false, false, false, false]),
+        ];
+        for (ident, expected) in cases {
+            for (edition, expected) in [Rust2015, Rust2018, Rust2021, Rust2024]
+                .into_iter()
+                .zip(expected)
+            {
+                assert_eq!(
+                    ident.is_rust_keyword(edition),
+                    expected,
+                    "{ident:?} {edition:?}"
+                );
+            }
+        }
+
+        assert!("dyn".is_rust_weak_keyword(Rust2015));
+        assert!(!"dyn".is_rust_weak_keyword(Rust2018));
+        assert!("union".is_rust_weak_keyword(Rust2024));
+        assert!(!"union".is_rust_strict_keyword(Rust2024));
+        assert!("yield".is_rust_reserved_keyword(Rust2015));
+        assert!(!"yield".is_rust_strict_keyword(Rust2015));
+        assert_eq!(RustEdition::default(), Rust2021);
+    }
 }
@@ -1,4 +1,7 @@
-use crate::{ext::CScannerExt, Scanner, ScannerResult};
+use crate::{
+    ext::{CScannerExt, CssScannerExt},
+    Scanner, ScannerResult,
+};
 
 /// [`Scanner`] extension for scanning SCSS tokens.
 ///
@@ -7,6 +10,77 @@ use crate::{ext::CScannerExt, Scanner, ScannerResult};
 /// [`CssScannerExt`]: super::CssScannerExt
 pub trait ScssScannerExt<'text>: crate::private::Sealed {
     fn scan_scss_line_comment(&mut self) -> ScannerResult<'text, &'text str>;
+
+    /// Scans a single [SCSS interpolation], i.e. `#{...}`.
+    ///
+    /// Nested braces and strings inside the interpolation are skipped,
+    /// such that `#{map-get($map, "}")}` is scanned as a single interpolation.
+    ///
+    /// **Note:** This has the same lifetime as the original `text`,
+    /// so the scanner can continue to be used while this exists.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use text_scanner::{ext::ScssScannerExt, Scanner};
+    ///
+    /// let text = r#"
+    ///   #{$name}
+    ///   #{if($a, "}", {})}
+    ///   #{unterminated
+    /// "#;
+    ///
+    /// let interpolations = [
+    ///     (3..11,  "#{$name}"),
+    ///     (14..32, r#"#{if($a, "}", {})}"#),
+    ///     (35..50, "#{unterminated\n"),
+    /// ];
+    ///
+    /// let mut scanner = Scanner::new(text);
+    /// for interpolation in interpolations {
+    ///     scanner.skip_whitespace();
+    ///     assert_eq!(scanner.scan_scss_interpolation(), Ok(interpolation));
+    /// }
+    ///
+    /// # scanner.skip_whitespace();
+    /// # assert_eq!(scanner.remaining_text(), "");
+    /// ```
+    ///
+    /// [SCSS interpolation]: https://sass-lang.com/documentation/interpolation/
+    fn scan_scss_interpolation(&mut self) -> ScannerResult<'text, &'text str>;
+
+    /// Scans a single [SCSS placeholder selector], i.e. `%name`.
+    ///
+    /// **Note:** This has the same lifetime as the original `text`,
+    /// so the scanner can continue to be used while this exists.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use text_scanner::{ext::ScssScannerExt, Scanner};
+    ///
+    /// let text = r#"
+    ///   %foo
+    ///   %foo-bar
+    /// "#;
+    ///
+    /// let placeholders = [
+    ///     (3..7,   "%foo"),
+    ///     (10..18, "%foo-bar"),
+    /// ];
+    ///
+    /// let mut scanner = Scanner::new(text);
+    /// for placeholder in placeholders {
+    ///     scanner.skip_whitespace();
+    ///     assert_eq!(scanner.scan_scss_placeholder(), Ok(placeholder));
+    /// }
+    ///
+    /// # scanner.skip_whitespace();
+    /// # assert_eq!(scanner.remaining_text(), "");
+    /// ```
+    ///
+    /// [SCSS placeholder selector]: https://sass-lang.com/documentation/style-rules/placeholder-selectors/
+    fn scan_scss_placeholder(&mut self) -> ScannerResult<'text, &'text str>;
 }
 
 impl<'text> ScssScannerExt<'text> for Scanner<'text> {
@@ -14,4 +88,88 @@ impl<'text> ScssScannerExt<'text> for Scanner<'text> {
     fn scan_scss_line_comment(&mut self) -> ScannerResult<'text, &'text str> {
         self.scan_c_line_comment()
     }
+
+    // Reference: https://sass-lang.com/documentation/interpolation/
+    fn scan_scss_interpolation(&mut self) -> ScannerResult<'text, &'text str> {
+        self.scan_with(|scanner| {
+            scanner.accept_str("#{")?;
+
+            let mut depth = 0usize;
+            loop {
+                scanner.skip_until(|c| matches!(c, '{' | '}' | '"' | '\''));
+
+                if scanner.scan_css_string().is_ok() {
+                    continue;
+                }
+
+                match scanner.next() {
+                    Ok((_r, '{')) => depth += 1,
+                    Ok((_r, '}')) if depth == 0 => break,
+                    Ok((_r, '}')) => depth -= 1,
+                    Ok(_) => unreachable!(),
+                    Err(_) => break,
+                }
+            }
+
+            Ok(())
+        })
+    }
+
+    // Reference: https://sass-lang.com/documentation/style-rules/placeholder-selectors/
+    fn scan_scss_placeholder(&mut self) -> ScannerResult<'text, &'text str> {
+        self.scan_with(|scanner| {
+            scanner.accept_char('%')?;
+            scanner.scan_css_identifier()?;
+            Ok(())
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_scss_interpolation() {
+        let cases = [
+            ("#{}", Ok((0..3, "#{}")), ""),
+            ("#{$x}", Ok((0..5, "#{$x}")), ""),
+            ("#{$x}-foo", Ok((0..5, "#{$x}")), "-foo"),
+            ("#{{}}", Ok((0..5, "#{{}}")), ""),
+            ("#{'}'}", Ok((0..6, "#{'}'}")), ""),
+            ("#{\"{\"}}", Ok((0..6, "#{\"{\"}")), "}"),
+            ("#{$x", Ok((0..4, "#{$x")), ""),
+            //
+            ("", Err((0..0, "")), ""),
+            ("#", Err((0..1, "#")), "#"),
+            ("#foo", Err((0..1, "#")), "#foo"),
+            ("{}", Err((0..0, "")), "{}"),
+        ];
+
+        for (text, expected, remaining) in cases {
+            let mut scanner = Scanner::new(text);
+            assert_eq!(scanner.scan_scss_interpolation(), expected, "{text:?}");
+            assert_eq!(scanner.remaining_text(), remaining, "{text:?}");
+        }
+    }
+
+    #[test]
+    fn test_scss_placeholder() {
+        let cases = [
+            ("%foo", Ok((0..4, "%foo")), ""),
+            ("%foo-bar {", Ok((0..8, "%foo-bar")), " {"),
+            ("%--x", Ok((0..4, "%--x")), ""),
+            //
+            ("", Err((0..0, "")), ""),
+            ("%", Err((0..1, "%")), "%"),
+            ("% foo", Err((0..1, "%")), "% foo"),
+            ("%1", Err((0..1, "%")), "%1"),
+        ];
+
+        for (text, expected, remaining) in cases {
+            let mut scanner = Scanner::new(text);
+            assert_eq!(scanner.scan_scss_placeholder(), expected, "{text:?}");
+            assert_eq!(scanner.remaining_text(), remaining, "{text:?}");
+        }
+    }
 }
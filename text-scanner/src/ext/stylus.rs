@@ -0,0 +1,72 @@
+use crate::{
+    ext::{CScannerExt, CssScannerExt},
+    Scanner, ScannerResult,
+};
+
+/// [`Scanner`] extension for scanning [Stylus] tokens.
+///
+/// See also [`CssScannerExt`].
+///
+/// [Stylus]: https://stylus-lang.com
+/// [`CssScannerExt`]: super::CssScannerExt
+pub trait StylusScannerExt<'text>: crate::private::Sealed {
+    fn scan_stylus_line_comment(&mut self) -> ScannerResult<'text, &'text str>;
+    fn scan_stylus_block_comment(&mut self) -> ScannerResult<'text, &'text str>;
+
+    /// Scans a single `$`-prefixed [Stylus variable], e.g. `$color`.
+    ///
+    /// **Note:** Stylus variables are not required to be prefixed with `$`,
+    /// in which case they are indistinguishable from identifiers.
+    ///
+    /// **Note:** This has the same lifetime as the original `text`,
+    /// so the scanner can continue to be used while this exists.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use text_scanner::{ext::StylusScannerExt, Scanner};
+    ///
+    /// let text = r#"
+    ///   $color
+    ///   $font-size
+    /// "#;
+    ///
+    /// let variables = [
+    ///     (3..9,   "$color"),
+    ///     (12..22, "$font-size"),
+    /// ];
+    ///
+    /// let mut scanner = Scanner::new(text);
+    /// for variable in variables {
+    ///     scanner.skip_whitespace();
+    ///     assert_eq!(scanner.scan_stylus_variable(), Ok(variable));
+    /// }
+    ///
+    /// # scanner.skip_whitespace();
+    /// # assert_eq!(scanner.remaining_text(), "");
+    /// ```
+    ///
+    /// [Stylus variable]: https://stylus-lang.com/docs/variables.html
+    fn scan_stylus_variable(&mut self) -> ScannerResult<'text, &'text str>;
+}
+
+impl<'text> StylusScannerExt<'text> for Scanner<'text> {
+    #[inline]
+    fn scan_stylus_line_comment(&mut self) -> ScannerResult<'text, &'text str> {
+        self.scan_c_line_comment()
+    }
+
+    #[inline]
+    fn scan_stylus_block_comment(&mut self) -> ScannerResult<'text, &'text str> {
+        self.scan_css_block_comment()
+    }
+
+    // Reference: https://stylus-lang.com/docs/variables.html
+    fn scan_stylus_variable(&mut self) -> ScannerResult<'text, &'text str> {
+        self.scan_with(|scanner| {
+            scanner.accept_char('$')?;
+            scanner.scan_css_identifier()?;
+            Ok(())
+        })
+    }
+}
@@ -1,4 +1,12 @@
-use crate::{ext::RustScannerExt, CharExt, Scanner, ScannerResult};
+use std::ops::Range;
+
+use crate::{ext::RustScannerExt, ByteSet, Captures, Scanner, ScannerResult, SeparatorPolicy};
+
+// Reference: https://docs.swift.org/swift-book/documentation/the-swift-programming-language/lexicalstructure#Integer-Literals
+const SWIFT_DIGIT_SEPARATORS: SeparatorPolicy = SeparatorPolicy {
+    leading: false,
+    ..SeparatorPolicy::LENIENT
+};
 
 /// Reference: <https://docs.swift.org/swift-book/documentation/the-swift-programming-language/lexicalstructure/#Keywords-and-Punctuation>
 #[rustfmt::skip]
@@ -89,6 +97,49 @@ pub trait SwiftScannerExt<'text>: crate::private::Sealed {
     fn scan_swift_float_hex_literal(&mut self) -> ScannerResult<'text, &'text str>;
 
     fn scan_swift_string_literal(&mut self) -> ScannerResult<'text, &'text str>;
+    /// Same as [`scan_swift_string_literal()`], but additionally returns
+    /// [`Captures`] of the string literal:
+    ///
+    /// - `"open"`: The opening delimiter, e.g. `#"""`
+    /// - `"body"`: The contents between the delimiters
+    /// - `"close"`: The closing delimiter, e.g. `"""#`, unless unterminated
+    /// - `"indent"`: The indentation of the closing delimiter of a multiline
+    ///   string literal, which is stripped from each line of the body
+    ///
+    /// The `"indent"` is only captured if the closing delimiter is on its
+    /// own line, i.e. it is only preceded by spaces and tabs, otherwise
+    /// the multiline string literal is invalid.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use text_scanner::{ext::SwiftScannerExt, Scanner};
+    ///
+    /// let text = "\"\"\"\n    Hello\n      World\n    \"\"\"";
+    /// let mut scanner = Scanner::new(text);
+    ///
+    /// let (r, (_s, caps)) = scanner.scan_swift_string_literal_captures().unwrap();
+    /// assert_eq!(r, 0..33);
+    /// assert_eq!(caps.get("open"), Some((0..3, "\"\"\"")));
+    /// assert_eq!(caps.get("body"), Some((3..30, "\n    Hello\n      World\n    ")));
+    /// assert_eq!(caps.get("close"), Some((30..33, "\"\"\"")));
+    /// assert_eq!(caps.get("indent"), Some((26..30, "    ")));
+    ///
+    /// // The lines of the string value, i.e. `"Hello\n  World"`
+    /// let (_r, indent) = caps.get("indent").unwrap();
+    /// let (_r, body) = caps.get("body").unwrap();
+    /// let lines = body
+    ///     .lines()
+    ///     .skip(1)
+    ///     .map(|line| line.strip_prefix(indent).unwrap_or(line))
+    ///     .collect::<Vec<_>>();
+    /// assert_eq!(lines, ["Hello", "  World", ""]);
+    /// ```
+    ///
+    /// [`scan_swift_string_literal()`]: Self::scan_swift_string_literal
+    fn scan_swift_string_literal_captures(
+        &mut self,
+    ) -> ScannerResult<'text, (&'text str, Captures<'text>)>;
 
     fn scan_swift_regex_literal(&mut self) -> ScannerResult<'text, &'text str>;
 }
@@ -103,7 +154,9 @@ impl<'text> SwiftScannerExt<'text> for Scanner<'text> {
     // Reference: https://docs.swift.org/swift-book/documentation/the-swift-programming-language/lexicalstructure#Whitespace-and-Comments
     #[inline]
     fn scan_swift_block_comment(&mut self) -> ScannerResult<'text, &'text str> {
-        self.scan_rust_block_comment()
+        // Block comments nest, including doc comments, i.e. `/** ... */`
+        // and playground markup, i.e. `/*: ... */`
+        self.scan_block_comment("/*", "*/", true)
     }
 
     // Reference: https://docs.swift.org/swift-book/documentation/the-swift-programming-language/lexicalstructure#Identifiers
@@ -188,12 +241,8 @@ impl<'text> SwiftScannerExt<'text> for Scanner<'text> {
                             }
                             // &<< &<<=
                             // &>> &>>=
-                            '<' | '>' => {
-                                if scanner.accept_char(c).is_ok() {
-                                    _ = scanner.accept_char('=');
-                                } else {
-                                    scanner.cursor = last.start;
-                                }
+                            '<' | '>' if scanner.accept_char(c).is_ok() => {
+                                _ = scanner.accept_char('=');
                             }
                             _ => {
                                 scanner.cursor = last.start;
@@ -295,8 +344,7 @@ impl<'text> SwiftScannerExt<'text> for Scanner<'text> {
     fn scan_swift_int_dec_literal(&mut self) -> ScannerResult<'text, &'text str> {
         self.scan_with(|scanner| {
             _ = scanner.accept_char('-');
-            scanner.accept_if_ext(char::is_ascii_digit)?;
-            scanner.skip_while(|c| c.is_ascii_digit() || (c == '_'));
+            scanner.scan_digits_sep(10, '_', SWIFT_DIGIT_SEPARATORS)?;
             Ok(())
         })
     }
@@ -311,10 +359,7 @@ impl<'text> SwiftScannerExt<'text> for Scanner<'text> {
             scanner.accept_char('0')?;
             scanner.accept_char('x')?;
 
-            scanner.skip_while_char('_');
-            scanner.accept_if_ext(char::is_ascii_hexdigit)?;
-
-            scanner.skip_while(|c| c.is_ascii_hexdigit() || (c == '_'));
+            scanner.scan_digits_sep(16, '_', SWIFT_DIGIT_SEPARATORS)?;
 
             Ok(())
         })
@@ -330,10 +375,7 @@ impl<'text> SwiftScannerExt<'text> for Scanner<'text> {
             scanner.accept_char('0')?;
             scanner.accept_char('o')?;
 
-            scanner.skip_while_char('_');
-            scanner.accept_if(CharExt::is_ascii_octdigit)?;
-
-            scanner.skip_while(|c| CharExt::is_ascii_octdigit(c) || (c == '_'));
+            scanner.scan_digits_sep(8, '_', SWIFT_DIGIT_SEPARATORS)?;
 
             Ok(())
         })
@@ -349,10 +391,7 @@ impl<'text> SwiftScannerExt<'text> for Scanner<'text> {
             scanner.accept_char('0')?;
             scanner.accept_char('b')?;
 
-            scanner.skip_while_char('_');
-            scanner.accept_if(CharExt::is_ascii_bindigit)?;
-
-            scanner.skip_while(|c| c.is_ascii_bindigit() || (c == '_'));
+            scanner.scan_digits_sep(2, '_', SWIFT_DIGIT_SEPARATORS)?;
 
             Ok(())
         })
@@ -375,7 +414,7 @@ impl<'text> SwiftScannerExt<'text> for Scanner<'text> {
 
             if scanner.accept_char('.').is_ok() {
                 scanner.accept_if_ext(char::is_ascii_digit)?;
-                scanner.skip_while(|c| c.is_ascii_digit() || (c == '_'));
+                scanner.skip_while_in(&ByteSet::ASCII_DIGIT_OR_UNDERSCORE);
             }
 
             if scanner.accept_char_any(&['e', 'E']).is_ok() {
@@ -399,14 +438,14 @@ impl<'text> SwiftScannerExt<'text> for Scanner<'text> {
 
             if scanner.accept_char('.').is_ok() {
                 scanner.accept_if_ext(char::is_ascii_hexdigit)?;
-                scanner.skip_while(|c| c.is_ascii_hexdigit() || (c == '_'));
+                scanner.skip_while_in(&ByteSet::ASCII_HEXDIGIT_OR_UNDERSCORE);
             }
 
             scanner.accept_char_any(&['p', 'P'])?;
 
             _ = scanner.accept_char_any(&['+', '-']);
             scanner.accept_if_ext(char::is_ascii_digit)?;
-            scanner.skip_while(|c| c.is_ascii_digit() || (c == '_'));
+            scanner.skip_while_in(&ByteSet::ASCII_DIGIT_OR_UNDERSCORE);
 
             Ok(())
         })
@@ -416,71 +455,32 @@ impl<'text> SwiftScannerExt<'text> for Scanner<'text> {
     #[inline]
     fn scan_swift_string_literal(&mut self) -> ScannerResult<'text, &'text str> {
         self.scan_with(|scanner| {
-            let hashes = scanner.skip_while_char('#').0.len();
-            scanner.accept_char('"')?;
-            let is_triple_quote = scanner.accept_str("\"\"").is_ok();
-
-            'scan: loop {
-                scanner.skip_until_char_any(&['"', '\\']);
-                match scanner.next() {
-                    Ok((_r, '"')) => {
-                        if is_triple_quote {
-                            for _ in 0..2 {
-                                if scanner.accept_char('"').is_err() {
-                                    continue 'scan;
-                                }
-                            }
-                        }
-
-                        for _ in 0..hashes {
-                            if scanner.accept_char('#').is_err() {
-                                continue 'scan;
-                            }
-                        }
-
-                        break;
-                    }
-                    Ok((r, '\\')) => {
-                        let inner_hashes = scanner.skip_while_char('#').0.len();
-                        if hashes == inner_hashes {
-                            if let Ok((_, '(')) = scanner.next() {
-                                let mut nested = 0;
-                                loop {
-                                    scanner.skip_until_char_any(&['(', ')', '"', '\\']);
-                                    match scanner.next() {
-                                        Ok((_r, '(')) => {
-                                            nested += 1;
-                                        }
-                                        Ok((_r, ')')) => {
-                                            if nested == 0 {
-                                                continue 'scan;
-                                            }
-                                            nested -= 1;
-                                        }
-                                        Ok((r, '"')) => {
-                                            scanner.cursor = r.start;
-                                            scanner.scan_swift_string_literal()?;
-                                        }
-                                        Ok((_r, '\\')) => {
-                                            // Skip the next character as it is escaped
-                                            // Note: Technically any character is not valid
-                                            _ = scanner.next();
-                                        }
-                                        Ok(_) => unreachable!(),
-                                        Err(_) => break,
-                                    }
-                                }
-                            } else {
-                                scanner.cursor = r.end;
+            scan_swift_string_literal(scanner)?;
+            Ok(())
+        })
+    }
 
-                                // Skip the next character as it is escaped
-                                // Note: Technically any character is not valid
-                                _ = scanner.next();
-                            }
-                        }
-                    }
-                    Ok(_) => unreachable!(),
-                    Err(_) => break,
+    // Reference: https://docs.swift.org/swift-book/documentation/the-swift-programming-language/lexicalstructure#Multiline-String-Literals
+    fn scan_swift_string_literal_captures(
+        &mut self,
+    ) -> ScannerResult<'text, (&'text str, Captures<'text>)> {
+        self.scan_capturing(|scanner, caps| {
+            let start = scanner.cursor_pos();
+            let (open, close) = scan_swift_string_literal(scanner)?;
+
+            caps.insert("open", start..open.end);
+            let Some(close) = close else {
+                caps.insert("body", open.end..scanner.cursor_pos());
+                return Ok(());
+            };
+            caps.insert("body", open.end..close.start);
+            caps.insert("close", close.clone());
+
+            if scanner.text[open.clone()].ends_with(r#"""""#) {
+                let line_start = scanner.text[..close.start].rfind('\n').map_or(0, |i| i + 1);
+                let indent = &scanner.text[line_start..close.start];
+                if (line_start > open.end) && indent.chars().all(|c| matches!(c, ' ' | '\t')) {
+                    caps.insert("indent", line_start..close.start);
                 }
             }
 
@@ -531,9 +531,74 @@ impl<'text> SwiftScannerExt<'text> for Scanner<'text> {
 
 // TODO: Swift supports a wider range of unicode characters as identifiers, e.g. emojis
 #[inline]
+/// Scans a Swift string literal, and returns the range of the opening
+/// delimiter, excluding any `#`s, and the range of the closing delimiter,
+/// including any `#`s, unless the string literal is unterminated.
+fn scan_swift_string_literal<'text>(
+    scanner: &mut Scanner<'text>,
+) -> ScannerResult<'text, Option<Range<usize>>> {
+    let hashes = scanner.skip_while_char('#').0.len();
+    let (mut open, _c) = scanner.accept_char('"')?;
+    if let Ok((r, _s)) = scanner.accept_str("\"\"") {
+        open.end = r.end;
+    }
+    let is_triple_quote = open.len() == 3;
+
+    'scan: loop {
+        scanner.skip_until_char_any(&['"', '\\']);
+        match scanner.next() {
+            Ok((r, '"')) => {
+                if is_triple_quote {
+                    for _ in 0..2 {
+                        if scanner.accept_char('"').is_err() {
+                            continue 'scan;
+                        }
+                    }
+                }
+
+                for _ in 0..hashes {
+                    if scanner.accept_char('#').is_err() {
+                        continue 'scan;
+                    }
+                }
+
+                return Ok((open, Some(r.start..scanner.cursor)));
+            }
+            Ok((r, '\\')) => {
+                let inner_hashes = scanner.skip_while_char('#').0.len();
+                if hashes == inner_hashes {
+                    if scanner.peek().is_ok_and(|(_r, c)| c == '(') {
+                        let interpolation = scanner.scan_interpolation(
+                            "(",
+                            ")",
+                            &[('(', ')')],
+                            Scanner::scan_swift_string_literal,
+                        );
+                        if let Err((r, _s)) = interpolation {
+                            // Unterminated interpolation
+                            scanner.cursor = r.end;
+                            break;
+                        }
+                    } else {
+                        scanner.cursor = r.end;
+
+                        // Skip the next character as it is escaped
+                        // Note: Technically any character is not valid
+                        _ = scanner.next();
+                    }
+                }
+            }
+            Ok(_) => unreachable!(),
+            Err(_) => break,
+        }
+    }
+
+    Ok((open, None))
+}
+
 fn scan_swift_identifier<'text>(scanner: &mut Scanner<'text>) -> ScannerResult<'text, &'text str> {
     let (first, _) = scanner.accept_if(|c| c.is_alphabetic() || (c == '_'))?;
-    let (last, _) = scanner.skip_while(|c| c.is_alphanumeric() || (c == '_'));
+    let (last, _) = scanner.skip_while_in(&ByteSet::IDENT_CONTINUE);
     Ok(scanner.ranged_text(first.start..last.end))
 }
 
@@ -644,28 +709,28 @@ mod tests {
 
     #[test]
     fn test_swift_nil_literals() {
-        assert_eq!("nil".is_swift_nil_literal(), true);
-        assert_eq!("nil".is_swift_boolean_literal(), false);
-        assert_eq!("nil".is_swift_keyword(), true);
+        assert!("nil".is_swift_nil_literal());
+        assert!(!"nil".is_swift_boolean_literal());
+        assert!("nil".is_swift_keyword());
 
-        assert_eq!("NIL".is_swift_nil_literal(), false);
-        assert_eq!("NIL".is_swift_boolean_literal(), false);
-        assert_eq!("NIL".is_swift_keyword(), false);
+        assert!(!"NIL".is_swift_nil_literal());
+        assert!(!"NIL".is_swift_boolean_literal());
+        assert!(!"NIL".is_swift_keyword());
 
-        assert_eq!("Nil".is_swift_nil_literal(), false);
-        assert_eq!("Nil".is_swift_boolean_literal(), false);
-        assert_eq!("Nil".is_swift_keyword(), false);
+        assert!(!"Nil".is_swift_nil_literal());
+        assert!(!"Nil".is_swift_boolean_literal());
+        assert!(!"Nil".is_swift_keyword());
     }
 
     #[test]
     fn test_swift_boolean_literals() {
-        assert_eq!("true".is_swift_boolean_literal(), true);
-        assert_eq!("true".is_swift_nil_literal(), false);
-        assert_eq!("true".is_swift_keyword(), true);
+        assert!("true".is_swift_boolean_literal());
+        assert!(!"true".is_swift_nil_literal());
+        assert!("true".is_swift_keyword());
 
-        assert_eq!("false".is_swift_boolean_literal(), true);
-        assert_eq!("false".is_swift_nil_literal(), false);
-        assert_eq!("false".is_swift_keyword(), true);
+        assert!("false".is_swift_boolean_literal());
+        assert!(!"false".is_swift_nil_literal());
+        assert!("false".is_swift_keyword());
     }
 
     #[test]
@@ -725,6 +790,8 @@ mod tests {
 
         assert_valid_cases!(scan_swift_int_literal, cases);
         assert_valid_cases!(scan_swift_int_literal, cases, "remaining");
+
+        assert_invalid_cases!(scan_swift_int_hex_literal, ["0x_0", "0x_F"]);
     }
 
     #[test]
@@ -850,6 +917,9 @@ mod tests {
             r#""1 2 \((""))""#,
             r#""1 2 \(("\""))""#,
             r#""1 2 \(("\("3\"4")"))""#,
+            r#""1 2 \("\("\("\(3)")")")""#,
+            r#""1 2 \(f(")", "(", ((((3)))))) 4""#,
+            r###"#"1 2 \#(")"#) 3 \(4"#"###,
             //
             r#""\(multiplier) times 2.5 is \(Double(multiplier) * 2.5)""#,
             r###"#"Write an interpolated string in Swift using \(multiplier."#"###,
@@ -860,6 +930,32 @@ mod tests {
         assert_valid_cases!(scan_swift_string_literal, cases, "remaining");
     }
 
+    #[test]
+    fn test_swift_string_literal_captures() {
+        #[rustfmt::skip]
+        let cases = [
+            // text, open, body, close, indent
+            ("\"a\"",                      "\"",      "a",              Some("\""),      None),
+            ("#\"a\"#",                    "#\"",     "a",              Some("\"#"),     None),
+            ("\"\"\"\na\n\"\"\"",          "\"\"\"",  "\na\n",          Some("\"\"\""),  Some("")),
+            ("#\"\"\"\n\ta\n\t\"\"\"#",    "#\"\"\"", "\n\ta\n\t",      Some("\"\"\"#"), Some("\t")),
+            ("\"\"\"\n  a \"\"\"",         "\"\"\"",  "\n  a ",         Some("\"\"\""),  None),
+            ("\"\"\"a\"\"\"",              "\"\"\"",  "a",              Some("\"\"\""),  None),
+            ("\"\"\"\n  a\n  ",            "\"\"\"",  "\n  a\n  ",      None,            None),
+        ];
+        for (text, open, body, close, indent) in cases {
+            let mut scanner = Scanner::new(text);
+            let (r, (_s, caps)) = scanner.scan_swift_string_literal_captures().unwrap();
+            assert_eq!(r, 0..text.len(), "{text:?}");
+
+            let get = |name| caps.get(name).map(|(_r, s)| s);
+            assert_eq!(get("open"), Some(open), "{text:?}");
+            assert_eq!(get("body"), Some(body), "{text:?}");
+            assert_eq!(get("close"), close, "{text:?}");
+            assert_eq!(get("indent"), indent, "{text:?}");
+        }
+    }
+
     #[test]
     fn test_swift_regex_literals() {
         let cases = [
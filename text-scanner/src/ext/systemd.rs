@@ -0,0 +1,257 @@
+use crate::{Scanner, ScannerResult};
+
+/// [`Scanner`] extension for scanning systemd unit file tokens, e.g.
+/// `.service`, `.socket`, and `.timer` files.
+///
+/// **Note:** Unit files are line-oriented, e.g. comments, section headers,
+/// and keys are only recognized at the start of a line. The scanning
+/// methods do not check this, that is up to the caller.
+///
+/// _Based on [systemd.syntax]_.
+///
+/// [systemd.syntax]: https://www.freedesktop.org/software/systemd/man/latest/systemd.syntax.html
+pub trait SystemdScannerExt<'text>: crate::private::Sealed {
+    /// Scans a systemd line comment, i.e. `#` or `;` until the end of
+    /// the line, e.g. `# comment`.
+    fn scan_systemd_line_comment(&mut self) -> ScannerResult<'text, &'text str>;
+
+    /// Scans a systemd section header, e.g. `[Unit]` and `[Service]`.
+    fn scan_systemd_section(&mut self) -> ScannerResult<'text, &'text str>;
+
+    /// Scans a systemd key, e.g. `ExecStart` in `ExecStart=/usr/bin/app`.
+    /// The key must be followed by `=`, optionally preceded by spaces
+    /// or tabs, which is not included.
+    fn scan_systemd_key(&mut self) -> ScannerResult<'text, &'text str>;
+
+    /// Scans a systemd specifier, e.g. `%i` and `%n`, or an escaped
+    /// percent sign, i.e. `%%`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use text_scanner::{ext::SystemdScannerExt, Scanner};
+    ///
+    /// let text = "%i.conf";
+    /// let mut scanner = Scanner::new(text);
+    ///
+    /// assert_eq!(scanner.scan_systemd_specifier(), Ok((0..2, "%i")));
+    /// assert_eq!(scanner.remaining_text(), ".conf");
+    /// ```
+    fn scan_systemd_specifier(&mut self) -> ScannerResult<'text, &'text str>;
+
+    /// Scans a systemd environment variable substitution, e.g. `$MAINPID`
+    /// and `${OPTIONS}`.
+    fn scan_systemd_variable(&mut self) -> ScannerResult<'text, &'text str>;
+
+    /// Scans a systemd line continuation, i.e. `\` at the end of a line.
+    /// The line break is not included.
+    fn scan_systemd_continuation(&mut self) -> ScannerResult<'text, &'text str>;
+
+    /// Scans the text of a systemd value, e.g. `/usr/bin/app` and
+    /// `multi-user.target`, until whitespace, a specifier, a variable,
+    /// or a line continuation.
+    fn scan_systemd_value(&mut self) -> ScannerResult<'text, &'text str>;
+}
+
+impl<'text> SystemdScannerExt<'text> for Scanner<'text> {
+    // Reference: https://www.freedesktop.org/software/systemd/man/latest/systemd.syntax.html
+    fn scan_systemd_line_comment(&mut self) -> ScannerResult<'text, &'text str> {
+        self.scan_with(|scanner| {
+            scanner.accept_char_any(&['#', ';'])?;
+            scanner.skip_until_char_any(&['\n', '\r']);
+            Ok(())
+        })
+    }
+
+    // Reference: https://www.freedesktop.org/software/systemd/man/latest/systemd.syntax.html
+    fn scan_systemd_section(&mut self) -> ScannerResult<'text, &'text str> {
+        self.scan_with(|scanner| {
+            scanner.accept_char('[')?;
+            scanner.accept_if(|c| !matches!(c, ']' | '[' | '\n' | '\r'))?;
+            scanner.skip_until_char_any(&[']', '[', '\n', '\r']);
+            scanner.accept_char(']')?;
+            Ok(())
+        })
+    }
+
+    // Reference: https://www.freedesktop.org/software/systemd/man/latest/systemd.syntax.html
+    fn scan_systemd_key(&mut self) -> ScannerResult<'text, &'text str> {
+        self.scan_with(|scanner| {
+            scanner.accept_if(|c| c.is_ascii_alphanumeric())?;
+            scanner.skip_while(|c| c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | '.'));
+
+            let is_assignment = scanner.peeking(|scanner| {
+                scanner.skip_while_char_any(&[' ', '\t']);
+                scanner.accept_char('=').is_ok()
+            });
+            if !is_assignment {
+                let pos = scanner.cursor_pos();
+                return Err(scanner.ranged_text(pos..pos));
+            }
+            Ok(())
+        })
+    }
+
+    // Reference: https://www.freedesktop.org/software/systemd/man/latest/systemd.unit.html#Specifiers
+    fn scan_systemd_specifier(&mut self) -> ScannerResult<'text, &'text str> {
+        self.scan_with(|scanner| {
+            scanner.accept_char('%')?;
+            scanner.accept_if(|c| c.is_ascii_alphabetic() || (c == '%'))?;
+            Ok(())
+        })
+    }
+
+    // Reference: https://www.freedesktop.org/software/systemd/man/latest/systemd.service.html#Command%20lines
+    fn scan_systemd_variable(&mut self) -> ScannerResult<'text, &'text str> {
+        self.scan_with(|scanner| {
+            scanner.accept_char('$')?;
+
+            let braced = scanner.accept_char('{').is_ok();
+            scanner.accept_if(|c| c.is_ascii_alphabetic() || (c == '_'))?;
+            scanner.skip_while(|c| c.is_ascii_alphanumeric() || (c == '_'));
+
+            if braced {
+                scanner.accept_char('}')?;
+            }
+            Ok(())
+        })
+    }
+
+    // Reference: https://www.freedesktop.org/software/systemd/man/latest/systemd.syntax.html
+    fn scan_systemd_continuation(&mut self) -> ScannerResult<'text, &'text str> {
+        self.scan_with(|scanner| {
+            let (r, _c) = scanner.accept_char('\\')?;
+            match scanner.peek() {
+                Ok((_, '\n' | '\r')) | Err(_) => Ok(()),
+                Ok(_) => Err(scanner.ranged_text(r)),
+            }
+        })
+    }
+
+    // Reference: https://www.freedesktop.org/software/systemd/man/latest/systemd.syntax.html
+    fn scan_systemd_value(&mut self) -> ScannerResult<'text, &'text str> {
+        self.scan_with(|scanner| {
+            let start = scanner.cursor_pos();
+            loop {
+                let stop = scanner.peeking(|scanner| {
+                    scanner.scan_systemd_specifier().is_ok()
+                        || scanner.scan_systemd_variable().is_ok()
+                        || scanner.scan_systemd_continuation().is_ok()
+                });
+                if stop {
+                    break;
+                }
+                match scanner.peek() {
+                    Ok((_r, '\\')) => {
+                        _ = scanner.next();
+                        _ = scanner.next();
+                    }
+                    Ok((_r, c)) if !c.is_whitespace() => {
+                        _ = scanner.next();
+                    }
+                    _ => break,
+                }
+            }
+
+            if scanner.cursor_pos() == start {
+                return Err((start..start, ""));
+            }
+            Ok(())
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{assert_invalid_cases, assert_valid_cases};
+
+    #[test]
+    fn test_systemd_line_comments() {
+        let cases = ["#", "# Comment", ";", "; Comment", "#[Unit]"];
+
+        assert_valid_cases!(scan_systemd_line_comment, cases);
+        assert_valid_cases!(scan_systemd_line_comment, cases, "\nrest");
+    }
+
+    #[test]
+    fn test_systemd_sections() {
+        let cases = ["[Unit]", "[Service]", "[X-Custom Section]"];
+
+        assert_valid_cases!(scan_systemd_section, cases);
+        assert_valid_cases!(scan_systemd_section, cases, "\nrest");
+    }
+
+    #[test]
+    fn test_systemd_sections_invalid() {
+        let cases = ["[", "[]", "[Unit", "[Unit\n]", "Unit]"];
+
+        assert_invalid_cases!(scan_systemd_section, cases);
+    }
+
+    #[test]
+    fn test_systemd_keys() {
+        let cases = ["ExecStart", "X-Custom", "Description", "CPUQuota"];
+
+        assert_valid_cases!(scan_systemd_key, cases, "=value");
+        assert_valid_cases!(scan_systemd_key, cases, " = value");
+        assert_valid_cases!(scan_systemd_key, cases, "\t=");
+    }
+
+    #[test]
+    fn test_systemd_keys_invalid() {
+        let cases = ["ExecStart", "ExecStart value", "-Key=x", "=x"];
+
+        assert_invalid_cases!(scan_systemd_key, cases);
+    }
+
+    #[test]
+    fn test_systemd_specifiers() {
+        let cases = ["%i", "%I", "%n", "%h", "%%"];
+
+        assert_valid_cases!(scan_systemd_specifier, cases);
+        assert_valid_cases!(scan_systemd_specifier, cases, ".conf");
+        assert_invalid_cases!(scan_systemd_specifier, ["%", "% i", "%1"]);
+    }
+
+    #[test]
+    fn test_systemd_variables() {
+        let cases = ["$MAINPID", "${OPTIONS}", "$_x1"];
+
+        assert_valid_cases!(scan_systemd_variable, cases);
+        assert_valid_cases!(scan_systemd_variable, cases, "/rest");
+        assert_invalid_cases!(scan_systemd_variable, ["$", "${}", "${X", "$1"]);
+    }
+
+    #[test]
+    fn test_systemd_continuations() {
+        let mut scanner = Scanner::new("\\\n");
+        assert_eq!(scanner.scan_systemd_continuation(), Ok((0..1, "\\")));
+        assert_eq!(scanner.remaining_text(), "\n");
+
+        assert_invalid_cases!(scan_systemd_continuation, ["\\a", "\\ \n", "a"]);
+    }
+
+    #[test]
+    fn test_systemd_values() {
+        let cases = [
+            "/usr/bin/app",
+            "multi-user.target",
+            "-/etc/default/app",
+            "on-failure",
+            r"a\ b",
+        ];
+
+        assert_valid_cases!(scan_systemd_value, cases);
+        assert_valid_cases!(scan_systemd_value, cases, " rest");
+        assert_valid_cases!(scan_systemd_value, cases, "%i");
+        assert_valid_cases!(scan_systemd_value, cases, "$MAINPID");
+    }
+
+    #[test]
+    fn test_systemd_values_invalid() {
+        let cases = ["", " ", "%i", "$MAINPID", "\\\n"];
+
+        assert_invalid_cases!(scan_systemd_value, cases);
+    }
+}
@@ -0,0 +1,782 @@
+use crate::{ByteSet, ScanOutcome, Scanner, ScannerResult, SeparatorPolicy};
+
+// Reference: https://toml.io/en/v1.0.0#keys
+const TOML_BARE_KEY: ByteSet = ByteSet::ASCII_ALPHANUMERIC.union(ByteSet::from_bytes(b"_-"));
+
+// Reference: https://toml.io/en/v1.0.0#integer
+const TOML_DIGIT_SEPARATORS: SeparatorPolicy = SeparatorPolicy::STRICT;
+
+/// [`Scanner`] extension for scanning TOML tokens, e.g. `Cargo.toml` and
+/// `pyproject.toml` files.
+///
+/// **Note:** Whether e.g. `true` and `1979-05-27` are keys or values depends
+/// on the context, as both are valid bare keys. The scanning methods do not
+/// check this, that is up to the caller.
+///
+/// _Based on [TOML v1.0.0]_.
+///
+/// [TOML v1.0.0]: https://toml.io/en/v1.0.0
+pub trait TomlScannerExt<'text>: crate::private::Sealed {
+    /// Scans a TOML line comment, i.e. `#` until the end of the line,
+    /// e.g. `# comment`.
+    fn scan_toml_line_comment(&mut self) -> ScannerResult<'text, &'text str>;
+
+    /// Scans a TOML bare key, i.e. one-to-many ASCII letters, ASCII digits,
+    /// `_`, and `-`, e.g. `name` and `rust-version`.
+    fn scan_toml_bare_key(&mut self) -> ScannerResult<'text, &'text str>;
+
+    /// Scans a TOML key, i.e. one-to-many bare keys and single-line strings
+    /// separated by `.`, e.g. `name`, `"127.0.0.1"`, and `dependencies.serde`.
+    ///
+    /// Spaces and tabs around `.` are included, while any trailing spaces
+    /// and tabs are not.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use text_scanner::{ext::TomlScannerExt, Scanner};
+    ///
+    /// let text = "site . \"google.com\" = true";
+    /// let mut scanner = Scanner::new(text);
+    ///
+    /// assert_eq!(scanner.scan_toml_key(), Ok((0..19, "site . \"google.com\"")));
+    /// assert_eq!(scanner.remaining_text(), " = true");
+    /// ```
+    fn scan_toml_key(&mut self) -> ScannerResult<'text, &'text str>;
+
+    /// Scans a TOML table header, i.e. either a standard table, e.g.
+    /// `[package]`, or an array of tables, e.g. `[[bin]]`.
+    ///
+    /// Spaces and tabs around the key are included, e.g. `[ a.b ]`.
+    fn scan_toml_table_header(&mut self) -> ScannerResult<'text, &'text str>;
+
+    /// Scans any TOML string, i.e. a basic or literal string, which is
+    /// either single-line or multi-line.
+    fn scan_toml_string(&mut self) -> ScannerResult<'text, &'text str>;
+
+    /// Scans a single-line TOML basic string, e.g. `"Hello\tWorld"`.
+    fn scan_toml_basic_string(&mut self) -> ScannerResult<'text, &'text str>;
+    /// Same as [`scan_toml_basic_string()`], but additionally returns whether
+    /// the string is unterminated, i.e. ends at the end of the line.
+    ///
+    /// [`scan_toml_basic_string()`]: Self::scan_toml_basic_string
+    fn scan_toml_basic_string_outcome(&mut self) -> ScannerResult<'text, ScanOutcome>;
+
+    /// Scans a multi-line TOML basic string, i.e. `"""` ... `"""`.
+    fn scan_toml_ml_basic_string(&mut self) -> ScannerResult<'text, &'text str>;
+    /// Same as [`scan_toml_ml_basic_string()`], but additionally returns
+    /// whether the string is unterminated.
+    ///
+    /// [`scan_toml_ml_basic_string()`]: Self::scan_toml_ml_basic_string
+    fn scan_toml_ml_basic_string_outcome(&mut self) -> ScannerResult<'text, ScanOutcome>;
+
+    /// Scans a single-line TOML literal string, e.g. `'C:\Users'`.
+    fn scan_toml_literal_string(&mut self) -> ScannerResult<'text, &'text str>;
+    /// Same as [`scan_toml_literal_string()`], but additionally returns
+    /// whether the string is unterminated, i.e. ends at the end of the line.
+    ///
+    /// [`scan_toml_literal_string()`]: Self::scan_toml_literal_string
+    fn scan_toml_literal_string_outcome(&mut self) -> ScannerResult<'text, ScanOutcome>;
+
+    /// Scans a multi-line TOML literal string, i.e. `'''` ... `'''`.
+    fn scan_toml_ml_literal_string(&mut self) -> ScannerResult<'text, &'text str>;
+    /// Same as [`scan_toml_ml_literal_string()`], but additionally returns
+    /// whether the string is unterminated.
+    ///
+    /// [`scan_toml_ml_literal_string()`]: Self::scan_toml_ml_literal_string
+    fn scan_toml_ml_literal_string_outcome(&mut self) -> ScannerResult<'text, ScanOutcome>;
+
+    /// Scans a TOML integer, e.g. `+99`, `1_000`, `0xDEAD_BEEF`,
+    /// `0o755`, and `0b1101`.
+    ///
+    /// Leading zeros are not allowed, e.g. `007` is an `Err`.
+    fn scan_toml_integer(&mut self) -> ScannerResult<'text, &'text str>;
+
+    /// Scans a TOML float, e.g. `3.1415`, `-0.01`, `5e+22`, `6.626e-34`,
+    /// `inf`, and `-nan`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use text_scanner::{ext::TomlScannerExt, Scanner};
+    ///
+    /// let text = "224_617.445_991 1";
+    /// let mut scanner = Scanner::new(text);
+    ///
+    /// assert_eq!(scanner.scan_toml_float(), Ok((0..15, "224_617.445_991")));
+    /// scanner.skip_whitespace();
+    /// assert_eq!(scanner.scan_toml_float(), Err((16..17, "1")));
+    /// ```
+    fn scan_toml_float(&mut self) -> ScannerResult<'text, &'text str>;
+
+    /// Scans a TOML boolean, i.e. `true` or `false`.
+    fn scan_toml_boolean(&mut self) -> ScannerResult<'text, &'text str>;
+
+    /// Scans a TOML date and/or time as specified by [RFC 3339], i.e. an
+    /// offset date-time, e.g. `1979-05-27T07:32:00Z`, a local date-time,
+    /// e.g. `1979-05-27 07:32:00.999`, a local date, e.g. `1979-05-27`,
+    /// or a local time, e.g. `07:32:00`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use text_scanner::{ext::TomlScannerExt, Scanner};
+    ///
+    /// let text = "1979-05-27 07:32:00-07:00 # Comment";
+    /// let mut scanner = Scanner::new(text);
+    ///
+    /// assert_eq!(scanner.scan_toml_datetime(), Ok((0..25, "1979-05-27 07:32:00-07:00")));
+    /// assert_eq!(scanner.remaining_text(), " # Comment");
+    /// ```
+    ///
+    /// [RFC 3339]: https://datatracker.ietf.org/doc/html/rfc3339
+    fn scan_toml_datetime(&mut self) -> ScannerResult<'text, &'text str>;
+}
+
+impl<'text> TomlScannerExt<'text> for Scanner<'text> {
+    // Reference: https://toml.io/en/v1.0.0#comment
+    fn scan_toml_line_comment(&mut self) -> ScannerResult<'text, &'text str> {
+        self.scan_with(|scanner| {
+            scanner.accept_char('#')?;
+            scanner.skip_until_char_any(&['\n', '\r']);
+            Ok(())
+        })
+    }
+
+    // Reference: https://toml.io/en/v1.0.0#keys
+    fn scan_toml_bare_key(&mut self) -> ScannerResult<'text, &'text str> {
+        self.scan_with(|scanner| {
+            scanner.accept_if(|c| TOML_BARE_KEY.contains_char(c))?;
+            scanner.skip_while_in(&TOML_BARE_KEY);
+            Ok(())
+        })
+    }
+
+    // Reference: https://toml.io/en/v1.0.0#keys
+    fn scan_toml_key(&mut self) -> ScannerResult<'text, &'text str> {
+        self.scan_with(|scanner| {
+            scan_toml_simple_key(scanner)?;
+
+            loop {
+                let dotted = scanner.scan_with(|scanner| {
+                    scanner.skip_while_char_any(&[' ', '\t']);
+                    scanner.accept_char('.')?;
+                    scanner.skip_while_char_any(&[' ', '\t']);
+                    scan_toml_simple_key(scanner)?;
+                    Ok(())
+                });
+                if dotted.is_err() {
+                    break;
+                }
+            }
+
+            Ok(())
+        })
+    }
+
+    // Reference: https://toml.io/en/v1.0.0#table
+    // Reference: https://toml.io/en/v1.0.0#array-of-tables
+    fn scan_toml_table_header(&mut self) -> ScannerResult<'text, &'text str> {
+        self.scan_with(|scanner| {
+            scanner.accept_char('[')?;
+            let is_array = scanner.accept_char('[').is_ok();
+
+            scanner.skip_while_char_any(&[' ', '\t']);
+            scanner.scan_toml_key()?;
+            scanner.skip_while_char_any(&[' ', '\t']);
+
+            scanner.accept_char(']')?;
+            if is_array {
+                scanner.accept_char(']')?;
+            }
+            Ok(())
+        })
+    }
+
+    // Reference: https://toml.io/en/v1.0.0#string
+    #[inline]
+    fn scan_toml_string(&mut self) -> ScannerResult<'text, &'text str> {
+        self.scan_toml_ml_basic_string()
+            .or_else(|_| self.scan_toml_basic_string())
+            .or_else(|_| self.scan_toml_ml_literal_string())
+            .or_else(|_| self.scan_toml_literal_string())
+    }
+
+    // Reference: https://toml.io/en/v1.0.0#string
+    #[inline]
+    fn scan_toml_basic_string(&mut self) -> ScannerResult<'text, &'text str> {
+        let (r, _outcome) = self.scan_toml_basic_string_outcome()?;
+        Ok(self.ranged_text(r))
+    }
+
+    // Reference: https://toml.io/en/v1.0.0#string
+    fn scan_toml_basic_string_outcome(&mut self) -> ScannerResult<'text, ScanOutcome> {
+        let mut outcome = ScanOutcome::Terminated;
+        let (r, _s) = self.scan_with(|scanner| {
+            let (open_range, _c) = scanner.accept_char('"')?;
+
+            loop {
+                scanner.skip_until_char_any(&['"', '\\', '\n', '\r']);
+                match scanner.peek() {
+                    Ok((_r, '"')) => {
+                        _ = scanner.next();
+                        break;
+                    }
+                    Ok((_r, '\\')) => {
+                        // Skip the next character as it is escaped,
+                        // unless it is a line break
+                        _ = scanner.next();
+                        _ = scanner.accept_if(|c| !matches!(c, '\n' | '\r'));
+                    }
+                    _ => {
+                        outcome = ScanOutcome::Unterminated(open_range);
+                        break;
+                    }
+                }
+            }
+
+            Ok(())
+        })?;
+        Ok((r, outcome))
+    }
+
+    // Reference: https://toml.io/en/v1.0.0#string
+    #[inline]
+    fn scan_toml_ml_basic_string(&mut self) -> ScannerResult<'text, &'text str> {
+        let (r, _outcome) = self.scan_toml_ml_basic_string_outcome()?;
+        Ok(self.ranged_text(r))
+    }
+
+    // Reference: https://toml.io/en/v1.0.0#string
+    fn scan_toml_ml_basic_string_outcome(&mut self) -> ScannerResult<'text, ScanOutcome> {
+        scan_toml_ml_string(self, '"', true)
+    }
+
+    // Reference: https://toml.io/en/v1.0.0#string
+    #[inline]
+    fn scan_toml_literal_string(&mut self) -> ScannerResult<'text, &'text str> {
+        let (r, _outcome) = self.scan_toml_literal_string_outcome()?;
+        Ok(self.ranged_text(r))
+    }
+
+    // Reference: https://toml.io/en/v1.0.0#string
+    fn scan_toml_literal_string_outcome(&mut self) -> ScannerResult<'text, ScanOutcome> {
+        let mut outcome = ScanOutcome::Terminated;
+        let (r, _s) = self.scan_with(|scanner| {
+            let (open_range, _c) = scanner.accept_char('\'')?;
+
+            scanner.skip_until_char_any(&['\'', '\n', '\r']);
+            if scanner.accept_char('\'').is_err() {
+                outcome = ScanOutcome::Unterminated(open_range);
+            }
+
+            Ok(())
+        })?;
+        Ok((r, outcome))
+    }
+
+    // Reference: https://toml.io/en/v1.0.0#string
+    #[inline]
+    fn scan_toml_ml_literal_string(&mut self) -> ScannerResult<'text, &'text str> {
+        let (r, _outcome) = self.scan_toml_ml_literal_string_outcome()?;
+        Ok(self.ranged_text(r))
+    }
+
+    // Reference: https://toml.io/en/v1.0.0#string
+    fn scan_toml_ml_literal_string_outcome(&mut self) -> ScannerResult<'text, ScanOutcome> {
+        scan_toml_ml_string(self, '\'', false)
+    }
+
+    // Reference: https://toml.io/en/v1.0.0#integer
+    fn scan_toml_integer(&mut self) -> ScannerResult<'text, &'text str> {
+        self.scan_with(|scanner| {
+            let prefixed = scanner.peeking(|scanner| {
+                scanner.accept_char('0').is_ok()
+                    && scanner.accept_char_any(&['x', 'o', 'b']).is_ok()
+            });
+
+            if prefixed {
+                _ = scanner.next();
+                let radix = match scanner.next() {
+                    Ok((_r, 'x')) => 16,
+                    Ok((_r, 'o')) => 8,
+                    _ => 2,
+                };
+                scanner.scan_digits_sep(radix, '_', TOML_DIGIT_SEPARATORS)?;
+            } else {
+                _ = scanner.accept_char_any(&['+', '-']);
+                scan_toml_dec_int(scanner)?;
+            }
+
+            Ok(())
+        })
+    }
+
+    // Reference: https://toml.io/en/v1.0.0#float
+    fn scan_toml_float(&mut self) -> ScannerResult<'text, &'text str> {
+        self.scan_with(|scanner| {
+            let start = scanner.cursor_pos();
+            _ = scanner.accept_char_any(&['+', '-']);
+
+            if scanner.accept_str_any(&["inf", "nan"]).is_ok() {
+                return Ok(());
+            }
+
+            scan_toml_dec_int(scanner)?;
+
+            let has_fraction = scanner.accept_char('.').is_ok();
+            if has_fraction {
+                scanner.scan_digits_sep(10, '_', TOML_DIGIT_SEPARATORS)?;
+            }
+
+            let has_exponent = scanner.accept_char_any(&['e', 'E']).is_ok();
+            if has_exponent {
+                _ = scanner.accept_char_any(&['+', '-']);
+                scanner.scan_digits_sep(10, '_', TOML_DIGIT_SEPARATORS)?;
+            }
+
+            if !has_fraction && !has_exponent {
+                return Err(scanner.ranged_text(start..scanner.cursor_pos()));
+            }
+
+            Ok(())
+        })
+    }
+
+    // Reference: https://toml.io/en/v1.0.0#boolean
+    fn scan_toml_boolean(&mut self) -> ScannerResult<'text, &'text str> {
+        self.scan_with(|scanner| {
+            let (r, _s) = scanner.accept_str_any(&["true", "false"])?;
+            if scanner
+                .accept_if(|c| TOML_BARE_KEY.contains_char(c))
+                .is_ok()
+            {
+                return Err(scanner.ranged_text(r));
+            }
+            Ok(())
+        })
+    }
+
+    // Reference: https://toml.io/en/v1.0.0#offset-date-time
+    // Reference: https://datatracker.ietf.org/doc/html/rfc3339#section-5.6
+    fn scan_toml_datetime(&mut self) -> ScannerResult<'text, &'text str> {
+        self.scan_with(|scanner| {
+            if scan_toml_partial_time(scanner).is_ok() {
+                return Ok(());
+            }
+
+            // full-date = date-fullyear "-" date-month "-" date-mday
+            scan_toml_digits(scanner, 4)?;
+            scanner.accept_char('-')?;
+            scan_toml_digits(scanner, 2)?;
+            scanner.accept_char('-')?;
+            scan_toml_digits(scanner, 2)?;
+
+            let has_time = scanner.peeking(|scanner| {
+                scanner.accept_char_any(&['T', 't', ' ']).is_ok()
+                    && scan_toml_partial_time(scanner).is_ok()
+            });
+            if !has_time {
+                return Ok(());
+            }
+            _ = scanner.next();
+            scan_toml_partial_time(scanner)?;
+
+            // time-offset = "Z" / time-numoffset
+            if scanner.accept_char_any(&['Z', 'z']).is_err() {
+                _ = scanner.scan_with(|scanner| {
+                    scanner.accept_char_any(&['+', '-'])?;
+                    scan_toml_digits(scanner, 2)?;
+                    scanner.accept_char(':')?;
+                    scan_toml_digits(scanner, 2)?;
+                    Ok(())
+                });
+            }
+
+            Ok(())
+        })
+    }
+}
+
+/// Scans a simple key, i.e. a bare key or a single-line string.
+///
+/// Reference: <https://toml.io/en/v1.0.0#keys>
+fn scan_toml_simple_key<'text>(scanner: &mut Scanner<'text>) -> ScannerResult<'text, &'text str> {
+    scanner
+        .scan_toml_bare_key()
+        .or_else(|_| scanner.scan_toml_basic_string())
+        .or_else(|_| scanner.scan_toml_literal_string())
+}
+
+/// Scans a multi-line string delimited by three `quote`s, where `escapes`
+/// controls whether `\` escapes the next character. Up to two additional
+/// `quote`s are allowed before the closing delimiter, e.g. `""""a"""""`.
+///
+/// Reference: <https://toml.io/en/v1.0.0#string>
+fn scan_toml_ml_string<'text>(
+    scanner: &mut Scanner<'text>,
+    quote: char,
+    escapes: bool,
+) -> ScannerResult<'text, ScanOutcome> {
+    let delim = if quote == '"' { "\"\"\"" } else { "'''" };
+
+    let mut outcome = ScanOutcome::Terminated;
+    let (r, _s) = scanner.scan_with(|scanner| {
+        let (open_range, _s) = scanner.accept_str(delim)?;
+
+        loop {
+            if escapes {
+                scanner.skip_until_char_any(&[quote, '\\']);
+            } else {
+                scanner.skip_until_char(quote);
+            }
+
+            if scanner.accept_str(delim).is_ok() {
+                // Include up to two quotes, which are part of the content
+                for _ in 0..2 {
+                    if scanner.accept_char(quote).is_err() {
+                        break;
+                    }
+                }
+                break;
+            }
+
+            match scanner.next() {
+                Ok((_r, '\\')) => {
+                    // Skip the next character as it is escaped
+                    _ = scanner.next();
+                }
+                Ok(_) => {}
+                Err(_) => {
+                    outcome = ScanOutcome::Unterminated(open_range);
+                    break;
+                }
+            }
+        }
+
+        Ok(())
+    })?;
+    Ok((r, outcome))
+}
+
+/// Scans the digits of a decimal integer, excluding any sign, where
+/// leading zeros are not allowed, e.g. `0` and `1_000` but not `01`.
+///
+/// Reference: <https://toml.io/en/v1.0.0#integer>
+fn scan_toml_dec_int<'text>(scanner: &mut Scanner<'text>) -> ScannerResult<'text, &'text str> {
+    scanner.scan_with(|scanner| {
+        if let Ok((r, _c)) = scanner.accept_char('0') {
+            if scanner
+                .accept_if(|c| c.is_ascii_digit() || (c == '_'))
+                .is_ok()
+            {
+                return Err(scanner.ranged_text(r.start..scanner.cursor_pos()));
+            }
+            return Ok(());
+        }
+        scanner.scan_digits_sep(10, '_', TOML_DIGIT_SEPARATORS)?;
+        Ok(())
+    })
+}
+
+/// Scans exactly `n` ASCII digits.
+fn scan_toml_digits<'text>(
+    scanner: &mut Scanner<'text>,
+    n: usize,
+) -> ScannerResult<'text, &'text str> {
+    scanner.scan_with(|scanner| {
+        for _ in 0..n {
+            scanner.accept_if(|c| c.is_ascii_digit())?;
+        }
+        Ok(())
+    })
+}
+
+/// Scans a time without an offset, e.g. `07:32:00` and `00:32:00.999999`.
+///
+/// Reference: <https://datatracker.ietf.org/doc/html/rfc3339#section-5.6>
+fn scan_toml_partial_time<'text>(scanner: &mut Scanner<'text>) -> ScannerResult<'text, &'text str> {
+    scanner.scan_with(|scanner| {
+        scan_toml_digits(scanner, 2)?;
+        scanner.accept_char(':')?;
+        scan_toml_digits(scanner, 2)?;
+        scanner.accept_char(':')?;
+        scan_toml_digits(scanner, 2)?;
+
+        if scanner.peeking(|scanner| {
+            scanner.accept_char('.').is_ok() && scanner.accept_if(|c| c.is_ascii_digit()).is_ok()
+        }) {
+            _ = scanner.next();
+            scanner.skip_while_in(&ByteSet::ASCII_DIGIT);
+        }
+
+        Ok(())
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{assert_invalid_cases, assert_valid_cases};
+
+    #[test]
+    fn test_toml_line_comments() {
+        let cases = ["#", "# Comment", "#[package]", "## \"\"\""];
+
+        assert_valid_cases!(scan_toml_line_comment, cases);
+        assert_valid_cases!(scan_toml_line_comment, cases, "\nrest");
+    }
+
+    #[test]
+    fn test_toml_keys() {
+        let cases = [
+            "key",
+            "bare_key",
+            "bare-key",
+            "1234",
+            "\"127.0.0.1\"",
+            "\"ʎǝʞ\"",
+            "'quoted \"value\"'",
+            "physical.color",
+            "site.\"google.com\"",
+            "fruit . flavor",
+            "3.14159",
+        ];
+
+        assert_valid_cases!(scan_toml_key, cases);
+        assert_valid_cases!(scan_toml_key, cases, " = 1");
+        assert_valid_cases!(scan_toml_key, cases, "\t=");
+        assert_valid_cases!(scan_toml_key, cases, ". = 1");
+    }
+
+    #[test]
+    fn test_toml_keys_invalid() {
+        let cases = ["", ".a", "= 1", "[a]"];
+
+        assert_invalid_cases!(scan_toml_key, cases);
+    }
+
+    #[test]
+    fn test_toml_table_headers() {
+        let cases = [
+            "[table]",
+            "[dependencies.serde]",
+            "[ j . \"ʞ\" . 'l' ]",
+            "[[bin]]",
+            "[[ fruits.varieties ]]",
+        ];
+
+        assert_valid_cases!(scan_toml_table_header, cases);
+        assert_valid_cases!(scan_toml_table_header, cases, " # Comment");
+    }
+
+    #[test]
+    fn test_toml_table_headers_invalid() {
+        let cases = ["[]", "[[]]", "[a", "[[a]", "[a.]", "[ [a] ]", "a]"];
+
+        assert_invalid_cases!(scan_toml_table_header, cases);
+    }
+
+    #[test]
+    fn test_toml_strings() {
+        let cases = [
+            r#""""#,
+            r#""Hello World""#,
+            r#""\"Hello\" \u00E9 \\""#,
+            "''",
+            r"'C:\Users\nodejs\templates'",
+            r#"'<\i\c*\s*>'"#,
+            "\"\"\"\"\"\"",
+            "\"\"\"\nRoses are red\nViolets are blue\"\"\"",
+            "\"\"\"\\\n  The quick brown \\\n  fox\"\"\"",
+            r#""""Here are two quotation marks: "". Simple enough.""""#,
+            r#""""Here are fifteen quotation marks: ""\"""\"""\"""\"""\".""""#,
+            r#"""""This," she said, "is just a pointless statement.""""""#,
+            "''''''",
+            "'''\nThe first newline is\ntrimmed in raw strings.\n'''",
+            "'''Here are fifteen quotation marks: \"\"\"\"\"\"\"\"\"\"\"\"\"\"\"'''",
+            "''''That,' she said, 'is still pointless.''''",
+        ];
+
+        assert_valid_cases!(scan_toml_string, cases);
+        assert_valid_cases!(scan_toml_string, cases, " # Comment");
+    }
+
+    #[test]
+    fn test_toml_strings_unterminated() {
+        let cases = [
+            ("\"abc\ndef\"", 0..4, ScanOutcome::Unterminated(0..1)),
+            ("\"abc\\\ndef\"", 0..5, ScanOutcome::Unterminated(0..1)),
+            ("'abc\ndef'", 0..4, ScanOutcome::Unterminated(0..1)),
+            ("'abc", 0..4, ScanOutcome::Unterminated(0..1)),
+        ];
+
+        for (text, expected_range, expected_outcome) in cases {
+            let mut scanner = Scanner::new(text);
+            let actual = scanner
+                .scan_toml_basic_string_outcome()
+                .or_else(|_| scanner.scan_toml_literal_string_outcome());
+            assert_eq!(actual, Ok((expected_range, expected_outcome)), "{text:?}");
+        }
+
+        let mut scanner = Scanner::new("\"\"\"abc\"\"");
+        assert_eq!(
+            scanner.scan_toml_ml_basic_string_outcome(),
+            Ok((0..8, ScanOutcome::Unterminated(0..3))),
+        );
+
+        let mut scanner = Scanner::new("'''abc\n''");
+        assert_eq!(
+            scanner.scan_toml_ml_literal_string_outcome(),
+            Ok((0..9, ScanOutcome::Unterminated(0..3))),
+        );
+    }
+
+    #[test]
+    fn test_toml_integers() {
+        let cases = [
+            "0",
+            "+0",
+            "-0",
+            "+99",
+            "42",
+            "-17",
+            "1_000",
+            "5_349_221",
+            "53_49_221",
+            "1_2_3_4_5",
+            "0xDEADBEEF",
+            "0xdeadbeef",
+            "0xdead_beef",
+            "0o01234567",
+            "0o755",
+            "0b11010110",
+        ];
+
+        assert_valid_cases!(scan_toml_integer, cases);
+        assert_valid_cases!(scan_toml_integer, cases, " # Comment");
+        assert_valid_cases!(scan_toml_integer, cases, ",");
+    }
+
+    #[test]
+    fn test_toml_integers_invalid() {
+        let cases = [
+            "", "_1", "+", "01", "-01", "0_1", "1__0", "0x", "0x_1", "0b2", "+0x1",
+        ];
+
+        for case in cases {
+            let mut scanner = Scanner::new(case);
+            if let Ok((r, _s)) = scanner.scan_toml_integer() {
+                assert_ne!(r.len(), case.len(), "{case:?}");
+            }
+        }
+    }
+
+    #[test]
+    fn test_toml_floats() {
+        let cases = [
+            "+1.0",
+            "3.1415",
+            "-0.01",
+            "5e+22",
+            "1e06",
+            "-2E-2",
+            "6.626e-34",
+            "224_617.445_991",
+            "0.0",
+            "+0.0",
+            "-0.0",
+            "0e0",
+            "inf",
+            "+inf",
+            "-inf",
+            "nan",
+            "+nan",
+            "-nan",
+        ];
+
+        assert_valid_cases!(scan_toml_float, cases);
+        assert_valid_cases!(scan_toml_float, cases, " # Comment");
+        assert_valid_cases!(scan_toml_float, cases, ",");
+    }
+
+    #[test]
+    fn test_toml_floats_invalid() {
+        let cases = [
+            "", "1", "-1", ".7", "7.", "3.e+20", "1e", "1e_1", "01.5", "infinity",
+        ];
+
+        for case in cases {
+            let mut scanner = Scanner::new(case);
+            if let Ok((r, _s)) = scanner.scan_toml_float() {
+                assert_ne!(r.len(), case.len(), "{case:?}");
+            }
+        }
+    }
+
+    #[test]
+    fn test_toml_booleans() {
+        let cases = ["true", "false"];
+
+        assert_valid_cases!(scan_toml_boolean, cases);
+        assert_valid_cases!(scan_toml_boolean, cases, " # Comment");
+        assert_valid_cases!(scan_toml_boolean, cases, ",");
+        assert_valid_cases!(scan_toml_boolean, cases, "]");
+
+        assert_invalid_cases!(scan_toml_boolean, ["True", "truer", "false_", "t"]);
+    }
+
+    #[test]
+    fn test_toml_datetimes() {
+        let cases = [
+            "1979-05-27T07:32:00Z",
+            "1979-05-27T00:32:00-07:00",
+            "1979-05-27T00:32:00.999999-07:00",
+            "1979-05-27 07:32:00Z",
+            "1979-05-27t07:32:00z",
+            "1979-05-27T07:32:00",
+            "1979-05-27T00:32:00.999999",
+            "1979-05-27",
+            "07:32:00",
+            "00:32:00.999999",
+        ];
+
+        assert_valid_cases!(scan_toml_datetime, cases);
+        assert_valid_cases!(scan_toml_datetime, cases, " # Comment");
+        assert_valid_cases!(scan_toml_datetime, cases, ",");
+    }
+
+    #[test]
+    fn test_toml_datetimes_invalid() {
+        let cases = [
+            "",
+            "1979",
+            "1979-05",
+            "1979-5-27",
+            "07:32",
+            "7:32:00",
+            "1_979-05-27",
+        ];
+
+        assert_invalid_cases!(scan_toml_datetime, cases);
+    }
+
+    #[test]
+    fn test_toml_datetimes_partial() {
+        let cases = [
+            ("1979-05-27 # Comment", "1979-05-27"),
+            ("1979-05-27 07:32", "1979-05-27"),
+            ("1979-05-27T07:32:00.", "1979-05-27T07:32:00"),
+            ("1979-05-27T07:32:00+07", "1979-05-27T07:32:00"),
+        ];
+
+        for (text, expected) in cases {
+            let mut scanner = Scanner::new(text);
+            assert_eq!(
+                scanner.scan_toml_datetime(),
+                Ok((0..expected.len(), expected)),
+                "{text:?}"
+            );
+        }
+    }
+}
@@ -0,0 +1,580 @@
+use crate::{ByteSet, IdentifierCharset, Scanner, ScannerResult};
+
+// Reference: https://www.w3.org/TR/xml/#NT-Name
+const XML_NAME: IdentifierCharset = IdentifierCharset {
+    start: ByteSet::ASCII_ALPHABETIC
+        .union(ByteSet::from_bytes(b":_"))
+        .with_non_ascii(is_xml_name_start_char),
+    continue_: ByteSet::ASCII_ALPHANUMERIC
+        .union(ByteSet::from_bytes(b":_-."))
+        .with_non_ascii(is_xml_name_char),
+    separator: None,
+};
+
+// Reference: https://www.w3.org/TR/xml-names/#NT-NCName
+const XML_NCNAME: IdentifierCharset = IdentifierCharset {
+    start: ByteSet::ASCII_ALPHABETIC
+        .union(ByteSet::from_bytes(b"_"))
+        .with_non_ascii(is_xml_name_start_char),
+    continue_: ByteSet::ASCII_ALPHANUMERIC
+        .union(ByteSet::from_bytes(b"_-."))
+        .with_non_ascii(is_xml_name_char),
+    separator: None,
+};
+
+// Reference: https://www.w3.org/TR/xml/#sec-prolog-dtd
+pub const XML_MARKUP_DECLARATIONS: &[&str] = &[
+    "<!DOCTYPE",
+    "<!ELEMENT",
+    "<!ATTLIST",
+    "<!ENTITY",
+    "<!NOTATION",
+];
+
+// Reference: https://www.w3.org/TR/xml/#sec-prolog-dtd
+pub const XML_DTD_KEYWORDS: &[&str] = &[
+    "#PCDATA",
+    "#REQUIRED",
+    "#IMPLIED",
+    "#FIXED",
+    "EMPTY",
+    "ANY",
+    "CDATA",
+    "ID",
+    "IDREF",
+    "IDREFS",
+    "ENTITY",
+    "ENTITIES",
+    "NMTOKEN",
+    "NMTOKENS",
+    "NOTATION",
+    "SYSTEM",
+    "PUBLIC",
+    "NDATA",
+];
+
+/// [`Scanner`] extension for scanning [XML] tokens, including tokens
+/// of document type definitions (DTDs).
+///
+/// See also [`HtmlScannerExt`](super::HtmlScannerExt).
+///
+/// [XML]: https://www.w3.org/TR/xml/
+pub trait XmlScannerExt<'text>: crate::private::Sealed {
+    /// Scans a single [XML name], e.g. `svg`, `xlink:href`, and `data-1.0`.
+    ///
+    /// [XML name]: https://www.w3.org/TR/xml/#NT-Name
+    fn scan_xml_name(&mut self) -> ScannerResult<'text, &'text str>;
+
+    /// Scans a single [XML qualified name], i.e. a name with an optional
+    /// namespace prefix, e.g. `svg`, `xlink:href`, and `xsl:value-of`.
+    ///
+    /// As opposed to [`scan_xml_name()`], the name can contain at most
+    /// one `:`, which must be between the prefix and the local part.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use text_scanner::{ext::XmlScannerExt, Scanner};
+    ///
+    /// let mut scanner = Scanner::new("xsl:value-of select");
+    /// assert_eq!(scanner.scan_xml_qname(), Ok((0..12, "xsl:value-of")));
+    ///
+    /// let mut scanner = Scanner::new("a:b:c");
+    /// assert_eq!(scanner.scan_xml_qname(), Ok((0..3, "a:b")));
+    ///
+    /// let mut scanner = Scanner::new(":a");
+    /// assert!(scanner.scan_xml_qname().is_err());
+    /// ```
+    ///
+    /// [XML qualified name]: https://www.w3.org/TR/xml-names/#ns-qualnames
+    /// [`scan_xml_name()`]: Self::scan_xml_name
+    fn scan_xml_qname(&mut self) -> ScannerResult<'text, &'text str>;
+
+    /// Scans a single [XML attribute], i.e. a [qualified name], `=`, and
+    /// a quoted value, where whitespace is allowed around the `=`, e.g.
+    /// `id="a"` and `xmlns:xlink = 'http://www.w3.org/1999/xlink'`.
+    ///
+    /// If the value is unterminated, then it ends at the end of the text.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use text_scanner::{ext::XmlScannerExt, Scanner};
+    ///
+    /// let text = r#"xml:lang = "en" checked>"#;
+    /// let mut scanner = Scanner::new(text);
+    ///
+    /// assert_eq!(scanner.scan_xml_attribute(), Ok((0..15, r#"xml:lang = "en""#)));
+    /// scanner.skip_whitespace();
+    /// // XML attributes always have a value
+    /// assert!(scanner.scan_xml_attribute().is_err());
+    /// assert_eq!(scanner.remaining_text(), "checked>");
+    /// ```
+    ///
+    /// [XML attribute]: https://www.w3.org/TR/xml/#NT-Attribute
+    /// [qualified name]: Self::scan_xml_qname
+    fn scan_xml_attribute(&mut self) -> ScannerResult<'text, &'text str>;
+
+    /// Scans a single [XML entity reference] or [character reference],
+    /// e.g. `&amp;`, `&#169;`, and `&#x1F600;`.
+    ///
+    /// Character references must refer to a valid XML character,
+    /// e.g. `&#0;` and `&#xD800;` are invalid.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use text_scanner::{ext::XmlScannerExt, Scanner};
+    ///
+    /// let text = "&lt;&#x1F600;&#0;";
+    /// let mut scanner = Scanner::new(text);
+    ///
+    /// assert_eq!(scanner.scan_xml_entity(), Ok((0..4, "&lt;")));
+    /// assert_eq!(scanner.scan_xml_entity(), Ok((4..13, "&#x1F600;")));
+    /// assert!(scanner.scan_xml_entity().is_err());
+    /// assert_eq!(scanner.remaining_text(), "&#0;");
+    /// ```
+    ///
+    /// [XML entity reference]: https://www.w3.org/TR/xml/#NT-EntityRef
+    /// [character reference]: https://www.w3.org/TR/xml/#NT-CharRef
+    fn scan_xml_entity(&mut self) -> ScannerResult<'text, &'text str>;
+
+    /// Scans a single [XML parameter-entity reference], e.g. `%name;`,
+    /// as used in DTDs.
+    ///
+    /// [XML parameter-entity reference]: https://www.w3.org/TR/xml/#NT-PEReference
+    fn scan_xml_pe_reference(&mut self) -> ScannerResult<'text, &'text str>;
+
+    /// Scans a single [XML comment], i.e. `<!-- ... -->`.
+    ///
+    /// If the comment is unterminated, then it ends at the end of the text.
+    ///
+    /// [XML comment]: https://www.w3.org/TR/xml/#sec-comments
+    fn scan_xml_comment(&mut self) -> ScannerResult<'text, &'text str>;
+
+    /// Scans a single [XML CDATA section], i.e. `<![CDATA[ ... ]]>`.
+    ///
+    /// If the section is unterminated, then it ends at the end of the text.
+    ///
+    /// [XML CDATA section]: https://www.w3.org/TR/xml/#sec-cdata-sect
+    fn scan_xml_cdata(&mut self) -> ScannerResult<'text, &'text str>;
+
+    /// Scans a single [XML processing instruction], e.g.
+    /// `<?xml version="1.0"?>` and `<?xml-stylesheet href="a.xsl"?>`.
+    ///
+    /// If the processing instruction is unterminated, then it ends
+    /// at the end of the text.
+    ///
+    /// [XML processing instruction]: https://www.w3.org/TR/xml/#sec-pi
+    fn scan_xml_processing_instruction(&mut self) -> ScannerResult<'text, &'text str>;
+
+    /// Scans the start of a single [XML markup declaration], i.e. one of
+    /// [`XML_MARKUP_DECLARATIONS`], e.g. `<!DOCTYPE` and `<!ELEMENT`.
+    ///
+    /// The remainder of the declaration can be scanned using e.g.
+    /// [`scan_xml_name()`], [`scan_xml_dtd_keyword()`], and
+    /// [`scan_xml_literal()`], until the closing `>`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use text_scanner::{ext::XmlScannerExt, Scanner};
+    ///
+    /// let text = r#"<!ENTITY copy "&#169;">"#;
+    /// let mut scanner = Scanner::new(text);
+    ///
+    /// assert_eq!(scanner.scan_xml_markup_decl_start(), Ok((0..8, "<!ENTITY")));
+    /// scanner.skip_whitespace();
+    /// assert_eq!(scanner.scan_xml_name(), Ok((9..13, "copy")));
+    /// scanner.skip_whitespace();
+    /// assert_eq!(scanner.scan_xml_literal(), Ok((14..22, r#""&#169;""#)));
+    /// assert_eq!(scanner.remaining_text(), ">");
+    /// ```
+    ///
+    /// [XML markup declaration]: https://www.w3.org/TR/xml/#NT-markupdecl
+    /// [`scan_xml_name()`]: Self::scan_xml_name
+    /// [`scan_xml_dtd_keyword()`]: Self::scan_xml_dtd_keyword
+    /// [`scan_xml_literal()`]: Self::scan_xml_literal
+    fn scan_xml_markup_decl_start(&mut self) -> ScannerResult<'text, &'text str>;
+
+    /// Scans a single DTD keyword, i.e. one of [`XML_DTD_KEYWORDS`],
+    /// e.g. `#PCDATA`, `#REQUIRED`, and `CDATA`.
+    fn scan_xml_dtd_keyword(&mut self) -> ScannerResult<'text, &'text str>;
+
+    /// Scans a single quoted XML literal, e.g. an [attribute value] or
+    /// a [system literal], i.e. `"..."` or `'...'`.
+    ///
+    /// If the literal is unterminated, then it ends at the end of the text.
+    ///
+    /// [attribute value]: https://www.w3.org/TR/xml/#NT-AttValue
+    /// [system literal]: https://www.w3.org/TR/xml/#NT-SystemLiteral
+    fn scan_xml_literal(&mut self) -> ScannerResult<'text, &'text str>;
+}
+
+impl<'text> XmlScannerExt<'text> for Scanner<'text> {
+    // Reference: https://www.w3.org/TR/xml/#NT-Name
+    #[inline]
+    fn scan_xml_name(&mut self) -> ScannerResult<'text, &'text str> {
+        self.scan_identifier_with_charset(XML_NAME)
+    }
+
+    // Reference: https://www.w3.org/TR/xml-names/#NT-QName
+    fn scan_xml_qname(&mut self) -> ScannerResult<'text, &'text str> {
+        self.scan_with(|scanner| {
+            scanner.scan_identifier_with_charset(XML_NCNAME)?;
+            _ = scanner.scan_with(|scanner| {
+                scanner.accept_char(':')?;
+                scanner.scan_identifier_with_charset(XML_NCNAME)?;
+                Ok(())
+            });
+            Ok(())
+        })
+    }
+
+    // Reference: https://www.w3.org/TR/xml/#NT-Attribute
+    fn scan_xml_attribute(&mut self) -> ScannerResult<'text, &'text str> {
+        self.scan_with(|scanner| {
+            scanner.scan_xml_qname()?;
+            scanner.skip_whitespace();
+            scanner.accept_char('=')?;
+            scanner.skip_whitespace();
+            scanner.scan_xml_literal()?;
+            Ok(())
+        })
+    }
+
+    // Reference: https://www.w3.org/TR/xml/#NT-Reference
+    fn scan_xml_entity(&mut self) -> ScannerResult<'text, &'text str> {
+        self.scan_with(|scanner| {
+            scanner.accept_char('&')?;
+
+            if scanner.accept_char('#').is_ok() {
+                let (radix, (r, digits)) = if scanner.accept_char('x').is_ok() {
+                    (16, scanner.skip_while(|c| c.is_ascii_hexdigit()))
+                } else {
+                    (10, scanner.skip_while(|c| c.is_ascii_digit()))
+                };
+
+                let is_valid = u32::from_str_radix(digits, radix)
+                    .ok()
+                    .and_then(char::from_u32)
+                    .is_some_and(is_xml_char);
+                if !is_valid {
+                    return Err(scanner.ranged_text(r));
+                }
+            } else {
+                scanner.scan_xml_name()?;
+            }
+
+            scanner.accept_char(';')?;
+            Ok(())
+        })
+    }
+
+    // Reference: https://www.w3.org/TR/xml/#NT-PEReference
+    fn scan_xml_pe_reference(&mut self) -> ScannerResult<'text, &'text str> {
+        self.scan_with(|scanner| {
+            scanner.accept_char('%')?;
+            scanner.scan_xml_name()?;
+            scanner.accept_char(';')?;
+            Ok(())
+        })
+    }
+
+    // Reference: https://www.w3.org/TR/xml/#sec-comments
+    fn scan_xml_comment(&mut self) -> ScannerResult<'text, &'text str> {
+        self.scan_with(|scanner| {
+            scanner.accept_str("<!--")?;
+            scanner.skip_until_str("-->");
+            _ = scanner.accept_str("-->");
+            Ok(())
+        })
+    }
+
+    // Reference: https://www.w3.org/TR/xml/#sec-cdata-sect
+    fn scan_xml_cdata(&mut self) -> ScannerResult<'text, &'text str> {
+        self.scan_with(|scanner| {
+            scanner.accept_str("<![CDATA[")?;
+            scanner.skip_until_str("]]>");
+            _ = scanner.accept_str("]]>");
+            Ok(())
+        })
+    }
+
+    // Reference: https://www.w3.org/TR/xml/#sec-pi
+    fn scan_xml_processing_instruction(&mut self) -> ScannerResult<'text, &'text str> {
+        self.scan_with(|scanner| {
+            scanner.accept_str("<?")?;
+            scanner.scan_xml_name()?;
+            scanner.skip_until_str("?>");
+            _ = scanner.accept_str("?>");
+            Ok(())
+        })
+    }
+
+    // Reference: https://www.w3.org/TR/xml/#NT-markupdecl
+    fn scan_xml_markup_decl_start(&mut self) -> ScannerResult<'text, &'text str> {
+        self.scan_with(|scanner| {
+            scanner.accept_str("<!")?;
+            let (r, s) = scanner.scan_xml_name()?;
+
+            let decl = &scanner.text()[(r.start - 2)..r.end];
+            if !XML_MARKUP_DECLARATIONS.contains(&decl) {
+                return Err((r, s));
+            }
+            Ok(())
+        })
+    }
+
+    // Reference: https://www.w3.org/TR/xml/#sec-prolog-dtd
+    fn scan_xml_dtd_keyword(&mut self) -> ScannerResult<'text, &'text str> {
+        self.scan_with(|scanner| {
+            let start = scanner.cursor_pos();
+            _ = scanner.accept_char('#');
+            let (r, _s) = scanner.scan_xml_name()?;
+
+            let keyword = scanner.ranged_text(start..r.end);
+            if !XML_DTD_KEYWORDS.contains(&keyword.1) {
+                return Err(keyword);
+            }
+            Ok(())
+        })
+    }
+
+    // Reference: https://www.w3.org/TR/xml/#NT-AttValue
+    fn scan_xml_literal(&mut self) -> ScannerResult<'text, &'text str> {
+        self.scan_with(|scanner| {
+            let (_r, quote) = scanner.accept_char_any(&['"', '\''])?;
+            scanner.skip_until_char(quote);
+            _ = scanner.accept_char(quote);
+            Ok(())
+        })
+    }
+}
+
+// Reference: https://www.w3.org/TR/xml/#NT-NameStartChar
+fn is_xml_name_start_char(c: char) -> bool {
+    matches!(c,
+        ':' | 'A'..='Z' | '_' | 'a'..='z'
+        | '\u{C0}'..='\u{D6}'
+        | '\u{D8}'..='\u{F6}'
+        | '\u{F8}'..='\u{2FF}'
+        | '\u{370}'..='\u{37D}'
+        | '\u{37F}'..='\u{1FFF}'
+        | '\u{200C}'..='\u{200D}'
+        | '\u{2070}'..='\u{218F}'
+        | '\u{2C00}'..='\u{2FEF}'
+        | '\u{3001}'..='\u{D7FF}'
+        | '\u{F900}'..='\u{FDCF}'
+        | '\u{FDF0}'..='\u{FFFD}'
+        | '\u{10000}'..='\u{EFFFF}'
+    )
+}
+
+// Reference: https://www.w3.org/TR/xml/#NT-NameChar
+fn is_xml_name_char(c: char) -> bool {
+    is_xml_name_start_char(c)
+        || matches!(c,
+            '-' | '.' | '0'..='9'
+            | '\u{B7}'
+            | '\u{300}'..='\u{36F}'
+            | '\u{203F}'..='\u{2040}'
+        )
+}
+
+// Reference: https://www.w3.org/TR/xml/#NT-Char
+fn is_xml_char(c: char) -> bool {
+    matches!(c,
+        '\t' | '\n' | '\r'
+        | '\u{20}'..='\u{D7FF}'
+        | '\u{E000}'..='\u{FFFD}'
+        | '\u{10000}'..='\u{10FFFF}'
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{assert_invalid_cases, assert_valid_cases};
+
+    #[test]
+    fn test_xml_names() {
+        let cases = [
+            "a",
+            "svg",
+            "_x",
+            ":x",
+            "xlink:href",
+            "data-1.0",
+            "ÆØÅ",
+            "名前",
+            "a\u{B7}b",
+        ];
+
+        assert_valid_cases!(scan_xml_name, cases);
+        assert_valid_cases!(scan_xml_name, cases, " rest");
+        assert_valid_cases!(scan_xml_name, cases, "=\"x\"");
+        assert_invalid_cases!(scan_xml_name, ["", "1a", "-a", ".a", "\u{B7}a", " a"]);
+    }
+
+    #[test]
+    fn test_xml_qnames() {
+        let cases = [
+            "a",
+            "svg",
+            "_x",
+            "xlink:href",
+            "xsl:value-of",
+            "a.b:c-d",
+            "名前:ÆØÅ",
+        ];
+
+        assert_valid_cases!(scan_xml_qname, cases);
+        assert_valid_cases!(scan_xml_qname, cases, " rest");
+        assert_valid_cases!(scan_xml_qname, cases, "=\"x\"");
+        assert_valid_cases!(scan_xml_qname, ["a:b"], ":c");
+        assert_valid_cases!(scan_xml_qname, ["a"], ":");
+        assert_valid_cases!(scan_xml_qname, ["a"], ":1");
+        assert_invalid_cases!(scan_xml_qname, ["", ":", ":a", "1a", "-a", " a"]);
+    }
+
+    #[test]
+    fn test_xml_attributes() {
+        let cases = [
+            "a=\"\"",
+            "id='x'",
+            "xml:lang=\"en\"",
+            "xmlns:xlink = \"http://www.w3.org/1999/xlink\"",
+            "a\n=\n'b'",
+        ];
+
+        assert_valid_cases!(scan_xml_attribute, cases);
+        assert_valid_cases!(scan_xml_attribute, cases, "/>");
+        assert_valid_cases!(scan_xml_attribute, cases, " b='c'>");
+        assert_valid_cases!(scan_xml_attribute, ["a=\"Unterminated"]);
+        assert_invalid_cases!(scan_xml_attribute, ["a", "a=", "a=b", "=\"b\"", ":a=\"b\""]);
+    }
+
+    #[test]
+    fn test_xml_entities() {
+        let cases = [
+            "&amp;",
+            "&lt;",
+            "&my-entity;",
+            "&#9;",
+            "&#169;",
+            "&#x1F600;",
+            "&#x10FFFF;",
+        ];
+
+        assert_valid_cases!(scan_xml_entity, cases);
+        assert_valid_cases!(scan_xml_entity, cases, "rest");
+    }
+
+    #[test]
+    fn test_xml_entities_invalid() {
+        let cases = [
+            "&",
+            "&;",
+            "&amp",
+            "& amp;",
+            "&#;",
+            "&#x;",
+            "&#0;",
+            "&#8;",
+            "&#xD800;",
+            "&#xFFFE;",
+            "&#x110000;",
+            "&#99999999999;",
+            "&#X41;",
+            "&#xG;",
+        ];
+
+        assert_invalid_cases!(scan_xml_entity, cases);
+    }
+
+    #[test]
+    fn test_xml_pe_references() {
+        assert_valid_cases!(scan_xml_pe_reference, ["%name;", "%x.y;"], " rest");
+        assert_invalid_cases!(scan_xml_pe_reference, ["%", "%name", "% name;", "%;"]);
+    }
+
+    #[test]
+    fn test_xml_comments() {
+        let cases = ["<!---->", "<!-- Comment -->", "<!-- Multi\nLine -->"];
+
+        assert_valid_cases!(scan_xml_comment, cases);
+        assert_valid_cases!(scan_xml_comment, cases, "<a/>");
+        assert_valid_cases!(scan_xml_comment, ["<!-- Unterminated"]);
+        assert_invalid_cases!(scan_xml_comment, ["<!-", "<!DOCTYPE x>"]);
+    }
+
+    #[test]
+    fn test_xml_cdata() {
+        let cases = ["<![CDATA[]]>", "<![CDATA[<a> & ]]>", "<![CDATA[a]b]]>"];
+
+        assert_valid_cases!(scan_xml_cdata, cases);
+        assert_valid_cases!(scan_xml_cdata, cases, "]]>");
+        assert_valid_cases!(scan_xml_cdata, ["<![CDATA[ Unterminated"]);
+        assert_invalid_cases!(scan_xml_cdata, ["<![CDATA", "<![cdata[x]]>"]);
+    }
+
+    #[test]
+    fn test_xml_processing_instructions() {
+        let cases = [
+            "<?xml version=\"1.0\"?>",
+            "<?xml-stylesheet href=\"a.xsl\" type=\"text/xsl\"?>",
+            "<?php echo 1; ?>",
+        ];
+
+        assert_valid_cases!(scan_xml_processing_instruction, cases);
+        assert_valid_cases!(scan_xml_processing_instruction, cases, "\n<a/>");
+        assert_invalid_cases!(scan_xml_processing_instruction, ["<?", "<? x?>", "<a>"]);
+    }
+
+    #[test]
+    fn test_xml_markup_decl_starts() {
+        assert_valid_cases!(
+            scan_xml_markup_decl_start,
+            XML_MARKUP_DECLARATIONS.iter().copied(),
+            " x>"
+        );
+        assert_invalid_cases!(
+            scan_xml_markup_decl_start,
+            [
+                "<!",
+                "<!doctype html>",
+                "<!DOCTYPEx",
+                "<!ELEMENTS",
+                "<!-- -->",
+                "<![CDATA[x]]>"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_xml_dtd_keywords() {
+        assert_valid_cases!(scan_xml_dtd_keyword, XML_DTD_KEYWORDS.iter().copied());
+        assert_valid_cases!(scan_xml_dtd_keyword, XML_DTD_KEYWORDS.iter().copied(), ")>");
+        assert_invalid_cases!(
+            scan_xml_dtd_keyword,
+            ["#", "#CDATA", "#pcdata", "IDS", "cdata", "PUBLICX"]
+        );
+    }
+
+    #[test]
+    fn test_xml_literals() {
+        let cases = [
+            "\"\"",
+            "''",
+            "\"a 'b' c\"",
+            "'a \"b\" c'",
+            "\"-//W3C//DTD\"",
+        ];
+
+        assert_valid_cases!(scan_xml_literal, cases);
+        assert_valid_cases!(scan_xml_literal, cases, ">");
+        assert_valid_cases!(scan_xml_literal, ["\"Unterminated"]);
+        assert_invalid_cases!(scan_xml_literal, ["", "a", "`a`"]);
+    }
+}
@@ -0,0 +1,273 @@
+//! [`ForwardScan`] for writing lexers, which work with both
+//! [`Scanner`] and [`StreamingScanner`].
+//!
+//! The trait is not re-exported from the crate root, as its methods share
+//! names with the inherent methods of [`Scanner`], and take precedence over
+//! them when calling methods on a `&mut Scanner`. Import it only where it
+//! is used as a bound.
+
+use std::convert::Infallible;
+use std::io::{self, BufRead};
+use std::ops::Range;
+
+use crate::{Scanner, ScannerItem, StreamingScanner};
+
+/// Subset of the [`Scanner`] API, which never moves the cursor backwards
+/// and never needs to re-read text before the cursor.
+///
+/// `ForwardScan` is implemented by both [`Scanner`] and [`StreamingScanner`],
+/// so bounding a lexer by `ForwardScan` guarantees at compile time, that the
+/// lexer can scan text read from a stream, which does not retain the text
+/// before the cursor.
+///
+/// Methods return ranges instead of text, as the text before the cursor is
+/// not necessarily retained. Use the ranges with the original text, if it
+/// is available, e.g. with [`Scanner::text()`].
+///
+/// Methods that fail to match, e.g. [`accept_char()`] returning `Ok(None)`,
+/// do not move the cursor. [`Self::Error`] is only returned if the text
+/// cannot be read, e.g. an [`io::Error`] for a [`StreamingScanner`], and
+/// [`Infallible`] for a [`Scanner`].
+///
+/// Methods which may backtrack, e.g. [`Scanner::scan_with()`] and
+/// [`Scanner::reset()`], are not part of `ForwardScan`.
+///
+/// # Example
+///
+/// ```rust
+/// use std::ops::Range;
+/// use text_scanner::{forward::ForwardScan, Scanner, StreamingScanner};
+///
+/// fn words<S: ForwardScan>(scanner: &mut S) -> Result<Vec<Range<usize>>, S::Error> {
+///     let mut words = Vec::new();
+///     loop {
+///         scanner.skip_whitespace()?;
+///         if !scanner.has_remaining_text()? {
+///             break;
+///         }
+///         words.push(scanner.skip_until(char::is_whitespace)?);
+///     }
+///     Ok(words)
+/// }
+///
+/// let text = "Hello World  !";
+///
+/// let mut scanner = Scanner::new(text);
+/// assert_eq!(words(&mut scanner), Ok(vec![0..5, 6..11, 13..14]));
+///
+/// let mut scanner = StreamingScanner::new(text.as_bytes());
+/// assert_eq!(words(&mut scanner).unwrap(), [0..5, 6..11, 13..14]);
+/// ```
+///
+/// [`accept_char()`]: Self::accept_char
+pub trait ForwardScan: crate::private::Sealed {
+    /// Error returned if the text cannot be read.
+    type Error;
+
+    /// Returns the current cursor position, i.e. the byte offset
+    /// from the start of the text.
+    fn cursor_pos(&self) -> usize;
+
+    /// Returns `true` if any text remains after the cursor.
+    fn has_remaining_text(&mut self) -> Result<bool, Self::Error>;
+
+    /// Returns the next [`char`] and its [`Range`] without
+    /// advancing the cursor, or `None` at the end of the text.
+    fn peek(&mut self) -> Result<Option<ScannerItem<char>>, Self::Error>;
+
+    /// Advances the cursor past the next [`char`], and returns
+    /// it and its [`Range`], or `None` at the end of the text.
+    fn next(&mut self) -> Result<Option<ScannerItem<char>>, Self::Error>;
+
+    /// Advances the cursor past the next [`char`], if `f` returns `true`.
+    fn accept_if<F>(&mut self, f: F) -> Result<Option<ScannerItem<char>>, Self::Error>
+    where
+        F: FnOnce(char) -> bool;
+
+    /// Advances the cursor past the next [`char`], if it is `expected`.
+    fn accept_char(&mut self, expected: char) -> Result<Option<ScannerItem<char>>, Self::Error>;
+
+    /// Advances the cursor past the next text, if it is `expected`,
+    /// and returns its [`Range`].
+    fn accept_str(&mut self, expected: &str) -> Result<Option<Range<usize>>, Self::Error>;
+
+    /// Advances the cursor while `f` returns `true`,
+    /// and returns the [`Range`] of the skipped text.
+    fn skip_while<F>(&mut self, f: F) -> Result<Range<usize>, Self::Error>
+    where
+        F: FnMut(char) -> bool;
+
+    /// Advances the cursor until `f` returns `true`,
+    /// and returns the [`Range`] of the skipped text.
+    fn skip_until<F>(&mut self, mut f: F) -> Result<Range<usize>, Self::Error>
+    where
+        F: FnMut(char) -> bool,
+    {
+        self.skip_while(|c| !f(c))
+    }
+
+    /// Advances the cursor past any whitespace,
+    /// and returns the [`Range`] of the skipped text.
+    #[inline]
+    fn skip_whitespace(&mut self) -> Result<Range<usize>, Self::Error> {
+        self.skip_while(char::is_whitespace)
+    }
+}
+
+impl ForwardScan for Scanner<'_> {
+    type Error = Infallible;
+
+    #[inline]
+    fn cursor_pos(&self) -> usize {
+        Scanner::cursor_pos(self)
+    }
+
+    #[inline]
+    fn has_remaining_text(&mut self) -> Result<bool, Self::Error> {
+        Ok(Scanner::has_remaining_text(self))
+    }
+
+    #[inline]
+    fn peek(&mut self) -> Result<Option<ScannerItem<char>>, Self::Error> {
+        Ok(Scanner::peek(self).ok())
+    }
+
+    #[inline]
+    fn next(&mut self) -> Result<Option<ScannerItem<char>>, Self::Error> {
+        Ok(Scanner::next(self).ok())
+    }
+
+    #[inline]
+    fn accept_if<F>(&mut self, f: F) -> Result<Option<ScannerItem<char>>, Self::Error>
+    where
+        F: FnOnce(char) -> bool,
+    {
+        Ok(Scanner::accept_if(self, f).ok())
+    }
+
+    #[inline]
+    fn accept_char(&mut self, expected: char) -> Result<Option<ScannerItem<char>>, Self::Error> {
+        Ok(Scanner::accept_char(self, expected).ok())
+    }
+
+    #[inline]
+    fn accept_str(&mut self, expected: &str) -> Result<Option<Range<usize>>, Self::Error> {
+        Ok(Scanner::accept_str(self, expected).ok().map(|(r, _s)| r))
+    }
+
+    #[inline]
+    fn skip_while<F>(&mut self, f: F) -> Result<Range<usize>, Self::Error>
+    where
+        F: FnMut(char) -> bool,
+    {
+        Ok(Scanner::skip_while(self, f).0)
+    }
+}
+
+impl<R> ForwardScan for StreamingScanner<R>
+where
+    R: BufRead,
+{
+    type Error = io::Error;
+
+    #[inline]
+    fn cursor_pos(&self) -> usize {
+        StreamingScanner::cursor_pos(self)
+    }
+
+    #[inline]
+    fn has_remaining_text(&mut self) -> Result<bool, Self::Error> {
+        StreamingScanner::has_remaining_text(self)
+    }
+
+    #[inline]
+    fn peek(&mut self) -> Result<Option<ScannerItem<char>>, Self::Error> {
+        Ok(StreamingScanner::peek(self)?.ok())
+    }
+
+    #[inline]
+    fn next(&mut self) -> Result<Option<ScannerItem<char>>, Self::Error> {
+        Ok(StreamingScanner::next(self)?.ok())
+    }
+
+    #[inline]
+    fn accept_if<F>(&mut self, f: F) -> Result<Option<ScannerItem<char>>, Self::Error>
+    where
+        F: FnOnce(char) -> bool,
+    {
+        Ok(StreamingScanner::accept_if(self, f)?.ok())
+    }
+
+    #[inline]
+    fn accept_char(&mut self, expected: char) -> Result<Option<ScannerItem<char>>, Self::Error> {
+        Ok(StreamingScanner::accept_char(self, expected)?.ok())
+    }
+
+    #[inline]
+    fn accept_str(&mut self, expected: &str) -> Result<Option<Range<usize>>, Self::Error> {
+        Ok(StreamingScanner::accept_str(self, expected)?
+            .ok()
+            .map(|(r, _s)| r))
+    }
+
+    #[inline]
+    fn skip_while<F>(&mut self, f: F) -> Result<Range<usize>, Self::Error>
+    where
+        F: FnMut(char) -> bool,
+    {
+        StreamingScanner::skip_while(self, f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::io::BufReader;
+
+    /// Scans numbers, identifiers, and `->`, and asserts that
+    /// the cursor never moves backwards.
+    fn tokens<S: ForwardScan>(scanner: &mut S) -> Result<Vec<Range<usize>>, S::Error> {
+        let mut tokens = Vec::new();
+        let mut last = scanner.cursor_pos();
+        loop {
+            scanner.skip_whitespace()?;
+            let start = scanner.cursor_pos();
+
+            if scanner.accept_if(|c| c.is_ascii_digit())?.is_some() {
+                scanner.skip_while(|c| c.is_ascii_digit())?;
+            } else if scanner.accept_str("->")?.is_some() {
+                // Arrow
+            } else if scanner.accept_char('-')?.is_some() {
+                scanner.skip_while(char::is_alphanumeric)?;
+            } else if scanner.peek()?.is_some() {
+                scanner.next()?;
+                scanner.skip_until(|c| c.is_whitespace() || (c == '-'))?;
+            } else {
+                break;
+            }
+
+            let end = scanner.cursor_pos();
+            assert!(last <= start && start < end);
+            last = end;
+            tokens.push(start..end);
+        }
+        assert!(!scanner.has_remaining_text()?);
+        Ok(tokens)
+    }
+
+    #[test]
+    fn test_forward_scan() {
+        let text = "12 -> foo->-bar  æøå 🦀-1";
+        let expected = [0..2, 3..5, 6..9, 9..11, 11..15, 17..23, 24..28, 28..30];
+
+        let mut scanner = Scanner::new(text);
+        assert_eq!(tokens(&mut scanner), Ok(expected.to_vec()));
+
+        for capacity in 1..5 {
+            let reader = BufReader::with_capacity(capacity, text.as_bytes());
+            let mut scanner = StreamingScanner::with_lookahead(reader, 1);
+            assert_eq!(tokens(&mut scanner).unwrap(), expected, "{capacity}");
+        }
+    }
+}
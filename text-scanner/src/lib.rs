@@ -6,22 +6,43 @@
 #![forbid(unsafe_code)]
 #![forbid(elided_lifetimes_in_paths)]
 
+mod byteset;
+mod closers;
+mod dispatch;
 #[cfg(feature = "ext")]
 pub mod ext;
+pub mod forward;
+mod line_index;
+mod streaming;
+mod utf16;
 
 pub mod prelude {
-    pub use super::{IntoScanner, ScanResult, Scanner, ScannerItem, ScannerResult};
+    pub use super::{
+        ByteSet, Captures, CharClass, Dispatcher, ExpectError, IntoScanner, ScanFn, ScanOutcome,
+        ScanResult, Scanner, ScannerItem, ScannerResult,
+    };
 }
 
 mod private {
     pub trait Sealed {}
 
     impl Sealed for crate::Scanner<'_> {}
+    impl<R> Sealed for crate::StreamingScanner<R> {}
     impl Sealed for str {}
 }
 
 pub use char_ranges::{CharRanges, CharRangesExt, CharRangesOffset};
 
+pub use crate::byteset::ByteSet;
+pub use crate::dispatch::{CharClass, Dispatcher, ScanFn};
+pub use crate::line_index::LineIndex;
+pub use crate::streaming::StreamingScanner;
+pub use crate::utf16::Utf16Map;
+
+use crate::closers::Closers;
+
+use std::error;
+use std::fmt;
 use std::ops::Range;
 
 pub type ScannerItem<T> = (Range<usize>, T);
@@ -30,6 +51,315 @@ pub type ScannerResult<'text, T> = Result<ScannerItem<T>, ScannerItem<&'text str
 
 pub type ScanResult<'text> = Result<(), ScannerItem<&'text str>>;
 
+/// Whether a delimited token, e.g. a string or a block comment, was closed
+/// by its closing delimiter, or ended at the end of the text (or line)
+/// instead.
+///
+/// Returned by the `*_outcome()` variants of scanners, e.g.
+/// `scan_rust_string_outcome()` in [`RustScannerExt`], such that
+/// unterminated tokens can be reported, while still being scanned
+/// the same as by e.g. `scan_rust_string()`.
+///
+/// [`RustScannerExt`]: ext::RustScannerExt
+#[derive(PartialEq, Eq, Clone, Debug)]
+pub enum ScanOutcome {
+    Terminated,
+    /// The closing delimiter is missing, where the range
+    /// is the range of the opening delimiter.
+    Unterminated(Range<usize>),
+}
+
+impl ScanOutcome {
+    #[inline]
+    pub fn is_terminated(&self) -> bool {
+        matches!(self, Self::Terminated)
+    }
+
+    #[inline]
+    pub fn is_unterminated(&self) -> bool {
+        matches!(self, Self::Unterminated(_))
+    }
+}
+
+/// Error returned by the `expect_*` methods, e.g. [`Scanner::expect_char()`],
+/// describing what was expected and what was found instead.
+///
+/// # Example
+///
+/// ```rust
+/// # use text_scanner::{ExpectError, Scanner};
+/// let mut scanner = Scanner::new("let x");
+///
+/// let err = scanner.expect_str("const").unwrap_err();
+/// assert_eq!(
+///     err,
+///     ExpectError {
+///         expected: "\"const\"".to_owned(),
+///         found_range: 0..5,
+///         found: "let x",
+///     }
+/// );
+/// assert_eq!(err.to_string(), "expected \"const\", found \"let x\"");
+/// ```
+#[derive(PartialEq, Eq, Clone, Debug)]
+pub struct ExpectError<'text> {
+    /// Description of what was expected, e.g. `'='` or `"fn"`.
+    pub expected: String,
+    /// The [`Range`] of [`found`](Self::found).
+    pub found_range: Range<usize>,
+    /// The text found instead of the expected, which is empty
+    /// if the end of the text was reached.
+    pub found: &'text str,
+}
+
+impl fmt::Display for ExpectError<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.found.is_empty() {
+            write!(f, "expected {}, found end of input", self.expected)
+        } else {
+            write!(f, "expected {}, found {:?}", self.expected, self.found)
+        }
+    }
+}
+
+impl error::Error for ExpectError<'_> {}
+
+/// Named sub-ranges recorded while scanning a token,
+/// see [`Scanner::scan_capturing()`].
+///
+/// # Example
+///
+/// ```rust
+/// # use text_scanner::{Captures, Scanner};
+/// let text = "r#\"foo\"#";
+/// let mut caps = Captures::new(text);
+/// caps.insert("prefix", 0..3);
+/// caps.insert("body", 3..6);
+///
+/// assert_eq!(caps.get("prefix"), Some((0..3, "r#\"")));
+/// assert_eq!(caps.get("body"), Some((3..6, "foo")));
+/// assert_eq!(caps.get("suffix"), None);
+/// ```
+#[derive(PartialEq, Eq, Clone, Debug)]
+pub struct Captures<'text> {
+    text: &'text str,
+    captures: Vec<(&'static str, Range<usize>)>,
+}
+
+impl<'text> Captures<'text> {
+    /// Constructs new empty [`Captures`] for ranges of `text`.
+    #[inline]
+    pub fn new(text: &'text str) -> Self {
+        Self {
+            text,
+            captures: Vec::new(),
+        }
+    }
+
+    /// Records `range` as `name`. If `name` was already recorded,
+    /// then its range is replaced.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `range` is not within the text, or if it is not
+    /// on UTF-8 [`char`] boundaries.
+    pub fn insert(&mut self, name: &'static str, range: Range<usize>) {
+        assert!(
+            self.text.get(range.clone()).is_some(),
+            "range {range:?} is not a valid range of the text"
+        );
+
+        match self.captures.iter_mut().find(|(n, _r)| *n == name) {
+            Some((_name, r)) => *r = range,
+            None => self.captures.push((name, range)),
+        }
+    }
+
+    /// Returns the [`Range`] and `&'text str` recorded as `name`, if any.
+    #[inline]
+    pub fn get(&self, name: &str) -> Option<ScannerItem<&'text str>> {
+        self.captures
+            .iter()
+            .find(|(n, _r)| *n == name)
+            .map(|(_name, r)| (r.clone(), &self.text[r.clone()]))
+    }
+
+    /// Returns an iterator over the recorded names, [`Range`]s,
+    /// and `&'text str`s, in the order they were first recorded.
+    #[inline]
+    pub fn iter(&self) -> impl Iterator<Item = (&'static str, ScannerItem<&'text str>)> + '_ {
+        self.captures
+            .iter()
+            .map(|(name, r)| (*name, (r.clone(), &self.text[r.clone()])))
+    }
+
+    /// Returns the amount of recorded captures.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.captures.len()
+    }
+
+    /// Returns `true` if no captures have been recorded.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.captures.is_empty()
+    }
+}
+
+/// Policy for where digit separators are allowed,
+/// see [`Scanner::scan_digits_sep()`].
+///
+/// Separators between two digits are always allowed,
+/// e.g. `1_000`.
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+pub struct SeparatorPolicy {
+    /// Allow separators before the first digit, e.g. `_1`.
+    ///
+    /// Mainly useful after a prefix, e.g. `0x_FF`.
+    pub leading: bool,
+    /// Allow separators after the last digit, e.g. `1_`.
+    ///
+    /// If `false`, then trailing separators are not included
+    /// in the scanned digits.
+    pub trailing: bool,
+    /// Allow multiple separators in a row, e.g. `1__2`.
+    ///
+    /// If `false`, then multiple separators in a row
+    /// results in an `Err`.
+    pub consecutive: bool,
+}
+
+impl SeparatorPolicy {
+    /// Only allow single separators between digits, e.g. `1_000`.
+    pub const STRICT: Self = Self {
+        leading: false,
+        trailing: false,
+        consecutive: false,
+    };
+
+    /// Allow separators anywhere, as long as there is at least one digit.
+    pub const LENIENT: Self = Self {
+        leading: true,
+        trailing: true,
+        consecutive: true,
+    };
+}
+
+/// Rules for disambiguating generic argument lists from comparisons,
+/// see [`Scanner::scan_nested_generic()`].
+///
+/// Within generic arguments, `(...)` and `[...]` must be balanced, and
+/// nested `<...>` are allowed, e.g. `Vec<[Option<T>; 4]>`.
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+pub struct GenericPolicy {
+    /// Allow `>>` to close two generic argument lists, e.g. `Vec<Vec<T>>`.
+    ///
+    /// If `false`, then `>>` is a shift operator, as in C++ before C++11,
+    /// and results in an `Err`.
+    pub split_shift: bool,
+    /// Treat `->` as an arrow, e.g. `Fn(T) -> U` in Rust or `a->b` in C++,
+    /// instead of `-` followed by a closing `>`.
+    pub arrows: bool,
+    /// Text which never occurs within generic arguments, e.g. `&&`, `||`,
+    /// and `;`, such that encountering it means the `<` is a comparison,
+    /// and results in an `Err`. Stops are only checked outside of nested
+    /// `(...)` and `[...]`, e.g. `;` in `[u8; 4]` is allowed.
+    pub stops: &'static [&'static str],
+}
+
+impl GenericPolicy {
+    /// Generic arguments in Rust, e.g. `HashMap<K, Vec<V>>` and
+    /// `Box<dyn Fn(&'a str) -> T>`.
+    pub const RUST: Self = Self {
+        split_shift: true,
+        arrows: true,
+        stops: &[";", "{", "}", "&&", "||", "==", "!=", "\"", "//", "/*"],
+    };
+
+    /// Template arguments in C++11 and later, e.g. `std::map<K, std::vector<V>>`.
+    pub const CPP: Self = Self {
+        split_shift: true,
+        arrows: true,
+        stops: &[";", "{", "}", "&&", "||", "==", "!=", "//", "/*"],
+    };
+
+    /// Type arguments in Java, e.g. `Map<K, List<? extends V>>` and
+    /// `<T extends A & B>`.
+    pub const JAVA: Self = Self {
+        split_shift: true,
+        arrows: false,
+        stops: &[";", "{", "}", "&&", "||", "==", "!=", "\"", "//", "/*"],
+    };
+}
+
+/// Characters starting and continuing an identifier,
+/// see [`Scanner::scan_identifier_with_charset()`].
+///
+/// # Example
+///
+/// ```rust
+/// # use text_scanner::{ByteSet, IdentifierCharset, Scanner};
+/// // Identifiers such as `$x` and `$1`
+/// const DOLLAR: IdentifierCharset = IdentifierCharset {
+///     start: ByteSet::from_bytes(b"$"),
+///     continue_: ByteSet::ASCII_ALPHANUMERIC,
+///     separator: None,
+/// };
+///
+/// let mut scanner = Scanner::new("$foo1-bar");
+/// assert_eq!(scanner.scan_identifier_with_charset(DOLLAR), Ok((0..5, "$foo1")));
+/// assert_eq!(scanner.remaining_text(), "-bar");
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct IdentifierCharset {
+    /// Characters allowed as the first character.
+    pub start: ByteSet,
+    /// Characters allowed after the first character.
+    pub continue_: ByteSet,
+    /// Character joining parts of the identifier, e.g. `-` in `font-size`.
+    ///
+    /// A separator is only included if it is followed by a character
+    /// in [`continue_`], i.e. identifiers never end with a separator,
+    /// and never contain multiple separators in a row.
+    ///
+    /// [`continue_`]: Self::continue_
+    pub separator: Option<char>,
+}
+
+impl IdentifierCharset {
+    /// ASCII identifiers as in C, e.g. `foo_bar1` and `_x`.
+    pub const C: Self = Self {
+        start: ByteSet::ASCII_ALPHABETIC.union(ByteSet::from_bytes(b"_")),
+        continue_: ByteSet::ASCII_ALPHANUMERIC.union(ByteSet::from_bytes(b"_")),
+        separator: None,
+    };
+
+    /// Unicode identifiers, e.g. `foo_bar1` and `ÆØÅ`, as in Rust and Python.
+    ///
+    /// Approximates `XID_Start` and `XID_Continue` using
+    /// [`char::is_alphabetic()`] and [`char::is_alphanumeric()`].
+    pub const XID: Self = Self {
+        start: ByteSet::ASCII_ALPHABETIC
+            .union(ByteSet::from_bytes(b"_"))
+            .with_non_ascii(char::is_alphabetic),
+        continue_: ByteSet::IDENT_CONTINUE,
+        separator: None,
+    };
+
+    /// Kebab-case identifiers, e.g. `font-size` and `h-100`.
+    pub const KEBAB_CASE: Self = Self {
+        start: ByteSet::ASCII_ALPHABETIC,
+        continue_: ByteSet::ASCII_ALPHANUMERIC,
+        separator: Some('-'),
+    };
+
+    /// Dotted paths of C identifiers, e.g. `java.util.List`.
+    pub const DOTTED: Self = Self {
+        separator: Some('.'),
+        ..Self::C
+    };
+}
+
 #[cfg(test)]
 macro_rules! assert_valid_cases {
     ($method:ident, $cases:expr) => {
@@ -80,17 +410,103 @@ pub(crate) use assert_valid_cases;
 /// **Note:** Cloning `Scanner` is essentially a copy, as it just contains
 /// a `&str` and a `usize` for its `cursor`. However, `Copy` is not
 /// implemented, to avoid accidentally copying immutable `Scanner`s.
+///
+/// # Cloning and Threads
+///
+/// Cloning a `Scanner` is _O(1)_, and the clone has its own independent
+/// `cursor`, i.e. scanning with one never affects the other. `Scanner` is
+/// also [`Send`] and [`Sync`], such that clones can be used for scanning
+/// speculatively in parallel, e.g. trying multiple grammars on the same
+/// text, see [`peek_scope()`].
+///
+/// # Empty Arguments
+///
+/// Methods never panic when given an empty `expected`, e.g.
+/// <code>[accept_str]\(&quot;&quot;)</code>, or `0` characters, e.g.
+/// <code>[peek_str]\(0)</code>. Instead the behavior is documented for each
+/// method, and is the same in both debug and release builds.
+///
+/// [accept_str]: Self::accept_str
+/// [peek_str]: Self::peek_str
+/// [`peek_scope()`]: Self::peek_scope
 #[derive(Clone, Debug)]
 pub struct Scanner<'text> {
     text: &'text str,
     cursor: usize,
+    /// Precomputed line index for `text`, see [`Scanner::with_line_index()`].
+    line_index: Option<&'text LineIndex>,
 }
 
 impl<'text> Scanner<'text> {
     /// Constructs a new [`Scanner`] with `text`.
+    ///
+    /// This is a `const fn`, along with a subset of the other methods,
+    /// e.g. [`test_str()`] and [`accept_char()`], such that `&'static str`
+    /// literals can be validated at compile-time.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use text_scanner::Scanner;
+    /// const fn is_hex_color(text: &str) -> bool {
+    ///     let mut scanner = Scanner::new(text);
+    ///     if scanner.accept_char('#').is_err() {
+    ///         return false;
+    ///     }
+    ///     let bytes = scanner.remaining_text().as_bytes();
+    ///     let mut i = 0;
+    ///     while i < bytes.len() {
+    ///         if !bytes[i].is_ascii_hexdigit() {
+    ///             return false;
+    ///         }
+    ///         i += 1;
+    ///     }
+    ///     matches!(bytes.len(), 3 | 6)
+    /// }
+    ///
+    /// const ACCENT: &str = "#FF8000";
+    /// const _: () = assert!(is_hex_color(ACCENT));
+    /// # assert!(!is_hex_color("#FF80"));
+    /// ```
+    ///
+    /// [`test_str()`]: Self::test_str
+    /// [`accept_char()`]: Self::accept_char
     #[inline]
-    pub fn new(text: &'text str) -> Self {
-        Self { text, cursor: 0 }
+    pub const fn new(text: &'text str) -> Self {
+        Self {
+            text,
+            cursor: 0,
+            line_index: None,
+        }
+    }
+
+    /// Constructs a new [`Scanner`] with `text`, which uses the
+    /// precomputed `line_index` for [`line_col()`], such that
+    /// converting positions is _O(log n)_ instead of walking
+    /// the text for each position.
+    ///
+    /// `line_index` must have been constructed for `text`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use text_scanner::{LineIndex, Scanner};
+    /// let text = "fn main() {\n    foo();\n}";
+    /// let index = LineIndex::new(text);
+    /// let mut scanner = Scanner::with_line_index(text, &index);
+    ///
+    /// scanner.skip_until_str("foo");
+    /// assert_eq!(scanner.line_col(scanner.cursor_pos()), (1, 4));
+    /// ```
+    ///
+    /// [`line_col()`]: Self::line_col
+    #[inline]
+    pub const fn with_line_index(text: &'text str, line_index: &'text LineIndex) -> Self {
+        Self {
+            text,
+            cursor: 0,
+            line_index: Some(line_index),
+        }
     }
 
     /// Returns the `text` the scanner was constructed with.
@@ -111,7 +527,7 @@ impl<'text> Scanner<'text> {
     /// assert_eq!(scanner.remaining_text(), "llo World");
     /// ```
     #[inline]
-    pub fn text(&self) -> &'text str {
+    pub const fn text(&self) -> &'text str {
         self.text
     }
 
@@ -143,8 +559,8 @@ impl<'text> Scanner<'text> {
     /// [`cursor_pos()`]: Self::cursor_pos
     /// [cursor_pos()]: Self::cursor_pos
     #[inline]
-    pub fn remaining_text(&self) -> &'text str {
-        &self.text[self.cursor..]
+    pub const fn remaining_text(&self) -> &'text str {
+        self.text.split_at(self.cursor).1
     }
 
     /// Returns `true` if [`remaining_text()`] has text, i.e.
@@ -172,10 +588,57 @@ impl<'text> Scanner<'text> {
     /// [`remaining_text()`]: Self::remaining_text
     /// [empty]: https://doc.rust-lang.org/std/primitive.str.html#method.is_empty
     #[inline]
-    pub fn has_remaining_text(&self) -> bool {
+    pub const fn has_remaining_text(&self) -> bool {
         self.cursor < self.text.len()
     }
 
+    /// Returns the [`remaining_text()`] and its range, while
+    /// advancing the cursor to the end of the text.
+    ///
+    /// If there is no remaining text, then an empty range at
+    /// the end of the text is returned, i.e. [`eof_item()`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use text_scanner::Scanner;
+    /// let mut scanner = Scanner::new("Hello World");
+    ///
+    /// assert_eq!(scanner.next(), Ok((0..1, 'H')));
+    ///
+    /// assert_eq!(scanner.take_remaining_text(), (1..11, "ello World"));
+    /// assert_eq!(scanner.remaining_text(), "");
+    ///
+    /// assert_eq!(scanner.take_remaining_text(), (11..11, ""));
+    /// ```
+    ///
+    /// [`remaining_text()`]: Self::remaining_text
+    /// [`eof_item()`]: Self::eof_item
+    #[inline]
+    pub fn take_remaining_text(&mut self) -> ScannerItem<&'text str> {
+        let r = self.cursor..self.text.len();
+        self.cursor = self.text.len();
+        self.ranged_text(r)
+    }
+
+    /// Returns an empty range at the end of the text, along with an
+    /// empty string, i.e. `(len..len, "")`, regardless of the cursor
+    /// position.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use text_scanner::Scanner;
+    /// let scanner = Scanner::new("Hello World");
+    ///
+    /// assert_eq!(scanner.eof_item(), (11..11, ""));
+    /// ```
+    #[inline]
+    pub fn eof_item(&self) -> ScannerItem<&'text str> {
+        let len = self.text.len();
+        (len..len, "")
+    }
+
     /// Utility for turning a `Range<usize>` into `(Range<usize>, &'text str)`.
     /// Where `range` is the start end end byte index relative to [`text()`].
     ///
@@ -192,10 +655,88 @@ impl<'text> Scanner<'text> {
     ///
     /// [`text()`]: Self::text
     #[inline]
-    pub fn cursor_pos(&self) -> usize {
+    pub const fn cursor_pos(&self) -> usize {
         self.cursor
     }
 
+    /// Returns the UTF-16 code unit offset of the byte offset `byte_pos`
+    /// into [`text()`], e.g. for the [Language Server Protocol].
+    ///
+    /// This walks the text up until `byte_pos`. When converting
+    /// many positions, e.g. for all tokens, use [`Utf16Map`] instead.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `byte_pos` is out of bounds, or if it is not
+    /// at a UTF-8 sequence boundary.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use text_scanner::Scanner;
+    /// let scanner = Scanner::new("🦀 = 'crab'");
+    ///
+    /// assert_eq!(scanner.utf16_pos(0), 0);
+    /// assert_eq!(scanner.utf16_pos(4), 2);
+    /// assert_eq!(scanner.utf16_pos(7), 5);
+    /// ```
+    ///
+    /// [`text()`]: Self::text
+    /// [Language Server Protocol]: https://microsoft.github.io/language-server-protocol/specifications/lsp/3.17/specification/#textDocuments
+    #[inline]
+    pub fn utf16_pos(&self, byte_pos: usize) -> usize {
+        self.text[..byte_pos].encode_utf16().count()
+    }
+
+    /// Returns the [`LineIndex`] the scanner was constructed with, if any,
+    /// see [`with_line_index()`].
+    ///
+    /// [`with_line_index()`]: Self::with_line_index
+    #[inline]
+    pub const fn line_index(&self) -> Option<&'text LineIndex> {
+        self.line_index
+    }
+
+    /// Returns the zero-based line and byte column of the byte offset `pos`
+    /// into [`text()`], e.g. for reporting diagnostics. Lines are separated
+    /// by `\n`, see [`LineIndex`] for details.
+    ///
+    /// If the scanner was constructed using [`with_line_index()`], then
+    /// this is a binary search. Otherwise this walks the text up until
+    /// `pos`, in which case use a [`LineIndex`] when converting many
+    /// positions.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `pos` is out of bounds, or if it is not
+    /// at a UTF-8 sequence boundary.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use text_scanner::Scanner;
+    /// let scanner = Scanner::new("a\r\nbc\n");
+    ///
+    /// assert_eq!(scanner.line_col(0), (0, 0));
+    /// assert_eq!(scanner.line_col(2), (0, 2));
+    /// assert_eq!(scanner.line_col(4), (1, 1));
+    /// assert_eq!(scanner.line_col(6), (2, 0));
+    /// ```
+    ///
+    /// [`text()`]: Self::text
+    /// [`with_line_index()`]: Self::with_line_index
+    pub fn line_col(&self, pos: usize) -> (usize, usize) {
+        let before = &self.text[..pos];
+        match self.line_index {
+            Some(index) => index.line_col(pos),
+            None => {
+                let line = before.bytes().filter(|&b| b == b'\n').count();
+                let col = pos - before.rfind('\n').map_or(0, |i| i + 1);
+                (line, col)
+            }
+        }
+    }
+
     /// Replaces the current cursor position with `pos`,
     /// while returning the old cursor position.
     ///
@@ -270,6 +811,53 @@ impl<'text> Scanner<'text> {
         f(&mut scanner)
     }
 
+    /// Returns an independent [`Scanner`] with the same [`text()`] and
+    /// [`cursor_pos()`], for speculatively scanning ahead, without
+    /// affecting this scanner. Unlike [`peeking()`], the returned scanner
+    /// is not limited to the scope of a closure, so it can e.g. be moved
+    /// to another thread.
+    ///
+    /// This is the same as [`clone()`], and is _O(1)_. Neither scanner
+    /// interferes with the other, as scanners only share the immutable
+    /// [`text()`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use text_scanner::Scanner;
+    /// let text = "fn main() {}";
+    /// let scanner = Scanner::new(text);
+    ///
+    /// // Try scanning multiple grammars in parallel
+    /// let grammars: [fn(&mut Scanner<'_>) -> bool; 2] = [
+    ///     |scanner| scanner.accept_str("fn ").is_ok(),
+    ///     |scanner| scanner.accept_str("def ").is_ok(),
+    /// ];
+    /// let matches = std::thread::scope(|s| {
+    ///     let handles = grammars.map(|grammar| {
+    ///         let mut scanner = scanner.peek_scope();
+    ///         s.spawn(move || grammar(&mut scanner))
+    ///     });
+    ///     handles.map(|handle| handle.join().unwrap())
+    /// });
+    ///
+    /// assert_eq!(matches, [true, false]);
+    /// assert_eq!(scanner.cursor_pos(), 0);
+    /// ```
+    ///
+    /// [`text()`]: Self::text
+    /// [`cursor_pos()`]: Self::cursor_pos
+    /// [`peeking()`]: Self::peeking
+    /// [`clone()`]: Clone::clone
+    #[inline]
+    pub const fn peek_scope(&self) -> Scanner<'text> {
+        Scanner {
+            text: self.text,
+            cursor: self.cursor,
+            line_index: self.line_index,
+        }
+    }
+
     /// Advances the scanner cursor and returns the next
     /// [`char`] and its [`Range`], if any.
     ///
@@ -429,15 +1017,12 @@ impl<'text> Scanner<'text> {
     /// characters. However `"foo"` has a length of 3 bytes, while `"🦀🦀🦀"`
     /// has a length of 12 bytes, when encoded in UTF-8.
     ///
-    /// # Panics
-    ///
-    /// Panics in non-optimized builds, if `n` is `0`.
-    ///
-    /// In optimized builds <code>Err(([cursor]..[cursor], &quot;&quot;))</code>
-    /// is returned instead, regardless of whether there is any remaining
-    /// characters.
+    /// # Zero Characters
     ///
-    /// In short there is a <code>[debug_assert_ne!](n, 0)</code>.
+    /// If `n` is `0`, then <code>Err(([cursor]..[cursor], &quot;&quot;))</code>
+    /// is returned, regardless of whether there is any remaining characters.
+    /// See [`try_peek_str()`] for a variant, where `0` results in an empty
+    /// string slice.
     ///
     /// # Example
     ///
@@ -459,6 +1044,7 @@ impl<'text> Scanner<'text> {
     ///
     /// [remaining text]: Self::remaining_text
     /// [`next_line()`]: Self::next_line
+    /// [`try_peek_str()`]: Self::try_peek_str
     /// [chars()]: str::chars
     /// [count()]: Iterator::count()
     /// [`len()`]: str::len
@@ -488,15 +1074,12 @@ impl<'text> Scanner<'text> {
     /// characters. However `"foo"` has a length of 3 bytes, while `"🦀🦀🦀"`
     /// has a length of 12 bytes, when encoded in UTF-8.
     ///
-    /// # Panics
-    ///
-    /// Panics in non-optimized builds, if `n` is `0`.
+    /// # Zero Characters
     ///
-    /// In optimized builds <code>Err(([cursor]..[cursor], &quot;&quot;))</code>
-    /// is returned instead, regardless of whether there is any remaining
-    /// characters.
-    ///
-    /// In short there is a <code>[debug_assert_ne!](n, 0)</code>.
+    /// If `n` is `0`, then <code>Err(([cursor]..[cursor], &quot;&quot;))</code>
+    /// is returned, regardless of whether there is any remaining characters.
+    /// See [`try_peek_str()`] for a variant, where `0` results in an empty
+    /// string slice.
     ///
     /// # Example
     ///
@@ -519,13 +1102,13 @@ impl<'text> Scanner<'text> {
     /// ```
     ///
     /// [remaining text]: Self::remaining_text
+    /// [`try_peek_str()`]: Self::try_peek_str
     /// [chars()]: str::chars
     /// [count()]: Iterator::count()
     /// [`len()`]: str::len
     /// [cursor]: Self::cursor_pos()
     #[inline]
     pub fn peek_str(&self, n: usize) -> ScannerResult<'text, &'text str> {
-        debug_assert_ne!(n, 0, "`n` must be greater than 0");
         if n == 0 {
             return Err((self.cursor..self.cursor, ""));
         }
@@ -534,6 +1117,63 @@ impl<'text> Scanner<'text> {
         Ok(self.ranged_text(r))
     }
 
+    /// Advances the scanner cursor and returns `Some` with a string slice of
+    /// the following `n` characters. If less than `n` are remaining, then
+    /// `None` is returned and the cursor is not advanced.
+    ///
+    /// Same as [`next_str()`], except that `0` is valid for `n`, and results in
+    /// <code>Some(([cursor]..[cursor], &quot;&quot;))</code>.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use text_scanner::Scanner;
+    /// let mut scanner = Scanner::new("Foo");
+    ///
+    /// assert_eq!(scanner.try_next_str(0), Some((0..0, "")));
+    /// assert_eq!(scanner.try_next_str(2), Some((0..2, "Fo")));
+    /// assert_eq!(scanner.try_next_str(2), None);
+    /// assert_eq!(scanner.remaining_text(), "o");
+    /// ```
+    ///
+    /// [`next_str()`]: Self::next_str
+    /// [cursor]: Self::cursor_pos()
+    #[inline]
+    pub fn try_next_str(&mut self, n: usize) -> Option<ScannerItem<&'text str>> {
+        let (r, s) = self.try_peek_str(n)?;
+        self.cursor = r.end;
+        Some((r, s))
+    }
+
+    /// Returns `Some` with a string slice of the following `n` characters,
+    /// without advancing the cursor. If less than `n` are remaining, then
+    /// `None` is returned.
+    ///
+    /// Same as [`peek_str()`], except that `0` is valid for `n`, and results in
+    /// <code>Some(([cursor]..[cursor], &quot;&quot;))</code>.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use text_scanner::Scanner;
+    /// let scanner = Scanner::new("🦀🦀");
+    ///
+    /// assert_eq!(scanner.try_peek_str(0), Some((0..0, "")));
+    /// assert_eq!(scanner.try_peek_str(1), Some((0..4, "🦀")));
+    /// assert_eq!(scanner.try_peek_str(2), Some((0..8, "🦀🦀")));
+    /// assert_eq!(scanner.try_peek_str(3), None);
+    /// ```
+    ///
+    /// [`peek_str()`]: Self::peek_str
+    /// [cursor]: Self::cursor_pos()
+    #[inline]
+    pub fn try_peek_str(&self, n: usize) -> Option<ScannerItem<&'text str>> {
+        if n == 0 {
+            return Some((self.cursor..self.cursor, ""));
+        }
+        self.peek_str(n).ok()
+    }
+
     /// Advances the scanner cursor and returns `Ok` with the `&'text str`
     /// and its [`Range`], of the next line, i.e. all the following characters
     /// until the next line terminator.
@@ -667,19 +1307,13 @@ impl<'text> Scanner<'text> {
         }
     }
 
+    /// Advances the cursor by `len` bytes, and returns the
+    /// [`Range`] of the bytes.
     #[inline]
-    fn test<T, F>(&mut self, f: F) -> ScannerResult<'text, T>
-    where
-        F: FnOnce(&Self) -> Option<(usize, T)>,
-    {
-        match f(self) {
-            Some((len_utf8, c)) => {
-                let start = self.cursor;
-                self.cursor += len_utf8;
-                Ok((start..self.cursor, c))
-            }
-            None => Err((self.cursor..self.cursor, "")),
-        }
+    const fn advance(&mut self, len: usize) -> Range<usize> {
+        let start = self.cursor;
+        self.cursor += len;
+        start..self.cursor
     }
 
     /// This method is a more efficient version of [`accept_str()`], with the
@@ -692,17 +1326,15 @@ impl<'text> Scanner<'text> {
     /// [`accept_str()`]: Self::accept_str
     /// [cursor]: Self::cursor_pos
     #[inline]
-    pub fn test_str(&mut self, expected: &str) -> ScannerResult<'text, &'text str> {
-        self.test(|scanner| {
-            let text = scanner.remaining_text();
-            if text.starts_with(expected) {
-                let len = expected.len();
-                let expected = &text[..len];
-                Some((len, expected))
-            } else {
-                None
-            }
-        })
+    pub const fn test_str(&mut self, expected: &str) -> ScannerResult<'text, &'text str> {
+        let text = self.remaining_text();
+        if expected.is_empty() || !starts_with(text.as_bytes(), expected.as_bytes()) {
+            return Err((self.cursor..self.cursor, ""));
+        }
+        Ok((
+            self.advance(expected.len()),
+            text.split_at(expected.len()).0,
+        ))
     }
 
     /// This method is a more efficient version of [`accept_str_any()`], with the
@@ -715,18 +1347,15 @@ impl<'text> Scanner<'text> {
     /// [`accept_str_any()`]: Self::accept_str_any
     /// [cursor]: Self::cursor_pos
     #[inline]
-    pub fn test_str_any(&mut self, expected: &[&str]) -> ScannerResult<'text, &'text str> {
-        self.test(|scanner| {
-            let text = scanner.remaining_text();
-            for &expected in expected {
-                if text.starts_with(expected) {
-                    let len = expected.len();
-                    let expected = &text[..len];
-                    return Some((len, expected));
-                }
+    pub const fn test_str_any(&mut self, expected: &[&str]) -> ScannerResult<'text, &'text str> {
+        let mut i = 0;
+        while i < expected.len() {
+            if let Ok(item) = self.test_str(expected[i]) {
+                return Ok(item);
             }
-            None
-        })
+            i += 1;
+        }
+        Err((self.cursor..self.cursor, ""))
     }
 
     /// Advances the scanner cursor and returns the next
@@ -797,30 +1426,23 @@ impl<'text> Scanner<'text> {
     /// assert_eq!(scanner.remaining_text(), "llo World");
     /// ```
     #[inline]
-    pub fn accept_char(&mut self, expected: char) -> ScannerResult<'text, char> {
-        self.accept_if(|c| c == expected)
-
-        // self.test(|scanner| {
-        //     scanner
-        //         .remaining_text()
-        //         .starts_with(expected)
-        //         .then(|| (expected.len_utf8(), expected))
-        // })
-    }
+    pub const fn accept_char(&mut self, expected: char) -> ScannerResult<'text, char> {
+        let mut buf = [0; 4];
+        let bytes = expected.encode_utf8(&mut buf).as_bytes();
+        if !starts_with(self.remaining_text().as_bytes(), bytes) {
+            return Err((self.cursor..self.cursor, ""));
+        }
+        Ok((self.advance(bytes.len()), expected))
+    }
 
     /// Advances the scanner cursor and returns the next
     /// [`char`] and its [`Range`], if the next character
     /// matches any `char` produced by `expected`.
     ///
-    /// # Panics
-    ///
-    /// Panics in non-optimized builds, if `expected` is [empty].
-    ///
-    /// In optimized builds <code>Err(([cursor]..[cursor], &quot;&quot;))</code>
-    /// is returned instead, regardless of whether there is any remaining
-    /// characters.
+    /// # Empty `expected`
     ///
-    /// In short there is a <code>[debug_assert!]\(!expected.is_empty())</code>.
+    /// If `expected` is [empty], then <code>Err(([cursor]..[cursor], &quot;&quot;))</code>
+    /// is returned, regardless of whether there is any remaining characters.
     ///
     /// # Example
     ///
@@ -844,8 +1466,6 @@ impl<'text> Scanner<'text> {
     /// [empty]: https://doc.rust-lang.org/std/primitive.slice.html#method.is_empty
     #[inline]
     pub fn accept_char_any(&mut self, expected: &[char]) -> ScannerResult<'text, char> {
-        debug_assert!(!expected.is_empty(), "`expected` is empty");
-
         self.accept_if(|c| expected.contains(&c))
     }
 
@@ -864,15 +1484,10 @@ impl<'text> Scanner<'text> {
     /// the original `text`, so the scanner can continue to be used
     /// while this exists.
     ///
-    /// # Panics
-    ///
-    /// Panics in non-optimized builds, if `expected` is [empty].
-    ///
-    /// In optimized builds <code>Err(([cursor]..[cursor], &quot;&quot;))</code>
-    /// is returned instead, regardless of whether there is any remaining
-    /// characters.
+    /// # Empty `expected`
     ///
-    /// In short there is a <code>[debug_assert!]\(!expected.is_empty())</code>.
+    /// If `expected` is [empty], then <code>Err(([cursor]..[cursor], &quot;&quot;))</code>
+    /// is returned, regardless of whether there is any remaining characters.
     ///
     /// # Example
     ///
@@ -895,7 +1510,6 @@ impl<'text> Scanner<'text> {
     /// [cursor]: Self::cursor_pos
     /// [empty]: https://doc.rust-lang.org/std/primitive.str.html#method.is_empty
     pub fn accept_str(&mut self, expected: &str) -> ScannerResult<'text, &'text str> {
-        debug_assert!(!expected.is_empty(), "`expected` is empty");
         if expected.is_empty() {
             return Err((self.cursor..self.cursor, ""));
         }
@@ -941,17 +1555,11 @@ impl<'text> Scanner<'text> {
     /// the original `text`, so the scanner can continue to be used
     /// while this exists.
     ///
-    /// # Panics
-    ///
-    /// Panics in non-optimized builds, if `expected` is [empty],
-    /// or if `expected` contains an [empty][empty2] `&str`.
+    /// # Empty `expected`
     ///
-    /// In optimized builds <code>Err(([cursor]..[cursor], &quot;&quot;))</code>
-    /// is returned instead, regardless of whether there is any remaining
-    /// characters.
-    ///
-    /// In short there is a <code>[debug_assert!]\(!expected.is_empty())</code>
-    /// (along with a similar assertion for the strings).
+    /// If `expected` is [empty], then <code>Err(([cursor]..[cursor], &quot;&quot;))</code>
+    /// is returned, regardless of whether there is any remaining characters.
+    /// [Empty][empty2] strings in `expected` never match.
     ///
     /// # Example
     ///
@@ -979,7 +1587,6 @@ impl<'text> Scanner<'text> {
     /// [empty]: https://doc.rust-lang.org/std/primitive.slice.html#method.is_empty
     /// [empty2]: https://doc.rust-lang.org/std/primitive.str.html#method.is_empty
     pub fn accept_str_any(&mut self, expected: &[&str]) -> ScannerResult<'text, &'text str> {
-        debug_assert!(!expected.is_empty(), "`expected` is empty");
         if expected.is_empty() {
             return Err((self.cursor..self.cursor, ""));
         }
@@ -998,6 +1605,125 @@ impl<'text> Scanner<'text> {
         Err(self.ranged_text(r))
     }
 
+    /// Advances the scanner cursor and returns the next
+    /// [`char`] and its [`Range`], if `f(c)` returns `true`
+    /// where `c` is the next character.
+    ///
+    /// Same as [`accept_if()`], except that the error is an [`ExpectError`]
+    /// using `expected` as the description of what was expected, along
+    /// with the next character found, if any.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use text_scanner::Scanner;
+    /// let mut scanner = Scanner::new("ab");
+    ///
+    /// assert_eq!(scanner.expect_if(char::is_alphabetic, "letter"), Ok((0..1, 'a')));
+    ///
+    /// let err = scanner.expect_if(char::is_numeric, "digit").unwrap_err();
+    /// assert_eq!(err.found_range, 1..2);
+    /// assert_eq!(err.to_string(), "expected digit, found \"b\"");
+    ///
+    /// assert_eq!(scanner.remaining_text(), "b");
+    /// ```
+    ///
+    /// [`accept_if()`]: Self::accept_if
+    #[inline]
+    pub fn expect_if<F>(
+        &mut self,
+        f: F,
+        expected: &str,
+    ) -> Result<ScannerItem<char>, ExpectError<'text>>
+    where
+        F: FnOnce(char) -> bool,
+    {
+        self.accept_if(f)
+            .map_err(|_| self.expect_error(expected.to_owned(), 1))
+    }
+
+    /// Advances the scanner cursor and returns the next
+    /// [`char`] and its [`Range`], if the next character
+    /// matches `expected`.
+    ///
+    /// Same as [`accept_char()`], except that the error is an [`ExpectError`],
+    /// along with the next character found, if any.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use text_scanner::Scanner;
+    /// let mut scanner = Scanner::new("x");
+    ///
+    /// assert_eq!(scanner.expect_char('x'), Ok((0..1, 'x')));
+    ///
+    /// let err = scanner.expect_char('=').unwrap_err();
+    /// assert_eq!(err.found_range, 1..1);
+    /// assert_eq!(err.to_string(), "expected '=', found end of input");
+    /// ```
+    ///
+    /// [`accept_char()`]: Self::accept_char
+    #[inline]
+    pub fn expect_char(&mut self, expected: char) -> Result<ScannerItem<char>, ExpectError<'text>> {
+        self.accept_char(expected)
+            .map_err(|_| self.expect_error(format!("{expected:?}"), 1))
+    }
+
+    /// Advances the scanner cursor and returns `Ok` with the `&'text str`
+    /// and its [`Range`], if the next characters matches the characters
+    /// in `expected`.
+    ///
+    /// Same as [`accept_str()`], except that the error is an [`ExpectError`],
+    /// where [`found`] is the next characters, as many as in `expected`
+    /// (fewer if the end of the text is reached).
+    ///
+    /// # Empty `expected`
+    ///
+    /// If `expected` is [empty], then an [`ExpectError`] is returned,
+    /// same as [`accept_str()`] returning `Err`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use text_scanner::Scanner;
+    /// let mut scanner = Scanner::new("fn foo");
+    ///
+    /// assert_eq!(scanner.expect_str("fn"), Ok((0..2, "fn")));
+    ///
+    /// let err = scanner.expect_str(" bar").unwrap_err();
+    /// assert_eq!(err.found_range, 2..6);
+    /// assert_eq!(err.to_string(), "expected \" bar\", found \" foo\"");
+    ///
+    /// assert_eq!(scanner.remaining_text(), " foo");
+    /// ```
+    ///
+    /// [`accept_str()`]: Self::accept_str
+    /// [`found`]: ExpectError::found
+    /// [empty]: https://doc.rust-lang.org/std/primitive.str.html#method.is_empty
+    pub fn expect_str(
+        &mut self,
+        expected: &str,
+    ) -> Result<ScannerItem<&'text str>, ExpectError<'text>> {
+        self.accept_str(expected)
+            .map_err(|_| self.expect_error(format!("{expected:?}"), expected.chars().count()))
+    }
+
+    /// Returns an [`ExpectError`] with the next `found_chars` characters,
+    /// without advancing the cursor.
+    fn expect_error(&self, expected: String, found_chars: usize) -> ExpectError<'text> {
+        let end = self
+            .peek_iter()
+            .take(found_chars)
+            .last()
+            .map_or(self.cursor, |(r, _c)| r.end);
+        let (found_range, found) = self.ranged_text(self.cursor..end);
+        ExpectError {
+            expected,
+            found_range,
+            found,
+        }
+    }
+
     /// Advances the scanner cursor and skips zero-to-many characters,
     /// **while** `f(c)` returns `true`, where `c` is the [remaining characters]
     /// in sequential order.
@@ -1051,6 +1777,53 @@ impl<'text> Scanner<'text> {
         self.ranged_text(r)
     }
 
+    /// Same as [`skip_while()`], but skips characters contained in `set`,
+    /// which is faster than using a closure, as ASCII characters are
+    /// checked using a lookup table.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use text_scanner::{ByteSet, Scanner};
+    /// let mut scanner = Scanner::new("foo_bar1 = 2");
+    ///
+    /// assert_eq!(scanner.skip_while_in(&ByteSet::IDENT_CONTINUE), (0..8, "foo_bar1"));
+    /// assert_eq!(scanner.skip_while_in(&ByteSet::IDENT_CONTINUE), (8..8, ""));
+    ///
+    /// assert_eq!(scanner.remaining_text(), " = 2");
+    /// ```
+    ///
+    /// [`skip_while()`]: Self::skip_while
+    #[inline]
+    pub fn skip_while_in(&mut self, set: &ByteSet) -> ScannerItem<&'text str> {
+        self.skip_in(set, true)
+    }
+
+    fn skip_in(&mut self, set: &ByteSet, contained: bool) -> ScannerItem<&'text str> {
+        let start = self.cursor;
+
+        let bytes = self.text.as_bytes();
+        while let Some(&b) = bytes.get(self.cursor) {
+            let len = if b.is_ascii() {
+                if set.contains(b) != contained {
+                    break;
+                }
+                1
+            } else {
+                // The cursor is always at a char boundary
+                let c = self.text[self.cursor..].chars().next().unwrap();
+                if set.contains_char(c) != contained {
+                    break;
+                }
+                c.len_utf8()
+            };
+            self.cursor += len;
+        }
+
+        let r = start..self.cursor;
+        self.ranged_text(r)
+    }
+
     #[allow(dead_code)]
     #[inline]
     pub(crate) fn skip_while_ext<A, Args>(&mut self, mut skip: A) -> ScannerItem<&'text str>
@@ -1099,15 +1872,10 @@ impl<'text> Scanner<'text> {
     /// If `expected` is only 1 character, then use [`skip_while_char()`]
     /// instead.
     ///
-    /// # Panics
-    ///
-    /// Panics in non-optimized builds, if `expected` is [empty].
-    ///
-    /// In optimized builds 0 characters are skipped, and
-    /// <code>([cursor]..[cursor], &quot;&quot;)</code> is returned instead,
-    /// regardless of whether there is any remaining characters.
+    /// # Empty `expected`
     ///
-    /// In short there is a <code>[debug_assert!]\(!expected.is_empty())</code>.
+    /// If `expected` is [empty], then 0 characters are skipped, and
+    /// <code>([cursor]..[cursor], &quot;&quot;)</code> is returned.
     ///
     /// # Example
     ///
@@ -1124,8 +1892,19 @@ impl<'text> Scanner<'text> {
     #[inline]
     pub fn skip_while_str(&mut self, expected: &str) -> ScannerItem<&'text str> {
         let start = self.cursor;
+        if expected.is_empty() {
+            return (start..start, "");
+        }
 
-        while self.accept_str(expected).is_ok() {}
+        // Comparing bytes is sufficient, as a complete match of a valid
+        // UTF-8 `expected` always ends at a char boundary
+        let count = self
+            .remaining_text()
+            .as_bytes()
+            .chunks_exact(expected.len())
+            .take_while(|&chunk| chunk == expected.as_bytes())
+            .count();
+        self.cursor += count * expected.len();
 
         self.ranged_text(start..self.cursor)
     }
@@ -1147,17 +1926,11 @@ impl<'text> Scanner<'text> {
     /// If `expected` only contains 1 character strings, then use
     /// [`skip_while_char_any()`] instead.
     ///
-    /// # Panics
-    ///
-    /// Panics in non-optimized builds, if `expected` is [empty],
-    /// or if `expected` contains an [empty][empty2] `&str`.
+    /// # Empty `expected`
     ///
-    /// In optimized builds 0 characters are skipped, and
-    /// <code>([cursor]..[cursor], &quot;&quot;)</code> is returned instead,
-    /// regardless of whether there is any remaining characters.
-    ///
-    /// In short there is a <code>[debug_assert!]\(!expected.is_empty())</code>
-    /// (along with a similar assertion for the strings).
+    /// If `expected` is [empty], then 0 characters are skipped, and
+    /// <code>([cursor]..[cursor], &quot;&quot;)</code> is returned.
+    /// [Empty][empty2] strings in `expected` never match.
     ///
     /// # Example
     ///
@@ -1176,7 +1949,15 @@ impl<'text> Scanner<'text> {
     pub fn skip_while_str_any(&mut self, expected: &[&str]) -> ScannerItem<&'text str> {
         let start = self.cursor;
 
-        while self.accept_str_any(expected).is_ok() {}
+        let text = self.remaining_text();
+        let mut len = 0;
+        while let Some(s) = expected
+            .iter()
+            .find(|s| !s.is_empty() && text[len..].starts_with(**s))
+        {
+            len += s.len();
+        }
+        self.cursor += len;
 
         self.ranged_text(start..self.cursor)
     }
@@ -1224,6 +2005,26 @@ impl<'text> Scanner<'text> {
         self.skip_while(|c| !f(c))
     }
 
+    /// Same as [`skip_until()`], but skips characters not contained in
+    /// `set`, which is faster than using a closure, as ASCII characters
+    /// are checked using a lookup table.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use text_scanner::{ByteSet, Scanner};
+    /// let mut scanner = Scanner::new("Hello 123");
+    ///
+    /// assert_eq!(scanner.skip_until_in(&ByteSet::ASCII_DIGIT), (0..6, "Hello "));
+    /// assert_eq!(scanner.remaining_text(), "123");
+    /// ```
+    ///
+    /// [`skip_until()`]: Self::skip_until
+    #[inline]
+    pub fn skip_until_in(&mut self, set: &ByteSet) -> ScannerItem<&'text str> {
+        self.skip_in(set, false)
+    }
+
     #[allow(dead_code)]
     #[inline]
     pub(crate) fn skip_until_ext<A, Args>(&mut self, mut skip: A) -> ScannerItem<&'text str>
@@ -1305,15 +2106,10 @@ impl<'text> Scanner<'text> {
     /// If `expected` is only 1 character, then use [`skip_until_char()`]
     /// instead.
     ///
-    /// # Panics
-    ///
-    /// Panics in non-optimized builds, if `expected` is [empty].
-    ///
-    /// In optimized builds 0 characters are skipped, and
-    /// <code>([cursor]..[cursor], &quot;&quot;)</code> is returned instead,
-    /// regardless of whether there is any remaining characters.
+    /// # Empty `expected`
     ///
-    /// In short there is a <code>[debug_assert!]\(!expected.is_empty())</code>.
+    /// If `expected` is [empty], then 0 characters are skipped, and
+    /// <code>([cursor]..[cursor], &quot;&quot;)</code> is returned.
     ///
     /// # Example
     ///
@@ -1368,17 +2164,11 @@ impl<'text> Scanner<'text> {
     /// If `expected` only contains 1 character strings, then use
     /// [`skip_until_char_any()`] instead.
     ///
-    /// # Panics
-    ///
-    /// Panics in non-optimized builds, if `expected` is [empty],
-    /// or if `expected` contains an [empty][empty2] `&str`.
+    /// # Empty `expected`
     ///
-    /// In optimized builds 0 characters are skipped, and
-    /// <code>([cursor]..[cursor], &quot;&quot;)</code> is returned instead,
-    /// regardless of whether there is any remaining characters.
-    ///
-    /// In short there is a <code>[debug_assert!]\(!expected.is_empty())</code>
-    /// (along with a similar assertion for the strings).
+    /// If `expected` is [empty], then 0 characters are skipped, and
+    /// <code>([cursor]..[cursor], &quot;&quot;)</code> is returned.
+    /// [Empty][empty2] strings in `expected` never match.
     ///
     /// # Example
     ///
@@ -1395,6 +2185,9 @@ impl<'text> Scanner<'text> {
     /// [empty2]: https://doc.rust-lang.org/std/primitive.str.html#method.is_empty
     pub fn skip_until_str_any(&mut self, expected: &[&str]) -> ScannerItem<&'text str> {
         let start = self.cursor;
+        if expected.is_empty() {
+            return (start..start, "");
+        }
 
         while self.has_remaining_text() {
             if let Ok((r, _)) = self.accept_str_any(expected) {
@@ -1424,6 +2217,80 @@ impl<'text> Scanner<'text> {
         self.skip_while(char::is_whitespace)
     }
 
+    /// Skips zero-to-many characters, while the next character is a
+    /// [whitespace], excluding newlines, i.e. only horizontal whitespace
+    /// such as spaces, tabs, and e.g. U+00A0 (no-break space).
+    ///
+    /// Newlines are `\n`, `\r`, U+0085 (next line), U+2028 (line separator),
+    /// and U+2029 (paragraph separator).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use text_scanner::Scanner;
+    /// let mut scanner = Scanner::new(" \t\u{A0}\r\n  Hello");
+    ///
+    /// assert_eq!(scanner.skip_whitespace_no_newline(), (0..4, " \t\u{A0}"));
+    /// assert_eq!(scanner.next_line_terminator(), Ok((4..6, "\r\n")));
+    /// assert_eq!(scanner.skip_whitespace_no_newline(), (6..8, "  "));
+    ///
+    /// assert_eq!(scanner.remaining_text(), "Hello");
+    /// ```
+    ///
+    /// [whitespace]: https://doc.rust-lang.org/std/primitive.char.html#method.is_whitespace
+    #[inline]
+    pub fn skip_whitespace_no_newline(&mut self) -> ScannerItem<&'text str> {
+        self.skip_while(CharExt::is_whitespace_no_newline)
+    }
+
+    /// Skips zero-to-many lines, which only contain [whitespace],
+    /// including their line terminators.
+    ///
+    /// The cursor is left at the start of the first non-blank line,
+    /// such that its indentation is preserved. If all remaining lines
+    /// are blank, then all remaining characters are skipped.
+    ///
+    /// See [`next_line_terminator()`] for information about line terminators.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use text_scanner::Scanner;
+    /// let mut scanner = Scanner::new("\n  \r\n\t\n    foo\n\n");
+    ///
+    /// assert_eq!(scanner.skip_blank_lines(), (0..7, "\n  \r\n\t\n"));
+    /// assert_eq!(scanner.remaining_text(), "    foo\n\n");
+    ///
+    /// // The current line is not blank
+    /// assert_eq!(scanner.skip_blank_lines(), (7..7, ""));
+    ///
+    /// scanner.next_line();
+    /// scanner.next_line_terminator();
+    /// assert_eq!(scanner.skip_blank_lines(), (15..16, "\n"));
+    /// assert_eq!(scanner.remaining_text(), "");
+    /// ```
+    ///
+    /// [whitespace]: https://doc.rust-lang.org/std/primitive.char.html#method.is_whitespace
+    /// [`next_line_terminator()`]: Self::next_line_terminator
+    pub fn skip_blank_lines(&mut self) -> ScannerItem<&'text str> {
+        let start = self.cursor;
+        let mut line_start = start;
+
+        loop {
+            self.skip_whitespace_no_newline();
+            if self.next_line_terminator().is_ok() {
+                line_start = self.cursor;
+            } else {
+                if self.has_remaining_text() {
+                    self.cursor = line_start;
+                }
+                break;
+            }
+        }
+
+        self.ranged_text(start..self.cursor)
+    }
+
     /// Advances the cursor if `f()` returns `Ok`, otherwise on `Err` the
     /// cursor position is backtracked to before `f()` was called.
     ///
@@ -1484,6 +2351,53 @@ impl<'text> Scanner<'text> {
         }
     }
 
+    /// Same as [`scan_with()`], except that `f()` can additionally record
+    /// named sub-ranges of the token, e.g. the prefix, body, and suffix
+    /// of a string literal, into the [`Captures`] returned alongside
+    /// the token.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use text_scanner::{Captures, ScanResult, Scanner};
+    /// fn scan_char<'text>(scanner: &mut Scanner<'text>, caps: &mut Captures<'text>) -> ScanResult<'text> {
+    ///     let (prefix, _s) = scanner.skip_while_char('b');
+    ///     let (quote, _c) = scanner.accept_char('\'')?;
+    ///     caps.insert("prefix", prefix.start..quote.end);
+    ///
+    ///     let (body, _c) = scanner.next()?;
+    ///     caps.insert("body", body);
+    ///
+    ///     let (suffix, _c) = scanner.accept_char('\'')?;
+    ///     caps.insert("suffix", suffix);
+    ///     Ok(())
+    /// }
+    ///
+    /// let text = "b'x' 'y";
+    /// let mut scanner = Scanner::new(text);
+    ///
+    /// let (r, (s, caps)) = scanner.scan_capturing(scan_char).unwrap();
+    /// assert_eq!((r, s), (0..4, "b'x'"));
+    /// assert_eq!(caps.get("prefix"), Some((0..2, "b'")));
+    /// assert_eq!(caps.get("body"), Some((2..3, "x")));
+    /// assert_eq!(caps.get("suffix"), Some((3..4, "'")));
+    ///
+    /// assert_eq!(scanner.next(), Ok((4..5, ' ')));
+    /// assert_eq!(scanner.scan_capturing(scan_char), Err((5..7, "'y")));
+    /// # assert_eq!(scanner.remaining_text(), "'y");
+    /// ```
+    ///
+    /// [`scan_with()`]: Self::scan_with
+    #[inline]
+    pub fn scan_capturing<F>(&mut self, f: F) -> ScannerResult<'text, (&'text str, Captures<'text>)>
+    where
+        F: FnOnce(&mut Self, &mut Captures<'text>) -> ScanResult<'text>,
+    {
+        let mut caps = Captures::new(self.text);
+        let (r, s) = self.scan_with(|scanner| f(scanner, &mut caps))?;
+        Ok((r, (s, caps)))
+    }
+
     /// This function accepts the following formats:
     ///
     /// - `0`
@@ -1575,7 +2489,7 @@ impl<'text> Scanner<'text> {
     /// [EBNF]: https://www.w3.org/TR/REC-xml/#sec-notation
     pub fn scan_digits_or_underscores(&mut self) -> ScannerResult<'text, &'text str> {
         let (first, _c) = self.accept_if_ext(char::is_ascii_digit)?;
-        let (last, _s) = self.skip_while(|c| c.is_ascii_digit() || (c == '_'));
+        let (last, _s) = self.skip_while_in(&ByteSet::ASCII_DIGIT_OR_UNDERSCORE);
         Ok(self.ranged_text(first.start..last.end))
     }
 
@@ -1702,63 +2616,598 @@ impl<'text> Scanner<'text> {
                 }
                 _ => {
                     scanner.accept_if(char::is_ascii_non_zero_digit)?;
-                    scanner.skip_while(|c| c.is_ascii_digit() || (c == '_'));
+                    scanner.skip_while_in(&ByteSet::ASCII_DIGIT_OR_UNDERSCORE);
                 }
             }
             Ok(())
         })
     }
-}
-
-pub trait IntoScanner<'text> {
-    fn into_scanner(self) -> Scanner<'text>;
-}
-
-impl<'text> IntoScanner<'text> for &'text str {
-    #[inline]
-    fn into_scanner(self) -> Scanner<'text> {
-        Scanner::new(self)
-    }
-}
 
-impl<'text, T> From<T> for Scanner<'text>
-where
-    T: IntoScanner<'text>,
-{
+    /// Scans an identifier, i.e. one character matching `start`, followed
+    /// by zero-to-many characters matching `continue_`.
+    ///
+    /// See also [`scan_identifier_with_charset()`] for prebuilt rules,
+    /// e.g. [`IdentifierCharset::C`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use text_scanner::Scanner;
+    /// let mut scanner = Scanner::new("?foo-bar! rest");
+    ///
+    /// let ident = scanner.scan_identifier_with(
+    ///     |c| c.is_ascii_alphabetic() || (c == '?'),
+    ///     |c| c.is_ascii_alphanumeric() || matches!(c, '-' | '!'),
+    /// );
+    /// assert_eq!(ident, Ok((0..9, "?foo-bar!")));
+    /// assert_eq!(scanner.remaining_text(), " rest");
+    /// ```
+    ///
+    /// [`scan_identifier_with_charset()`]: Self::scan_identifier_with_charset
     #[inline]
-    fn from(value: T) -> Self {
-        value.into_scanner()
+    pub fn scan_identifier_with(
+        &mut self,
+        start: impl Fn(char) -> bool,
+        continue_: impl Fn(char) -> bool,
+    ) -> ScannerResult<'text, &'text str> {
+        self.scan_with(|scanner| {
+            scanner.accept_if(start)?;
+            scanner.skip_while(continue_);
+            Ok(())
+        })
     }
-}
-
-// Currently not publicly exported, as using e.g. `accept_if()` with a
-// closure would require specifying types more often than desired.
-pub(crate) trait ScanOne<Args> {
-    fn scan_one(self, next: char) -> bool;
-}
 
-impl<F> ScanOne<char> for F
-where
-    F: FnOnce(char) -> bool,
-{
-    #[inline]
-    fn scan_one(self, next: char) -> bool {
-        self(next)
-    }
-}
+    /// Scans an identifier using the rules of `charset`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use text_scanner::{IdentifierCharset, Scanner};
+    /// let mut scanner = Scanner::new("font-size: 1em");
+    /// assert_eq!(
+    ///     scanner.scan_identifier_with_charset(IdentifierCharset::KEBAB_CASE),
+    ///     Ok((0..9, "font-size")),
+    /// );
+    ///
+    /// let mut scanner = Scanner::new("java.util.List;");
+    /// assert_eq!(
+    ///     scanner.scan_identifier_with_charset(IdentifierCharset::DOTTED),
+    ///     Ok((0..14, "java.util.List")),
+    /// );
+    ///
+    /// let mut scanner = Scanner::new("1st");
+    /// assert!(scanner.scan_identifier_with_charset(IdentifierCharset::C).is_err());
+    /// ```
+    ///
+    /// # Grammar
+    ///
+    /// The following [EBNF] grammar represents what this method accepts:
+    ///
+    /// ```text
+    /// Identifier ::= Start Continue* ( Separator Continue+ )*
+    /// ```
+    ///
+    /// [EBNF]: https://www.w3.org/TR/REC-xml/#sec-notation
+    pub fn scan_identifier_with_charset(
+        &mut self,
+        charset: IdentifierCharset,
+    ) -> ScannerResult<'text, &'text str> {
+        self.scan_with(|scanner| {
+            scanner.accept_if(|c| charset.start.contains_char(c))?;
+            scanner.skip_while_in(&charset.continue_);
+
+            if let Some(sep) = charset.separator {
+                while scanner.peeking(|scanner| {
+                    scanner.accept_char(sep).is_ok()
+                        && scanner
+                            .accept_if(|c| charset.continue_.contains_char(c))
+                            .is_ok()
+                }) {
+                    _ = scanner.next();
+                    scanner.skip_while_in(&charset.continue_);
+                }
+            }
 
-impl<F> ScanOne<&char> for F
-where
-    F: FnOnce(&char) -> bool,
-{
-    #[inline]
-    fn scan_one(self, next: char) -> bool {
-        self(&next)
+            Ok(())
+        })
     }
-}
 
-// Currently not publicly exported, as using e.g. `skip_while()` with a
-// closure would require specifying types more often than desired.
+    /// Scans one-to-many digits in the given `radix`, optionally
+    /// grouped by `sep`, where `policy` controls where separators
+    /// are allowed.
+    ///
+    /// If `policy` disallows [`consecutive`] separators, then multiple
+    /// separators in a row results in an `Err`, with the range up to
+    /// and including the first separator.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `radix` is not in the range `2..=36`, regardless of
+    /// whether any text remains to be scanned.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use text_scanner::{Scanner, SeparatorPolicy};
+    /// let policy = SeparatorPolicy::STRICT;
+    ///
+    /// let mut scanner = Scanner::new("1_000_");
+    /// assert_eq!(scanner.scan_digits_sep(10, '_', policy), Ok((0..5, "1_000")));
+    /// assert_eq!(scanner.remaining_text(), "_");
+    ///
+    /// let mut scanner = Scanner::new("F__F");
+    /// assert_eq!(scanner.scan_digits_sep(16, '_', policy), Err((0..2, "F_")));
+    /// assert_eq!(scanner.remaining_text(), "F__F");
+    ///
+    /// let policy = SeparatorPolicy::LENIENT;
+    ///
+    /// let mut scanner = Scanner::new("_1__0_");
+    /// assert_eq!(scanner.scan_digits_sep(2, '_', policy), Ok((0..6, "_1__0_")));
+    /// ```
+    ///
+    /// # Grammar
+    ///
+    /// The following [EBNF] grammar represents what this method accepts,
+    /// with all separators allowed:
+    ///
+    /// ```text
+    /// Digits ::= Sep* Digit ( Digit | Sep )*
+    /// Digit  ::= [0-9a-zA-Z] (in radix)
+    /// ```
+    ///
+    /// [`consecutive`]: SeparatorPolicy::consecutive
+    /// [EBNF]: https://www.w3.org/TR/REC-xml/#sec-notation
+    pub fn scan_digits_sep(
+        &mut self,
+        radix: u32,
+        sep: char,
+        policy: SeparatorPolicy,
+    ) -> ScannerResult<'text, &'text str> {
+        assert!(
+            (2..=36).contains(&radix),
+            "scan_digits_sep: radix must be in the range 2..=36, got {radix}"
+        );
+
+        let is_digit = |c: char| c.is_digit(radix);
+
+        self.scan_with(|scanner| {
+            if policy.leading {
+                let (r, s) = scanner.skip_while_char(sep);
+                if !policy.consecutive && (s.len() > sep.len_utf8()) {
+                    return Err(scanner.ranged_text(r.start..(r.start + sep.len_utf8())));
+                }
+            }
+
+            scanner.accept_if(is_digit)?;
+
+            loop {
+                scanner.skip_while(is_digit);
+
+                let (r, s) = scanner.skip_while_char(sep);
+                if r.is_empty() {
+                    break;
+                }
+
+                let is_trailing = !scanner.peek().is_ok_and(|(_r, c)| is_digit(c));
+                if is_trailing && !policy.trailing {
+                    scanner.cursor = r.start;
+                    break;
+                } else if !policy.consecutive && (s.len() > sep.len_utf8()) {
+                    return Err(scanner.ranged_text(r.start..(r.start + sep.len_utf8())));
+                } else if is_trailing {
+                    break;
+                }
+            }
+
+            Ok(())
+        })
+    }
+
+    /// Scans an interpolation, e.g. `${...}` in shell and Kotlin strings,
+    /// or `\(...)` in Swift strings, starting with `open` and ending
+    /// with `close`.
+    ///
+    /// Within the interpolation, `nested_delims` are `(open, close)`
+    /// pairs of delimiters, which are balanced. Thereby, `close` only
+    /// ends the interpolation, when all nested delimiters are closed.
+    ///
+    /// Before each character, `scan_nested` is called, to allow skipping
+    /// over e.g. string literals, which can themselves contain `close`
+    /// and further interpolations. If `scan_nested` returns `Err`, then
+    /// the scanner must be left unchanged, e.g. by using [`scan_with()`].
+    ///
+    /// Any character following a `\` is skipped.
+    ///
+    /// If the interpolation is unterminated, then `Err` is returned
+    /// with the range to the end of the text.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use text_scanner::{Scanner, ScannerResult};
+    /// fn scan_string<'text>(scanner: &mut Scanner<'text>) -> ScannerResult<'text, &'text str> {
+    ///     scanner.scan_with(|scanner| {
+    ///         scanner.accept_char('"')?;
+    ///         scanner.skip_until_char('"');
+    ///         scanner.accept_char('"')?;
+    ///         Ok(())
+    ///     })
+    /// }
+    ///
+    /// let text = r#"${map["}"].let { it }} rest"#;
+    /// let mut scanner = Scanner::new(text);
+    ///
+    /// let interpolation = scanner.scan_interpolation("${", "}", &[('{', '}')], scan_string);
+    /// assert_eq!(interpolation, Ok((0..22, r#"${map["}"].let { it }}"#)));
+    /// assert_eq!(scanner.remaining_text(), " rest");
+    ///
+    /// let mut scanner = Scanner::new("${a { b }");
+    /// let interpolation = scanner.scan_interpolation("${", "}", &[('{', '}')], scan_string);
+    /// assert_eq!(interpolation, Err((0..9, "${a { b }")));
+    /// assert_eq!(scanner.remaining_text(), "${a { b }");
+    /// ```
+    ///
+    /// [`scan_with()`]: Self::scan_with
+    pub fn scan_interpolation<F>(
+        &mut self,
+        open: &str,
+        close: &str,
+        nested_delims: &[(char, char)],
+        mut scan_nested: F,
+    ) -> ScannerResult<'text, &'text str>
+    where
+        F: FnMut(&mut Self) -> ScannerResult<'text, &'text str>,
+    {
+        self.scan_with(|scanner| {
+            scanner.accept_str(open)?;
+
+            let mut closers = Closers::new();
+            loop {
+                if closers.is_empty() && scanner.accept_str(close).is_ok() {
+                    return Ok(());
+                }
+
+                if scan_nested(scanner).is_ok() {
+                    continue;
+                }
+
+                match scanner.next()? {
+                    (_r, '\\') => {
+                        // Skip the next character as it is escaped
+                        _ = scanner.next();
+                    }
+                    (_r, c) if closers.pop_if(c) => {}
+                    (_r, c) => {
+                        let opened = nested_delims.iter().find(|&&(open, _)| open == c);
+                        if let Some(&(_open, close)) = opened {
+                            closers.push(close);
+                        }
+                    }
+                }
+            }
+        })
+    }
+
+    /// Scans a block comment, starting with `open` and ending with `close`,
+    /// e.g. `/*` and `*/` in C, or `(*` and `*)` in Pascal.
+    ///
+    /// If `nesting` is `true`, then block comments can be nested, i.e.
+    /// each `open` must be matched by a `close`, as in Rust and Swift.
+    /// Otherwise, the first `close` ends the block comment, as in C.
+    ///
+    /// If the block comment is unterminated, then it ends at the end
+    /// of the text, see [`scan_block_comment_outcome()`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use text_scanner::Scanner;
+    /// let text = "/* a /* b */ c */";
+    ///
+    /// let mut scanner = Scanner::new(text);
+    /// let comment = scanner.scan_block_comment("/*", "*/", false);
+    /// assert_eq!(comment, Ok((0..12, "/* a /* b */")));
+    /// assert_eq!(scanner.remaining_text(), " c */");
+    ///
+    /// let mut scanner = Scanner::new(text);
+    /// let comment = scanner.scan_block_comment("/*", "*/", true);
+    /// assert_eq!(comment, Ok((0..17, "/* a /* b */ c */")));
+    /// assert_eq!(scanner.remaining_text(), "");
+    /// ```
+    ///
+    /// [`scan_block_comment_outcome()`]: Self::scan_block_comment_outcome
+    #[inline]
+    pub fn scan_block_comment(
+        &mut self,
+        open: &str,
+        close: &str,
+        nesting: bool,
+    ) -> ScannerResult<'text, &'text str> {
+        let (r, _outcome) = self.scan_block_comment_outcome(open, close, nesting)?;
+        Ok(self.ranged_text(r))
+    }
+
+    /// Same as [`scan_block_comment()`], but additionally returns whether
+    /// the block comment is unterminated, including if a nested block
+    /// comment is unterminated.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use text_scanner::{ScanOutcome, Scanner};
+    /// let mut scanner = Scanner::new("/* a /* b */");
+    /// assert_eq!(
+    ///     scanner.scan_block_comment_outcome("/*", "*/", true),
+    ///     Ok((0..12, ScanOutcome::Unterminated(0..2))),
+    /// );
+    /// ```
+    ///
+    /// [`scan_block_comment()`]: Self::scan_block_comment
+    pub fn scan_block_comment_outcome(
+        &mut self,
+        open: &str,
+        close: &str,
+        nesting: bool,
+    ) -> ScannerResult<'text, ScanOutcome> {
+        let open_first = open.chars().next();
+        let close_first = close.chars().next();
+
+        let mut outcome = ScanOutcome::Terminated;
+        let (r, _s) = self.scan_with(|scanner| {
+            let (open_range, _s) = scanner.accept_str(open)?;
+
+            let mut depth = 1_usize;
+            loop {
+                scanner.skip_until(|c| {
+                    (Some(c) == close_first) || (nesting && (Some(c) == open_first))
+                });
+
+                if scanner.accept_str(close).is_ok() {
+                    depth -= 1;
+                    if depth == 0 {
+                        break;
+                    }
+                } else if nesting && scanner.accept_str(open).is_ok() {
+                    depth += 1;
+                } else if scanner.next().is_err() {
+                    outcome = ScanOutcome::Unterminated(open_range);
+                    break;
+                }
+            }
+
+            Ok(())
+        })?;
+        Ok((r, outcome))
+    }
+
+    /// Scans a line comment starting with `prefix`, e.g. `--` in Ada,
+    /// VHDL, and SQL, until the end of the line, excluding the line break.
+    ///
+    /// Only the whole `prefix` starts a line comment, i.e. a single `-`
+    /// is left to be scanned as an operator. However, as `prefix` commonly
+    /// also starts operators, e.g. `--` in C, then line comments must be
+    /// scanned before operators.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use text_scanner::Scanner;
+    /// let text = "-1 -- comment\nx";
+    /// let mut scanner = Scanner::new(text);
+    ///
+    /// assert!(scanner.scan_line_comment("--").is_err());
+    /// assert_eq!(scanner.accept_char('-'), Ok((0..1, '-')));
+    ///
+    /// scanner.skip_until_char(' ');
+    /// scanner.skip_whitespace();
+    /// assert_eq!(scanner.scan_line_comment("--"), Ok((3..13, "-- comment")));
+    /// assert_eq!(scanner.remaining_text(), "\nx");
+    /// ```
+    pub fn scan_line_comment(&mut self, prefix: &str) -> ScannerResult<'text, &'text str> {
+        self.scan_with(|scanner| {
+            scanner.accept_str(prefix)?;
+            scanner.skip_until_char_any(&['\n', '\r']);
+            Ok(())
+        })
+    }
+
+    /// Scans an attribute mark, i.e. `'` followed by an identifier, e.g.
+    /// `'Image` in Ada's `Integer'Image (X)` or `'event` in VHDL's
+    /// `clk'event`.
+    ///
+    /// Attribute marks are ambiguous with character literals, e.g. `'a'`.
+    /// Thereby, an attribute mark is only scanned if the `'` directly
+    /// follows a name, i.e. an alphanumeric character, `_`, or `)`.
+    /// Additionally, `'` followed by something else than an identifier,
+    /// e.g. the qualified expression `Character'('a')`, is not an
+    /// attribute mark.
+    ///
+    /// Given that, attribute marks must be scanned before character literals.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use text_scanner::Scanner;
+    /// let text = "X'Image 'a'";
+    /// let mut scanner = Scanner::new(text);
+    ///
+    /// scanner.skip_until_char('\'');
+    /// assert_eq!(scanner.scan_attribute_mark(), Ok((1..7, "'Image")));
+    ///
+    /// // `'a'` does not follow a name, so it is a character literal
+    /// scanner.skip_whitespace();
+    /// assert_eq!(scanner.scan_attribute_mark(), Err((8..9, "'")));
+    /// assert_eq!(scanner.remaining_text(), "'a'");
+    /// ```
+    pub fn scan_attribute_mark(&mut self) -> ScannerResult<'text, &'text str> {
+        self.scan_with(|scanner| {
+            let (r, _c) = scanner.accept_char('\'')?;
+
+            let prev = scanner.text[..r.start].chars().next_back();
+            let follows_name = prev.is_some_and(|c| c.is_alphanumeric() || matches!(c, '_' | ')'));
+            if !follows_name {
+                return Err(scanner.ranged_text(r));
+            }
+
+            scanner.accept_if(char::is_alphabetic)?;
+            scanner.skip_while(|c| c.is_alphanumeric() || (c == '_'));
+            Ok(())
+        })
+    }
+
+    /// Scans a balanced generic argument list, i.e. `<` followed by the
+    /// generic arguments and the matching `>`, e.g. `<K, Vec<V>>` in Rust
+    /// or `<std::vector<int>>` in C++.
+    ///
+    /// As `<` and `>` are also comparison and shift operators, then the
+    /// scan fails, if the text does not form generic arguments according
+    /// to `policy`, e.g. `a < b && c > d` encounters a stop, while in
+    /// `f(a < b)` the `)` is unbalanced. Additionally, `<<` and `<=` are
+    /// never the start of generic arguments.
+    ///
+    /// **Note:** Some text is inherently ambiguous, e.g. `f(a < b, c > d)`,
+    /// where `< b, c >` is scanned as generic arguments. Thereby, this
+    /// should only be called where generic arguments are expected, e.g.
+    /// following an identifier in a type context.
+    ///
+    /// If the generic arguments are unterminated, then `Err` is returned.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use text_scanner::{GenericPolicy, Scanner};
+    /// let text = "Vec<Box<dyn Fn(i32) -> i32>>::new()";
+    /// let mut scanner = Scanner::new(text);
+    ///
+    /// scanner.skip_until_char('<');
+    /// assert_eq!(
+    ///     scanner.scan_nested_generic(GenericPolicy::RUST),
+    ///     Ok((3..28, "<Box<dyn Fn(i32) -> i32>>")),
+    /// );
+    /// assert_eq!(scanner.remaining_text(), "::new()");
+    ///
+    /// let mut scanner = Scanner::new("a < b && c > d");
+    /// scanner.skip_until_char('<');
+    /// assert!(scanner.scan_nested_generic(GenericPolicy::RUST).is_err());
+    /// assert_eq!(scanner.remaining_text(), "< b && c > d");
+    /// ```
+    pub fn scan_nested_generic(
+        &mut self,
+        policy: GenericPolicy,
+    ) -> ScannerResult<'text, &'text str> {
+        self.scan_with(|scanner| {
+            let (r, _c) = scanner.accept_char('<')?;
+            if let Ok((r2, _c)) = scanner.accept_char_any(&['<', '=']) {
+                return Err(scanner.ranged_text(r.start..r2.end));
+            }
+
+            let mut depth = 1_usize;
+            let mut closers = Closers::new();
+            loop {
+                if closers.is_empty() {
+                    if let Ok((r, _s)) = scanner.test_str_any(policy.stops) {
+                        return Err(scanner.ranged_text(r));
+                    }
+                }
+
+                if policy.arrows && scanner.test_str("->").is_ok() {
+                    continue;
+                }
+
+                match scanner.next()? {
+                    (_r, '<') => depth += 1,
+                    (r, '>') => {
+                        if !policy.split_shift && scanner.accept_char('>').is_ok() {
+                            return Err(scanner.ranged_text(r.start..scanner.cursor));
+                        }
+
+                        depth -= 1;
+                        if depth == 0 {
+                            return if closers.is_empty() {
+                                Ok(())
+                            } else {
+                                Err(scanner.ranged_text(r))
+                            };
+                        }
+                    }
+                    (_r, '(') => closers.push(')'),
+                    (_r, '[') => closers.push(']'),
+                    (r, c @ (')' | ']')) if !closers.pop_if(c) => {
+                        return Err(scanner.ranged_text(r));
+                    }
+                    _ => {}
+                }
+            }
+        })
+    }
+}
+
+pub trait IntoScanner<'text> {
+    fn into_scanner(self) -> Scanner<'text>;
+}
+
+/// Returns `true` if `text` starts with `expected`, same as
+/// [`slice::starts_with()`] except usable in `const fn`.
+#[inline]
+const fn starts_with(text: &[u8], expected: &[u8]) -> bool {
+    if text.len() < expected.len() {
+        return false;
+    }
+    let mut i = 0;
+    while i < expected.len() {
+        if text[i] != expected[i] {
+            return false;
+        }
+        i += 1;
+    }
+    true
+}
+
+impl<'text> IntoScanner<'text> for &'text str {
+    #[inline]
+    fn into_scanner(self) -> Scanner<'text> {
+        Scanner::new(self)
+    }
+}
+
+impl<'text, T> From<T> for Scanner<'text>
+where
+    T: IntoScanner<'text>,
+{
+    #[inline]
+    fn from(value: T) -> Self {
+        value.into_scanner()
+    }
+}
+
+// Currently not publicly exported, as using e.g. `accept_if()` with a
+// closure would require specifying types more often than desired.
+pub(crate) trait ScanOne<Args> {
+    fn scan_one(self, next: char) -> bool;
+}
+
+impl<F> ScanOne<char> for F
+where
+    F: FnOnce(char) -> bool,
+{
+    #[inline]
+    fn scan_one(self, next: char) -> bool {
+        self(next)
+    }
+}
+
+impl<F> ScanOne<&char> for F
+where
+    F: FnOnce(&char) -> bool,
+{
+    #[inline]
+    fn scan_one(self, next: char) -> bool {
+        self(&next)
+    }
+}
+
+// Currently not publicly exported, as using e.g. `skip_while()` with a
+// closure would require specifying types more often than desired.
 pub(crate) trait ScanMany<Args>: ScanOne<Args> {
     fn scan_many(&mut self, next: char) -> bool;
 }
@@ -1788,9 +3237,10 @@ pub(crate) trait CharExt {
     fn is_ascii_non_zero_digit(self) -> bool;
 
     // `std::char::is_ascii_octdigit` is unstable
+    #[cfg(feature = "ext")]
     fn is_ascii_octdigit(self) -> bool;
 
-    fn is_ascii_bindigit(self) -> bool;
+    fn is_whitespace_no_newline(self) -> bool;
 }
 
 impl CharExt for char {
@@ -1799,14 +3249,15 @@ impl CharExt for char {
         matches!(self, '1'..='9')
     }
 
+    #[cfg(feature = "ext")]
     #[inline]
     fn is_ascii_octdigit(self) -> bool {
         matches!(self, '0'..='7')
     }
 
     #[inline]
-    fn is_ascii_bindigit(self) -> bool {
-        matches!(self, '0' | '1')
+    fn is_whitespace_no_newline(self) -> bool {
+        self.is_whitespace() && !matches!(self, '\n' | '\r' | '\u{85}' | '\u{2028}' | '\u{2029}')
     }
 }
 
@@ -1832,6 +3283,607 @@ mod tests {
         assert_eq!(scanner.remaining_text(), "Baz");
     }
 
+    #[test]
+    fn test_const_scanning() {
+        const fn scan(text: &str) -> (usize, bool, usize) {
+            let mut scanner = Scanner::new(text);
+            let kw = scanner.test_str_any(&["fn", "let"]).is_ok();
+            let space = scanner.accept_char(' ').is_ok() && scanner.accept_char('é').is_ok();
+            (
+                scanner.cursor_pos(),
+                kw && space,
+                scanner.remaining_text().len(),
+            )
+        }
+
+        const LET: (usize, bool, usize) = scan("let é = 1");
+        const FN: (usize, bool, usize) = scan("fn foo");
+        const EMPTY: (usize, bool, usize) = scan("");
+
+        assert_eq!(LET, (6, true, 4));
+        assert_eq!(FN, (3, false, 3));
+        assert_eq!(EMPTY, (0, false, 0));
+    }
+
+    #[test]
+    fn test_peek_scope() {
+        fn assert_send_sync<T: Send + Sync>() {}
+        assert_send_sync::<Scanner<'_>>();
+
+        let mut scanner = Scanner::new("let x = 1;");
+        scanner.accept_str("let").unwrap();
+
+        let mut a = scanner.peek_scope();
+        let mut b = a.clone();
+        assert_eq!(a.skip_whitespace(), (3..4, " "));
+        assert_eq!(a.next(), Ok((4..5, 'x')));
+        assert_eq!(b.next(), Ok((3..4, ' ')));
+        assert_eq!((a.cursor_pos(), b.cursor_pos()), (5, 4));
+        assert_eq!(scanner.cursor_pos(), 3);
+
+        // Scanners moved to other threads do not interfere
+        let cursors = std::thread::scope(|s| {
+            let handles = (0..4)
+                .map(|n| {
+                    let mut scanner = scanner.peek_scope();
+                    s.spawn(move || {
+                        for _ in 0..n {
+                            scanner.next().unwrap();
+                        }
+                        scanner.cursor_pos()
+                    })
+                })
+                .collect::<Vec<_>>();
+            handles
+                .into_iter()
+                .map(|handle| handle.join().unwrap())
+                .collect::<Vec<_>>()
+        });
+        assert_eq!(cursors, [3, 4, 5, 6]);
+        assert_eq!(scanner.cursor_pos(), 3);
+        assert_eq!(scanner.remaining_text(), " x = 1;");
+    }
+
+    #[test]
+    fn test_expect() {
+        #[rustfmt::skip]
+        let cases = [
+            ("", "x", "\"x\"", 0..0, ""),
+            ("y", "x", "\"x\"", 0..1, "y"),
+            ("foo", "bar", "\"bar\"", 0..3, "foo"),
+            ("fo", "foo", "\"foo\"", 0..2, "fo"),
+            ("ÆØÅÆ", "ÆØx", "\"ÆØx\"", 0..6, "ÆØÅ"),
+        ];
+
+        for (text, expected_str, expected, found_range, found) in cases {
+            let mut scanner = Scanner::new(text);
+            let err = ExpectError {
+                expected: expected.to_owned(),
+                found_range,
+                found,
+            };
+            assert_eq!(scanner.expect_str(expected_str), Err(err), "{text:?}");
+            assert_eq!(scanner.cursor_pos(), 0);
+        }
+
+        let mut scanner = Scanner::new("a=");
+        assert_eq!(
+            scanner.expect_if(char::is_alphabetic, "letter"),
+            Ok((0..1, 'a'))
+        );
+        assert_eq!(
+            scanner
+                .expect_if(char::is_alphabetic, "letter")
+                .map_err(|err| err.to_string()),
+            Err("expected letter, found \"=\"".to_owned())
+        );
+        assert_eq!(scanner.expect_char('='), Ok((1..2, '=')));
+        assert_eq!(
+            scanner.expect_char('=').map_err(|err| err.to_string()),
+            Err("expected '=', found end of input".to_owned())
+        );
+    }
+
+    #[test]
+    fn test_scan_capturing() {
+        let mut scanner = Scanner::new("0x1F;");
+        let (r, (s, caps)) = scanner
+            .scan_capturing(|scanner, caps| {
+                let (prefix, _s) = scanner.accept_str("0x")?;
+                caps.insert("prefix", prefix);
+                let (digits, _s) = scanner.skip_while(|c| c.is_ascii_hexdigit());
+                caps.insert("digits", digits.clone());
+                // Replaces the previous capture
+                caps.insert("prefix", 0..1);
+                Ok(())
+            })
+            .unwrap();
+
+        assert_eq!((r, s), (0..4, "0x1F"));
+        assert_eq!(caps.len(), 2);
+        assert_eq!(
+            caps.iter().collect::<Vec<_>>(),
+            [("prefix", (0..1, "0")), ("digits", (2..4, "1F"))]
+        );
+        assert_eq!(scanner.remaining_text(), ";");
+
+        // Captures are discarded when backtracking
+        let res = scanner.scan_capturing(|scanner, caps| {
+            let (r, _c) = scanner.accept_char(';')?;
+            caps.insert("semi", r);
+            scanner.accept_char(';')?;
+            Ok(())
+        });
+        assert_eq!(res, Err((4..5, ";")));
+        assert_eq!(scanner.remaining_text(), ";");
+    }
+
+    #[test]
+    fn test_scan_digits_sep() {
+        const BETWEEN: SeparatorPolicy = SeparatorPolicy {
+            consecutive: true,
+            ..SeparatorPolicy::STRICT
+        };
+        const PREFIXED: SeparatorPolicy = SeparatorPolicy {
+            leading: true,
+            ..SeparatorPolicy::STRICT
+        };
+
+        #[rustfmt::skip]
+        let cases = [
+            // text, radix, policy, expected, remaining text
+            ("1_2", 10, SeparatorPolicy::STRICT, Ok((0..3, "1_2")), ""),
+            ("1_2_", 10, SeparatorPolicy::STRICT, Ok((0..3, "1_2")), "_"),
+            ("1_2__", 10, SeparatorPolicy::STRICT, Ok((0..3, "1_2")), "__"),
+            ("12__34", 10, SeparatorPolicy::STRICT, Err((0..3, "12_")), "12__34"),
+            ("_1", 10, SeparatorPolicy::STRICT, Err((0..0, "")), "_1"),
+            ("1A", 10, SeparatorPolicy::STRICT, Ok((0..1, "1")), "A"),
+            ("1A", 16, SeparatorPolicy::STRICT, Ok((0..2, "1A")), ""),
+            ("012", 2, SeparatorPolicy::STRICT, Ok((0..2, "01")), "2"),
+            //
+            ("1__2_", 10, BETWEEN, Ok((0..4, "1__2")), "_"),
+            ("_1", 10, BETWEEN, Err((0..0, "")), "_1"),
+            //
+            ("_F_F", 16, PREFIXED, Ok((0..4, "_F_F")), ""),
+            ("__F", 16, PREFIXED, Err((0..1, "_")), "__F"),
+            ("_", 16, PREFIXED, Err((0..1, "_")), "_"),
+            ("F__F", 16, PREFIXED, Err((0..2, "F_")), "F__F"),
+            //
+            ("__1__2__", 10, SeparatorPolicy::LENIENT, Ok((0..8, "__1__2__")), ""),
+            ("__", 10, SeparatorPolicy::LENIENT, Err((0..2, "__")), "__"),
+            //
+            ("", 10, SeparatorPolicy::LENIENT, Err((0..0, "")), ""),
+        ];
+
+        for (text, radix, policy, expected, remaining) in cases {
+            let mut scanner = Scanner::new(text);
+
+            let actual = scanner.scan_digits_sep(radix, '_', policy);
+            assert_eq!(actual, expected, "{text:?}");
+
+            assert_eq!(scanner.remaining_text(), remaining, "{text:?}");
+        }
+
+        let mut scanner = Scanner::new("1'000'000");
+        #[rustfmt::skip]
+        assert_eq!(scanner.scan_digits_sep(10, '\'', SeparatorPolicy::STRICT), Ok((0..9, "1'000'000")));
+    }
+
+    #[test]
+    #[should_panic(expected = "radix must be in the range 2..=36")]
+    fn test_scan_digits_sep_invalid_radix() {
+        let mut scanner = Scanner::new("");
+        _ = scanner.scan_digits_sep(1, '_', SeparatorPolicy::STRICT);
+    }
+
+    #[test]
+    fn test_take_remaining_text() {
+        let mut scanner = Scanner::new("🦀 = 'crab'");
+        assert_eq!(scanner.next(), Ok((0..4, '🦀')));
+        assert_eq!(scanner.take_remaining_text(), (4..13, " = 'crab'"));
+        assert_eq!(scanner.take_remaining_text(), (13..13, ""));
+        assert_eq!(scanner.peek(), Err(scanner.eof_item()));
+
+        let mut scanner = Scanner::new("");
+        assert_eq!(scanner.take_remaining_text(), (0..0, ""));
+        assert_eq!(scanner.eof_item(), (0..0, ""));
+    }
+
+    #[test]
+    fn test_line_col() {
+        let texts = ["", "a", "\n", "a\nb", "a\r\nbc\n", "\n\næ\n東\n"];
+
+        for text in texts {
+            let index = LineIndex::new(text);
+            let scanner = Scanner::new(text);
+            let indexed = Scanner::with_line_index(text, &index);
+            assert!(scanner.line_index().is_none());
+            assert_eq!(indexed.peek_scope().line_index(), Some(&index));
+
+            let positions = (0..=text.len()).filter(|&pos| text.is_char_boundary(pos));
+            for pos in positions {
+                let expected = index.line_col(pos);
+                assert_eq!(scanner.line_col(pos), expected, "{text:?} {pos}");
+                assert_eq!(indexed.line_col(pos), expected, "{text:?} {pos}");
+            }
+        }
+    }
+
+    #[test]
+    fn test_scan_identifier_with() {
+        let cases = [
+            ("$foo1 ", Ok((0..5, "$foo1")), " "),
+            ("1x", Err((0..0, "")), "1x"),
+        ];
+        for (text, expected, remaining) in cases {
+            let mut scanner = Scanner::new(text);
+            let actual = scanner.scan_identifier_with(|c| c == '$', |c| c.is_ascii_alphanumeric());
+            assert_eq!(actual, expected, "{text:?}");
+            assert_eq!(scanner.remaining_text(), remaining, "{text:?}");
+        }
+    }
+
+    #[test]
+    fn test_scan_identifier_with_charset() {
+        #[rustfmt::skip]
+        let cases = [
+            // text, charset, expected, remaining text
+            ("foo_bar1", IdentifierCharset::C, Ok((0..8, "foo_bar1")), ""),
+            ("_x-y", IdentifierCharset::C, Ok((0..2, "_x")), "-y"),
+            ("ÆØÅ", IdentifierCharset::C, Err((0..0, "")), "ÆØÅ"),
+            ("1x", IdentifierCharset::C, Err((0..0, "")), "1x"),
+            //
+            ("ÆØÅ_1 x", IdentifierCharset::XID, Ok((0..8, "ÆØÅ_1")), " x"),
+            ("_", IdentifierCharset::XID, Ok((0..1, "_")), ""),
+            ("1x", IdentifierCharset::XID, Err((0..0, "")), "1x"),
+            //
+            ("font-size:", IdentifierCharset::KEBAB_CASE, Ok((0..9, "font-size")), ":"),
+            ("h-100", IdentifierCharset::KEBAB_CASE, Ok((0..5, "h-100")), ""),
+            ("a--b", IdentifierCharset::KEBAB_CASE, Ok((0..1, "a")), "--b"),
+            ("a-", IdentifierCharset::KEBAB_CASE, Ok((0..1, "a")), "-"),
+            ("-a", IdentifierCharset::KEBAB_CASE, Err((0..0, "")), "-a"),
+            //
+            ("java.util.List;", IdentifierCharset::DOTTED, Ok((0..14, "java.util.List")), ";"),
+            ("a.b.", IdentifierCharset::DOTTED, Ok((0..3, "a.b")), "."),
+            ("a.1", IdentifierCharset::DOTTED, Ok((0..3, "a.1")), ""),
+            (".a", IdentifierCharset::DOTTED, Err((0..0, "")), ".a"),
+            //
+            ("", IdentifierCharset::C, Err((0..0, "")), ""),
+        ];
+
+        for (text, charset, expected, remaining) in cases {
+            let mut scanner = Scanner::new(text);
+
+            let actual = scanner.scan_identifier_with_charset(charset);
+            assert_eq!(actual, expected, "{text:?}");
+
+            assert_eq!(scanner.remaining_text(), remaining, "{text:?}");
+        }
+    }
+
+    #[test]
+    fn test_scan_interpolation() {
+        // Strings with nested interpolations, e.g. `"a${b}c"` in shell and Kotlin
+        fn scan_string<'text>(scanner: &mut Scanner<'text>) -> ScannerResult<'text, &'text str> {
+            scanner.scan_with(|scanner| {
+                scanner.accept_char('"')?;
+                loop {
+                    if scanner.accept_char('"').is_ok() {
+                        return Ok(());
+                    } else if scanner.remaining_text().starts_with("${") {
+                        scanner.scan_interpolation("${", "}", &[('{', '}')], scan_string)?;
+                    } else {
+                        scanner.next()?;
+                    }
+                }
+            })
+        }
+
+        let cases = [
+            ("${}", "${}"),
+            ("${a}b}", "${a}"),
+            ("${a{b{c{d}}}}e}", "${a{b{c{d}}}}"),
+            ("${f { it }}}", "${f { it }}"),
+            ("${\\}}}", "${\\}}"),
+            (r#"${"}"}}"#, r#"${"}"}"#),
+            (r#"${"${"}"}"}}"#, r#"${"${"}"}"}"#),
+            (r#"${a["${b { "}" }}"]}}"#, r#"${a["${b { "}" }}"]}"#),
+            // Closers of other delimiters are not balanced
+            ("${a)}", "${a)}"),
+        ];
+        for (text, expected) in cases {
+            let mut scanner = Scanner::new(text);
+            let actual = scanner.scan_interpolation("${", "}", &[('{', '}')], scan_string);
+            assert_eq!(actual, Ok((0..expected.len(), expected)), "{text:?}");
+        }
+
+        let cases = ["", "$", "{}", "${", "${{}", r#"${"${"}"#];
+        for text in cases {
+            let mut scanner = Scanner::new(text);
+            let actual = scanner.scan_interpolation("${", "}", &[('{', '}')], scan_string);
+            assert!(actual.is_err(), "{text:?}");
+            assert_eq!(scanner.remaining_text(), text);
+        }
+    }
+
+    #[test]
+    fn test_scan_line_comment() {
+        let cases = [
+            ("--", "--"),
+            ("-- comment", "-- comment"),
+            ("--- comment\nx", "--- comment"),
+            ("-- comment\r\nx", "-- comment"),
+        ];
+        for (text, expected) in cases {
+            let mut scanner = Scanner::new(text);
+            let actual = scanner.scan_line_comment("--");
+            assert_eq!(actual, Ok((0..expected.len(), expected)), "{text:?}");
+        }
+
+        let cases = ["", "-", "- -", "-x", "//"];
+        for text in cases {
+            let mut scanner = Scanner::new(text);
+            assert!(scanner.scan_line_comment("--").is_err(), "{text:?}");
+            assert_eq!(scanner.remaining_text(), text);
+        }
+    }
+
+    #[test]
+    fn test_scan_block_comment_pascal() {
+        let cases = [
+            ("(**)", "(**)"),
+            ("(* a *) b *)", "(* a *)"),
+            ("(*) a *)", "(*) a *)"),
+            ("(* a\nb *)", "(* a\nb *)"),
+        ];
+        for (text, expected) in cases {
+            let mut scanner = Scanner::new(text);
+            let actual = scanner.scan_block_comment("(*", "*)", false);
+            assert_eq!(actual, Ok((0..expected.len(), expected)), "{text:?}");
+        }
+
+        let mut scanner = Scanner::new("(*)");
+        assert_eq!(
+            scanner.scan_block_comment_outcome("(*", "*)", false),
+            Ok((0..3, ScanOutcome::Unterminated(0..2))),
+        );
+    }
+
+    #[test]
+    fn test_scan_nested_generic() {
+        let policy = GenericPolicy::RUST;
+        let cases = [
+            ("<T>", "<T>"),
+            ("<T>()", "<T>"),
+            ("<K, Vec<V>>", "<K, Vec<V>>"),
+            ("<Vec<Vec<T>>>", "<Vec<Vec<T>>>"),
+            ("<'a, T: 'a>", "<'a, T: 'a>"),
+            ("<[u8; 4]>", "<[u8; 4]>"),
+            ("<dyn Fn(i32) -> i32>", "<dyn Fn(i32) -> i32>"),
+            ("<Box<dyn Fn(Vec<T>) -> U>>", "<Box<dyn Fn(Vec<T>) -> U>>"),
+            ("<T> > x", "<T>"),
+        ];
+        for (text, expected) in cases {
+            let mut scanner = Scanner::new(text);
+            let actual = scanner.scan_nested_generic(policy);
+            assert_eq!(actual, Ok((0..expected.len(), expected)), "{text:?}");
+        }
+
+        // Comparisons and shifts
+        let cases = [
+            "<",
+            "< b",
+            "<= b",
+            "<< 2",
+            "< b && c > d",
+            "< b || c > d",
+            "< b) > c",
+            "< b] > c",
+            "<(b > c)",
+            "< b; c > d",
+            "< b { c > d }",
+            "< b == c > d",
+            "< \"a>b\"",
+            "<T // >\n",
+        ];
+        for text in cases {
+            let mut scanner = Scanner::new(text);
+            let actual = scanner.scan_nested_generic(policy);
+            assert!(actual.is_err(), "{text:?}: {actual:?}");
+            assert_eq!(scanner.cursor_pos(), 0, "{text:?}");
+        }
+    }
+
+    #[test]
+    fn test_scan_nested_generic_policy() {
+        let text = "<std::vector<int>>";
+
+        let mut scanner = Scanner::new(text);
+        let actual = scanner.scan_nested_generic(GenericPolicy::CPP);
+        assert_eq!(actual, Ok((0..text.len(), text)));
+
+        let policy = GenericPolicy {
+            split_shift: false,
+            ..GenericPolicy::CPP
+        };
+        let mut scanner = Scanner::new(text);
+        assert!(scanner.scan_nested_generic(policy).is_err());
+        let text = "<std::vector<int> >";
+        let mut scanner = Scanner::new(text);
+        let actual = scanner.scan_nested_generic(policy);
+        assert_eq!(actual, Ok((0..text.len(), text)));
+
+        // `->` is member access in C++
+        let text = "<a->b>";
+        let mut scanner = Scanner::new(text);
+        let actual = scanner.scan_nested_generic(GenericPolicy::CPP);
+        assert_eq!(actual, Ok((0..text.len(), text)));
+
+        let mut scanner = Scanner::new(text);
+        let actual = scanner.scan_nested_generic(GenericPolicy::JAVA);
+        assert_eq!(actual, Ok((0..4, "<a->")));
+
+        let text = "<T extends A & B, ? super List<T>>";
+        let mut scanner = Scanner::new(text);
+        let actual = scanner.scan_nested_generic(GenericPolicy::JAVA);
+        assert_eq!(actual, Ok((0..text.len(), text)));
+    }
+
+    #[test]
+    fn test_scan_attribute_mark() {
+        let cases = [
+            ("X'Image", 1, "'Image"),
+            ("Integer'Image (X)", 7, "'Image"),
+            ("clk'event", 3, "'event"),
+            ("A_1'Last", 3, "'Last"),
+            ("F (X)'Length", 5, "'Length"),
+            (
+                "X'Max_Size_In_Storage_Elements",
+                1,
+                "'Max_Size_In_Storage_Elements",
+            ),
+        ];
+        for (text, start, expected) in cases {
+            let mut scanner = Scanner::new(text);
+            scanner.set_cursor_pos(start);
+            let actual = scanner.scan_attribute_mark();
+            assert_eq!(
+                actual,
+                Ok((start..(start + expected.len()), expected)),
+                "{text:?}"
+            );
+        }
+
+        // Character literals and qualified expressions
+        let cases = [
+            ("'a'", 0),
+            ("X := 'a'", 5),
+            ("('a')", 1),
+            ("Character'('a')", 9),
+            ("X'1", 1),
+            ("X'", 1),
+        ];
+        for (text, start) in cases {
+            let mut scanner = Scanner::new(text);
+            scanner.set_cursor_pos(start);
+            assert!(scanner.scan_attribute_mark().is_err(), "{text:?}");
+            assert_eq!(scanner.cursor_pos(), start, "{text:?}");
+        }
+    }
+
+    #[test]
+    fn test_skip_while_str() {
+        let cases = [
+            ("", "ab", (0..0, ""), ""),
+            ("ababa", "ab", (0..4, "abab"), "a"),
+            ("ÆØÅÆØÅÆ", "ÆØÅ", (0..12, "ÆØÅÆØÅ"), "Æ"),
+            ("Æa", "Æ", (0..2, "Æ"), "a"),
+            ("xab", "ab", (0..0, ""), "xab"),
+        ];
+
+        for (text, expected, item, remaining) in cases {
+            let mut scanner = Scanner::new(text);
+            assert_eq!(scanner.skip_while_str(expected), item, "{text:?}");
+            assert_eq!(scanner.remaining_text(), remaining, "{text:?}");
+        }
+    }
+
+    #[test]
+    fn test_skip_while_str_any() {
+        let cases = [
+            ("", &["a", "b"][..], (0..0, ""), ""),
+            ("abbac", &["a", "b"], (0..4, "abba"), "c"),
+            ("ÆØÆÅ", &["Å", "Æ", "Ø"], (0..8, "ÆØÆÅ"), ""),
+            ("foobarfoo", &["foo", "foobar"], (0..3, "foo"), "barfoo"),
+            ("foobarfoo", &["foobar", "foo"], (0..9, "foobarfoo"), ""),
+        ];
+
+        for (text, expected, item, remaining) in cases {
+            let mut scanner = Scanner::new(text);
+            assert_eq!(scanner.skip_while_str_any(expected), item, "{text:?}");
+            assert_eq!(scanner.remaining_text(), remaining, "{text:?}");
+        }
+    }
+
+    #[test]
+    fn test_empty_arguments() {
+        for text in ["", "foo"] {
+            let mut scanner = Scanner::new(text);
+            assert_eq!(
+                scanner.next(),
+                text.chars().next().map(|c| (0..1, c)).ok_or((0..0, ""))
+            );
+            let cursor = scanner.cursor_pos();
+            let empty = Err((cursor..cursor, ""));
+
+            assert_eq!(scanner.peek_str(0), empty);
+            assert_eq!(scanner.next_str(0), empty);
+            assert_eq!(scanner.try_peek_str(0), Some((cursor..cursor, "")));
+            assert_eq!(scanner.try_next_str(0), Some((cursor..cursor, "")));
+
+            assert_eq!(scanner.accept_char_any(&[]), Err((cursor..cursor, "")));
+            assert_eq!(scanner.accept_str(""), empty);
+            assert_eq!(scanner.accept_str_any(&[]), empty);
+            assert_eq!(scanner.accept_str_any(&[""]), empty);
+            assert_eq!(scanner.test_str(""), empty);
+            assert_eq!(scanner.test_str_any(&[]), empty);
+            assert_eq!(scanner.test_str_any(&[""]), empty);
+            assert!(scanner.expect_str("").is_err());
+
+            assert_eq!(scanner.skip_while_str(""), (cursor..cursor, ""));
+            assert_eq!(scanner.skip_while_str_any(&[]), (cursor..cursor, ""));
+            assert_eq!(scanner.skip_while_str_any(&[""]), (cursor..cursor, ""));
+            assert_eq!(scanner.skip_until_str(""), (cursor..cursor, ""));
+            assert_eq!(scanner.skip_until_str_any(&[]), (cursor..cursor, ""));
+
+            assert_eq!(scanner.cursor_pos(), cursor, "{text:?}");
+        }
+    }
+
+    #[test]
+    fn test_skip_whitespace_no_newline() {
+        let cases = [
+            ("", (0..0, ""), ""),
+            ("  \t", (0..3, "  \t"), ""),
+            (
+                "\u{A0}\u{3000}\u{2003}a",
+                (0..8, "\u{A0}\u{3000}\u{2003}"),
+                "a",
+            ),
+            (" \n ", (0..1, " "), "\n "),
+            (" \r\n", (0..1, " "), "\r\n"),
+            (" \u{85}", (0..1, " "), "\u{85}"),
+            (" \u{2028}", (0..1, " "), "\u{2028}"),
+            (" \u{2029}", (0..1, " "), "\u{2029}"),
+            ("a ", (0..0, ""), "a "),
+        ];
+
+        for (text, item, remaining) in cases {
+            let mut scanner = Scanner::new(text);
+            assert_eq!(scanner.skip_whitespace_no_newline(), item, "{text:?}");
+            assert_eq!(scanner.remaining_text(), remaining, "{text:?}");
+        }
+    }
+
+    #[test]
+    fn test_skip_blank_lines() {
+        let cases = [
+            ("", (0..0, ""), ""),
+            ("  ", (0..2, "  "), ""),
+            ("\n\n", (0..2, "\n\n"), ""),
+            (" \n\u{A0}\n  a", (0..5, " \n\u{A0}\n"), "  a"),
+            ("\r\n\r\n\ta\n", (0..4, "\r\n\r\n"), "\ta\n"),
+            ("  a\n\n", (0..0, ""), "  a\n\n"),
+            (" \r \n", (0..0, ""), " \r \n"),
+        ];
+
+        for (text, item, remaining) in cases {
+            let mut scanner = Scanner::new(text);
+            assert_eq!(scanner.skip_blank_lines(), item, "{text:?}");
+            assert_eq!(scanner.remaining_text(), remaining, "{text:?}");
+        }
+    }
+
     #[test]
     fn test_scan_digits() {
         let cases = ["0", "1", "0000", "0123", "123", "123456789", "0123456789"];
@@ -0,0 +1,197 @@
+use std::ops::Range;
+
+/// Index of the line starts of a text, for converting byte offsets into
+/// line numbers, e.g. for line numbering in renderers or for reporting
+/// diagnostics.
+///
+/// Constructing the index walks the text once, after which looking up
+/// the start of a line is a constant-time operation, and looking up
+/// the line of a byte offset is a binary search.
+///
+/// Lines are separated by `\n`, thereby `\r\n` is also supported. Line
+/// numbers are zero-based, and columns are byte offsets from the start
+/// of the line. A trailing line ending starts a final empty line, same
+/// as in most editors.
+///
+/// # Example
+///
+/// ```rust
+/// # use text_scanner::LineIndex;
+/// let text = "fn main() {\r\n    foo();\n}\n";
+/// let index = LineIndex::new(text);
+///
+/// assert_eq!(index.line_count(), 4);
+/// assert_eq!(index.line_start(1), Some(13));
+/// assert_eq!(index.line_range(1), Some(13..24));
+///
+/// let foo = text.find("foo").unwrap();
+/// assert_eq!(index.line_col(foo), (1, 4));
+/// ```
+#[derive(PartialEq, Eq, Clone, Debug)]
+pub struct LineIndex {
+    /// The byte offset of the start of each line,
+    /// where the first is always `0`.
+    line_starts: Vec<usize>,
+    len: usize,
+}
+
+impl LineIndex {
+    /// Constructs a new [`LineIndex`] for `text`.
+    pub fn new(text: &str) -> Self {
+        let line_starts = [0]
+            .into_iter()
+            .chain(text.match_indices('\n').map(|(i, _)| i + 1))
+            .collect();
+        Self {
+            line_starts,
+            len: text.len(),
+        }
+    }
+
+    /// Updates the index after the text was changed from the start of `line`
+    /// and onwards, where `text` is the whole changed text, such that only
+    /// the changed lines are indexed again.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `line` is out of bounds.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use text_scanner::LineIndex;
+    /// let mut index = LineIndex::new("a\nb\nc");
+    ///
+    /// let text = "a\nb\nx\ny\n";
+    /// index.reindex_from(text, 2);
+    /// assert_eq!(index, LineIndex::new(text));
+    /// ```
+    pub fn reindex_from(&mut self, text: &str, line: usize) {
+        let start = self.line_starts[line];
+        self.line_starts.truncate(line + 1);
+        self.line_starts.extend(
+            text[start..]
+                .match_indices('\n')
+                .map(|(i, _)| start + i + 1),
+        );
+        self.len = text.len();
+    }
+
+    /// Returns the length of the indexed text in bytes.
+    #[inline]
+    pub fn text_len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns the amount of lines, which is always at least 1.
+    #[inline]
+    pub fn line_count(&self) -> usize {
+        self.line_starts.len()
+    }
+
+    /// Returns the byte offsets of the start of each line.
+    #[inline]
+    pub fn line_starts(&self) -> &[usize] {
+        &self.line_starts
+    }
+
+    /// Returns the byte offset of the start of `line`,
+    /// or `None` if `line` is out of bounds.
+    #[inline]
+    pub fn line_start(&self, line: usize) -> Option<usize> {
+        self.line_starts.get(line).copied()
+    }
+
+    /// Returns the byte range of `line` including its line ending,
+    /// or `None` if `line` is out of bounds.
+    pub fn line_range(&self, line: usize) -> Option<Range<usize>> {
+        let start = self.line_start(line)?;
+        let end = self.line_start(line + 1).unwrap_or(self.len);
+        Some(start..end)
+    }
+
+    /// Returns the zero-based line containing the byte offset `pos`.
+    ///
+    /// A line ending is part of the line it ends.
+    ///
+    /// # Panics
+    ///
+    /// Panics in non-optimized builds, if `pos` is greater than
+    /// the length of the text. In optimized builds, the last
+    /// line is then returned.
+    pub fn line(&self, pos: usize) -> usize {
+        debug_assert!(pos <= self.len, "`pos` is out of bounds");
+
+        // The first line start is always `0`, so this is at least `1`
+        self.line_starts.partition_point(|&start| start <= pos) - 1
+    }
+
+    /// Returns the zero-based line and byte column of the byte offset `pos`,
+    /// see [`line()`](Self::line).
+    #[inline]
+    pub fn line_col(&self, pos: usize) -> (usize, usize) {
+        let line = self.line(pos);
+        (line, pos - self.line_starts[line])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_line_index() {
+        let texts = ["", "a", "\n", "a\nb", "a\r\nbc\n", "\n\næ\n東\n"];
+
+        for text in texts {
+            let index = LineIndex::new(text);
+            assert_eq!(index.line_count(), text.split('\n').count(), "{text:?}");
+
+            let positions = (0..=text.len()).filter(|&pos| text.is_char_boundary(pos));
+            for pos in positions {
+                let before = &text[..pos];
+                let line = before.matches('\n').count();
+                let col = pos - before.rfind('\n').map_or(0, |i| i + 1);
+                assert_eq!(index.line_col(pos), (line, col), "{text:?} {pos}");
+
+                let range = index.line_range(line).unwrap();
+                assert!(
+                    range.contains(&pos) || (pos == text.len()),
+                    "{text:?} {pos}"
+                );
+            }
+
+            let ranges = (0..index.line_count())
+                .map(|line| &text[index.line_range(line).unwrap()])
+                .collect::<String>();
+            assert_eq!(ranges, text);
+
+            assert_eq!(index.line_start(index.line_count()), None);
+            assert_eq!(index.line_range(index.line_count()), None);
+        }
+    }
+
+    #[test]
+    fn test_line_index_reindex_from() {
+        let texts = ["a", "a\nb", "a\nb\n", "a\n\nbc\nd"];
+
+        for old in texts {
+            for new in texts {
+                let old_index = LineIndex::new(old);
+                // Lines before the first changed line must be the same
+                let same = old_index
+                    .line_starts()
+                    .iter()
+                    .zip(LineIndex::new(new).line_starts())
+                    .take_while(|(a, b)| (a == b) && old[..**a] == new[..**b])
+                    .count();
+
+                for line in 0..same {
+                    let mut index = old_index.clone();
+                    index.reindex_from(new, line);
+                    assert_eq!(index, LineIndex::new(new), "{old:?} {new:?} {line}");
+                }
+            }
+        }
+    }
+}
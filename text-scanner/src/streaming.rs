@@ -0,0 +1,480 @@
+use std::io::{self, BufRead};
+use std::ops::Range;
+use std::str;
+
+use crate::{ScanResult, Scanner, ScannerResult};
+
+/// Scanner over text read from a [`BufRead`] source, e.g. a huge log file,
+/// which only keeps a bounded window of the text in memory, as opposed to
+/// [`Scanner`] which requires the whole text as a `&str`.
+///
+/// Scanning is done using [`scan_with()`], which gives access to the whole
+/// [`Scanner`] API, including [extensions], on the buffered text. Before
+/// scanning, at least [`lookahead()`] bytes are buffered after the cursor,
+/// unless the end of the source is reached. If a scan, successful or not,
+/// ends with at most [`lookahead()`] bytes buffered after it, then more
+/// text is read and the scan is retried. Such that tokens longer than the
+/// lookahead are still scanned correctly, at the cost of buffering the
+/// whole token, and such that a scan failing or stopping short, e.g. on a
+/// prefix like `/` of `//`, is only final if it did not depend on more than
+/// [`lookahead()`] bytes after it.
+///
+/// [`skip_while()`] and [`skip_until()`] do not buffer the skipped text,
+/// and thereby skip any amount of text in bounded memory.
+///
+/// Lexers bounded by [`ForwardScan`] work with both a [`StreamingScanner`]
+/// and a [`Scanner`].
+///
+/// All ranges are byte offsets from the start of the source.
+///
+/// # Errors
+///
+/// All methods reading from the source return its errors, as well as
+/// [`io::ErrorKind::InvalidData`] if the source is not valid UTF-8.
+///
+/// # Example
+///
+/// ```rust
+/// # use text_scanner::StreamingScanner;
+/// # fn main() -> std::io::Result<()> {
+/// let log = "INFO start\nWARN disk almost full\nINFO stop\n";
+/// let mut scanner = StreamingScanner::new(log.as_bytes());
+///
+/// let mut warnings = Vec::new();
+/// while scanner.has_remaining_text()? {
+///     let level = scanner.scan_with(|scanner| {
+///         scanner.skip_while(|c| c.is_ascii_uppercase());
+///         Ok(())
+///     })?;
+///     let level = level.map(|(_r, level)| level == "WARN").unwrap_or(false);
+///
+///     let message = scanner.skip_until(|c| c == '\n')?;
+///     if level {
+///         warnings.push(message);
+///     }
+///     scanner.skip_while(|c| c == '\n')?;
+/// }
+///
+/// assert_eq!(warnings, [15..32]);
+/// # Ok(())
+/// # }
+/// ```
+///
+/// [`scan_with()`]: Self::scan_with
+/// [`lookahead()`]: Self::lookahead
+/// [`skip_while()`]: Self::skip_while
+/// [`skip_until()`]: Self::skip_until
+/// [extensions]: crate::ext
+/// [`ForwardScan`]: crate::forward::ForwardScan
+#[derive(Debug)]
+pub struct StreamingScanner<R> {
+    reader: R,
+    /// Buffered text, starting at `offset` in the source.
+    buf: String,
+    /// Bytes of an incomplete UTF-8 sequence at the end of the last read.
+    partial: Vec<u8>,
+    offset: usize,
+    /// Cursor position in `buf`.
+    cursor: usize,
+    lookahead: usize,
+    eof: bool,
+}
+
+impl<R> StreamingScanner<R>
+where
+    R: BufRead,
+{
+    /// Default amount of bytes buffered after the cursor,
+    /// see [`lookahead()`](Self::lookahead).
+    pub const DEFAULT_LOOKAHEAD: usize = 8 * 1024;
+
+    /// Constructs a new [`StreamingScanner`] reading from `reader`,
+    /// with a lookahead of [`DEFAULT_LOOKAHEAD`](Self::DEFAULT_LOOKAHEAD).
+    #[inline]
+    pub fn new(reader: R) -> Self {
+        Self::with_lookahead(reader, Self::DEFAULT_LOOKAHEAD)
+    }
+
+    /// Constructs a new [`StreamingScanner`] reading from `reader`,
+    /// which buffers at least `lookahead` bytes after the cursor
+    /// before scanning.
+    pub fn with_lookahead(reader: R, lookahead: usize) -> Self {
+        Self {
+            reader,
+            buf: String::new(),
+            partial: Vec::new(),
+            offset: 0,
+            cursor: 0,
+            lookahead: lookahead.max(1),
+            eof: false,
+        }
+    }
+
+    /// Returns the minimum amount of bytes buffered after
+    /// the cursor before scanning, unless at the end.
+    #[inline]
+    pub fn lookahead(&self) -> usize {
+        self.lookahead
+    }
+
+    /// Returns the current cursor position, i.e. the byte
+    /// offset from the start of the source.
+    #[inline]
+    pub fn cursor_pos(&self) -> usize {
+        self.offset + self.cursor
+    }
+
+    /// Returns the text buffered after the cursor, which is at most
+    /// the remaining text of the source.
+    #[inline]
+    pub fn buffered_text(&self) -> &str {
+        &self.buf[self.cursor..]
+    }
+
+    /// Returns `true` if any text remains after the cursor.
+    pub fn has_remaining_text(&mut self) -> io::Result<bool> {
+        self.fill_lookahead()?;
+        Ok(self.cursor < self.buf.len())
+    }
+
+    /// Calls `f` with a [`Scanner`] positioned at the cursor, and advances
+    /// the cursor if `f` returns `Ok`, same as [`Scanner::scan_with()`].
+    ///
+    /// The ranges of the [`Scanner`] passed to `f` are relative to the
+    /// buffered text, while the returned range is relative to the start
+    /// of the source.
+    ///
+    /// `f` might be called multiple times, if it ends with at most
+    /// [`lookahead()`] bytes buffered after it, see the
+    /// [type documentation](StreamingScanner).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use text_scanner::{ext::RustScannerExt, StreamingScanner};
+    /// # fn main() -> std::io::Result<()> {
+    /// let code = "// Comment\nfn main() {}";
+    /// let mut scanner = StreamingScanner::with_lookahead(code.as_bytes(), 4);
+    ///
+    /// let comment = scanner.scan_with(|scanner| {
+    ///     scanner.scan_rust_line_comment()?;
+    ///     Ok(())
+    /// })?;
+    /// assert_eq!(comment, Ok((0..10, "// Comment")));
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`lookahead()`]: Self::lookahead
+    pub fn scan_with<F>(&mut self, mut f: F) -> io::Result<ScannerResult<'_, &str>>
+    where
+        F: for<'text> FnMut(&mut Scanner<'text>) -> ScanResult<'text>,
+    {
+        self.fill_lookahead()?;
+
+        let result = loop {
+            let mut scanner = Scanner::new(&self.buf);
+            scanner.set_cursor_pos(self.cursor);
+
+            let result = match scanner.scan_with(&mut f) {
+                Ok((r, _s)) => Ok(r),
+                Err((r, _s)) => Err(r),
+            };
+            let (Ok(r) | Err(r)) = &result;
+            if ((self.buf.len() - r.end) > self.lookahead) || self.eof {
+                break result;
+            }
+
+            self.fill()?;
+        };
+
+        if let Ok(r) = &result {
+            self.cursor = r.end;
+        }
+
+        let (offset, buf) = (self.offset, &self.buf);
+        let item = |r: Range<usize>| ((offset + r.start)..(offset + r.end), &buf[r]);
+        Ok(result.map(item).map_err(item))
+    }
+
+    /// Returns the next [`char`] without advancing the cursor,
+    /// same as [`Scanner::peek()`].
+    pub fn peek(&mut self) -> io::Result<ScannerResult<'_, char>> {
+        self.fill_lookahead()?;
+
+        let mut scanner = Scanner::new(&self.buf);
+        scanner.set_cursor_pos(self.cursor);
+        Ok(self.offset_result(scanner.peek()))
+    }
+
+    /// Advances the cursor past the next [`char`],
+    /// same as [`Scanner::next()`].
+    #[allow(clippy::should_implement_trait)]
+    pub fn next(&mut self) -> io::Result<ScannerResult<'_, char>> {
+        self.accept_if(|_c| true)
+    }
+
+    /// Advances the cursor if `f` returns `true` for the next [`char`],
+    /// same as [`Scanner::accept_if()`].
+    pub fn accept_if<F>(&mut self, f: F) -> io::Result<ScannerResult<'_, char>>
+    where
+        F: FnOnce(char) -> bool,
+    {
+        self.fill_lookahead()?;
+
+        let mut scanner = Scanner::new(&self.buf);
+        scanner.set_cursor_pos(self.cursor);
+        let result = scanner.accept_if(f);
+        self.cursor = scanner.cursor_pos();
+        Ok(self.offset_result(result))
+    }
+
+    /// Advances the cursor if the next [`char`] is `expected`,
+    /// same as [`Scanner::accept_char()`].
+    pub fn accept_char(&mut self, expected: char) -> io::Result<ScannerResult<'_, char>> {
+        self.fill_lookahead()?;
+
+        let mut scanner = Scanner::new(&self.buf);
+        scanner.set_cursor_pos(self.cursor);
+        let result = scanner.accept_char(expected);
+        self.cursor = scanner.cursor_pos();
+        Ok(self.offset_result(result))
+    }
+
+    /// Advances the cursor if the next text is `expected`,
+    /// same as [`Scanner::accept_str()`].
+    pub fn accept_str(&mut self, expected: &str) -> io::Result<ScannerResult<'_, &str>> {
+        self.fill_lookahead()?;
+        while !self.eof && ((self.buf.len() - self.cursor) < expected.len()) {
+            self.fill()?;
+        }
+
+        let mut scanner = Scanner::new(&self.buf);
+        scanner.set_cursor_pos(self.cursor);
+        let result = scanner.accept_str(expected);
+        self.cursor = scanner.cursor_pos();
+        Ok(self.offset_result(result))
+    }
+
+    /// Advances the cursor while `f` returns `true`, and returns the range
+    /// of the skipped text, same as [`Scanner::skip_while()`].
+    ///
+    /// The skipped text is not buffered, so any amount
+    /// of text can be skipped in bounded memory.
+    pub fn skip_while<F>(&mut self, mut f: F) -> io::Result<Range<usize>>
+    where
+        F: FnMut(char) -> bool,
+    {
+        let start = self.cursor_pos();
+        loop {
+            self.fill_lookahead()?;
+
+            let rest = &self.buf[self.cursor..];
+            self.cursor += rest.find(|c| !f(c)).unwrap_or(rest.len());
+
+            if (self.cursor < self.buf.len()) || self.eof {
+                break;
+            }
+        }
+        Ok(start..self.cursor_pos())
+    }
+
+    /// Advances the cursor until `f` returns `true`, and returns the range
+    /// of the skipped text, same as [`Scanner::skip_until()`].
+    ///
+    /// The skipped text is not buffered, so any amount
+    /// of text can be skipped in bounded memory.
+    #[inline]
+    pub fn skip_until<F>(&mut self, mut f: F) -> io::Result<Range<usize>>
+    where
+        F: FnMut(char) -> bool,
+    {
+        self.skip_while(|c| !f(c))
+    }
+
+    /// Advances the cursor past any whitespace, see [`skip_while()`].
+    ///
+    /// [`skip_while()`]: Self::skip_while
+    #[inline]
+    pub fn skip_whitespace(&mut self) -> io::Result<Range<usize>> {
+        self.skip_while(char::is_whitespace)
+    }
+
+    /// Returns the reader, discarding any buffered text.
+    #[inline]
+    pub fn into_inner(self) -> R {
+        self.reader
+    }
+
+    /// Translates the ranges of `result` from `buf` into the source.
+    fn offset_result<'a, T>(&self, result: ScannerResult<'a, T>) -> ScannerResult<'a, T> {
+        let offset = |r: Range<usize>| (self.offset + r.start)..(self.offset + r.end);
+        match result {
+            Ok((r, value)) => Ok((offset(r), value)),
+            Err((r, s)) => Err((offset(r), s)),
+        }
+    }
+
+    /// Reads until at least [`lookahead()`] bytes are buffered after the
+    /// cursor, while discarding the text before the cursor.
+    ///
+    /// [`lookahead()`]: Self::lookahead
+    fn fill_lookahead(&mut self) -> io::Result<()> {
+        if (self.buf.len() - self.cursor) >= self.lookahead {
+            return Ok(());
+        }
+
+        self.buf.drain(..self.cursor);
+        self.offset += self.cursor;
+        self.cursor = 0;
+
+        while !self.eof && (self.buf.len() < self.lookahead) {
+            self.fill()?;
+        }
+        Ok(())
+    }
+
+    /// Reads the next chunk of the source into `buf`.
+    fn fill(&mut self) -> io::Result<()> {
+        let chunk = self.reader.fill_buf()?;
+        if chunk.is_empty() {
+            self.eof = true;
+            if !self.partial.is_empty() {
+                return Err(io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    "stream did not contain valid UTF-8",
+                ));
+            }
+            return Ok(());
+        }
+
+        let len = chunk.len();
+        self.partial.extend_from_slice(chunk);
+        self.reader.consume(len);
+
+        let valid_len = match str::from_utf8(&self.partial) {
+            Ok(s) => s.len(),
+            // Incomplete UTF-8 sequence at the end of the chunk
+            Err(err) if err.error_len().is_none() => err.valid_up_to(),
+            Err(err) => return Err(io::Error::new(io::ErrorKind::InvalidData, err)),
+        };
+        let valid = str::from_utf8(&self.partial[..valid_len]).unwrap();
+        self.buf.push_str(valid);
+        self.partial.drain(..valid_len);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::io::BufReader;
+
+    /// Returns a reader producing at most `chunk_len` bytes per read.
+    fn reader(text: &str, chunk_len: usize) -> impl BufRead + '_ {
+        BufReader::with_capacity(chunk_len, text.as_bytes())
+    }
+
+    #[test]
+    fn test_streaming_scanner_chunks() {
+        let text = "fn 🦀() { \"a long string literal\" }  æøå";
+
+        for chunk_len in 1..8 {
+            for lookahead in [1, 2, 5, 64] {
+                let mut scanner =
+                    StreamingScanner::with_lookahead(reader(text, chunk_len), lookahead);
+
+                let mut tokens = Vec::new();
+                while scanner.has_remaining_text().unwrap() {
+                    scanner.skip_whitespace().unwrap();
+                    let tok = scanner
+                        .scan_with(|scanner| {
+                            if scanner.accept_char('"').is_ok() {
+                                scanner.skip_until_char('"');
+                                scanner.accept_char('"')?;
+                            } else {
+                                scanner.skip_until(char::is_whitespace);
+                            }
+                            Ok(())
+                        })
+                        .unwrap();
+                    if let Ok((r, s)) = tok {
+                        assert_eq!(&text[r.clone()], s);
+                        tokens.push(s.to_owned());
+                    }
+                }
+
+                assert_eq!(
+                    tokens,
+                    ["fn", "🦀()", "{", "\"a long string literal\"", "}", "æøå"],
+                    "{chunk_len} {lookahead}"
+                );
+                assert_eq!(scanner.cursor_pos(), text.len());
+            }
+        }
+    }
+
+    #[cfg(feature = "ext")]
+    #[test]
+    fn test_streaming_scanner_chunk_boundary() {
+        use crate::ext::RustScannerExt;
+
+        for chunk_len in 1..4 {
+            let mut scanner = StreamingScanner::with_lookahead(reader("// c", chunk_len), 1);
+            let comment = scanner.scan_with(|scanner| {
+                scanner.scan_rust_line_comment()?;
+                Ok(())
+            });
+            assert_eq!(comment.unwrap(), Ok((0..4, "// c")), "{chunk_len}");
+
+            let mut scanner = StreamingScanner::with_lookahead(reader("1.5", chunk_len), 1);
+            let num = scanner.scan_with(|scanner| {
+                scanner.accept_if_ext(char::is_ascii_digit)?;
+                // Fraction is only accepted if followed by a digit
+                _ = scanner.scan_with(|scanner| {
+                    scanner.accept_char('.')?;
+                    scanner.accept_if_ext(char::is_ascii_digit)?;
+                    Ok(())
+                });
+                Ok(())
+            });
+            assert_eq!(num.unwrap(), Ok((0..3, "1.5")), "{chunk_len}");
+        }
+    }
+
+    #[test]
+    fn test_streaming_scanner_accept() {
+        let mut scanner = StreamingScanner::with_lookahead(reader("ab\ncd", 1), 1);
+
+        assert_eq!(scanner.peek().unwrap(), Ok((0..1, 'a')));
+        assert_eq!(scanner.accept_str("ab\nc").unwrap(), Ok((0..4, "ab\nc")));
+        assert_eq!(scanner.accept_char('x').unwrap(), Err((4..4, "")));
+        assert_eq!(scanner.accept_char('d').unwrap(), Ok((4..5, 'd')));
+        assert_eq!(scanner.peek().unwrap(), Err((5..5, "")));
+        assert!(!scanner.has_remaining_text().unwrap());
+    }
+
+    #[test]
+    fn test_streaming_scanner_bounded() {
+        let text = format!("{}x", " ".repeat(100_000));
+        let mut scanner = StreamingScanner::with_lookahead(reader(&text, 16), 32);
+
+        assert_eq!(scanner.skip_whitespace().unwrap(), 0..100_000);
+        assert!(scanner.buf.len() <= 64, "{}", scanner.buf.len());
+        assert_eq!(scanner.buffered_text(), "x");
+    }
+
+    #[test]
+    fn test_streaming_scanner_invalid_utf8() {
+        let bytes: &[u8] = b"ab\xFFcd";
+        let mut scanner = StreamingScanner::new(bytes);
+        let err = scanner.skip_whitespace().unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+
+        let bytes: &[u8] = b"ab\xF0\x9F";
+        let mut scanner = StreamingScanner::new(bytes);
+        let err = scanner.skip_whitespace().unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+    }
+}
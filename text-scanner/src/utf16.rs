@@ -0,0 +1,127 @@
+use std::ops::Range;
+
+/// Mapping between UTF-8 byte offsets and UTF-16 code unit offsets
+/// of a text, e.g. for converting token ranges into the positions used
+/// by the [Language Server Protocol] and JavaScript.
+///
+/// Constructing the mapping walks the text once, after which each
+/// conversion is a binary search over the non-ASCII characters.
+/// For ASCII-only text, conversions are free.
+///
+/// For a one-off conversion, use [`Scanner::utf16_pos()`] instead.
+///
+/// # Example
+///
+/// ```rust
+/// # use text_scanner::Utf16Map;
+/// let text = "a 🦀 b";
+/// let map = Utf16Map::new(text);
+///
+/// // The crab is 4 bytes in UTF-8, and 2 code units in UTF-16
+/// assert_eq!(map.utf16_pos(2), 2);
+/// assert_eq!(map.utf16_pos(6), 4);
+/// assert_eq!(map.utf16_range(7..8), 5..6);
+///
+/// assert_eq!(map.byte_pos(5), 7);
+/// ```
+///
+/// [Language Server Protocol]: https://microsoft.github.io/language-server-protocol/specifications/lsp/3.17/specification/#textDocuments
+/// [`Scanner::utf16_pos()`]: crate::Scanner::utf16_pos
+#[derive(PartialEq, Eq, Clone, Debug)]
+pub struct Utf16Map {
+    /// The byte offset and UTF-16 offset after each non-ASCII character.
+    checkpoints: Vec<(usize, usize)>,
+    len: usize,
+}
+
+impl Utf16Map {
+    /// Constructs a new [`Utf16Map`] for `text`.
+    pub fn new(text: &str) -> Self {
+        let mut checkpoints = Vec::new();
+        let mut utf16_pos = 0;
+        for (i, c) in text.char_indices() {
+            utf16_pos += c.len_utf16();
+            if !c.is_ascii() {
+                checkpoints.push((i + c.len_utf8(), utf16_pos));
+            }
+        }
+        Self {
+            checkpoints,
+            len: text.len(),
+        }
+    }
+
+    /// Returns the UTF-16 offset of the UTF-8 byte offset `byte_pos`.
+    ///
+    /// # Panics
+    ///
+    /// Panics in non-optimized builds, if `byte_pos` is greater than
+    /// the length of the text. In optimized builds, the result is then
+    /// unspecified, as it is if `byte_pos` is not at a UTF-8 sequence
+    /// boundary.
+    pub fn utf16_pos(&self, byte_pos: usize) -> usize {
+        debug_assert!(byte_pos <= self.len, "`byte_pos` is out of bounds");
+
+        let i = self
+            .checkpoints
+            .partition_point(|&(byte, _utf16)| byte <= byte_pos);
+        match i.checked_sub(1).map(|i| self.checkpoints[i]) {
+            Some((byte, utf16)) => utf16 + (byte_pos - byte),
+            None => byte_pos,
+        }
+    }
+
+    /// Returns the UTF-8 byte offset of the UTF-16 offset `utf16_pos`.
+    ///
+    /// If `utf16_pos` is past the end of the text, then the length
+    /// of the text is returned. If `utf16_pos` is not at a character
+    /// boundary, then the result is unspecified.
+    pub fn byte_pos(&self, utf16_pos: usize) -> usize {
+        let i = self
+            .checkpoints
+            .partition_point(|&(_byte, utf16)| utf16 <= utf16_pos);
+        let byte_pos = match i.checked_sub(1).map(|i| self.checkpoints[i]) {
+            Some((byte, utf16)) => byte + (utf16_pos - utf16),
+            None => utf16_pos,
+        };
+        byte_pos.min(self.len)
+    }
+
+    /// Returns the UTF-16 range of the UTF-8 byte range `range`,
+    /// see [`utf16_pos()`](Self::utf16_pos).
+    #[inline]
+    pub fn utf16_range(&self, range: Range<usize>) -> Range<usize> {
+        self.utf16_pos(range.start)..self.utf16_pos(range.end)
+    }
+
+    /// Returns the UTF-8 byte range of the UTF-16 range `range`,
+    /// see [`byte_pos()`](Self::byte_pos).
+    #[inline]
+    pub fn byte_range(&self, range: Range<usize>) -> Range<usize> {
+        self.byte_pos(range.start)..self.byte_pos(range.end)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_utf16_map() {
+        let texts = ["", "abc", "ÆØÅ", "a🦀b東c", "🦀🦀\n\u{10FFFF}x"];
+
+        for text in texts {
+            let map = Utf16Map::new(text);
+
+            let boundaries = text.char_indices().map(|(i, _c)| i).chain([text.len()]);
+            for byte_pos in boundaries {
+                let expected = text[..byte_pos].encode_utf16().count();
+                assert_eq!(map.utf16_pos(byte_pos), expected, "{text:?} {byte_pos}");
+                assert_eq!(map.byte_pos(expected), byte_pos, "{text:?} {expected}");
+            }
+
+            let utf16_len = text.encode_utf16().count();
+            assert_eq!(map.byte_pos(utf16_len + 10), text.len());
+        }
+    }
+}